@@ -0,0 +1,62 @@
+// Timing of algebra::business_days_between over multi-decade ranges, against
+// the previous approach of materializing every business day.
+// Run with `cargo bench --bench business_days`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use findates::algebra;
+use findates::calendar;
+
+fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, day).unwrap()
+}
+
+fn time<F: FnMut()>(label: &str, iterations: u32, mut f: F) -> Duration {
+    let started = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let elapsed = started.elapsed();
+    println!("{label:<48} {:>12.3?} / iter", elapsed / iterations);
+    elapsed
+}
+
+fn main() {
+    let mut cal = calendar::basic_calendar();
+    for year in 1970..=2070 {
+        cal.add_holidays([d(year, 1, 1), d(year, 7, 4), d(year, 12, 25)]);
+    }
+
+    for (label, start, end) in [
+        ("1y", d(2024, 1, 2), d(2025, 1, 2)),
+        ("10y", d(2024, 1, 2), d(2034, 1, 2)),
+        ("50y", d(2000, 1, 3), d(2050, 1, 3)),
+    ] {
+        let schedule = time(&format!("bus_day_schedule_with().len() {label}"), 10, || {
+            let options = algebra::BusDayScheduleOptions::default();
+            black_box(algebra::bus_day_schedule_with(&start, &end, &cal, options).len());
+        });
+        let direct = time(&format!("business_days_between {label}"), 10, || {
+            black_box(algebra::business_days_between(&start, &end, &cal, None));
+        });
+        println!("{:<48} {:>11.1}x", "speedup", schedule.as_secs_f64() / direct.as_secs_f64());
+    }
+
+    // A short range on a calendar with many holidays: the holiday count
+    // should not grow with the size of the calendar.
+    let mut large = calendar::basic_calendar();
+    let mut day = d(1900, 1, 1);
+    while day < d(2100, 1, 1) {
+        large.add_holidays([day]);
+        day = day + chrono::Days::new(3);
+    }
+    let (start, end) = (d(2024, 3, 4), d(2024, 3, 15));
+    time("business_days_between 2w, small calendar", 10_000, || {
+        black_box(algebra::business_days_between(&start, &end, &cal, None));
+    });
+    time("business_days_between 2w, large calendar", 10_000, || {
+        black_box(algebra::business_days_between(&start, &end, &large, None));
+    });
+}
@@ -0,0 +1,63 @@
+// Timing comparison between Calendar and CompiledCalendar business day math.
+// Run with `cargo bench --bench compiled`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use chrono::{Days, NaiveDate};
+use findates::algebra;
+use findates::calendar::{self, Calendar};
+
+fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, day).unwrap()
+}
+
+fn time<F: FnMut()>(label: &str, iterations: u32, mut f: F) -> Duration {
+    let started = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let elapsed = started.elapsed();
+    println!("{label:<48} {:>12.3?} / iter", elapsed / iterations);
+    elapsed
+}
+
+fn main() {
+    let mut cal: Calendar = calendar::basic_calendar();
+    for year in 2000..=2050 {
+        cal.add_holidays([d(year, 1, 1), d(year, 7, 4), d(year, 12, 25)]);
+    }
+    let start = d(2000, 1, 3);
+    let end = d(2050, 12, 30);
+
+    time("compile 2000..=2050", 20, || {
+        black_box(cal.compile(start..=end).unwrap());
+    });
+    let fast = cal.compile(start..=end).unwrap();
+
+    let dates: Vec<NaiveDate> = (0..10_000u64).map(|i| start + Days::new(i * 7 % 18_000)).collect();
+    time("Calendar::is_business_day x10k", 50, || {
+        for date in &dates {
+            black_box(cal.is_business_day(black_box(date)));
+        }
+    });
+    time("CompiledCalendar::is_business_day x10k", 50, || {
+        for date in &dates {
+            black_box(fast.is_business_day(black_box(date)).unwrap());
+        }
+    });
+
+    time("algebra::business_days_between 50y", 5, || {
+        black_box(algebra::business_days_between(&start, &end, &cal, None));
+    });
+    time("CompiledCalendar::business_days_between 50y", 5, || {
+        black_box(fast.business_days_between(&start, &end).unwrap());
+    });
+
+    time("algebra::add_business_days 5000", 5, || {
+        black_box(algebra::add_business_days(&start, 5000, &cal).unwrap());
+    });
+    time("CompiledCalendar::add_business_days 5000", 5, || {
+        black_box(fast.add_business_days(&start, 5000).unwrap());
+    });
+}
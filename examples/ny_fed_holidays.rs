@@ -11,9 +11,10 @@
 use findates::algebra;
 use findates::calendar::Calendar;
 use findates::conventions::*;
+use findates::holidays::{HolidayRule, ObservanceRule};
 use findates::schedule::Schedule;
 // Chrono imports
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{NaiveDate, Weekday};
 
 fn main() {
     // ========================================================================================================================
@@ -75,164 +76,39 @@ fn main() {
     // Ok, but according to https://www.federalpay.org/holidays
     // Holidays that fall on a weekend should be observed on a Friday if they
     // fall on a Saturday and on a Monday if they fall on a Sunday.
-    // To achieve that, we can make use of the Nearest Adjustment Rule:
-    let new_year_schedule: Schedule = Schedule::new(
-        Frequency::Annual,
-        Some(&ny_fed_calendar),
-        Some(AdjustRule::Nearest),
-    );
-    let real_new_years: Vec<NaiveDate> = new_year_schedule
-        .generate(
-            &new_year_day,
-            &algebra::checked_add_years(&new_year_day, 10).unwrap(),
-        )
-        .expect("This should work");
-    println!("The actual observed days: {:?}", &real_new_years);
-
-    // Lets add these to our calendar:
-    // ny_fed_calendar.add_holidays(real_new_years);
-
-    // It turns out that for the next four years NY's days will fall
-    // on Weekdays, so no adjustment was needed! Let's repeat this procedure
-    // for the rest of the fixed date holidays:
-    let independence_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 7, 4).unwrap();
-    let independence_day_sch: Schedule = Schedule::new(
-        Frequency::Annual,
-        Some(&ny_fed_calendar),
-        Some(AdjustRule::Nearest),
-    );
-    let indep_days = independence_day_sch
-        .generate(
-            &independence_day,
-            &algebra::checked_add_years(&independence_day, 10).unwrap(),
-        )
-        .unwrap();
-    println!("4th of july dates: {:?}", &indep_days);
-
-    // Christmas day now.
-    let christmas_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
-    let christmas_day_sch: Schedule = Schedule::new(
-        Frequency::Annual,
-        Some(&ny_fed_calendar),
-        Some(AdjustRule::Nearest),
-    );
-    let christmas_days = christmas_day_sch
-        .generate(
-            &christmas_day,
-            &algebra::checked_add_years(&christmas_day, 10).unwrap(),
-        )
-        .unwrap();
-    println!("Christmas dates: {:?}", &christmas_days);
-
-    // And Veterans day.
-    let veterans_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 11, 11).unwrap();
-    let veterans_day_sch: Schedule = Schedule::new(
-        Frequency::Annual,
-        Some(&ny_fed_calendar),
-        Some(AdjustRule::Nearest),
-    );
-    let veterans_days = veterans_day_sch
-        .generate(
-            &veterans_day,
-            &algebra::checked_add_years(&veterans_day, 10).unwrap(),
-        )
-        .unwrap();
-    println!("Veteran days dates: {:?}", &veterans_days);
-
-    // And Juneteenth.
-    let juneteenth_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 06, 19).unwrap();
-    let juneteenth_day_sch: Schedule = Schedule::new(
-        Frequency::Annual,
-        Some(&ny_fed_calendar),
-        Some(AdjustRule::Nearest),
+    // Rather than generating and adjusting each holiday by hand, we can describe
+    // every holiday as a rule and attach that observance policy to it:
+    let observed = ObservanceRule::NearestWeekday;
+    let us_federal_rules = [
+        // Fixed date holidays
+        HolidayRule::fixed("New Year's Day", 1, 1).observed(observed),
+        HolidayRule::fixed("Juneteenth National Independence Day", 6, 19).observed(observed),
+        HolidayRule::fixed("Independence Day", 7, 4).observed(observed),
+        HolidayRule::fixed("Veterans Day", 11, 11).observed(observed),
+        HolidayRule::fixed("Christmas Day", 12, 25).observed(observed),
+        // Floating holidays always fall on a weekday, so there is nothing to observe.
+        HolidayRule::nth_weekday("Martin Luther King Jr. Day", 1, Weekday::Mon, 3),
+        HolidayRule::nth_weekday("Washington's Birthday", 2, Weekday::Mon, 3),
+        HolidayRule::last_weekday("Memorial Day", 5, Weekday::Mon),
+        HolidayRule::nth_weekday("Labor Day", 9, Weekday::Mon, 1),
+        HolidayRule::nth_weekday("Columbus Day", 10, Weekday::Mon, 2),
+        HolidayRule::nth_weekday("Thanksgiving Day", 11, Weekday::Thu, 4),
+    ];
+
+    // The calendar stores the observed dates, using its own weekend to decide
+    // when a holiday needs to move. Let's generate ten years of holidays:
+    ny_fed_calendar.add_holiday_rules(&us_federal_rules, 2024..=2033);
+
+    // Independence Day 2026 falls on a Saturday, so it is observed on Friday the 3rd:
+    let observed_independence_day = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+    println!(
+        "{} is {:?}",
+        observed_independence_day,
+        ny_fed_calendar.holiday_name(&observed_independence_day)
     );
-    let juneteenth_days = juneteenth_day_sch
-        .generate(
-            &juneteenth_day,
-            &algebra::checked_add_years(&juneteenth_day, 10).unwrap(),
-        )
-        .unwrap();
-    println!("Juneteenth dates: {:?}", &juneteenth_days);
-
-    // Creating the floating holidays for a particular year
-    // is made easy using the functionalities from chrono and the use of closures.
-    // Let's start
-    let years = 2024..=2033;
-    let thanksgiving_days: Vec<NaiveDate>;
-    thanksgiving_days = years
-        .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 11, Weekday::Thu, 4).unwrap())
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("Thanksgiving dates: {:?}", &thanksgiving_days);
-
-    let labor_days: Vec<NaiveDate>;
-    labor_days = years
-        .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 9, Weekday::Mon, 1).unwrap())
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("Labor Day dates: {:?}", &labor_days);
-
-    let columbus_days: Vec<NaiveDate>;
-    columbus_days = years
-        .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 10, Weekday::Mon, 2).unwrap())
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("Columbus Day dates: {:?}", &columbus_days);
-
-    let mlkjr_days: Vec<NaiveDate>;
-    mlkjr_days = years
-        .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 1, Weekday::Mon, 3).unwrap())
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("MLK Jr Day dates: {:?}", &mlkjr_days);
-
-    let washington_days: Vec<NaiveDate>;
-    washington_days = years
-        .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 2, Weekday::Mon, 3).unwrap())
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("Washington's birthdate dates: {:?}", &washington_days);
-
-    // For memorial day we use a little trick with Weekdays
-    fn last_monday_of_may(year: i32) -> NaiveDate {
-        let may_31st = NaiveDate::from_ymd_opt(year, 5, 31).unwrap();
-        let delta = may_31st.weekday().num_days_from_monday();
-        return may_31st - chrono::Duration::days(delta as i64);
-    }
-
-    let memorial_days: Vec<NaiveDate>;
-    memorial_days = years
-        .clone()
-        .map(|x| last_monday_of_may(x))
-        .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
-        .collect();
-    println!("Memorial Day dates: {:?}", &memorial_days);
-
-    // Now let's add all of those dates to our calendar.
-    let mut all_holidays: Vec<NaiveDate> = itertools::concat([
-        real_new_years,
-        christmas_days,
-        indep_days,
-        veterans_days,
-        juneteenth_days,
-        thanksgiving_days,
-        labor_days,
-        columbus_days,
-        mlkjr_days,
-        washington_days,
-        memorial_days,
-    ])
-    .into_iter()
-    .collect();
-    all_holidays.sort();
-    println!("Holiday Calendar: {:?}", &all_holidays);
 
-    ny_fed_calendar.add_holidays(all_holidays);
+    // And the full list of holidays for a given year is one call away:
+    println!("Holidays in 2026: {:?}", ny_fed_calendar.holidays_in_year(2026));
 
     // Now let's see how our calendar looks:
     println!(
@@ -284,7 +160,7 @@ fn main() {
     // day count convention, so lets calculate that:
     let mut dcfs: Vec<f64> = vec![];
     for i in 0..(coupon_dates_list.len() - 1) {
-        let dcf = algebra::day_count_fraction(
+        let dcf = algebra::try_day_count_fraction(
             coupon_dates_list.get(i).unwrap(),
             coupon_dates_list.get(i + 1).unwrap(),
             DayCount::D30360Euro,
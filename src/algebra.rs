@@ -5,13 +5,19 @@
 //! maintaining any internal state.
 
 use crate::calendar::Calendar;
-use crate::conventions::{AdjustRule, DayCount};
-use crate::error::{BusinessDayError, DayCountError};
-use chrono::{Datelike, Days, NaiveDate};
+use crate::conventions::{AdjustRule, DayCount, ExpiryRule, ExpiryStyle, Frequency, Rounding, Tenor};
+use crate::error::{
+    BusinessDayError, CalendarError, DateError, DayCountError, ForwardDateError, FuturesExpiryError, ParseTenorError,
+};
+use crate::sessions::{MarketHours, TradingSession};
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, TimeZone, Weekday};
+use std::collections::HashSet;
+use std::fmt;
 
 /// Returns `true` if `date` is a good business day in `calendar`.
 ///
 /// A date is a business day when it is neither a weekend day nor a holiday.
+/// Same as [`Calendar::is_business_day`].
 ///
 /// # Examples
 ///
@@ -28,10 +34,7 @@ use chrono::{Datelike, Days, NaiveDate};
 /// assert!(!is_business_day(&saturday, &cal));
 /// ```
 pub fn is_business_day(date: &NaiveDate, calendar: &Calendar) -> bool {
-    if calendar.get_weekend().contains(&date.weekday()) {
-        return false;
-    }
-    !calendar.get_holidays().contains(date)
+    calendar.is_business_day(date)
 }
 
 /// Adjusts `date` to a business day according to `calendar` and `adjust_rule`.
@@ -67,90 +70,529 @@ pub fn adjust(
     opt_calendar: Option<&Calendar>,
     adjust_rule: Option<AdjustRule>,
 ) -> NaiveDate {
-    let calendar = match opt_calendar {
-        None      => return *date,
-        Some(cal) => cal,
-    };
+    checked_adjust(date, opt_calendar, adjust_rule)
+        .unwrap_or_else(|| panic!("Date out of range while searching for business day"))
+}
 
-    if is_business_day(date, calendar) {
-        return *date;
+/// Adjusts `date` like [`adjust`], returning `None` instead of panicking if
+/// the search for a business day runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::Calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::algebra::checked_adjust;
+///
+/// // The last representable date is a holiday: there is no following business day.
+/// let cal = Calendar::with_holidays([NaiveDate::MAX]);
+/// assert_eq!(checked_adjust(&NaiveDate::MAX, Some(&cal), Some(AdjustRule::Following)), None);
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// assert!(checked_adjust(&d, Some(&cal), Some(AdjustRule::Following)).is_some());
+/// ```
+pub fn checked_adjust(
+    date: &NaiveDate,
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Option<NaiveDate> {
+    match adjust_rule {
+        None       => Some(*date),
+        Some(rule) => checked_adjust_with(date, opt_calendar, &rule),
     }
+}
 
-    match adjust_rule {
-        None | Some(AdjustRule::Unadjusted) => *date,
+/// Why a date did or did not need business day adjustment.
+///
+/// Part of the [`Adjustment`] returned by [`adjust_explain`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::{adjust_explain, AdjustReason};
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// let explained = adjust_explain(&sat, Some(&basic_calendar()), Some(AdjustRule::Following));
+/// assert_eq!(explained.reason, AdjustReason::Weekend(Weekday::Sat));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdjustReason {
+    /// The date is already a business day.
+    BusinessDay,
+    /// No adjustment applies: there is no calendar, no rule, or the rule is
+    /// [`Unadjusted`](AdjustRule::Unadjusted).
+    NotAdjusted,
+    /// The date falls on this weekend day of the calendar.
+    Weekend(Weekday),
+    /// The date is a holiday of the calendar, with its name if the calendar
+    /// has one (see [`Calendar::holiday_name`]).
+    Holiday(Option<String>),
+}
 
-        Some(AdjustRule::Following) => add_adjust(date, calendar),
+/// The result of [`adjust_explain`]: a date before and after adjustment, and
+/// why it moved.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{adjust_explain, AdjustReason};
+/// use findates::calendar::Calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+/// let mut cal = Calendar::weekend_only();
+/// cal.add_named_holiday(xmas, "Christmas Day");
+///
+/// let explained = adjust_explain(&xmas, Some(&cal), Some(AdjustRule::Preceding));
+/// assert_eq!(explained.adjusted, NaiveDate::from_ymd_opt(2024, 12, 24).unwrap());
+/// assert_eq!(explained.days_moved, -1);
+/// assert_eq!(explained.reason, AdjustReason::Holiday(Some("Christmas Day".to_string())));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Adjustment {
+    /// The date before adjustment.
+    pub original: NaiveDate,
+    /// The date after adjustment, as returned by [`adjust`].
+    pub adjusted: NaiveDate,
+    /// Calendar days from `original` to `adjusted`; negative when the date
+    /// moved back.
+    pub days_moved: i64,
+    /// Why `original` did or did not need adjusting.
+    pub reason: AdjustReason,
+}
 
-        Some(AdjustRule::ModFollowing) => {
-            let adj = add_adjust(date, calendar);
-            if adj.month() != date.month() { sub_adjust(date, calendar) } else { adj }
-        }
+/// Adjusts `date` like [`adjust`] and explains the result, so that
+/// reconciliation tools can report whether a date moved because of a
+/// weekend or a named holiday.
+///
+/// The reason describes `date` itself.  A date that is both a weekend day
+/// and a holiday is reported as [`AdjustReason::Weekend`].  A
+/// non-business day left in place by
+/// [`Unadjusted`](AdjustRule::Unadjusted) or a missing calendar or rule is
+/// [`AdjustReason::NotAdjusted`].
+///
+/// # Panics
+///
+/// Panics if the search for a business day runs off the representable date
+/// range, as [`adjust`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{adjust_explain, AdjustReason};
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let cal = basic_calendar();
+/// let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+///
+/// let explained = adjust_explain(&sat, Some(&cal), Some(AdjustRule::Following));
+/// assert_eq!(explained.adjusted, NaiveDate::from_ymd_opt(2024, 3, 18).unwrap());
+/// assert_eq!(explained.days_moved, 2);
+///
+/// let unadjusted = adjust_explain(&sat, Some(&cal), Some(AdjustRule::Unadjusted));
+/// assert_eq!(unadjusted.reason, AdjustReason::NotAdjusted);
+/// ```
+pub fn adjust_explain(
+    date: &NaiveDate,
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Adjustment {
+    let adjusted = adjust(date, opt_calendar, adjust_rule);
+    let reason = match (opt_calendar, adjust_rule) {
+        (Some(cal), _) if cal.is_business_day(date) => AdjustReason::BusinessDay,
+        (None, _) | (_, None) | (_, Some(AdjustRule::Unadjusted)) => AdjustReason::NotAdjusted,
+        (Some(cal), _) if cal.is_weekend(date) => AdjustReason::Weekend(date.weekday()),
+        (Some(cal), _) => AdjustReason::Holiday(cal.holiday_name(date).map(str::to_string)),
+    };
+    Adjustment {
+        original: *date,
+        adjusted,
+        days_moved: (adjusted - *date).num_days(),
+        reason,
+    }
+}
 
-        Some(AdjustRule::Preceding) => sub_adjust(date, calendar),
+/// A business day adjustment rule.
+///
+/// Implemented by [`AdjustRule`] for the standard conventions.  Implement it
+/// for your own types to plug other rules into [`adjust_with`] and
+/// [`checked_adjust_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{adjust_with, BusinessDayAdjuster};
+/// use findates::calendar::{basic_calendar, Calendar};
+/// use findates::conventions::AdjustRule;
+///
+/// // Following, unless that would roll past the next coupon date.
+/// struct FollowingBefore(NaiveDate);
+///
+/// impl BusinessDayAdjuster for FollowingBefore {
+///     fn checked_adjust(&self, date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+///         match AdjustRule::Following.checked_adjust(date, calendar)? {
+///             adjusted if adjusted < self.0 => Some(adjusted),
+///             _ => AdjustRule::Preceding.checked_adjust(date, calendar),
+///         }
+///     }
+/// }
+///
+/// let cal    = basic_calendar();
+/// let sat    = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// let coupon = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+/// assert_eq!(adjust_with(&sat, Some(&cal), &FollowingBefore(coupon)),
+///            NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+/// ```
+pub trait BusinessDayAdjuster {
+    /// Adjusts `date` to a business day of `calendar`, returning `None` if
+    /// the search runs off the representable date range.
+    ///
+    /// Called for every date, including dates that are already business
+    /// days.
+    fn checked_adjust(&self, date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate>;
+}
 
-        Some(AdjustRule::ModPreceding) => {
-            let adj = sub_adjust(date, calendar);
-            if adj.month() != date.month() { add_adjust(date, calendar) } else { adj }
+impl BusinessDayAdjuster for AdjustRule {
+    /// Returns business days unchanged and adjusts other dates as described
+    /// for each variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::algebra::BusinessDayAdjuster;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::AdjustRule;
+    ///
+    /// let cal = basic_calendar();
+    /// let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// assert_eq!(AdjustRule::Preceding.checked_adjust(&sat, &cal),
+    ///            NaiveDate::from_ymd_opt(2024, 3, 15));
+    /// ```
+    fn checked_adjust(&self, date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+        if is_business_day(date, calendar) {
+            return Some(*date);
         }
 
-        Some(AdjustRule::HalfMonthModFollowing) => {
-            let adj = add_adjust(date, calendar);
-            if adj.month() != date.month() || (date.day() <= 15 && adj.day() > 15) {
-                sub_adjust(date, calendar)
-            } else {
-                adj
+        match self {
+            AdjustRule::Unadjusted => Some(*date),
+
+            AdjustRule::Following => add_adjust(date, calendar),
+
+            AdjustRule::ModFollowing => {
+                let adj = add_adjust(date, calendar);
+                match adj {
+                    Some(adj) if adj.month() == date.month() => Some(adj),
+                    _ => sub_adjust(date, calendar),
+                }
             }
-        }
 
-        Some(AdjustRule::Nearest) => {
-            let fwd = add_adjust(date, calendar);
-            let bwd = sub_adjust(date, calendar);
-            if (fwd - *date).num_days().abs() <= (bwd - *date).num_days().abs() {
-                fwd
-            } else {
-                bwd
+            AdjustRule::Preceding => sub_adjust(date, calendar),
+
+            AdjustRule::ModPreceding => {
+                let adj = sub_adjust(date, calendar);
+                match adj {
+                    Some(adj) if adj.month() == date.month() => Some(adj),
+                    _ => add_adjust(date, calendar),
+                }
+            }
+
+            AdjustRule::HalfMonthModFollowing => {
+                let adj = add_adjust(date, calendar);
+                match adj {
+                    Some(adj) if adj.month() == date.month() && !(date.day() <= 15 && adj.day() > 15) => {
+                        Some(adj)
+                    }
+                    _ => sub_adjust(date, calendar),
+                }
+            }
+
+            AdjustRule::Nearest => {
+                let fwd = add_adjust(date, calendar);
+                let bwd = sub_adjust(date, calendar);
+                match (fwd, bwd) {
+                    (Some(fwd), Some(bwd)) => {
+                        if (fwd - *date).num_days().abs() <= (bwd - *date).num_days().abs() {
+                            Some(fwd)
+                        } else {
+                            Some(bwd)
+                        }
+                    }
+                    (fwd, bwd) => fwd.or(bwd),
+                }
             }
         }
     }
 }
 
-fn add_adjust(date: &NaiveDate, calendar: &Calendar) -> NaiveDate {
+/// Adjusts `date` with any [`BusinessDayAdjuster`].
+///
+/// The generic form of [`adjust`]: `date` is returned unchanged when
+/// `opt_calendar` is `None`, and passed to `adjuster` otherwise.
+///
+/// # Panics
+///
+/// Panics if `adjuster` returns `None`, i.e. the search for a business day
+/// runs off the representable date range.  Use [`checked_adjust_with`] to
+/// handle that case.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{adjust, adjust_with};
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let cal = basic_calendar();
+/// let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// assert_eq!(adjust_with(&sat, Some(&cal), &AdjustRule::Following),
+///            adjust(&sat, Some(&cal), Some(AdjustRule::Following)));
+/// ```
+pub fn adjust_with<A: BusinessDayAdjuster + ?Sized>(
+    date: &NaiveDate,
+    opt_calendar: Option<&Calendar>,
+    adjuster: &A,
+) -> NaiveDate {
+    checked_adjust_with(date, opt_calendar, adjuster)
+        .unwrap_or_else(|| panic!("Date out of range while searching for business day"))
+}
+
+/// Adjusts `date` like [`adjust_with`], returning `None` instead of
+/// panicking if the search for a business day runs off the representable
+/// date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::checked_adjust_with;
+/// use findates::calendar::Calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let cal = Calendar::with_holidays([NaiveDate::MAX]);
+/// assert_eq!(checked_adjust_with(&NaiveDate::MAX, Some(&cal), &AdjustRule::Following), None);
+/// assert_eq!(checked_adjust_with(&NaiveDate::MAX, None, &AdjustRule::Following), Some(NaiveDate::MAX));
+/// ```
+pub fn checked_adjust_with<A: BusinessDayAdjuster + ?Sized>(
+    date: &NaiveDate,
+    opt_calendar: Option<&Calendar>,
+    adjuster: &A,
+) -> Option<NaiveDate> {
+    match opt_calendar {
+        None           => Some(*date),
+        Some(calendar) => adjuster.checked_adjust(date, calendar),
+    }
+}
+
+/// Adjusts `date` like [`adjust`], returning an error instead of a silent
+/// answer when the calendar's valid range does not cover the date or its
+/// adjustment.
+///
+/// # Errors
+///
+/// Returns [`CalendarError::OutOfRange`] if `date` or the adjusted date lies
+/// outside the range set with
+/// [`Calendar::set_valid_range`](crate::calendar::Calendar::set_valid_range),
+/// or if the search for a business day runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::algebra::try_adjust;
+/// use findates::CalendarError;
+///
+/// let mut cal = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(); // Saturday
+/// cal.set_valid_range(start, end).unwrap();
+///
+/// let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// assert_eq!(try_adjust(&sat, Some(&cal), Some(AdjustRule::Following)),
+///            Ok(NaiveDate::from_ymd_opt(2024, 3, 18).unwrap()));
+///
+/// // Following from the last covered day lands on Monday 2024-12-30,
+/// // beyond the loaded data.
+/// let monday = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// assert_eq!(try_adjust(&end, Some(&cal), Some(AdjustRule::Following)),
+///            Err(CalendarError::OutOfRange { date: monday }));
+/// ```
+pub fn try_adjust(
+    date: &NaiveDate,
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<NaiveDate, CalendarError> {
+    let out_of_range = |date: NaiveDate| CalendarError::OutOfRange { date };
+    if let Some(cal) = opt_calendar {
+        if !cal.is_in_valid_range(date) {
+            return Err(out_of_range(*date));
+        }
+    }
+    let adjusted = checked_adjust(date, opt_calendar, adjust_rule).ok_or(out_of_range(*date))?;
+    match opt_calendar {
+        Some(cal) if !cal.is_in_valid_range(&adjusted) => Err(out_of_range(adjusted)),
+        _ => Ok(adjusted),
+    }
+}
+
+fn add_adjust(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
     let mut t = 1u64;
     loop {
-        let candidate = date.checked_add_days(Days::new(t))
-            .unwrap_or_else(|| panic!("Date out of range while searching forward for business day"));
+        let candidate = date.checked_add_days(Days::new(t))?;
         if is_business_day(&candidate, calendar) {
-            return candidate;
+            return Some(candidate);
         }
         t += 1;
     }
 }
 
-fn sub_adjust(date: &NaiveDate, calendar: &Calendar) -> NaiveDate {
+fn sub_adjust(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
     let mut t = 1u64;
     loop {
-        let candidate = date.checked_sub_days(Days::new(t))
-            .unwrap_or_else(|| panic!("Date out of range while searching backward for business day"));
+        let candidate = date.checked_sub_days(Days::new(t))?;
         if is_business_day(&candidate, calendar) {
-            return candidate;
+            return Some(candidate);
         }
         t += 1;
     }
 }
 
+/// Adjusts every date in `dates` like [`adjust`], returning the results in
+/// the same order.
+///
+/// When there is nothing to adjust — no calendar, or no rule or
+/// [`Unadjusted`](AdjustRule::Unadjusted) — the dates are copied without any
+/// calendar lookups.
+///
+/// # Panics
+///
+/// Panics like [`adjust`] if the search for a business day runs off the
+/// representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::algebra::adjust_many;
+///
+/// let cal   = basic_calendar();
+/// let dates = [
+///     NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(), // Friday
+///     NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(), // Saturday
+/// ];
+/// let adjusted = adjust_many(&dates, Some(&cal), Some(AdjustRule::Following));
+/// assert_eq!(adjusted, [dates[0], NaiveDate::from_ymd_opt(2024, 3, 18).unwrap()]);
+/// ```
+pub fn adjust_many(
+    dates: &[NaiveDate],
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Vec<NaiveDate> {
+    match (opt_calendar, adjust_rule) {
+        (None, _) | (_, None) | (_, Some(AdjustRule::Unadjusted)) => dates.to_vec(),
+        (calendar, rule) => dates.iter().map(|date| adjust(date, calendar, rule)).collect(),
+    }
+}
+
+/// Which business days [`bus_day_schedule_with`] lists.
+///
+/// The default lists the business days from the start date up to but not
+/// including the end date, without adjusting either date — the days that
+/// accrue over the period, matching [`business_days_between`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::{Boundaries, BusDayScheduleOptions};
+///
+/// let options = BusDayScheduleOptions::default();
+/// assert_eq!(options.boundaries, Boundaries::IncludeStart);
+/// assert_eq!(options.adjust_endpoints, None);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Default)]
+pub struct BusDayScheduleOptions {
+    /// Whether the start and end dates are listed when they are business
+    /// days.
+    pub boundaries: Boundaries,
+    /// Rule used to adjust both endpoints before listing; `None` leaves
+    /// them as given.
+    pub adjust_endpoints: Option<AdjustRule>,
+}
+
+/// Lists the business days between `start_date` and `end_date`, with
+/// `options` choosing the endpoints.
+///
+/// The endpoints are first adjusted with `options.adjust_endpoints`, if
+/// set.  An endpoint is listed only if it is a business day and
+/// `options.boundaries` includes it, so the length of the result equals the
+/// matching [`business_days_between_with_boundaries`] count.  The result is
+/// empty when the end is before the start.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::{bus_day_schedule_with, Boundaries, BusDayScheduleOptions};
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+/// let end   = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
+///
+/// // Monday to Thursday by default
+/// let days = bus_day_schedule_with(&start, &end, &cal, BusDayScheduleOptions::default());
+/// assert_eq!(days.len(), 4);
+///
+/// let both = BusDayScheduleOptions { boundaries: Boundaries::IncludeBoth, ..Default::default() };
+/// assert_eq!(bus_day_schedule_with(&start, &end, &cal, both).len(), 5);
+/// ```
+pub fn bus_day_schedule_with(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    calendar: &Calendar,
+    options: BusDayScheduleOptions,
+) -> Vec<NaiveDate> {
+    let (start, end) = match options.adjust_endpoints {
+        None => (*start_date, *end_date),
+        rule => (adjust(start_date, Some(calendar), rule), adjust(end_date, Some(calendar), rule)),
+    };
+    let include_start = matches!(options.boundaries, Boundaries::IncludeStart | Boundaries::IncludeBoth);
+    let include_end   = matches!(options.boundaries, Boundaries::IncludeEnd | Boundaries::IncludeBoth);
+    business_days_iter(&start, &end, calendar)
+        .filter(|date| (include_start || *date != start) && (include_end || *date != end))
+        .collect()
+}
+
 /// Generates a sorted vector of every business day from `start_date` to
 /// `end_date` inclusive.
 ///
 /// Both endpoints are first adjusted to business days using `adjust_rule`
-/// (defaults to [`Following`](AdjustRule::Following) when `None`).
+/// (defaults to [`Following`](AdjustRule::Following) when `None`).  The
+/// adjusted start is always included; the remaining dates are those of
+/// [`business_days_iter`] up to the adjusted end.
 ///
-/// Consecutive non-business days (e.g. a long holiday period) are handled
-/// correctly — the function always steps to the next business day regardless
-/// of how many non-working days lie between two valid dates.
+/// Prefer [`bus_day_schedule_with`], whose defaults neither adjust the
+/// endpoints nor include the end date.  For adjusting rules this function
+/// equals `bus_day_schedule_with` with
+/// [`Boundaries::IncludeBoth`] and the rule in `adjust_endpoints`.
 ///
 /// # Examples
 ///
 /// ```rust
+/// # #![allow(deprecated)]
 /// use chrono::NaiveDate;
 /// use findates::calendar::basic_calendar;
 /// use findates::algebra::bus_day_schedule;
@@ -161,6 +603,9 @@ fn sub_adjust(date: &NaiveDate, calendar: &Calendar) -> NaiveDate {
 /// let days  = bus_day_schedule(&start, &end, &cal, None);
 /// assert_eq!(days.len(), 5); // Mon – Fri
 /// ```
+#[deprecated(
+    note = "adjusts and includes both endpoints implicitly; use `bus_day_schedule_with`"
+)]
 pub fn bus_day_schedule(
     start_date: &NaiveDate,
     end_date: &NaiveDate,
@@ -173,314 +618,3511 @@ pub fn bus_day_schedule(
     let new_end   = adjust(end_date,   Some(calendar), rule);
 
     let mut schedule = vec![new_start];
-    let mut prev = new_start;
-
-    while prev < new_end {
-        let mut t = 1u64;
-        let mut next = adjust(
-            &prev.checked_add_days(Days::new(t)).unwrap(),
-            Some(calendar),
-            rule,
-        );
-        while next <= prev {
-            t += 1;
-            next = adjust(
-                &prev.checked_add_days(Days::new(t)).unwrap(),
-                Some(calendar),
-                rule,
-            );
-        }
-        schedule.push(next);
-        prev = next;
+    if let Some(next) = new_start.succ_opt() {
+        schedule.extend(business_days_iter(&next, &new_end, calendar));
     }
-
     schedule
 }
 
-/// Counts the number of business days from `start_date` up to but not
-/// including `end_date`.
+/// Returns a lazy iterator over the business days from `start_date` to
+/// `end_date` inclusive.
 ///
-/// This follows the common financial convention of including the start date
-/// and excluding the end date.  Both endpoints are adjusted as in
-/// [`bus_day_schedule`].  The result is equivalent to
-/// `bus_day_schedule(...).len() - 1`.
+/// Neither endpoint is adjusted: non-business days are simply skipped, and
+/// the iterator is empty when `end_date` is before `start_date`.  It is
+/// double-ended, so `.rev()` walks back from `end_date`.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use chrono::NaiveDate;
 /// use findates::calendar::basic_calendar;
-/// use findates::algebra::business_days_between;
+/// use findates::algebra::business_days_iter;
 ///
 /// let cal   = basic_calendar();
-/// let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
-/// let end   = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
-/// // Mon, Tue, Wed, Thu = 4 business days (end excluded)
-/// assert_eq!(business_days_between(&start, &end, &cal, None), 4);
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(); // Saturday
+/// let end   = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(); // Sunday
+///
+/// let mut days = business_days_iter(&start, &end, &cal);
+/// assert_eq!(days.next(), NaiveDate::from_ymd_opt(2024, 3, 18));
+/// assert_eq!(days.next_back(), NaiveDate::from_ymd_opt(2024, 3, 29));
+/// assert_eq!(days.count(), 8);
 /// ```
-pub fn business_days_between(
+pub fn business_days_iter<'a>(
     start_date: &NaiveDate,
     end_date: &NaiveDate,
-    calendar: &Calendar,
-    adjust_rule: Option<AdjustRule>,
-) -> u64 {
-    let schedule = bus_day_schedule(start_date, end_date, calendar, adjust_rule);
-    schedule.len() as u64 - 1
+    calendar: &'a Calendar,
+) -> BusinessDayIterator<'a> {
+    BusinessDayIterator {
+        calendar,
+        front: *start_date,
+        back: *end_date,
+        finished: end_date < start_date,
+    }
 }
 
-/// Computes the day count fraction between two dates using the given convention.
-///
-/// If `calendar` is `None`, no date adjustment is performed.  If `calendar`
-/// is provided and `adjust_rule` is `None`, the adjustment rule defaults to
-/// [`Following`](AdjustRule::Following) before computing the fraction.
-/// To suppress adjustment while still providing a calendar (e.g. for
-/// [`Bd252`](DayCount::Bd252)), pass `Some(AdjustRule::Unadjusted)`.
-///
-/// If `end_date` is before `start_date` the fraction is computed on the
-/// absolute time difference.
-///
-/// # Errors
+/// Lazy, double-ended iterator over the business days of a date range.
 ///
-/// Returns [`Err(DayCountError::MissingCalendar)`](DayCountError::MissingCalendar)
-/// if `daycount` is [`Bd252`](DayCount::Bd252) and `calendar` is `None`.
+/// Created by [`business_days_iter`] — do not construct directly.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use chrono::NaiveDate;
-/// use findates::algebra::day_count_fraction;
-/// use findates::conventions::DayCount;
-///
-/// // 2023 is not a leap year: exactly 365 days between these dates.
-/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
-/// let end   = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::business_days_iter;
 ///
-/// // Act/365 over a full non-leap year = exactly 1.0
-/// let dcf = day_count_fraction(&start, &end, DayCount::Act365, None, None).unwrap();
-/// assert!((dcf - 1.0).abs() < 1e-9);
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
 ///
-/// // Act/360 over 365 days
-/// let dcf360 = day_count_fraction(&start, &end, DayCount::Act360, None, None).unwrap();
-/// assert!((dcf360 - 365.0 / 360.0).abs() < 1e-9);
+/// // The last three business days of 2024
+/// let last: Vec<NaiveDate> = business_days_iter(&start, &end, &cal).rev().take(3).collect();
+/// assert_eq!(last[0], end);
 /// ```
-pub fn day_count_fraction(
-    start_date: &NaiveDate,
-    end_date: &NaiveDate,
-    daycount: DayCount,
-    calendar: Option<&Calendar>,
-    adjust_rule: Option<AdjustRule>,
-) -> Result<f64, DayCountError> {
-    let (start_adjusted, end_adjusted, some_adjust_rule, delta) = if calendar.is_none() {
-        (
-            *start_date,
-            *end_date,
-            adjust_rule,
-            (*end_date - *start_date).num_days().abs(),
-        )
-    } else {
-        let rule = if adjust_rule.is_none() {
-            Some(AdjustRule::Following)
-        } else {
-            adjust_rule
-        };
-        let s = adjust(start_date, calendar, rule);
-        let e = adjust(end_date, calendar, rule);
-        let d = (s - e).num_days().abs();
-        (s, e, rule, d)
-    };
-
-    let start_year:  i32 = start_adjusted.year();
-    let start_month: i32 = start_adjusted.month() as i32;
-    let mut start_day: i32 = start_adjusted.day() as i32;
-    let end_year:    i32 = end_adjusted.year();
-    let end_month:   i32 = end_adjusted.month() as i32;
-    let mut end_day: i32 = end_adjusted.day() as i32;
-
-    match daycount {
-        DayCount::Act360 => Ok(delta as f64 / 360.0),
-
-        DayCount::Act365 => Ok(delta as f64 / 365.0),
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessDayIterator<'a> {
+    calendar: &'a Calendar,
+    front: NaiveDate,
+    back: NaiveDate,
+    finished: bool,
+}
 
-        DayCount::Act365Fixed => Ok(delta as f64 / 365.0),
+impl<'a> Iterator for BusinessDayIterator<'a> {
+    type Item = NaiveDate;
 
-        DayCount::ActActISDA => {
-            if start_adjusted == end_adjusted {
-                return Ok(0.0);
-            }
-            if start_year == end_year && is_leap_year(start_year) {
-                return Ok(delta as f64 / 366.0);
-            }
-            if start_year == end_year {
-                return Ok(delta as f64 / 365.0);
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let date = self.front;
+            if date == self.back {
+                self.finished = true;
+            } else {
+                // `date < back`, so there is a next day.
+                self.front = date.succ_opt()?;
             }
-            if start_adjusted > end_adjusted {
-                return day_count_fraction(
-                    &end_adjusted,
-                    &start_adjusted,
-                    DayCount::ActActISDA,
-                    calendar,
-                    some_adjust_rule,
-                );
+            if is_business_day(&date, self.calendar) {
+                return Some(date);
             }
-            let dcf = end_year as f64 - start_year as f64 - 1.0;
-            let base1 = if is_leap_year(start_year) { 366 } else { 365 };
-            let base2 = if is_leap_year(end_year)   { 366 } else { 365 };
-            let dcf1 = (NaiveDate::from_ymd_opt(start_year + 1, 1, 1).unwrap()
-                - start_adjusted).num_days() as f64
-                / base1 as f64;
-            let dcf2 = (end_adjusted
-                - NaiveDate::from_ymd_opt(end_year, 1, 1).unwrap()).num_days() as f64
-                / base2 as f64;
-            Ok(dcf + dcf1 + dcf2)
         }
+        None
+    }
 
-        DayCount::D30360Euro => {
-            if start_day == 31 { start_day = 30; }
-            if end_day   == 31 { end_day   = 30; }
-            let res = 360 * (end_year - start_year)
-                + 30 * (end_month - start_month)
-                + (end_day - start_day);
-            Ok(res as f64 / 360.0)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
         }
+        (0, Some((self.back - self.front).num_days() as usize + 1))
+    }
+}
 
-        DayCount::Thirty360US => {
-            let start_is_eom_feb = is_last_day_of_february(start_adjusted);
-            let end_is_eom_feb   = is_last_day_of_february(end_adjusted);
+impl<'a> DoubleEndedIterator for BusinessDayIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let date = self.back;
+            if date == self.front {
+                self.finished = true;
+            } else {
+                // `date > front`, so there is a previous day.
+                self.back = date.pred_opt()?;
+            }
+            if is_business_day(&date, self.calendar) {
+                return Some(date);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> std::iter::FusedIterator for BusinessDayIterator<'a> {}
+
+/// Which endpoints of a date range are counted by
+/// [`business_days_between_with_boundaries`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::Boundaries;
+///
+/// assert_eq!(Boundaries::default(), Boundaries::IncludeStart);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Default)]
+pub enum Boundaries {
+    /// `[start, end)` — the usual financial convention, used by
+    /// [`business_days_between`].
+    #[default]
+    IncludeStart,
+    /// `(start, end]`.
+    IncludeEnd,
+    /// `[start, end]`.
+    IncludeBoth,
+    /// `(start, end)`.
+    ExcludeBoth,
+}
+
+/// Counts the number of business days from `start_date` up to but not
+/// including `end_date`.
+///
+/// This follows the common financial convention of including the start date
+/// and excluding the end date.  Both endpoints are first adjusted with
+/// `adjust_rule` (defaults to [`Following`](AdjustRule::Following) when
+/// `None`; [`Unadjusted`](AdjustRule::Unadjusted) leaves them as they are).
+/// Returns zero when the adjusted end is not after the adjusted start.  For
+/// other boundary conventions see [`business_days_between_with_boundaries`].
+///
+/// The count is computed directly from weekday arithmetic and the holidays
+/// in range, without building the list of days, so it costs
+/// O(number of holidays) regardless of how far apart the dates are.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::business_days_between;
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+/// let end   = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
+/// // Mon, Tue, Wed, Thu = 4 business days (end excluded)
+/// assert_eq!(business_days_between(&start, &end, &cal, None), 4);
+/// ```
+pub fn business_days_between(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    calendar: &Calendar,
+    adjust_rule: Option<AdjustRule>,
+) -> u64 {
+    business_days_between_with_boundaries(
+        start_date,
+        end_date,
+        calendar,
+        adjust_rule,
+        Boundaries::IncludeStart,
+    )
+}
+
+/// Counts the business days between `start_date` and `end_date`, with
+/// `boundaries` choosing whether each endpoint is counted.
+///
+/// Endpoints are adjusted as in [`business_days_between`], which this
+/// function equals for [`Boundaries::IncludeStart`].  Returns zero when the
+/// adjusted end is before the adjusted start.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::{business_days_between_with_boundaries, Boundaries};
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+/// let end   = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
+/// let count = |b| business_days_between_with_boundaries(&start, &end, &cal, None, b);
+///
+/// assert_eq!(count(Boundaries::IncludeStart), 4);
+/// assert_eq!(count(Boundaries::IncludeEnd), 4);
+/// assert_eq!(count(Boundaries::IncludeBoth), 5);
+/// assert_eq!(count(Boundaries::ExcludeBoth), 3);
+/// ```
+pub fn business_days_between_with_boundaries(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    calendar: &Calendar,
+    adjust_rule: Option<AdjustRule>,
+    boundaries: Boundaries,
+) -> u64 {
+    let rule = adjust_rule.or(Some(AdjustRule::Following));
+    let start = adjust(start_date, Some(calendar), rule);
+    let end   = adjust(end_date,   Some(calendar), rule);
+    if end < start {
+        return 0;
+    }
+
+    let half_open = count_business_days(start, end, calendar) as i64;
+    let at_start = is_business_day(&start, calendar) as i64;
+    let at_end = is_business_day(&end, calendar) as i64;
+    let count = match boundaries {
+        Boundaries::IncludeStart => half_open,
+        Boundaries::IncludeEnd   => half_open - at_start + at_end,
+        Boundaries::IncludeBoth  => half_open + at_end,
+        Boundaries::ExcludeBoth  => half_open - at_start,
+    };
+    count.max(0) as u64
+}
+
+/// Signed business day difference from `start_date` to `end_date`.
+///
+/// Equals [`business_days_between`] (with the default
+/// [`Following`](AdjustRule::Following) endpoint adjustment) when
+/// `end_date` is on or after `start_date`, and its negation with the dates
+/// swapped otherwise, so `business_days_signed(a, b) == -business_days_signed(b, a)`
+/// and callers need not sort their dates first.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::business_days_signed;
+///
+/// let cal    = basic_calendar();
+/// let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+/// let friday = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap();
+///
+/// assert_eq!(business_days_signed(&monday, &friday, &cal), 4);
+/// assert_eq!(business_days_signed(&friday, &monday, &cal), -4);
+/// ```
+pub fn business_days_signed(start_date: &NaiveDate, end_date: &NaiveDate, calendar: &Calendar) -> i64 {
+    if end_date >= start_date {
+        business_days_between(start_date, end_date, calendar, None) as i64
+    } else {
+        -(business_days_between(end_date, start_date, calendar, None) as i64)
+    }
+}
+
+// Business days in `[start, end)`: weekdays from whole weeks plus the
+// remainder, minus holidays that fall on a working weekday.
+fn count_business_days(start: NaiveDate, end: NaiveDate, calendar: &Calendar) -> u64 {
+    if end <= start {
+        return 0;
+    }
+    let weekend = calendar.get_weekend();
+    let days = (end - start).num_days() as u64;
+    let (weeks, rest) = (days / 7, days % 7);
+
+    let mut count = weeks * (7 - weekend.len() as u64);
+    let mut day = start.weekday();
+    for _ in 0..rest {
+        if !weekend.contains(&day) {
+            count += 1;
+        }
+        day = day.succ();
+    }
+    let holidays = calendar
+        .holidays_in_range(start, end)
+        .filter(|h| !weekend.contains(&h.weekday()))
+        .count() as u64;
+    count - holidays
+}
+
+/// Computes the day count fraction between two dates using the given convention.
+///
+/// If `calendar` is `None`, no date adjustment is performed.  If `calendar`
+/// is provided and `adjust_rule` is `None`, the adjustment rule defaults to
+/// [`Following`](AdjustRule::Following) before computing the fraction.
+/// To suppress adjustment while still providing a calendar (e.g. for
+/// [`Bd252`](DayCount::Bd252)), pass `Some(AdjustRule::Unadjusted)`.
+///
+/// If `end_date` is before `start_date` the fraction is computed on the
+/// absolute time difference.
+///
+/// The adjusted dates are passed to the [`DayCounter`] implementation of
+/// `daycount`, or of [`BusinessDays252`] for [`Bd252`](DayCount::Bd252).
+///
+/// # Errors
+///
+/// Returns [`Err(DayCountError::MissingCalendar)`](DayCountError::MissingCalendar)
+/// if `daycount` is [`Bd252`](DayCount::Bd252) and `calendar` is `None`,
+/// [`Err(DayCountError::MissingReferencePeriod)`](DayCountError::MissingReferencePeriod)
+/// if `daycount` is [`ActActICMA`](DayCount::ActActICMA), and
+/// [`Err(DayCountError::OutOfRange)`](DayCountError::OutOfRange) if adjusting
+/// either date runs off the representable date range.  This function never
+/// panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::try_day_count_fraction;
+/// use findates::conventions::DayCount;
+///
+/// // 2023 is not a leap year: exactly 365 days between these dates.
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///
+/// // Act/365 over a full non-leap year = exactly 1.0
+/// let dcf = try_day_count_fraction(&start, &end, DayCount::Act365, None, None).unwrap();
+/// assert!((dcf - 1.0).abs() < 1e-9);
+///
+/// // Act/360 over 365 days
+/// let dcf360 = try_day_count_fraction(&start, &end, DayCount::Act360, None, None).unwrap();
+/// assert!((dcf360 - 365.0 / 360.0).abs() < 1e-9);
+/// ```
+pub fn try_day_count_fraction(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    dcf_impl(start_date, end_date, daycount, &DayCountContext::default(), calendar, adjust_rule)
+}
+
+/// Computes the day count fraction between two dates using the given
+/// convention.
+///
+/// Same as [`try_day_count_fraction`], which this forwards to.
+///
+/// # Errors
+///
+/// Same as [`try_day_count_fraction`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// use chrono::NaiveDate;
+/// use findates::algebra::day_count_fraction;
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+/// assert_eq!(day_count_fraction(&start, &end, DayCount::Act360, None, None), Ok(181.0 / 360.0));
+/// ```
+#[deprecated(note = "renamed to `try_day_count_fraction`")]
+pub fn day_count_fraction(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    try_day_count_fraction(start_date, end_date, daycount, calendar, adjust_rule)
+}
+
+/// Computes the day count fraction between two dates, rounded by
+/// `rounding`.
+///
+/// Same as [`try_day_count_fraction`] followed by [`Rounding::round`], for
+/// markets that quote year fractions to a fixed precision.
+///
+/// # Errors
+///
+/// Same as [`try_day_count_fraction`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::day_count_fraction_rounded;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{DayCount, Rounding, RoundingDirection};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 2, 14).unwrap();
+/// let cal   = basic_calendar();
+///
+/// // 31 business days: 31 / 252 = 0.123015873...
+/// let truncated = Rounding::new(8, RoundingDirection::Down);
+/// let dcf = day_count_fraction_rounded(&start, &end, DayCount::Bd252, Some(&cal), None, truncated).unwrap();
+/// assert_eq!(dcf, 0.12301587);
+/// ```
+pub fn day_count_fraction_rounded(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+    rounding: Rounding,
+) -> Result<f64, DayCountError> {
+    let context = DayCountContext {
+        rounding: Some(rounding),
+        ..DayCountContext::default()
+    };
+    dcf_impl(start_date, end_date, daycount, &context, calendar, adjust_rule)
+}
+
+/// Counts the days between two dates as `daycount` counts them.
+///
+/// Returns the numerator of [`try_day_count_fraction`]: 30/360-style days for
+/// the 30/360 family, business days for [`Bd252`](DayCount::Bd252), days
+/// excluding 29 February for [`NL365`](DayCount::NL365), and actual days
+/// for every other convention.  Dates are adjusted exactly as
+/// [`try_day_count_fraction`] adjusts them when given no rule: not at all
+/// without a calendar, [`Following`](AdjustRule::Following) with one.
+///
+/// # Errors
+///
+/// Returns [`Err(DayCountError::MissingCalendar)`](DayCountError::MissingCalendar)
+/// if `daycount` is [`Bd252`](DayCount::Bd252) and `calendar` is `None`, and
+/// [`Err(DayCountError::OutOfRange)`](DayCountError::OutOfRange) if adjusting
+/// either date runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::day_count;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+///
+/// assert_eq!(day_count(&start, &end, DayCount::Act360, None), Ok(182));
+/// assert_eq!(day_count(&start, &end, DayCount::D30360Euro, None), Ok(180));
+/// assert_eq!(day_count(&start, &end, DayCount::Bd252, Some(&basic_calendar())), Ok(130));
+/// ```
+pub fn day_count(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+) -> Result<i64, DayCountError> {
+    let (start_adjusted, end_adjusted) = adjust_period(start_date, end_date, calendar, None)?;
+    match (daycount, calendar) {
+        (DayCount::Bd252, Some(calendar)) => {
+            BusinessDays252 { calendar }.day_count(&start_adjusted, &end_adjusted)
+        }
+        _ => daycount.day_count(&start_adjusted, &end_adjusted),
+    }
+}
+
+/// Computes the interest accrued on `notional` at the annual `rate` from
+/// `start_date` to `end_date`.
+///
+/// Equals `notional * rate * try_day_count_fraction(...)`, with the dates
+/// adjusted as documented for [`try_day_count_fraction`].  `rate` is a decimal,
+/// e.g. `0.05` for 5%.
+///
+/// # Errors
+///
+/// Same as [`try_day_count_fraction`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::accrued;
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+///
+/// // Three 30/360 months of a 4% coupon on 1,000,000
+/// let interest = accrued(&start, &end, DayCount::D30360Euro, 1_000_000.0, 0.04, None, None).unwrap();
+/// assert!((interest - 10_000.0).abs() < 1e-9);
+/// ```
+pub fn accrued(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    notional: f64,
+    rate: f64,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    let dcf = try_day_count_fraction(start_date, end_date, daycount, calendar, adjust_rule)?;
+    Ok(notional * rate * dcf)
+}
+
+/// Computes the day count fraction of each consecutive pair of `dates`.
+///
+/// Returns one fraction per period, i.e. `dates.len() - 1` values (none for
+/// fewer than two dates); element `i` equals
+/// `try_day_count_fraction(&dates[i], &dates[i + 1], daycount, calendar, adjust_rule)`.
+/// Each date is adjusted only once rather than once per period it bounds.
+///
+/// # Errors
+///
+/// Returns the first [`DayCountError`] any period produces, e.g.
+/// [`MissingCalendar`](DayCountError::MissingCalendar) for
+/// [`DayCount::Bd252`] without a calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::day_count_fractions;
+/// use findates::conventions::DayCount;
+///
+/// let dates = [
+///     NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+///     NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+/// ];
+/// let dcfs = day_count_fractions(&dates, DayCount::D30360Euro, None, None).unwrap();
+/// assert_eq!(dcfs, vec![0.5, 0.5]);
+/// ```
+pub fn day_count_fractions(
+    dates: &[NaiveDate],
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Vec<f64>, DayCountError> {
+    // Mirror `dcf_impl`: with a calendar, a missing rule means Following.
+    let (adjusted, rule) = match calendar {
+        None => (dates.to_vec(), adjust_rule),
+        Some(_) => {
+            let rule = adjust_rule.or(Some(AdjustRule::Following));
+            let adjusted = dates
+                .iter()
+                .map(|date| checked_adjust(date, calendar, rule).ok_or(DayCountError::OutOfRange))
+                .collect::<Result<Vec<_>, _>>()?;
+            (adjusted, Some(AdjustRule::Unadjusted))
+        }
+    };
+    adjusted
+        .windows(2)
+        .map(|pair| {
+            dcf_impl(&pair[0], &pair[1], daycount, &DayCountContext::default(), calendar, rule)
+        })
+        .collect()
+}
+
+/// Computes the day count and fraction from `start_date` to `end_date` under
+/// each of `daycounts`, for comparing conventions side by side.
+///
+/// Each row holds [`day_count`] and [`try_day_count_fraction`] for one
+/// convention, in the order given.  Dates are adjusted as those functions
+/// adjust them when given no rule: not at all without a calendar,
+/// [`Following`](AdjustRule::Following) with one.  The matrix prints as a
+/// table; see [`DcfMatrix`].
+///
+/// # Errors
+///
+/// Returns the first [`DayCountError`] any convention produces:
+/// [`MissingCalendar`](DayCountError::MissingCalendar) for
+/// [`DayCount::Bd252`] without a calendar, and
+/// [`MissingReferencePeriod`](DayCountError::MissingReferencePeriod) for
+/// [`DayCount::ActActICMA`], which needs a coupon period; use
+/// [`icma_year_fraction`] for it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::dcf_matrix;
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+///
+/// let matrix = dcf_matrix(&start, &end, &[DayCount::Act360, DayCount::D30360Euro], None).unwrap();
+/// assert_eq!(matrix.fraction(DayCount::Act360), Some(182.0 / 360.0));
+/// assert_eq!(matrix.fraction(DayCount::D30360Euro), Some(0.5));
+/// assert_eq!(matrix.fraction(DayCount::Act365Fixed), None);
+/// ```
+pub fn dcf_matrix(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycounts: &[DayCount],
+    calendar: Option<&Calendar>,
+) -> Result<DcfMatrix, DayCountError> {
+    let rows = daycounts
+        .iter()
+        .map(|&daycount| {
+            Ok(DcfRow {
+                daycount,
+                days: day_count(start_date, end_date, daycount, calendar)?,
+                fraction: try_day_count_fraction(start_date, end_date, daycount, calendar, None)?,
+            })
+        })
+        .collect::<Result<Vec<_>, DayCountError>>()?;
+    Ok(DcfMatrix { start: *start_date, end: *end_date, rows })
+}
+
+/// Day counts and fractions of one period under several conventions,
+/// returned by [`dcf_matrix`].
+///
+/// Displays as a table with one line per convention and fractions to ten
+/// decimal places.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::dcf_matrix;
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+///
+/// let matrix = dcf_matrix(&start, &end, &[DayCount::Act360, DayCount::D30360Euro], None).unwrap();
+/// assert_eq!(
+///     matrix.to_string(),
+///     "2024-01-31 to 2024-07-31\n\
+///      Convention          Days        Fraction\n\
+///      Act360               182    0.5055555556\n\
+///      D30360Euro           180    0.5000000000",
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DcfMatrix {
+    /// Start of the period, as given.
+    pub start: NaiveDate,
+    /// End of the period, as given.
+    pub end: NaiveDate,
+    /// One row per convention, in the order requested.
+    pub rows: Vec<DcfRow>,
+}
+
+/// Day count and fraction under one convention; see [`DcfMatrix`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DcfRow {
+    /// The convention.
+    pub daycount: DayCount,
+    /// Numerator of the fraction, as returned by [`day_count`].
+    pub days: i64,
+    /// The year fraction, as returned by [`try_day_count_fraction`].
+    pub fraction: f64,
+}
+
+impl DcfMatrix {
+    /// Returns the fraction under `daycount`, or `None` if the matrix has
+    /// no row for it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::algebra::dcf_matrix;
+    /// use findates::conventions::DayCount;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    /// let matrix = dcf_matrix(&start, &end, &[DayCount::ActActISDA], None).unwrap();
+    /// assert_eq!(matrix.fraction(DayCount::ActActISDA), Some(1.0));
+    /// ```
+    pub fn fraction(&self, daycount: DayCount) -> Option<f64> {
+        self.rows.iter().find(|row| row.daycount == daycount).map(|row| row.fraction)
+    }
+
+    /// Largest difference between the fractions of any two conventions, or
+    /// `0.0` for fewer than two rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::algebra::dcf_matrix;
+    /// use findates::conventions::DayCount;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// let matrix = dcf_matrix(&start, &end, &[DayCount::Act360, DayCount::Act365Fixed], None).unwrap();
+    /// assert!((matrix.spread() - (30.0 / 360.0 - 30.0 / 365.0)).abs() < 1e-15);
+    /// ```
+    pub fn spread(&self) -> f64 {
+        let fractions = self.rows.iter().map(|row| row.fraction);
+        let max = fractions.clone().fold(f64::NEG_INFINITY, f64::max);
+        let min = fractions.fold(f64::INFINITY, f64::min);
+        if self.rows.len() < 2 { 0.0 } else { max - min }
+    }
+}
+
+impl fmt::Display for DcfMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} to {}", self.start, self.end)?;
+        write!(f, "\n{:<16}{:>8}{:>16}", "Convention", "Days", "Fraction")?;
+        for row in &self.rows {
+            write!(f, "\n{:<16}{:>8}{:>16.10}", row.daycount.to_string(), row.days, row.fraction)?;
+        }
+        Ok(())
+    }
+}
+
+/// The regular coupon period an accrual period belongs to.
+///
+/// Required by [`DayCount::ActActICMA`], whose fraction depends on the
+/// length of the coupon period and on how many coupons are paid per year.
+/// `start` and `end` are the nominal (unadjusted) coupon dates.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::ReferencePeriod;
+/// use findates::conventions::Frequency;
+///
+/// let period = ReferencePeriod {
+///     start: NaiveDate::from_ymd_opt(2023, 8, 15).unwrap(),
+///     end: NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+///     frequency: Frequency::Semiannual,
+/// };
+/// assert_eq!(period.frequency, Frequency::Semiannual);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReferencePeriod {
+    /// Nominal start of the regular coupon period.
+    pub start: NaiveDate,
+    /// Nominal end of the regular coupon period.
+    pub end: NaiveDate,
+    /// Coupon frequency of the instrument.
+    pub frequency: Frequency,
+}
+
+/// Computes the day count fraction between two dates given the regular
+/// coupon period they belong to.
+///
+/// Behaves exactly like [`try_day_count_fraction`] for every convention except
+/// [`ActActICMA`](DayCount::ActActICMA), which needs the reference period to
+/// compute its denominator.  Accrual periods that extend beyond the reference
+/// period (short or long stubs) are split into notional periods stepped by
+/// `period.frequency`, following the ICMA Rule 251 treatment.
+///
+/// # Errors
+///
+/// Same as [`try_day_count_fraction`], plus
+/// [`Err(DayCountError::InvalidFrequency)`](DayCountError::InvalidFrequency) if
+/// `daycount` is [`ActActICMA`](DayCount::ActActICMA) and `period.frequency`
+/// is [`Frequency::Zero`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{day_count_fraction_with_period, ReferencePeriod};
+/// use findates::conventions::{DayCount, Frequency};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+/// let period = ReferencePeriod { start, end, frequency: Frequency::Semiannual };
+///
+/// // A full regular semiannual coupon is always exactly 0.5
+/// let dcf = day_count_fraction_with_period(
+///     &start, &end, DayCount::ActActICMA, &period, None, None,
+/// ).unwrap();
+/// assert!((dcf - 0.5).abs() < 1e-12);
+/// ```
+pub fn day_count_fraction_with_period(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    period: &ReferencePeriod,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    let context = DayCountContext {
+        reference_period: Some(*period),
+        ..DayCountContext::default()
+    };
+    dcf_impl(start_date, end_date, daycount, &context, calendar, adjust_rule)
+}
+
+/// Computes the Actual/Actual ICMA year fraction of an accrual period from
+/// `start` to `end`, given the regular coupon period from `period_start` to
+/// `period_end`.
+///
+/// Inside the regular period the fraction is the days accrued divided by
+/// `frequency` times the days in the period.  An accrual period that runs
+/// outside it is split into notional periods, as in ICMA Rule 251:
+///
+/// - a short or long first coupon ends on or before `period_end` and is
+///   measured against notional periods stepped backwards from `period_start`;
+/// - a short or long last coupon starts on or after `period_start` and is
+///   measured against notional periods stepped forwards from `period_end`,
+///   each whole one counting as `1 / frequency`.
+///
+/// When both ends of the regular period are month ends, notional periods
+/// also end on month ends.  Dates are used as given: pass the unadjusted
+/// coupon dates.  This is shorthand for [`day_count_fraction_with_period`]
+/// with [`DayCount::ActActICMA`].
+///
+/// # Errors
+///
+/// - [`Err(DayCountError::InvalidFrequency)`](DayCountError::InvalidFrequency)
+///   if `frequency` has no regular coupon length, e.g. [`Frequency::Zero`].
+/// - [`Err(DayCountError::InvalidReferencePeriod)`](DayCountError::InvalidReferencePeriod)
+///   if `period_end` is not after `period_start`.
+/// - [`Err(DayCountError::OutOfRange)`](DayCountError::OutOfRange) if a
+///   notional period falls outside the supported date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::icma_year_fraction;
+/// use findates::conventions::Frequency;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+///
+/// // Long first coupon from 15 Aug 2002, first regular period 15 Jan - 15 Jul 2003:
+/// // 153 / (2 * 184) + 181 / (2 * 181)
+/// let dcf = icma_year_fraction(&d(2002, 8, 15), &d(2003, 7, 15), &d(2003, 1, 15), &d(2003, 7, 15), Frequency::Semiannual).unwrap();
+/// assert!((dcf - 0.915760869565).abs() < 1e-12);
+/// ```
+pub fn icma_year_fraction(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    period_start: &NaiveDate,
+    period_end: &NaiveDate,
+    frequency: Frequency,
+) -> Result<f64, DayCountError> {
+    let period = ReferencePeriod { start: *period_start, end: *period_end, frequency };
+    day_count_fraction_with_period(start, end, DayCount::ActActICMA, &period, None, None)
+}
+
+/// Extra inputs needed by conventions whose fraction depends on more than
+/// the two dates.
+///
+/// - `reference_period` — the regular coupon period, required by
+///   [`ActActICMA`](DayCount::ActActICMA).
+/// - `is_termination_date` — whether `end_date` is the maturity of the
+///   instrument.  [`ThirtyE360ISDA`](DayCount::ThirtyE360ISDA) does not move
+///   a last-of-February termination date to the 30th.
+/// - `rounding` — the precision of the result.  Applied by
+///   [`day_count_fraction_with_context`] to whatever the convention
+///   returns; [`DayCounter::year_fraction`] implementations ignore it.
+///
+/// The default context has no reference period, treats the end date as an
+/// ordinary period end and does not round.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::DayCountContext;
+///
+/// let ctx = DayCountContext { is_termination_date: true, ..Default::default() };
+/// assert!(ctx.reference_period.is_none());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DayCountContext {
+    /// Regular coupon period the accrual period belongs to.
+    pub reference_period: Option<ReferencePeriod>,
+    /// `true` if the end date is the termination (maturity) date.
+    pub is_termination_date: bool,
+    /// Rounding applied to the year fraction.
+    pub rounding: Option<Rounding>,
+}
+
+/// A day count convention, turning a pair of dates into a year fraction.
+///
+/// Implemented by [`DayCount`] for the built-in conventions and by
+/// [`BusinessDays252`] for Bus/252 on a given calendar.  Implement it for
+/// your own types to add bespoke conventions.
+///
+/// Dates are used exactly as given: business day adjustment is up to the
+/// caller, as done by [`try_day_count_fraction`] before it delegates here.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{DayCountContext, DayCounter};
+/// use findates::DayCountError;
+///
+/// // Actual/364, used by some money market instruments.
+/// struct Act364;
+///
+/// impl DayCounter for Act364 {
+///     fn year_fraction(
+///         &self,
+///         start: &NaiveDate,
+///         end: &NaiveDate,
+///         _context: &DayCountContext,
+///     ) -> Result<f64, DayCountError> {
+///         Ok(self.day_count(start, end)? as f64 / 364.0)
+///     }
+///
+///     fn day_count(&self, start: &NaiveDate, end: &NaiveDate) -> Result<i64, DayCountError> {
+///         Ok((*end - *start).num_days())
+///     }
+/// }
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// assert_eq!(Act364.year_fraction(&start, &end, &DayCountContext::default()), Ok(1.0));
+/// ```
+pub trait DayCounter {
+    /// Computes the year fraction from `start` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DayCountError`] if the fraction cannot be computed, e.g.
+    /// when `context` lacks an input the convention needs.
+    fn year_fraction(
+        &self,
+        start: &NaiveDate,
+        end: &NaiveDate,
+        context: &DayCountContext,
+    ) -> Result<f64, DayCountError>;
+
+    /// Counts the days from `start` to `end` as the convention counts them:
+    /// the numerator of the year fraction for conventions with a fixed
+    /// denominator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DayCountError`] if the days cannot be counted.
+    fn day_count(&self, start: &NaiveDate, end: &NaiveDate) -> Result<i64, DayCountError>;
+}
+
+/// Business days divided by 252, counted on a specific calendar.
+///
+/// [`DayCount::Bd252`] needs a calendar to count business days;
+/// [`try_day_count_fraction`] pairs it with the calendar it is given through
+/// this type.  Business days are counted from `start` up to but not
+/// including `end`, without adjusting either date.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{BusinessDays252, DayCountContext, DayCounter};
+/// use findates::calendar::basic_calendar;
+///
+/// let cal    = basic_calendar();
+/// let bd252  = BusinessDays252 { calendar: &cal };
+/// let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+/// let next   = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+///
+/// assert_eq!(bd252.day_count(&monday, &next), Ok(5));
+/// assert_eq!(bd252.year_fraction(&monday, &next, &DayCountContext::default()), Ok(5.0 / 252.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct BusinessDays252<'a> {
+    /// Calendar whose business days are counted.
+    pub calendar: &'a Calendar,
+}
+
+impl DayCounter for BusinessDays252<'_> {
+    fn year_fraction(
+        &self,
+        start: &NaiveDate,
+        end: &NaiveDate,
+        _context: &DayCountContext,
+    ) -> Result<f64, DayCountError> {
+        Ok(self.day_count(start, end)? as f64 / 252.0)
+    }
+
+    fn day_count(&self, start: &NaiveDate, end: &NaiveDate) -> Result<i64, DayCountError> {
+        Ok(business_days_between(start, end, self.calendar, Some(AdjustRule::Unadjusted)) as i64)
+    }
+}
+
+/// Computes the day count fraction between two dates with the extra inputs
+/// some conventions need.
+///
+/// The most general form of [`try_day_count_fraction`]; adjustment behaves
+/// exactly as documented there.
+///
+/// # Errors
+///
+/// Same as [`day_count_fraction_with_period`], with
+/// [`Err(DayCountError::MissingReferencePeriod)`](DayCountError::MissingReferencePeriod)
+/// when `daycount` is [`ActActICMA`](DayCount::ActActICMA) and
+/// `context.reference_period` is `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{day_count_fraction_with_context, DayCountContext};
+/// use findates::conventions::DayCount;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 8, 31).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+///
+/// // An ordinary period end on the last day of February counts as the 30th...
+/// let regular = DayCountContext::default();
+/// let dcf = day_count_fraction_with_context(&start, &end, DayCount::ThirtyE360ISDA, &regular, None, None).unwrap();
+/// assert!((dcf - 180.0 / 360.0).abs() < 1e-12);
+///
+/// // ...but a termination date keeps its actual day.
+/// let maturity = DayCountContext { is_termination_date: true, ..Default::default() };
+/// let dcf = day_count_fraction_with_context(&start, &end, DayCount::ThirtyE360ISDA, &maturity, None, None).unwrap();
+/// assert!((dcf - 179.0 / 360.0).abs() < 1e-12);
+/// ```
+pub fn day_count_fraction_with_context(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    context: &DayCountContext,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    dcf_impl(start_date, end_date, daycount, context, calendar, adjust_rule)
+}
+
+/// Computes the year fraction of trading time between two instants, for
+/// volatility time in option pricing.
+///
+/// Only time when the market described by `calendar` and `hours` could move
+/// counts: each trading day is one unit and a year is 252 units, as in
+/// [`Bd252`](DayCount::Bd252).  `overnight_weight` is the share of a
+/// trading day's variance that builds up while the market is closed; it
+/// accrues evenly over the hours between the previous close and the open,
+/// and the remainder accrues evenly over the regular session.  `None`
+/// means `0.0`: only intraday time counts.  Early close days count a
+/// proportionally shorter session.
+///
+/// The fraction is negative when `end` is before `start`.
+///
+/// # Errors
+///
+/// - [`Err(DayCountError::InvalidOvernightWeight)`](DayCountError::InvalidOvernightWeight)
+///   if `overnight_weight` is outside `0.0..=1.0`.
+/// - [`Err(DayCountError::OutOfRange)`](DayCountError::OutOfRange) if the
+///   search for a trading day runs past the calendar's
+///   [valid range](Calendar::valid_range) or the representable date range,
+///   or if the calendar treats every weekday as weekend.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveTime, TimeZone};
+/// use findates::algebra::trading_time_fraction;
+/// use findates::calendar::basic_calendar;
+/// use findates::sessions::MarketHours;
+///
+/// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let cal = basic_calendar();
+/// let hours = MarketHours::new(
+///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+///     new_york,
+/// ).unwrap();
+///
+/// // Friday noon to Monday noon: half of Friday's session and half of Monday's.
+/// let friday = new_york.with_ymd_and_hms(2024, 3, 15, 12, 45, 0).unwrap();
+/// let monday = new_york.with_ymd_and_hms(2024, 3, 18, 12, 45, 0).unwrap();
+/// let t = trading_time_fraction(&friday, &monday, &cal, &hours, None).unwrap();
+/// assert!((t - 1.0 / 252.0).abs() < 1e-12);
+///
+/// // Over the weekend alone, only overnight variance accrues.
+/// let close = new_york.with_ymd_and_hms(2024, 3, 15, 16, 0, 0).unwrap();
+/// let open  = new_york.with_ymd_and_hms(2024, 3, 18, 9, 30, 0).unwrap();
+/// assert_eq!(trading_time_fraction(&close, &open, &cal, &hours, None), Ok(0.0));
+/// let t = trading_time_fraction(&close, &open, &cal, &hours, Some(0.2)).unwrap();
+/// assert!((t - 0.2 / 252.0).abs() < 1e-12);
+/// ```
+pub fn trading_time_fraction<Tz1: TimeZone, Tz2: TimeZone, Tz: TimeZone>(
+    start: &DateTime<Tz1>,
+    end: &DateTime<Tz2>,
+    calendar: &Calendar,
+    hours: &MarketHours<Tz>,
+    overnight_weight: Option<f64>,
+) -> Result<f64, DayCountError> {
+    let weight = overnight_weight.unwrap_or(0.0);
+    if !(0.0..=1.0).contains(&weight) {
+        return Err(DayCountError::InvalidOvernightWeight);
+    }
+    let session = TradingSession::new(calendar, hours.clone());
+    let (start, end) = (session.to_local(start), session.to_local(end));
+    if end < start {
+        let days = session.trading_days(end, start, weight).ok_or(DayCountError::OutOfRange)?;
+        return Ok(-days / 252.0);
+    }
+    let days = session.trading_days(start, end, weight).ok_or(DayCountError::OutOfRange)?;
+    Ok(days / 252.0)
+}
+
+fn dcf_impl(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    context: &DayCountContext,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<f64, DayCountError> {
+    let (start_adjusted, end_adjusted) = adjust_period(start_date, end_date, calendar, adjust_rule)?;
+    let dcf = match (daycount, calendar) {
+        (DayCount::Bd252, Some(calendar)) => {
+            BusinessDays252 { calendar }.year_fraction(&start_adjusted, &end_adjusted, context)
+        }
+        _ => daycount.year_fraction(&start_adjusted, &end_adjusted, context),
+    }?;
+    Ok(context.rounding.map_or(dcf, |rounding| rounding.round(dcf)))
+}
+
+// Adjusts both ends of a period the way the day count functions do: not at
+// all without a calendar, and Following when a calendar has no rule.
+pub(crate) fn adjust_period(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<(NaiveDate, NaiveDate), DayCountError> {
+    if calendar.is_none() {
+        return Ok((*start_date, *end_date));
+    }
+    let rule = adjust_rule.or(Some(AdjustRule::Following));
+    Ok((
+        checked_adjust(start_date, calendar, rule).ok_or(DayCountError::OutOfRange)?,
+        checked_adjust(end_date, calendar, rule).ok_or(DayCountError::OutOfRange)?,
+    ))
+}
+
+impl DayCounter for DayCount {
+    /// Computes the fraction for the built-in convention.
+    ///
+    /// Reversed dates give the fraction of the absolute difference, except
+    /// for the 30/360 family, whose formula is signed.
+    ///
+    /// # Errors
+    ///
+    /// [`Bd252`](DayCount::Bd252) always returns
+    /// [`DayCountError::MissingCalendar`]; use [`BusinessDays252`] instead.
+    /// [`ActActICMA`](DayCount::ActActICMA) returns
+    /// [`DayCountError::MissingReferencePeriod`] without
+    /// `context.reference_period`.
+    fn year_fraction(
+        &self,
+        start_date: &NaiveDate,
+        end_date: &NaiveDate,
+        context: &DayCountContext,
+    ) -> Result<f64, DayCountError> {
+        let (first, last) = if start_date <= end_date {
+            (*start_date, *end_date)
+        } else {
+            (*end_date, *start_date)
+        };
+        let actual = (last - first).num_days() as f64;
+
+        match self {
+            DayCount::Act360 => Ok(actual / 360.0),
+
+            DayCount::Act365 | DayCount::Act365Fixed => Ok(actual / 365.0),
+
+            DayCount::Act365_25 => Ok(actual / 365.25),
+
+            DayCount::NL365 => Ok(self.day_count(start_date, end_date)? as f64 / 365.0),
+
+            DayCount::ActActISDA => isda_fraction(&first, &last),
+
+            DayCount::D30360Euro
+            | DayCount::Thirty360ISDA
+            | DayCount::ThirtyE360ISDA
+            | DayCount::Thirty360US => {
+                Ok(thirty_days(*self, start_date, end_date, context) as f64 / 360.0)
+            }
+
+            DayCount::D30365 => Ok(thirty_days(*self, start_date, end_date, context) as f64 / 365.0),
+
+            DayCount::Bd252 => Err(DayCountError::MissingCalendar),
+
+            DayCount::ActActAFB => afb_fraction(&first, &last),
+
+            DayCount::ActActICMA => {
+                let period = context
+                    .reference_period
+                    .as_ref()
+                    .ok_or(DayCountError::MissingReferencePeriod)?;
+                icma_fraction(&first, &last, period)
+            }
+        }
+    }
+
+    /// Counts the days for the built-in convention: 30/360-style days for
+    /// the 30/360 family, days excluding 29 February for
+    /// [`NL365`](DayCount::NL365), and actual days otherwise.
+    ///
+    /// # Errors
+    ///
+    /// [`Bd252`](DayCount::Bd252) always returns
+    /// [`DayCountError::MissingCalendar`]; use [`BusinessDays252`] instead.
+    fn day_count(&self, start_date: &NaiveDate, end_date: &NaiveDate) -> Result<i64, DayCountError> {
+        match self {
+            DayCount::NL365 => Ok((no_leap_serial(*end_date) - no_leap_serial(*start_date)).abs()),
+
+            DayCount::D30360Euro
+            | DayCount::Thirty360ISDA
+            | DayCount::ThirtyE360ISDA
+            | DayCount::Thirty360US
+            | DayCount::D30365 => {
+                Ok(thirty_days(*self, start_date, end_date, &DayCountContext::default()))
+            }
+
+            DayCount::Bd252 => Err(DayCountError::MissingCalendar),
+
+            _ => Ok((*end_date - *start_date).num_days().abs()),
+        }
+    }
+}
+
+// 30/360-style day count: each convention first moves the day-of-month of
+// one or both dates to 30, then counts 30-day months and 360-day years.
+// D30365 uses the unmodified dates.
+pub(crate) fn thirty_days(
+    daycount: DayCount,
+    start: &NaiveDate,
+    end: &NaiveDate,
+    context: &DayCountContext,
+) -> i64 {
+    let mut start_day = start.day() as i64;
+    let mut end_day   = end.day() as i64;
+
+    match daycount {
+        DayCount::D30360Euro => {
+            if start_day == 31 { start_day = 30; }
+            if end_day   == 31 { end_day   = 30; }
+        }
+        DayCount::Thirty360ISDA => {
+            if start_day == 31 { start_day = 30; }
+            if end_day == 31 && start_day == 30 { end_day = 30; }
+        }
+        DayCount::ThirtyE360ISDA => {
+            if is_end_of_month(start) { start_day = 30; }
+            let keep_february_end = context.is_termination_date && end.month() == 2;
+            if is_end_of_month(end) && !keep_february_end { end_day = 30; }
+        }
+        DayCount::Thirty360US => {
+            let start_is_eom_feb = is_last_day_of_february(*start);
+            let end_is_eom_feb   = is_last_day_of_february(*end);
             if start_day == 31 || start_is_eom_feb { start_day = 30; }
             if end_day == 31 && start_day >= 30    { end_day = 30; }
             if end_is_eom_feb && start_is_eom_feb  { end_day = 30; }
-            let res = 360 * (end_year - start_year)
-                + 30  * (end_month - start_month)
-                + (end_day - start_day);
-            Ok(res as f64 / 360.0)
         }
+        _ => {}
+    }
+
+    360 * (end.year() - start.year()) as i64
+        + 30 * (end.month() as i64 - start.month() as i64)
+        + (end_day - start_day)
+}
+
+// Number type the Actual/Actual fractions are computed in: `f64`, or
+// `Decimal` with the `decimal` feature.
+pub(crate) trait Fraction: Copy + std::ops::Add<Output = Self> {
+    fn whole(n: i64) -> Self;
+    // `days / basis`.
+    fn ratio(days: i64, basis: i64) -> Self;
+    // `days / (coupons per year * period_days)`.  `frequency` has already
+    // been checked to have a regular coupon period.
+    fn coupon_ratio(days: i64, period_days: i64, frequency: Frequency) -> Self;
+    // `1 / coupons per year`, with the same check on `frequency`.
+    fn coupon(frequency: Frequency) -> Self;
+}
+
+impl Fraction for f64 {
+    fn whole(n: i64) -> Self {
+        n as f64
+    }
+
+    fn ratio(days: i64, basis: i64) -> Self {
+        days as f64 / basis as f64
+    }
+
+    fn coupon_ratio(days: i64, period_days: i64, frequency: Frequency) -> Self {
+        let per_year = frequency.periods_per_year().unwrap_or(f64::NAN);
+        days as f64 / (per_year * period_days as f64)
+    }
+
+    fn coupon(frequency: Frequency) -> Self {
+        1.0 / frequency.periods_per_year().unwrap_or(f64::NAN)
+    }
+}
+
+// Actual/Actual ISDA for `start <= end`: days in each calendar year are
+// divided by that year's length.
+pub(crate) fn isda_fraction<T: Fraction>(start: &NaiveDate, end: &NaiveDate) -> Result<T, DayCountError> {
+    let (start_year, end_year) = (start.year(), end.year());
+    let basis = |year: i32| if is_leap_year(year) { 366 } else { 365 };
+    if start_year == end_year {
+        return Ok(T::ratio((*end - *start).num_days(), basis(start_year)));
+    }
+    let next_year_start = NaiveDate::from_ymd_opt(start_year + 1, 1, 1)
+        .ok_or(DayCountError::OutOfRange)?;
+    let end_year_start = NaiveDate::from_ymd_opt(end_year, 1, 1)
+        .ok_or(DayCountError::OutOfRange)?;
+    let dcf1 = T::ratio((next_year_start - *start).num_days(), basis(start_year));
+    let dcf2 = T::ratio((*end - end_year_start).num_days(), basis(end_year));
+    Ok(T::whole((end_year - start_year - 1) as i64) + dcf1 + dcf2)
+}
+
+// Actual/Actual ICMA for `start <= end`.  Within the reference period the
+// fraction is days / (frequency * days in period); stubs are split into
+// notional periods stepped backwards (long first coupon) or forwards (long
+// last coupon) from the reference period.  A monthly reference period that
+// starts and ends on month ends steps on month ends too, so 29 Feb is
+// followed by 31 May rather than 29 May.
+pub(crate) fn icma_fraction<T: Fraction>(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    period: &ReferencePeriod,
+) -> Result<T, DayCountError> {
+    if start == end {
+        return Ok(T::whole(0));
+    }
+    period.frequency.periods_per_year().ok_or(DayCountError::InvalidFrequency)?;
+    let ref_days = (period.end - period.start).num_days();
+    if ref_days <= 0 {
+        return Err(DayCountError::InvalidReferencePeriod);
+    }
+    let eom = period.frequency.to_months().is_some()
+        && is_end_of_month(&period.start)
+        && is_end_of_month(&period.end);
+
+    if *end <= period.end {
+        if *start >= period.start {
+            return Ok(T::coupon_ratio((*end - *start).num_days(), ref_days, period.frequency));
+        }
+        // Long (or short) first coupon: step notional periods backwards from
+        // the reference period until one holds `start`, then add up their
+        // shares from the earliest on.
+        let mut notionals = vec![*period];
+        let mut earliest = *period;
+        while earliest.start > *start {
+            earliest = ReferencePeriod {
+                start: step_frequency(&earliest.start, period.frequency, false, eom)
+                    .ok_or(DayCountError::OutOfRange)?,
+                end: earliest.start,
+                frequency: period.frequency,
+            };
+            notionals.push(earliest);
+        }
+        let mut sum = T::whole(0);
+        for (i, notional) in notionals.iter().rev().enumerate() {
+            if notional.start >= *end {
+                break;
+            }
+            let from = if i == 0 { *start } else { notional.start };
+            let to = if *end < notional.end { *end } else { notional.end };
+            let days = (notional.end - notional.start).num_days();
+            sum = sum + T::coupon_ratio((to - from).num_days(), days, period.frequency);
+        }
+        return Ok(sum);
+    }
+
+    // Long last coupon: whole notional periods after the reference period
+    // contribute 1 / frequency each, the remainder is pro-rated.
+    let mut sum = if *start < period.end {
+        icma_fraction(start, &period.end, period)?
+    } else {
+        T::whole(0)
+    };
+    let mut notional_start = period.end;
+    loop {
+        let notional_end = step_frequency(&notional_start, period.frequency, true, eom)
+            .ok_or(DayCountError::OutOfRange)?;
+        let notional = ReferencePeriod {
+            start: notional_start,
+            end: notional_end,
+            frequency: period.frequency,
+        };
+        if *end <= notional_end {
+            let from = if *start > notional_start { *start } else { notional_start };
+            return Ok(sum + icma_fraction(&from, end, &notional)?);
+        }
+        if *start <= notional_start {
+            sum = sum + T::coupon(period.frequency);
+        } else if *start < notional_end {
+            sum = sum + icma_fraction(start, &notional_end, &notional)?;
+        }
+        notional_start = notional_end;
+    }
+}
+
+// Actual/Actual AFB for `start <= end`: whole years are peeled off
+// backwards from `end`, then the remaining stub is divided by 366 if it
+// contains 29 February and by 365 otherwise.
+pub(crate) fn afb_fraction<T: Fraction>(start: &NaiveDate, end: &NaiveDate) -> Result<T, DayCountError> {
+    let mut years = 0;
+    let mut stub_end = *end;
+    loop {
+        // chrono clamps 29 Feb to 28 Feb when stepping into a non-leap year
+        let mut candidate = stub_end
+            .checked_sub_months(Months::new(12))
+            .ok_or(DayCountError::OutOfRange)?;
+        // Stepping back from 28 Feb into a leap year lands on 29 Feb
+        if candidate.month() == 2 && candidate.day() == 28 && is_leap_year(candidate.year()) {
+            candidate = candidate.succ_opt().ok_or(DayCountError::OutOfRange)?;
+        }
+        if candidate < *start {
+            break;
+        }
+        years += 1;
+        stub_end = candidate;
+    }
+
+    let contains_leap_day = |year: i32| {
+        NaiveDate::from_ymd_opt(year, 2, 29)
+            .is_some_and(|leap_day| *start <= leap_day && leap_day < stub_end)
+    };
+    let basis = if contains_leap_day(stub_end.year()) || contains_leap_day(start.year()) {
+        366
+    } else {
+        365
+    };
+    Ok(T::whole(years) + T::ratio((stub_end - *start).num_days(), basis))
+}
+
+// Moves `date` one period of `frequency` forwards or backwards, landing on
+// the month end if `eom` is set and the frequency is in months.
+fn step_frequency(date: &NaiveDate, frequency: Frequency, forward: bool, eom: bool) -> Option<NaiveDate> {
+    if let Some(months) = frequency.to_months() {
+        let stepped = if forward {
+            date.checked_add_months(Months::new(months))
+        } else {
+            date.checked_sub_months(Months::new(months))
+        }?;
+        return Some(if eom { end_of_month(&stepped) } else { stepped });
+    }
+    let days = Days::new(frequency.to_days()?.into());
+    if forward {
+        date.checked_add_days(days)
+    } else {
+        date.checked_sub_days(days)
+    }
+}
+
+/// How date arithmetic treats a day of the month that does not exist in
+/// the target month, e.g. 29 February one year later.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::MonthEndPolicy;
+///
+/// assert_eq!(MonthEndPolicy::default(), MonthEndPolicy::ClampToEndOfMonth);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Default)]
+pub enum MonthEndPolicy {
+    /// Use the last day of the target month: 29 Feb 2024 + 1Y = 28 Feb 2025.
+    #[default]
+    ClampToEndOfMonth,
+    /// Use the first day of the following month: 29 Feb 2024 + 1Y = 1 Mar 2025.
+    RollToNextMonth,
+    /// Return `None`.
+    Reject,
+}
+
+/// Adds `years_to_add` years to `date`, returning `None` if the result is
+/// outside the representable date range.
+///
+/// 29 February moved into a non-leap year becomes 28 February
+/// ([`MonthEndPolicy::ClampToEndOfMonth`]); use [`checked_add_years_with`]
+/// for the other policies.  chrono does not provide year arithmetic
+/// directly; this function fills the gap.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::checked_add_years;
+///
+/// let d = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+/// assert_eq!(
+///     checked_add_years(&d, 1),
+///     NaiveDate::from_ymd_opt(2024, 8, 15)
+/// );
+///
+/// // Feb 29 in a leap year → non-leap target year is clamped to Feb 28
+/// let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+/// assert_eq!(checked_add_years(&leap_day, 1), NaiveDate::from_ymd_opt(2025, 2, 28));
+/// ```
+pub fn checked_add_years(date: &NaiveDate, years_to_add: i32) -> Option<NaiveDate> {
+    checked_add_years_with(date, years_to_add, MonthEndPolicy::default())
+}
+
+/// Adds `years_to_add` years to `date`, resolving 29 February in a non-leap
+/// target year with `policy`.
+///
+/// Returns `None` if the result is outside the representable date range, or
+/// if the day does not exist and `policy` is [`MonthEndPolicy::Reject`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{checked_add_years_with, MonthEndPolicy};
+///
+/// let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+/// assert_eq!(checked_add_years_with(&leap_day, 1, MonthEndPolicy::RollToNextMonth),
+///            NaiveDate::from_ymd_opt(2025, 3, 1));
+/// assert_eq!(checked_add_years_with(&leap_day, 1, MonthEndPolicy::Reject), None);
+/// assert_eq!(checked_add_years_with(&leap_day, 4, MonthEndPolicy::Reject),
+///            NaiveDate::from_ymd_opt(2028, 2, 29));
+/// ```
+pub fn checked_add_years_with(
+    date: &NaiveDate,
+    years_to_add: i32,
+    policy: MonthEndPolicy,
+) -> Option<NaiveDate> {
+    let year = date.year().checked_add(years_to_add)?;
+    if let Some(result) = NaiveDate::from_ymd_opt(year, date.month(), date.day()) {
+        return Some(result);
+    }
+    // Only 29 February can be missing from the target year.
+    match policy {
+        MonthEndPolicy::ClampToEndOfMonth => last_day_of_month(year, date.month()),
+        MonthEndPolicy::RollToNextMonth => NaiveDate::from_ymd_opt(year, date.month() + 1, 1),
+        MonthEndPolicy::Reject => None,
+    }
+}
+
+/// Subtracts `years_to_subtract` years from `date`, like
+/// [`checked_add_years`] with the years negated.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::checked_sub_years;
+///
+/// let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+/// assert_eq!(checked_sub_years(&leap_day, 1), NaiveDate::from_ymd_opt(2023, 2, 28));
+/// assert_eq!(checked_sub_years(&leap_day, 4), NaiveDate::from_ymd_opt(2020, 2, 29));
+/// ```
+pub fn checked_sub_years(date: &NaiveDate, years_to_subtract: i32) -> Option<NaiveDate> {
+    checked_add_years(date, years_to_subtract.checked_neg()?)
+}
+
+/// Adds `months` months (negative to go back) to `date`, keeping month ends
+/// on month ends.
+///
+/// Days past the end of the target month are clamped to its last day, and a
+/// `date` on the last day of its month always lands on the last day of the
+/// target month — the end-of-month rule.  Returns `None` if the result is
+/// outside the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::checked_add_months_eom;
+///
+/// let feb_end = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+/// assert_eq!(checked_add_months_eom(&feb_end, 1), NaiveDate::from_ymd_opt(2023, 3, 31));
+///
+/// let jan_30 = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap();
+/// assert_eq!(checked_add_months_eom(&jan_30, 1), NaiveDate::from_ymd_opt(2023, 2, 28));
+/// assert_eq!(checked_add_months_eom(&jan_30, -2), NaiveDate::from_ymd_opt(2022, 11, 30));
+/// ```
+pub fn checked_add_months_eom(date: &NaiveDate, months: i32) -> Option<NaiveDate> {
+    let shifted = if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))?
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))?
+    };
+    if is_end_of_month(date) {
+        last_day_of_month(shifted.year(), shifted.month())
+    } else {
+        Some(shifted)
+    }
+}
+
+/// Moves `date` forward by `tenor` following the standard market convention
+/// for rolling dates by periods, then adjusts the result.
+///
+/// With a calendar:
+/// - **Day tenors** (`nD`, and `ON`/`TN`/`SN` as one day) count *business*
+///   days: the date is moved to the n-th business day after `date`.  A `0D`
+///   tenor simply adjusts `date` with `adjust_rule`.
+/// - **Week, month and year tenors** are added on the calendar and the
+///   result is adjusted with `adjust_rule`.
+/// - With `eom = true`, if `date` is the last business day of its month,
+///   month and year tenors land on the last business day of the target month
+///   (e.g. 28 Feb 2023 + 3M = 31 May 2023 rather than 28 May).
+///
+/// Without a calendar every tenor is added on the calendar, no adjustment is
+/// applied, and the end-of-month rule uses the last calendar day of the month.
+///
+/// Returns `None` if the result is out of the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::add_tenor;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{AdjustRule, Tenor};
+///
+/// let cal = basic_calendar();
+/// let spot = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(); // last business day of Feb
+///
+/// // End-of-month rule keeps the roll on month ends
+/// let eom = add_tenor(&spot, Tenor::Months(3), Some(&cal), Some(AdjustRule::ModFollowing), true);
+/// assert_eq!(eom, NaiveDate::from_ymd_opt(2023, 5, 31));
+///
+/// // Without it, the day of month is preserved
+/// let plain = add_tenor(&spot, Tenor::Months(3), Some(&cal), Some(AdjustRule::ModFollowing), false);
+/// assert_eq!(plain, NaiveDate::from_ymd_opt(2023, 5, 29));
+/// ```
+pub fn add_tenor(
+    date: &NaiveDate,
+    tenor: Tenor,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+    eom: bool,
+) -> Option<NaiveDate> {
+    let calendar = match calendar {
+        None => {
+            let raw = tenor.add_to(date)?;
+            let rolls_monthly = matches!(tenor, Tenor::Months(_) | Tenor::Years(_));
+            if eom && rolls_monthly && is_end_of_month(date) {
+                return last_day_of_month(raw.year(), raw.month());
+            }
+            return Some(raw);
+        }
+        Some(cal) => cal,
+    };
+
+    match tenor {
+        Tenor::Days(0) => checked_adjust(date, Some(calendar), adjust_rule),
+        Tenor::Days(n) => {
+            let mut current = *date;
+            for _ in 0..n {
+                current = add_adjust(&current, calendar)?;
+            }
+            Some(current)
+        }
+        Tenor::Overnight | Tenor::TomorrowNext | Tenor::SpotNext => add_adjust(date, calendar),
+        Tenor::Weeks(_) => checked_adjust(&tenor.add_to(date)?, Some(calendar), adjust_rule),
+        Tenor::Months(_) | Tenor::Years(_) => {
+            let raw = tenor.add_to(date)?;
+            if eom && is_last_business_day_of_month(date, calendar) {
+                let month_end = last_day_of_month(raw.year(), raw.month())?;
+                return checked_adjust(&month_end, Some(calendar), Some(AdjustRule::Preceding));
+            }
+            checked_adjust(&raw, Some(calendar), adjust_rule)
+        }
+    }
+}
+
+/// Returns the maturity of a money-market deposit starting on `spot` for
+/// `tenor`, following the broker conventions used to roll deposit and FRA
+/// maturities.
+///
+/// - **Month and year tenors** are added on the calendar and adjusted with
+///   `adjust_rule`, [`ModFollowing`](AdjustRule::ModFollowing) when `None`.
+///   With `eom = true` the end/end rule applies: a deposit starting on the
+///   last business day of a month matures on the last business day of the
+///   target month.
+/// - **Week tenors** are added on the calendar and rolled
+///   [`Following`](AdjustRule::Following): periods shorter than a month are
+///   never pulled back into the month.
+/// - **Day tenors** (`nD`, `ON`, `TN`, `SN`) count business days, as in
+///   [`add_tenor`].
+///
+/// Returns `None` if the result is out of the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::deposit_maturity;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::Tenor;
+///
+/// let cal  = basic_calendar();
+/// let spot = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap(); // last business day of September
+///
+/// // End/end: 1M runs to the last business day of October.
+/// assert_eq!(deposit_maturity(&spot, Tenor::Months(1), &cal, None, true), NaiveDate::from_ymd_opt(2023, 10, 31));
+/// // Otherwise Sunday 29 October rolls to Monday 30 October.
+/// assert_eq!(deposit_maturity(&spot, Tenor::Months(1), &cal, None, false), NaiveDate::from_ymd_opt(2023, 10, 30));
+/// ```
+pub fn deposit_maturity(
+    spot: &NaiveDate,
+    tenor: Tenor,
+    calendar: &Calendar,
+    adjust_rule: Option<AdjustRule>,
+    eom: bool,
+) -> Option<NaiveDate> {
+    match tenor {
+        Tenor::Months(_) | Tenor::Years(_) => {
+            let rule = adjust_rule.unwrap_or(AdjustRule::ModFollowing);
+            add_tenor(spot, tenor, Some(calendar), Some(rule), eom)
+        }
+        _ => add_tenor(spot, tenor, Some(calendar), Some(AdjustRule::Following), false),
+    }
+}
+
+/// Returns the start and end of an overnight (`ON`) deposit traded on
+/// `today`: from today to the next business day.
+///
+/// A `today` that is not a business day is rolled
+/// [`Following`](AdjustRule::Following) first, as the deposit can only start
+/// on a business day.  Returns `None` if a date is out of the representable
+/// range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::on_dates;
+/// use findates::calendar::basic_calendar;
+///
+/// let cal    = basic_calendar();
+/// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+/// assert_eq!(on_dates(&friday, &cal), Some((friday, NaiveDate::from_ymd_opt(2024, 3, 18).unwrap())));
+/// ```
+pub fn on_dates(today: &NaiveDate, calendar: &Calendar) -> Option<(NaiveDate, NaiveDate)> {
+    next_day_deposit(today, 0, calendar)
+}
+
+/// Returns the start and end of a tom-next (`TN`) deposit or FX swap traded
+/// on `today`: from the next business day to the business day after.
+///
+/// The near leg of a tom-next swap is the far leg of the overnight one
+/// returned by [`on_dates`].  A `today` that is not a business day is rolled
+/// [`Following`](AdjustRule::Following) first.  Returns `None` if a date is
+/// out of the representable range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::tn_dates;
+/// use findates::calendar::basic_calendar;
+///
+/// let cal      = basic_calendar();
+/// let thursday = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+/// assert_eq!(
+///     tn_dates(&thursday, &cal),
+///     Some((NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 18).unwrap()))
+/// );
+/// ```
+pub fn tn_dates(today: &NaiveDate, calendar: &Calendar) -> Option<(NaiveDate, NaiveDate)> {
+    next_day_deposit(today, 1, calendar)
+}
+
+/// Returns the start and end of a spot-next (`SN`) deposit or FX swap traded
+/// on `today`: from the spot date, two business days after today, to the
+/// business day after spot.
+///
+/// For currency pairs with their own spot rules, compute the spot date with
+/// [`fx_spot_date`] and roll it one business day with
+/// [`deposit_maturity`] and [`Tenor::SpotNext`].  A `today` that is not a
+/// business day is rolled [`Following`](AdjustRule::Following) first.
+/// Returns `None` if a date is out of the representable range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::sn_dates;
+/// use findates::calendar::basic_calendar;
+///
+/// let cal      = basic_calendar();
+/// let thursday = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+/// assert_eq!(
+///     sn_dates(&thursday, &cal),
+///     Some((NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 19).unwrap()))
+/// );
+/// ```
+pub fn sn_dates(today: &NaiveDate, calendar: &Calendar) -> Option<(NaiveDate, NaiveDate)> {
+    next_day_deposit(today, 2, calendar)
+}
+
+// A one business day period starting `lag` business days after `today`.
+fn next_day_deposit(today: &NaiveDate, lag: u32, calendar: &Calendar) -> Option<(NaiveDate, NaiveDate)> {
+    let start = settlement_date(today, lag, calendar, Some(AdjustRule::Following))?;
+    Some((start, add_adjust(&start, calendar)?))
+}
+
+/// Dates of a forward rate agreement: when the rate fixes, when the
+/// underlying deposit starts and when it matures.
+///
+/// Returned by [`fra_dates`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::FraDates;
+///
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// let fra = FraDates { fixing: d(4, 15), settlement: d(4, 17), maturity: d(7, 17) };
+/// assert!(fra.fixing < fra.settlement && fra.settlement < fra.maturity);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FraDates {
+    /// Date the reference rate is fixed, two business days before settlement.
+    pub fixing: NaiveDate,
+    /// Start of the forward period, when the FRA settles.
+    pub settlement: NaiveDate,
+    /// End of the forward period.
+    pub maturity: NaiveDate,
+}
+
+/// Returns the fixing, settlement and maturity dates of an FRA quoted in
+/// market notation, e.g. `"3x6"` for a three-month period starting three
+/// months after `spot`.
+///
+/// Both legs run from `spot` like [`deposit_maturity`] with
+/// [`ModFollowing`](AdjustRule::ModFollowing) and the end/end rule, and the
+/// rate fixes two business days before settlement.  The notation is two
+/// month counts separated by `x` (case-insensitive), the first smaller than
+/// the second; `"0x3"` starts at spot.
+///
+/// # Errors
+///
+/// - [`Err(ForwardDateError::InvalidTenor)`](ForwardDateError::InvalidTenor)
+///   if `notation` is not a valid FRA period.
+/// - [`Err(ForwardDateError::OutOfRange)`](ForwardDateError::OutOfRange) if
+///   a date runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::fra_dates;
+/// use findates::calendar::basic_calendar;
+///
+/// let cal  = basic_calendar();
+/// let spot = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap();
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+///
+/// let fra = fra_dates("3x6", &spot, &cal).unwrap();
+/// assert_eq!((fra.fixing, fra.settlement, fra.maturity), (d(4, 15), d(4, 17), d(7, 17)));
+/// assert!(fra_dates("6x3", &spot, &cal).is_err());
+/// ```
+pub fn fra_dates(notation: &str, spot: &NaiveDate, calendar: &Calendar) -> Result<FraDates, ForwardDateError> {
+    let (start_months, end_months) = parse_fra(notation)?;
+    let leg = |months| {
+        deposit_maturity(spot, Tenor::Months(months), calendar, None, true).ok_or(ForwardDateError::OutOfRange)
+    };
+    let settlement = leg(start_months)?;
+    let maturity = leg(end_months)?;
+    // Settlement is adjusted to a business day, so only overflow can fail.
+    let fixing = subtract_business_days(&settlement, 2, calendar).map_err(|_| ForwardDateError::OutOfRange)?;
+    Ok(FraDates { fixing, settlement, maturity })
+}
+
+// Splits FRA notation such as "3x6" into its start and end month counts.
+fn parse_fra(notation: &str) -> Result<(u32, u32), ParseTenorError> {
+    let months = |s: &str| -> Result<u32, ParseTenorError> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTenorError);
+        }
+        s.parse().map_err(|_| ParseTenorError)
+    };
+    let (start, end) = notation.trim().split_once(['x', 'X']).ok_or(ParseTenorError)?;
+    let (start, end) = (months(start)?, months(end)?);
+    if start >= end {
+        return Err(ParseTenorError);
+    }
+    Ok((start, end))
+}
+
+/// Counts the coupon periods of `frequency` left between `as_of` and
+/// `maturity`, including the one in progress.
+///
+/// Coupon dates are rolled back from `maturity` in whole periods, as for a
+/// bond with a front stub: `maturity`, `maturity - 1 period`, and so on,
+/// each computed from `maturity` so short months do not accumulate.  The
+/// result is how many of those dates fall strictly after `as_of`, i.e. the
+/// number of coupons still to be paid.  [`Frequency::Zero`] has a single
+/// period ending at maturity.  Returns `0` once `as_of` is on or after
+/// `maturity`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::remaining_periods;
+/// use findates::conventions::Frequency;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// let maturity = d(2034, 8, 15);
+///
+/// // Coupons on 15 Feb and 15 Aug: 2024-08-15 through 2034-08-15 remain.
+/// assert_eq!(remaining_periods(&d(2024, 6, 1), &maturity, Frequency::Semiannual), 21);
+/// // On a coupon date, that coupon is no longer remaining.
+/// assert_eq!(remaining_periods(&d(2024, 8, 15), &maturity, Frequency::Semiannual), 20);
+/// assert_eq!(remaining_periods(&maturity, &maturity, Frequency::Semiannual), 0);
+/// ```
+pub fn remaining_periods(as_of: &NaiveDate, maturity: &NaiveDate, frequency: Frequency) -> u32 {
+    if as_of >= maturity {
+        return 0;
+    }
+    if frequency == Frequency::Zero {
+        return 1;
+    }
+    // The n-th coupon date before maturity, or None once out of range.
+    let nth_before = |n: u32| -> Option<NaiveDate> {
+        if let Some(months) = frequency.to_months() {
+            maturity.checked_sub_months(Months::new(months.checked_mul(n)?))
+        } else {
+            let days = u64::from(frequency.to_days()?) * u64::from(n);
+            maturity.checked_sub_days(Days::new(days))
+        }
+    };
+    let mut count = 1;
+    while nth_before(count).is_some_and(|date| date > *as_of) {
+        count += 1;
+    }
+    count
+}
+
+/// A period in whole years, months and days, e.g. `9Y11M`, as returned by
+/// [`tenor_between`].
+///
+/// Displays in market notation, leaving out zero parts; a zero term is
+/// `0D`.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::Term;
+///
+/// let term = Term { years: 9, months: 11, days: 0 };
+/// assert_eq!(term.to_string(), "9Y11M");
+/// assert_eq!(Term { years: 0, months: 2, days: 15 }.to_string(), "2M15D");
+/// assert_eq!(Term::default().to_string(), "0D");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Term {
+    /// Whole years.
+    pub years: u32,
+    /// Whole months after the years, `0..12`.
+    pub months: u32,
+    /// Days after the whole months.  Usually less than a month, but a
+    /// start on a day of month that the next month clamps can leave more:
+    /// 31 January to 30 March 2024 is one month (to 29 February) and 30
+    /// days, as two months would reach 31 March.
+    pub days: u32,
+}
+
+impl Term {
+    /// Total whole months, counting each year as twelve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::algebra::Term;
+    ///
+    /// assert_eq!(Term { years: 9, months: 11, days: 3 }.total_months(), 119);
+    /// ```
+    pub fn total_months(&self) -> u32 {
+        self.years * 12 + self.months
+    }
+
+    /// Returns the single [`Tenor`] closest to this term, for bucketing.
+    ///
+    /// Terms under a month are a number of weeks when the days divide
+    /// evenly by seven and a number of days otherwise.  Longer terms round
+    /// to the nearest month, with 15 days or more rounding up, and are
+    /// quoted in years when the months are a whole number of years.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::algebra::Term;
+    /// use findates::conventions::Tenor;
+    ///
+    /// assert_eq!(Term { years: 9, months: 11, days: 20 }.to_tenor(), Tenor::Years(10));
+    /// assert_eq!(Term { years: 9, months: 11, days: 3 }.to_tenor(), Tenor::Months(119));
+    /// assert_eq!(Term { years: 0, months: 0, days: 14 }.to_tenor(), Tenor::Weeks(2));
+    /// assert_eq!(Term { years: 0, months: 0, days: 10 }.to_tenor(), Tenor::Days(10));
+    /// ```
+    pub fn to_tenor(&self) -> Tenor {
+        let months = self.total_months();
+        if months == 0 {
+            return match self.days {
+                days if days > 0 && days % 7 == 0 => Tenor::Weeks(days / 7),
+                days => Tenor::Days(days),
+            };
+        }
+        let months = if self.days >= 15 { months + 1 } else { months };
+        if months % 12 == 0 {
+            Tenor::Years(months / 12)
+        } else {
+            Tenor::Months(months)
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Term::default() {
+            return write!(f, "0D");
+        }
+        if self.years > 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months > 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days > 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the whole years, months and days from `start` to `end`, or
+/// `None` if `end` is before `start`.
+///
+/// The months are the most that can be added to `start` without passing
+/// `end`, using the same month arithmetic as [`Tenor::add_to`]: the day of
+/// month is clamped to shorter months, so 31 January to 29 February 2024 is
+/// exactly one month.  The days are what is left, which can be a month or
+/// more when the months end on a clamped day (see [`Term::days`]).  Use
+/// [`Term::to_tenor`] for the nearest single tenor.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::tenor_between;
+/// use findates::conventions::Tenor;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+///
+/// let term = tenor_between(&d(2024, 6, 17), &d(2034, 5, 15)).unwrap();
+/// assert_eq!(term.to_string(), "9Y10M28D");
+/// assert_eq!(term.to_tenor(), Tenor::Months(119));
+/// assert_eq!(tenor_between(&d(2024, 1, 31), &d(2024, 2, 29)).unwrap().to_string(), "1M");
+/// // Two months from 31 January would pass 30 March, so the rest is days.
+/// let term = tenor_between(&d(2024, 1, 31), &d(2024, 3, 30)).unwrap();
+/// assert_eq!(term.to_string(), "1M30D");
+/// assert_eq!(term.to_tenor(), Tenor::Months(2));
+/// assert_eq!(tenor_between(&d(2024, 2, 29), &d(2024, 1, 31)), None);
+/// ```
+pub fn tenor_between(start: &NaiveDate, end: &NaiveDate) -> Option<Term> {
+    if end < start {
+        return None;
+    }
+    let month_index = |date: &NaiveDate| i64::from(date.year()) * 12 + i64::from(date.month0());
+    // At most one too many: the day of month may not have been reached.
+    let mut months = u32::try_from(month_index(end) - month_index(start)).ok()?;
+    let mut anchor = start.checked_add_months(Months::new(months))?;
+    if anchor > *end {
+        months -= 1;
+        anchor = start.checked_add_months(Months::new(months))?;
+    }
+    Some(Term {
+        years: months / 12,
+        months: months % 12,
+        days: u32::try_from((*end - anchor).num_days()).ok()?,
+    })
+}
+
+/// Returns the last trading day of the futures contract `code` under `rule`.
+///
+/// `code` ends with a month code (`F` January, `G`, `H`, `J`, `K`, `M`, `N`,
+/// `Q`, `U`, `V`, `X`, `Z` December) and a two- or four-digit year, e.g.
+/// `"ESZ24"`, `"SR3H2025"` or just `"Z24"`.  Two-digit years are in the
+/// 2000s; single-digit years are rejected as ambiguous.  Whatever precedes
+/// the month code is the contract root and is not checked.  Case and
+/// surrounding whitespace are ignored.
+///
+/// # Errors
+///
+/// Returns [`FuturesExpiryError::InvalidCode`] if `code` cannot be parsed,
+/// and [`FuturesExpiryError::Date`] if `rule` names a weekday the month does
+/// not have (e.g. a fifth Friday) or the expiry is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::futures_expiry;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::ExpiryRule;
+///
+/// let mut cal = basic_calendar();
+/// cal.add_holidays([NaiveDate::from_ymd_opt(2024, 6, 19).unwrap()]); // Juneteenth
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+///
+/// assert_eq!(futures_expiry("ESH24", ExpiryRule::ES, &cal), Ok(d(3, 15)));
+/// assert_eq!(futures_expiry("FFZ24", ExpiryRule::FF, &cal), Ok(d(12, 31)));
+/// // The March 2024 contract references the quarter to 19 June.
+/// assert_eq!(futures_expiry("SR3H24", ExpiryRule::SR3, &cal), Ok(d(6, 18)));
+/// assert_eq!(futures_expiry("IZ24", ExpiryRule::EURIBOR, &cal), Ok(d(12, 16)));
+/// assert!(futures_expiry("ESZ4", ExpiryRule::ES, &cal).is_err());
+/// ```
+pub fn futures_expiry(code: &str, rule: ExpiryRule, calendar: &Calendar) -> Result<NaiveDate, FuturesExpiryError> {
+    let (year, month) = parse_futures_code(code).ok_or_else(|| FuturesExpiryError::InvalidCode(code.to_string()))?;
+    let expiry = match rule {
+        ExpiryRule::NthWeekday { n, weekday } => business_days_before_nth_weekday(year, month, n, weekday, 0, calendar)?,
+        ExpiryRule::LastBusinessDay => last_business_day_of_month(year, month, calendar).ok_or(DateError::OutOfRange)?,
+        ExpiryRule::BusinessDaysBeforeNthWeekday { days, n, weekday, months_after } => {
+            let index = (year as i64) * 12 + (month as i64 - 1) + months_after as i64;
+            let year = i32::try_from(index.div_euclid(12)).map_err(|_| DateError::OutOfRange)?;
+            let month = index.rem_euclid(12) as u32 + 1;
+            business_days_before_nth_weekday(year, month, n, weekday, days, calendar)?
+        }
+    };
+    Ok(expiry)
+}
+
+// Splits a futures code such as "ESZ24" into its contract year and month.
+fn parse_futures_code(code: &str) -> Option<(i32, u32)> {
+    const MONTH_CODES: &str = "FGHJKMNQUVXZ";
+    let code = code.trim().to_ascii_uppercase();
+    let digits = code.bytes().rev().take_while(u8::is_ascii_digit).count();
+    let (rest, year) = code.split_at(code.len() - digits);
+    let year: i32 = match digits {
+        2 => 2000 + year.parse::<i32>().ok()?,
+        4 => year.parse().ok()?,
+        _ => return None,
+    };
+    let month = MONTH_CODES.find(rest.chars().last()?)? as u32 + 1;
+    Some((year, month))
+}
+
+// `days` business days before the n-th weekday of the month; with `days`
+// zero, the weekday itself or the business day before it.
+fn business_days_before_nth_weekday(
+    year: i32,
+    month: u32,
+    n: u8,
+    weekday: Weekday,
+    days: u32,
+    calendar: &Calendar,
+) -> Result<NaiveDate, DateError> {
+    let mut date = nth_weekday_of_month(year, month, weekday, n)?;
+    if days == 0 {
+        return checked_adjust(&date, Some(calendar), Some(AdjustRule::Preceding)).ok_or(DateError::OutOfRange);
+    }
+    for _ in 0..days {
+        date = sub_adjust(&date, calendar).ok_or(DateError::OutOfRange)?;
+    }
+    Ok(date)
+}
+
+/// Returns the expiry dates of the listed option series `style` in `month` of
+/// `year`, in date order.
+///
+/// An expiry that is not a business day moves to the business day before
+/// it, as when Good Friday is the third Friday.  The Saturday expiries of
+/// [`ExpiryStyle::Monthly`] contracts before February 2015 are returned as
+/// they are; trading ended the Friday before.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidMonth`] if `month` is not in `1..=12`, and
+/// [`DateError::OutOfRange`] if the month is not representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::option_expiries;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::ExpiryStyle;
+///
+/// let mut cal = basic_calendar();
+/// cal.add_holidays([NaiveDate::from_ymd_opt(2022, 4, 15).unwrap()]); // Good Friday
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+///
+/// assert_eq!(option_expiries(2022, 4, ExpiryStyle::Monthly, &cal), Ok(vec![d(2022, 4, 14)]));
+/// assert_eq!(option_expiries(2014, 12, ExpiryStyle::Monthly, &cal), Ok(vec![d(2014, 12, 20)]));
+/// assert_eq!(option_expiries(2022, 4, ExpiryStyle::EndOfMonth, &cal), Ok(vec![d(2022, 4, 29)]));
+/// assert_eq!(
+///     option_expiries(2022, 4, ExpiryStyle::Weekly(Weekday::Fri), &cal),
+///     Ok(vec![d(2022, 4, 1), d(2022, 4, 8), d(2022, 4, 14), d(2022, 4, 22), d(2022, 4, 29)])
+/// );
+/// ```
+pub fn option_expiries(
+    year: i32,
+    month: u32,
+    style: ExpiryStyle,
+    calendar: &Calendar,
+) -> Result<Vec<NaiveDate>, DateError> {
+    if !(1..=12).contains(&month) {
+        return Err(DateError::InvalidMonth);
+    }
+    let preceding = |date: NaiveDate| checked_adjust(&date, Some(calendar), Some(AdjustRule::Preceding)).ok_or(DateError::OutOfRange);
+    let expiries = match style {
+        ExpiryStyle::Monthly => {
+            let friday = nth_weekday_of_month(year, month, Weekday::Fri, 3)?;
+            if (year, month) < (2015, 2) {
+                vec![friday.succ_opt().ok_or(DateError::OutOfRange)?]
+            } else {
+                vec![preceding(friday)?]
+            }
+        }
+        ExpiryStyle::Weekly(weekday) => {
+            let mut expiries = Vec::new();
+            for n in 1..=5 {
+                match nth_weekday_of_month(year, month, weekday, n) {
+                    Ok(date) => expiries.push(preceding(date)?),
+                    Err(DateError::InvalidOrdinal) => break,
+                    Err(err) => return Err(err),
+                }
+            }
+            expiries.dedup();
+            expiries
+        }
+        ExpiryStyle::EndOfMonth => {
+            vec![last_business_day_of_month(year, month, calendar).ok_or(DateError::OutOfRange)?]
+        }
+    };
+    Ok(expiries)
+}
+
+/// Returns the settlement date `lag` business days after `trade_date`
+/// (T+1, T+2, T+3, ...), or `None` if it is out of range.
+///
+/// A trade date that is not a business day is first adjusted with
+/// `adjust_rule`; with `None` or [`Unadjusted`](AdjustRule::Unadjusted) it is
+/// kept and the count starts from the next business day, so a Saturday trade
+/// settles T+1 on Monday.  The result is always a business day: `lag = 0`
+/// rolls a non-business day forward.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::settlement_date;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+///
+/// let cal      = basic_calendar();
+/// let thursday = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+/// assert_eq!(settlement_date(&thursday, 2, &cal, None), NaiveDate::from_ymd_opt(2024, 3, 18));
+///
+/// // A Saturday trade adjusted Following counts from Monday
+/// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// assert_eq!(
+///     settlement_date(&saturday, 1, &cal, Some(AdjustRule::Following)),
+///     NaiveDate::from_ymd_opt(2024, 3, 19)
+/// );
+/// assert_eq!(settlement_date(&saturday, 1, &cal, None), NaiveDate::from_ymd_opt(2024, 3, 18));
+/// ```
+pub fn settlement_date(
+    trade_date: &NaiveDate,
+    lag: u32,
+    calendar: &Calendar,
+    adjust_rule: Option<AdjustRule>,
+) -> Option<NaiveDate> {
+    let start = checked_adjust(trade_date, Some(calendar), adjust_rule)?;
+    add_tenor(&start, Tenor::Days(lag), Some(calendar), Some(AdjustRule::Following), false)
+}
+
+/// Returns the FX spot date (T+2) for a trade on `trade_date`, or `None` if
+/// it is out of range or the calendars' weekends together cover the whole
+/// week.
+///
+/// Pass the holiday calendars of the two currencies as `ccy1_cal` and
+/// `ccy2_cal`, using `None` for the USD side of a USD pair, and the U.S.
+/// calendar as `usd_cal`.  The market convention is:
+///
+/// 1. Each day counted towards the spot lag must be a business day in both
+///    non-USD currencies.  A USD holiday on an intermediate day (T+1) does
+///    not delay spot.
+/// 2. The spot date itself must be a business day in both currencies and in
+///    the U.S., for crosses as well as USD pairs; otherwise it rolls forward
+///    to the next day that is.
+///
+/// A `None` calendar places no restriction.  For currency pairs that settle
+/// T+1, such as USDCAD, use [`fx_spot_date_with_lag`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::fx_spot_date;
+/// use findates::calendar::basic_calendar;
+///
+/// let eur = basic_calendar();
+/// let mut usd = basic_calendar();
+/// usd.add_holidays([NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()]);
+///
+/// // EURUSD on Wednesday 3 July: T+1 is a USD holiday but still counts
+/// let trade = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+/// assert_eq!(fx_spot_date(&trade, Some(&eur), None, Some(&usd)), NaiveDate::from_ymd_opt(2024, 7, 5));
+///
+/// // On Tuesday 2 July, T+2 is the USD holiday itself and spot rolls to Friday
+/// let trade = NaiveDate::from_ymd_opt(2024, 7, 2).unwrap();
+/// assert_eq!(fx_spot_date(&trade, Some(&eur), None, Some(&usd)), NaiveDate::from_ymd_opt(2024, 7, 5));
+/// ```
+pub fn fx_spot_date(
+    trade_date: &NaiveDate,
+    ccy1_cal: Option<&Calendar>,
+    ccy2_cal: Option<&Calendar>,
+    usd_cal: Option<&Calendar>,
+) -> Option<NaiveDate> {
+    fx_spot_date_with_lag(trade_date, 2, ccy1_cal, ccy2_cal, usd_cal)
+}
+
+/// Returns the FX spot date `spot_lag` good days after `trade_date`,
+/// following the same rules as [`fx_spot_date`].
+///
+/// Returns `None` if the calendars' weekends together cover the whole week,
+/// so that no date could be spot, or if the search runs off the
+/// representable date range.
+///
+/// Use a lag of 1 for pairs that settle T+1 (USDCAD, and also USDTRY, USDRUB
+/// and USDPHP) and 2 for everything else.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::fx_spot_date_with_lag;
+/// use findates::calendar::basic_calendar;
+///
+/// let mut cad = basic_calendar();
+/// cad.add_holidays([NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()]); // Canada Day
+/// let usd = basic_calendar();
+///
+/// // USDCAD on Friday 28 June: Monday is a CAD holiday, so spot is Tuesday
+/// let trade = NaiveDate::from_ymd_opt(2024, 6, 28).unwrap();
+/// assert_eq!(
+///     fx_spot_date_with_lag(&trade, 1, None, Some(&cad), Some(&usd)),
+///     NaiveDate::from_ymd_opt(2024, 7, 2)
+/// );
+/// ```
+pub fn fx_spot_date_with_lag(
+    trade_date: &NaiveDate,
+    spot_lag: u32,
+    ccy1_cal: Option<&Calendar>,
+    ccy2_cal: Option<&Calendar>,
+    usd_cal: Option<&Calendar>,
+) -> Option<NaiveDate> {
+    let weekend: HashSet<Weekday> = [ccy1_cal, ccy2_cal, usd_cal]
+        .into_iter()
+        .flatten()
+        .flat_map(|calendar| calendar.get_weekend())
+        .copied()
+        .collect();
+    if weekend.len() == 7 {
+        return None;
+    }
+    let open = |cal: Option<&Calendar>, date: &NaiveDate| cal.map_or(true, |c| is_business_day(date, c));
+    let mut current = *trade_date;
+    for _ in 0..spot_lag {
+        current = current.succ_opt()?;
+        while !(open(ccy1_cal, &current) && open(ccy2_cal, &current)) {
+            current = current.succ_opt()?;
+        }
+    }
+    while !(open(ccy1_cal, &current) && open(ccy2_cal, &current) && open(usd_cal, &current)) {
+        current = current.succ_opt()?;
+    }
+    Some(current)
+}
+
+/// Returns `true` if `date` is a business day in every one of `calendars`,
+/// as a cross-border payment date must be.
+///
+/// With no calendars every date is good.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::is_good_settlement_date;
+/// use findates::calendar::basic_calendar;
+///
+/// let eur = basic_calendar();
+/// let mut usd = basic_calendar();
+/// usd.add_holidays([NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()]);
+///
+/// let july_4 = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+/// assert!(is_good_settlement_date(&july_4, &[&eur]));
+/// assert!(!is_good_settlement_date(&july_4, &[&eur, &usd]));
+/// ```
+pub fn is_good_settlement_date(date: &NaiveDate, calendars: &[&Calendar]) -> bool {
+    calendars.iter().all(|calendar| is_business_day(date, calendar))
+}
+
+/// Returns the first date on or after `date` that is a business day in every
+/// one of `calendars`; see [`is_good_settlement_date`].
+///
+/// Returns `None` if the calendars' weekends together cover the whole week,
+/// so that no date is ever good, or if the search runs off the representable
+/// date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::next_good_settlement_date;
+/// use findates::calendar::{basic_calendar, Calendar};
+///
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// let mut usd = basic_calendar();
+/// usd.add_holidays([d(7, 4)]);
+/// let aed = Calendar::with_weekends([Weekday::Fri, Weekday::Sat]);
+///
+/// // Thursday 4 July is a USD holiday, Friday an AED weekend day, and
+/// // Saturday and Sunday are USD weekend days.
+/// assert_eq!(next_good_settlement_date(&d(7, 4), &[&usd, &aed]), Some(d(7, 8)));
+/// assert_eq!(next_good_settlement_date(&d(7, 8), &[&usd, &aed]), Some(d(7, 8)));
+///
+/// let mon_to_thu = Calendar::with_weekends([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu]);
+/// assert_eq!(next_good_settlement_date(&d(7, 4), &[&usd, &aed, &mon_to_thu]), None);
+/// ```
+pub fn next_good_settlement_date(date: &NaiveDate, calendars: &[&Calendar]) -> Option<NaiveDate> {
+    let weekend: HashSet<Weekday> = calendars.iter().flat_map(|calendar| calendar.get_weekend()).copied().collect();
+    if weekend.len() == 7 {
+        return None;
+    }
+    let mut current = *date;
+    while !is_good_settlement_date(&current, calendars) {
+        current = current.succ_opt()?;
+    }
+    Some(current)
+}
+
+/// One rung of an FX forward ladder: a tenor and its value date.
+///
+/// Returned by [`fx_forward_dates`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::FxForwardDate;
+/// use findates::conventions::Tenor;
+///
+/// let rung = FxForwardDate { tenor: Tenor::Months(1), value_date: NaiveDate::from_ymd_opt(2024, 7, 5).unwrap() };
+/// assert_eq!(rung.tenor.to_string(), "1M");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FxForwardDate {
+    /// Tenor of the forward, counted from spot.
+    pub tenor: Tenor,
+    /// Date the forward settles.
+    pub value_date: NaiveDate,
+}
+
+/// Returns the value dates of FX forwards for each of `tenors`, in market
+/// notation, counted from `spot`.
+///
+/// A value date must be a business day in every one of `calendars`, usually
+/// those of the two currencies; see [`is_good_settlement_date`].  Tenors are
+/// rolled on the joint calendar as in [`deposit_maturity`]: month and year
+/// tenors use the end/end rule and
+/// [`ModFollowing`](AdjustRule::ModFollowing), so a spot on the last good
+/// day of its month gives value dates on the last good day of each month;
+/// week tenors roll [`Following`](AdjustRule::Following); day tenors and
+/// `SN` count good days from spot.  The ladder keeps the order of `tenors`.
+///
+/// # Errors
+///
+/// - [`Err(ForwardDateError::InvalidTenor)`](ForwardDateError::InvalidTenor)
+///   if a tenor cannot be parsed, or is `ON` or `TN`, which settle before
+///   spot; see [`on_dates`] and [`tn_dates`].
+/// - [`Err(ForwardDateError::NoBusinessDays)`](ForwardDateError::NoBusinessDays)
+///   if the calendars' weekends together cover the whole week.
+/// - [`Err(ForwardDateError::OutOfRange)`](ForwardDateError::OutOfRange) if
+///   a value date is out of the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::fx_forward_dates;
+/// use findates::calendar::basic_calendar;
+///
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// let eur = basic_calendar();
+/// let mut usd = basic_calendar();
+/// usd.add_holidays([d(7, 4)]);
+///
+/// // Spot on 31 May, the last good day of the month: value dates stay at month end.
+/// let ladder = fx_forward_dates(&d(5, 31), &["1W", "1M", "2M"], &[&eur, &usd]).unwrap();
+/// let dates: Vec<_> = ladder.iter().map(|rung| rung.value_date).collect();
+/// assert_eq!(dates, [d(6, 7), d(6, 28), d(7, 31)]);
+///
+/// // From 4 June, 1M lands on the USD holiday and rolls to Friday.
+/// let ladder = fx_forward_dates(&d(6, 4), &["1M"], &[&eur, &usd]).unwrap();
+/// assert_eq!(ladder[0].value_date, d(7, 5));
+/// ```
+pub fn fx_forward_dates(
+    spot: &NaiveDate,
+    tenors: &[&str],
+    calendars: &[&Calendar],
+) -> Result<Vec<FxForwardDate>, ForwardDateError> {
+    let mut joint = Calendar::new();
+    for calendar in calendars {
+        joint.add_holidays(calendar.iter_holidays_sorted());
+        joint.add_weekends(calendar.get_weekend().iter().copied());
+    }
+    if joint.get_weekend().len() == 7 {
+        return Err(ForwardDateError::NoBusinessDays);
+    }
+    tenors
+        .iter()
+        .map(|notation| {
+            let tenor: Tenor = notation.parse()?;
+            if matches!(tenor, Tenor::Overnight | Tenor::TomorrowNext) {
+                return Err(ParseTenorError.into());
+            }
+            let value_date =
+                deposit_maturity(spot, tenor, &joint, None, true).ok_or(ForwardDateError::OutOfRange)?;
+            Ok(FxForwardDate { tenor, value_date })
+        })
+        .collect()
+}
+
+/// Moves `date` forward by `n` business days in `calendar`.
+///
+/// **Precondition**: `date` must already be a business day.  If it is not,
+/// [`Err(BusinessDayError::InvalidStartDate)`](BusinessDayError::InvalidStartDate)
+/// is returned — including when `n = 0`.  The recommended workflow is to
+/// adjust the start date first, then call this function:
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::algebra::{adjust, add_business_days};
+///
+/// let cal = basic_calendar();
+/// // Start from a Saturday — adjust to Monday first, then step forward.
+/// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// let monday   = adjust(&saturday, Some(&cal), Some(AdjustRule::Following));
+/// let result   = add_business_days(&monday, 3, &cal).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 21).unwrap()); // Thursday
+/// ```
+///
+/// When `n = 0` and `date` is a business day, `date` is returned unchanged.
+/// Returns [`Err(BusinessDayError::OutOfRange)`](BusinessDayError::OutOfRange)
+/// if the result would be after the last representable date.
+pub fn add_business_days(
+    date: &NaiveDate,
+    n: u32,
+    calendar: &Calendar,
+) -> Result<NaiveDate, BusinessDayError> {
+    if !is_business_day(date, calendar) {
+        return Err(BusinessDayError::InvalidStartDate);
+    }
+    let mut current = *date;
+    for _ in 0..n {
+        current = add_adjust(&current, calendar).ok_or(BusinessDayError::OutOfRange)?;
+    }
+    Ok(current)
+}
+
+/// Moves `date` backward by `n` business days in `calendar`.
+///
+/// **Precondition**: `date` must already be a business day.  If it is not,
+/// [`Err(BusinessDayError::InvalidStartDate)`](BusinessDayError::InvalidStartDate)
+/// is returned — including when `n = 0`.  The recommended workflow is to
+/// adjust the start date first, then call this function:
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::algebra::{adjust, subtract_business_days};
+///
+/// let cal = basic_calendar();
+/// // Start from a Saturday — adjust to Monday first, then step backward.
+/// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+/// let monday   = adjust(&saturday, Some(&cal), Some(AdjustRule::Following));
+/// let result   = subtract_business_days(&monday, 1, &cal).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()); // Friday
+/// ```
+///
+/// When `n = 0` and `date` is a business day, `date` is returned unchanged.
+/// Returns [`Err(BusinessDayError::OutOfRange)`](BusinessDayError::OutOfRange)
+/// if the result would be before the first representable date.
+pub fn subtract_business_days(
+    date: &NaiveDate,
+    n: u32,
+    calendar: &Calendar,
+) -> Result<NaiveDate, BusinessDayError> {
+    if !is_business_day(date, calendar) {
+        return Err(BusinessDayError::InvalidStartDate);
+    }
+    let mut current = *date;
+    for _ in 0..n {
+        current = sub_adjust(&current, calendar).ok_or(BusinessDayError::OutOfRange)?;
+    }
+    Ok(current)
+}
+
+/// Returns the first business day strictly after `date`.
+///
+/// Unlike [`adjust`] with [`Following`](AdjustRule::Following), a `date`
+/// that is already a business day still moves forward.  Returns `None` if
+/// the search runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::next_business_day;
+///
+/// let cal    = basic_calendar();
+/// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+/// assert_eq!(next_business_day(&friday, &cal), NaiveDate::from_ymd_opt(2024, 3, 18));
+/// assert_eq!(next_business_day(&NaiveDate::MAX, &cal), None);
+/// ```
+pub fn next_business_day(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    add_adjust(date, calendar)
+}
+
+/// Returns the last business day strictly before `date`.
+///
+/// Unlike [`adjust`] with [`Preceding`](AdjustRule::Preceding), a `date`
+/// that is already a business day still moves back.  Returns `None` if the
+/// search runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::previous_business_day;
+///
+/// let cal    = basic_calendar();
+/// let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+/// assert_eq!(previous_business_day(&monday, &cal), NaiveDate::from_ymd_opt(2024, 3, 15));
+/// assert_eq!(previous_business_day(&NaiveDate::MIN, &cal), None);
+/// ```
+pub fn previous_business_day(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    sub_adjust(date, calendar)
+}
+
+/// Returns the ex-dividend date of a coupon paid on `coupon_date`: the
+/// `ex_div_business_days`-th business day before it.
+///
+/// A bond bought for settlement on or after this date trades without the
+/// coupon, which goes to the holder on the record date
+/// ([`record_date`]).  UK gilts go ex-dividend 7 business days before the
+/// coupon date.  `coupon_date` is the scheduled date and need not be a
+/// business day; with `ex_div_business_days` zero it is returned unchanged.
+/// Returns `None` if the search for a business day runs off the
+/// representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::ex_dividend_date;
+/// use findates::calendar::Calendar;
+///
+/// let uk = Calendar::uk_bank_holidays(2024..=2025);
+/// // A gilt coupon on Saturday 7 December 2024
+/// let coupon = NaiveDate::from_ymd_opt(2024, 12, 7).unwrap();
+/// assert_eq!(ex_dividend_date(&coupon, 7, &uk), NaiveDate::from_ymd_opt(2024, 11, 28));
+/// ```
+pub fn ex_dividend_date(coupon_date: &NaiveDate, ex_div_business_days: u32, calendar: &Calendar) -> Option<NaiveDate> {
+    let mut date = *coupon_date;
+    for _ in 0..ex_div_business_days {
+        date = previous_business_day(&date, calendar)?;
+    }
+    Some(date)
+}
+
+/// Returns the record date of a coupon paid on `coupon_date`: the business
+/// day before its [`ex_dividend_date`].  The coupon is paid to whoever holds
+/// the bond at the close of this day.  Returns `None` if the search for a
+/// business day runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::record_date;
+/// use findates::calendar::Calendar;
+///
+/// let uk = Calendar::uk_bank_holidays(2024..=2025);
+/// let coupon = NaiveDate::from_ymd_opt(2024, 12, 7).unwrap();
+/// assert_eq!(record_date(&coupon, 7, &uk), NaiveDate::from_ymd_opt(2024, 11, 27));
+/// ```
+pub fn record_date(coupon_date: &NaiveDate, ex_div_business_days: u32, calendar: &Calendar) -> Option<NaiveDate> {
+    previous_business_day(&ex_dividend_date(coupon_date, ex_div_business_days, calendar)?, calendar)
+}
+
+/// Returns `true` if `settlement` falls in the ex-dividend period of the
+/// coupon paid on `coupon_date`: on or after its [`ex_dividend_date`] and
+/// before the coupon date.
+///
+/// Accrued interest for such a settlement is negative, running from the
+/// settlement date to the coupon date, since the buyer does not receive the
+/// coupon.
+///
+/// Returns `None` if the ex-dividend date runs off the representable date
+/// range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::is_ex_dividend;
+/// use findates::calendar::Calendar;
+///
+/// let uk = Calendar::uk_bank_holidays(2024..=2025);
+/// let coupon = NaiveDate::from_ymd_opt(2024, 12, 7).unwrap();
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+///
+/// assert_eq!(is_ex_dividend(&d(11, 27), &coupon, 7, &uk), Some(false));
+/// assert_eq!(is_ex_dividend(&d(11, 28), &coupon, 7, &uk), Some(true));
+/// assert_eq!(is_ex_dividend(&d(12, 7), &coupon, 7, &uk), Some(false));
+/// ```
+pub fn is_ex_dividend(
+    settlement: &NaiveDate,
+    coupon_date: &NaiveDate,
+    ex_div_business_days: u32,
+    calendar: &Calendar,
+) -> Option<bool> {
+    let ex_dividend = ex_dividend_date(coupon_date, ex_div_business_days, calendar)?;
+    Some(settlement < coupon_date && *settlement >= ex_dividend)
+}
+
+/// Returns the observation period of a compounded-in-arrears rate (SOFR,
+/// SONIA, €STR...) under the observation shift convention: `period_start`
+/// and `period_end` each moved back `lookback_days` business days in
+/// `calendar`.
+///
+/// A boundary that is not a business day is first moved to the preceding
+/// business day.  Returns `None` if a date runs off the representable date
+/// range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::observation_shift;
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+/// let end   = NaiveDate::from_ymd_opt(2024, 6, 18).unwrap(); // Tuesday
+///
+/// assert_eq!(
+///     observation_shift(&start, &end, 5, &cal),
+///     Some((NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(), NaiveDate::from_ymd_opt(2024, 6, 11).unwrap())),
+/// );
+/// ```
+pub fn observation_shift(
+    period_start: &NaiveDate,
+    period_end: &NaiveDate,
+    lookback_days: u32,
+    calendar: &Calendar,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let shift = |date: &NaiveDate| {
+        let mut shifted = checked_adjust(date, Some(calendar), Some(AdjustRule::Preceding))?;
+        for _ in 0..lookback_days {
+            shifted = sub_adjust(&shifted, calendar)?;
+        }
+        Some(shifted)
+    };
+    Some((shift(period_start)?, shift(period_end)?))
+}
+
+/// How the daily fixings of a compounded-in-arrears rate are observed.
+///
+/// - `lookback_days` — business days between each day and the fixing used
+///   for it.
+/// - `lockout_days` — with `n > 1`, the last `n - 1` business days of the
+///   period reuse the fixing of the `n`-th business day before the period
+///   end (the lockout or rate cut-off date).
+/// - `observation_shift` — if `true`, the whole period is shifted back by
+///   `lookback_days` ([`observation_shift`]) and the day weights come from
+///   the shifted period; if `false` (plain lookback), the weights come from
+///   the interest period itself.
+///
+/// The default observes each day's own fixing with no lookback or lockout.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::RfrConventions;
+///
+/// // SONIA with a five-day observation shift.
+/// let sonia = RfrConventions { lookback_days: 5, observation_shift: true, ..Default::default() };
+/// assert_eq!(sonia.lockout_days, 0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RfrConventions {
+    /// Business days between each observed day and its fixing.
+    pub lookback_days: u32,
+    /// Business days at the end of the period sharing one fixing.
+    pub lockout_days: u32,
+    /// `true` to weight days by the shifted observation period.
+    pub observation_shift: bool,
+}
+
+/// One business day of a compounded-in-arrears interest period.
+///
+/// The period's compounded rate is
+/// `(Π (1 + r(fixing_date) * days / D) - 1) * D / Σ days`, where `D` is the
+/// day count basis (360 for SOFR and €STR, 365 for SONIA).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::RfrObservation;
+///
+/// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+/// let obs = RfrObservation { fixing_date: friday, days: 3 };
+/// assert_eq!(obs.days, 3); // Friday's rate applies over the weekend
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RfrObservation {
+    /// Business day whose published rate applies.
+    pub fixing_date: NaiveDate,
+    /// Calendar days the rate applies for.
+    pub days: u32,
+}
+
+/// Returns the daily observations of a compounded-in-arrears rate over the
+/// interest period `period_start..period_end`: for each business day, the
+/// fixing date to use and the number of calendar days it weighs.
+///
+/// Days are weighted up to the next business day, so a Friday usually
+/// counts 3.  The weights add up to the calendar days of the interest
+/// period, or of the observation period under an observation shift, when
+/// its start is a business day.  An empty period gives no observations.
+/// Returns `None` if a date runs off the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::{rfr_observations, RfrConventions};
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap(); // Thursday
+/// let end   = NaiveDate::from_ymd_opt(2024, 3, 19).unwrap(); // Tuesday
+/// let d = |day| NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+///
+/// // Two-day lookback: Thursday uses Tuesday's fixing, weighted 1 day.
+/// let lookback = RfrConventions { lookback_days: 2, ..Default::default() };
+/// let obs = rfr_observations(&start, &end, &lookback, &cal).unwrap();
+/// let fixings: Vec<_> = obs.iter().map(|o| (o.fixing_date, o.days)).collect();
+/// assert_eq!(fixings, [(d(12), 1), (d(13), 3), (d(14), 1)]);
+/// ```
+pub fn rfr_observations(
+    period_start: &NaiveDate,
+    period_end: &NaiveDate,
+    conventions: &RfrConventions,
+    calendar: &Calendar,
+) -> Option<Vec<RfrObservation>> {
+    let (start, end, lookback) = if conventions.observation_shift {
+        let (start, end) = observation_shift(period_start, period_end, conventions.lookback_days, calendar)?;
+        (start, end, 0)
+    } else {
+        (*period_start, *period_end, conventions.lookback_days)
+    };
+
+    let mut days = Vec::new();
+    let mut date = start;
+    while date < end {
+        if is_business_day(&date, calendar) {
+            days.push(date);
+        }
+        date = date.succ_opt()?;
+    }
+
+    let mut observations = Vec::with_capacity(days.len());
+    for (i, day) in days.iter().enumerate() {
+        let next = days.get(i + 1).copied().unwrap_or(end);
+        let mut fixing_date = *day;
+        for _ in 0..lookback {
+            fixing_date = sub_adjust(&fixing_date, calendar)?;
+        }
+        observations.push(RfrObservation { fixing_date, days: (next - *day).num_days() as u32 });
+    }
+
+    let lockout = conventions.lockout_days as usize;
+    if lockout > 1 && lockout <= observations.len() {
+        let cut_off = observations.len() - lockout;
+        let fixing_date = observations[cut_off].fixing_date;
+        for observation in &mut observations[cut_off..] {
+            observation.fixing_date = fixing_date;
+        }
+    }
+    Some(observations)
+}
+
+/// Returns the first business day of the given month.
+///
+/// Returns `None` if `month` is not a valid month, or if the month has no
+/// business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::first_business_day_of_month;
+///
+/// // 1 June 2024 is a Saturday
+/// let cal = basic_calendar();
+/// assert_eq!(first_business_day_of_month(2024, 6, &cal), NaiveDate::from_ymd_opt(2024, 6, 3));
+/// ```
+pub fn first_business_day_of_month(year: i32, month: u32, calendar: &Calendar) -> Option<NaiveDate> {
+    nth_business_day_of_month(year, month, 1, calendar)
+}
+
+/// Returns the last business day of the given month.
+///
+/// Returns `None` if `month` is not a valid month, or if the month has no
+/// business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::last_business_day_of_month;
+///
+/// // 31 August 2024 is a Saturday
+/// let cal = basic_calendar();
+/// assert_eq!(last_business_day_of_month(2024, 8, &cal), NaiveDate::from_ymd_opt(2024, 8, 30));
+/// ```
+pub fn last_business_day_of_month(year: i32, month: u32, calendar: &Calendar) -> Option<NaiveDate> {
+    let last = last_day_of_month(year, month)?;
+    (1..=last.day())
+        .rev()
+        .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .find(|date| is_business_day(date, calendar))
+}
+
+/// Returns the `n`-th business day of the given month, counting from 1.
+///
+/// Returns `None` if `month` is not a valid month, if `n` is zero, or if the
+/// month has fewer than `n` business days.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::nth_business_day_of_month;
+///
+/// let cal = basic_calendar();
+/// // The fifth business day of June 2024, counting Monday the 3rd as the first
+/// assert_eq!(nth_business_day_of_month(2024, 6, 5, &cal), NaiveDate::from_ymd_opt(2024, 6, 7));
+/// assert_eq!(nth_business_day_of_month(2024, 6, 0, &cal), None);
+/// assert_eq!(nth_business_day_of_month(2024, 6, 21, &cal), None);
+/// ```
+pub fn nth_business_day_of_month(
+    year: i32,
+    month: u32,
+    n: u32,
+    calendar: &Calendar,
+) -> Option<NaiveDate> {
+    let index = n.checked_sub(1)? as usize;
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    first
+        .iter_days()
+        .take_while(|date| date.month() == month)
+        .filter(|date| is_business_day(date, calendar))
+        .nth(index)
+}
+
+/// Returns the number of days in the given month.
+///
+/// Returns `None` if `month` is not in `1..=12` or the month lies outside
+/// the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::algebra::days_in_month;
+///
+/// assert_eq!(days_in_month(2024, 2), Some(29));
+/// assert_eq!(days_in_month(2023, 2), Some(28));
+/// assert_eq!(days_in_month(2024, 4), Some(30));
+/// assert_eq!(days_in_month(2024, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    last_day_of_month(year, month).map(|last| last.day())
+}
+
+/// Returns the last calendar day of `date`'s month.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::end_of_month;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+/// assert_eq!(end_of_month(&date), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+/// ```
+pub fn end_of_month(date: &NaiveDate) -> NaiveDate {
+    // Every valid date's month has a representable last day.
+    last_day_of_month(date.year(), date.month()).unwrap_or(*date)
+}
+
+/// Returns `true` if `date` is the last calendar day of its month.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::is_end_of_month;
+///
+/// assert!(is_end_of_month(&NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()));
+/// assert!(!is_end_of_month(&NaiveDate::from_ymd_opt(2024, 2, 28).unwrap()));
+/// ```
+pub fn is_end_of_month(date: &NaiveDate) -> bool {
+    date.succ_opt().map_or(true, |next| next.month() != date.month())
+}
 
-        DayCount::D30365 => {
-            let res = 360.0 * (end_year - start_year) as f64
-                + 30.0 * (end_month - start_month) as f64
-                + (end_day - start_day) as f64;
-            Ok(res / 365.0)
-        }
+/// Returns `true` if `date` is the last business day of its month in
+/// `calendar`.
+///
+/// This is the "end of month" that [`Schedule`](crate::schedule::Schedule)
+/// recognizes for its end-of-month rule when it has a calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::is_last_business_day_of_month;
+///
+/// // 31 August 2024 is a Saturday
+/// let cal = basic_calendar();
+/// assert!(is_last_business_day_of_month(&NaiveDate::from_ymd_opt(2024, 8, 30).unwrap(), &cal));
+/// assert!(!is_last_business_day_of_month(&NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(), &cal));
+/// ```
+pub fn is_last_business_day_of_month(date: &NaiveDate, calendar: &Calendar) -> bool {
+    is_business_day(date, calendar)
+        && add_adjust(date, calendar).map_or(true, |next| next.month() != date.month())
+}
 
-        DayCount::Bd252 => {
-            let cal = calendar.ok_or(DayCountError::MissingCalendar)?;
-            Ok(business_days_between(
-                &start_adjusted,
-                &end_adjusted,
-                cal,
-                some_adjust_rule,
-            ) as f64 / 252.0)
-        }
-    }
+/// Returns the calendar quarter of `date`, from 1 to 4.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::quarter_of;
+///
+/// assert_eq!(quarter_of(&NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()), 1);
+/// assert_eq!(quarter_of(&NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()), 2);
+/// ```
+pub fn quarter_of(date: &NaiveDate) -> u32 {
+    date.month0() / 3 + 1
 }
 
-/// Adds `years_to_add` years to `date`, returning `None` if the result is out
-/// of range (e.g. Feb 29 in a non-leap target year).
+/// Returns the first day of `date`'s calendar quarter.
+///
+/// # Examples
 ///
-/// chrono does not provide year arithmetic directly; this function fills the gap.
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::quarter_start;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+/// assert_eq!(quarter_start(&date), NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+/// ```
+pub fn quarter_start(date: &NaiveDate) -> NaiveDate {
+    period_start(date, 3)
+}
+
+/// Returns the last day of `date`'s calendar quarter.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use chrono::NaiveDate;
-/// use findates::algebra::checked_add_years;
+/// use findates::algebra::quarter_end;
 ///
-/// let d = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
-/// assert_eq!(
-///     checked_add_years(&d, 1),
-///     NaiveDate::from_ymd_opt(2024, 8, 15)
-/// );
+/// let date = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+/// assert_eq!(quarter_end(&date), NaiveDate::from_ymd_opt(2024, 9, 30).unwrap());
+/// ```
+pub fn quarter_end(date: &NaiveDate) -> NaiveDate {
+    period_end(date, 3)
+}
+
+/// Returns the first day of `date`'s half year: 1 January or 1 July.
 ///
-/// // Feb 29 in a leap year → non-leap target year returns None
-/// let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
-/// assert!(checked_add_years(&leap_day, 1).is_none());
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::semester_start;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 8, 20).unwrap();
+/// assert_eq!(semester_start(&date), NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
 /// ```
-pub fn checked_add_years(date: &NaiveDate, years_to_add: i32) -> Option<NaiveDate> {
-    NaiveDate::from_ymd_opt(
-        date.year() + years_to_add,
-        date.month(),
-        date.day(),
-    )
+pub fn semester_start(date: &NaiveDate) -> NaiveDate {
+    period_start(date, 6)
 }
 
-/// Moves `date` forward by `n` business days in `calendar`.
+/// Returns the last day of `date`'s half year: 30 June or 31 December.
 ///
-/// **Precondition**: `date` must already be a business day.  If it is not,
-/// [`Err(BusinessDayError::InvalidStartDate)`](BusinessDayError::InvalidStartDate)
-/// is returned — including when `n = 0`.  The recommended workflow is to
-/// adjust the start date first, then call this function:
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::semester_end;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+/// assert_eq!(semester_end(&date), NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+/// ```
+pub fn semester_end(date: &NaiveDate) -> NaiveDate {
+    period_end(date, 6)
+}
+
+/// Returns the Monday that starts `date`'s ISO 8601 week.
+///
+/// The week may begin in the previous month or year: use
+/// [`Datelike::iso_week`] for the ISO year and week number.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::iso_week_start;
+///
+/// // Wednesday 1 January 2025 is in ISO week 1 of 2025, which starts in 2024.
+/// let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// assert_eq!(iso_week_start(&date), NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+/// ```
+pub fn iso_week_start(date: &NaiveDate) -> NaiveDate {
+    let offset = u64::from(date.weekday().num_days_from_monday());
+    // Only the first days of the date range have no Monday before them.
+    date.checked_sub_days(Days::new(offset)).unwrap_or(NaiveDate::MIN)
+}
+
+/// Returns the Sunday that ends `date`'s ISO 8601 week.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::iso_week_end;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// assert_eq!(iso_week_end(&date), NaiveDate::from_ymd_opt(2025, 1, 5).unwrap());
+/// ```
+pub fn iso_week_end(date: &NaiveDate) -> NaiveDate {
+    let offset = u64::from(6 - date.weekday().num_days_from_monday());
+    // Only the last days of the date range have no Sunday after them.
+    date.checked_add_days(Days::new(offset)).unwrap_or(NaiveDate::MAX)
+}
+
+/// Returns the first business day of `date`'s calendar quarter.
+///
+/// Returns `None` if the quarter has no business day.
+///
+/// # Examples
 ///
 /// ```rust
 /// use chrono::NaiveDate;
 /// use findates::calendar::basic_calendar;
-/// use findates::conventions::AdjustRule;
-/// use findates::algebra::{adjust, add_business_days};
+/// use findates::algebra::first_business_day_of_quarter;
 ///
+/// // 1 June 2024 is a Saturday, but the quarter started on Monday 1 April.
 /// let cal = basic_calendar();
-/// // Start from a Saturday — adjust to Monday first, then step forward.
-/// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
-/// let monday   = adjust(&saturday, Some(&cal), Some(AdjustRule::Following));
-/// let result   = add_business_days(&monday, 3, &cal).unwrap();
-/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 21).unwrap()); // Thursday
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+/// assert_eq!(first_business_day_of_quarter(&date, &cal), NaiveDate::from_ymd_opt(2024, 4, 1));
 /// ```
+pub fn first_business_day_of_quarter(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    first_business_day_between(quarter_start(date), quarter_end(date), calendar)
+}
+
+/// Returns the last business day of `date`'s calendar quarter, the usual
+/// reporting date for quarter-end valuations.
 ///
-/// When `n = 0` and `date` is a business day, `date` is returned unchanged.
-pub fn add_business_days(
-    date: &NaiveDate,
-    n: u32,
-    calendar: &Calendar,
-) -> Result<NaiveDate, BusinessDayError> {
-    if !is_business_day(date, calendar) {
-        return Err(BusinessDayError::InvalidStartDate);
-    }
-    let mut current = *date;
-    for _ in 0..n {
-        current = add_adjust(&current, calendar);
-    }
-    Ok(current)
+/// Returns `None` if the quarter has no business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::last_business_day_of_quarter;
+///
+/// // 30 June 2024 is a Sunday
+/// let cal = basic_calendar();
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+/// assert_eq!(last_business_day_of_quarter(&date, &cal), NaiveDate::from_ymd_opt(2024, 6, 28));
+/// ```
+pub fn last_business_day_of_quarter(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    last_business_day_between(quarter_start(date), quarter_end(date), calendar)
 }
 
-/// Moves `date` backward by `n` business days in `calendar`.
+/// Returns the first business day of `date`'s half year.
 ///
-/// **Precondition**: `date` must already be a business day.  If it is not,
-/// [`Err(BusinessDayError::InvalidStartDate)`](BusinessDayError::InvalidStartDate)
-/// is returned — including when `n = 0`.  The recommended workflow is to
-/// adjust the start date first, then call this function:
+/// Returns `None` if the half year has no business day.
+///
+/// # Examples
 ///
 /// ```rust
 /// use chrono::NaiveDate;
 /// use findates::calendar::basic_calendar;
-/// use findates::conventions::AdjustRule;
-/// use findates::algebra::{adjust, subtract_business_days};
+/// use findates::algebra::first_business_day_of_semester;
 ///
+/// // 1 July 2023 is a Saturday
 /// let cal = basic_calendar();
-/// // Start from a Saturday — adjust to Monday first, then step backward.
-/// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
-/// let monday   = adjust(&saturday, Some(&cal), Some(AdjustRule::Following));
-/// let result   = subtract_business_days(&monday, 1, &cal).unwrap();
-/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()); // Friday
+/// let date = NaiveDate::from_ymd_opt(2023, 9, 1).unwrap();
+/// assert_eq!(first_business_day_of_semester(&date, &cal), NaiveDate::from_ymd_opt(2023, 7, 3));
 /// ```
+pub fn first_business_day_of_semester(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    first_business_day_between(semester_start(date), semester_end(date), calendar)
+}
+
+/// Returns the last business day of `date`'s half year.
 ///
-/// When `n = 0` and `date` is a business day, `date` is returned unchanged.
-pub fn subtract_business_days(
-    date: &NaiveDate,
-    n: u32,
-    calendar: &Calendar,
-) -> Result<NaiveDate, BusinessDayError> {
-    if !is_business_day(date, calendar) {
-        return Err(BusinessDayError::InvalidStartDate);
+/// Returns `None` if the half year has no business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::last_business_day_of_semester;
+///
+/// let mut cal = basic_calendar();
+/// cal.add_holidays([NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()]);
+/// let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+/// assert_eq!(last_business_day_of_semester(&date, &cal), NaiveDate::from_ymd_opt(2024, 12, 30));
+/// ```
+pub fn last_business_day_of_semester(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    last_business_day_between(semester_start(date), semester_end(date), calendar)
+}
+
+/// Returns the first business day of `date`'s ISO 8601 week.
+///
+/// Returns `None` if the week has no business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::first_business_day_of_iso_week;
+///
+/// // Easter Monday 2024 is a holiday
+/// let mut cal = basic_calendar();
+/// cal.add_holidays([NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()]);
+/// let date = NaiveDate::from_ymd_opt(2024, 4, 5).unwrap();
+/// assert_eq!(first_business_day_of_iso_week(&date, &cal), NaiveDate::from_ymd_opt(2024, 4, 2));
+/// ```
+pub fn first_business_day_of_iso_week(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    first_business_day_between(iso_week_start(date), iso_week_end(date), calendar)
+}
+
+/// Returns the last business day of `date`'s ISO 8601 week.
+///
+/// Returns `None` if the week has no business day.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::algebra::last_business_day_of_iso_week;
+///
+/// // Good Friday 2024 is a holiday
+/// let mut cal = basic_calendar();
+/// cal.add_holidays([NaiveDate::from_ymd_opt(2024, 3, 29).unwrap()]);
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+/// assert_eq!(last_business_day_of_iso_week(&date, &cal), NaiveDate::from_ymd_opt(2024, 3, 28));
+/// ```
+pub fn last_business_day_of_iso_week(date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    last_business_day_between(iso_week_start(date), iso_week_end(date), calendar)
+}
+
+// First day of the block of `months` months, counted from January, that
+// contains `date`.
+fn period_start(date: &NaiveDate, months: u32) -> NaiveDate {
+    let month = date.month0() / months * months + 1;
+    NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap_or(*date)
+}
+
+// Last day of the block of `months` months that contains `date`.
+fn period_end(date: &NaiveDate, months: u32) -> NaiveDate {
+    let month = (date.month0() / months + 1) * months;
+    last_day_of_month(date.year(), month).unwrap_or(*date)
+}
+
+fn first_business_day_between(start: NaiveDate, end: NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    start.iter_days().take_while(|date| *date <= end).find(|date| is_business_day(date, calendar))
+}
+
+fn last_business_day_between(start: NaiveDate, end: NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+    end.iter_days().rev().take_while(|date| *date >= start).find(|date| is_business_day(date, calendar))
+}
+
+/// Returns the `n`-th `weekday` of the given month, counting from 1.
+///
+/// The building block of floating holidays such as "fourth Thursday of
+/// November"; pass the result to [`adjust`] if it must be a business day.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidMonth`] if `month` is not in `1..=12`,
+/// [`DateError::InvalidOrdinal`] if `n` is zero or the month has fewer than
+/// `n` such weekdays, and [`DateError::OutOfRange`] if the month is not
+/// representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::nth_weekday_of_month;
+/// use findates::DateError;
+///
+/// // US Thanksgiving 2024
+/// assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 4),
+///            Ok(NaiveDate::from_ymd_opt(2024, 11, 28).unwrap()));
+/// // November 2024 has only four Thursdays
+/// assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 5), Err(DateError::InvalidOrdinal));
+/// ```
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: u8) -> Result<NaiveDate, DateError> {
+    let last = last_day_of_month_checked(year, month)?;
+    if n == 0 {
+        return Err(DateError::InvalidOrdinal);
     }
-    let mut current = *date;
-    for _ in 0..n {
-        current = sub_adjust(&current, calendar);
+    let first = last.with_day(1).ok_or(DateError::OutOfRange)?;
+    let offset = (7 + weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+    let day = 1 + offset + 7 * (n as u32 - 1);
+    if day > last.day() {
+        return Err(DateError::InvalidOrdinal);
     }
-    Ok(current)
+    last.with_day(day).ok_or(DateError::OutOfRange)
+}
+
+/// Returns the last `weekday` of the given month.
+///
+/// The building block of floating holidays such as "last Monday of May".
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidMonth`] if `month` is not in `1..=12`, and
+/// [`DateError::OutOfRange`] if the month is not representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::last_weekday_of_month;
+///
+/// // US Memorial Day 2024
+/// assert_eq!(last_weekday_of_month(2024, 5, Weekday::Mon),
+///            Ok(NaiveDate::from_ymd_opt(2024, 5, 27).unwrap()));
+/// ```
+pub fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Result<NaiveDate, DateError> {
+    let last = last_day_of_month_checked(year, month)?;
+    let offset = (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+    last.with_day(last.day() - offset).ok_or(DateError::OutOfRange)
+}
+
+/// Returns Easter Sunday of `year` in the Gregorian calendar (Western
+/// Easter).
+///
+/// The anchor of the moveable feasts — Good Friday, Easter Monday, Carnival,
+/// Ascension, Whit Monday and Corpus Christi are fixed offsets from it.
+///
+/// # Errors
+///
+/// Returns [`DateError::OutOfRange`] if the date is not representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{Days, NaiveDate};
+/// use findates::algebra::easter_sunday;
+///
+/// let easter = easter_sunday(2024).unwrap();
+/// assert_eq!(easter, NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+///
+/// let good_friday = easter - Days::new(2);
+/// assert_eq!(good_friday, NaiveDate::from_ymd_opt(2024, 3, 29).unwrap());
+/// ```
+pub fn easter_sunday(year: i32) -> Result<NaiveDate, DateError> {
+    // Anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+    let y = year as i64;
+    let a = y.rem_euclid(19);
+    let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).ok_or(DateError::OutOfRange)
+}
+
+/// Returns the local date in `tz` of the Unix timestamp `secs`, counted in
+/// seconds since 1970-01-01 00:00:00 UTC.
+///
+/// Works with any [`TimeZone`]: [`Utc`](chrono::Utc),
+/// [`FixedOffset`](chrono::FixedOffset), or a `chrono_tz::Tz` with the `tz`
+/// feature.
+///
+/// # Errors
+///
+/// Returns [`DateError::OutOfRange`] if the timestamp is outside the
+/// representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveDate, Utc};
+/// use findates::algebra::date_from_timestamp;
+///
+/// // 2024-03-15 22:30:00 UTC
+/// let secs = 1_710_541_800;
+/// assert_eq!(date_from_timestamp(secs, &Utc).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+///
+/// // Already the next day in Tokyo.
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+/// assert_eq!(date_from_timestamp(secs, &tokyo).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 16).unwrap());
+/// ```
+pub fn date_from_timestamp<Tz: TimeZone>(secs: i64, tz: &Tz) -> Result<NaiveDate, DateError> {
+    let datetime = tz.timestamp_opt(secs, 0).single().ok_or(DateError::OutOfRange)?;
+    Ok(datetime.date_naive())
+}
+
+/// Returns the Unix timestamp, in seconds, of `date` at local time `time` in
+/// `tz` — e.g. midnight in New York with [`NaiveTime::MIN`](chrono::NaiveTime::MIN).
+///
+/// A local time repeated by a daylight saving change resolves to its first
+/// occurrence.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidLocalTime`] if `time` does not exist on
+/// `date` in `tz` because a daylight saving change skips it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveDate, NaiveTime, Utc};
+/// use findates::algebra::{date_from_timestamp, timestamp_at};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+/// assert_eq!(timestamp_at(&date, NaiveTime::MIN, &Utc).unwrap(), 1_710_460_800);
+///
+/// // Midnight in New York (UTC-5 in winter) is five hours later.
+/// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let secs = timestamp_at(&date, NaiveTime::MIN, &new_york).unwrap();
+/// assert_eq!(secs, 1_710_460_800 + 5 * 3600);
+/// assert_eq!(date_from_timestamp(secs, &new_york).unwrap(), date);
+/// ```
+pub fn timestamp_at<Tz: TimeZone>(date: &NaiveDate, time: NaiveTime, tz: &Tz) -> Result<i64, DateError> {
+    tz.from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|datetime| datetime.timestamp())
+        .ok_or(DateError::InvalidLocalTime)
+}
+
+// `last_day_of_month` with the reason for a missing date.
+fn last_day_of_month_checked(year: i32, month: u32) -> Result<NaiveDate, DateError> {
+    if !(1..=12).contains(&month) {
+        return Err(DateError::InvalidMonth);
+    }
+    last_day_of_month(year, month).ok_or(DateError::OutOfRange)
 }
 
 fn is_leap_year(year: i32) -> bool {
     NaiveDate::from_ymd_opt(year, 2, 29).is_some()
 }
 
+// Day number on a calendar where every year has 365 days; 29 February
+// shares its number with 28 February.
+fn no_leap_serial(date: NaiveDate) -> i64 {
+    const MONTH_OFFSET: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let day = if date.month() == 2 && date.day() == 29 { 28 } else { date.day() };
+    date.year() as i64 * 365 + MONTH_OFFSET[date.month0() as usize] + day as i64
+}
+
+pub(crate) fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    let first_of_next = if month == 12 {
+        year.checked_add(1).and_then(|y| NaiveDate::from_ymd_opt(y, 1, 1))
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    match first_of_next {
+        Some(d) => d.pred_opt(),
+        // 31 December of the last representable year
+        None => NaiveDate::from_ymd_opt(year, month, 31),
+    }
+}
+
 fn is_last_day_of_february(date: NaiveDate) -> bool {
     date.month() == 2 && (date.day() == 29 || (date.day() == 28 && !is_leap_year(date.year())))
 }
@@ -0,0 +1,274 @@
+//! [proptest](https://docs.rs/proptest) strategies for findates types.
+//!
+//! Implements [`Arbitrary`] for [`Calendar`], [`DayCount`], [`AdjustRule`],
+//! [`Frequency`], [`RollConvention`], [`StubRule`], [`Rounding`], [`Tenor`]
+//! and [`ScheduleOwned`], so that `any::<T>()` generates random but valid values,
+//! and adds date strategies.  Use them to check your own invariants over
+//! findates types:
+//!
+//! ```rust
+//! use findates::arbitrary::dates;
+//! use findates::calendar::Calendar;
+//! use findates::conventions::AdjustRule;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     // Mark with #[test] in a test module.
+//!     fn following_is_a_business_day(date in dates(), cal in any::<Calendar>()) {
+//!         let adjusted = findates::algebra::adjust(&date, Some(&cal), Some(AdjustRule::Following));
+//!         prop_assert!(cal.is_business_day(&adjusted));
+//!     }
+//! }
+//! following_is_a_business_day();
+//! ```
+//!
+//! Generated calendars always keep at least one weekday as a business day,
+//! generated frequencies never have a zero count, and dates fall between
+//! 1950 and 2099, far enough from the ends of the `NaiveDate` range that
+//! schedule arithmetic does not overflow.
+//!
+//! Requires the `proptest` feature.
+
+use std::sync::Arc;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+
+use crate::calendar::Calendar;
+use crate::conventions::{AdjustRule, DayCount, Frequency, RollConvention, Rounding, RoundingDirection, StubRule, Tenor};
+use crate::schedule::ScheduleOwned;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Strategy for dates from 1 January 1950 to 31 December 2099.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::arbitrary::dates;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let date = dates().new_tree(&mut TestRunner::default()).unwrap().current();
+/// assert!((1950..2100).contains(&chrono::Datelike::year(&date)));
+/// ```
+pub fn dates() -> BoxedStrategy<NaiveDate> {
+    let start = NaiveDate::from_ymd_opt(1950, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2099, 12, 31).unwrap();
+    dates_between(start, end)
+}
+
+/// Strategy for dates from `start` to `end` inclusive.
+///
+/// # Panics
+///
+/// Panics if `end` is before `start`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::arbitrary::dates_between;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// let date  = dates_between(start, end).new_tree(&mut TestRunner::default()).unwrap().current();
+/// assert!(start <= date && date <= end);
+/// ```
+pub fn dates_between(start: NaiveDate, end: NaiveDate) -> BoxedStrategy<NaiveDate> {
+    assert!(start <= end, "end date must not be before start date");
+    (start.num_days_from_ce()..=end.num_days_from_ce())
+        .prop_map(|days| NaiveDate::from_num_days_from_ce_opt(days).unwrap())
+        .boxed()
+}
+
+impl Arbitrary for Calendar {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Calendars with up to six weekend days and up to 64 holidays between
+    /// 1950 and 2099.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (subsequence(WEEKDAYS.to_vec(), 0..7), prop::collection::vec(dates(), 0..64))
+            .prop_map(|(weekend, holidays)| {
+                let mut cal = Calendar::with_weekends(weekend);
+                cal.add_holidays(holidays);
+                cal
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for DayCount {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(vec![
+            DayCount::Act360,
+            DayCount::Act365,
+            DayCount::Act365Fixed,
+            DayCount::Act365_25,
+            DayCount::NL365,
+            DayCount::Bd252,
+            DayCount::ActActISDA,
+            DayCount::ActActICMA,
+            DayCount::ActActAFB,
+            DayCount::D30360Euro,
+            DayCount::Thirty360US,
+            DayCount::Thirty360ISDA,
+            DayCount::ThirtyE360ISDA,
+            DayCount::D30365,
+        ])
+        .boxed()
+    }
+}
+
+impl Arbitrary for AdjustRule {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(vec![
+            AdjustRule::Following,
+            AdjustRule::ModFollowing,
+            AdjustRule::Preceding,
+            AdjustRule::ModPreceding,
+            AdjustRule::Unadjusted,
+            AdjustRule::HalfMonthModFollowing,
+            AdjustRule::Nearest,
+        ])
+        .boxed()
+    }
+}
+
+impl Arbitrary for Frequency {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Named frequencies, and parametric ones of up to 24 months, 26 weeks
+    /// or 60 days.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            select(vec![
+                Frequency::Zero,
+                Frequency::Annual,
+                Frequency::Semiannual,
+                Frequency::EveryFourthMonth,
+                Frequency::Quarterly,
+                Frequency::Bimonthly,
+                Frequency::Monthly,
+                Frequency::EndOfMonth,
+                Frequency::EveryFourthWeek,
+                Frequency::Biweekly,
+                Frequency::Weekly,
+                Frequency::Daily,
+            ]),
+            (1..=24u32).prop_map(Frequency::EveryNMonths),
+            (1..=26u32).prop_map(Frequency::EveryNWeeks),
+            (1..=60u32).prop_map(Frequency::EveryNDays),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for RollConvention {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            (1..=31u32).prop_map(RollConvention::Day),
+            Just(RollConvention::EndOfMonth),
+            Just(RollConvention::Imm),
+            Just(RollConvention::Cds),
+            select(WEEKDAYS.to_vec()).prop_map(RollConvention::DayOfWeek),
+            Just(RollConvention::FirstBusinessDay),
+            Just(RollConvention::LastBusinessDay),
+            (1..=31u32).prop_map(RollConvention::NthBusinessDay),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for StubRule {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(vec![StubRule::ShortFront, StubRule::LongFront, StubRule::ShortBack, StubRule::LongBack]).boxed()
+    }
+}
+
+impl Arbitrary for Rounding {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Up to 15 decimal places, the precision of an `f64` year fraction.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            0..=15u32,
+            select(vec![RoundingDirection::Nearest, RoundingDirection::Up, RoundingDirection::Down]),
+        )
+            .prop_map(|(decimals, direction)| Rounding::new(decimals, direction))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Tenor {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Tenors of up to a year of days, two years of weeks, 30 years of
+    /// months or 50 years.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            (0..=365u32).prop_map(Tenor::Days),
+            (0..=104u32).prop_map(Tenor::Weeks),
+            (0..=360u32).prop_map(Tenor::Months),
+            (0..=50u32).prop_map(Tenor::Years),
+            Just(Tenor::Overnight),
+            Just(Tenor::TomorrowNext),
+            Just(Tenor::SpotNext),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for ScheduleOwned {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            any::<Frequency>(),
+            any::<Option<Calendar>>(),
+            any::<Option<AdjustRule>>(),
+            any::<bool>(),
+            any::<Option<RollConvention>>(),
+            any::<Option<StubRule>>(),
+            any::<Option<Rounding>>(),
+        )
+            .prop_map(|(frequency, calendar, adjust_rule, eom, roll, stub, rounding)| ScheduleOwned {
+                frequency,
+                calendar: calendar.map(Arc::new),
+                adjust_rule,
+                eom,
+                roll,
+                stub,
+                rounding,
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,188 @@
+//! The `findates` command-line tool: adjust dates, compute day count
+//! fractions, build coupon schedules, count business days and list holidays
+//! without writing Rust.
+//!
+//! ```text
+//! findates adjust 2024-03-30 --calendar TARGET --rule MF
+//! findates dcf 2024-01-15 2024-07-15 --daycount 30E/360
+//! findates schedule 2024-01-15 2026-01-15 --frequency 6M --calendar holidays.csv
+//! findates busdays 2024-01-01 2025-01-01 --calendar calendar.json
+//! findates holidays TARGET 2024
+//! ```
+//!
+//! A calendar is either a file or an expression over the built-in calendars:
+//!
+//! - `*.json` — a calendar as written by `Calendar::to_json_writer`.
+//! - `*.csv` — a holiday list as read by `Calendar::from_csv`, with Saturday
+//!   and Sunday as the weekend.
+//! - `WE` (weekends only), `TARGET`, `UK`, `JP` or `B3`, combined with `|`
+//!   and `&` as in the `registry` module, e.g. `TARGET|UK`.
+//!
+//! Requires the `cli` feature: `cargo install findates --features cli`.
+
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+use std::process::ExitCode;
+
+use chrono::{NaiveDate, Weekday};
+use clap::{Parser, Subcommand};
+use findates::algebra;
+use findates::calendar::{basic_calendar, Calendar};
+use findates::conventions::{AdjustRule, DayCount, Frequency};
+use findates::registry::CalendarRegistry;
+use findates::schedule::Schedule;
+
+#[derive(Parser)]
+#[command(name = "findates", version, about = "Financial date arithmetic from the command line")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Adjust dates to business days
+    Adjust {
+        #[arg(required = true)]
+        dates: Vec<NaiveDate>,
+        /// Calendar file or expression
+        #[arg(short, long, default_value = "WE")]
+        calendar: String,
+        /// Business day convention, e.g. Following, MF, Preceding
+        #[arg(short, long, default_value = "Following")]
+        rule: AdjustRule,
+    },
+    /// Print the day count fraction between two dates
+    Dcf {
+        start: NaiveDate,
+        end: NaiveDate,
+        /// Day count convention, e.g. ACT/360, ACT/365F, 30E/360
+        #[arg(short, long, default_value = "ACT/360")]
+        daycount: DayCount,
+        /// Calendar file or expression, for BUS/252 or to adjust the dates first
+        #[arg(short, long)]
+        calendar: Option<String>,
+        /// Business day convention applied to both dates
+        #[arg(short, long)]
+        rule: Option<AdjustRule>,
+    },
+    /// Print the coupon periods from an effective date to maturity as CSV
+    Schedule {
+        effective: NaiveDate,
+        maturity: NaiveDate,
+        /// Payment frequency, e.g. 3M, 6M, Annual
+        #[arg(short, long)]
+        frequency: Frequency,
+        /// Calendar file or expression
+        #[arg(short, long, default_value = "WE")]
+        calendar: String,
+        /// Business day convention for accrual dates
+        #[arg(short, long, default_value = "ModFollowing")]
+        rule: AdjustRule,
+        /// Day count convention for the year fractions
+        #[arg(short, long, default_value = "ACT/360")]
+        daycount: DayCount,
+        /// Business days from accrual end to payment
+        #[arg(long, default_value_t = 0)]
+        pay_lag: u32,
+        /// Roll on month ends when the effective date is one
+        #[arg(long)]
+        eom: bool,
+    },
+    /// Count business days from start (included) to end (excluded)
+    Busdays {
+        start: NaiveDate,
+        end: NaiveDate,
+        /// Calendar file or expression
+        #[arg(short, long, default_value = "WE")]
+        calendar: String,
+    },
+    /// List a calendar's holidays in a year as CSV
+    Holidays {
+        /// Calendar file or expression
+        calendar: String,
+        year: i32,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("findates: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Adjust { dates, calendar, rule } => {
+            let cal = load_calendar(&calendar)?;
+            for date in dates {
+                let adjusted = algebra::try_adjust(&date, Some(&cal), Some(rule))?;
+                println!("{adjusted}");
+            }
+        }
+        Command::Dcf { start, end, daycount, calendar, rule } => {
+            let cal = calendar.as_deref().map(load_calendar).transpose()?;
+            println!("{}", algebra::try_day_count_fraction(&start, &end, daycount, cal.as_ref(), rule)?);
+        }
+        Command::Schedule { effective, maturity, frequency, calendar, rule, daycount, pay_lag, eom } => {
+            let cal = load_calendar(&calendar)?;
+            let sched = Schedule::new(frequency, Some(&cal), Some(rule)).with_eom(eom);
+            println!("accrual_start,accrual_end,payment_date,year_fraction");
+            for period in sched.coupon_periods(&effective, &maturity, daycount, pay_lag)? {
+                println!(
+                    "{},{},{},{}",
+                    period.accrual_start, period.accrual_end, period.payment_date, period.year_fraction
+                );
+            }
+        }
+        Command::Busdays { start, end, calendar } => {
+            let cal = load_calendar(&calendar)?;
+            println!("{}", algebra::business_days_between(&start, &end, &cal, Some(AdjustRule::Unadjusted)));
+        }
+        Command::Holidays { calendar, year } => {
+            let cal = load_calendar(&calendar)?;
+            println!("date,name");
+            for date in cal.holidays_in_year(year) {
+                println!("{date},{}", cal.holiday_name(&date).unwrap_or_default());
+            }
+        }
+    }
+    Ok(())
+}
+
+// Reads a calendar file, or parses an expression over the built-in calendars.
+fn load_calendar(spec: &str) -> Result<Calendar, Box<dyn Error>> {
+    let path = Path::new(spec);
+    let extension = path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase);
+    let open = || File::open(path).map_err(|err| format!("cannot open {spec}: {err}"));
+    match extension.as_deref() {
+        Some("json") => Ok(Calendar::from_json_reader(open()?)?),
+        Some("csv") => {
+            let mut cal = Calendar::from_csv(open()?)?;
+            cal.add_weekends([Weekday::Sat, Weekday::Sun]);
+            Ok(cal)
+        }
+        _ => Ok(builtin_calendars().parse(spec)?),
+    }
+}
+
+fn builtin_calendars() -> CalendarRegistry {
+    let years = 1970..=2099;
+    let builtins = [
+        ("WE", basic_calendar()),
+        ("TARGET", Calendar::target2()),
+        ("UK", Calendar::uk_bank_holidays(years.clone())),
+        ("JP", Calendar::japan(years.clone())),
+        ("B3", Calendar::brazil_b3(years)),
+    ];
+    let mut registry = CalendarRegistry::new();
+    for (code, calendar) in builtins {
+        registry.register(code, calendar).expect("built-in calendar codes are valid");
+    }
+    registry
+}
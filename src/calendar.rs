@@ -7,11 +7,45 @@
 //! Calendars can be combined with [`Calendar::union`] (useful when a trade
 //! settles in two jurisdictions) or [`Calendar::intersection`] (useful when
 //! only days that are holidays in *both* calendars should be excluded).
+//! Both mutate `self`; [`Calendar::unioned`] and [`Calendar::intersected`]
+//! (or the `|` and `&` operators on `&Calendar`) return new calendars instead.
+//! [`Calendar::difference`] and [`Calendar::symmetric_difference`] return new
+//! calendars showing where two calendars disagree.
+//!
+//! Holiday lists maintained outside the binary can be loaded at runtime with
+//! [`Calendar::from_csv`] (one date per row) or, with the `json` feature,
+//! [`Calendar::from_json_reader`].
+//!
+//! For hot loops, [`Calendar::compile`] precomputes a
+//! [`CompiledCalendar`](crate::compiled::CompiledCalendar) answering business
+//! day queries in constant time.
+//!
+//! Holidays may carry a name and category ([`HolidayInfo`]), added with
+//! [`Calendar::add_named_holiday`] and queried with [`Calendar::holiday_name`].
+//!
+//! Half trading days (e.g. Christmas Eve) are recorded as early closes with
+//! [`Calendar::add_early_close`] and queried with [`Calendar::close_time`].
+//! They remain business days.
+//!
+//! For logs and reports, a calendar's `Display` form lists its weekend days
+//! and holidays by year, and [`Calendar::summary`] counts business days and
+//! holidays per month.
+//!
+//! Before rolling out a calendar update, [`diff_report`] lists the holidays it
+//! adds or removes and the generated schedule dates that would move.
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use chrono::Weekday;
-use std::borrow::Borrow;
-use std::collections::HashSet;
+use std::borrow::{Borrow, Cow};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::ops::{BitAnd, BitOr, Deref};
+use std::sync::OnceLock;
+
+use crate::conventions::AdjustRule;
+use crate::error::{CalendarError, CalendarIoError, DateError};
+use crate::schedule::GeneratedDate;
 
 /// A business-day calendar.
 ///
@@ -22,6 +56,14 @@ use std::collections::HashSet;
 /// A date is a good business day if and only if it is neither in `weekend`
 /// nor in `holidays`.
 ///
+/// Holidays may optionally carry a [`HolidayInfo`] (name and category).  The
+/// metadata is descriptive only: it never affects business day checks.
+/// Likewise, early closes only record a closing time for business days
+/// that end ahead of the regular session.
+///
+/// Listings, `Debug` output and serialized calendars give holidays in date
+/// order, so they are the same from run to run.
+///
 /// # Examples
 ///
 /// ```rust
@@ -35,11 +77,347 @@ use std::collections::HashSet;
 ///
 /// assert!(cal.get_holidays().contains(&xmas));
 /// ```
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CalendarData"))]
 pub struct Calendar {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_weekend"))]
+    weekend: HashSet<Weekday>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_holidays"))]
+    holidays: Holidays,
+    // Metadata for a subset of `holidays`; every key is also in `holidays`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    holiday_info: BTreeMap<NaiveDate, HolidayInfo>,
+    // Dates the holiday data covers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_valid_range", skip_serializing_if = "ValidRange::is_unbounded")
+    )]
+    valid_range: ValidRange,
+    // Closing times of business days that end early.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    early_closes: BTreeMap<NaiveDate, NaiveTime>,
+}
+
+impl PartialEq for Calendar {
+    fn eq(&self, other: &Self) -> bool {
+        self.weekend == other.weekend
+            && self.holidays.dates == other.holidays.dates
+            && self.holiday_info == other.holiday_info
+            && self.valid_range == other.valid_range
+            && self.early_closes == other.early_closes
+    }
+}
+
+impl Eq for Calendar {}
+
+impl fmt::Debug for Calendar {
+    // Weekend days in week order and holidays in date order, so the output
+    // is deterministic.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Calendar")
+            .field("weekend", &sorted_weekend(&self.weekend))
+            .field("holidays", &self.holidays)
+            .field("holiday_info", &self.holiday_info)
+            .field("valid_range", &self.valid_range)
+            .field("early_closes", &self.early_closes)
+            .finish()
+    }
+}
+
+impl fmt::Display for Calendar {
+    /// Lists the weekend days, then the holidays grouped by year, one per
+    /// line with the holiday name when one is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// cal.add_named_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), "Christmas Day");
+    /// cal.add_holidays([NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()]);
+    ///
+    /// assert_eq!(
+    ///     cal.to_string(),
+    ///     "Weekend: Sat, Sun\n2024:\n  2024-12-25 Christmas Day\n2025:\n  2025-01-01"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let weekend: Vec<String> = sorted_weekend(&self.weekend).iter().map(Weekday::to_string).collect();
+        if weekend.is_empty() {
+            write!(f, "Weekend: none")?;
+        } else {
+            write!(f, "Weekend: {}", weekend.join(", "))?;
+        }
+        let mut year = None;
+        for date in self.iter_holidays_sorted() {
+            if year != Some(date.year()) {
+                year = Some(date.year());
+                write!(f, "\n{}:", date.year())?;
+            }
+            write!(f, "\n  {date}")?;
+            if let Some(name) = self.holiday_name(&date) {
+                write!(f, " {name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Weekend days from Monday to Sunday.
+fn sorted_weekend(weekend: &HashSet<Weekday>) -> Vec<Weekday> {
+    let mut days: Vec<Weekday> = weekend.iter().copied().collect();
+    days.sort_by_key(Weekday::num_days_from_monday);
+    days
+}
+
+// A calendar's holiday dates, kept in date order so listings and range
+// queries need no sorting.  Reads go through `Deref` to the ordered set; the
+// `HashSet` handed out by `Calendar::get_holidays` is built on first use and
+// dropped whenever the dates change.
+#[derive(Clone, Default)]
+struct Holidays {
+    dates: BTreeSet<NaiveDate>,
+    hashed: OnceLock<HashSet<NaiveDate>>,
+}
+
+impl Holidays {
+    fn as_hash_set(&self) -> &HashSet<NaiveDate> {
+        self.hashed.get_or_init(|| self.dates.iter().copied().collect())
+    }
+
+    // Mutable access to the dates, invalidating the hashed copy.
+    fn dates_mut(&mut self) -> &mut BTreeSet<NaiveDate> {
+        self.hashed.take();
+        &mut self.dates
+    }
+}
+
+impl Deref for Holidays {
+    type Target = BTreeSet<NaiveDate>;
+
+    fn deref(&self) -> &BTreeSet<NaiveDate> {
+        &self.dates
+    }
+}
+
+impl PartialEq<HashSet<NaiveDate>> for Holidays {
+    fn eq(&self, other: &HashSet<NaiveDate>) -> bool {
+        self.as_hash_set() == other
+    }
+}
+
+impl fmt::Debug for Holidays {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.dates.fmt(f)
+    }
+}
+
+impl FromIterator<NaiveDate> for Holidays {
+    fn from_iter<I: IntoIterator<Item = NaiveDate>>(iter: I) -> Self {
+        Holidays {
+            dates: iter.into_iter().collect(),
+            hashed: OnceLock::new(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_weekend<S: serde::Serializer>(weekend: &HashSet<Weekday>, serializer: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&sorted_weekend(weekend), serializer)
+}
+
+// Dates a calendar's holiday data covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValidRange {
+    // Every date: no range has been set.
+    Unbounded,
+    // The inclusive range `start..=end`.
+    Bounded(NaiveDate, NaiveDate),
+    // No date at all, after combining calendars whose ranges do not
+    // intersect.
+    Empty,
+}
+
+impl ValidRange {
+    #[cfg(feature = "serde")]
+    fn is_unbounded(&self) -> bool {
+        *self == ValidRange::Unbounded
+    }
+
+    fn contains(&self, date: &NaiveDate) -> bool {
+        match self {
+            ValidRange::Unbounded => true,
+            ValidRange::Bounded(start, end) => start <= date && date <= end,
+            ValidRange::Empty => false,
+        }
+    }
+
+    // Dates covered by both ranges.
+    fn overlap(self, other: ValidRange) -> ValidRange {
+        match (self, other) {
+            (ValidRange::Empty, _) | (_, ValidRange::Empty) => ValidRange::Empty,
+            (ValidRange::Bounded(a_start, a_end), ValidRange::Bounded(b_start, b_end)) => {
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if end < start {
+                    ValidRange::Empty
+                } else {
+                    ValidRange::Bounded(start, end)
+                }
+            }
+            (range, ValidRange::Unbounded) | (ValidRange::Unbounded, range) => range,
+        }
+    }
+}
+
+// Quotes a CSV field that would not read back as written, doubling any
+// quotes inside it.
+fn quote_csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+// Reads the rest of a quoted CSV field whose opening quote has been
+// consumed, pulling in further lines while the quotes are open.  `None` if
+// the input ends before the closing quote, or text other than whitespace
+// follows it.
+fn unquote_csv_field<I>(first: &str, lines: &mut I) -> std::io::Result<Option<String>>
+where
+    I: Iterator<Item = (usize, std::io::Result<String>)>,
+{
+    let mut field = String::new();
+    let mut rest = first.to_string();
+    loop {
+        let mut chars = rest.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '"' {
+                field.push(c);
+            } else if chars.next_if_eq(&'"').is_some() {
+                field.push('"');
+            } else {
+                return Ok(chars.all(char::is_whitespace).then_some(field));
+            }
+        }
+        match lines.next() {
+            Some((_, line)) => rest = line?,
+            None => return Ok(None),
+        }
+        field.push('\n');
+    }
+}
+
+// A bounded range is written as `[start, end]` and an empty one as `[]`.
+#[cfg(feature = "serde")]
+fn serialize_valid_range<S: serde::Serializer>(range: &ValidRange, serializer: S) -> Result<S::Ok, S::Error> {
+    let dates = match range {
+        ValidRange::Bounded(start, end) => vec![*start, *end],
+        ValidRange::Unbounded | ValidRange::Empty => Vec::new(),
+    };
+    serde::Serialize::serialize(&dates, serializer)
+}
+
+#[cfg(feature = "serde")]
+fn serialize_holidays<S: serde::Serializer>(holidays: &Holidays, serializer: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&holidays.dates, serializer)
+}
+
+// Deserialized form of a `Calendar`, checked against the invariants the
+// mutating methods maintain before it becomes one.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CalendarData {
     weekend: HashSet<Weekday>,
-    holidays: HashSet<NaiveDate>,
+    holidays: BTreeSet<NaiveDate>,
+    #[serde(default)]
+    holiday_info: BTreeMap<NaiveDate, HolidayInfo>,
+    #[serde(default)]
+    valid_range: Option<Vec<NaiveDate>>,
+    #[serde(default)]
+    early_closes: BTreeMap<NaiveDate, NaiveTime>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CalendarData> for Calendar {
+    type Error = CalendarError;
+
+    fn try_from(data: CalendarData) -> Result<Self, CalendarError> {
+        if let Some(date) = data.holiday_info.keys().find(|date| !data.holidays.contains(date)) {
+            return Err(CalendarError::UnknownHoliday { date: *date });
+        }
+        let mut cal = Calendar {
+            weekend: data.weekend,
+            holidays: data.holidays.into_iter().collect(),
+            holiday_info: data.holiday_info,
+            valid_range: ValidRange::Unbounded,
+            early_closes: data.early_closes,
+        };
+        match data.valid_range.as_deref() {
+            None => {}
+            Some([]) => cal.valid_range = ValidRange::Empty,
+            Some([start, end]) => cal.set_valid_range(*start, *end)?,
+            Some(_) => return Err(CalendarError::InvalidRange),
+        }
+        Ok(cal)
+    }
+}
+
+/// Descriptive metadata attached to a holiday date.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::calendar::HolidayInfo;
+///
+/// let info = HolidayInfo::new("Christmas Day").with_category("Bank");
+/// assert_eq!(info.name, "Christmas Day");
+/// assert_eq!(info.category.as_deref(), Some("Bank"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HolidayInfo {
+    /// Holiday name, e.g. `"Christmas Day"`.
+    pub name: String,
+    /// Optional category, e.g. `"Bank"` or `"Exchange"`.
+    pub category: Option<String>,
+}
+
+impl HolidayInfo {
+    /// Creates holiday metadata with a name and no category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::HolidayInfo;
+    ///
+    /// let info = HolidayInfo::new("Boxing Day");
+    /// assert_eq!(info.category, None);
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            category: None,
+        }
+    }
+
+    /// Returns the metadata with its category set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::HolidayInfo;
+    ///
+    /// let info = HolidayInfo::new("Good Friday").with_category("Exchange");
+    /// assert_eq!(info.category.as_deref(), Some("Exchange"));
+    /// ```
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
 }
 
 impl Default for Calendar {
@@ -71,7 +449,10 @@ impl Default for Calendar {
 pub fn basic_calendar() -> Calendar {
     Calendar {
         weekend: [Weekday::Sat, Weekday::Sun].into_iter().collect(),
-        holidays: HashSet::new(),
+        holidays: Holidays::default(),
+        holiday_info: BTreeMap::new(),
+        valid_range: ValidRange::Unbounded,
+        early_closes: BTreeMap::new(),
     }
 }
 
@@ -116,7 +497,10 @@ impl Calendar {
     pub fn new() -> Self {
         Self {
             weekend: HashSet::new(),
-            holidays: HashSet::new(),
+            holidays: Holidays::default(),
+            holiday_info: BTreeMap::new(),
+            valid_range: ValidRange::Unbounded,
+            early_closes: BTreeMap::new(),
         }
     }
 
@@ -169,8 +553,84 @@ impl Calendar {
         calendar
     }
 
+    /// Construct a calendar with a Saturday/Sunday weekend and no holidays.
+    ///
+    /// Same as [`basic_calendar`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::{basic_calendar, Calendar};
+    ///
+    /// assert_eq!(Calendar::weekend_only(), basic_calendar());
+    /// ```
+    pub fn weekend_only() -> Self {
+        Self::with_weekends([Weekday::Sat, Weekday::Sun])
+    }
+
+    /// Construct a calendar with a Friday/Saturday weekend and no holidays,
+    /// as used in Israel and several Middle Eastern markets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::middle_east_weekend();
+    /// assert!(!cal.is_business_day(&NaiveDate::from_ymd_opt(2024, 3, 15).unwrap())); // Friday
+    /// assert!(cal.is_business_day(&NaiveDate::from_ymd_opt(2024, 3, 17).unwrap()));  // Sunday
+    /// ```
+    pub fn middle_east_weekend() -> Self {
+        Self::with_weekends([Weekday::Fri, Weekday::Sat])
+    }
+
+    /// Construct a calendar from its working days: every other weekday is a
+    /// weekend day.  The calendar has no holidays.
+    ///
+    /// Accepts any iterable of weekdays, including borrowed collections.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::NoBusinessDays`] if `workweek` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Weekday;
+    /// use findates::calendar::Calendar;
+    ///
+    /// // A Monday to Thursday working week
+    /// let cal = Calendar::with_workweek([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu]).unwrap();
+    /// assert_eq!(cal.get_weekend().len(), 3);
+    /// assert!(cal.get_weekend().contains(&Weekday::Fri));
+    /// ```
+    pub fn with_workweek<I>(workweek: I) -> Result<Self, CalendarError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Weekday>,
+    {
+        let working: HashSet<Weekday> = workweek.into_iter().map(|day| *day.borrow()).collect();
+        if working.is_empty() {
+            return Err(CalendarError::NoBusinessDays);
+        }
+        let mut calendar = Self::new();
+        let mut day = Weekday::Mon;
+        for _ in 0..7 {
+            if !working.contains(&day) {
+                calendar.weekend.insert(day);
+            }
+            day = day.succ();
+        }
+        Ok(calendar)
+    }
+
     /// Returns a reference to the set of holiday dates.
     ///
+    /// The set is built from the calendar's ordered holidays on the first
+    /// call after they change.  Use [`Calendar::iter_holidays_sorted`] to
+    /// walk them in date order without building it.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -183,7 +643,28 @@ impl Calendar {
     /// assert!(cal.get_holidays().contains(&d));
     /// ```
     pub fn get_holidays(&self) -> &HashSet<NaiveDate> {
-        &self.holidays
+        self.holidays.as_hash_set()
+    }
+
+    /// Iterates over the holiday dates in ascending order, unlike
+    /// [`Calendar::get_holidays`] whose order is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let dates = [(2024, 12, 26), (2024, 1, 1), (2024, 12, 25)]
+    ///     .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    /// let cal = Calendar::with_holidays(dates);
+    ///
+    /// let sorted: Vec<NaiveDate> = cal.iter_holidays_sorted().collect();
+    /// assert_eq!(sorted, [dates[1], dates[2], dates[0]]);
+    /// assert_eq!(cal.iter_holidays_sorted().next_back(), Some(dates[0]));
+    /// ```
+    pub fn iter_holidays_sorted(&self) -> impl DoubleEndedIterator<Item = NaiveDate> + '_ {
+        self.holidays.iter().copied()
     }
 
     /// Returns a reference to the set of non-working weekdays.
@@ -224,112 +705,1455 @@ impl Calendar {
         I::Item: Borrow<NaiveDate>,
     {
         self.holidays
+            .dates_mut()
             .extend(holidays.into_iter().map(|holiday| *holiday.borrow()));
     }
 
-    /// Adds weekdays to the weekend set (union with existing weekend days).
-    ///
-    /// Accepts any iterable of weekdays, including borrowed collections.
-    /// Duplicate weekdays are silently ignored.
+    /// Adds a holiday with a name, replacing any metadata already attached to
+    /// that date.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use chrono::Weekday;
+    /// use chrono::NaiveDate;
     /// use findates::calendar::Calendar;
     ///
     /// let mut cal = Calendar::new();
-    /// cal.add_weekends([Weekday::Sat, Weekday::Sun]);
-    /// assert!(cal.get_weekend().contains(&Weekday::Sun));
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// cal.add_named_holiday(xmas, "Christmas Day");
+    ///
+    /// assert!(cal.get_holidays().contains(&xmas));
+    /// assert_eq!(cal.holiday_name(&xmas), Some("Christmas Day"));
     /// ```
-    pub fn add_weekends<I>(&mut self, weekends: I)
-    where
-        I: IntoIterator,
-        I::Item: Borrow<Weekday>,
-    {
-        self.weekend
-            .extend(weekends.into_iter().map(|weekday| *weekday.borrow()));
+    pub fn add_named_holiday(&mut self, date: NaiveDate, name: impl Into<String>) {
+        self.add_holiday_info(date, HolidayInfo::new(name));
     }
 
-    /// Mutates `self` to be the union of `self` and `other`.
+    /// Adds a holiday with its full [`HolidayInfo`], replacing any metadata
+    /// already attached to that date.
     ///
-    /// A date is non-working in the result if it is non-working in *either*
-    /// calendar.  Useful when a transaction settles in multiple jurisdictions.
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::{Calendar, HolidayInfo};
+    ///
+    /// let mut cal = Calendar::new();
+    /// let good_friday = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+    /// cal.add_holiday_info(good_friday, HolidayInfo::new("Good Friday").with_category("Exchange"));
+    ///
+    /// let info = cal.holiday_info(&good_friday).unwrap();
+    /// assert_eq!(info.category.as_deref(), Some("Exchange"));
+    /// ```
+    pub fn add_holiday_info(&mut self, date: NaiveDate, info: HolidayInfo) {
+        self.holidays.dates_mut().insert(date);
+        self.holiday_info.insert(date, info);
+    }
+
+    /// Returns the name of the holiday on `date`, or `None` if `date` is not a
+    /// holiday or was added without a name.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use chrono::{NaiveDate, Weekday};
+    /// use chrono::NaiveDate;
     /// use findates::calendar::Calendar;
     ///
-    /// let mut cal1 = Calendar::new();
-    /// cal1.add_weekends([Weekday::Sat]);
-    ///
-    /// let mut cal2 = Calendar::new();
-    /// cal2.add_weekends([Weekday::Sun]);
+    /// let xmas   = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let mut cal = Calendar::with_holidays([boxing]);
+    /// cal.add_named_holiday(xmas, "Christmas Day");
     ///
-    /// cal1.union(&cal2);
-    /// assert!(cal1.get_weekend().contains(&Weekday::Sat));
-    /// assert!(cal1.get_weekend().contains(&Weekday::Sun));
+    /// assert_eq!(cal.holiday_name(&xmas), Some("Christmas Day"));
+    /// assert_eq!(cal.holiday_name(&boxing), None);
     /// ```
-    pub fn union(&mut self, other: &Calendar) {
-        self.holidays = self.holidays.union(&other.holidays).cloned().collect();
-        self.weekend = self.weekend.union(&other.weekend).cloned().collect();
+    pub fn holiday_name(&self, date: &NaiveDate) -> Option<&str> {
+        self.holiday_info.get(date).map(|info| info.name.as_str())
     }
 
-    /// Returns `true` if `date` is a good business day in this calendar.
-    ///
-    /// Equivalent to calling [`algebra::is_business_day`](crate::algebra::is_business_day)
-    /// but more ergonomic when you already have a `Calendar` in scope.
+    /// Returns the metadata of the holiday on `date`, or `None` if `date` is
+    /// not a holiday or was added without metadata.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use chrono::NaiveDate;
-    /// use findates::calendar::basic_calendar;
+    /// use findates::calendar::{Calendar, HolidayInfo};
     ///
-    /// let cal = basic_calendar();
-    /// let monday   = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
-    /// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let mut cal = Calendar::new();
+    /// cal.add_named_holiday(xmas, "Christmas Day");
     ///
-    /// assert!(cal.is_business_day(&monday));
-    /// assert!(!cal.is_business_day(&saturday));
+    /// assert_eq!(cal.holiday_info(&xmas), Some(&HolidayInfo::new("Christmas Day")));
     /// ```
-    pub fn is_business_day(&self, date: &chrono::NaiveDate) -> bool {
-        crate::algebra::is_business_day(date, self)
+    pub fn holiday_info(&self, date: &NaiveDate) -> Option<&HolidayInfo> {
+        self.holiday_info.get(date)
     }
 
-    /// Mutates `self` to be the intersection of `self` and `other`.
+    /// Records that trading on `date` ends early, at `close`, replacing any
+    /// closing time already recorded for that date.
     ///
-    /// A date is non-working in the result only if it is non-working in *both*
-    /// calendars.  Useful when only common holidays matter.
+    /// An early close does not make `date` a holiday: it stays a business
+    /// day.  An early close recorded on a weekend day or holiday is kept but
+    /// ignored by [`Calendar::is_early_close`] and [`Calendar::close_time`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use chrono::{NaiveDate, Weekday};
-    /// use findates::calendar::Calendar;
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
     ///
-    /// let xmas  = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
-    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let mut cal = basic_calendar();
+    /// let black_friday = NaiveDate::from_ymd_opt(2024, 11, 29).unwrap();
+    /// cal.add_early_close(black_friday, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
     ///
-    /// let mut cal1 = Calendar::new();
-    /// cal1.add_holidays([xmas, boxing]);
+    /// assert!(cal.is_early_close(&black_friday));
+    /// assert!(cal.is_business_day(&black_friday));
+    /// ```
+    pub fn add_early_close(&mut self, date: NaiveDate, close: NaiveTime) {
+        self.early_closes.insert(date, close);
+    }
+
+    /// Removes the early closes recorded on `dates`.  Dates without one are
+    /// ignored.
     ///
-    /// let mut cal2 = Calendar::new();
-    /// cal2.add_holidays([xmas]);
+    /// # Examples
     ///
-    /// cal1.intersection(&cal2);
-    /// assert!(cal1.get_holidays().contains(&xmas));
-    /// assert!(!cal1.get_holidays().contains(&boxing));
+    /// ```rust
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    /// cal.add_early_close(xmas_eve, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    ///
+    /// cal.remove_early_closes([xmas_eve]);
+    /// assert!(!cal.is_early_close(&xmas_eve));
     /// ```
-    pub fn intersection(&mut self, other: &Calendar) {
-        self.holidays = self
-            .holidays
-            .intersection(&other.holidays)
-            .cloned()
-            .collect();
-        self.weekend = self.weekend.intersection(&other.weekend).cloned().collect();
+    pub fn remove_early_closes<I>(&mut self, dates: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<NaiveDate>,
+    {
+        for date in dates {
+            self.early_closes.remove(date.borrow());
+        }
+    }
+
+    /// Returns every recorded early close, keyed by date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// assert!(cal.get_early_closes().is_empty());
+    ///
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    /// cal.add_early_close(xmas_eve, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    /// assert_eq!(cal.get_early_closes().len(), 1);
+    /// ```
+    pub fn get_early_closes(&self) -> &BTreeMap<NaiveDate, NaiveTime> {
+        &self.early_closes
+    }
+
+    /// Returns `true` if `date` is a business day with an early close.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    /// cal.add_early_close(xmas_eve, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    ///
+    /// assert!(cal.is_early_close(&xmas_eve));
+    /// assert!(!cal.is_early_close(&NaiveDate::from_ymd_opt(2024, 12, 23).unwrap()));
+    /// ```
+    pub fn is_early_close(&self, date: &NaiveDate) -> bool {
+        self.close_time(date).is_some()
+    }
+
+    /// Returns the early closing time of `date`, or `None` if `date` closes
+    /// at the regular time or is not a business day.
+    ///
+    /// The calendar does not know the regular closing time; callers fall back
+    /// to their own session hours on `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let one_pm = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+    /// let regular = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+    /// let mut cal = basic_calendar();
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    /// cal.add_early_close(xmas_eve, one_pm);
+    ///
+    /// assert_eq!(cal.close_time(&xmas_eve), Some(one_pm));
+    /// let xmas_eve_eve = NaiveDate::from_ymd_opt(2024, 12, 23).unwrap();
+    /// assert_eq!(cal.close_time(&xmas_eve_eve).unwrap_or(regular), regular);
+    /// ```
+    pub fn close_time(&self, date: &NaiveDate) -> Option<NaiveTime> {
+        if !self.is_business_day(date) {
+            return None;
+        }
+        self.early_closes.get(date).copied()
+    }
+
+    /// Adds weekdays to the weekend set (union with existing weekend days).
+    ///
+    /// Accepts any iterable of weekdays, including borrowed collections.
+    /// Duplicate weekdays are silently ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Weekday;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal = Calendar::new();
+    /// cal.add_weekends([Weekday::Sat, Weekday::Sun]);
+    /// assert!(cal.get_weekend().contains(&Weekday::Sun));
+    /// ```
+    pub fn add_weekends<I>(&mut self, weekends: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Weekday>,
+    {
+        self.weekend
+            .extend(weekends.into_iter().map(|weekday| *weekday.borrow()));
+    }
+
+    /// Removes dates from the holiday set, along with any metadata attached
+    /// to them.
+    ///
+    /// Dates that are not holidays are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas   = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let mut cal = Calendar::with_holidays([xmas, boxing]);
+    ///
+    /// cal.remove_holidays([boxing]);
+    /// assert!(cal.get_holidays().contains(&xmas));
+    /// assert!(!cal.get_holidays().contains(&boxing));
+    /// ```
+    pub fn remove_holidays<I>(&mut self, holidays: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<NaiveDate>,
+    {
+        for holiday in holidays {
+            self.holidays.dates_mut().remove(holiday.borrow());
+            self.holiday_info.remove(holiday.borrow());
+        }
+    }
+
+    /// Removes a weekday from the weekend set, making it a working day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// // A market where Saturday becomes a working day.
+    /// let mut cal = basic_calendar();
+    /// cal.remove_weekend(Weekday::Sat);
+    ///
+    /// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// assert!(cal.is_business_day(&saturday));
+    /// ```
+    pub fn remove_weekend(&mut self, weekday: Weekday) {
+        self.weekend.remove(&weekday);
+    }
+
+    /// Removes every holiday, keeping the weekend days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal = Calendar::with_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]);
+    /// cal.add_weekends([Weekday::Sun]);
+    ///
+    /// cal.clear_holidays();
+    /// assert!(cal.get_holidays().is_empty());
+    /// assert!(cal.get_weekend().contains(&Weekday::Sun));
+    /// ```
+    pub fn clear_holidays(&mut self) {
+        self.holidays.dates_mut().clear();
+        self.holiday_info.clear();
+    }
+
+    /// Restricts the calendar's valid range to `start..=end`: the dates its
+    /// holiday data is known to cover.
+    ///
+    /// Calendars built from finite holiday lists otherwise treat every year
+    /// beyond the data as holiday-free.  The plain queries
+    /// ([`Calendar::is_business_day`], [`algebra::adjust`](crate::algebra::adjust),
+    /// ...) ignore the range; use [`Calendar::try_is_business_day`] and
+    /// [`algebra::try_adjust`](crate::algebra::try_adjust) to get an error
+    /// instead of a silent answer outside it.
+    ///
+    /// Combining calendars keeps only the overlap of their ranges.  If the
+    /// ranges do not intersect, the combined calendar's range is empty (see
+    /// [`Calendar::has_empty_valid_range`]) and every checked query fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::InvalidRange`] if `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::CalendarError;
+    ///
+    /// let mut cal = basic_calendar();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// cal.set_valid_range(start, end).unwrap();
+    ///
+    /// let later = NaiveDate::from_ymd_opt(2031, 3, 17).unwrap();
+    /// assert!(cal.is_business_day(&later)); // unchecked: silently "true"
+    /// assert_eq!(cal.try_is_business_day(&later), Err(CalendarError::OutOfRange { date: later }));
+    /// ```
+    pub fn set_valid_range(&mut self, start: NaiveDate, end: NaiveDate) -> Result<(), CalendarError> {
+        if end < start {
+            return Err(CalendarError::InvalidRange);
+        }
+        self.valid_range = ValidRange::Bounded(start, end);
+        Ok(())
+    }
+
+    /// Removes the valid range, so every date is considered covered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal = Calendar::new();
+    /// let d = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// cal.set_valid_range(d, d).unwrap();
+    /// cal.clear_valid_range();
+    /// assert_eq!(cal.valid_range(), None);
+    /// ```
+    pub fn clear_valid_range(&mut self) {
+        self.valid_range = ValidRange::Unbounded;
+    }
+
+    /// Returns the inclusive valid range, or `None` if the calendar is
+    /// unbounded or its range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal = Calendar::new();
+    /// assert_eq!(cal.valid_range(), None);
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// cal.set_valid_range(start, end).unwrap();
+    /// assert_eq!(cal.valid_range(), Some((start, end)));
+    /// ```
+    pub fn valid_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match self.valid_range {
+            ValidRange::Bounded(start, end) => Some((start, end)),
+            ValidRange::Unbounded | ValidRange::Empty => None,
+        }
+    }
+
+    /// Returns `true` if the valid range covers no date at all.
+    ///
+    /// This happens when calendars whose valid ranges do not intersect are
+    /// combined: no date is covered by both sets of holiday data, so every
+    /// checked query on the result returns [`CalendarError::OutOfRange`].
+    /// Setting or clearing the range replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let d = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap();
+    /// let mut old = Calendar::new();
+    /// old.set_valid_range(d(2000), d(2009)).unwrap();
+    /// let mut new = Calendar::new();
+    /// new.set_valid_range(d(2020), d(2029)).unwrap();
+    ///
+    /// let combined = &old | &new;
+    /// assert!(combined.has_empty_valid_range());
+    /// assert_eq!(combined.valid_range(), None);
+    /// assert!(combined.try_is_business_day(&d(2024)).is_err());
+    /// ```
+    pub fn has_empty_valid_range(&self) -> bool {
+        self.valid_range == ValidRange::Empty
+    }
+
+    /// Returns `true` if `date` lies within the valid range, or if no range
+    /// is set.  Always `false` when the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal = Calendar::new();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// cal.set_valid_range(start, end).unwrap();
+    ///
+    /// assert!(cal.is_in_valid_range(&end));
+    /// assert!(!cal.is_in_valid_range(&NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()));
+    /// ```
+    pub fn is_in_valid_range(&self, date: &NaiveDate) -> bool {
+        self.valid_range.contains(date)
+    }
+
+    /// Like [`Calendar::is_business_day`], but returns an error when `date`
+    /// lies outside the calendar's valid range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::OutOfRange`] if `date` is outside the range
+    /// set with [`Calendar::set_valid_range`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// cal.set_valid_range(start, end).unwrap();
+    ///
+    /// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// assert_eq!(cal.try_is_business_day(&saturday), Ok(false));
+    /// assert!(cal.try_is_business_day(&NaiveDate::from_ymd_opt(2025, 3, 17).unwrap()).is_err());
+    /// ```
+    pub fn try_is_business_day(&self, date: &NaiveDate) -> Result<bool, CalendarError> {
+        if !self.is_in_valid_range(date) {
+            return Err(CalendarError::OutOfRange { date: *date });
+        }
+        Ok(self.is_business_day(date))
+    }
+
+    /// Mutates `self` to be the union of `self` and `other`.
+    ///
+    /// A date is non-working in the result if it is non-working in *either*
+    /// calendar.  Useful when a transaction settles in multiple jurisdictions.
+    /// Early closes from both calendars are kept; where both close early on
+    /// the same date, the earlier time wins.
+    ///
+    /// The valid range of the result is the overlap of both ranges.  If the
+    /// ranges do not intersect, the holidays and weekends are still merged
+    /// but the valid range becomes empty, so every checked query fails; use
+    /// [`Calendar::try_unioned`] to get an error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let mut cal1 = Calendar::new();
+    /// cal1.add_weekends([Weekday::Sat]);
+    ///
+    /// let mut cal2 = Calendar::new();
+    /// cal2.add_weekends([Weekday::Sun]);
+    ///
+    /// cal1.union(&cal2);
+    /// assert!(cal1.get_weekend().contains(&Weekday::Sat));
+    /// assert!(cal1.get_weekend().contains(&Weekday::Sun));
+    /// ```
+    pub fn union(&mut self, other: &Calendar) {
+        self.holidays.dates_mut().extend(other.holidays.iter().copied());
+        for (date, info) in &other.holiday_info {
+            self.holiday_info.entry(*date).or_insert_with(|| info.clone());
+        }
+        for (date, close) in &other.early_closes {
+            let entry = self.early_closes.entry(*date).or_insert(*close);
+            *entry = (*entry).min(*close);
+        }
+        self.valid_range = self.valid_range.overlap(other.valid_range);
+        self.weekend = self.weekend.union(&other.weekend).cloned().collect();
+    }
+
+    /// Returns `true` if `date` is a good business day in this calendar:
+    /// neither a weekend day nor a holiday.
+    ///
+    /// [`algebra::is_business_day`](crate::algebra::is_business_day) is the
+    /// free-function form of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let cal = basic_calendar();
+    /// let monday   = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+    /// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    ///
+    /// assert!(cal.is_business_day(&monday));
+    /// assert!(!cal.is_business_day(&saturday));
+    /// ```
+    pub fn is_business_day(&self, date: &chrono::NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Returns `true` if `date` is one of this calendar's holidays, whether
+    /// or not it also falls on a weekend day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let xmas    = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap(); // Sunday
+    /// let mut cal = basic_calendar();
+    /// cal.add_holidays([xmas]);
+    ///
+    /// assert!(cal.is_holiday(&xmas));
+    /// assert!(!cal.is_holiday(&NaiveDate::from_ymd_opt(2022, 12, 24).unwrap()));
+    /// ```
+    pub fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.holidays.contains(date)
+    }
+
+    /// Returns `true` if `date` falls on one of this calendar's weekend days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let cal = basic_calendar();
+    /// assert!(cal.is_weekend(&NaiveDate::from_ymd_opt(2024, 3, 16).unwrap()));
+    /// assert!(!cal.is_weekend(&NaiveDate::from_ymd_opt(2024, 3, 18).unwrap()));
+    /// ```
+    pub fn is_weekend(&self, date: &NaiveDate) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+
+    /// Returns the holidays falling in `year`, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let dates = [(2024, 12, 25), (2024, 1, 1), (2025, 1, 1)]
+    ///     .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    /// let cal = Calendar::with_holidays(dates);
+    /// assert_eq!(cal.holidays_in_year(2024), vec![dates[1], dates[0]]);
+    /// ```
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        match (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) {
+            (Some(first), Some(last)) => self.holidays_between(&first, &last),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the holidays from `start` to `end` inclusive, sorted; empty
+    /// when `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let dates = [(2024, 12, 25), (2024, 12, 26), (2025, 1, 1)]
+    ///     .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    /// let cal = Calendar::with_holidays(dates);
+    /// assert_eq!(cal.holidays_between(&dates[1], &dates[2]), vec![dates[1], dates[2]]);
+    /// ```
+    pub fn holidays_between(&self, start: &NaiveDate, end: &NaiveDate) -> Vec<NaiveDate> {
+        if end < start {
+            return Vec::new();
+        }
+        self.holidays.range(start..=end).copied().collect()
+    }
+
+    // Holidays in `[start, end)`, in date order; `end` must not be before
+    // `start`.
+    pub(crate) fn holidays_in_range(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = &NaiveDate> {
+        self.holidays.range(start..end)
+    }
+
+    /// Counts the business days in the given month.
+    ///
+    /// Returns `None` if `month` is not in `1..=12` or the month lies outside
+    /// the representable date range.  Summing the months of a year gives the
+    /// business days behind a [`Bd252`](crate::conventions::DayCount::Bd252)
+    /// annualization.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let mut cal = basic_calendar();
+    /// cal.add_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]);
+    /// // 22 weekdays in December 2024, one of them Christmas
+    /// assert_eq!(cal.business_day_count_in_month(2024, 12), Some(21));
+    /// assert_eq!(cal.business_day_count_in_month(2024, 13), None);
+    /// ```
+    pub fn business_day_count_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let last = crate::algebra::end_of_month(&first);
+        Some(crate::algebra::business_days_iter(&first, &last, self).count() as u32)
+    }
+
+    /// Counts the business days and holidays in each month of `year`, for
+    /// logging and reports.  Its `Display` form is a table with a row per
+    /// month and a total.
+    ///
+    /// Returns `None` if part of the year lies outside the representable
+    /// date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    ///
+    /// let summary = Calendar::target2().summary(2024).unwrap();
+    /// // Good Friday and Easter Monday
+    /// assert_eq!(summary.months[2].holidays, 1);
+    /// assert_eq!(summary.months[3].holidays, 1);
+    /// assert_eq!(summary.months[11].business_days, 20);
+    /// assert_eq!(summary.business_days(), 256);
+    /// assert_eq!(summary.holidays(), 6);
+    /// ```
+    pub fn summary(&self, year: i32) -> Option<CalendarSummary> {
+        let mut months = Vec::with_capacity(12);
+        for month in 1..=12 {
+            let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+            let last = crate::algebra::end_of_month(&first);
+            months.push(MonthSummary {
+                month,
+                business_days: self.business_day_count_in_month(year, month)?,
+                holidays: self.holidays.range(first..=last).count() as u32,
+            });
+        }
+        Some(CalendarSummary {
+            year,
+            months: months.try_into().ok()?,
+        })
+    }
+
+    /// Mutates `self` to be the intersection of `self` and `other`.
+    ///
+    /// A date is non-working in the result only if it is non-working in *both*
+    /// calendars.  Useful when only common holidays matter.  Only early
+    /// closes recorded in both calendars are kept, at the later time.
+    ///
+    /// The valid range of the result is the overlap of both ranges.  If the
+    /// ranges do not intersect, the holidays and weekends are still
+    /// intersected but the valid range becomes empty, so every checked query
+    /// fails; use [`Calendar::try_intersected`] to get an error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas  = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    ///
+    /// let mut cal1 = Calendar::new();
+    /// cal1.add_holidays([xmas, boxing]);
+    ///
+    /// let mut cal2 = Calendar::new();
+    /// cal2.add_holidays([xmas]);
+    ///
+    /// cal1.intersection(&cal2);
+    /// assert!(cal1.get_holidays().contains(&xmas));
+    /// assert!(!cal1.get_holidays().contains(&boxing));
+    /// ```
+    pub fn intersection(&mut self, other: &Calendar) {
+        self.holidays.dates_mut().retain(|date| other.holidays.contains(date));
+        let holidays = &self.holidays;
+        self.holiday_info.retain(|date, _| holidays.contains(date));
+        self.early_closes = self
+            .early_closes
+            .iter()
+            .filter_map(|(date, close)| {
+                let other_close = other.early_closes.get(date)?;
+                Some((*date, (*close).max(*other_close)))
+            })
+            .collect();
+        self.valid_range = self.valid_range.overlap(other.valid_range);
+        self.weekend = self.weekend.intersection(&other.weekend).cloned().collect();
+    }
+
+    /// Returns the union of `self` and `other` as a new calendar, leaving both
+    /// inputs untouched.
+    ///
+    /// Non-mutating counterpart of [`Calendar::union`]; also available as
+    /// `&a | &b`.  See [`Calendar::try_unioned`] to reject calendars whose
+    /// valid ranges do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let thanksgiving = NaiveDate::from_ymd_opt(2024, 11, 28).unwrap();
+    /// let uk = Calendar::with_weekends([Weekday::Sat, Weekday::Sun]);
+    /// let us = Calendar::with_holidays([thanksgiving]);
+    ///
+    /// let combined = uk.unioned(&us);
+    /// assert!(combined.get_holidays().contains(&thanksgiving));
+    /// assert!(uk.get_holidays().is_empty());
+    /// assert_eq!(&uk | &us, combined);
+    /// ```
+    pub fn unioned(&self, other: &Calendar) -> Calendar {
+        let mut result = self.clone();
+        result.union(other);
+        result
+    }
+
+    /// Like [`Calendar::unioned`], but returns an error instead of a calendar
+    /// with an empty valid range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::DisjointRanges`] if the valid ranges of the
+    /// two calendars do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    /// use findates::CalendarError;
+    ///
+    /// let d = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap();
+    /// let mut old = Calendar::new();
+    /// old.set_valid_range(d(2000), d(2009)).unwrap();
+    /// let mut new = Calendar::new();
+    /// new.set_valid_range(d(2005), d(2029)).unwrap();
+    ///
+    /// assert_eq!(old.try_unioned(&new).unwrap().valid_range(), Some((d(2005), d(2009))));
+    /// new.set_valid_range(d(2020), d(2029)).unwrap();
+    /// assert_eq!(old.try_unioned(&new), Err(CalendarError::DisjointRanges));
+    /// ```
+    pub fn try_unioned(&self, other: &Calendar) -> Result<Calendar, CalendarError> {
+        let result = self.unioned(other);
+        if result.has_empty_valid_range() {
+            return Err(CalendarError::DisjointRanges);
+        }
+        Ok(result)
+    }
+
+    /// Returns the intersection of `self` and `other` as a new calendar,
+    /// leaving both inputs untouched.
+    ///
+    /// Non-mutating counterpart of [`Calendar::intersection`]; also available
+    /// as `&a & &b`.  See [`Calendar::try_intersected`] to reject calendars
+    /// whose valid ranges do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas   = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let uk = Calendar::with_holidays([xmas, boxing]);
+    /// let us = Calendar::with_holidays([xmas]);
+    ///
+    /// assert_eq!(uk.intersected(&us), Calendar::with_holidays([xmas]));
+    /// assert_eq!(&uk & &us, us);
+    /// ```
+    pub fn intersected(&self, other: &Calendar) -> Calendar {
+        let mut result = self.clone();
+        result.intersection(other);
+        result
+    }
+
+    /// Like [`Calendar::intersected`], but returns an error instead of a
+    /// calendar with an empty valid range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::DisjointRanges`] if the valid ranges of the
+    /// two calendars do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    /// use findates::CalendarError;
+    ///
+    /// let d = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap();
+    /// let mut old = Calendar::new();
+    /// old.set_valid_range(d(2000), d(2009)).unwrap();
+    /// let mut new = Calendar::new();
+    /// new.set_valid_range(d(2020), d(2029)).unwrap();
+    ///
+    /// assert_eq!(old.try_intersected(&new), Err(CalendarError::DisjointRanges));
+    /// assert!(old.try_intersected(&Calendar::new()).is_ok());
+    /// ```
+    pub fn try_intersected(&self, other: &Calendar) -> Result<Calendar, CalendarError> {
+        let result = self.intersected(other);
+        if result.has_empty_valid_range() {
+            return Err(CalendarError::DisjointRanges);
+        }
+        Ok(result)
+    }
+
+    /// Returns a new calendar holding the non-working days of `self` that are
+    /// not non-working in `other`: holidays in `self` but not in `other`, and
+    /// weekend days in `self` but not in `other`.
+    ///
+    /// Useful for auditing what a vendor removed between two versions of a
+    /// calendar (`old.difference(&new)`) or added (`new.difference(&old)`).
+    /// Holiday metadata is taken from `self`, as are the early closes that
+    /// `other` does not record at the same time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::DisjointRanges`] if both calendars have a
+    /// valid range and the ranges do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas   = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let v1 = Calendar::with_holidays([xmas]);
+    /// let v2 = Calendar::with_holidays([xmas, boxing]);
+    ///
+    /// assert_eq!(v2.difference(&v1), Ok(Calendar::with_holidays([boxing])));
+    /// assert!(v1.difference(&v2).unwrap().get_holidays().is_empty());
+    /// ```
+    pub fn difference(&self, other: &Calendar) -> Result<Calendar, CalendarError> {
+        let valid_range = self.valid_range.overlap(other.valid_range);
+        if valid_range == ValidRange::Empty {
+            return Err(CalendarError::DisjointRanges);
+        }
+        let holidays: Holidays = self.holidays.difference(&other.holidays).copied().collect();
+        let holiday_info = self
+            .holiday_info
+            .iter()
+            .filter(|(date, _)| holidays.contains(date))
+            .map(|(date, info)| (*date, info.clone()))
+            .collect();
+        let early_closes = self
+            .early_closes
+            .iter()
+            .filter(|(date, close)| other.early_closes.get(date) != Some(close))
+            .map(|(date, close)| (*date, *close))
+            .collect();
+        Ok(Calendar {
+            weekend: self.weekend.difference(&other.weekend).cloned().collect(),
+            holidays,
+            holiday_info,
+            valid_range,
+            early_closes,
+        })
+    }
+
+    /// Returns a new calendar holding the non-working days that are
+    /// non-working in exactly one of `self` and `other`.
+    ///
+    /// An empty result means both calendars agree on every day.  Holiday
+    /// metadata is taken from whichever calendar holds the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::DisjointRanges`] if both calendars have a
+    /// valid range and the ranges do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas   = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let boxing = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// let mut v1 = Calendar::with_holidays([xmas]);
+    /// v1.add_weekends([Weekday::Sat, Weekday::Sun]);
+    /// let mut v2 = Calendar::with_holidays([boxing]);
+    /// v2.add_weekends([Weekday::Sun]);
+    ///
+    /// let changed = v1.symmetric_difference(&v2).unwrap();
+    /// assert_eq!(changed.get_holidays().len(), 2);
+    /// assert_eq!(changed.get_weekend(), &[Weekday::Sat].into_iter().collect());
+    /// ```
+    pub fn symmetric_difference(&self, other: &Calendar) -> Result<Calendar, CalendarError> {
+        let mut result = self.difference(other)?;
+        result.union(&other.difference(self)?);
+        Ok(result)
+    }
+
+    /// Reads a holiday list in CSV format: one `YYYY-MM-DD` date per row,
+    /// optionally followed by a comma and the holiday name.
+    ///
+    /// Blank lines, lines starting with `#` and a leading `date` header row
+    /// are skipped.  A non-empty name is stored as the holiday name (see
+    /// [`Calendar::holiday_name`]).  Unquoted names are trimmed; a name in
+    /// double quotes is kept as written, may contain commas and line breaks,
+    /// and writes a literal quote as `""`.  The
+    /// returned calendar has no weekend days, like [`Calendar::with_holidays`];
+    /// add them with [`Calendar::add_weekends`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarIoError::InvalidDate`] for a row that does not start
+    /// with a valid date, [`CalendarIoError::InvalidName`] for a quoted name
+    /// that is not closed or is followed by more text, and
+    /// [`CalendarIoError::Io`] if reading fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let csv = "date,name\n2024-12-25,Christmas Day\n2024-12-26,Boxing Day\n";
+    /// let cal = Calendar::from_csv(csv.as_bytes()).unwrap();
+    ///
+    /// let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    /// assert!(cal.get_holidays().contains(&boxing_day));
+    /// assert_eq!(cal.holiday_name(&boxing_day), Some("Boxing Day"));
+    /// assert!(cal.get_weekend().is_empty());
+    ///
+    /// let quoted = Calendar::from_csv(r#"2024-05-27,"Memorial Day, observed""#.as_bytes()).unwrap();
+    /// let memorial_day = NaiveDate::from_ymd_opt(2024, 5, 27).unwrap();
+    /// assert_eq!(quoted.holiday_name(&memorial_day), Some("Memorial Day, observed"));
+    /// ```
+    pub fn from_csv<R: Read>(reader: R) -> Result<Self, CalendarIoError> {
+        let mut cal = Calendar::new();
+        let mut lines = BufReader::new(reader).lines().enumerate();
+        while let Some((idx, line)) = lines.next() {
+            let line = line?;
+            let mut fields = line.splitn(2, ',');
+            let field = fields.next().unwrap_or_default().trim();
+            if field.is_empty() || field.starts_with('#') {
+                continue;
+            }
+            if idx == 0 && field.eq_ignore_ascii_case("date") {
+                continue;
+            }
+            let date = NaiveDate::parse_from_str(field, "%Y-%m-%d")
+                .map_err(|_| CalendarIoError::InvalidDate { line: idx + 1 })?;
+            let name = match fields.next().map(str::trim) {
+                Some(name) => match name.strip_prefix('"') {
+                    Some(quoted) => unquote_csv_field(quoted, &mut lines)?
+                        .ok_or(CalendarIoError::InvalidName { line: idx + 1 })?,
+                    None => name.to_string(),
+                },
+                None => String::new(),
+            };
+            if name.is_empty() {
+                cal.holidays.dates_mut().insert(date);
+            } else {
+                cal.add_named_holiday(date, name);
+            }
+        }
+        Ok(cal)
+    }
+
+    /// Writes the holidays as CSV: a `date,name` header row followed by one
+    /// `YYYY-MM-DD` date per row, in ascending order, with the holiday name
+    /// when one is set.
+    ///
+    /// Names containing a comma, a double quote or a line break, or starting
+    /// or ending with whitespace, are written in double quotes.  Weekend days
+    /// are not written.  The output can be read back with
+    /// [`Calendar::from_csv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarIoError::Io`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::with_holidays([
+    ///     NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+    /// ]);
+    /// let mut out = Vec::new();
+    /// cal.to_csv_writer(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "date,name\n2024-12-25,\n2024-12-26,\n");
+    /// ```
+    pub fn to_csv_writer<W: Write>(&self, mut writer: W) -> Result<(), CalendarIoError> {
+        writeln!(writer, "date,name")?;
+        for date in self.iter_holidays_sorted() {
+            let name = self.holiday_name(&date).unwrap_or_default();
+            writeln!(writer, "{},{}", date.format("%Y-%m-%d"), quote_csv_field(name))?;
+        }
+        Ok(())
+    }
+
+    /// Reads a calendar — weekend days and holidays — from JSON, in the
+    /// format written by [`Calendar::to_json_writer`].
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarIoError::Json`] if the input is not a valid
+    /// calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::Calendar;
+    ///
+    /// let json = r#"{"weekend":["Sat","Sun"],"holidays":["2024-12-25"]}"#;
+    /// let cal = Calendar::from_json_reader(json.as_bytes()).unwrap();
+    ///
+    /// assert!(cal.get_weekend().contains(&Weekday::Sat));
+    /// assert!(cal.get_holidays().contains(&NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self, CalendarIoError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Writes the calendar — weekend days and holidays — as JSON.
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarIoError::Json`] if serialization or writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::{basic_calendar, Calendar};
+    ///
+    /// let cal = basic_calendar();
+    /// let mut out = Vec::new();
+    /// cal.to_json_writer(&mut out).unwrap();
+    /// assert_eq!(Calendar::from_json_reader(out.as_slice()).unwrap(), cal);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn to_json_writer<W: Write>(&self, writer: W) -> Result<(), CalendarIoError> {
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+}
+
+/// Business day and holiday counts for each month of a year, returned by
+/// [`Calendar::summary`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::calendar::basic_calendar;
+///
+/// let summary = basic_calendar().summary(2024).unwrap();
+/// let table = summary.to_string();
+/// assert!(table.starts_with("2024   Business days  Holidays\nJan               23         0\n"));
+/// assert!(table.ends_with("Total            262         0"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarSummary {
+    /// The year summarized.
+    pub year: i32,
+    /// One entry per month, January first.
+    pub months: [MonthSummary; 12],
+}
+
+/// Business day and holiday counts for one month; see [`CalendarSummary`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MonthSummary {
+    /// Month number, `1..=12`.
+    pub month: u32,
+    /// Days that are neither weekend days nor holidays.
+    pub business_days: u32,
+    /// Holidays in the month, including those falling on weekend days.
+    pub holidays: u32,
+}
+
+impl CalendarSummary {
+    /// Total business days in the year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// assert_eq!(basic_calendar().summary(2023).unwrap().business_days(), 260);
+    /// ```
+    pub fn business_days(&self) -> u32 {
+        self.months.iter().map(|month| month.business_days).sum()
+    }
+
+    /// Total holidays in the year, including those falling on weekend days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    ///
+    /// assert_eq!(Calendar::target2().summary(2023).unwrap().holidays(), 6);
+    /// ```
+    pub fn holidays(&self) -> u32 {
+        self.months.iter().map(|month| month.holidays).sum()
+    }
+}
+
+impl fmt::Display for CalendarSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+        write!(f, "{:<6}{:>14}{:>10}", self.year, "Business days", "Holidays")?;
+        for month in &self.months {
+            let name = MONTHS[month.month as usize - 1];
+            write!(f, "\n{name:<6}{:>14}{:>10}", month.business_days, month.holidays)?;
+        }
+        write!(f, "\n{:<6}{:>14}{:>10}", "Total", self.business_days(), self.holidays())
+    }
+}
+
+/// Layout options for [`render_month_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use findates::calendar::RenderOptions;
+///
+/// let options = RenderOptions { week_start: Weekday::Sun, ..Default::default() };
+/// assert!(!options.ansi);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// First day of each week row.  Defaults to Monday.
+    pub week_start: Weekday,
+    /// Colour holidays red and weekend days dim with ANSI escape codes, for
+    /// terminals.  Defaults to `false`.
+    pub ansi: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            week_start: Weekday::Mon,
+            ansi: false,
+        }
+    }
+}
+
+/// Renders `month` of `year` as a text grid, with weeks starting on Monday
+/// and no colours.  See [`render_month_with`] for the layout.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidMonth`] if `month` is not in `1..=12`, and
+/// [`DateError::OutOfRange`] if the month is not representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::calendar::{render_month, Calendar};
+///
+/// let grid = render_month(&Calendar::target2(), 2024, 3).unwrap();
+/// println!("{grid}");
+/// assert!(grid.contains(" 25  26  27  28  29* 30- 31-"));
+/// assert!(grid.ends_with(" 29 Good Friday"));
+/// ```
+pub fn render_month(calendar: &Calendar, year: i32, month: u32) -> Result<String, DateError> {
+    render_month_with(calendar, year, month, &RenderOptions::default())
+}
+
+/// Renders `month` of `year` as a text grid, for CLI tools and debugging.
+///
+/// Below a title and a weekday header, each day is marked `*` if it is a
+/// holiday or `-` if it falls on a weekend day.  A legend follows the grid,
+/// then the named holidays of the month, one per line.  Lines carry no
+/// trailing spaces:
+///
+/// ```text
+///        March 2024
+///  Mo  Tu  We  Th  Fr  Sa  Su
+///                   1   2-  3-
+///   4   5   6   7   8   9- 10-
+///  11  12  13  14  15  16- 17-
+///  18  19  20  21  22  23- 24-
+///  25  26  27  28  29* 30- 31-
+/// * holiday  - weekend
+///  29 Good Friday
+/// ```
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidMonth`] if `month` is not in `1..=12`, and
+/// [`DateError::OutOfRange`] if the month is not representable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use findates::calendar::{basic_calendar, render_month_with, RenderOptions};
+///
+/// let options = RenderOptions { week_start: Weekday::Sun, ansi: true };
+/// let grid = render_month_with(&basic_calendar(), 2024, 9, &options).unwrap();
+/// assert!(grid.lines().nth(1).unwrap().starts_with(" Su  Mo"));
+/// // Sunday 1 September is dimmed.
+/// assert!(grid.lines().nth(2).unwrap().starts_with("\x1b[2m  1-\x1b[0m"));
+/// ```
+pub fn render_month_with(
+    calendar: &Calendar,
+    year: i32,
+    month: u32,
+    options: &RenderOptions,
+) -> Result<String, DateError> {
+    const HOLIDAY: &str = "\x1b[31m";
+    const WEEKEND: &str = "\x1b[2m";
+    const RESET: &str = "\x1b[0m";
+
+    if !(1..=12).contains(&month) {
+        return Err(DateError::InvalidMonth);
+    }
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateError::OutOfRange)?;
+    let last = crate::algebra::end_of_month(&first);
+    let name = chrono::Month::try_from(month as u8).map_or("", |m| m.name());
+
+    let mut lines = vec![format!("{:^28}", format!("{name} {year}"))];
+    let mut day = options.week_start;
+    let mut header = String::new();
+    for _ in 0..7 {
+        header.push_str(&format!(" {} ", &day.to_string()[..2]));
+        day = day.succ();
+    }
+    lines.push(header);
+
+    let offset = (7 + first.weekday().num_days_from_monday() - options.week_start.num_days_from_monday()) % 7;
+    let mut row = "    ".repeat(offset as usize);
+    for date in first.iter_days().take_while(|date| *date <= last) {
+        let (marker, colour) = if calendar.is_holiday(&date) {
+            ('*', HOLIDAY)
+        } else if calendar.is_weekend(&date) {
+            ('-', WEEKEND)
+        } else {
+            (' ', "")
+        };
+        let cell = format!("{:>3}{marker}", date.day());
+        if options.ansi && !colour.is_empty() {
+            row.push_str(&format!("{colour}{cell}{RESET}"));
+        } else {
+            row.push_str(&cell);
+        }
+        if date.weekday().succ() == options.week_start {
+            lines.push(std::mem::take(&mut row));
+        }
+    }
+    if !row.is_empty() {
+        lines.push(row);
+    }
+    lines.push("* holiday  - weekend".to_string());
+    for (date, info) in calendar.holiday_info.range(first..=last) {
+        lines.push(format!("{:>3} {}", date.day(), info.name));
+    }
+
+    let lines: Vec<&str> = lines.iter().map(|line| line.trim_end()).collect();
+    Ok(lines.join("\n"))
+}
+
+/// How a calendar update changes a set of generated schedule dates; returned
+/// by [`diff_report`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::calendar::{basic_calendar, diff_report};
+///
+/// let cal = basic_calendar();
+/// let diff = diff_report(&cal, &cal.clone(), &[]);
+/// assert!(diff.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalendarDiff {
+    /// Holidays in the new calendar but not the old one, in date order.
+    pub added_holidays: Vec<NaiveDate>,
+    /// Holidays in the old calendar but not the new one, in date order.
+    pub removed_holidays: Vec<NaiveDate>,
+    /// Schedule dates whose adjusted date moves, in the order given to
+    /// [`diff_report`].
+    pub changes: Vec<DateChange>,
+}
+
+impl CalendarDiff {
+    /// Whether the update leaves the holidays and every schedule date as
+    /// they were.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::{diff_report, Calendar};
+    ///
+    /// let old = Calendar::new();
+    /// let new = Calendar::with_holidays([NaiveDate::from_ymd_opt(2024, 5, 8).unwrap()]);
+    /// // A new holiday is reported even when no schedule date moves.
+    /// assert!(!diff_report(&old, &new, &[]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.added_holidays.is_empty() && self.removed_holidays.is_empty() && self.changes.is_empty()
+    }
+}
+
+/// A schedule date that adjusts differently under the new calendar; see
+/// [`CalendarDiff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateChange {
+    /// Date before business day adjustment.
+    pub unadjusted: NaiveDate,
+    /// Adjustment rule applied under both calendars.
+    pub adjust_rule: Option<AdjustRule>,
+    /// Adjusted date under the old calendar.
+    pub old: NaiveDate,
+    /// Adjusted date under the new calendar.
+    pub new: NaiveDate,
+}
+
+/// Reports which schedule dates move when `old` is replaced by `new`, e.g.
+/// after a newly declared holiday, for downstream alerting.
+///
+/// `schedule` holds the traced dates of a portfolio's schedules, as returned
+/// by [`Schedule::generate_with_trace`](crate::schedule::Schedule::generate_with_trace);
+/// traces of several schedules may be concatenated.  Each unadjusted date is
+/// adjusted with its own rule under both calendars, and a [`DateChange`] is
+/// reported when the results differ.  Dates without a rule never move.
+///
+/// # Panics
+///
+/// Panics if adjusting a date runs off the representable date range, as
+/// [`adjust`](crate::algebra::adjust) does.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::{basic_calendar, diff_report};
+/// use findates::conventions::{AdjustRule, Frequency};
+/// use findates::schedule::Schedule;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// let old = basic_calendar();
+/// let mut new = old.clone();
+/// new.add_holidays([d(2024, 6, 17)]);
+///
+/// let sched = Schedule::new(Frequency::Monthly, Some(&old), Some(AdjustRule::Following));
+/// let trace = sched.generate_with_trace(&d(2024, 4, 15), &d(2024, 8, 15)).unwrap();
+///
+/// let diff = diff_report(&old, &new, &trace);
+/// assert_eq!(diff.added_holidays, [d(2024, 6, 17)]);
+/// assert_eq!(diff.changes.len(), 1);
+/// // Saturday 15 June rolled to Monday the 17th, and now to the 18th.
+/// assert_eq!((diff.changes[0].old, diff.changes[0].new), (d(2024, 6, 17), d(2024, 6, 18)));
+/// ```
+pub fn diff_report(old: &Calendar, new: &Calendar, schedule: &[GeneratedDate]) -> CalendarDiff {
+    let changes = schedule
+        .iter()
+        .filter_map(|date| {
+            let rule = date.adjust_rule?;
+            let before = crate::algebra::adjust(&date.unadjusted, Some(old), Some(rule));
+            let after = crate::algebra::adjust(&date.unadjusted, Some(new), Some(rule));
+            (before != after).then_some(DateChange {
+                unadjusted: date.unadjusted,
+                adjust_rule: Some(rule),
+                old: before,
+                new: after,
+            })
+        })
+        .collect();
+    CalendarDiff {
+        added_holidays: new.holidays.difference(&old.holidays).copied().collect(),
+        removed_holidays: old.holidays.difference(&new.holidays).copied().collect(),
+        changes,
+    }
+}
+
+impl BitOr for &Calendar {
+    type Output = Calendar;
+
+    /// Returns the union of two calendars.  See [`Calendar::unioned`].
+    fn bitor(self, other: &Calendar) -> Calendar {
+        self.unioned(other)
+    }
+}
+
+impl BitAnd for &Calendar {
+    type Output = Calendar;
+
+    /// Returns the intersection of two calendars.  See
+    /// [`Calendar::intersected`].
+    fn bitand(self, other: &Calendar) -> Calendar {
+        self.intersected(other)
+    }
+}
+
+impl BitOr<&Calendar> for Calendar {
+    type Output = Calendar;
+
+    /// Returns the union of two calendars, reusing `self`, so that
+    /// `&a | &b | &c` chains.  See [`Calendar::union`].
+    fn bitor(mut self, other: &Calendar) -> Calendar {
+        self.union(other);
+        self
+    }
+}
+
+impl BitAnd<&Calendar> for Calendar {
+    type Output = Calendar;
+
+    /// Returns the intersection of two calendars, reusing `self`, so that
+    /// `&a & &b & &c` chains.  See [`Calendar::intersection`].
+    fn bitand(mut self, other: &Calendar) -> Calendar {
+        self.intersection(other);
+        self
     }
 }
 
@@ -0,0 +1,212 @@
+//! Standard CDS dates: the 20th of March, June, September and December.
+//!
+//! Standard credit default swaps pay quarterly coupons on the 20th of the
+//! IMM months and mature on one of those dates.  Since the ISDA 2015 rule
+//! change (effective 21 December 2015) on-the-run contracts only roll twice
+//! a year, on 20 March and 20 September, so maturities always fall on
+//! 20 June or 20 December; coupon dates remain quarterly.  The
+//! [`CdsRollRule`] enum selects between the two regimes.
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::algebra::checked_adjust;
+use crate::calendar::Calendar;
+use crate::conventions::{AdjustRule, Tenor};
+
+/// Which ISDA maturity roll rule to apply when computing a standard CDS
+/// maturity with [`cds_maturity`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::cds::CdsRollRule;
+///
+/// let rule = CdsRollRule::Semiannual;
+/// assert_ne!(rule, CdsRollRule::Quarterly);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CdsRollRule {
+    /// Pre-2015 rule: maturities roll every quarter date.
+    ///
+    /// QuantLib equivalent: `DateGeneration::CDS`
+    Quarterly,
+    /// ISDA 2015 rule: maturities roll on 20 March and 20 September only,
+    /// so every maturity is a 20 June or 20 December.
+    ///
+    /// QuantLib equivalent: `DateGeneration::CDS2015`
+    Semiannual,
+}
+
+/// Returns `true` if `date` is a standard CDS date (20 Mar/Jun/Sep/Dec).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::cds::is_cds_date;
+///
+/// assert!(is_cds_date(&NaiveDate::from_ymd_opt(2024, 6, 20).unwrap()));
+/// assert!(!is_cds_date(&NaiveDate::from_ymd_opt(2024, 7, 20).unwrap()));
+/// ```
+pub fn is_cds_date(date: &NaiveDate) -> bool {
+    date.day() == 20 && date.month() % 3 == 0
+}
+
+/// Returns the first standard CDS date strictly after `date`, or `None` if
+/// it is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::cds::next_cds_date;
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+/// assert_eq!(next_cds_date(&d), NaiveDate::from_ymd_opt(2024, 6, 20));
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+/// assert_eq!(next_cds_date(&d), NaiveDate::from_ymd_opt(2025, 3, 20));
+/// ```
+pub fn next_cds_date(date: &NaiveDate) -> Option<NaiveDate> {
+    let on_or_before = cds_date_on_or_before(date)?;
+    on_or_before.checked_add_months(Months::new(3))
+}
+
+/// Returns the last standard CDS date strictly before `date`, or `None` if
+/// it is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::cds::previous_cds_date;
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+/// assert_eq!(previous_cds_date(&d), NaiveDate::from_ymd_opt(2023, 12, 20));
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+/// assert_eq!(previous_cds_date(&d), NaiveDate::from_ymd_opt(2024, 3, 20));
+/// ```
+pub fn previous_cds_date(date: &NaiveDate) -> Option<NaiveDate> {
+    cds_date_on_or_before(&date.pred_opt()?)
+}
+
+// Last 20 Mar/Jun/Sep/Dec on or before `date`.
+fn cds_date_on_or_before(date: &NaiveDate) -> Option<NaiveDate> {
+    let mut year = date.year();
+    let mut month = date.month() - date.month() % 3;
+    if month == 0 {
+        month = 12;
+        year -= 1;
+    }
+    let mut candidate = NaiveDate::from_ymd_opt(year, month, 20)?;
+    if candidate > *date {
+        candidate = candidate.checked_sub_months(Months::new(3))?;
+    }
+    Some(candidate)
+}
+
+/// Returns the maturity of a standard CDS traded on `trade_date` with the
+/// given tenor.
+///
+/// The maturity is the roll anchor on or before `trade_date` plus `tenor`
+/// plus three months:
+/// - [`CdsRollRule::Quarterly`] anchors on the last CDS date on or before
+///   the trade date.
+/// - [`CdsRollRule::Semiannual`] anchors on the last 20 March or
+///   20 September on or before the trade date, so a 5Y trade on
+///   1 July 2024 matures on 20 June 2029, not 20 September 2029.
+///
+/// Maturities are not business-day adjusted.  Returns `None` for day and
+/// week tenors, for a zero tenor when it would produce a maturity before the
+/// trade date, and when the result is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::cds::{cds_maturity, CdsRollRule};
+/// use findates::conventions::Tenor;
+///
+/// let trade = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// assert_eq!(
+///     cds_maturity(&trade, Tenor::Years(5), CdsRollRule::Semiannual),
+///     NaiveDate::from_ymd_opt(2029, 6, 20)
+/// );
+/// assert_eq!(
+///     cds_maturity(&trade, Tenor::Years(5), CdsRollRule::Quarterly),
+///     NaiveDate::from_ymd_opt(2029, 9, 20)
+/// );
+/// ```
+pub fn cds_maturity(trade_date: &NaiveDate, tenor: Tenor, rule: CdsRollRule) -> Option<NaiveDate> {
+    let months = match tenor {
+        Tenor::Months(n) => n,
+        Tenor::Years(n) => n.checked_mul(12)?,
+        _ => return None,
+    };
+    let mut anchor = cds_date_on_or_before(trade_date)?;
+    if rule == CdsRollRule::Semiannual && (anchor.month() == 6 || anchor.month() == 12) {
+        if months == 0 {
+            return None;
+        }
+        anchor = anchor.checked_sub_months(Months::new(3))?;
+    }
+    anchor.checked_add_months(Months::new(months.checked_add(3)?))
+}
+
+/// Generates the quarterly coupon schedule of a standard CDS.
+///
+/// The first date is the accrual start: the last CDS date on or before
+/// `start_date`.  Subsequent dates step through every 20th of March, June,
+/// September and December up to and including `maturity`.  All dates except
+/// the maturity are adjusted with `calendar` and `adjust_rule` (ISDA uses
+/// [`Following`](AdjustRule::Following)); the maturity itself stays
+/// unadjusted as in the standard contract.
+///
+/// Returns an empty vector if `maturity` is before `start_date`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::cds::cds_schedule;
+/// use findates::conventions::AdjustRule;
+///
+/// let cal      = basic_calendar();
+/// let trade    = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+/// let maturity = NaiveDate::from_ymd_opt(2025, 6, 20).unwrap();
+/// let dates = cds_schedule(&trade, &maturity, Some(&cal), Some(AdjustRule::Following));
+///
+/// // Accrual starts on the CDS date before the trade, then steps quarterly
+/// assert_eq!(dates.first(), NaiveDate::from_ymd_opt(2024, 3, 20).as_ref());
+/// assert_eq!(dates.len(), 6);
+/// assert_eq!(dates.last(), Some(&maturity));
+/// ```
+pub fn cds_schedule(
+    start_date: &NaiveDate,
+    maturity: &NaiveDate,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Vec<NaiveDate> {
+    let mut dates = vec![];
+    if maturity < start_date {
+        return dates;
+    }
+    let mut current = match cds_date_on_or_before(start_date) {
+        Some(d) => d,
+        None => return dates,
+    };
+    while current < *maturity {
+        if let Some(adjusted) = checked_adjust(&current, calendar, adjust_rule) {
+            dates.push(adjusted);
+        }
+        current = match next_cds_date(&current) {
+            Some(d) => d,
+            None => break,
+        };
+    }
+    dates.push(*maturity);
+    dates
+}
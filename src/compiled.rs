@@ -0,0 +1,273 @@
+//! Precomputed business day lookups for hot loops.
+//!
+//! [`Calendar::compile`] turns a calendar into a [`CompiledCalendar`] covering
+//! a fixed date range.  It stores one bit per day plus running counts, so
+//! business day checks, business day counts and business day offsets are all
+//! O(1) — no hashing and no day-by-day loops.  The trade-off is memory (a
+//! few bytes per business day in the range) and an up-front O(range) build.
+
+use chrono::{Days, NaiveDate};
+use std::ops::RangeInclusive;
+
+use crate::calendar::Calendar;
+use crate::error::{BusinessDayError, CalendarError};
+
+/// A [`Calendar`] precomputed over a fixed date range.
+///
+/// Created by [`Calendar::compile`].  Queries outside the compiled range
+/// return an error rather than a silent answer.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2034, 12, 31).unwrap();
+/// let fast  = basic_calendar().compile(start..=end).unwrap();
+///
+/// let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+/// let friday = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap();
+/// assert_eq!(fast.is_business_day(&monday), Ok(true));
+/// assert_eq!(fast.business_days_between(&monday, &friday), Ok(4));
+/// assert_eq!(fast.add_business_days(&friday, 1), Ok(NaiveDate::from_ymd_opt(2024, 3, 25).unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledCalendar {
+    start: NaiveDate,
+    end: NaiveDate,
+    // Bit `i % 64` of word `i / 64` is set when `start + i` is a business day.
+    bits: Vec<u64>,
+    // Number of business days before word `w`.
+    word_ranks: Vec<u32>,
+    // Day offsets of the business days, in ascending order.
+    business_days: Vec<u32>,
+}
+
+impl Calendar {
+    /// Precomputes this calendar over `range` for O(1) business day queries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::InvalidRange`] if the range is empty, and
+    /// [`CalendarError::OutOfRange`] if it extends beyond the calendar's
+    /// [valid range](Calendar::set_valid_range).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let fast  = basic_calendar().compile(start..=end).unwrap();
+    /// assert_eq!(fast.range(), start..=end);
+    /// ```
+    pub fn compile(&self, range: RangeInclusive<NaiveDate>) -> Result<CompiledCalendar, CalendarError> {
+        let (start, end) = range.into_inner();
+        if end < start {
+            return Err(CalendarError::InvalidRange);
+        }
+        for date in [start, end] {
+            if !self.is_in_valid_range(&date) {
+                return Err(CalendarError::OutOfRange { date });
+            }
+        }
+
+        let len = (end - start).num_days() as usize + 1;
+        let mut bits = vec![0u64; (len + 63) / 64];
+        let mut business_days = Vec::new();
+        for (offset, date) in start.iter_days().take(len).enumerate() {
+            if self.is_business_day(&date) {
+                bits[offset / 64] |= 1 << (offset % 64);
+                business_days.push(offset as u32);
+            }
+        }
+        let mut word_ranks = Vec::with_capacity(bits.len());
+        let mut rank = 0u32;
+        for word in &bits {
+            word_ranks.push(rank);
+            rank += word.count_ones();
+        }
+
+        Ok(CompiledCalendar {
+            start,
+            end,
+            bits,
+            word_ranks,
+            business_days,
+        })
+    }
+}
+
+impl CompiledCalendar {
+    /// Returns the inclusive date range this calendar was compiled for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let day  = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let fast = Calendar::new().compile(day..=day).unwrap();
+    /// assert_eq!(fast.range(), day..=day);
+    /// ```
+    pub fn range(&self) -> RangeInclusive<NaiveDate> {
+        self.start..=self.end
+    }
+
+    /// Returns `true` if `date` is a good business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::OutOfRange`] if `date` is outside the
+    /// compiled range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let fast  = basic_calendar().compile(start..=end).unwrap();
+    ///
+    /// let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// assert_eq!(fast.is_business_day(&saturday), Ok(false));
+    /// assert!(fast.is_business_day(&NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()).is_err());
+    /// ```
+    pub fn is_business_day(&self, date: &NaiveDate) -> Result<bool, CalendarError> {
+        let offset = self.offset(date)?;
+        Ok(self.bits[offset / 64] & (1 << (offset % 64)) != 0)
+    }
+
+    /// Counts the business days from `start_date` up to but not including
+    /// `end_date`; zero when `end_date` is not after `start_date`.
+    ///
+    /// Matches [`algebra::business_days_between`](crate::algebra::business_days_between)
+    /// with its default [`Following`](crate::conventions::AdjustRule::Following)
+    /// endpoint adjustment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::OutOfRange`] if either date is outside the
+    /// compiled range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let fast  = basic_calendar().compile(start..=end).unwrap();
+    ///
+    /// // 2024 has 262 weekdays; 31 December is one of them.
+    /// assert_eq!(fast.business_days_between(&start, &end), Ok(261));
+    /// ```
+    pub fn business_days_between(
+        &self,
+        start_date: &NaiveDate,
+        end_date: &NaiveDate,
+    ) -> Result<u64, CalendarError> {
+        let from = self.rank(self.offset(start_date)?);
+        let to = self.rank(self.offset(end_date)?);
+        Ok(to.saturating_sub(from) as u64)
+    }
+
+    /// Moves `date` forward by `n` business days.
+    ///
+    /// Like [`algebra::add_business_days`](crate::algebra::add_business_days),
+    /// `date` must already be a business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BusinessDayError::InvalidStartDate`] if `date` is not a
+    /// business day, and [`BusinessDayError::OutOfRange`] if `date` or the
+    /// result is outside the compiled range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let fast  = basic_calendar().compile(start..=end).unwrap();
+    ///
+    /// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    /// assert_eq!(fast.add_business_days(&friday, 5), Ok(NaiveDate::from_ymd_opt(2024, 3, 22).unwrap()));
+    /// ```
+    pub fn add_business_days(&self, date: &NaiveDate, n: u32) -> Result<NaiveDate, BusinessDayError> {
+        let rank = self.business_day_rank(date)?;
+        let target = rank.checked_add(n as usize).ok_or(BusinessDayError::OutOfRange)?;
+        self.nth_business_day(target)
+    }
+
+    /// Moves `date` backward by `n` business days.
+    ///
+    /// Like [`algebra::subtract_business_days`](crate::algebra::subtract_business_days),
+    /// `date` must already be a business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BusinessDayError::InvalidStartDate`] if `date` is not a
+    /// business day, and [`BusinessDayError::OutOfRange`] if `date` or the
+    /// result is outside the compiled range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let fast  = basic_calendar().compile(start..=end).unwrap();
+    ///
+    /// let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+    /// assert_eq!(fast.subtract_business_days(&monday, 1), Ok(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()));
+    /// ```
+    pub fn subtract_business_days(
+        &self,
+        date: &NaiveDate,
+        n: u32,
+    ) -> Result<NaiveDate, BusinessDayError> {
+        let rank = self.business_day_rank(date)?;
+        let target = rank.checked_sub(n as usize).ok_or(BusinessDayError::OutOfRange)?;
+        self.nth_business_day(target)
+    }
+
+    fn offset(&self, date: &NaiveDate) -> Result<usize, CalendarError> {
+        if *date < self.start || *date > self.end {
+            return Err(CalendarError::OutOfRange { date: *date });
+        }
+        Ok((*date - self.start).num_days() as usize)
+    }
+
+    // Number of business days strictly before `offset`.
+    fn rank(&self, offset: usize) -> usize {
+        let below = (1u64 << (offset % 64)) - 1;
+        self.word_ranks[offset / 64] as usize + (self.bits[offset / 64] & below).count_ones() as usize
+    }
+
+    // Index of `date` among the business days; errors if it is not one.
+    fn business_day_rank(&self, date: &NaiveDate) -> Result<usize, BusinessDayError> {
+        let offset = self.offset(date).map_err(|_| BusinessDayError::OutOfRange)?;
+        if self.bits[offset / 64] & (1 << (offset % 64)) == 0 {
+            return Err(BusinessDayError::InvalidStartDate);
+        }
+        Ok(self.rank(offset))
+    }
+
+    fn nth_business_day(&self, index: usize) -> Result<NaiveDate, BusinessDayError> {
+        let offset = *self.business_days.get(index).ok_or(BusinessDayError::OutOfRange)?;
+        Ok(self.start + Days::new(offset as u64))
+    }
+}
@@ -1,15 +1,39 @@
 //! Enumerations for the standard financial market conventions.
 //!
-//! All three enums implement [`std::fmt::Display`] and [`std::str::FromStr`]
-//! so they can be round-tripped through strings.  The string representation
-//! matches the variant name exactly (case-sensitive).
+//! [`DayCount`], [`AdjustRule`] and [`Frequency`] implement [`std::fmt::Display`]
+//! and [`std::str::FromStr`] so they can be round-tripped through strings.  They
+//! display as the variant name.  Parsing ignores case, spaces and the
+//! punctuation `/ - _ . ( )`, and also accepts the usual market spellings,
+//! such as `"ACT/360"`, `"MF"` or `"6M"`.  `to_fpml` and `from_fpml` map them
+//! to and from the codes of the [FpML](https://www.fpml.org) coding schemes
+//! used in trade messages.  With the `serde` feature they serialize as market
+//! strings (mostly the FpML codes) and deserialize from anything `FromStr`
+//! accepts.
+//!
+//! [`Tenor`] uses the market notation instead (`"3M"`, `"10Y"`, `"ON"`), and
+//! [`RollConvention`] the ISDA/FpML roll codes (`"15"`, `"EOM"`, `"IMM"`, `"MON"`)
+//! and business day anchors such as the last business day of the month.  They
+//! serialize in the same notation, and also deserialize from the variant form,
+//! such as `{"Months":3}`.
+//! [`StubRule`] says where a schedule's irregular period goes.
+//! [`ExpiryRule`] gives the last trading day of a futures contract, and
+//! [`ExpiryStyle`] the expiry dates of a listed option series.
+//! [`Rounding`] is the precision some markets quote year fractions to.
 
+use crate::calendar::Calendar;
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 use std::fmt;
+use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+pub use crate::error::{
+    ParseAdjustRuleError, ParseDayCountError, ParseFrequencyError, ParseRollConventionError,
+    ParseTenorError,
+};
+
 /// Day count conventions used when computing time fractions between two dates.
 ///
-/// Pass one of these values to [`algebra::day_count_fraction`](crate::algebra::day_count_fraction).
+/// Pass one of these values to [`algebra::try_day_count_fraction`](crate::algebra::try_day_count_fraction).
 ///
 /// # Examples
 ///
@@ -23,7 +47,6 @@ use std::str::FromStr;
 /// assert_eq!(parsed, DayCount::Act360);
 /// ```
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DayCount {
     /// Actual days divided by 360.
     ///
@@ -32,6 +55,10 @@ pub enum DayCount {
 
     /// Actual days divided by 365.
     ///
+    /// Computes exactly the same fraction as [`Act365Fixed`](DayCount::Act365Fixed)
+    /// and is kept for compatibility; prefer `Act365Fixed` in new code, whose
+    /// name states the fixed denominator explicitly.
+    ///
     /// QuantLib equivalent: `Actual365Fixed` (Standard variant)
     Act365,
 
@@ -39,14 +66,32 @@ pub enum DayCount {
     /// The standard convention for GBP interest rate swaps, many money
     /// market instruments, and Asian markets.
     ///
-    /// Unlike [`Act365`] which uses the actual year length, this convention
-    /// always divides by exactly 365 even in a leap year.
+    /// A period containing 29 February therefore accrues slightly more than
+    /// one year: a full leap year gives 366/365.  Use
+    /// [`ActActISDA`](DayCount::ActActISDA) when leap days should be
+    /// absorbed by the denominator instead.
     ///
     /// QuantLib equivalent: `Actual365Fixed`
     Act365Fixed,
 
+    /// Actual days divided by 365.25, the average length of a Julian year.
+    /// Occasionally used for long-dated instruments and in some actuarial
+    /// contexts to smooth out leap years.
+    ///
+    /// QuantLib equivalent: `Actual36525`
+    Act365_25,
+
+    /// Actual/365 No Leap (NL/365): actual days excluding any 29 February,
+    /// divided by 365.  Every year therefore counts exactly 365 days.  Used in
+    /// some commodity and insurance contracts.
+    ///
+    /// A date falling on 29 February is treated as 28 February.
+    ///
+    /// QuantLib equivalent: `Actual365Fixed(Actual365Fixed::NoLeap)`
+    NL365,
+
     /// Business days divided by 252 (Brazilian convention).
-    /// Requires a [`Calendar`](crate::calendar::Calendar).
+    /// Requires a [`Calendar`].
     ///
     /// QuantLib equivalent: `Business252`
     Bd252,
@@ -57,6 +102,25 @@ pub enum DayCount {
     /// QuantLib equivalent: `ActualActual(ActualActual::ISDA)`
     ActActISDA,
 
+    /// Actual/Actual ICMA: actual days divided by the number of days in the
+    /// regular coupon period times the coupon frequency.  A full regular
+    /// coupon period therefore always accrues exactly `1 / frequency`.
+    /// The standard convention for European government bonds.
+    ///
+    /// Requires a [`ReferencePeriod`](crate::algebra::ReferencePeriod): use
+    /// [`algebra::day_count_fraction_with_period`](crate::algebra::day_count_fraction_with_period).
+    ///
+    /// QuantLib equivalent: `ActualActual(ActualActual::ISMA)`
+    ActActICMA,
+
+    /// Actual/Actual AFB (Association Française des Banques), also used by
+    /// the Euro master agreement.  Whole years are counted backwards from the
+    /// end date; the remaining stub is divided by 366 if it contains
+    /// 29 February and by 365 otherwise.
+    ///
+    /// QuantLib equivalent: `ActualActual(ActualActual::AFB)`
+    ActActAFB,
+
     /// 30/360 European: if either date falls on the 31st of a month
     /// it is treated as the 30th. Year of 360 days.
     ///
@@ -65,7 +129,8 @@ pub enum DayCount {
     D30360Euro,
 
     /// 30/360 US: the standard for US corporate and municipal bonds.
-    /// Also known as "30/360" or "360/360".
+    /// Also known as "30U/360".  A bare "30/360" means
+    /// [`Thirty360ISDA`](DayCount::Thirty360ISDA), as in FpML.
     ///
     /// Rules applied in order:
     /// 1. If the start date is the 31st, change it to the 30th.
@@ -75,9 +140,34 @@ pub enum DayCount {
     /// 4. If the end date is the last day of February and the start date is
     ///    also the last day of February, change the end date to the 30th.
     ///
+    /// The February rules are what distinguish this from
+    /// [`D30360Euro`](DayCount::D30360Euro) and from the ISDA 30/360
+    /// "Bond Basis", which only apply the 31st rules.
+    ///
     /// QuantLib equivalent: `Thirty360(Thirty360::USA)`
     Thirty360US,
 
+    /// 30/360 ISDA, also known as "30/360", "360/360" or "Bond Basis" (2006
+    /// ISDA Definitions section 4.16(f)): a start date on the 31st becomes the 30th, and an
+    /// end date on the 31st becomes the 30th only if the start date is the
+    /// 30th or 31st.  Unlike [`Thirty360US`](DayCount::Thirty360US) there is
+    /// no special treatment of February.
+    ///
+    /// QuantLib equivalent: `Thirty360(Thirty360::BondBasis)`
+    Thirty360ISDA,
+
+    /// 30E/360 ISDA (2006 ISDA Definitions section 4.16(h)): a date on the
+    /// last day of its month becomes the 30th, except when the end date is
+    /// the termination date and falls in February.
+    ///
+    /// The termination flag is passed through
+    /// [`DayCountContext`](crate::algebra::DayCountContext); plain
+    /// [`try_day_count_fraction`](crate::algebra::try_day_count_fraction) treats the
+    /// end date as an ordinary period end.
+    ///
+    /// QuantLib equivalent: `Thirty360(Thirty360::ISDA, terminationDate)`
+    ThirtyE360ISDA,
+
     /// 30/365: months of 30 days, year of 365 days.
     ///
     /// QuantLib equivalent: no direct equivalent — closest is
@@ -85,35 +175,118 @@ pub enum DayCount {
     D30365,
 }
 
+impl DayCount {
+    /// Returns the FpML `dayCountFractionScheme` code for this convention,
+    /// or `None` if FpML has no code for it.
+    ///
+    /// [`Act365`](DayCount::Act365) shares `ACT/365.FIXED` with
+    /// [`Act365Fixed`](DayCount::Act365Fixed).  FpML's `30/360` is the ISDA
+    /// bond basis, so it is the code of
+    /// [`Thirty360ISDA`](DayCount::Thirty360ISDA);
+    /// [`Thirty360US`](DayCount::Thirty360US) has none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::DayCount;
+    ///
+    /// assert_eq!(DayCount::Act360.to_fpml(), Some("ACT/360"));
+    /// assert_eq!(DayCount::ActActISDA.to_fpml(), Some("ACT/ACT.ISDA"));
+    /// assert_eq!(DayCount::D30365.to_fpml(), None);
+    /// ```
+    pub fn to_fpml(&self) -> Option<&'static str> {
+        match self {
+            DayCount::Act360 => Some("ACT/360"),
+            DayCount::Act365 | DayCount::Act365Fixed => Some("ACT/365.FIXED"),
+            DayCount::Bd252 => Some("BUS/252"),
+            DayCount::ActActISDA => Some("ACT/ACT.ISDA"),
+            DayCount::ActActICMA => Some("ACT/ACT.ICMA"),
+            DayCount::ActActAFB => Some("ACT/ACT.AFB"),
+            DayCount::D30360Euro => Some("30E/360"),
+            DayCount::Thirty360ISDA => Some("30/360"),
+            DayCount::ThirtyE360ISDA => Some("30E/360.ISDA"),
+            DayCount::Act365_25 | DayCount::NL365 | DayCount::Thirty360US | DayCount::D30365 => None,
+        }
+    }
+
+    /// Parses an FpML `dayCountFractionScheme` code (case-sensitive, as in
+    /// the scheme).  `ACT/ACT.ISMA` is accepted for
+    /// [`ActActICMA`](DayCount::ActActICMA).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseDayCountError`] for codes with no findates convention.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::DayCount;
+    ///
+    /// assert_eq!(DayCount::from_fpml("ACT/365.FIXED"), Ok(DayCount::Act365Fixed));
+    /// assert_eq!(DayCount::from_fpml("30/360"), Ok(DayCount::Thirty360ISDA));
+    /// assert!(DayCount::from_fpml("ACT/365L").is_err());
+    /// ```
+    pub fn from_fpml(code: &str) -> Result<Self, ParseDayCountError> {
+        match code {
+            "ACT/360" => Ok(DayCount::Act360),
+            "ACT/365.FIXED" => Ok(DayCount::Act365Fixed),
+            "BUS/252" => Ok(DayCount::Bd252),
+            "ACT/ACT.ISDA" => Ok(DayCount::ActActISDA),
+            "ACT/ACT.ICMA" | "ACT/ACT.ISMA" => Ok(DayCount::ActActICMA),
+            "ACT/ACT.AFB" => Ok(DayCount::ActActAFB),
+            "30E/360" => Ok(DayCount::D30360Euro),
+            "30/360" => Ok(DayCount::Thirty360ISDA),
+            "30E/360.ISDA" => Ok(DayCount::ThirtyE360ISDA),
+            _ => Err(ParseDayCountError),
+        }
+    }
+}
+
 impl fmt::Display for DayCount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DayCount::Act360 => write!(f, "Act360"),
             DayCount::Act365 => write!(f, "Act365"),
             DayCount::Act365Fixed => write!(f, "Act365Fixed"),
+            DayCount::Act365_25 => write!(f, "Act365_25"),
+            DayCount::NL365 => write!(f, "NL365"),
             DayCount::Bd252 => write!(f, "Bd252"),
             DayCount::ActActISDA => write!(f, "ActActISDA"),
+            DayCount::ActActICMA => write!(f, "ActActICMA"),
+            DayCount::ActActAFB => write!(f, "ActActAFB"),
             DayCount::D30360Euro => write!(f, "D30360Euro"),
             DayCount::Thirty360US => write!(f, "Thirty360US"),
+            DayCount::Thirty360ISDA => write!(f, "Thirty360ISDA"),
+            DayCount::ThirtyE360ISDA => write!(f, "ThirtyE360ISDA"),
             DayCount::D30365 => write!(f, "D30365"),
         }
     }
 }
 
-/// Error returned when a string cannot be parsed into a [`DayCount`].
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseDayCountError;
-
-impl fmt::Display for ParseDayCountError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "unknown day count convention string")
-    }
-}
-
 impl FromStr for DayCount {
     type Err = ParseDayCountError;
 
-    /// Parse a [`DayCount`] from its canonical string representation (case-sensitive).
+    /// Parse a [`DayCount`] from its variant name or a market alias.
+    ///
+    /// Matching ignores case, spaces and `/ - _ . ( )`.  Besides the variant
+    /// names it accepts:
+    ///
+    /// | Convention | Aliases |
+    /// |---|---|
+    /// | `Act360` | `ACT/360`, `Actual/360`, `A/360` |
+    /// | `Act365` | `ACT/365`, `Actual/365` |
+    /// | `Act365Fixed` | `ACT/365F`, `ACT/365 (Fixed)`, `Actual/365 Fixed`, `A/365F` |
+    /// | `Act365_25` | `ACT/365.25`, `Actual/365.25` |
+    /// | `NL365` | `NL/365`, `ACT/365 NL`, `Actual/365 No Leap` |
+    /// | `Bd252` | `BUS/252`, `Business/252`, `BD/252` |
+    /// | `ActActISDA` | `ACT/ACT`, `ACT/ACT ISDA`, `Actual/Actual`, `Actual/Actual (ISDA)` |
+    /// | `ActActICMA` | `ACT/ACT ICMA`, `ACT/ACT ISMA`, `Actual/Actual (ICMA)` |
+    /// | `ActActAFB` | `ACT/ACT AFB`, `Actual/Actual (AFB)` |
+    /// | `D30360Euro` | `30E/360`, `30/360 Euro`, `Eurobond Basis` |
+    /// | `Thirty360US` | `30/360 US`, `30U/360` |
+    /// | `Thirty360ISDA` | `30/360`, `360/360`, `30/360 ISDA`, `Bond Basis` |
+    /// | `ThirtyE360ISDA` | `30E/360 ISDA` |
+    /// | `D30365` | `30/365` |
     ///
     /// # Examples
     ///
@@ -121,23 +294,68 @@ impl FromStr for DayCount {
     /// use findates::conventions::DayCount;
     ///
     /// assert_eq!("ActActISDA".parse::<DayCount>().unwrap(), DayCount::ActActISDA);
-    /// assert!("actactisda".parse::<DayCount>().is_err()); // case-sensitive
+    /// assert_eq!("actactisda".parse::<DayCount>().unwrap(), DayCount::ActActISDA);
+    /// assert_eq!("ACT/360".parse::<DayCount>().unwrap(), DayCount::Act360);
+    /// assert_eq!("Actual/365 (Fixed)".parse::<DayCount>().unwrap(), DayCount::Act365Fixed);
+    /// assert_eq!("30E/360".parse::<DayCount>().unwrap(), DayCount::D30360Euro);
+    /// assert!("ACT/364".parse::<DayCount>().is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Act360" => Ok(DayCount::Act360),
-            "Act365" => Ok(DayCount::Act365),
-            "Act365Fixed" => Ok(DayCount::Act365Fixed),
-            "Bd252" => Ok(DayCount::Bd252),
-            "ActActISDA" => Ok(DayCount::ActActISDA),
-            "D30360Euro" => Ok(DayCount::D30360Euro),
-            "Thirty360US" => Ok(DayCount::Thirty360US),
-            "D30365" => Ok(DayCount::D30365),
+        match normalize(s).as_str() {
+            "ACT360" | "ACTUAL360" | "A360" => Ok(DayCount::Act360),
+            "ACT365" | "ACTUAL365" => Ok(DayCount::Act365),
+            "ACT365FIXED" | "ACT365F" | "ACTUAL365FIXED" | "ACTUAL365F" | "A365F" => Ok(DayCount::Act365Fixed),
+            "ACT36525" | "ACTUAL36525" => Ok(DayCount::Act365_25),
+            "NL365" | "ACT365NL" | "ACTUAL365NL" | "ACT365NOLEAP" | "ACTUAL365NOLEAP" => Ok(DayCount::NL365),
+            "BD252" | "BUS252" | "BUSINESS252" => Ok(DayCount::Bd252),
+            "ACTACTISDA" | "ACTACT" | "ACTUALACTUAL" | "ACTUALACTUALISDA" => Ok(DayCount::ActActISDA),
+            "ACTACTICMA" | "ACTACTISMA" | "ACTUALACTUALICMA" | "ACTUALACTUALISMA" => Ok(DayCount::ActActICMA),
+            "ACTACTAFB" | "ACTUALACTUALAFB" => Ok(DayCount::ActActAFB),
+            "D30360EURO" | "30E360" | "30360EURO" | "30360EUROPEAN" | "EUROBONDBASIS" | "30E360EUROBONDBASIS" => {
+                Ok(DayCount::D30360Euro)
+            }
+            "THIRTY360US" | "30360US" | "30U360" => Ok(DayCount::Thirty360US),
+            "THIRTY360ISDA" | "30360" | "360360" | "30360ISDA" | "BONDBASIS" | "30360BONDBASIS" => {
+                Ok(DayCount::Thirty360ISDA)
+            }
+            "THIRTYE360ISDA" | "30E360ISDA" => Ok(DayCount::ThirtyE360ISDA),
+            "D30365" | "30365" => Ok(DayCount::D30365),
             _ => Err(ParseDayCountError),
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for DayCount {
+    // Market strings, each of which parses back to the same convention.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            DayCount::Act360 => "ACT/360",
+            DayCount::Act365 => "ACT/365",
+            DayCount::Act365Fixed => "ACT/365.FIXED",
+            DayCount::Act365_25 => "ACT/365.25",
+            DayCount::NL365 => "NL/365",
+            DayCount::Bd252 => "BUS/252",
+            DayCount::ActActISDA => "ACT/ACT.ISDA",
+            DayCount::ActActICMA => "ACT/ACT.ICMA",
+            DayCount::ActActAFB => "ACT/ACT.AFB",
+            DayCount::D30360Euro => "30E/360",
+            DayCount::Thirty360US => "30U/360",
+            DayCount::Thirty360ISDA => "30/360.ISDA",
+            DayCount::ThirtyE360ISDA => "30E/360.ISDA",
+            DayCount::D30365 => "30/365",
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DayCount {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(|_| serde::de::Error::custom(format_args!("unknown day count {s:?}")))
+    }
+}
+
 /// Business day adjustment conventions.
 ///
 /// Determines how a non-business date is moved to the nearest business day.
@@ -157,7 +375,6 @@ impl FromStr for DayCount {
 /// assert_eq!(parsed, AdjustRule::Preceding);
 /// ```
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AdjustRule {
     /// Choose the first business day after the given holiday.
     ///
@@ -193,6 +410,60 @@ pub enum AdjustRule {
     Nearest,
 }
 
+impl AdjustRule {
+    /// Returns the FpML `businessDayConventionScheme` code for this rule, or
+    /// `None` for [`HalfMonthModFollowing`](AdjustRule::HalfMonthModFollowing),
+    /// which FpML does not define.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::AdjustRule;
+    ///
+    /// assert_eq!(AdjustRule::ModFollowing.to_fpml(), Some("MODFOLLOWING"));
+    /// assert_eq!(AdjustRule::Unadjusted.to_fpml(), Some("NONE"));
+    /// ```
+    pub fn to_fpml(&self) -> Option<&'static str> {
+        match self {
+            AdjustRule::Following => Some("FOLLOWING"),
+            AdjustRule::ModFollowing => Some("MODFOLLOWING"),
+            AdjustRule::Preceding => Some("PRECEDING"),
+            AdjustRule::ModPreceding => Some("MODPRECEDING"),
+            AdjustRule::Unadjusted => Some("NONE"),
+            AdjustRule::Nearest => Some("NEAREST"),
+            AdjustRule::HalfMonthModFollowing => None,
+        }
+    }
+
+    /// Parses an FpML `businessDayConventionScheme` code (case-sensitive,
+    /// as in the scheme).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseAdjustRuleError`] for codes with no findates rule,
+    /// such as `FRN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::AdjustRule;
+    ///
+    /// assert_eq!(AdjustRule::from_fpml("MODFOLLOWING"), Ok(AdjustRule::ModFollowing));
+    /// assert!(AdjustRule::from_fpml("FRN").is_err());
+    /// ```
+    pub fn from_fpml(code: &str) -> Result<Self, ParseAdjustRuleError> {
+        match code {
+            "FOLLOWING" => Ok(AdjustRule::Following),
+            "MODFOLLOWING" => Ok(AdjustRule::ModFollowing),
+            "PRECEDING" => Ok(AdjustRule::Preceding),
+            "MODPRECEDING" => Ok(AdjustRule::ModPreceding),
+            "NONE" => Ok(AdjustRule::Unadjusted),
+            "NEAREST" => Ok(AdjustRule::Nearest),
+            _ => Err(ParseAdjustRuleError),
+        }
+    }
+}
+
 impl fmt::Display for AdjustRule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -207,20 +478,16 @@ impl fmt::Display for AdjustRule {
     }
 }
 
-/// Error returned when a string cannot be parsed into an [`AdjustRule`].
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseAdjustRuleError;
-
-impl fmt::Display for ParseAdjustRuleError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "unknown adjust rule string")
-    }
-}
-
 impl FromStr for AdjustRule {
     type Err = ParseAdjustRuleError;
 
-    /// Parse an [`AdjustRule`] from its canonical string representation (case-sensitive).
+    /// Parse an [`AdjustRule`] from its variant name or a market alias.
+    ///
+    /// Matching ignores case, spaces and `/ - _ . ( )`.  Besides the variant
+    /// names it accepts `F`, `MF` and `Modified Following`, `P`, `MP` and
+    /// `Modified Preceding`, `None` for
+    /// [`Unadjusted`](AdjustRule::Unadjusted), and `HMMF` and
+    /// `Half-Month Modified Following`.
     ///
     /// # Examples
     ///
@@ -228,28 +495,60 @@ impl FromStr for AdjustRule {
     /// use findates::conventions::AdjustRule;
     ///
     /// assert_eq!("Following".parse::<AdjustRule>().unwrap(), AdjustRule::Following);
-    /// assert!("following".parse::<AdjustRule>().is_err()); // case-sensitive
+    /// assert_eq!("following".parse::<AdjustRule>().unwrap(), AdjustRule::Following);
+    /// assert_eq!("MF".parse::<AdjustRule>().unwrap(), AdjustRule::ModFollowing);
+    /// assert_eq!("ModifiedFollowing".parse::<AdjustRule>().unwrap(), AdjustRule::ModFollowing);
+    /// assert!("Backward".parse::<AdjustRule>().is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Following" => Ok(AdjustRule::Following),
-            "ModFollowing" => Ok(AdjustRule::ModFollowing),
-            "Preceding" => Ok(AdjustRule::Preceding),
-            "ModPreceding" => Ok(AdjustRule::ModPreceding),
-            "Unadjusted" => Ok(AdjustRule::Unadjusted),
-            "HalfMonthModFollowing" => Ok(AdjustRule::HalfMonthModFollowing),
-            "Nearest" => Ok(AdjustRule::Nearest),
+        match normalize(s).as_str() {
+            "FOLLOWING" | "F" => Ok(AdjustRule::Following),
+            "MODFOLLOWING" | "MODIFIEDFOLLOWING" | "MF" => Ok(AdjustRule::ModFollowing),
+            "PRECEDING" | "P" => Ok(AdjustRule::Preceding),
+            "MODPRECEDING" | "MODIFIEDPRECEDING" | "MP" => Ok(AdjustRule::ModPreceding),
+            "UNADJUSTED" | "NONE" => Ok(AdjustRule::Unadjusted),
+            "HALFMONTHMODFOLLOWING" | "HALFMONTHMODIFIEDFOLLOWING" | "HMMF" => {
+                Ok(AdjustRule::HalfMonthModFollowing)
+            }
+            "NEAREST" => Ok(AdjustRule::Nearest),
             _ => Err(ParseAdjustRuleError),
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for AdjustRule {
+    // FpML codes, with the spelled-out name for the one FpML lacks.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_fpml().unwrap_or("HALFMONTHMODFOLLOWING"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AdjustRule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(|_| serde::de::Error::custom(format_args!("unknown business day convention {s:?}")))
+    }
+}
+
 /// Coupon or payment frequencies.
 ///
 /// Used by [`Schedule`](crate::schedule::Schedule) to determine how dates are
 /// stepped forward in time.  Frequencies are defined relative to a one-year
 /// period.
 ///
+/// Periods without a named variant, such as the 13-week roll of a Treasury
+/// bill or the 28-day period of a TIIE swap, are expressed with
+/// [`EveryNMonths`](Frequency::EveryNMonths),
+/// [`EveryNWeeks`](Frequency::EveryNWeeks) and
+/// [`EveryNDays`](Frequency::EveryNDays).  Build them with
+/// [`Frequency::every_n_months`] and friends, which return the named variant
+/// when there is one.  A parametric variant written directly with the period
+/// of a named one, such as `EveryNMonths(3)`, is treated as that variant: it
+/// compares and hashes equal to [`Quarterly`](Frequency::Quarterly) and
+/// displays and serializes the same way.
+///
 /// # Examples
 ///
 /// ```rust
@@ -261,8 +560,7 @@ impl FromStr for AdjustRule {
 /// let parsed: Frequency = "Monthly".parse().unwrap();
 /// assert_eq!(parsed, Frequency::Monthly);
 /// ```
-#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Eq, Copy, Clone, Debug)]
 pub enum Frequency {
     /// Zero coupon (single payment at maturity). For
     /// [`Schedule::generate`](crate::schedule::Schedule::generate),
@@ -294,7 +592,7 @@ pub enum Frequency {
     /// QuantLib equivalent: `Frequency::Monthly`
     Monthly,
     /// Every month, always landing on the last calendar day of the month.
-    /// When a [`Calendar`](crate::calendar::Calendar) and
+    /// When a [`Calendar`] and
     /// [`AdjustRule`] are provided, the
     /// last calendar day is further adjusted to the nearest business day.
     EndOfMonth,
@@ -312,11 +610,312 @@ pub enum Frequency {
     ///
     /// QuantLib equivalent: `Frequency::Daily`
     Daily,
+    /// Every `n` months, e.g. `EveryNMonths(5)`.  A zero count has no
+    /// period and generates no dates after the anchor.
+    EveryNMonths(u32),
+    /// Every `n` weeks, e.g. `EveryNWeeks(13)` for a Treasury bill roll.  A
+    /// zero count has no period and generates no dates after the anchor.
+    EveryNWeeks(u32),
+    /// Every `n` calendar days, e.g. `EveryNDays(28)` for a TIIE swap.  A
+    /// zero count has no period and generates no dates after the anchor.
+    EveryNDays(u32),
+}
+
+impl PartialEq for Frequency {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl std::hash::Hash for Frequency {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+impl Frequency {
+    // The named variant for a parametric frequency's period, if there is
+    // one; `self` otherwise, including for zero counts.
+    fn normalized(self) -> Frequency {
+        match self {
+            Frequency::EveryNMonths(n) => Frequency::every_n_months(n),
+            Frequency::EveryNWeeks(n) => Frequency::every_n_weeks(n),
+            Frequency::EveryNDays(n) => Frequency::every_n_days(n),
+            _ => None,
+        }
+        .unwrap_or(self)
+    }
+
+    // What equality and hashing compare: the variant and count after
+    // normalizing.
+    fn identity(&self) -> (std::mem::Discriminant<Frequency>, u32) {
+        let normalized = self.normalized();
+        let count = match normalized {
+            Frequency::EveryNMonths(n) | Frequency::EveryNWeeks(n) | Frequency::EveryNDays(n) => n,
+            _ => 0,
+        };
+        (std::mem::discriminant(&normalized), count)
+    }
+
+    /// Returns the frequency with a period of `n` months: the named variant
+    /// when there is one, otherwise [`EveryNMonths`](Frequency::EveryNMonths).
+    /// Returns `None` if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::every_n_months(3), Some(Frequency::Quarterly));
+    /// assert_eq!(Frequency::every_n_months(5), Some(Frequency::EveryNMonths(5)));
+    /// assert_eq!(Frequency::every_n_months(0), None);
+    /// ```
+    pub fn every_n_months(n: u32) -> Option<Frequency> {
+        match n {
+            0 => None,
+            1 => Some(Frequency::Monthly),
+            2 => Some(Frequency::Bimonthly),
+            3 => Some(Frequency::Quarterly),
+            4 => Some(Frequency::EveryFourthMonth),
+            6 => Some(Frequency::Semiannual),
+            12 => Some(Frequency::Annual),
+            _ => Some(Frequency::EveryNMonths(n)),
+        }
+    }
+
+    /// Returns the frequency with a period of `n` weeks: the named variant
+    /// when there is one, otherwise [`EveryNWeeks`](Frequency::EveryNWeeks).
+    /// Returns `None` if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::every_n_weeks(2), Some(Frequency::Biweekly));
+    /// assert_eq!(Frequency::every_n_weeks(13), Some(Frequency::EveryNWeeks(13)));
+    /// ```
+    pub fn every_n_weeks(n: u32) -> Option<Frequency> {
+        match n {
+            0 => None,
+            1 => Some(Frequency::Weekly),
+            2 => Some(Frequency::Biweekly),
+            4 => Some(Frequency::EveryFourthWeek),
+            _ => Some(Frequency::EveryNWeeks(n)),
+        }
+    }
+
+    /// Returns the frequency with a period of `n` days: the named variant
+    /// when there is one, a week-based frequency when `n` is a whole number
+    /// of weeks, otherwise [`EveryNDays`](Frequency::EveryNDays).  Returns
+    /// `None` if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::every_n_days(1), Some(Frequency::Daily));
+    /// assert_eq!(Frequency::every_n_days(28), Some(Frequency::EveryFourthWeek));
+    /// assert_eq!(Frequency::every_n_days(91), Some(Frequency::EveryNWeeks(13)));
+    /// assert_eq!(Frequency::every_n_days(30), Some(Frequency::EveryNDays(30)));
+    /// ```
+    pub fn every_n_days(n: u32) -> Option<Frequency> {
+        match n {
+            0 => None,
+            1 => Some(Frequency::Daily),
+            _ if n % 7 == 0 => Frequency::every_n_weeks(n / 7),
+            _ => Some(Frequency::EveryNDays(n)),
+        }
+    }
+
+    /// Returns the FpML frequency code for this frequency: the period
+    /// multiplier followed by the period, e.g. `6M`, with `1T` (term) for
+    /// [`Zero`](Frequency::Zero).
+    ///
+    /// [`EndOfMonth`](Frequency::EndOfMonth) is `1M`; FpML carries the month
+    /// end in the `EOM` roll convention instead (see
+    /// [`RollConvention::EndOfMonth`]).  The parametric frequencies give
+    /// their count, e.g. `13W` for [`EveryNWeeks(13)`](Frequency::EveryNWeeks),
+    /// or the code of the named variant with the same period.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::Semiannual.to_fpml(), "6M");
+    /// assert_eq!(Frequency::Zero.to_fpml(), "1T");
+    /// assert_eq!(Frequency::EveryNDays(30).to_fpml(), "30D");
+    /// assert_eq!(Frequency::EveryNDays(28).to_fpml(), "4W");
+    /// ```
+    pub fn to_fpml(&self) -> String {
+        let code = match self.normalized() {
+            Frequency::Zero => "1T",
+            Frequency::Annual => "1Y",
+            Frequency::Semiannual => "6M",
+            Frequency::EveryFourthMonth => "4M",
+            Frequency::Quarterly => "3M",
+            Frequency::Bimonthly => "2M",
+            Frequency::Monthly | Frequency::EndOfMonth => "1M",
+            Frequency::EveryFourthWeek => "4W",
+            Frequency::Biweekly => "2W",
+            Frequency::Weekly => "1W",
+            Frequency::Daily => "1D",
+            Frequency::EveryNMonths(n) => return format!("{n}M"),
+            Frequency::EveryNWeeks(n) => return format!("{n}W"),
+            Frequency::EveryNDays(n) => return format!("{n}D"),
+        };
+        code.to_string()
+    }
+
+    /// Parses an FpML frequency code such as `3M` (case-sensitive, as in the
+    /// scheme).  Equal periods give the same frequency: `12M` and `1Y` are
+    /// both [`Annual`](Frequency::Annual), and periods without a named
+    /// variant give [`EveryNMonths`](Frequency::EveryNMonths) and friends.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFrequencyError`] for anything other than a positive
+    /// multiplier followed by `D`, `W`, `M` or `Y`, or `1T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::from_fpml("3M"), Ok(Frequency::Quarterly));
+    /// assert_eq!(Frequency::from_fpml("1T"), Ok(Frequency::Zero));
+    /// assert_eq!(Frequency::from_fpml("5M"), Ok(Frequency::EveryNMonths(5)));
+    /// assert!(Frequency::from_fpml("0M").is_err());
+    /// ```
+    pub fn from_fpml(code: &str) -> Result<Self, ParseFrequencyError> {
+        if code == "1T" {
+            return Ok(Frequency::Zero);
+        }
+        let (split, _) = code.char_indices().last().ok_or(ParseFrequencyError)?;
+        let (count, unit) = code.split_at(split);
+        if count.is_empty() || !count.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseFrequencyError);
+        }
+        let n: u32 = count.parse().map_err(|_| ParseFrequencyError)?;
+        match unit {
+            "D" => Frequency::every_n_days(n),
+            "W" => Frequency::every_n_weeks(n),
+            "M" => Frequency::every_n_months(n),
+            "Y" => Frequency::every_n_months(n.checked_mul(12).ok_or(ParseFrequencyError)?),
+            _ => None,
+        }
+        .ok_or(ParseFrequencyError)
+    }
+
+    /// Returns the number of periods in a year, or `None` for
+    /// [`Zero`](Frequency::Zero) and zero counts.
+    ///
+    /// Week-based frequencies count 52 weeks to the year and
+    /// [`Daily`](Frequency::Daily) 365 days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::Quarterly.periods_per_year(), Some(4.0));
+    /// assert_eq!(Frequency::Biweekly.periods_per_year(), Some(26.0));
+    /// assert_eq!(Frequency::EveryNWeeks(13).periods_per_year(), Some(4.0));
+    /// assert_eq!(Frequency::Zero.periods_per_year(), None);
+    /// ```
+    pub fn periods_per_year(&self) -> Option<f64> {
+        match self {
+            Frequency::Zero => None,
+            Frequency::Annual => Some(1.0),
+            Frequency::Semiannual => Some(2.0),
+            Frequency::EveryFourthMonth => Some(3.0),
+            Frequency::Quarterly => Some(4.0),
+            Frequency::Bimonthly => Some(6.0),
+            Frequency::Monthly | Frequency::EndOfMonth => Some(12.0),
+            Frequency::EveryFourthWeek => Some(13.0),
+            Frequency::Biweekly => Some(26.0),
+            Frequency::Weekly => Some(52.0),
+            Frequency::Daily => Some(365.0),
+            Frequency::EveryNMonths(n) => (*n > 0).then(|| 12.0 / f64::from(*n)),
+            Frequency::EveryNWeeks(n) => (*n > 0).then(|| 52.0 / f64::from(*n)),
+            Frequency::EveryNDays(n) => (*n > 0).then(|| 365.0 / f64::from(*n)),
+        }
+    }
+
+    /// Returns the length of one period in months, or `None` if the
+    /// frequency is not a whole number of months.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::Semiannual.to_months(), Some(6));
+    /// assert_eq!(Frequency::EndOfMonth.to_months(), Some(1));
+    /// assert_eq!(Frequency::EveryNMonths(5).to_months(), Some(5));
+    /// assert_eq!(Frequency::Weekly.to_months(), None);
+    /// ```
+    pub fn to_months(&self) -> Option<u32> {
+        match self {
+            Frequency::Annual => Some(12),
+            Frequency::Semiannual => Some(6),
+            Frequency::EveryFourthMonth => Some(4),
+            Frequency::Quarterly => Some(3),
+            Frequency::Bimonthly => Some(2),
+            Frequency::Monthly | Frequency::EndOfMonth => Some(1),
+            Frequency::EveryNMonths(n) => Some(*n).filter(|&n| n > 0),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of one period in days, or `None` if the frequency
+    /// is month-based, [`Zero`](Frequency::Zero) or has a zero count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    ///
+    /// assert_eq!(Frequency::Biweekly.to_days(), Some(14));
+    /// assert_eq!(Frequency::EveryNWeeks(13).to_days(), Some(91));
+    /// assert_eq!(Frequency::Monthly.to_days(), None);
+    /// ```
+    pub fn to_days(&self) -> Option<u32> {
+        match self {
+            Frequency::EveryFourthWeek => Some(28),
+            Frequency::Biweekly => Some(14),
+            Frequency::Weekly => Some(7),
+            Frequency::Daily => Some(1),
+            Frequency::EveryNWeeks(n) => n.checked_mul(7).filter(|&days| days > 0),
+            Frequency::EveryNDays(n) => Some(*n).filter(|&n| n > 0),
+            _ => None,
+        }
+    }
+
+    /// Returns the frequency whose period is `tenor`, or `None` if there is
+    /// none.  Same as [`Tenor::to_frequency`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, Tenor};
+    ///
+    /// assert_eq!(Frequency::from_tenor(Tenor::Months(3)), Some(Frequency::Quarterly));
+    /// assert_eq!(Frequency::from_tenor(Tenor::Days(7)), Some(Frequency::Weekly));
+    /// assert_eq!(Frequency::from_tenor(Tenor::Overnight), None);
+    /// ```
+    pub fn from_tenor(tenor: Tenor) -> Option<Frequency> {
+        tenor.to_frequency()
+    }
 }
 
 impl fmt::Display for Frequency {
+    /// Named variants display as their name and parametric ones as the
+    /// tenor of one period, e.g. `13W`; both parse back.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
+        match self.normalized() {
             Frequency::Zero => write!(f, "Zero"),
             Frequency::Annual => write!(f, "Annual"),
             Frequency::Semiannual => write!(f, "Semiannual"),
@@ -329,24 +928,28 @@ impl fmt::Display for Frequency {
             Frequency::Biweekly => write!(f, "Biweekly"),
             Frequency::Weekly => write!(f, "Weekly"),
             Frequency::Daily => write!(f, "Daily"),
+            Frequency::EveryNMonths(n) => write!(f, "{n}M"),
+            Frequency::EveryNWeeks(n) => write!(f, "{n}W"),
+            Frequency::EveryNDays(n) => write!(f, "{n}D"),
         }
     }
 }
 
-/// Error returned when a string cannot be parsed into a [`Frequency`].
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseFrequencyError;
-
-impl fmt::Display for ParseFrequencyError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "unknown frequency string")
-    }
-}
-
 impl FromStr for Frequency {
     type Err = ParseFrequencyError;
 
-    /// Parse a [`Frequency`] from its canonical string representation (case-sensitive).
+    /// Parse a [`Frequency`] from its variant name, an alias or the tenor
+    /// of one period.
+    ///
+    /// Matching ignores case, spaces and `/ - _ . ( )`, so `"Semi-Annual"`
+    /// and `"SEMIANNUAL"` both parse.  Also accepted: `Once` for
+    /// [`Zero`](Frequency::Zero), `Annually` and `Yearly`, `Semiannually`
+    /// and `S/A`, `EOM`, `Fortnightly`, `1T` (term), and the tenor of any
+    /// whole number of days, weeks, months or years, which parses like
+    /// [`Frequency::from_fpml`] (so `5M` gives
+    /// [`EveryNMonths(5)`](Frequency::EveryNMonths) and `12M` gives
+    /// [`Annual`](Frequency::Annual)).  The variant form of the parametric
+    /// frequencies, such as `EveryNWeeks(13)`, is accepted too.
     ///
     /// # Examples
     ///
@@ -354,60 +957,1077 @@ impl FromStr for Frequency {
     /// use findates::conventions::Frequency;
     ///
     /// assert_eq!("Quarterly".parse::<Frequency>().unwrap(), Frequency::Quarterly);
-    /// assert!("quarterly".parse::<Frequency>().is_err()); // case-sensitive
+    /// assert_eq!("quarterly".parse::<Frequency>().unwrap(), Frequency::Quarterly);
+    /// assert_eq!("SemiAnnual".parse::<Frequency>().unwrap(), Frequency::Semiannual);
+    /// assert_eq!("6M".parse::<Frequency>().unwrap(), Frequency::Semiannual);
+    /// assert_eq!("13w".parse::<Frequency>().unwrap(), Frequency::EveryNWeeks(13));
+    /// assert_eq!("EveryNMonths(6)".parse::<Frequency>().unwrap(), Frequency::EveryNMonths(6));
+    /// assert!("0M".parse::<Frequency>().is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Zero" => Ok(Frequency::Zero),
-            "Annual" => Ok(Frequency::Annual),
-            "Semiannual" => Ok(Frequency::Semiannual),
-            "EveryFourthMonth" => Ok(Frequency::EveryFourthMonth),
-            "Quarterly" => Ok(Frequency::Quarterly),
-            "Bimonthly" => Ok(Frequency::Bimonthly),
-            "Monthly" => Ok(Frequency::Monthly),
-            "EndOfMonth" => Ok(Frequency::EndOfMonth),
-            "EveryFourthWeek" => Ok(Frequency::EveryFourthWeek),
-            "Biweekly" => Ok(Frequency::Biweekly),
-            "Weekly" => Ok(Frequency::Weekly),
-            "Daily" => Ok(Frequency::Daily),
-            _ => Err(ParseFrequencyError),
+        let s = normalize(s);
+        let count = |prefix: &str| {
+            let n = s.strip_prefix(prefix)?;
+            let n: u32 = n.bytes().all(|b| b.is_ascii_digit()).then(|| n.parse().ok())??;
+            Some(n).filter(|&n| n > 0)
+        };
+        if let Some(n) = count("EVERYNMONTHS") {
+            return Frequency::every_n_months(n).ok_or(ParseFrequencyError);
+        }
+        if let Some(n) = count("EVERYNWEEKS") {
+            return Frequency::every_n_weeks(n).ok_or(ParseFrequencyError);
+        }
+        if let Some(n) = count("EVERYNDAYS") {
+            return Frequency::every_n_days(n).ok_or(ParseFrequencyError);
+        }
+        match s.as_str() {
+            "ZERO" | "ONCE" => Ok(Frequency::Zero),
+            "ANNUAL" | "ANNUALLY" | "YEARLY" => Ok(Frequency::Annual),
+            "SEMIANNUAL" | "SEMIANNUALLY" | "SA" => Ok(Frequency::Semiannual),
+            "EVERYFOURTHMONTH" => Ok(Frequency::EveryFourthMonth),
+            "QUARTERLY" => Ok(Frequency::Quarterly),
+            "BIMONTHLY" => Ok(Frequency::Bimonthly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "ENDOFMONTH" | "EOM" => Ok(Frequency::EndOfMonth),
+            "EVERYFOURTHWEEK" => Ok(Frequency::EveryFourthWeek),
+            "BIWEEKLY" | "FORTNIGHTLY" => Ok(Frequency::Biweekly),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "DAILY" => Ok(Frequency::Daily),
+            code => Frequency::from_fpml(code),
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn from_string_parse_test() {
-        let from_str = DayCount::from_str("ActActISDA");
-        assert_eq!(DayCount::ActActISDA, from_str.unwrap());
+#[cfg(feature = "serde")]
+impl serde::Serialize for Frequency {
+    // FpML codes, except `EOM` so that end-of-month stays distinct from `1M`.
+    // Parametric frequencies are written as the named variant for their
+    // period, if there is one; a zero count has no code and is an error.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Frequency::EndOfMonth => serializer.serialize_str("EOM"),
+            _ if self.periods_per_year().is_none() && *self != Frequency::Zero => {
+                Err(serde::ser::Error::custom(format_args!("frequency {self:?} has no period")))
+            }
+            _ => serializer.serialize_str(&self.to_fpml()),
+        }
     }
+}
 
-    #[test]
-    #[should_panic]
-    fn incorrect_string_panic_test() {
-        // Case sensitive
-        let _from_str = DayCount::from_str("ActActIsda").unwrap();
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Frequency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(|_| serde::de::Error::custom(format_args!("unknown frequency {s:?}")))
     }
+}
 
-    #[test]
-    #[should_panic]
-    fn not_implemented_convention_panic_test() {
-        let _from_str = DayCount::from_str("D30360ISDA").unwrap();
-    }
+// Upper-cases `s` and drops the separators convention names are written
+// with, so "Act/365 (Fixed)" and "ACT365FIXED" compare equal.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !matches!(c, ' ' | '\t' | '/' | '-' | '_' | '.' | '(' | ')'))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
 
-    #[test]
-    fn to_string_test() {
-        let conv = AdjustRule::HalfMonthModFollowing;
-        assert_eq!(conv.to_string(), "HalfMonthModFollowing");
-    }
+/// A period of time in market notation, such as `3M`, `10Y`, `2W` or `ON`.
+///
+/// Tenors are how practitioners specify maturities, coupon periods and date
+/// offsets.  They parse from and display as the usual short strings; parsing
+/// is case-insensitive and also accepts `O/N`, `T/N` and `S/N`.
+///
+/// Adding a month or year tenor to a date follows chrono's month arithmetic:
+/// the day of month is kept where possible and clamped to the last day of
+/// shorter months (31 Jan + 1M = 29 Feb in a leap year).  Business-day
+/// adjustment is not applied here.
+///
+/// The money-market tenors [`Overnight`](Tenor::Overnight),
+/// [`TomorrowNext`](Tenor::TomorrowNext) and [`SpotNext`](Tenor::SpotNext)
+/// each span one day; they differ only in where that day starts (today,
+/// tomorrow, spot), which depends on a calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::Tenor;
+///
+/// let tenor: Tenor = "3m".parse().unwrap();
+/// assert_eq!(tenor, Tenor::Months(3));
+/// assert_eq!(tenor.to_string(), "3M");
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// assert_eq!(d + Tenor::Months(1), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub enum Tenor {
+    /// A number of calendar days, e.g. `1D`.
+    Days(u32),
+    /// A number of weeks, e.g. `2W`.
+    Weeks(u32),
+    /// A number of months, e.g. `6M`.
+    Months(u32),
+    /// A number of years, e.g. `10Y`.
+    Years(u32),
+    /// Overnight (`ON`): from today to the next business day.
+    Overnight,
+    /// Tomorrow-next (`TN`): from tomorrow to the business day after.
+    TomorrowNext,
+    /// Spot-next (`SN`): from the spot date to the business day after.
+    SpotNext,
+}
 
-    #[test]
-    fn eq_trait_test() {
-        let conv = Frequency::EveryFourthMonth;
-        assert_eq!(conv, Frequency::EveryFourthMonth);
+impl Tenor {
+    /// Returns `date` moved forward by this tenor, or `None` if the result
+    /// is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Tenor;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    /// assert_eq!(Tenor::Years(10).add_to(&d), NaiveDate::from_ymd_opt(2033, 8, 15));
+    /// assert_eq!(Tenor::Years(1).add_to(&NaiveDate::MAX), None);
+    /// ```
+    pub fn add_to(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            Tenor::Days(n) => date.checked_add_days(Days::new(n as u64)),
+            Tenor::Weeks(n) => date.checked_add_days(Days::new(n as u64 * 7)),
+            Tenor::Months(n) => date.checked_add_months(Months::new(n)),
+            Tenor::Years(n) => date.checked_add_months(Months::new(n.checked_mul(12)?)),
+            Tenor::Overnight | Tenor::TomorrowNext | Tenor::SpotNext => {
+                date.checked_add_days(Days::new(1))
+            }
+        }
+    }
+
+    /// Returns `date` moved backward by this tenor, or `None` if the result
+    /// is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Tenor;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    /// assert_eq!(Tenor::Months(1).sub_from(&d), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// ```
+    pub fn sub_from(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            Tenor::Days(n) => date.checked_sub_days(Days::new(n as u64)),
+            Tenor::Weeks(n) => date.checked_sub_days(Days::new(n as u64 * 7)),
+            Tenor::Months(n) => date.checked_sub_months(Months::new(n)),
+            Tenor::Years(n) => date.checked_sub_months(Months::new(n.checked_mul(12)?)),
+            Tenor::Overnight | Tenor::TomorrowNext | Tenor::SpotNext => {
+                date.checked_sub_days(Days::new(1))
+            }
+        }
+    }
+
+    /// Returns the tenor of one period of `frequency`, or `None` for
+    /// [`Frequency::Zero`] which has no regular period.
+    ///
+    /// [`Frequency::EndOfMonth`] maps to `1M`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, Tenor};
+    ///
+    /// assert_eq!(Tenor::from_frequency(Frequency::Semiannual), Some(Tenor::Months(6)));
+    /// assert_eq!(Tenor::from_frequency(Frequency::Zero), None);
+    /// ```
+    pub fn from_frequency(frequency: Frequency) -> Option<Tenor> {
+        match frequency {
+            Frequency::Zero => None,
+            Frequency::Annual => Some(Tenor::Years(1)),
+            Frequency::Semiannual => Some(Tenor::Months(6)),
+            Frequency::EveryFourthMonth => Some(Tenor::Months(4)),
+            Frequency::Quarterly => Some(Tenor::Months(3)),
+            Frequency::Bimonthly => Some(Tenor::Months(2)),
+            Frequency::Monthly | Frequency::EndOfMonth => Some(Tenor::Months(1)),
+            Frequency::EveryFourthWeek => Some(Tenor::Weeks(4)),
+            Frequency::Biweekly => Some(Tenor::Weeks(2)),
+            Frequency::Weekly => Some(Tenor::Weeks(1)),
+            Frequency::Daily => Some(Tenor::Days(1)),
+            Frequency::EveryNMonths(n) => Some(Tenor::Months(n)),
+            Frequency::EveryNWeeks(n) => Some(Tenor::Weeks(n)),
+            Frequency::EveryNDays(n) => Some(Tenor::Days(n)),
+        }
+    }
+
+    /// Returns the [`Frequency`] whose period equals this tenor, or `None`
+    /// for zero-length and money-market tenors such as `0D` or `ON`.
+    ///
+    /// Equivalent spellings map to the same frequency: `12M` and `1Y` are
+    /// both [`Frequency::Annual`], `7D` and `1W` are both [`Frequency::Weekly`].
+    /// Periods without a named variant map to
+    /// [`Frequency::EveryNMonths`], [`Frequency::EveryNWeeks`] or
+    /// [`Frequency::EveryNDays`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, Tenor};
+    ///
+    /// assert_eq!(Tenor::Months(3).to_frequency(), Some(Frequency::Quarterly));
+    /// assert_eq!(Tenor::Months(12).to_frequency(), Some(Frequency::Annual));
+    /// assert_eq!(Tenor::Months(5).to_frequency(), Some(Frequency::EveryNMonths(5)));
+    /// assert_eq!(Tenor::Overnight.to_frequency(), None);
+    /// ```
+    pub fn to_frequency(&self) -> Option<Frequency> {
+        match *self {
+            Tenor::Days(n) => Frequency::every_n_days(n),
+            Tenor::Weeks(n) => Frequency::every_n_weeks(n),
+            Tenor::Months(n) => Frequency::every_n_months(n),
+            Tenor::Years(n) => Frequency::every_n_months(n.checked_mul(12)?),
+            Tenor::Overnight | Tenor::TomorrowNext | Tenor::SpotNext => None,
+        }
+    }
+}
+
+/// Returns `date + tenor`, or `None` if the result is out of range.
+///
+/// The checked form of `NaiveDate + Tenor`, the same as [`Tenor::add_to`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::{checked_add_tenor, Tenor};
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+/// assert_eq!(checked_add_tenor(&d, Tenor::Months(1)), NaiveDate::from_ymd_opt(2024, 2, 29));
+/// assert_eq!(checked_add_tenor(&NaiveDate::MAX, Tenor::Days(1)), None);
+/// ```
+pub fn checked_add_tenor(date: &NaiveDate, tenor: Tenor) -> Option<NaiveDate> {
+    tenor.add_to(date)
+}
+
+/// Returns `date - tenor`, or `None` if the result is out of range.
+///
+/// The checked form of `NaiveDate - Tenor`, the same as [`Tenor::sub_from`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::{checked_sub_tenor, Tenor};
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+/// assert_eq!(checked_sub_tenor(&d, Tenor::Weeks(2)), NaiveDate::from_ymd_opt(2024, 3, 17));
+/// assert_eq!(checked_sub_tenor(&NaiveDate::MIN, Tenor::Years(1)), None);
+/// ```
+pub fn checked_sub_tenor(date: &NaiveDate, tenor: Tenor) -> Option<NaiveDate> {
+    tenor.sub_from(date)
+}
+
+impl Add<Tenor> for NaiveDate {
+    type Output = NaiveDate;
+
+    /// Moves the date forward by `tenor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range; use [`checked_add_tenor`] to
+    /// handle that case.
+    fn add(self, tenor: Tenor) -> NaiveDate {
+        tenor.add_to(&self).expect("`NaiveDate + Tenor` overflowed")
+    }
+}
+
+impl Sub<Tenor> for NaiveDate {
+    type Output = NaiveDate;
+
+    /// Moves the date backward by `tenor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range; use [`checked_sub_tenor`] to
+    /// handle that case.
+    fn sub(self, tenor: Tenor) -> NaiveDate {
+        tenor.sub_from(&self).expect("`NaiveDate - Tenor` overflowed")
+    }
+}
+
+impl fmt::Display for Tenor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tenor::Days(n) => write!(f, "{n}D"),
+            Tenor::Weeks(n) => write!(f, "{n}W"),
+            Tenor::Months(n) => write!(f, "{n}M"),
+            Tenor::Years(n) => write!(f, "{n}Y"),
+            Tenor::Overnight => write!(f, "ON"),
+            Tenor::TomorrowNext => write!(f, "TN"),
+            Tenor::SpotNext => write!(f, "SN"),
+        }
+    }
+}
+
+impl FromStr for Tenor {
+    type Err = ParseTenorError;
+
+    /// Parse a [`Tenor`] from market notation (case-insensitive).
+    ///
+    /// Accepts a non-negative integer followed by `D`, `W`, `M` or `Y`, or
+    /// one of `ON`, `TN`, `SN` (optionally written `O/N`, `T/N`, `S/N`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Tenor;
+    ///
+    /// assert_eq!("10Y".parse::<Tenor>().unwrap(), Tenor::Years(10));
+    /// assert_eq!("2w".parse::<Tenor>().unwrap(), Tenor::Weeks(2));
+    /// assert_eq!("T/N".parse::<Tenor>().unwrap(), Tenor::TomorrowNext);
+    /// assert!("M3".parse::<Tenor>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.trim().to_ascii_uppercase();
+        match upper.as_str() {
+            "ON" | "O/N" => return Ok(Tenor::Overnight),
+            "TN" | "T/N" => return Ok(Tenor::TomorrowNext),
+            "SN" | "S/N" => return Ok(Tenor::SpotNext),
+            _ => {}
+        }
+        let unit = upper.chars().last().ok_or(ParseTenorError)?;
+        let digits = &upper[..upper.len() - unit.len_utf8()];
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseTenorError);
+        }
+        let length: u32 = digits.parse().map_err(|_| ParseTenorError)?;
+        match unit {
+            'D' => Ok(Tenor::Days(length)),
+            'W' => Ok(Tenor::Weeks(length)),
+            'M' => Ok(Tenor::Months(length)),
+            'Y' => Ok(Tenor::Years(length)),
+            _ => Err(ParseTenorError),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Tenor {
+    // Market notation, e.g. `3M` or `ON`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// The variant form tenors were serialized in before they used market
+// notation, e.g. `{"Months":3}` or `"Overnight"`, still accepted on input.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(remote = "Tenor")]
+enum TenorVariant {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+    Overnight,
+    TomorrowNext,
+    SpotNext,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum TenorData {
+    Variant(#[serde(with = "TenorVariant")] Tenor),
+    Code(String),
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Tenor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TenorData::deserialize(deserializer)? {
+            TenorData::Variant(tenor) => Ok(tenor),
+            TenorData::Code(s) => s.parse().map_err(|_| serde::de::Error::custom(format_args!("unknown tenor {s:?}"))),
+        }
+    }
+}
+
+/// Roll conventions: which day of the period a schedule's dates are pinned to.
+///
+/// Mirrors the ISDA roll-convention concept.  Set one on a
+/// [`Schedule`](crate::schedule::Schedule) with
+/// [`with_roll`](crate::schedule::Schedule::with_roll); the frequency then
+/// chooses the month (or week) of each date and the roll convention chooses
+/// the day within it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::RollConvention;
+///
+/// let d = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+/// assert_eq!(RollConvention::Imm.apply(&d), NaiveDate::from_ymd_opt(2024, 3, 20));
+///
+/// let parsed: RollConvention = "EOM".parse().unwrap();
+/// assert_eq!(parsed, RollConvention::EndOfMonth);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub enum RollConvention {
+    /// A fixed day of the month (1 to 31), clamped to the month's length so
+    /// that `Day(31)` lands on 30 April and 28 or 29 February.
+    ///
+    /// FpML codes: `1` to `30`
+    Day(u32),
+    /// The last day of the month.
+    ///
+    /// FpML code: `EOM`
+    EndOfMonth,
+    /// The third Wednesday of the month (IMM settlement date).
+    ///
+    /// FpML code: `IMM`
+    Imm,
+    /// The 20th of the month, the standard CDS date.  Combined with a
+    /// quarterly frequency anchored in an IMM month this gives the CDS
+    /// schedule of [`cds`](crate::cds).
+    Cds,
+    /// A day of the week, for weekly frequencies.
+    ///
+    /// FpML codes: `MON` to `SUN`
+    DayOfWeek(Weekday),
+    /// The first business day of the month in the schedule's calendar.  With
+    /// a quarterly frequency anchored in January, April, July or October
+    /// this is the first business day of each quarter.
+    ///
+    /// Code: `FBD`
+    FirstBusinessDay,
+    /// The last business day of the month in the schedule's calendar, the
+    /// usual month-end reporting or fund NAV date.  With a quarterly
+    /// frequency anchored in March, June, September or December this is the
+    /// last business day of each quarter.
+    ///
+    /// Code: `LBD`
+    LastBusinessDay,
+    /// The `n`-th business day of the month in the schedule's calendar,
+    /// counting from 1.  Months with fewer than `n` business days have no
+    /// such date.
+    ///
+    /// Codes: `BD1` to `BD31`
+    NthBusinessDay(u32),
+}
+
+impl RollConvention {
+    /// Pins `date` to this roll convention.
+    ///
+    /// Day-of-month conventions keep the year and month of `date` and replace
+    /// the day.  [`DayOfWeek`](RollConvention::DayOfWeek) moves to the first
+    /// matching weekday on or after `date`.  The business day conventions
+    /// count every day as a business day here; use
+    /// [`apply_in`](RollConvention::apply_in) to apply them in a calendar.
+    /// Returns `None` for a [`Day`](RollConvention::Day) outside 1 to 31, an
+    /// [`NthBusinessDay`](RollConvention::NthBusinessDay) the month does not
+    /// have, or if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::conventions::RollConvention;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2023, 2, 10).unwrap(); // Friday
+    /// assert_eq!(RollConvention::Day(31).apply(&d), NaiveDate::from_ymd_opt(2023, 2, 28));
+    /// assert_eq!(RollConvention::Cds.apply(&d), NaiveDate::from_ymd_opt(2023, 2, 20));
+    /// assert_eq!(
+    ///     RollConvention::DayOfWeek(Weekday::Mon).apply(&d),
+    ///     NaiveDate::from_ymd_opt(2023, 2, 13)
+    /// );
+    /// ```
+    pub fn apply(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            RollConvention::Day(day) => {
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let last = crate::algebra::last_day_of_month(date.year(), date.month())?;
+                date.with_day(day.min(last.day()))
+            }
+            RollConvention::EndOfMonth | RollConvention::LastBusinessDay => {
+                crate::algebra::last_day_of_month(date.year(), date.month())
+            }
+            RollConvention::Imm => {
+                let first = date.with_day(1)?;
+                let offset = (7 + Weekday::Wed.num_days_from_monday()
+                    - first.weekday().num_days_from_monday())
+                    % 7;
+                first.with_day(1 + offset + 14)
+            }
+            RollConvention::Cds => date.with_day(20),
+            RollConvention::DayOfWeek(weekday) => {
+                let offset = (7 + weekday.num_days_from_monday()
+                    - date.weekday().num_days_from_monday())
+                    % 7;
+                date.checked_add_days(Days::new(offset as u64))
+            }
+            RollConvention::FirstBusinessDay => date.with_day(1),
+            RollConvention::NthBusinessDay(n) => date.with_day(n),
+        }
+    }
+
+    /// Pins `date` to this roll convention, counting business days in
+    /// `calendar`.
+    ///
+    /// Same as [`apply`](RollConvention::apply) except for the business day
+    /// conventions, which pick their day among the business days of
+    /// `date`'s month.  Returns `None` if the month has no such business day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::RollConvention;
+    ///
+    /// // 31 August 2024 is a Saturday
+    /// let cal = basic_calendar();
+    /// let d = NaiveDate::from_ymd_opt(2024, 8, 10).unwrap();
+    /// assert_eq!(RollConvention::LastBusinessDay.apply_in(&d, &cal), NaiveDate::from_ymd_opt(2024, 8, 30));
+    /// assert_eq!(RollConvention::NthBusinessDay(3).apply_in(&d, &cal), NaiveDate::from_ymd_opt(2024, 8, 5));
+    /// assert_eq!(RollConvention::Day(31).apply_in(&d, &cal), NaiveDate::from_ymd_opt(2024, 8, 31));
+    /// ```
+    pub fn apply_in(&self, date: &NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+        let (year, month) = (date.year(), date.month());
+        match *self {
+            RollConvention::FirstBusinessDay => crate::algebra::first_business_day_of_month(year, month, calendar),
+            RollConvention::LastBusinessDay => crate::algebra::last_business_day_of_month(year, month, calendar),
+            RollConvention::NthBusinessDay(n) => crate::algebra::nth_business_day_of_month(year, month, n, calendar),
+            _ => self.apply(date),
+        }
+    }
+}
+
+impl fmt::Display for RollConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RollConvention::Day(n) => write!(f, "{n}"),
+            RollConvention::EndOfMonth => write!(f, "EOM"),
+            RollConvention::Imm => write!(f, "IMM"),
+            RollConvention::Cds => write!(f, "CDS"),
+            RollConvention::FirstBusinessDay => write!(f, "FBD"),
+            RollConvention::LastBusinessDay => write!(f, "LBD"),
+            RollConvention::NthBusinessDay(n) => write!(f, "BD{n}"),
+            RollConvention::DayOfWeek(weekday) => {
+                let code = match weekday {
+                    Weekday::Mon => "MON",
+                    Weekday::Tue => "TUE",
+                    Weekday::Wed => "WED",
+                    Weekday::Thu => "THU",
+                    Weekday::Fri => "FRI",
+                    Weekday::Sat => "SAT",
+                    Weekday::Sun => "SUN",
+                };
+                write!(f, "{code}")
+            }
+        }
+    }
+}
+
+impl FromStr for RollConvention {
+    type Err = ParseRollConventionError;
+
+    /// Parse a [`RollConvention`] from its roll code (case-insensitive).
+    ///
+    /// Accepts a day of month `1` to `31`, `EOM`, `IMM`, `CDS`, a
+    /// three-letter weekday `MON` to `SUN`, `FBD`, `LBD`, or a business day
+    /// of the month `BD1` to `BD31`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Weekday;
+    /// use findates::conventions::RollConvention;
+    ///
+    /// assert_eq!("15".parse::<RollConvention>().unwrap(), RollConvention::Day(15));
+    /// assert_eq!("imm".parse::<RollConvention>().unwrap(), RollConvention::Imm);
+    /// assert_eq!(
+    ///     "WED".parse::<RollConvention>().unwrap(),
+    ///     RollConvention::DayOfWeek(Weekday::Wed)
+    /// );
+    /// assert_eq!("bd5".parse::<RollConvention>().unwrap(), RollConvention::NthBusinessDay(5));
+    /// assert!("32".parse::<RollConvention>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.trim().to_ascii_uppercase();
+        match upper.as_str() {
+            "EOM" => Ok(RollConvention::EndOfMonth),
+            "IMM" => Ok(RollConvention::Imm),
+            "CDS" => Ok(RollConvention::Cds),
+            "MON" => Ok(RollConvention::DayOfWeek(Weekday::Mon)),
+            "TUE" => Ok(RollConvention::DayOfWeek(Weekday::Tue)),
+            "WED" => Ok(RollConvention::DayOfWeek(Weekday::Wed)),
+            "THU" => Ok(RollConvention::DayOfWeek(Weekday::Thu)),
+            "FRI" => Ok(RollConvention::DayOfWeek(Weekday::Fri)),
+            "SAT" => Ok(RollConvention::DayOfWeek(Weekday::Sat)),
+            "SUN" => Ok(RollConvention::DayOfWeek(Weekday::Sun)),
+            "FBD" => Ok(RollConvention::FirstBusinessDay),
+            "LBD" => Ok(RollConvention::LastBusinessDay),
+            code => {
+                let (digits, roll): (_, fn(u32) -> RollConvention) = match code.strip_prefix("BD") {
+                    Some(digits) => (digits, RollConvention::NthBusinessDay),
+                    None => (code, RollConvention::Day),
+                };
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseRollConventionError);
+                }
+                match digits.parse::<u32>() {
+                    Ok(day @ 1..=31) => Ok(roll(day)),
+                    _ => Err(ParseRollConventionError),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RollConvention {
+    // Roll codes, e.g. `15`, `EOM` or `WED`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// The variant form roll conventions were serialized in before they used
+// roll codes, e.g. `{"Day":15}` or `"EndOfMonth"`, still accepted on input.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(remote = "RollConvention")]
+enum RollConventionVariant {
+    Day(u32),
+    EndOfMonth,
+    Imm,
+    Cds,
+    DayOfWeek(Weekday),
+    FirstBusinessDay,
+    LastBusinessDay,
+    NthBusinessDay(u32),
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum RollConventionData {
+    Variant(#[serde(with = "RollConventionVariant")] RollConvention),
+    Code(String),
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RollConvention {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match RollConventionData::deserialize(deserializer)? {
+            RollConventionData::Variant(roll) => Ok(roll),
+            RollConventionData::Code(s) => {
+                s.parse().map_err(|_| serde::de::Error::custom(format_args!("unknown roll convention {s:?}")))
+            }
+        }
+    }
+}
+
+/// Stub rules: where the irregular period of a schedule goes when its
+/// effective and maturity dates are not a whole number of periods apart.
+///
+/// Set one on a [`Schedule`](crate::schedule::Schedule) with
+/// [`with_stub`](crate::schedule::Schedule::with_stub).  Front stubs roll the
+/// schedule backwards from the maturity date and back stubs forwards from the
+/// effective date.  A short stub is a period of its own; a long stub is
+/// merged into the regular period next to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::{Frequency, StubRule};
+/// use findates::schedule::Schedule;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// let boundaries = |stub| {
+///     let sched = Schedule::new(Frequency::Semiannual, None, None).with_stub(stub);
+///     sched.payment_dates(&d(2024, 2, 10), &d(2025, 6, 15), 0).unwrap()
+/// };
+/// assert_eq!(boundaries(StubRule::ShortBack), [d(2024, 8, 10), d(2025, 2, 10), d(2025, 6, 15)]);
+/// assert_eq!(boundaries(StubRule::LongBack), [d(2024, 8, 10), d(2025, 6, 15)]);
+/// assert_eq!(boundaries(StubRule::ShortFront), [d(2024, 6, 15), d(2024, 12, 15), d(2025, 6, 15)]);
+/// assert_eq!(boundaries(StubRule::LongFront), [d(2024, 12, 15), d(2025, 6, 15)]);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StubRule {
+    /// A short first period, shorter than a regular one.
+    ///
+    /// FpML code: `ShortInitial`
+    ShortFront,
+    /// A long first period, between one and two regular periods.
+    ///
+    /// FpML code: `LongInitial`
+    LongFront,
+    /// A short last period.  This is the default.
+    ///
+    /// FpML code: `ShortFinal`
+    ShortBack,
+    /// A long last period.
+    ///
+    /// FpML code: `LongFinal`
+    LongBack,
+}
+
+impl StubRule {
+    // True for stubs at the start of the schedule.
+    pub(crate) fn is_front(&self) -> bool {
+        matches!(self, StubRule::ShortFront | StubRule::LongFront)
+    }
+
+    // True for stubs merged into the adjacent regular period.
+    pub(crate) fn is_long(&self) -> bool {
+        matches!(self, StubRule::LongFront | StubRule::LongBack)
+    }
+}
+
+/// Expiry rules: the last trading day of a futures contract, given its
+/// contract month.
+///
+/// Pass one to [`algebra::futures_expiry`](crate::algebra::futures_expiry)
+/// with a contract code.  The associated constants are the rules of common
+/// contracts.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::futures_expiry;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::ExpiryRule;
+///
+/// let cal = basic_calendar();
+/// let third_friday = ExpiryRule::NthWeekday { n: 3, weekday: Weekday::Fri };
+/// assert_eq!(third_friday, ExpiryRule::ES);
+/// assert_eq!(futures_expiry("ESZ24", third_friday, &cal), Ok(NaiveDate::from_ymd_opt(2024, 12, 20).unwrap()));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExpiryRule {
+    /// The `n`-th `weekday` of the contract month, e.g. the third Friday, or
+    /// the business day before it if it is not one.
+    NthWeekday {
+        /// Occurrence in the month, from 1.
+        n: u8,
+        /// Day of the week.
+        weekday: Weekday,
+    },
+    /// The last business day of the contract month.
+    LastBusinessDay,
+    /// `days` business days before the `n`-th `weekday` of the month
+    /// `months_after` months after the contract month, e.g. two business
+    /// days before the third Wednesday.  With `days` zero, the business day
+    /// on or before that weekday.
+    BusinessDaysBeforeNthWeekday {
+        /// Business days to count back.
+        days: u32,
+        /// Occurrence in the month, from 1.
+        n: u8,
+        /// Day of the week.
+        weekday: Weekday,
+        /// Months from the contract month to the month holding the weekday.
+        months_after: u32,
+    },
+}
+
+impl ExpiryRule {
+    /// E-mini S&P 500 futures (CME `ES`), and other equity index futures:
+    /// the third Friday of the contract month.
+    pub const ES: ExpiryRule = ExpiryRule::NthWeekday { n: 3, weekday: Weekday::Fri };
+
+    /// 30-Day Federal Funds futures (CME `FF`): the last business day of the
+    /// contract month.
+    pub const FF: ExpiryRule = ExpiryRule::LastBusinessDay;
+
+    /// One-Month SOFR futures (CME `SR1`): the last business day of the
+    /// contract month.
+    pub const SR1: ExpiryRule = ExpiryRule::LastBusinessDay;
+
+    /// Three-Month SOFR futures (CME `SR3`): the business day before the
+    /// third Wednesday of the month three months after the contract month,
+    /// where the reference quarter ends.
+    pub const SR3: ExpiryRule = ExpiryRule::BusinessDaysBeforeNthWeekday {
+        days: 1,
+        n: 3,
+        weekday: Weekday::Wed,
+        months_after: 3,
+    };
+
+    /// Three-Month Euribor futures (ICE `I`), and other IMM-dated money
+    /// market futures: two business days before the third Wednesday of the
+    /// contract month.
+    pub const EURIBOR: ExpiryRule = ExpiryRule::BusinessDaysBeforeNthWeekday {
+        days: 2,
+        n: 3,
+        weekday: Weekday::Wed,
+        months_after: 0,
+    };
+}
+
+/// Listed option expiry series, for
+/// [`algebra::option_expiries`](crate::algebra::option_expiries).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::algebra::option_expiries;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::ExpiryStyle;
+///
+/// let cal = basic_calendar();
+/// let mondays = option_expiries(2024, 9, ExpiryStyle::Weekly(Weekday::Mon), &cal).unwrap();
+/// assert_eq!(mondays.len(), 5);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExpiryStyle {
+    /// Standard monthly options: the third Friday of the month.  Contracts
+    /// before February 2015 expire on the Saturday after it, as U.S. equity
+    /// options did before the switch to Friday expirations.
+    Monthly,
+    /// Weekly options: every occurrence of the weekday in the month,
+    /// including the monthly expiry for Fridays.
+    Weekly(Weekday),
+    /// End-of-month options: the last business day of the month.
+    EndOfMonth,
+}
+
+/// Directions for [`Rounding`] a value to a number of decimal places.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::conventions::{Rounding, RoundingDirection};
+///
+/// assert_eq!(Rounding::new(2, RoundingDirection::Nearest).round(0.125), 0.13);
+/// assert_eq!(Rounding::new(2, RoundingDirection::Up).round(0.121), 0.13);
+/// assert_eq!(Rounding::new(2, RoundingDirection::Down).round(0.129), 0.12);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingDirection {
+    /// To the nearest value, with halves rounded away from zero.
+    Nearest,
+    /// Away from zero.
+    Up,
+    /// Towards zero, i.e. truncation.
+    Down,
+}
+
+/// A rounding policy: a number of decimal places and a direction.
+///
+/// Some markets fix the precision of year fractions, e.g. Brazilian
+/// Bus/252 factors truncated to a set number of places.  Set one on a
+/// [`DayCountContext`](crate::algebra::DayCountContext), pass one to
+/// [`algebra::day_count_fraction_rounded`](crate::algebra::day_count_fraction_rounded),
+/// or give one to a schedule with
+/// [`with_rounding`](crate::schedule::Schedule::with_rounding) to round the
+/// year fraction of each coupon period.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::conventions::{Rounding, RoundingDirection};
+///
+/// let truncate = Rounding::new(6, RoundingDirection::Down);
+/// assert_eq!(truncate.round(31.0 / 252.0), 0.123015);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rounding {
+    /// Decimal places kept.
+    pub decimals: u32,
+    /// Which way the discarded digits round.
+    pub direction: RoundingDirection,
+}
+
+impl Rounding {
+    /// Creates a new [`Rounding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Rounding, RoundingDirection};
+    ///
+    /// let rounding = Rounding::new(8, RoundingDirection::Nearest);
+    /// assert_eq!(rounding.decimals, 8);
+    /// ```
+    pub const fn new(decimals: u32, direction: RoundingDirection) -> Self {
+        Self { decimals, direction }
+    }
+
+    /// Rounds `value` to [`decimals`](Rounding::decimals) places in
+    /// [`direction`](Rounding::direction).
+    ///
+    /// Values within floating-point noise of a boundary are treated as on
+    /// it, so `0.29` truncated to two places stays `0.29` and `1.005`
+    /// rounds to `1.01`, as they would in decimal arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Rounding, RoundingDirection};
+    ///
+    /// assert_eq!(Rounding::new(2, RoundingDirection::Down).round(0.29), 0.29);
+    /// assert_eq!(Rounding::new(2, RoundingDirection::Nearest).round(1.005), 1.01);
+    /// assert_eq!(Rounding::new(1, RoundingDirection::Up).round(-0.21), -0.3);
+    /// ```
+    pub fn round(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.decimals.min(i32::MAX as u32) as i32);
+        let scaled = value * factor;
+        if !scaled.is_finite() {
+            return value;
+        }
+        // Relative tolerance for the representation error of `value` and
+        // the scaling.
+        let tolerance = scaled.abs().max(1.0) * 1e-12;
+        let nearest = scaled.round();
+        let rounded = if (scaled - nearest).abs() <= tolerance {
+            nearest
+        } else {
+            match self.direction {
+                RoundingDirection::Nearest => {
+                    let half = scaled.trunc() + 0.5 * scaled.signum();
+                    if (scaled - half).abs() <= tolerance { half + 0.5 * scaled.signum() } else { nearest }
+                }
+                RoundingDirection::Up => scaled.trunc() + scaled.signum(),
+                RoundingDirection::Down => scaled.trunc(),
+            }
+        };
+        rounded / factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_string_parse_test() {
+        let from_str = DayCount::from_str("ActActISDA");
+        assert_eq!(DayCount::ActActISDA, from_str.unwrap());
+    }
+
+    #[test]
+    fn case_insensitive_parse_test() {
+        assert_eq!(DayCount::from_str("ActActIsda"), Ok(DayCount::ActActISDA));
+        assert_eq!(AdjustRule::from_str("MODFOLLOWING"), Ok(AdjustRule::ModFollowing));
+        assert_eq!(Frequency::from_str(" monthly "), Ok(Frequency::Monthly));
+    }
+
+    #[test]
+    fn daycount_alias_parse_test() {
+        let aliases = [
+            ("ACT/360", DayCount::Act360),
+            ("Actual/360", DayCount::Act360),
+            ("ACT/365", DayCount::Act365),
+            ("ACT/365F", DayCount::Act365Fixed),
+            ("Actual/365 (Fixed)", DayCount::Act365Fixed),
+            ("Act/365.25", DayCount::Act365_25),
+            ("NL/365", DayCount::NL365),
+            ("BUS/252", DayCount::Bd252),
+            ("ACT/ACT", DayCount::ActActISDA),
+            ("Actual/Actual (ISDA)", DayCount::ActActISDA),
+            ("ACT/ACT.ICMA", DayCount::ActActICMA),
+            ("Actual/Actual (ISMA)", DayCount::ActActICMA),
+            ("ACT/ACT AFB", DayCount::ActActAFB),
+            ("30E/360", DayCount::D30360Euro),
+            ("30/360", DayCount::Thirty360ISDA),
+            ("360/360", DayCount::Thirty360ISDA),
+            ("30U/360", DayCount::Thirty360US),
+            ("30/360 US", DayCount::Thirty360US),
+            ("30/360 ISDA", DayCount::Thirty360ISDA),
+            ("Bond Basis", DayCount::Thirty360ISDA),
+            ("30E/360.ISDA", DayCount::ThirtyE360ISDA),
+            ("30/365", DayCount::D30365),
+        ];
+        for (s, expected) in aliases {
+            assert_eq!(s.parse::<DayCount>(), Ok(expected), "{s:?}");
+        }
+        for s in ["", "ACT", "ACT/364", "30/361", "Actual Actual Fixed"] {
+            assert_eq!(s.parse::<DayCount>(), Err(ParseDayCountError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn adjustrule_alias_parse_test() {
+        let aliases = [
+            ("F", AdjustRule::Following),
+            ("MF", AdjustRule::ModFollowing),
+            ("Modified Following", AdjustRule::ModFollowing),
+            ("modified_following", AdjustRule::ModFollowing),
+            ("P", AdjustRule::Preceding),
+            ("MP", AdjustRule::ModPreceding),
+            ("ModifiedPreceding", AdjustRule::ModPreceding),
+            ("NONE", AdjustRule::Unadjusted),
+            ("HMMF", AdjustRule::HalfMonthModFollowing),
+            ("nearest", AdjustRule::Nearest),
+        ];
+        for (s, expected) in aliases {
+            assert_eq!(s.parse::<AdjustRule>(), Ok(expected), "{s:?}");
+        }
+        assert_eq!("FF".parse::<AdjustRule>(), Err(ParseAdjustRuleError));
+    }
+
+    #[test]
+    fn frequency_alias_parse_test() {
+        let aliases = [
+            ("Once", Frequency::Zero),
+            ("1Y", Frequency::Annual),
+            ("Yearly", Frequency::Annual),
+            ("SemiAnnual", Frequency::Semiannual),
+            ("Semi-Annual", Frequency::Semiannual),
+            ("S/A", Frequency::Semiannual),
+            ("6M", Frequency::Semiannual),
+            ("4m", Frequency::EveryFourthMonth),
+            ("3M", Frequency::Quarterly),
+            ("2M", Frequency::Bimonthly),
+            ("1M", Frequency::Monthly),
+            ("EOM", Frequency::EndOfMonth),
+            ("4W", Frequency::EveryFourthWeek),
+            ("Fortnightly", Frequency::Biweekly),
+            ("1W", Frequency::Weekly),
+            ("1D", Frequency::Daily),
+            ("7D", Frequency::Weekly),
+            ("5M", Frequency::EveryNMonths(5)),
+            ("18m", Frequency::EveryNMonths(18)),
+            ("13W", Frequency::EveryNWeeks(13)),
+            ("28D", Frequency::EveryFourthWeek),
+            ("every n days (30)", Frequency::EveryNDays(30)),
+        ];
+        for (s, expected) in aliases {
+            assert_eq!(s.parse::<Frequency>(), Ok(expected), "{s:?}");
+        }
+        for s in ["", "0M", "M", "3Q", "EveryNWeeks(0)", "EveryNDays", "Hourly"] {
+            assert_eq!(s.parse::<Frequency>(), Err(ParseFrequencyError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn unknown_day_count_code_rejected_test() {
+        // The 30/360 ISDA variant is spelt "Thirty360ISDA", not "D30360ISDA".
+        assert_eq!(DayCount::from_str("D30360ISDA"), Err(ParseDayCountError));
+    }
+
+    #[test]
+    fn to_string_test() {
+        let conv = AdjustRule::HalfMonthModFollowing;
+        assert_eq!(conv.to_string(), "HalfMonthModFollowing");
+    }
+
+    #[test]
+    fn eq_trait_test() {
+        let conv = Frequency::EveryFourthMonth;
+        assert_eq!(conv, Frequency::EveryFourthMonth);
     }
 
     #[test]
@@ -424,10 +2044,16 @@ mod tests {
             DayCount::Act360,
             DayCount::Act365,
             DayCount::Act365Fixed,
+            DayCount::Act365_25,
+            DayCount::NL365,
             DayCount::Bd252,
             DayCount::ActActISDA,
+            DayCount::ActActICMA,
+            DayCount::ActActAFB,
             DayCount::D30360Euro,
             DayCount::Thirty360US,
+            DayCount::Thirty360ISDA,
+            DayCount::ThirtyE360ISDA,
             DayCount::D30365,
         ];
         for v in variants {
@@ -453,6 +2079,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tenor_parse_test() {
+        assert_eq!("3M".parse::<Tenor>(), Ok(Tenor::Months(3)));
+        assert_eq!("6m".parse::<Tenor>(), Ok(Tenor::Months(6)));
+        assert_eq!("10Y".parse::<Tenor>(), Ok(Tenor::Years(10)));
+        assert_eq!("2W".parse::<Tenor>(), Ok(Tenor::Weeks(2)));
+        assert_eq!("1D".parse::<Tenor>(), Ok(Tenor::Days(1)));
+        assert_eq!(" 18M ".parse::<Tenor>(), Ok(Tenor::Months(18)));
+        assert_eq!("ON".parse::<Tenor>(), Ok(Tenor::Overnight));
+        assert_eq!("tn".parse::<Tenor>(), Ok(Tenor::TomorrowNext));
+        assert_eq!("S/N".parse::<Tenor>(), Ok(Tenor::SpotNext));
+    }
+
+    #[test]
+    fn tenor_parse_rejects_invalid_test() {
+        for s in ["", "M", "3", "3X", "-3M", "+3M", "3.5Y", "M3", "3MM", "99999999999D", "ÉM"] {
+            assert_eq!(s.parse::<Tenor>(), Err(ParseTenorError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn all_tenor_roundtrip_test() {
+        let variants = [
+            Tenor::Days(1),
+            Tenor::Weeks(2),
+            Tenor::Months(3),
+            Tenor::Years(10),
+            Tenor::Overnight,
+            Tenor::TomorrowNext,
+            Tenor::SpotNext,
+        ];
+        for v in variants {
+            let parsed: Tenor = v.to_string().parse().unwrap();
+            assert_eq!(v, parsed);
+        }
+    }
+
+    #[test]
+    fn tenor_frequency_roundtrip_test() {
+        let variants = [
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::EveryFourthMonth,
+            Frequency::Quarterly,
+            Frequency::Bimonthly,
+            Frequency::Monthly,
+            Frequency::EveryFourthWeek,
+            Frequency::Biweekly,
+            Frequency::Weekly,
+            Frequency::Daily,
+        ];
+        for v in variants {
+            let tenor = Tenor::from_frequency(v).unwrap();
+            assert_eq!(tenor.to_frequency(), Some(v));
+        }
+        assert_eq!(Tenor::from_frequency(Frequency::Zero), None);
+        assert_eq!(Tenor::from_frequency(Frequency::EndOfMonth), Some(Tenor::Months(1)));
+        assert_eq!(Tenor::Overnight.to_frequency(), None);
+    }
+
+    #[test]
+    fn checked_tenor_arithmetic_test() {
+        let d = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        for tenor in [Tenor::Days(3), Tenor::Weeks(2), Tenor::Months(1), Tenor::Years(1), Tenor::SpotNext] {
+            assert_eq!(checked_add_tenor(&d, tenor), Some(d + tenor));
+            assert_eq!(checked_sub_tenor(&d, tenor), Some(d - tenor));
+        }
+        assert_eq!(checked_add_tenor(&NaiveDate::MAX, Tenor::Overnight), None);
+        assert_eq!(checked_sub_tenor(&NaiveDate::MIN, Tenor::Days(1)), None);
+    }
+
     #[test]
     fn all_frequency_roundtrip_test() {
         let variants = [
@@ -474,4 +2171,233 @@ mod tests {
             assert_eq!(v, parsed);
         }
     }
+
+    #[test]
+    fn all_roll_convention_roundtrip_test() {
+        let mut variants = vec![
+            RollConvention::EndOfMonth,
+            RollConvention::Imm,
+            RollConvention::Cds,
+            RollConvention::DayOfWeek(Weekday::Mon),
+            RollConvention::DayOfWeek(Weekday::Sun),
+        ];
+        variants.extend((1..=31).map(RollConvention::Day));
+        variants.extend([RollConvention::FirstBusinessDay, RollConvention::LastBusinessDay]);
+        variants.extend((1..=31).map(RollConvention::NthBusinessDay));
+        for v in variants {
+            let parsed: RollConvention = v.to_string().parse().unwrap();
+            assert_eq!(v, parsed);
+        }
+        assert!("0".parse::<RollConvention>().is_err());
+        assert!("BD0".parse::<RollConvention>().is_err());
+        assert!("BD".parse::<RollConvention>().is_err());
+        assert!("".parse::<RollConvention>().is_err());
+        assert!("MONDAY".parse::<RollConvention>().is_err());
+    }
+
+    #[test]
+    fn frequency_periods_test() {
+        let variants = [
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::EveryFourthMonth,
+            Frequency::Quarterly,
+            Frequency::Bimonthly,
+            Frequency::Monthly,
+            Frequency::EndOfMonth,
+        ];
+        for v in variants {
+            let months = v.to_months().unwrap();
+            assert_eq!(v.periods_per_year(), Some(12.0 / months as f64), "{v}");
+            assert_eq!(v.to_days(), None, "{v}");
+        }
+        for (v, days, per_year) in [
+            (Frequency::EveryFourthWeek, 28, 13.0),
+            (Frequency::Biweekly, 14, 26.0),
+            (Frequency::Weekly, 7, 52.0),
+            (Frequency::Daily, 1, 365.0),
+        ] {
+            assert_eq!(v.to_days(), Some(days), "{v}");
+            assert_eq!(v.to_months(), None, "{v}");
+            assert_eq!(v.periods_per_year(), Some(per_year), "{v}");
+        }
+        assert_eq!(Frequency::Zero.periods_per_year(), None);
+        assert_eq!(Frequency::Zero.to_months(), None);
+        assert_eq!(Frequency::Zero.to_days(), None);
+    }
+
+    #[test]
+    fn parametric_frequency_test() {
+        for (v, tenor) in [
+            (Frequency::EveryNMonths(5), Tenor::Months(5)),
+            (Frequency::EveryNWeeks(13), Tenor::Weeks(13)),
+            (Frequency::EveryNDays(28), Tenor::Days(28)),
+        ] {
+            assert_eq!(v.to_string().parse::<Frequency>(), Ok(v));
+            assert_eq!(Tenor::from_frequency(v), Some(tenor));
+        }
+        assert_eq!(Frequency::EveryNWeeks(13).to_days(), Some(91));
+        assert_eq!(Frequency::EveryNDays(28).periods_per_year(), Some(365.0 / 28.0));
+        assert_eq!(Frequency::EveryNMonths(18).to_fpml(), "18M");
+        for v in [Frequency::EveryNMonths(0), Frequency::EveryNWeeks(0), Frequency::EveryNDays(0)] {
+            assert_eq!((v.to_months(), v.to_days(), v.periods_per_year()), (None, None, None), "{v}");
+        }
+        assert_eq!(Frequency::every_n_months(12), Some(Frequency::Annual));
+        assert_eq!(Frequency::every_n_weeks(0), None);
+    }
+
+    #[test]
+    fn parametric_frequency_normalization_test() {
+        use std::collections::HashSet;
+
+        for (v, named) in [
+            (Frequency::EveryNMonths(3), Frequency::Quarterly),
+            (Frequency::EveryNMonths(12), Frequency::Annual),
+            (Frequency::EveryNWeeks(2), Frequency::Biweekly),
+            (Frequency::EveryNDays(7), Frequency::Weekly),
+            (Frequency::EveryNDays(14), Frequency::Biweekly),
+        ] {
+            assert_eq!(v, named);
+            assert_eq!(v.to_string(), named.to_string());
+            assert_eq!(v.to_fpml(), named.to_fpml());
+            assert_eq!(HashSet::from([v, named]).len(), 1, "{v:?}");
+        }
+        assert_eq!(Frequency::EveryNDays(21), Frequency::EveryNWeeks(3));
+        assert_ne!(Frequency::EveryNMonths(5), Frequency::EveryNWeeks(5));
+        assert_ne!(Frequency::Monthly, Frequency::EndOfMonth);
+        assert_eq!(Frequency::EveryNMonths(5).to_string(), "5M");
+        assert_eq!(Frequency::EveryNWeeks(13).to_string(), "13W");
+        assert_eq!(Frequency::EveryNDays(30).to_string(), "30D");
+        assert_eq!("EveryNMonths(6)".parse::<Frequency>(), Ok(Frequency::Semiannual));
+    }
+
+    #[test]
+    fn frequency_from_tenor_test() {
+        assert_eq!(Frequency::from_tenor(Tenor::Years(1)), Some(Frequency::Annual));
+        assert_eq!(Frequency::from_tenor(Tenor::Months(12)), Some(Frequency::Annual));
+        assert_eq!(Frequency::from_tenor(Tenor::Weeks(2)), Some(Frequency::Biweekly));
+        assert_eq!(Frequency::from_tenor(Tenor::Months(5)), Some(Frequency::EveryNMonths(5)));
+        assert_eq!(Frequency::from_tenor(Tenor::Years(2)), Some(Frequency::EveryNMonths(24)));
+        assert_eq!(Frequency::from_tenor(Tenor::Days(91)), Some(Frequency::EveryNWeeks(13)));
+        assert_eq!(Frequency::from_tenor(Tenor::Days(0)), None);
+        assert_eq!(Frequency::from_tenor(Tenor::SpotNext), None);
+    }
+
+    #[test]
+    fn fpml_roundtrip_test() {
+        let day_counts = [
+            DayCount::Act360,
+            DayCount::Act365,
+            DayCount::Act365Fixed,
+            DayCount::Act365_25,
+            DayCount::NL365,
+            DayCount::Bd252,
+            DayCount::ActActISDA,
+            DayCount::ActActICMA,
+            DayCount::ActActAFB,
+            DayCount::D30360Euro,
+            DayCount::Thirty360US,
+            DayCount::Thirty360ISDA,
+            DayCount::ThirtyE360ISDA,
+            DayCount::D30365,
+        ];
+        for v in day_counts {
+            match (v, v.to_fpml()) {
+                // Same fraction as Act365Fixed, which owns the code.
+                (DayCount::Act365, Some(code)) => assert_eq!(DayCount::from_fpml(code), Ok(DayCount::Act365Fixed)),
+                (_, Some(code)) => assert_eq!(DayCount::from_fpml(code), Ok(v), "{code}"),
+                (_, None) => assert!(
+                    matches!(v, DayCount::Act365_25 | DayCount::NL365 | DayCount::Thirty360US | DayCount::D30365),
+                    "{v}"
+                ),
+            }
+        }
+
+        let adjust_rules = [
+            AdjustRule::Following,
+            AdjustRule::ModFollowing,
+            AdjustRule::Preceding,
+            AdjustRule::ModPreceding,
+            AdjustRule::Unadjusted,
+            AdjustRule::HalfMonthModFollowing,
+            AdjustRule::Nearest,
+        ];
+        for v in adjust_rules {
+            match v.to_fpml() {
+                Some(code) => assert_eq!(AdjustRule::from_fpml(code), Ok(v), "{code}"),
+                None => assert_eq!(v, AdjustRule::HalfMonthModFollowing),
+            }
+        }
+
+        let frequencies = [
+            Frequency::Zero,
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::EveryFourthMonth,
+            Frequency::Quarterly,
+            Frequency::Bimonthly,
+            Frequency::Monthly,
+            Frequency::EndOfMonth,
+            Frequency::EveryFourthWeek,
+            Frequency::Biweekly,
+            Frequency::Weekly,
+            Frequency::Daily,
+        ];
+        for v in frequencies {
+            let expected = if v == Frequency::EndOfMonth { Frequency::Monthly } else { v };
+            assert_eq!(Frequency::from_fpml(&v.to_fpml()), Ok(expected), "{v}");
+        }
+        for v in [Frequency::EveryNMonths(5), Frequency::EveryNWeeks(13), Frequency::EveryNDays(30)] {
+            let expected = v;
+            assert_eq!(Frequency::from_fpml(&v.to_fpml()), Ok(expected), "{v}");
+        }
+    }
+
+    #[test]
+    fn fpml_codes_test() {
+        assert_eq!(DayCount::from_fpml("ACT/ACT.ISMA"), Ok(DayCount::ActActICMA));
+        // A bare "30/360" is Bond Basis whichever way it is parsed.
+        assert_eq!(DayCount::from_fpml("30/360"), "30/360".parse::<DayCount>());
+        assert_eq!(DayCount::from_fpml("act/360"), Err(ParseDayCountError));
+        assert_eq!(DayCount::from_fpml("1/1"), Err(ParseDayCountError));
+        assert_eq!(AdjustRule::from_fpml("NotApplicable"), Err(ParseAdjustRuleError));
+        assert_eq!(Frequency::from_fpml("12M"), Ok(Frequency::Annual));
+        assert_eq!(Frequency::from_fpml("Quarterly"), Err(ParseFrequencyError));
+        assert_eq!(Frequency::from_fpml("2Y"), Ok(Frequency::EveryNMonths(24)));
+        assert_eq!(Frequency::from_fpml("14D"), Ok(Frequency::Biweekly));
+        assert_eq!(Frequency::from_fpml("3m"), Err(ParseFrequencyError));
+        assert_eq!(Frequency::from_fpml("0D"), Err(ParseFrequencyError));
+    }
+
+    #[test]
+    fn rounding_test() {
+        let round = |decimals, direction, value| Rounding::new(decimals, direction).round(value);
+        assert_eq!(round(2, RoundingDirection::Nearest, 0.125), 0.13);
+        assert_eq!(round(2, RoundingDirection::Nearest, -0.125), -0.13);
+        assert_eq!(round(2, RoundingDirection::Nearest, 0.1249), 0.12);
+        assert_eq!(round(0, RoundingDirection::Nearest, 2.5), 3.0);
+        assert_eq!(round(2, RoundingDirection::Up, 0.1201), 0.13);
+        assert_eq!(round(2, RoundingDirection::Up, -0.1201), -0.13);
+        assert_eq!(round(2, RoundingDirection::Down, -0.129), -0.12);
+        // Decimal values whose binary form falls just short of the boundary
+        assert_eq!(round(2, RoundingDirection::Down, 0.29), 0.29);
+        assert_eq!(round(2, RoundingDirection::Up, 0.29), 0.29);
+        assert_eq!(round(2, RoundingDirection::Nearest, 1.005), 1.01);
+        assert_eq!(round(14, RoundingDirection::Down, 1.0 / 3.0), 0.33333333333333);
+        // Non-finite inputs and precisions beyond f64 are left alone
+        assert!(round(4, RoundingDirection::Down, f64::NAN).is_nan());
+        assert_eq!(round(400, RoundingDirection::Down, 0.1), 0.1);
+    }
+
+    #[test]
+    fn imm_roll_test() {
+        // Third Wednesdays of 2024 IMM months
+        for (month, day) in [(3, 20), (6, 19), (9, 18), (12, 18)] {
+            let d = NaiveDate::from_ymd_opt(2024, month, 28).unwrap();
+            assert_eq!(RollConvention::Imm.apply(&d), NaiveDate::from_ymd_opt(2024, month, day));
+        }
+        // Month starting on a Wednesday
+        let d = NaiveDate::from_ymd_opt(2023, 11, 1).unwrap();
+        assert_eq!(RollConvention::Imm.apply(&d), NaiveDate::from_ymd_opt(2023, 11, 15));
+    }
 }
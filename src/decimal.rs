@@ -0,0 +1,195 @@
+//! Day count fractions in exact decimal arithmetic, using
+//! [rust_decimal](https://docs.rs/rust_decimal).
+//!
+//! Requires the `decimal` feature.  The functions here mirror
+//! [`algebra::try_day_count_fraction`](crate::algebra::try_day_count_fraction) and
+//! [`algebra::day_count_fraction_with_context`](crate::algebra::day_count_fraction_with_context)
+//! but return a [`Decimal`].  Every convention is computed from whole day
+//! counts, so `0.1` is `0.1` rather than the nearest `f64`, and sums of
+//! fractions do not pick up binary rounding error.  Divisions that do not
+//! terminate, such as `1 / 3`, are carried to the 28 significant digits a
+//! `Decimal` holds.  Accounting and settlement systems that book amounts in
+//! decimal can use these fractions directly.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use findates::conventions::DayCount;
+//! use findates::decimal::day_count_fraction_decimal;
+//! use rust_decimal::Decimal;
+//!
+//! let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//! let end   = NaiveDate::from_ymd_opt(2024, 2, 6).unwrap();
+//!
+//! // 36 / 360: ten of these make exactly one year, unlike ten f64 0.1s.
+//! let dcf = day_count_fraction_decimal(&start, &end, DayCount::Act360, None, None).unwrap();
+//! assert_eq!(dcf, Decimal::new(1, 1));
+//! assert_eq!((0..10).map(|_| dcf).sum::<Decimal>(), Decimal::ONE);
+//! assert_ne!((0..10).map(|_| 0.1).sum::<f64>(), 1.0);
+//! ```
+
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::algebra::{
+    adjust_period, afb_fraction, icma_fraction, isda_fraction, thirty_days, BusinessDays252, DayCountContext,
+    DayCounter, Fraction,
+};
+use crate::calendar::Calendar;
+use crate::conventions::{AdjustRule, DayCount, Frequency, Rounding, RoundingDirection};
+use crate::error::DayCountError;
+
+/// Computes the day count fraction between two dates as a [`Decimal`].
+///
+/// Same as [`algebra::try_day_count_fraction`](crate::algebra::try_day_count_fraction),
+/// including how the dates are adjusted, but computed in decimal.
+///
+/// # Errors
+///
+/// Same as [`algebra::try_day_count_fraction`](crate::algebra::try_day_count_fraction).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::DayCount;
+/// use findates::decimal::day_count_fraction_decimal;
+/// use rust_decimal::Decimal;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap();
+///
+/// let dcf = day_count_fraction_decimal(&start, &end, DayCount::Act365_25, None, None).unwrap();
+/// assert_eq!(dcf, Decimal::from(91) / Decimal::new(36525, 2));
+/// ```
+pub fn day_count_fraction_decimal(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Decimal, DayCountError> {
+    day_count_fraction_decimal_with_context(
+        start_date,
+        end_date,
+        daycount,
+        &DayCountContext::default(),
+        calendar,
+        adjust_rule,
+    )
+}
+
+/// Computes the day count fraction between two dates as a [`Decimal`], with
+/// the extra inputs in `context`.
+///
+/// Same as
+/// [`algebra::day_count_fraction_with_context`](crate::algebra::day_count_fraction_with_context)
+/// but computed in decimal.  A [`Rounding`] in `context.rounding` is applied
+/// in decimal too, so a fraction exactly half-way between two values rounds
+/// the way the rounding direction says.
+///
+/// # Errors
+///
+/// Same as
+/// [`algebra::day_count_fraction_with_context`](crate::algebra::day_count_fraction_with_context).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::{DayCountContext, ReferencePeriod};
+/// use findates::conventions::{DayCount, Frequency, Rounding, RoundingDirection};
+/// use findates::decimal::day_count_fraction_decimal_with_context;
+/// use rust_decimal::Decimal;
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// let period = ReferencePeriod { start: d(2024, 1, 15), end: d(2024, 7, 15), frequency: Frequency::Semiannual };
+/// let ctx = DayCountContext {
+///     reference_period: Some(period),
+///     rounding: Some(Rounding::new(4, RoundingDirection::Nearest)),
+///     ..Default::default()
+/// };
+///
+/// // 91 / (2 * 182) = 0.25 exactly
+/// let dcf = day_count_fraction_decimal_with_context(&d(2024, 4, 15), &d(2024, 7, 15), DayCount::ActActICMA, &ctx, None, None);
+/// assert_eq!(dcf, Ok(Decimal::new(2500, 4)));
+/// ```
+pub fn day_count_fraction_decimal_with_context(
+    start_date: &NaiveDate,
+    end_date: &NaiveDate,
+    daycount: DayCount,
+    context: &DayCountContext,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Decimal, DayCountError> {
+    let (start, end) = adjust_period(start_date, end_date, calendar, adjust_rule)?;
+    let (first, last) = if start <= end { (start, end) } else { (end, start) };
+    let actual = (last - first).num_days();
+
+    let dcf = match daycount {
+        DayCount::Act360 => Decimal::ratio(actual, 360),
+        DayCount::Act365 | DayCount::Act365Fixed => Decimal::ratio(actual, 365),
+        DayCount::Act365_25 => Decimal::from(actual) / Decimal::new(36525, 2),
+        DayCount::NL365 => Decimal::ratio(daycount.day_count(&start, &end)?, 365),
+        DayCount::ActActISDA => isda_fraction(&first, &last)?,
+        DayCount::D30360Euro
+        | DayCount::Thirty360ISDA
+        | DayCount::ThirtyE360ISDA
+        | DayCount::Thirty360US => Decimal::ratio(thirty_days(daycount, &start, &end, context), 360),
+        DayCount::D30365 => Decimal::ratio(thirty_days(daycount, &start, &end, context), 365),
+        DayCount::Bd252 => {
+            let calendar = calendar.ok_or(DayCountError::MissingCalendar)?;
+            Decimal::ratio(BusinessDays252 { calendar }.day_count(&start, &end)?, 252)
+        }
+        DayCount::ActActAFB => afb_fraction(&first, &last)?,
+        DayCount::ActActICMA => {
+            let period = context
+                .reference_period
+                .as_ref()
+                .ok_or(DayCountError::MissingReferencePeriod)?;
+            icma_fraction(&first, &last, period)?
+        }
+    };
+    Ok(context.rounding.map_or(dcf, |rounding| round(dcf, rounding)))
+}
+
+// Rounds in decimal, so halves are exact.
+fn round(value: Decimal, rounding: Rounding) -> Decimal {
+    let strategy = match rounding.direction {
+        RoundingDirection::Nearest => RoundingStrategy::MidpointAwayFromZero,
+        RoundingDirection::Up => RoundingStrategy::AwayFromZero,
+        RoundingDirection::Down => RoundingStrategy::ToZero,
+    };
+    value.round_dp_with_strategy(rounding.decimals, strategy)
+}
+
+// Coupons per year as `(numerator, denominator)`, so that parametric
+// frequencies such as every 5 months stay exact.
+fn periods_per_year(frequency: Frequency) -> (i64, i64) {
+    match frequency {
+        Frequency::EveryNMonths(n) => (12, n.into()),
+        Frequency::EveryNWeeks(n) => (52, n.into()),
+        Frequency::EveryNDays(n) => (365, n.into()),
+        // The named frequencies have a whole number of coupons per year.
+        _ => (frequency.periods_per_year().unwrap_or(0.0) as i64, 1),
+    }
+}
+
+impl Fraction for Decimal {
+    fn whole(n: i64) -> Self {
+        Decimal::from(n)
+    }
+
+    fn ratio(days: i64, basis: i64) -> Self {
+        Decimal::from(days) / Decimal::from(basis)
+    }
+
+    fn coupon_ratio(days: i64, period_days: i64, frequency: Frequency) -> Self {
+        let (per_year, every) = periods_per_year(frequency);
+        Decimal::from(days) * Decimal::from(every) / (Decimal::from(per_year) * Decimal::from(period_days))
+    }
+
+    fn coupon(frequency: Frequency) -> Self {
+        let (per_year, every) = periods_per_year(frequency);
+        Decimal::from(every) / Decimal::from(per_year)
+    }
+}
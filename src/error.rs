@@ -1,10 +1,42 @@
 //! Error types returned by fallible findates functions.
 //!
-//! - [`DayCountError`] — returned by [`algebra::day_count_fraction`](crate::algebra::day_count_fraction)
+//! - [`DayCountError`] — returned by [`algebra::try_day_count_fraction`](crate::algebra::try_day_count_fraction)
+//!   and [`algebra::day_count_fraction_with_period`](crate::algebra::day_count_fraction_with_period)
 //!   when called with an incompatible combination of arguments.
 //! - [`BusinessDayError`] — returned by [`algebra::add_business_days`](crate::algebra::add_business_days)
 //!   and [`algebra::subtract_business_days`](crate::algebra::subtract_business_days) when the
 //!   start date is not a business day in the given calendar.
+//! - [`ScheduleError`] — returned by [`Schedule::generate`](crate::schedule::Schedule::generate)
+//!   when the requested date range is invalid, and by
+//!   [`Schedule::coupon_periods`](crate::schedule::Schedule::coupon_periods) when a
+//!   period cannot be built.
+//! - [`ScheduleBuildError`] — returned by
+//!   [`ScheduleBuilder::build`](crate::schedule::ScheduleBuilder::build) when
+//!   the schedule options are incompatible.
+//! - [`CalendarError`] — returned when a [`Calendar`](crate::calendar::Calendar)
+//!   cannot be built or queried as requested.
+//! - [`DateError`] — returned by date helpers such as
+//!   [`algebra::nth_weekday_of_month`](crate::algebra::nth_weekday_of_month)
+//!   when the requested date does not exist.
+//! - [`FuturesExpiryError`] — returned by
+//!   [`algebra::futures_expiry`](crate::algebra::futures_expiry) when a
+//!   contract code cannot be parsed or its expiry does not exist.
+//! - [`CalendarIoError`] — returned when a calendar cannot be loaded from or
+//!   saved to a CSV or JSON file.
+//! - [`IcsError`] — returned by `Calendar::from_ics` (requires the `ics`
+//!   feature) when an iCalendar document cannot be parsed.
+//! - [`HolidayApiError`] — returned by `Calendar::fetch_public_holidays`
+//!   (requires the `http` feature) when holidays cannot be loaded from a
+//!   public holiday API.
+//! - [`ParseDayCountError`], [`ParseAdjustRuleError`], [`ParseFrequencyError`],
+//!   [`ParseTenorError`], [`ParseRollConventionError`] —
+//!   returned by the [`FromStr`](std::str::FromStr) implementations in
+//!   [`conventions`](crate::conventions).
+//! - [`ParseCalendarError`] — returned when a calendar expression such as
+//!   `"NYC|LON"` cannot be parsed (see [`registry`](crate::registry)).
+//!
+//! Every error type implements [`std::error::Error`], so they compose with
+//! `?` and `Box<dyn Error>` in downstream code.
 
 use std::fmt;
 
@@ -14,6 +46,19 @@ pub enum DayCountError {
     /// Returned when [`DayCount::Bd252`](crate::conventions::DayCount::Bd252) is
     /// called without a calendar.
     MissingCalendar,
+    /// Returned when [`DayCount::ActActICMA`](crate::conventions::DayCount::ActActICMA)
+    /// is called without a reference coupon period.
+    MissingReferencePeriod,
+    /// Returned when the reference period ends on or before its start.
+    InvalidReferencePeriod,
+    /// Returned when the reference period frequency has no regular coupon
+    /// length (e.g. [`Frequency::Zero`](crate::conventions::Frequency::Zero)).
+    InvalidFrequency,
+    /// Returned when stepping notional coupon periods, or searching for a
+    /// trading session, leaves the representable date range.
+    OutOfRange,
+    /// Returned when an overnight variance weight is outside `0.0..=1.0`.
+    InvalidOvernightWeight,
 }
 
 impl fmt::Display for DayCountError {
@@ -22,6 +67,21 @@ impl fmt::Display for DayCountError {
             DayCountError::MissingCalendar => {
                 write!(f, "DayCount::Bd252 requires a Calendar")
             }
+            DayCountError::MissingReferencePeriod => {
+                write!(f, "DayCount::ActActICMA requires a reference coupon period")
+            }
+            DayCountError::InvalidReferencePeriod => {
+                write!(f, "reference period must end after it starts")
+            }
+            DayCountError::InvalidFrequency => {
+                write!(f, "frequency has no regular coupon period")
+            }
+            DayCountError::OutOfRange => {
+                write!(f, "date out of range while computing day count fraction")
+            }
+            DayCountError::InvalidOvernightWeight => {
+                write!(f, "overnight weight must be between 0 and 1")
+            }
         }
     }
 }
@@ -33,6 +93,10 @@ impl std::error::Error for DayCountError {}
 pub enum BusinessDayError {
     /// Returned when the start date is not a business day in the given calendar.
     InvalidStartDate,
+    /// Returned when the start date or the result falls outside the range a
+    /// [`CompiledCalendar`](crate::compiled::CompiledCalendar) was built for,
+    /// or when the result would overflow the representable date range.
+    OutOfRange,
 }
 
 impl fmt::Display for BusinessDayError {
@@ -41,8 +105,527 @@ impl fmt::Display for BusinessDayError {
             BusinessDayError::InvalidStartDate => {
                 write!(f, "start date is not a business day in the given calendar")
             }
+            BusinessDayError::OutOfRange => {
+                write!(f, "date is outside the calendar or representable date range")
+            }
         }
     }
 }
 
 impl std::error::Error for BusinessDayError {}
+
+/// Errors returned by schedule generation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// Returned when the end date is not strictly after the anchor date.
+    InvalidDateRange,
+    /// Returned when a schedule or payment date falls outside the
+    /// representable date range.
+    OutOfRange,
+    /// Returned when the day count fraction of a period cannot be computed.
+    DayCount(DayCountError),
+    /// Returned when the accrual periods of two legs do not nest inside
+    /// each other (see [`align_legs`](crate::schedule::align_legs)).
+    MisalignedLegs {
+        /// Start of the first period that cannot be paired.
+        date: chrono::NaiveDate,
+    },
+    /// Returned when the interval between generated dates is zero, e.g. in
+    /// [`weekday_dates`](crate::schedule::weekday_dates).
+    InvalidInterval,
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::InvalidDateRange => {
+                write!(f, "anchor date must be before end date")
+            }
+            ScheduleError::OutOfRange => {
+                write!(f, "schedule date is outside the representable range")
+            }
+            ScheduleError::DayCount(err) => write!(f, "day count failed: {err}"),
+            ScheduleError::MisalignedLegs { date } => {
+                write!(f, "leg periods starting {date} do not nest")
+            }
+            ScheduleError::InvalidInterval => write!(f, "date interval must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScheduleError::DayCount(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Errors returned by [`ScheduleBuilder::build`](crate::schedule::ScheduleBuilder::build)
+/// when the requested schedule options do not fit together.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScheduleBuildError {
+    /// Returned when no frequency was set.
+    MissingFrequency,
+    /// Returned when a parametric frequency has a count of zero, such as
+    /// [`EveryNMonths(0)`](crate::conventions::Frequency::EveryNMonths), so
+    /// the schedule could never step past its anchor.
+    ZeroLengthFrequency {
+        /// The schedule's frequency.
+        frequency: crate::conventions::Frequency,
+    },
+    /// Returned when an adjustment rule is set without a calendar, so it
+    /// would have no effect.
+    AdjustRuleWithoutCalendar,
+    /// Returned when both the end-of-month flag and a roll convention are
+    /// set.  Use [`RollConvention::EndOfMonth`](crate::conventions::RollConvention::EndOfMonth)
+    /// to always roll on month ends.
+    ConflictingRoll,
+    /// Returned when the end-of-month flag is set on a frequency that is not
+    /// a whole number of months.
+    IncompatibleEom {
+        /// The schedule's frequency.
+        frequency: crate::conventions::Frequency,
+    },
+    /// Returned when the roll convention does not apply to the frequency,
+    /// e.g. a day of the week on a monthly schedule.
+    IncompatibleRoll {
+        /// The requested roll convention.
+        roll: crate::conventions::RollConvention,
+        /// The schedule's frequency.
+        frequency: crate::conventions::Frequency,
+    },
+    /// Returned when a stub rule is set on a frequency without regular
+    /// periods, i.e. [`Frequency::Zero`](crate::conventions::Frequency::Zero).
+    IncompatibleStub {
+        /// The schedule's frequency.
+        frequency: crate::conventions::Frequency,
+    },
+}
+
+impl fmt::Display for ScheduleBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleBuildError::MissingFrequency => write!(f, "schedule frequency is not set"),
+            ScheduleBuildError::ZeroLengthFrequency { frequency } => {
+                write!(f, "schedule frequency {frequency} has a zero-length period")
+            }
+            ScheduleBuildError::AdjustRuleWithoutCalendar => {
+                write!(f, "adjustment rule requires a calendar")
+            }
+            ScheduleBuildError::ConflictingRoll => {
+                write!(f, "end-of-month flag and roll convention are mutually exclusive")
+            }
+            ScheduleBuildError::IncompatibleEom { frequency } => {
+                write!(f, "end-of-month rolling requires a monthly frequency, not {frequency}")
+            }
+            ScheduleBuildError::IncompatibleRoll { roll, frequency } => {
+                write!(f, "roll convention {roll} does not apply to frequency {frequency}")
+            }
+            ScheduleBuildError::IncompatibleStub { frequency } => {
+                write!(f, "stub rule requires regular periods, not frequency {frequency}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleBuildError {}
+
+impl From<DayCountError> for ScheduleError {
+    fn from(err: DayCountError) -> Self {
+        ScheduleError::DayCount(err)
+    }
+}
+
+/// Errors returned by calendar construction and queries.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CalendarError {
+    /// Returned when every day of the week is a weekend day, so the calendar
+    /// has no business days at all and adjustment could never terminate.
+    NoBusinessDays,
+    /// Returned when a calendar's valid range would end before it starts.
+    InvalidRange,
+    /// Returned when a checked query falls outside the calendar's valid
+    /// range, where its holiday data cannot be trusted.
+    OutOfRange {
+        /// The date that fell outside the range.
+        date: chrono::NaiveDate,
+    },
+    /// Returned when market hours do not close after they open.
+    InvalidHours,
+    /// Returned when combining calendars whose valid ranges do not
+    /// intersect, so the result would cover no date at all.
+    DisjointRanges,
+    /// Returned when holiday metadata is given for a date that is not a
+    /// holiday of the calendar.
+    UnknownHoliday {
+        /// The date the metadata refers to.
+        date: chrono::NaiveDate,
+    },
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::NoBusinessDays => {
+                write!(f, "calendar has no business days")
+            }
+            CalendarError::InvalidRange => {
+                write!(f, "calendar valid range must not end before it starts")
+            }
+            CalendarError::OutOfRange { date } => {
+                write!(f, "{date} is outside the calendar's valid range")
+            }
+            CalendarError::InvalidHours => {
+                write!(f, "market hours must close after they open")
+            }
+            CalendarError::DisjointRanges => {
+                write!(f, "calendar valid ranges do not intersect")
+            }
+            CalendarError::UnknownHoliday { date } => {
+                write!(f, "{date} has holiday metadata but is not a holiday")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+/// Errors returned by date helpers when the requested date does not exist.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DateError {
+    /// Returned when the month is not in `1..=12`.
+    InvalidMonth,
+    /// Returned when the day does not exist in the month, or is outside the
+    /// range a function accepts.
+    InvalidDay,
+    /// Returned when the month has no `n`-th occurrence of the requested
+    /// day, including when `n` is zero.
+    InvalidOrdinal,
+    /// Returned when the date lies outside the representable date range.
+    OutOfRange,
+    /// Returned when a local time does not exist on the date in the
+    /// requested time zone, because a daylight saving change skips it.
+    InvalidLocalTime,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::InvalidMonth => write!(f, "month must be between 1 and 12"),
+            DateError::InvalidDay => write!(f, "day is not valid for the month"),
+            DateError::InvalidOrdinal => {
+                write!(f, "the month has no such occurrence of the requested day")
+            }
+            DateError::OutOfRange => write!(f, "date is outside the representable range"),
+            DateError::InvalidLocalTime => {
+                write!(f, "local time does not exist on that date in the time zone")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// Errors returned by [`algebra::futures_expiry`](crate::algebra::futures_expiry).
+#[derive(Debug, PartialEq, Eq)]
+pub enum FuturesExpiryError {
+    /// Returned when the contract code has no month code and two- or
+    /// four-digit year at its end, e.g. `"ESZ4"` or `"ES24"`.
+    InvalidCode(String),
+    /// Returned when the expiry rule names a day the contract month does not
+    /// have, or the expiry is outside the representable date range.
+    Date(DateError),
+}
+
+impl fmt::Display for FuturesExpiryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuturesExpiryError::InvalidCode(code) => write!(f, "invalid futures contract code {code:?}"),
+            FuturesExpiryError::Date(err) => write!(f, "no expiry date: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FuturesExpiryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FuturesExpiryError::Date(err) => Some(err),
+            FuturesExpiryError::InvalidCode(_) => None,
+        }
+    }
+}
+
+impl From<DateError> for FuturesExpiryError {
+    fn from(err: DateError) -> Self {
+        FuturesExpiryError::Date(err)
+    }
+}
+
+/// Errors returned by [`algebra::fra_dates`](crate::algebra::fra_dates) and
+/// [`algebra::fx_forward_dates`](crate::algebra::fx_forward_dates).
+#[derive(Debug, PartialEq, Eq)]
+pub enum ForwardDateError {
+    /// Returned when the FRA notation or a forward tenor is not valid.
+    InvalidTenor(ParseTenorError),
+    /// Returned when the calendars' weekends together cover the whole week,
+    /// so no date is a business day in every calendar.
+    NoBusinessDays,
+    /// Returned when a date runs off the representable date range.
+    OutOfRange,
+}
+
+impl fmt::Display for ForwardDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForwardDateError::InvalidTenor(err) => write!(f, "no forward dates: {err}"),
+            ForwardDateError::NoBusinessDays => write!(f, "no date is a business day in every calendar"),
+            ForwardDateError::OutOfRange => write!(f, "forward date is outside the representable range"),
+        }
+    }
+}
+
+impl std::error::Error for ForwardDateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ForwardDateError::InvalidTenor(err) => Some(err),
+            ForwardDateError::NoBusinessDays | ForwardDateError::OutOfRange => None,
+        }
+    }
+}
+
+impl From<ParseTenorError> for ForwardDateError {
+    fn from(err: ParseTenorError) -> Self {
+        ForwardDateError::InvalidTenor(err)
+    }
+}
+
+/// Errors returned when loading or saving a
+/// [`Calendar`](crate::calendar::Calendar).
+#[derive(Debug)]
+pub enum CalendarIoError {
+    /// Returned when reading from or writing to the underlying stream fails.
+    Io(std::io::Error),
+    /// Returned when the JSON input is not a valid calendar.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// Returned when a CSV row does not start with a `YYYY-MM-DD` date.
+    /// `line` is 1-based.
+    InvalidDate { line: usize },
+    /// Returned when a quoted CSV holiday name is not closed, or is followed
+    /// by more text.  `line` is the 1-based line the name starts on.
+    InvalidName { line: usize },
+}
+
+impl fmt::Display for CalendarIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarIoError::Io(err) => write!(f, "calendar I/O failed: {err}"),
+            #[cfg(feature = "json")]
+            CalendarIoError::Json(err) => write!(f, "invalid calendar JSON: {err}"),
+            CalendarIoError::InvalidDate { line } => {
+                write!(f, "invalid holiday date on line {line}")
+            }
+            CalendarIoError::InvalidName { line } => {
+                write!(f, "unterminated or malformed quoted holiday name on line {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalendarIoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CalendarIoError::Io(err) => Some(err),
+            #[cfg(feature = "json")]
+            CalendarIoError::Json(err) => Some(err),
+            CalendarIoError::InvalidDate { .. } | CalendarIoError::InvalidName { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CalendarIoError {
+    fn from(err: std::io::Error) -> Self {
+        CalendarIoError::Io(err)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for CalendarIoError {
+    fn from(err: serde_json::Error) -> Self {
+        CalendarIoError::Json(err)
+    }
+}
+
+/// Errors returned when an iCalendar document cannot be parsed into a
+/// [`Calendar`](crate::calendar::Calendar).
+///
+/// `line` is the 1-based line number after unfolding continuation lines.
+#[cfg(feature = "ics")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum IcsError {
+    /// Returned when a `DTSTART`, `DTEND`, `EXDATE` or `UNTIL` value is not a
+    /// valid date.
+    InvalidDate { line: usize },
+    /// Returned when a `VEVENT` ends without a `DTSTART`.
+    MissingStart { line: usize },
+    /// Returned when an `RRULE` is not a yearly rule or uses unsupported parts.
+    UnsupportedRule { line: usize },
+}
+
+#[cfg(feature = "ics")]
+impl fmt::Display for IcsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IcsError::InvalidDate { line } => write!(f, "invalid iCalendar date on line {line}"),
+            IcsError::MissingStart { line } => {
+                write!(f, "event ending on line {line} has no DTSTART")
+            }
+            IcsError::UnsupportedRule { line } => {
+                write!(f, "unsupported recurrence rule on line {line}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ics")]
+impl std::error::Error for IcsError {}
+
+/// Errors returned when loading holidays from a public holiday API (requires
+/// the `http` feature).
+#[cfg(feature = "http")]
+#[derive(Debug)]
+pub enum HolidayApiError {
+    /// Returned when the HTTP request fails or the server answers with an
+    /// error status, e.g. for an unknown country code.
+    Request(String),
+    /// Returned when the response is not the expected JSON.
+    Json(serde_json::Error),
+    /// Returned when a holiday date in the response is not `YYYY-MM-DD`.
+    InvalidDate(String),
+}
+
+#[cfg(feature = "http")]
+impl fmt::Display for HolidayApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HolidayApiError::Request(err) => write!(f, "holiday API request failed: {err}"),
+            HolidayApiError::Json(err) => write!(f, "invalid holiday API response: {err}"),
+            HolidayApiError::InvalidDate(date) => write!(f, "invalid holiday date {date:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl std::error::Error for HolidayApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HolidayApiError::Json(err) => Some(err),
+            HolidayApiError::Request(_) | HolidayApiError::InvalidDate(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<serde_json::Error> for HolidayApiError {
+    fn from(err: serde_json::Error) -> Self {
+        HolidayApiError::Json(err)
+    }
+}
+
+/// Error returned when a string cannot be parsed into a
+/// [`DayCount`](crate::conventions::DayCount).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDayCountError;
+
+impl fmt::Display for ParseDayCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown day count convention string")
+    }
+}
+
+impl std::error::Error for ParseDayCountError {}
+
+/// Error returned when a string cannot be parsed into an
+/// [`AdjustRule`](crate::conventions::AdjustRule).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAdjustRuleError;
+
+impl fmt::Display for ParseAdjustRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown adjust rule string")
+    }
+}
+
+impl std::error::Error for ParseAdjustRuleError {}
+
+/// Error returned when a string cannot be parsed into a
+/// [`Frequency`](crate::conventions::Frequency).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFrequencyError;
+
+impl fmt::Display for ParseFrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown frequency string")
+    }
+}
+
+impl std::error::Error for ParseFrequencyError {}
+
+/// Error returned when a string cannot be parsed into a
+/// [`Tenor`](crate::conventions::Tenor).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTenorError;
+
+impl fmt::Display for ParseTenorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid tenor string")
+    }
+}
+
+impl std::error::Error for ParseTenorError {}
+
+/// Error returned when a string cannot be parsed into a
+/// [`RollConvention`](crate::conventions::RollConvention).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRollConventionError;
+
+impl fmt::Display for ParseRollConventionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid roll convention string")
+    }
+}
+
+impl std::error::Error for ParseRollConventionError {}
+
+/// Error returned when a calendar expression cannot be parsed into a
+/// [`Calendar`](crate::calendar::Calendar).
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseCalendarError {
+    /// Returned when a code is not in the registry.
+    UnknownCode(String),
+    /// Returned when registering a code that is empty or contains `|` or
+    /// `&`, so it could never be parsed back.
+    InvalidCode(String),
+    /// Returned when a code is missing, e.g. in `""` or `"NYC|"`.
+    InvalidExpression,
+    /// Returned when the expression combines calendars whose valid ranges do
+    /// not intersect.
+    DisjointRanges,
+}
+
+impl fmt::Display for ParseCalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCalendarError::UnknownCode(code) => write!(f, "unknown calendar code {code:?}"),
+            ParseCalendarError::InvalidCode(code) => {
+                write!(f, "invalid calendar code {code:?}: must be non-empty and contain no '|' or '&'")
+            }
+            ParseCalendarError::InvalidExpression => write!(f, "invalid calendar expression"),
+            ParseCalendarError::DisjointRanges => write!(f, "calendar valid ranges do not intersect"),
+        }
+    }
+}
+
+impl std::error::Error for ParseCalendarError {}
@@ -0,0 +1,373 @@
+//! Fiscal years that start on a date other than 1 January.
+//!
+//! A [`FiscalYear`] fixes the month and day each fiscal year starts on and
+//! how years are numbered: by the calendar year they start in, as Japan
+//! does for the year from April 2024 to March 2025, or by the year they end
+//! in, as the US federal government does for fiscal 2025, which runs from
+//! October 2024 to September 2025.  From that it derives fiscal quarter
+//! boundaries, year-to-date business day counts and the period ends that
+//! reporting schedules align to.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use findates::fiscal::FiscalYear;
+//!
+//! let fy = FiscalYear::US_FEDERAL;
+//! let date = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+//!
+//! assert_eq!(fy.year_of(&date), 2025);
+//! assert_eq!(fy.quarter_of(&date), 1);
+//! assert_eq!(fy.end(2025), NaiveDate::from_ymd_opt(2025, 9, 30));
+//! ```
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::algebra::{business_days_between_with_boundaries, Boundaries};
+use crate::calendar::Calendar;
+use crate::conventions::{AdjustRule, Frequency};
+use crate::error::DateError;
+
+/// Which calendar year gives a [`FiscalYear`] its number.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::fiscal::{FiscalYear, FiscalYearLabel};
+///
+/// assert_eq!(FiscalYear::JAPAN.label(), FiscalYearLabel::StartYear);
+/// assert_eq!(FiscalYear::US_FEDERAL.label(), FiscalYearLabel::EndYear);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub enum FiscalYearLabel {
+    /// Numbered by the calendar year the fiscal year starts in.
+    StartYear,
+    /// Numbered by the calendar year the fiscal year ends in.
+    EndYear,
+}
+
+/// A fiscal year starting on a fixed month and day each year.
+///
+/// The start day is limited to `1..=28`, so every fiscal year and every
+/// fiscal month within it starts on the same day of the month.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::fiscal::{FiscalYear, FiscalYearLabel};
+///
+/// // Australia: 1 July to 30 June, named after the year it ends in.
+/// let fy = FiscalYear::new(7, 1, FiscalYearLabel::EndYear).unwrap();
+/// assert_eq!(fy, FiscalYear::AUSTRALIA);
+/// assert_eq!(fy.start(2025), NaiveDate::from_ymd_opt(2024, 7, 1));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct FiscalYear {
+    start_month: u32,
+    start_day: u32,
+    label: FiscalYearLabel,
+}
+
+impl FiscalYear {
+    /// The calendar year, 1 January to 31 December.
+    pub const CALENDAR: FiscalYear = FiscalYear { start_month: 1, start_day: 1, label: FiscalYearLabel::StartYear };
+    /// The UK government financial year, from 1 April: 2024-25 is year 2024.
+    pub const UK: FiscalYear = FiscalYear { start_month: 4, start_day: 1, label: FiscalYearLabel::StartYear };
+    /// The UK personal tax year, from 6 April: 2024-25 is year 2024.
+    pub const UK_TAX: FiscalYear = FiscalYear { start_month: 4, start_day: 6, label: FiscalYearLabel::StartYear };
+    /// The Japanese fiscal year, from 1 April and named after its start.
+    pub const JAPAN: FiscalYear = FiscalYear { start_month: 4, start_day: 1, label: FiscalYearLabel::StartYear };
+    /// The US federal fiscal year, from 1 October and named after its end.
+    pub const US_FEDERAL: FiscalYear = FiscalYear { start_month: 10, start_day: 1, label: FiscalYearLabel::EndYear };
+    /// The Australian financial year, from 1 July and named after its end.
+    pub const AUSTRALIA: FiscalYear = FiscalYear { start_month: 7, start_day: 1, label: FiscalYearLabel::EndYear };
+
+    /// Creates a fiscal year starting on `start_day` of `start_month`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::InvalidMonth`] if `start_month` is not in
+    /// `1..=12`, and [`DateError::InvalidDay`] if `start_day` is not in
+    /// `1..=28`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::fiscal::{FiscalYear, FiscalYearLabel};
+    /// use findates::DateError;
+    ///
+    /// assert!(FiscalYear::new(2, 1, FiscalYearLabel::EndYear).is_ok());
+    /// assert_eq!(FiscalYear::new(13, 1, FiscalYearLabel::EndYear), Err(DateError::InvalidMonth));
+    /// assert_eq!(FiscalYear::new(1, 31, FiscalYearLabel::EndYear), Err(DateError::InvalidDay));
+    /// ```
+    pub fn new(start_month: u32, start_day: u32, label: FiscalYearLabel) -> Result<Self, DateError> {
+        if !(1..=12).contains(&start_month) {
+            return Err(DateError::InvalidMonth);
+        }
+        if !(1..=28).contains(&start_day) {
+            return Err(DateError::InvalidDay);
+        }
+        Ok(FiscalYear { start_month, start_day, label })
+    }
+
+    /// Returns the month the fiscal year starts in, from 1 to 12.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::UK_TAX.start_month(), 4);
+    /// ```
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// Returns the day of the month the fiscal year starts on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::UK_TAX.start_day(), 6);
+    /// ```
+    pub fn start_day(&self) -> u32 {
+        self.start_day
+    }
+
+    /// Returns how fiscal years are numbered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::fiscal::{FiscalYear, FiscalYearLabel};
+    ///
+    /// assert_eq!(FiscalYear::UK.label(), FiscalYearLabel::StartYear);
+    /// ```
+    pub fn label(&self) -> FiscalYearLabel {
+        self.label
+    }
+
+    /// Returns the number of the fiscal year containing `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2025, 4, 5).unwrap();
+    /// assert_eq!(FiscalYear::UK_TAX.year_of(&date), 2024);
+    /// assert_eq!(FiscalYear::UK.year_of(&date), 2025);
+    /// ```
+    pub fn year_of(&self, date: &NaiveDate) -> i32 {
+        let starts_in = if (date.month(), date.day()) < (self.start_month, self.start_day) {
+            date.year() - 1
+        } else {
+            date.year()
+        };
+        starts_in + self.label_offset()
+    }
+
+    /// Returns the first day of fiscal year `year`, or `None` if it is out
+    /// of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::US_FEDERAL.start(2025), NaiveDate::from_ymd_opt(2024, 10, 1));
+    /// assert_eq!(FiscalYear::JAPAN.start(2025), NaiveDate::from_ymd_opt(2025, 4, 1));
+    /// ```
+    pub fn start(&self, year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year.checked_sub(self.label_offset())?, self.start_month, self.start_day)
+    }
+
+    /// Returns the last day of fiscal year `year`, or `None` if it is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::UK_TAX.end(2024), NaiveDate::from_ymd_opt(2025, 4, 5));
+    /// ```
+    pub fn end(&self, year: i32) -> Option<NaiveDate> {
+        self.start(year.checked_add(1)?)?.pred_opt()
+    }
+
+    /// Returns the fiscal quarter containing `date`, from 1 to 4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// // 1 April to 30 June is the first quarter of the Japanese fiscal year.
+    /// assert_eq!(FiscalYear::JAPAN.quarter_of(&NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()), 1);
+    /// assert_eq!(FiscalYear::JAPAN.quarter_of(&NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()), 4);
+    /// ```
+    pub fn quarter_of(&self, date: &NaiveDate) -> u32 {
+        self.months_into(date) / 3 + 1
+    }
+
+    /// Returns the first day of quarter `quarter` of fiscal year `year`.
+    ///
+    /// Returns `None` if `quarter` is not in `1..=4` or the date is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::US_FEDERAL.quarter_start(2025, 2), NaiveDate::from_ymd_opt(2025, 1, 1));
+    /// assert_eq!(FiscalYear::US_FEDERAL.quarter_start(2025, 5), None);
+    /// ```
+    pub fn quarter_start(&self, year: i32, quarter: u32) -> Option<NaiveDate> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        self.start(year)?.checked_add_months(Months::new(3 * (quarter - 1)))
+    }
+
+    /// Returns the last day of quarter `quarter` of fiscal year `year`.
+    ///
+    /// Returns `None` if `quarter` is not in `1..=4` or the date is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::UK_TAX.quarter_end(2024, 1), NaiveDate::from_ymd_opt(2024, 7, 5));
+    /// ```
+    pub fn quarter_end(&self, year: i32, quarter: u32) -> Option<NaiveDate> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        self.start(year)?.checked_add_months(Months::new(3 * quarter))?.pred_opt()
+    }
+
+    /// Counts the business days in `calendar` from the start of `date`'s
+    /// fiscal year up to and including `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// // Tuesday 1 October to Monday 14 October 2024: two full weeks.
+    /// let cal = basic_calendar();
+    /// let date = NaiveDate::from_ymd_opt(2024, 10, 14).unwrap();
+    /// assert_eq!(FiscalYear::US_FEDERAL.ytd_business_days(&date, &cal), 10);
+    /// ```
+    pub fn ytd_business_days(&self, date: &NaiveDate, calendar: &Calendar) -> u64 {
+        match self.start(self.year_of(date)) {
+            Some(start) => business_days_between_with_boundaries(
+                &start,
+                date,
+                calendar,
+                Some(AdjustRule::Unadjusted),
+                Boundaries::IncludeBoth,
+            ),
+            None => 0,
+        }
+    }
+
+    /// Returns the last day of the fiscal period of `frequency` containing
+    /// `date`.
+    ///
+    /// Fiscal periods split the fiscal year into equal numbers of months, so
+    /// `frequency` must be a whole number of months that divides twelve,
+    /// such as [`Frequency::Monthly`], [`Frequency::Quarterly`] or
+    /// [`Frequency::Annual`].  Returns `None` for any other frequency, or
+    /// if the date is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+    /// let fy = FiscalYear::JAPAN;
+    /// assert_eq!(fy.period_end(&date, Frequency::Semiannual), NaiveDate::from_ymd_opt(2025, 3, 31));
+    /// assert_eq!(fy.period_end(&date, Frequency::Quarterly), NaiveDate::from_ymd_opt(2024, 12, 31));
+    /// assert_eq!(fy.period_end(&date, Frequency::Weekly), None);
+    /// ```
+    pub fn period_end(&self, date: &NaiveDate, frequency: Frequency) -> Option<NaiveDate> {
+        let months = fiscal_period_months(frequency)?;
+        let start = self.start(self.year_of(date))?;
+        let periods = self.months_into(date) / months + 1;
+        start.checked_add_months(Months::new(periods * months))?.pred_opt()
+    }
+
+    /// Returns the ends of the fiscal periods of `frequency` that fall from
+    /// `from` to `to` inclusive, in order.
+    ///
+    /// These are the reporting dates a schedule aligned to the fiscal year
+    /// falls on; adjust them with
+    /// [`algebra::adjust_many`](crate::algebra::adjust_many) and
+    /// [`AdjustRule::Preceding`] to land on the last business day of each
+    /// period.  `frequency` must divide the fiscal year as in
+    /// [`period_end`](Self::period_end); returns `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::fiscal::FiscalYear;
+    ///
+    /// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    /// let ends = FiscalYear::UK_TAX.period_ends(&d(2024, 1, 1), &d(2024, 12, 31), Frequency::Quarterly);
+    /// assert_eq!(ends, Some(vec![d(2024, 1, 5), d(2024, 4, 5), d(2024, 7, 5), d(2024, 10, 5)]));
+    /// ```
+    pub fn period_ends(&self, from: &NaiveDate, to: &NaiveDate, frequency: Frequency) -> Option<Vec<NaiveDate>> {
+        let months = fiscal_period_months(frequency)?;
+        let start = self.start(self.year_of(from))?;
+        let period_end = |periods: u32| start.checked_add_months(Months::new(periods * months))?.pred_opt();
+        let ends = (1..)
+            .map_while(period_end)
+            .take_while(|end| end <= to)
+            .filter(|end| end >= from)
+            .collect();
+        Some(ends)
+    }
+
+    // Years between the calendar year a fiscal year starts in and its number.
+    fn label_offset(&self) -> i32 {
+        match self.label {
+            FiscalYearLabel::EndYear if (self.start_month, self.start_day) != (1, 1) => 1,
+            _ => 0,
+        }
+    }
+
+    // Whole fiscal months from the start of `date`'s fiscal year to `date`.
+    fn months_into(&self, date: &NaiveDate) -> u32 {
+        let months = (date.month() + 12 - self.start_month) % 12;
+        if date.day() < self.start_day {
+            (months + 11) % 12
+        } else {
+            months
+        }
+    }
+}
+
+// Months in a fiscal period of `frequency`, if it divides the year evenly.
+fn fiscal_period_months(frequency: Frequency) -> Option<u32> {
+    frequency.to_months().filter(|months| 12 % months == 0)
+}
@@ -0,0 +1,457 @@
+//! Public holiday APIs as a source of [`Calendar`]s.
+//!
+//! [`Calendar::fetch_public_holidays`] builds a calendar from the free
+//! [Nager.Date](https://date.nager.at) API.  Other sources plug in through
+//! the [`HolidayProvider`] trait and [`Calendar::from_holiday_provider`];
+//! [`OpenHolidays`] implements it for the
+//! [OpenHolidays](https://www.openholidaysapi.org) API.
+//!
+//! Only nationwide public and bank holidays are kept: regional holidays and
+//! optional or school days are dropped.  Requires the `http` feature, which
+//! pulls in [ureq](https://docs.rs/ureq).
+//!
+//! The response parsers ([`NagerDate::parse`], [`OpenHolidays::parse`]) are
+//! public so that recorded responses can be loaded without the network.
+
+use std::ops::RangeInclusive;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::calendar::{basic_calendar, Calendar};
+use crate::error::HolidayApiError;
+
+/// A holiday as returned by a [`HolidayProvider`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::holiday_api::PublicHoliday;
+///
+/// let holiday = PublicHoliday::new(NaiveDate::from_ymd_opt(2024, 7, 4).unwrap(), "Independence Day");
+/// assert_eq!(holiday.name, "Independence Day");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicHoliday {
+    /// Date the holiday falls on.
+    pub date: NaiveDate,
+    /// English name of the holiday.
+    pub name: String,
+}
+
+impl PublicHoliday {
+    /// Creates a holiday on `date` called `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::holiday_api::PublicHoliday;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// assert_eq!(PublicHoliday::new(date, "Christmas Day").date, date);
+    /// ```
+    pub fn new(date: NaiveDate, name: impl Into<String>) -> Self {
+        PublicHoliday { date, name: name.into() }
+    }
+}
+
+/// A source of nationwide public holidays, looked up by ISO 3166-1 alpha-2
+/// country code and year.
+///
+/// Implement this to load calendars from another API or a local database
+/// with [`Calendar::from_holiday_provider`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::Calendar;
+/// use findates::holiday_api::{HolidayProvider, PublicHoliday};
+/// use findates::HolidayApiError;
+///
+/// // Christmas everywhere, as a stand-in for a real source.
+/// struct Christmas;
+///
+/// impl HolidayProvider for Christmas {
+///     fn public_holidays(&self, _country_code: &str, year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+///         let date = NaiveDate::from_ymd_opt(year, 12, 25).ok_or(HolidayApiError::InvalidDate(year.to_string()))?;
+///         Ok(vec![PublicHoliday::new(date, "Christmas Day")])
+///     }
+/// }
+///
+/// let cal = Calendar::from_holiday_provider(&Christmas, "GB", 2024..=2025).unwrap();
+/// assert_eq!(cal.get_holidays().len(), 2);
+/// ```
+pub trait HolidayProvider {
+    /// Returns the nationwide public holidays of `country_code` in `year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HolidayApiError`] if the holidays cannot be retrieved or
+    /// the response cannot be read.
+    fn public_holidays(&self, country_code: &str, year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError>;
+}
+
+/// The [Nager.Date](https://date.nager.at) public holiday API.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::holiday_api::NagerDate;
+///
+/// // Point at a mirror or a local test server.
+/// let provider = NagerDate::with_base_url("http://localhost:8080");
+/// assert_eq!(provider.base_url(), "http://localhost:8080");
+/// assert_eq!(NagerDate::default().base_url(), NagerDate::DEFAULT_URL);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NagerDate {
+    base_url: String,
+}
+
+impl NagerDate {
+    /// Base URL of the public Nager.Date service.
+    pub const DEFAULT_URL: &'static str = "https://date.nager.at";
+
+    /// Creates a provider for the public Nager.Date service.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::NagerDate;
+    ///
+    /// assert_eq!(NagerDate::new(), NagerDate::default());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_base_url(Self::DEFAULT_URL)
+    }
+
+    /// Creates a provider for a Nager.Date server at `base_url`, without a
+    /// trailing slash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::NagerDate;
+    ///
+    /// assert_eq!(NagerDate::with_base_url("http://localhost/").base_url(), "http://localhost");
+    /// ```
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        NagerDate { base_url: base_url.trim_end_matches('/').to_string() }
+    }
+
+    /// Returns the base URL requests are sent to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::NagerDate;
+    ///
+    /// assert_eq!(NagerDate::new().base_url(), "https://date.nager.at");
+    /// ```
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Parses the body of a `/api/v3/PublicHolidays/{year}/{country}`
+    /// response, keeping the nationwide (`global`) holidays of type `Public`
+    /// or `Bank`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayApiError::Json`] if `body` is not a list of holidays
+    /// and [`HolidayApiError::InvalidDate`] if a date is not `YYYY-MM-DD`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::holiday_api::NagerDate;
+    ///
+    /// let body = r#"[
+    ///     {"date":"2024-07-04","localName":"Independence Day","name":"Independence Day",
+    ///      "countryCode":"US","global":true,"types":["Public"]},
+    ///     {"date":"2024-10-14","localName":"Columbus Day","name":"Columbus Day",
+    ///      "countryCode":"US","global":false,"types":["Public"]}
+    /// ]"#;
+    /// let holidays = NagerDate::parse(body).unwrap();
+    /// assert_eq!(holidays.len(), 1);
+    /// assert_eq!(holidays[0].date, NaiveDate::from_ymd_opt(2024, 7, 4).unwrap());
+    /// ```
+    pub fn parse(body: &str) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+        let entries: Vec<NagerEntry> = serde_json::from_str(body)?;
+        let mut holidays = Vec::new();
+        for entry in entries {
+            let observed = entry.types.is_empty() || entry.types.iter().any(|t| t == "Public" || t == "Bank");
+            if entry.global && observed {
+                holidays.push(PublicHoliday::new(parse_date(&entry.date)?, entry.name));
+            }
+        }
+        Ok(holidays)
+    }
+}
+
+impl Default for NagerDate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HolidayProvider for NagerDate {
+    fn public_holidays(&self, country_code: &str, year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+        let url = format!("{}/api/v3/PublicHolidays/{year}/{country_code}", self.base_url);
+        Self::parse(&get(&url)?)
+    }
+}
+
+/// The [OpenHolidays](https://www.openholidaysapi.org) public holiday API,
+/// which mostly covers European countries.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::holiday_api::OpenHolidays;
+///
+/// assert_eq!(OpenHolidays::new().base_url(), OpenHolidays::DEFAULT_URL);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenHolidays {
+    base_url: String,
+}
+
+impl OpenHolidays {
+    /// Base URL of the public OpenHolidays service.
+    pub const DEFAULT_URL: &'static str = "https://openholidaysapi.org";
+
+    /// Creates a provider for the public OpenHolidays service.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::OpenHolidays;
+    ///
+    /// assert_eq!(OpenHolidays::new(), OpenHolidays::default());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_base_url(Self::DEFAULT_URL)
+    }
+
+    /// Creates a provider for an OpenHolidays server at `base_url`, without
+    /// a trailing slash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::OpenHolidays;
+    ///
+    /// assert_eq!(OpenHolidays::with_base_url("http://localhost/").base_url(), "http://localhost");
+    /// ```
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        OpenHolidays { base_url: base_url.trim_end_matches('/').to_string() }
+    }
+
+    /// Returns the base URL requests are sent to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holiday_api::OpenHolidays;
+    ///
+    /// assert_eq!(OpenHolidays::new().base_url(), "https://openholidaysapi.org");
+    /// ```
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Parses the body of a `/PublicHolidays` response, keeping the
+    /// nationwide holidays of type `Public` or `Bank`.  Holidays spanning
+    /// several days give one entry per day, and names are taken in English
+    /// where available.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayApiError::Json`] if `body` is not a list of holidays
+    /// and [`HolidayApiError::InvalidDate`] if a date is not `YYYY-MM-DD`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::holiday_api::OpenHolidays;
+    ///
+    /// let body = r#"[{"id":"1","startDate":"2024-10-03","endDate":"2024-10-03","type":"Public",
+    ///     "name":[{"language":"DE","text":"Tag der Deutschen Einheit"},
+    ///             {"language":"EN","text":"German Unity Day"}],
+    ///     "nationwide":true}]"#;
+    /// let holidays = OpenHolidays::parse(body).unwrap();
+    /// assert_eq!(holidays[0].date, NaiveDate::from_ymd_opt(2024, 10, 3).unwrap());
+    /// assert_eq!(holidays[0].name, "German Unity Day");
+    /// ```
+    pub fn parse(body: &str) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+        let entries: Vec<OpenHolidaysEntry> = serde_json::from_str(body)?;
+        let mut holidays = Vec::new();
+        for entry in entries {
+            if !entry.nationwide || !(entry.kind == "Public" || entry.kind == "Bank") {
+                continue;
+            }
+            let name = entry
+                .name
+                .iter()
+                .find(|name| name.language.eq_ignore_ascii_case("EN"))
+                .or_else(|| entry.name.first())
+                .map(|name| name.text.clone())
+                .unwrap_or_default();
+            let end = parse_date(&entry.end_date)?;
+            let mut date = parse_date(&entry.start_date)?;
+            while date <= end {
+                holidays.push(PublicHoliday::new(date, name.clone()));
+                date = match date.succ_opt() {
+                    Some(next) => next,
+                    None => break,
+                };
+            }
+        }
+        Ok(holidays)
+    }
+}
+
+impl Default for OpenHolidays {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HolidayProvider for OpenHolidays {
+    fn public_holidays(&self, country_code: &str, year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+        let url = format!(
+            "{}/PublicHolidays?countryIsoCode={country_code}&languageIsoCode=EN\
+             &validFrom={year:04}-01-01&validTo={year:04}-12-31",
+            self.base_url
+        );
+        Self::parse(&get(&url)?)
+    }
+}
+
+impl Calendar {
+    /// Fetches the nationwide public holidays of `country_code` (ISO 3166-1
+    /// alpha-2, e.g. `"US"`) for `years` from [Nager.Date](NagerDate).
+    ///
+    /// Same as [`Calendar::from_holiday_provider`] with
+    /// [`NagerDate::new()`].  Requires the `http` feature and network
+    /// access.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayApiError::Request`] if a request fails, including
+    /// for unknown country codes, and [`HolidayApiError::Json`] or
+    /// [`HolidayApiError::InvalidDate`] if a response cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let us = Calendar::fetch_public_holidays("US", 2024..=2025).unwrap();
+    /// assert!(!us.is_business_day(&NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()));
+    /// ```
+    pub fn fetch_public_holidays(country_code: &str, years: RangeInclusive<i32>) -> Result<Calendar, HolidayApiError> {
+        Calendar::from_holiday_provider(&NagerDate::new(), country_code, years)
+    }
+
+    /// Builds a calendar from the holidays `provider` returns for
+    /// `country_code` in each of `years`.
+    ///
+    /// The calendar has a Saturday/Sunday weekend, like [`basic_calendar`],
+    /// and each holiday is recorded under its name (see
+    /// [`Calendar::holiday_name`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error `provider` reports.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    /// use findates::holiday_api::{HolidayProvider, NagerDate, PublicHoliday};
+    /// use findates::HolidayApiError;
+    ///
+    /// // Serve a recorded response instead of calling the API.
+    /// struct Recorded(&'static str);
+    ///
+    /// impl HolidayProvider for Recorded {
+    ///     fn public_holidays(&self, _country_code: &str, _year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+    ///         NagerDate::parse(self.0)
+    ///     }
+    /// }
+    ///
+    /// let body = r#"[{"date":"2024-12-25","name":"Christmas Day","global":true,"types":["Public"]}]"#;
+    /// let cal = Calendar::from_holiday_provider(&Recorded(body), "US", 2024..=2024).unwrap();
+    /// assert_eq!(cal.holiday_name(&NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()), Some("Christmas Day"));
+    /// ```
+    pub fn from_holiday_provider<P: HolidayProvider + ?Sized>(
+        provider: &P,
+        country_code: &str,
+        years: RangeInclusive<i32>,
+    ) -> Result<Calendar, HolidayApiError> {
+        let mut cal = basic_calendar();
+        for year in years {
+            for holiday in provider.public_holidays(country_code, year)? {
+                cal.add_named_holiday(holiday.date, holiday.name);
+            }
+        }
+        Ok(cal)
+    }
+}
+
+// One entry of a Nager.Date response; unused fields are ignored.
+#[derive(Deserialize)]
+struct NagerEntry {
+    date: String,
+    name: String,
+    #[serde(default = "nationwide_default")]
+    global: bool,
+    #[serde(default)]
+    types: Vec<String>,
+}
+
+// One entry of an OpenHolidays response; unused fields are ignored.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenHolidaysEntry {
+    start_date: String,
+    end_date: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    name: Vec<LocalizedName>,
+    #[serde(default = "nationwide_default")]
+    nationwide: bool,
+}
+
+#[derive(Deserialize)]
+struct LocalizedName {
+    language: String,
+    text: String,
+}
+
+// Entries that do not say otherwise apply to the whole country.
+fn nationwide_default() -> bool {
+    true
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, HolidayApiError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| HolidayApiError::InvalidDate(value.to_string()))
+}
+
+// GETs `url` and returns the response body.
+fn get(url: &str) -> Result<String, HolidayApiError> {
+    let response = ureq::get(url).call().map_err(|err| HolidayApiError::Request(err.to_string()))?;
+    response.into_string().map_err(|err| HolidayApiError::Request(err.to_string()))
+}
@@ -0,0 +1,343 @@
+//! Rule-based holiday definitions and weekend observance.
+//!
+//! A [`HolidayRule`] describes a recurring holiday — a fixed date, the nth
+//! weekday of a month or an offset from Easter — together with the
+//! [`ObservanceRule`] that decides which day is taken off when the holiday
+//! falls on a weekend.  [`Calendar::add_holiday_rules`] expands a set of rules
+//! over a range of years and stores the observed dates.
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::ops::RangeInclusive;
+
+use crate::algebra::{easter_sunday, last_weekday_of_month, nth_weekday_of_month};
+use crate::calendar::Calendar;
+
+/// Which day is taken off when a holiday falls on a weekend.
+///
+/// Weekend days are those of the calendar the holiday is added to.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::holidays::ObservanceRule;
+///
+/// let cal      = basic_calendar();
+/// let saturday = NaiveDate::from_ymd_opt(2026, 7, 4).unwrap();
+///
+/// assert_eq!(ObservanceRule::NearestWeekday.observe(saturday, &cal), NaiveDate::from_ymd_opt(2026, 7, 3));
+/// assert_eq!(ObservanceRule::NextWeekday.observe(saturday, &cal), NaiveDate::from_ymd_opt(2026, 7, 6));
+/// assert_eq!(ObservanceRule::Actual.observe(saturday, &cal), Some(saturday));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObservanceRule {
+    /// The holiday is kept on its date, even on a weekend.
+    #[default]
+    Actual,
+    /// A weekend holiday moves to the nearest weekday, preferring the later
+    /// one when both are equally far: Saturday to Friday and Sunday to Monday
+    /// with a Saturday/Sunday weekend (US federal holidays).
+    NearestWeekday,
+    /// A weekend holiday moves to the next weekday.
+    NextWeekday,
+    /// A weekend holiday moves to the next weekday that is not already a
+    /// holiday (UK substitute days, e.g. Christmas on Saturday is observed on
+    /// Monday and Boxing Day on Tuesday).
+    NextFreeWeekday,
+    /// A holiday on a Sunday moves to the next day that is not already a
+    /// holiday; Saturday holidays are not moved (Japanese substitute
+    /// holidays).
+    SundayToNextFreeDay,
+}
+
+impl ObservanceRule {
+    /// Returns the day on which a holiday falling on `date` is observed in
+    /// `calendar`, or `None` if no such day is found within a year.
+    ///
+    /// [`NextFreeWeekday`](ObservanceRule::NextFreeWeekday) and
+    /// [`SundayToNextFreeDay`](ObservanceRule::SundayToNextFreeDay) skip the
+    /// holidays already in `calendar`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::holidays::ObservanceRule;
+    ///
+    /// // Christmas 2021 fell on a Saturday and Boxing Day on a Sunday.
+    /// let mut cal = basic_calendar();
+    /// let christmas = NaiveDate::from_ymd_opt(2021, 12, 25).unwrap();
+    /// let observed  = ObservanceRule::NextFreeWeekday.observe(christmas, &cal).unwrap();
+    /// assert_eq!(observed, NaiveDate::from_ymd_opt(2021, 12, 27).unwrap());
+    ///
+    /// cal.add_holidays([observed]);
+    /// let boxing_day = NaiveDate::from_ymd_opt(2021, 12, 26).unwrap();
+    /// assert_eq!(ObservanceRule::NextFreeWeekday.observe(boxing_day, &cal), NaiveDate::from_ymd_opt(2021, 12, 28));
+    /// ```
+    pub fn observe(self, date: NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
+        let later = |accept: &dyn Fn(&NaiveDate) -> bool| date.iter_days().skip(1).take(366).find(|day| accept(day));
+        match self {
+            ObservanceRule::Actual => Some(date),
+            ObservanceRule::SundayToNextFreeDay if date.weekday() == Weekday::Sun => later(&|day| !calendar.is_holiday(day)),
+            ObservanceRule::SundayToNextFreeDay => Some(date),
+            _ if !calendar.is_weekend(&date) => Some(date),
+            ObservanceRule::NearestWeekday => {
+                let next = later(&|day| !calendar.is_weekend(day))?;
+                let previous = date.iter_days().rev().skip(1).take(366).find(|day| !calendar.is_weekend(day));
+                match previous {
+                    Some(previous) if date - previous < next - date => Some(previous),
+                    _ => Some(next),
+                }
+            }
+            ObservanceRule::NextWeekday => later(&|day| !calendar.is_weekend(day)),
+            ObservanceRule::NextFreeWeekday => later(&|day| calendar.is_business_day(day)),
+        }
+    }
+}
+
+/// When a recurring holiday falls in a given year.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::holidays::HolidayDate;
+///
+/// let thanksgiving = HolidayDate::NthWeekday { month: 11, weekday: Weekday::Thu, n: 4 };
+/// assert_eq!(thanksgiving.in_year(2024), NaiveDate::from_ymd_opt(2024, 11, 28));
+///
+/// let good_friday = HolidayDate::EasterOffset(-2);
+/// assert_eq!(good_friday.in_year(2024), NaiveDate::from_ymd_opt(2024, 3, 29));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HolidayDate {
+    /// The same month and day every year.  Skipped in years where the day
+    /// does not exist (29 February).
+    Fixed {
+        /// Month, 1 to 12.
+        month: u32,
+        /// Day of the month.
+        day: u32,
+    },
+    /// The `n`th `weekday` of `month`, e.g. the fourth Thursday of November.
+    NthWeekday {
+        /// Month, 1 to 12.
+        month: u32,
+        /// Day of the week.
+        weekday: Weekday,
+        /// Ordinal, 1 to 5.
+        n: u8,
+    },
+    /// The last `weekday` of `month`, e.g. the last Monday of May.
+    LastWeekday {
+        /// Month, 1 to 12.
+        month: u32,
+        /// Day of the week.
+        weekday: Weekday,
+    },
+    /// A number of days from Easter Sunday, e.g. `-2` for Good Friday.
+    EasterOffset(i32),
+}
+
+impl HolidayDate {
+    /// Returns the (unobserved) date of the holiday in `year`, or `None` if
+    /// it does not occur that year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::holidays::HolidayDate;
+    ///
+    /// let leap_day = HolidayDate::Fixed { month: 2, day: 29 };
+    /// assert_eq!(leap_day.in_year(2024), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert_eq!(leap_day.in_year(2025), None);
+    /// ```
+    pub fn in_year(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            HolidayDate::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayDate::NthWeekday { month, weekday, n } => nth_weekday_of_month(year, month, weekday, n).ok(),
+            HolidayDate::LastWeekday { month, weekday } => last_weekday_of_month(year, month, weekday).ok(),
+            HolidayDate::EasterOffset(days) => {
+                let easter = easter_sunday(year).ok()?;
+                let offset = Days::new(days.unsigned_abs() as u64);
+                if days < 0 {
+                    easter.checked_sub_days(offset)
+                } else {
+                    easter.checked_add_days(offset)
+                }
+            }
+        }
+    }
+}
+
+/// A named recurring holiday with its weekend [`ObservanceRule`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::holidays::{HolidayRule, ObservanceRule};
+///
+/// let independence_day = HolidayRule::fixed("Independence Day", 7, 4).observed(ObservanceRule::NearestWeekday);
+/// assert_eq!(independence_day.name, "Independence Day");
+/// assert_eq!(independence_day.date.in_year(2026), NaiveDate::from_ymd_opt(2026, 7, 4));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HolidayRule {
+    /// Holiday name, stored on the observed date.
+    pub name: String,
+    /// When the holiday falls.
+    pub date: HolidayDate,
+    /// Which day is taken off when the holiday falls on a weekend.
+    pub observance: ObservanceRule,
+}
+
+impl HolidayRule {
+    /// Creates a rule for a holiday on `date`, kept on its date even on a
+    /// weekend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holidays::{HolidayDate, HolidayRule, ObservanceRule};
+    ///
+    /// let rule = HolidayRule::new("Corpus Christi", HolidayDate::EasterOffset(60));
+    /// assert_eq!(rule.observance, ObservanceRule::Actual);
+    /// ```
+    pub fn new(name: impl Into<String>, date: HolidayDate) -> Self {
+        Self {
+            name: name.into(),
+            date,
+            observance: ObservanceRule::Actual,
+        }
+    }
+
+    /// Creates a rule for a holiday on the same month and day every year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holidays::{HolidayDate, HolidayRule};
+    ///
+    /// let rule = HolidayRule::fixed("Christmas Day", 12, 25);
+    /// assert_eq!(rule.date, HolidayDate::Fixed { month: 12, day: 25 });
+    /// ```
+    pub fn fixed(name: impl Into<String>, month: u32, day: u32) -> Self {
+        Self::new(name, HolidayDate::Fixed { month, day })
+    }
+
+    /// Creates a rule for a holiday on the `n`th `weekday` of `month`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::holidays::HolidayRule;
+    ///
+    /// let labor_day = HolidayRule::nth_weekday("Labor Day", 9, Weekday::Mon, 1);
+    /// assert_eq!(labor_day.date.in_year(2024), NaiveDate::from_ymd_opt(2024, 9, 2));
+    /// ```
+    pub fn nth_weekday(name: impl Into<String>, month: u32, weekday: Weekday, n: u8) -> Self {
+        Self::new(name, HolidayDate::NthWeekday { month, weekday, n })
+    }
+
+    /// Creates a rule for a holiday on the last `weekday` of `month`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::holidays::HolidayRule;
+    ///
+    /// let memorial_day = HolidayRule::last_weekday("Memorial Day", 5, Weekday::Mon);
+    /// assert_eq!(memorial_day.date.in_year(2024), NaiveDate::from_ymd_opt(2024, 5, 27));
+    /// ```
+    pub fn last_weekday(name: impl Into<String>, month: u32, weekday: Weekday) -> Self {
+        Self::new(name, HolidayDate::LastWeekday { month, weekday })
+    }
+
+    /// Creates a rule for a holiday `days` days from Easter Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::holidays::HolidayRule;
+    ///
+    /// let easter_monday = HolidayRule::easter_offset("Easter Monday", 1);
+    /// assert_eq!(easter_monday.date.in_year(2024), NaiveDate::from_ymd_opt(2024, 4, 1));
+    /// ```
+    pub fn easter_offset(name: impl Into<String>, days: i32) -> Self {
+        Self::new(name, HolidayDate::EasterOffset(days))
+    }
+
+    /// Sets the rule's [`ObservanceRule`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::holidays::{HolidayRule, ObservanceRule};
+    ///
+    /// let rule = HolidayRule::fixed("New Year's Day", 1, 1).observed(ObservanceRule::NextWeekday);
+    /// assert_eq!(rule.observance, ObservanceRule::NextWeekday);
+    /// ```
+    pub fn observed(mut self, observance: ObservanceRule) -> Self {
+        self.observance = observance;
+        self
+    }
+}
+
+impl Calendar {
+    /// Adds the observed dates of `rules` for every year in `years`, named
+    /// after their rule.
+    ///
+    /// Set the calendar's weekend first: observance depends on it.  Within
+    /// each year, holidays that stay on their date are added first and the
+    /// moved ones after, in the order of `rules`, so a substitute day never
+    /// lands on another holiday of the same year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, Weekday};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::holidays::{HolidayRule, ObservanceRule};
+    ///
+    /// let mut cal = basic_calendar();
+    /// cal.add_holiday_rules(&[
+    ///     HolidayRule::fixed("Independence Day", 7, 4).observed(ObservanceRule::NearestWeekday),
+    ///     HolidayRule::nth_weekday("Thanksgiving Day", 11, Weekday::Thu, 4),
+    /// ], 2025..=2027);
+    ///
+    /// // 4 July 2026 is a Saturday, observed on Friday 3 July.
+    /// let friday = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+    /// assert_eq!(cal.holiday_name(&friday), Some("Independence Day"));
+    /// assert_eq!(cal.holidays_in_year(2026).len(), 2);
+    /// ```
+    pub fn add_holiday_rules(&mut self, rules: &[HolidayRule], years: RangeInclusive<i32>) {
+        for year in years {
+            let mut moved = Vec::new();
+            for rule in rules {
+                let Some(date) = rule.date.in_year(year) else {
+                    continue;
+                };
+                if rule.observance.observe(date, self) == Some(date) {
+                    self.add_named_holiday(date, rule.name.clone());
+                } else {
+                    moved.push((rule, date));
+                }
+            }
+            for (rule, date) in moved {
+                if let Some(observed) = rule.observance.observe(date, self) {
+                    self.add_named_holiday(observed, rule.name.clone());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! iCalendar (`.ics`) import and export for [`Calendar`].
+//!
+//! Only the parts of RFC 5545 that holiday feeds actually use are supported:
+//! `VEVENT` entries with a `DTSTART` date (and optional exclusive `DTEND`),
+//! `EXDATE` exclusions and yearly `RRULE` recurrences.  Everything else —
+//! times, time zones, alarms — is ignored.
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::calendar::Calendar;
+use crate::error::IcsError;
+
+/// Number of years an `RRULE` is expanded for, counting from its `DTSTART`.
+/// Rules whose `COUNT` or `UNTIL` reach further are cut off here.
+pub const ICS_RRULE_HORIZON_YEARS: i32 = 100;
+
+/// Maximum number of days a single event occurrence covers.  Days past this
+/// from `DTSTART` are ignored, however far away `DTEND` is.
+pub const ICS_MAX_EVENT_DAYS: u64 = 366;
+
+impl Calendar {
+    /// Parses the all-day events of an iCalendar document into a holiday
+    /// calendar.
+    ///
+    /// Every day covered by a `VEVENT` becomes a holiday: `DTSTART` through
+    /// the day before `DTEND` (or just `DTSTART` when there is no `DTEND`).
+    /// Date-time values are truncated to their date.  `RRULE:FREQ=YEARLY`
+    /// recurrences are expanded, honouring `INTERVAL`, `COUNT`, `UNTIL`,
+    /// `BYMONTH`, `BYMONTHDAY` and a single `BYDAY` with an optional ordinal
+    /// (e.g. `4TH` for Thanksgiving, `-1MO` for the last Monday).  Rules are
+    /// expanded for at most [`ICS_RRULE_HORIZON_YEARS`] years, and each
+    /// occurrence covers at most [`ICS_MAX_EVENT_DAYS`] days, so a far-away
+    /// `UNTIL` or `DTEND` cannot blow up the calendar.  `EXDATE` dates are
+    /// removed.  An
+    /// event's `SUMMARY` becomes the holiday name (see
+    /// [`Calendar::holiday_name`]).
+    ///
+    /// The returned calendar has no weekend days, like
+    /// [`Calendar::with_holidays`].  Requires the `ics` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IcsError`] naming the offending (unfolded) line when a
+    /// date cannot be parsed, an event has no `DTSTART`, or a recurrence rule
+    /// uses parts not listed above.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let ics = "BEGIN:VCALENDAR\r\n\
+    ///            BEGIN:VEVENT\r\n\
+    ///            DTSTART;VALUE=DATE:20231225\r\n\
+    ///            RRULE:FREQ=YEARLY;COUNT=3\r\n\
+    ///            SUMMARY:Christmas Day\r\n\
+    ///            END:VEVENT\r\n\
+    ///            END:VCALENDAR\r\n";
+    /// let cal = Calendar::from_ics(ics).unwrap();
+    ///
+    /// assert_eq!(cal.get_holidays().len(), 3);
+    /// let xmas = NaiveDate::from_ymd_opt(2025, 12, 25).unwrap();
+    /// assert_eq!(cal.holiday_name(&xmas), Some("Christmas Day"));
+    /// ```
+    pub fn from_ics(ics: &str) -> Result<Self, IcsError> {
+        let mut cal = Calendar::new();
+        let mut event: Option<Event> = None;
+
+        for (idx, line) in unfold(ics).iter().enumerate() {
+            let line_no = idx + 1;
+            let (name, value) = match split_property(line) {
+                Some(parts) => parts,
+                None => continue,
+            };
+            match (name.as_str(), value) {
+                ("BEGIN", v) if v.eq_ignore_ascii_case("VEVENT") => {
+                    event = Some(Event::default());
+                }
+                ("END", v) if v.eq_ignore_ascii_case("VEVENT") => {
+                    if let Some(ev) = event.take() {
+                        ev.expand(line_no, &mut cal)?;
+                    }
+                }
+                ("DTSTART", v) => {
+                    if let Some(ev) = event.as_mut() {
+                        ev.start = Some(parse_date(v, line_no)?);
+                    }
+                }
+                ("DTEND", v) => {
+                    if let Some(ev) = event.as_mut() {
+                        ev.end = Some(parse_date(v, line_no)?);
+                    }
+                }
+                ("RRULE", v) => {
+                    if let Some(ev) = event.as_mut() {
+                        ev.rule = Some(Rule::parse(v, line_no)?);
+                    }
+                }
+                ("SUMMARY", v) => {
+                    if let Some(ev) = event.as_mut() {
+                        ev.summary = Some(unescape_text(v));
+                    }
+                }
+                ("EXDATE", v) => {
+                    if let Some(ev) = event.as_mut() {
+                        for date in v.split(',') {
+                            ev.exdates.push(parse_date(date, line_no)?);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(cal)
+    }
+
+    /// Renders the holidays as an iCalendar document named `name`, with one
+    /// all-day `VEVENT` per holiday in ascending date order.  Holiday names
+    /// are written as the event `SUMMARY`.
+    ///
+    /// Weekend days are not exported.  Lines end in `CRLF` as required by
+    /// RFC 5545, and the output is deterministic: `DTSTAMP` is derived from
+    /// each holiday's date rather than the current time.  Requires the `ics`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let cal  = Calendar::with_holidays([xmas]);
+    ///
+    /// let ics = cal.to_ics("UK");
+    /// assert!(ics.contains("DTSTART;VALUE=DATE:20241225\r\n"));
+    /// assert_eq!(Calendar::from_ics(&ics).unwrap(), cal);
+    /// ```
+    pub fn to_ics(&self, name: &str) -> String {
+        let name = escape_text(name);
+
+        let mut out = String::new();
+        out.push_str("BEGIN:VCALENDAR\r\n");
+        out.push_str("VERSION:2.0\r\n");
+        out.push_str("PRODID:-//findates//findates//EN\r\n");
+        out.push_str(&format!("X-WR-CALNAME:{name}\r\n"));
+        for date in self.iter_holidays_sorted() {
+            let day = date.format("%Y%m%d");
+            let next = date.checked_add_days(Days::new(1)).unwrap_or(date);
+            out.push_str("BEGIN:VEVENT\r\n");
+            out.push_str(&format!("UID:{day}@findates\r\n"));
+            out.push_str(&format!("DTSTAMP:{day}T000000Z\r\n"));
+            out.push_str(&format!("DTSTART;VALUE=DATE:{day}\r\n"));
+            out.push_str(&format!("DTEND;VALUE=DATE:{}\r\n", next.format("%Y%m%d")));
+            if let Some(summary) = self.holiday_name(&date) {
+                out.push_str(&format!("SUMMARY:{}\r\n", escape_text(summary)));
+            }
+            out.push_str("TRANSP:TRANSPARENT\r\n");
+            out.push_str("END:VEVENT\r\n");
+        }
+        out.push_str("END:VCALENDAR\r\n");
+        out
+    }
+}
+
+#[derive(Default)]
+struct Event {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    rule: Option<Rule>,
+    exdates: Vec<NaiveDate>,
+    summary: Option<String>,
+}
+
+impl Event {
+    // Adds every day covered by the event and its recurrences to `cal`.
+    fn expand(self, line_no: usize, cal: &mut Calendar) -> Result<(), IcsError> {
+        let start = self.start.ok_or(IcsError::MissingStart { line: line_no })?;
+        // All-day DTEND is exclusive; a missing or non-increasing one means a
+        // single day.  Very long events are cut at ICS_MAX_EVENT_DAYS.
+        let span = self
+            .end
+            .map_or(1, |end| (end - start).num_days().max(1)) as u64;
+        let span = span.min(ICS_MAX_EVENT_DAYS);
+
+        let occurrences = match &self.rule {
+            Some(rule) => rule.occurrences(start),
+            None => vec![start],
+        };
+        for first in occurrences {
+            if self.exdates.contains(&first) {
+                continue;
+            }
+            for offset in 0..span {
+                let day = match first.checked_add_days(Days::new(offset)) {
+                    Some(day) => day,
+                    None => continue,
+                };
+                match &self.summary {
+                    Some(name) => cal.add_named_holiday(day, name.as_str()),
+                    None => cal.add_holidays([day]),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Rule {
+    interval: i32,
+    count: Option<usize>,
+    until: Option<NaiveDate>,
+    month: Option<u32>,
+    month_day: Option<u32>,
+    // (ordinal, weekday); an ordinal of 0 means "first on or after the
+    // month day", i.e. no ordinal was given.
+    by_day: Option<(i32, Weekday)>,
+}
+
+impl Rule {
+    fn parse(value: &str, line: usize) -> Result<Self, IcsError> {
+        let unsupported = || IcsError::UnsupportedRule { line };
+        let mut rule = Rule {
+            interval: 1,
+            count: None,
+            until: None,
+            month: None,
+            month_day: None,
+            by_day: None,
+        };
+        let mut yearly = false;
+        for part in value.split(';') {
+            let (key, val) = part.split_once('=').ok_or_else(unsupported)?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => yearly = val.eq_ignore_ascii_case("YEARLY"),
+                "INTERVAL" => {
+                    rule.interval = val.parse().ok().filter(|n| *n > 0).ok_or_else(unsupported)?;
+                }
+                "COUNT" => rule.count = Some(val.parse().map_err(|_| unsupported())?),
+                "UNTIL" => rule.until = Some(parse_date(val, line)?),
+                "BYMONTH" => {
+                    let month = val.parse().ok().filter(|m| (1..=12).contains(m));
+                    rule.month = Some(month.ok_or_else(unsupported)?);
+                }
+                "BYMONTHDAY" => {
+                    let day = val.parse().ok().filter(|d| (1..=31).contains(d));
+                    rule.month_day = Some(day.ok_or_else(unsupported)?);
+                }
+                "BYDAY" => rule.by_day = Some(parse_by_day(val).ok_or_else(unsupported)?),
+                "WKST" => {}
+                _ => return Err(unsupported()),
+            }
+        }
+        if yearly {
+            Ok(rule)
+        } else {
+            Err(unsupported())
+        }
+    }
+
+    // Occurrence dates, starting from DTSTART's year and stopping after
+    // ICS_RRULE_HORIZON_YEARS years whatever COUNT or UNTIL say.
+    fn occurrences(&self, start: NaiveDate) -> Vec<NaiveDate> {
+        let month = self.month.unwrap_or(start.month());
+        let horizon = start.year().saturating_add(ICS_RRULE_HORIZON_YEARS - 1);
+        let last_year = self.until.map_or(horizon, |until| until.year().min(horizon));
+        let mut dates = Vec::new();
+        let mut year = start.year();
+        while year <= last_year && self.count.map_or(true, |n| dates.len() < n) {
+            if let Some(date) = self.occurrence_in(year, month, start) {
+                if date >= start && self.until.map_or(true, |until| date <= until) {
+                    dates.push(date);
+                }
+            }
+            year = match year.checked_add(self.interval) {
+                Some(next) if NaiveDate::from_ymd_opt(next, 1, 1).is_some() => next,
+                _ => break,
+            };
+        }
+        dates
+    }
+
+    fn occurrence_in(&self, year: i32, month: u32, start: NaiveDate) -> Option<NaiveDate> {
+        match self.by_day {
+            Some((0, weekday)) => {
+                // Plain weekday: first one on or after the month day.
+                let from = NaiveDate::from_ymd_opt(year, month, self.month_day.unwrap_or(1))?;
+                let ahead = (7 + weekday.num_days_from_monday() as i64
+                    - from.weekday().num_days_from_monday() as i64)
+                    % 7;
+                from.checked_add_days(Days::new(ahead as u64))
+                    .filter(|d| d.month() == month)
+            }
+            Some((n, weekday)) if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, u8::try_from(n).ok()?)
+            }
+            Some((n, weekday)) => {
+                let last = crate::algebra::last_day_of_month(year, month)?;
+                let back = (7 + last.weekday().num_days_from_monday() as i64
+                    - weekday.num_days_from_monday() as i64)
+                    % 7
+                    + 7 * (-n as i64 - 1);
+                last.checked_sub_days(Days::new(back as u64))
+                    .filter(|d| d.month() == month)
+            }
+            None => NaiveDate::from_ymd_opt(year, month, self.month_day.unwrap_or(start.day())),
+        }
+    }
+}
+
+// Parses a single BYDAY value such as "MO", "4TH" or "-1MO".
+fn parse_by_day(value: &str) -> Option<(i32, Weekday)> {
+    if value.len() < 2 || value.contains(',') || !value.is_char_boundary(value.len() - 2) {
+        return None;
+    }
+    let (ordinal, day) = value.split_at(value.len() - 2);
+    let weekday = match day.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+    let ordinal = match ordinal.trim_start_matches('+') {
+        "" => 0,
+        n => n.parse().ok().filter(|n: &i32| (1..=5).contains(&n.abs()))?,
+    };
+    Some((ordinal, weekday))
+}
+
+// Parses a DATE or DATE-TIME value, keeping only the date.
+fn parse_date(value: &str, line: usize) -> Result<NaiveDate, IcsError> {
+    value
+        .get(..8)
+        .and_then(|digits| NaiveDate::parse_from_str(digits, "%Y%m%d").ok())
+        .ok_or(IcsError::InvalidDate { line })
+}
+
+// Splits "NAME;PARAM=X:VALUE" into the upper-cased name and the value.
+fn split_property(line: &str) -> Option<(String, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let name = head.split(';').next()?.trim().to_ascii_uppercase();
+    Some((name, value.trim()))
+}
+
+// Joins folded continuation lines (RFC 5545 §3.1).
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.lines() {
+        match (raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+// Reverses `escape_text`.
+fn unescape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n' | 'N')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some(next)) => {
+                out.push(next);
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Escapes TEXT values (RFC 5545 §3.3.11).
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,268 @@
+//! Approximate Islamic (Hijri) holidays from the tabular Islamic calendar.
+//!
+//! Requires the `islamic` feature.
+//!
+//! The religious Hijri calendar starts each month on the sighting of the new
+//! crescent moon, so the official dates of Eid al-Fitr and Eid al-Adha are
+//! only announced shortly beforehand and differ between countries.  This
+//! module uses the *tabular* (arithmetical) Islamic calendar instead: months
+//! alternate between 30 and 29 days and 11 years of every 30-year cycle are
+//! leap years (the common "type II" pattern, civil epoch 16 July 622 Julian).
+//!
+//! **The dates produced here are approximations.**  They typically match the
+//! observed holiday or fall one day away from it, and occasionally two.  Use
+//! them to pre-build GCC market calendars for planning and long-dated
+//! schedules, and replace them with the exchange's published dates once those
+//! are announced.
+
+use chrono::{Datelike, NaiveDate};
+use std::ops::RangeInclusive;
+
+use crate::calendar::Calendar;
+
+// Day number (from 1 January of year 1 CE) of 1 Muharram 1 AH, i.e.
+// 16 July 622 in the Julian calendar.
+const EPOCH_DAYS_FROM_CE: i64 = 227_015;
+
+/// A date in the tabular Islamic calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::islamic::HijriDate;
+///
+/// // 1 Shawwal 1445 — Eid al-Fitr.
+/// let eid = HijriDate::new(1445, 10, 1).unwrap();
+/// assert_eq!(eid.to_gregorian(), NaiveDate::from_ymd_opt(2024, 4, 10));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+pub struct HijriDate {
+    /// Hijri year (AH).
+    pub year: i32,
+    /// Month, 1 (Muharram) to 12 (Dhu al-Hijjah).
+    pub month: u32,
+    /// Day of the month, 1 to 30.
+    pub day: u32,
+}
+
+impl HijriDate {
+    /// Creates a tabular Hijri date, or `None` if the month or day does not
+    /// exist in that year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::islamic::HijriDate;
+    ///
+    /// assert!(HijriDate::new(1446, 12, 29).is_some());
+    /// assert!(HijriDate::new(1446, 12, 30).is_none()); // 1446 is not a leap year
+    /// assert!(HijriDate::new(1446, 13, 1).is_none());
+    /// ```
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let len = days_in_month(year, month)?;
+        (1..=len).contains(&day).then_some(Self { year, month, day })
+    }
+
+    /// Converts a Gregorian date to the tabular Hijri calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::islamic::HijriDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 6, 17).unwrap();
+    /// assert_eq!(HijriDate::from_gregorian(&date), HijriDate::new(1445, 12, 10).unwrap());
+    /// ```
+    pub fn from_gregorian(date: &NaiveDate) -> Self {
+        let days = date.num_days_from_ce() as i64 - EPOCH_DAYS_FROM_CE;
+        let year = (30 * days + 10_646).div_euclid(10_631) as i32;
+        // Months are 29.5 days on average; the ceiling picks the month whose
+        // first day is on or before `date`.
+        let into_year = days - (days_before(year, 1) + 29);
+        let month = (-(-2 * into_year).div_euclid(59) + 1).clamp(1, 12) as u32;
+        let day = (days - days_before(year, month) + 1) as u32;
+        Self { year, month, day }
+    }
+
+    /// Converts this date to the Gregorian calendar, or returns `None` if it
+    /// falls outside `NaiveDate`'s range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::islamic::HijriDate;
+    ///
+    /// let new_year = HijriDate::new(1446, 1, 1).unwrap();
+    /// assert_eq!(new_year.to_gregorian(), NaiveDate::from_ymd_opt(2024, 7, 8));
+    /// ```
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        let days = days_before(self.year, self.month) + self.day as i64 - 1;
+        let from_ce = i32::try_from(EPOCH_DAYS_FROM_CE + days).ok()?;
+        NaiveDate::from_num_days_from_ce_opt(from_ce)
+    }
+}
+
+/// Returns `true` if `year` (AH) is a leap year of the tabular Islamic
+/// calendar, i.e. Dhu al-Hijjah has 30 days instead of 29.
+///
+/// Years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of each 30-year cycle are
+/// leap years.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::islamic::is_leap_year;
+///
+/// assert!(is_leap_year(1447));
+/// assert!(!is_leap_year(1446));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year as i64).rem_euclid(30) < 11
+}
+
+/// Returns the number of days in a tabular Hijri month, or `None` if `month`
+/// is not in `1..=12`.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::islamic::days_in_month;
+///
+/// assert_eq!(days_in_month(1445, 9), Some(30));  // Ramadan
+/// assert_eq!(days_in_month(1446, 12), Some(29));
+/// assert_eq!(days_in_month(1447, 12), Some(30)); // leap year
+/// assert_eq!(days_in_month(1445, 0), None);
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        12 if is_leap_year(year) => Some(30),
+        1..=12 if month % 2 == 1 => Some(30),
+        1..=12 => Some(29),
+        _ => None,
+    }
+}
+
+/// Returns every Gregorian date in `year` that falls on the given tabular
+/// Hijri month and day, in ascending order.
+///
+/// A Hijri year is about 11 days shorter than a Gregorian one, so a Hijri
+/// date usually occurs once in a Gregorian year and occasionally twice (or
+/// not at all, for the 30th of a month).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::islamic::gregorian_dates_in_year;
+///
+/// // 1 Shawwal fell twice in 2000.
+/// assert_eq!(gregorian_dates_in_year(2000, 10, 1), vec![
+///     NaiveDate::from_ymd_opt(2000, 1, 8).unwrap(),
+///     NaiveDate::from_ymd_opt(2000, 12, 28).unwrap(),
+/// ]);
+/// ```
+pub fn gregorian_dates_in_year(year: i32, month: u32, day: u32) -> Vec<NaiveDate> {
+    let (Some(first), Some(last)) = (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) else {
+        return Vec::new();
+    };
+    let first_year = HijriDate::from_gregorian(&first).year;
+    let last_year = HijriDate::from_gregorian(&last).year;
+    (first_year..=last_year)
+        .filter_map(|hijri_year| HijriDate::new(hijri_year, month, day)?.to_gregorian())
+        .filter(|date| date.year() == year)
+        .collect()
+}
+
+/// Returns the approximate first day of Eid al-Fitr (1 Shawwal) in the
+/// Gregorian `year`.
+///
+/// The result is usually one date, occasionally two.  See the
+/// [module documentation](self) for how approximate it is.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::islamic::eid_al_fitr;
+///
+/// // Observed on 10 April 2024 in most GCC countries.
+/// assert_eq!(eid_al_fitr(2024), vec![NaiveDate::from_ymd_opt(2024, 4, 10).unwrap()]);
+/// ```
+pub fn eid_al_fitr(year: i32) -> Vec<NaiveDate> {
+    gregorian_dates_in_year(year, 10, 1)
+}
+
+/// Returns the approximate first day of Eid al-Adha (10 Dhu al-Hijjah) in the
+/// Gregorian `year`.
+///
+/// The result is usually one date, occasionally two.  See the
+/// [module documentation](self) for how approximate it is.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::islamic::eid_al_adha;
+///
+/// // Observed on 16 June 2024 in most GCC countries; the tabular date is a day later.
+/// assert_eq!(eid_al_adha(2024), vec![NaiveDate::from_ymd_opt(2024, 6, 17).unwrap()]);
+/// ```
+pub fn eid_al_adha(year: i32) -> Vec<NaiveDate> {
+    gregorian_dates_in_year(year, 12, 10)
+}
+
+/// Builds a holiday calendar with approximate Eid al-Fitr and Eid al-Adha
+/// closures for every Gregorian year in `years`.
+///
+/// Each Eid starts on the date given by [`eid_al_fitr`] or [`eid_al_adha`] and
+/// lasts `fitr_days` or `adha_days` consecutive calendar days; GCC exchanges
+/// typically close for three to five.  Holidays are named `"Eid al-Fitr"` and
+/// `"Eid al-Adha"`.  Like [`Calendar::with_holidays`], the result has no
+/// weekend days; combine it with a weekend calendar and the market's fixed
+/// holidays via [`Calendar::union`].
+///
+/// The calendar's [valid range](Calendar::set_valid_range) is set to the
+/// years requested, so using it outside them is detectable.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::Calendar;
+/// use findates::islamic::eid_holidays;
+///
+/// let mut cal = Calendar::middle_east_weekend();
+/// cal.union(&eid_holidays(2024..=2026, 3, 4));
+///
+/// let eid = NaiveDate::from_ymd_opt(2024, 4, 11).unwrap();
+/// assert!(!cal.is_business_day(&eid));
+/// assert_eq!(cal.holiday_name(&eid), Some("Eid al-Fitr"));
+/// assert_eq!(cal.valid_range().unwrap().1, NaiveDate::from_ymd_opt(2026, 12, 31).unwrap());
+/// ```
+pub fn eid_holidays(years: RangeInclusive<i32>, fitr_days: u32, adha_days: u32) -> Calendar {
+    let mut cal = Calendar::new();
+    let (first, last) = (*years.start(), *years.end());
+    for year in years {
+        for (starts, days, name) in [(eid_al_fitr(year), fitr_days, "Eid al-Fitr"), (eid_al_adha(year), adha_days, "Eid al-Adha")] {
+            for start in starts {
+                for date in start.iter_days().take(days as usize) {
+                    cal.add_named_holiday(date, name);
+                }
+            }
+        }
+    }
+    if let (Some(start), Some(end)) = (NaiveDate::from_ymd_opt(first, 1, 1), NaiveDate::from_ymd_opt(last, 12, 31)) {
+        // Only fails for an empty range, which leaves the calendar unbounded.
+        let _ = cal.set_valid_range(start, end);
+    }
+    cal
+}
+
+// Days from the epoch to the first day of `month` in `year`.
+fn days_before(year: i32, month: u32) -> i64 {
+    let year = year as i64;
+    (59 * (month as i64 - 1) + 1) / 2 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
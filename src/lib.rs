@@ -11,29 +11,84 @@
 //! ## Modules
 //!
 //! - [`calendar`] — [`Calendar`](calendar::Calendar) struct: weekends and holiday sets, set operations
-//! - [`conventions`] — [`DayCount`](conventions::DayCount), [`AdjustRule`](conventions::AdjustRule), [`Frequency`](conventions::Frequency) enums
+//! - [`conventions`] — [`DayCount`](conventions::DayCount), [`AdjustRule`](conventions::AdjustRule), [`Frequency`](conventions::Frequency) enums, the [`Tenor`](conventions::Tenor) period type, [`RollConvention`](conventions::RollConvention), [`StubRule`](conventions::StubRule), [`ExpiryRule`](conventions::ExpiryRule), [`ExpiryStyle`](conventions::ExpiryStyle) and [`Rounding`](conventions::Rounding)
 //! - [`algebra`] — core functions: business day checks, adjustment, day count fractions, schedule counting
-//! - [`schedule`] — [`Schedule`](schedule::Schedule) and lazy [`ScheduleIterator`](schedule::ScheduleIterator)
-//! - [`error`] — [`DayCountError`], [`BusinessDayError`] returned by fallible functions
+//! - [`schedule`] — [`Schedule`](schedule::Schedule), its validating [`ScheduleBuilder`](schedule::ScheduleBuilder) and lazy [`ScheduleIterator`](schedule::ScheduleIterator)
+//! - [`holidays`] — rule-based holiday definitions ([`HolidayRule`](holidays::HolidayRule)) and weekend [`ObservanceRule`](holidays::ObservanceRule)s
+//! - [`markets`] — built-in market holiday calendars such as [`Calendar::brazil_b3`](calendar::Calendar::brazil_b3)
+//! - [`registry`] — [`CalendarRegistry`](registry::CalendarRegistry): calendars by code and composite expressions such as `"NYC|LON"`
+//! - [`sessions`] — [`MarketHours`](sessions::MarketHours) and [`TradingSession`](sessions::TradingSession): intraday open/close checks and business minutes
+//! - [`lunar`] — Chinese lunisolar calendar conversion and lunar festival holidays
+//! - [`cds`] — standard CDS roll dates (20 Mar/Jun/Sep/Dec) and maturities
+//! - [`fiscal`] — [`FiscalYear`](fiscal::FiscalYear): fiscal years with a configurable start, their quarters and period ends
+//! - [`compiled`] — [`CompiledCalendar`](compiled::CompiledCalendar): O(1) business day queries over a fixed range
+//! - [`tracked`] — [`TrackedCalendar`](tracked::TrackedCalendar): holiday additions and removals recorded with timestamps and sources, with replay and rollback
+//! - `ics` — iCalendar import and export for calendars (requires the `ics` feature)
+//! - `islamic` — approximate Eid holidays from the tabular Hijri calendar (requires the `islamic` feature)
+//! - `holiday_api` — calendars from public holiday APIs such as Nager.Date (requires the `http` feature)
+//! - `zoned` — `DateTime` versions of adjustment and local fixing times for schedule dates (requires the `tz` feature)
+//! - `parallel` — rayon-based batch adjustment, holiday expansion and schedule generation (requires the `parallel` feature)
+//! - `decimal` — day count fractions as exact `rust_decimal::Decimal`s (requires the `decimal` feature)
+//! - `arbitrary` — proptest strategies and `Arbitrary` implementations for calendars, conventions and schedules (requires the `proptest` feature)
+//! - [`error`] — [`DayCountError`], [`BusinessDayError`], [`ScheduleError`], [`ScheduleBuildError`], [`CalendarError`], [`CalendarIoError`], [`DateError`], [`FuturesExpiryError`], [`ForwardDateError`] returned by fallible functions
 //!
 //! ## Features
 //!
 //! - **`serde`** *(optional)* — derives `Serialize` and `Deserialize` for
 //!   [`DayCount`](conventions::DayCount), [`AdjustRule`](conventions::AdjustRule),
-//!   [`Frequency`](conventions::Frequency), and [`Calendar`](calendar::Calendar).
+//!   [`Frequency`](conventions::Frequency), [`Tenor`](conventions::Tenor),
+//!   [`RollConvention`](conventions::RollConvention),
+//!   [`Calendar`](calendar::Calendar),
+//!   [`ScheduleOwned`](schedule::ScheduleOwned),
+//!   [`CouponPeriod`](schedule::CouponPeriod) and
+//!   [`CalendarChange`](tracked::CalendarChange); [`Schedule`](schedule::Schedule)
+//!   is `Serialize` only, since it borrows its calendar.  Day counts,
+//!   adjustment rules and frequencies are written as market strings
+//!   (`"ACT/360"`, `"MODFOLLOWING"`, `"6M"`) and read from any string their
+//!   `FromStr` accepts, including the variant names.
 //!   Enable in `Cargo.toml`:
 //!   ```toml
 //!   [dependencies]
 //!   findates = { version = "0.1", features = ["serde"] }
 //!   ```
+//! - **`json`** *(optional)* — implies `serde` and adds
+//!   [`Calendar::from_json_reader`](calendar::Calendar::from_json_reader) and
+//!   [`Calendar::to_json_writer`](calendar::Calendar::to_json_writer).
+//! - **`ics`** *(optional)* — adds `Calendar::from_ics` and
+//!   `Calendar::to_ics` for iCalendar holiday feeds (see the `ics` module).
+//! - **`islamic`** *(optional)* — adds the `islamic` module, which generates
+//!   approximate Eid al-Fitr and Eid al-Adha dates from the tabular Islamic
+//!   calendar for assembling GCC market calendars.
+//! - **`parallel`** *(optional)* — adds the `parallel` module with
+//!   [rayon](https://docs.rs/rayon)-based versions of batch adjustment,
+//!   holiday expansion and schedule generation for large date sets.
+//! - **`tz`** *(optional)* — adds the `zoned` module, with time-zone-aware
+//!   adjustment and local fixing times (e.g. 11:00 London) for schedule
+//!   dates, using [chrono-tz](https://docs.rs/chrono-tz) time zones.
+//! - **`http`** *(optional)* — implies `json` and adds the `holiday_api`
+//!   module with `Calendar::fetch_public_holidays`, which loads national
+//!   holidays from a public holiday API over HTTP.
+//! - **`proptest`** *(optional)* — adds the `arbitrary` module, implementing
+//!   [proptest](https://docs.rs/proptest)'s `Arbitrary` for calendars,
+//!   conventions and [`ScheduleOwned`](schedule::ScheduleOwned) so you can
+//!   property-test your own code over random but valid inputs.
+//! - **`decimal`** *(optional)* — adds the `decimal` module, with day count
+//!   fractions computed in [rust_decimal](https://docs.rs/rust_decimal)
+//!   `Decimal` arithmetic instead of `f64`, for accounting and settlement
+//!   systems that must not drift.
+//! - **`cli`** *(optional)* — implies `json` and builds the `findates`
+//!   binary, with `adjust`, `dcf`, `schedule`, `busdays` and `holidays`
+//!   subcommands over calendars loaded from JSON or CSV files or the
+//!   built-in markets.  Install with `cargo install findates --features cli`.
 //!
 //! ## Date Types
 //!
 //! findates uses [`chrono::NaiveDate`] as its date representation throughout.
 //! All public functions accept and return `NaiveDate` (aliased as
-//! [`FinDate`] for convenience).  Timezone-aware dates are not supported —
-//! financial date arithmetic operates on calendar dates without reference
-//! to time of day or timezone.
+//! [`FinDate`] for convenience).  Financial date arithmetic operates on
+//! calendar dates without reference to time of day or timezone; only the
+//! [`sessions`] module, which deals with intraday market hours, and the
+//! `zoned` module (`tz` feature) take `DateTime` values.
 //!
 //! If your codebase uses the [`time`](https://docs.rs/time) crate, you will
 //! need to convert to `NaiveDate` at the boundary.  Broader date type
@@ -64,21 +119,54 @@
 //! assert_eq!(dates.len(), 3); // 2023-01-01, 2023-07-01, 2024-01-01
 //!
 //! // Act/365 over 365 days = exactly 1.0
-//! let dcf = algebra::day_count_fraction(
+//! let dcf = algebra::try_day_count_fraction(
 //!     &anchor, &end, DayCount::Act365, None, None,
 //! ).unwrap();
 //! assert!((dcf - 1.0).abs() < 1e-9);
 //! ```
 
 pub mod algebra;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 pub mod calendar;
+pub mod cds;
+pub mod compiled;
 pub mod conventions;
-pub(crate) mod date;
+#[cfg(feature = "decimal")]
+pub mod decimal;
 pub mod error;
+pub mod fiscal;
+pub mod holidays;
+#[cfg(feature = "http")]
+pub mod holiday_api;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "ics")]
+pub mod ics;
+#[cfg(feature = "islamic")]
+pub mod islamic;
+pub mod lunar;
+pub mod markets;
+pub mod registry;
 pub mod schedule;
+pub mod sessions;
+pub mod tracked;
+#[cfg(feature = "tz")]
+pub mod zoned;
 
 pub use error::BusinessDayError;
+pub use error::CalendarError;
+pub use error::CalendarIoError;
+pub use error::DateError;
 pub use error::DayCountError;
+pub use error::ForwardDateError;
+pub use error::FuturesExpiryError;
+#[cfg(feature = "http")]
+pub use error::HolidayApiError;
+#[cfg(feature = "ics")]
+pub use error::IcsError;
+pub use error::ScheduleBuildError;
+pub use error::ScheduleError;
 
 /// Type alias for the date type used throughout the library.
 pub type FinDate = chrono::NaiveDate;
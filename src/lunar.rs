@@ -0,0 +1,564 @@
+//! Chinese lunisolar calendar conversion and lunar festival holidays.
+//!
+//! The Chinese calendar starts each month on the day (Beijing time) of an
+//! astronomical new moon, and inserts a leap month in years with 13 new moons
+//! between two winter solstices — the first month of such a year that
+//! contains no principal solar term repeats the number of the month before
+//! it.  This module computes new moons and solar terms with the series from
+//! Jean Meeus, *Astronomical Algorithms* (2nd ed., chapters 25 and 49), which
+//! is accurate to well under a minute for new moons and about a quarter of an
+//! hour for solar terms — enough to reproduce the published calendar for
+//! [`SUPPORTED_YEARS`].
+//!
+//! On top of the conversion, [`LunarFestival`] gives the dates of Chinese New
+//! Year, the Dragon Boat Festival and the Mid-Autumn Festival, and
+//! [`festival_holidays`] turns them into a holiday calendar for markets that
+//! observe them (CNY, HKD, TWD, KRW, ...).
+
+use chrono::{Datelike, Days, NaiveDate};
+use std::ops::RangeInclusive;
+
+use crate::calendar::Calendar;
+
+/// Lunar years (numbered by the Gregorian year they start in) that this
+/// module converts.  Functions return `None` outside this range.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::lunar::{lunar_new_year, SUPPORTED_YEARS};
+///
+/// assert!(SUPPORTED_YEARS.contains(&2024));
+/// assert_eq!(lunar_new_year(1850), None);
+/// ```
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = 1901..=2100;
+
+/// A date in the Chinese lunisolar calendar.
+///
+/// `year` is the Gregorian year in which the lunar year starts, so the last
+/// days of lunar year 2023 fall in January or February 2024.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::lunar::LunarDate;
+///
+/// let new_year = LunarDate::new(2024, 1, false, 1).unwrap();
+/// assert_eq!(new_year.to_gregorian(), NaiveDate::from_ymd_opt(2024, 2, 10));
+///
+/// // 2023 had a leap second month.
+/// let leap = LunarDate::new(2023, 2, true, 1).unwrap();
+/// assert_eq!(leap.to_gregorian(), NaiveDate::from_ymd_opt(2023, 3, 22));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct LunarDate {
+    /// Lunar year, numbered by the Gregorian year it starts in.
+    pub year: i32,
+    /// Month number, 1 to 12.
+    pub month: u32,
+    /// `true` for the leap (intercalary) month that follows month `month`.
+    pub leap: bool,
+    /// Day of the month, 1 to 30.
+    pub day: u32,
+}
+
+impl LunarDate {
+    /// Creates a lunar date, or `None` if the month or day does not exist in
+    /// that year or the year is outside [`SUPPORTED_YEARS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::lunar::LunarDate;
+    ///
+    /// assert!(LunarDate::new(2024, 8, false, 15).is_some());
+    /// assert!(LunarDate::new(2024, 2, true, 1).is_none()); // no leap second month in 2024
+    /// assert!(LunarDate::new(2024, 13, false, 1).is_none());
+    /// ```
+    pub fn new(year: i32, month: u32, leap: bool, day: u32) -> Option<Self> {
+        let month = find_month(year, month, leap)?;
+        (1..=month.len).contains(&day).then_some(Self {
+            year,
+            month: month.number,
+            leap,
+            day,
+        })
+    }
+
+    /// Converts a Gregorian date to the lunar calendar, or returns `None` if
+    /// it falls outside [`SUPPORTED_YEARS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::lunar::LunarDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 9, 17).unwrap();
+    /// assert_eq!(LunarDate::from_gregorian(&date), LunarDate::new(2024, 8, false, 15));
+    ///
+    /// // Late January 2024 is still in lunar year 2023.
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// assert_eq!(LunarDate::from_gregorian(&date), LunarDate::new(2023, 12, false, 21));
+    /// ```
+    pub fn from_gregorian(date: &NaiveDate) -> Option<Self> {
+        let mut sui_year = date.year();
+        let mut months = months_of_sui(sui_year)?;
+        if *date < months[0].start {
+            sui_year -= 1;
+            months = months_of_sui(sui_year)?;
+        } else if *date >= months[months.len() - 1].end() {
+            sui_year += 1;
+            months = months_of_sui(sui_year)?;
+        }
+        let index = months.iter().rposition(|month| month.start <= *date)?;
+        let month = &months[index];
+        // Months 11 and 12 before the first month belong to the previous year.
+        let first = months.iter().position(|month| month.number == 1 && !month.leap)?;
+        let year = if index < first { sui_year - 1 } else { sui_year };
+        if !SUPPORTED_YEARS.contains(&year) {
+            return None;
+        }
+        Some(Self {
+            year,
+            month: month.number,
+            leap: month.leap,
+            day: (*date - month.start).num_days() as u32 + 1,
+        })
+    }
+
+    /// Converts this date to the Gregorian calendar, or returns `None` if it
+    /// does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::lunar::LunarDate;
+    ///
+    /// let dragon_boat = LunarDate { year: 2024, month: 5, leap: false, day: 5 };
+    /// assert_eq!(dragon_boat.to_gregorian(), NaiveDate::from_ymd_opt(2024, 6, 10));
+    /// ```
+    pub fn to_gregorian(&self) -> Option<NaiveDate> {
+        let month = find_month(self.year, self.month, self.leap)?;
+        if !(1..=month.len).contains(&self.day) {
+            return None;
+        }
+        month.start.checked_add_days(Days::new(self.day as u64 - 1))
+    }
+}
+
+/// Returns the leap month of lunar `year` — the month number it repeats —
+/// or `None` if the year has no leap month or is outside
+/// [`SUPPORTED_YEARS`].
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::lunar::leap_month;
+///
+/// assert_eq!(leap_month(2023), Some(2));
+/// assert_eq!(leap_month(2024), None);
+/// assert_eq!(leap_month(2025), Some(6));
+/// ```
+pub fn leap_month(year: i32) -> Option<u32> {
+    if !SUPPORTED_YEARS.contains(&year) {
+        return None;
+    }
+    // A leap month after month 10 falls in the next solstice-to-solstice year.
+    let leap = months_of_sui(year)?
+        .into_iter()
+        .chain(months_of_sui(year + 1)?)
+        .skip_while(|month| month.number != 1 || month.leap)
+        .take_while(|month| !(month.number == 1 && !month.leap && month.start.year() > year))
+        .find(|month| month.leap)?;
+    Some(leap.number)
+}
+
+/// Returns the first day of lunar `year` (Chinese New Year), or `None`
+/// outside [`SUPPORTED_YEARS`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::lunar::lunar_new_year;
+///
+/// assert_eq!(lunar_new_year(2025), NaiveDate::from_ymd_opt(2025, 1, 29));
+/// ```
+pub fn lunar_new_year(year: i32) -> Option<NaiveDate> {
+    LunarFestival::NewYear.date(year)
+}
+
+/// A festival of the Chinese lunar calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::lunar::LunarFestival;
+///
+/// assert_eq!(LunarFestival::MidAutumn.date(2025), NaiveDate::from_ymd_opt(2025, 10, 6));
+/// assert_eq!(LunarFestival::MidAutumn.name(), "Mid-Autumn Festival");
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LunarFestival {
+    /// Chinese New Year (Spring Festival, Seollal, Tết): 1st day of the 1st month.
+    NewYear,
+    /// Dragon Boat Festival (Tuen Ng, Duanwu): 5th day of the 5th month.
+    DragonBoat,
+    /// Mid-Autumn Festival (Chuseok): 15th day of the 8th month.
+    MidAutumn,
+}
+
+impl LunarFestival {
+    /// Returns the festival's date in lunar `year`, or `None` outside
+    /// [`SUPPORTED_YEARS`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::lunar::LunarFestival;
+    ///
+    /// assert_eq!(LunarFestival::DragonBoat.date(2025), NaiveDate::from_ymd_opt(2025, 5, 31));
+    /// ```
+    pub fn date(self, year: i32) -> Option<NaiveDate> {
+        let (month, day) = match self {
+            LunarFestival::NewYear => (1, 1),
+            LunarFestival::DragonBoat => (5, 5),
+            LunarFestival::MidAutumn => (8, 15),
+        };
+        LunarDate { year, month, leap: false, day }.to_gregorian()
+    }
+
+    /// Returns the festival's English name, used to label generated holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::lunar::LunarFestival;
+    ///
+    /// assert_eq!(LunarFestival::NewYear.name(), "Lunar New Year");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            LunarFestival::NewYear => "Lunar New Year",
+            LunarFestival::DragonBoat => "Dragon Boat Festival",
+            LunarFestival::MidAutumn => "Mid-Autumn Festival",
+        }
+    }
+}
+
+/// A market closure tied to a [`LunarFestival`]: `days` consecutive calendar
+/// days starting `offset` days from the festival.
+///
+/// # Examples
+///
+/// ```rust
+/// use findates::lunar::{FestivalClosure, LunarFestival};
+///
+/// // Korea closes the day before, the day of and the day after Chuseok.
+/// let chuseok = FestivalClosure::new(LunarFestival::MidAutumn, -1, 3);
+/// assert_eq!(chuseok.offset, -1);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FestivalClosure {
+    /// The festival the closure is anchored to.
+    pub festival: LunarFestival,
+    /// Calendar days from the festival to the first closed day.
+    pub offset: i64,
+    /// Number of consecutive closed calendar days.
+    pub days: u32,
+}
+
+impl FestivalClosure {
+    /// Creates a closure of `days` calendar days starting `offset` days from
+    /// `festival`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::lunar::{FestivalClosure, LunarFestival};
+    ///
+    /// // Hong Kong closes on the day following the Mid-Autumn Festival.
+    /// let closure = FestivalClosure::new(LunarFestival::MidAutumn, 1, 1);
+    /// assert_eq!(closure.days, 1);
+    /// ```
+    pub fn new(festival: LunarFestival, offset: i64, days: u32) -> Self {
+        Self { festival, offset, days }
+    }
+}
+
+/// Builds a holiday calendar with the given festival closures for every
+/// lunar year in `years`.
+///
+/// Holidays are named after their festival (see [`LunarFestival::name`]).
+/// Weekend substitution rules differ between markets and are not applied;
+/// like [`Calendar::with_holidays`], the result has no weekend days.  Combine
+/// it with a weekend calendar and the market's solar holidays via
+/// [`Calendar::union`].
+///
+/// Years outside [`SUPPORTED_YEARS`] are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::lunar::{festival_holidays, FestivalClosure, LunarFestival};
+///
+/// // HKEX: the first three days of the New Year, Tuen Ng and the day
+/// // following the Mid-Autumn Festival.
+/// let hk_lunar = festival_holidays(2024..=2026, &[
+///     FestivalClosure::new(LunarFestival::NewYear, 0, 3),
+///     FestivalClosure::new(LunarFestival::DragonBoat, 0, 1),
+///     FestivalClosure::new(LunarFestival::MidAutumn, 1, 1),
+/// ]);
+/// let mut cal = basic_calendar();
+/// cal.union(&hk_lunar);
+///
+/// let day = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+/// assert!(!cal.is_business_day(&day));
+/// assert_eq!(cal.holiday_name(&day), Some("Lunar New Year"));
+/// ```
+pub fn festival_holidays(years: RangeInclusive<i32>, closures: &[FestivalClosure]) -> Calendar {
+    let mut cal = Calendar::new();
+    for year in years {
+        for closure in closures {
+            let Some(start) = closure.festival.date(year).and_then(|date| offset_date(date, closure.offset)) else {
+                continue;
+            };
+            for date in start.iter_days().take(closure.days as usize) {
+                cal.add_named_holiday(date, closure.festival.name());
+            }
+        }
+    }
+    cal
+}
+
+fn offset_date(date: NaiveDate, offset: i64) -> Option<NaiveDate> {
+    if offset < 0 {
+        date.checked_sub_days(Days::new(offset.unsigned_abs()))
+    } else {
+        date.checked_add_days(Days::new(offset as u64))
+    }
+}
+
+// ── Calendar construction ────────────────────────────────────────────────────
+
+// One month of a solstice-to-solstice year.
+#[derive(Clone, Copy, Debug)]
+struct LunarMonth {
+    number: u32,
+    leap: bool,
+    start: NaiveDate,
+    len: u32,
+}
+
+impl LunarMonth {
+    fn end(&self) -> NaiveDate {
+        self.start + Days::new(self.len as u64)
+    }
+}
+
+fn find_month(year: i32, number: u32, leap: bool) -> Option<LunarMonth> {
+    if !SUPPORTED_YEARS.contains(&year) || !(1..=12).contains(&number) {
+        return None;
+    }
+    // Months 1 to 10 lie in the solstice year ending in `year`; months 11 and
+    // 12 usually do too, but move to the next one in a year with a leap month.
+    months_of_sui(year)?
+        .into_iter()
+        .chain(months_of_sui(year + 1)?)
+        .skip_while(|month| month.number != 1 || month.leap)
+        .find(|month| month.number == number && month.leap == leap)
+}
+
+// The months from the one containing the winter solstice of `year - 1` up to,
+// but excluding, the one containing the winter solstice of `year`.  The first
+// month is always month 11.
+fn months_of_sui(year: i32) -> Option<Vec<LunarMonth>> {
+    if !(SUPPORTED_YEARS.start() - 1..=SUPPORTED_YEARS.end() + 1).contains(&year) {
+        return None;
+    }
+    let solstice = solar_term(approx_solstice(year - 1), 270.0);
+    let first = new_moon_on_or_before(beijing_date(solstice)?)?;
+    let last = new_moon_on_or_before(beijing_date(solar_term(approx_solstice(year), 270.0))?)?;
+    let starts = (first..=last)
+        .map(|k| beijing_date(new_moon(k)))
+        .collect::<Option<Vec<_>>>()?;
+    // Dates of the 12 principal terms from the first solstice onwards.
+    let terms = (0..12)
+        .map(|i| beijing_date(solar_term(solstice + 30.44 * i as f64, (270.0 + 30.0 * i as f64) % 360.0)))
+        .collect::<Option<Vec<_>>>()?;
+
+    let has_leap = starts.len() == 14;
+    let mut leap_found = false;
+    let mut number = 11;
+    let mut months = Vec::with_capacity(starts.len() - 1);
+    for (i, window) in starts.windows(2).enumerate() {
+        let leap = has_leap
+            && !leap_found
+            && !terms.iter().any(|term| window[0] <= *term && *term < window[1]);
+        leap_found |= leap;
+        if i > 0 && !leap {
+            number = number % 12 + 1;
+        }
+        months.push(LunarMonth {
+            number,
+            leap,
+            start: window[0],
+            len: (window[1] - window[0]).num_days() as u32,
+        });
+    }
+    Some(months)
+}
+
+// Index `k` of the last new moon falling on or before `date`.
+fn new_moon_on_or_before(date: NaiveDate) -> Option<i64> {
+    let days_since_2000 = (date - NaiveDate::from_ymd_opt(2000, 1, 6)?).num_days();
+    let mut k = (days_since_2000 as f64 / SYNODIC_MONTH).floor() as i64 + 1;
+    while beijing_date(new_moon(k))? > date {
+        k -= 1;
+    }
+    while beijing_date(new_moon(k + 1))? <= date {
+        k += 1;
+    }
+    Some(k)
+}
+
+// ── Astronomy ────────────────────────────────────────────────────────────────
+
+const SYNODIC_MONTH: f64 = 29.530_588_861;
+const TROPICAL_YEAR: f64 = 365.242_2;
+const J2000: f64 = 2_451_545.0;
+
+fn sin_deg(degrees: f64) -> f64 {
+    degrees.to_radians().sin()
+}
+
+// Julian Ephemeris Day of the `k`-th new moon after 6 January 2000
+// (Meeus, chapter 49).
+fn new_moon(k: i64) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+    let jde = 2_451_550.097_66 + SYNODIC_MONTH * k + 0.000_154_37 * t2 - 0.000_000_150 * t3 + 0.000_000_000_73 * t4;
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t2;
+    let m = 2.5534 + 29.105_356_70 * k - 0.000_001_4 * t2 - 0.000_000_11 * t3;
+    let mp = 201.5643 + 385.816_935_28 * k + 0.010_758_2 * t2 + 0.000_012_38 * t3 - 0.000_000_058 * t4;
+    let f = 160.7108 + 390.670_502_84 * k - 0.001_611_8 * t2 - 0.000_002_27 * t3 + 0.000_000_011 * t4;
+    let omega = 124.7746 - 1.563_755_88 * k + 0.002_067_2 * t2 + 0.000_002_15 * t3;
+
+    let periodic = -0.40720 * sin_deg(mp) + 0.17241 * e * sin_deg(m) + 0.01608 * sin_deg(2.0 * mp)
+        + 0.01039 * sin_deg(2.0 * f)
+        + 0.00739 * e * sin_deg(mp - m)
+        - 0.00514 * e * sin_deg(mp + m)
+        + 0.00208 * e * e * sin_deg(2.0 * m)
+        - 0.00111 * sin_deg(mp - 2.0 * f)
+        - 0.00057 * sin_deg(mp + 2.0 * f)
+        + 0.00056 * e * sin_deg(2.0 * mp + m)
+        - 0.00042 * sin_deg(3.0 * mp)
+        + 0.00042 * e * sin_deg(m + 2.0 * f)
+        + 0.00038 * e * sin_deg(m - 2.0 * f)
+        - 0.00024 * e * sin_deg(2.0 * mp - m)
+        - 0.00017 * sin_deg(omega)
+        - 0.00007 * sin_deg(mp + 2.0 * m)
+        + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+        + 0.00004 * sin_deg(3.0 * m)
+        + 0.00003 * sin_deg(mp + m - 2.0 * f)
+        + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+        - 0.00003 * sin_deg(mp + m + 2.0 * f)
+        + 0.00003 * sin_deg(mp - m + 2.0 * f)
+        - 0.00002 * sin_deg(mp - m - 2.0 * f)
+        - 0.00002 * sin_deg(3.0 * mp + m)
+        + 0.00002 * sin_deg(4.0 * mp);
+
+    // Planetary arguments: (constant, rate per lunation, coefficient).
+    const PLANETARY: [(f64, f64, f64); 14] = [
+        (299.77, 0.107_408, 0.000_325),
+        (251.88, 0.016_321, 0.000_165),
+        (251.83, 26.651_886, 0.000_164),
+        (349.42, 36.412_478, 0.000_126),
+        (84.66, 18.206_239, 0.000_110),
+        (141.74, 53.303_771, 0.000_062),
+        (207.14, 2.453_732, 0.000_060),
+        (154.84, 7.306_860, 0.000_056),
+        (34.52, 27.261_239, 0.000_047),
+        (207.19, 0.121_824, 0.000_042),
+        (291.34, 1.844_379, 0.000_040),
+        (161.72, 24.198_154, 0.000_037),
+        (239.56, 25.513_099, 0.000_035),
+        (331.55, 3.592_518, 0.000_023),
+    ];
+    let planetary: f64 = PLANETARY
+        .iter()
+        .enumerate()
+        .map(|(i, (constant, rate, coefficient))| {
+            let a1_correction = if i == 0 { 0.009_173 * t2 } else { 0.0 };
+            coefficient * sin_deg(constant + rate * k - a1_correction)
+        })
+        .sum();
+
+    jde + periodic + planetary
+}
+
+// Apparent geocentric longitude of the Sun in degrees (Meeus, chapter 25).
+fn sun_longitude(jde: f64) -> f64 {
+    let t = (jde - J2000) / 36_525.0;
+    let l0 = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = 357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t;
+    let center = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * sin_deg(m)
+        + (0.019_993 - 0.000_101 * t) * sin_deg(2.0 * m)
+        + 0.000_289 * sin_deg(3.0 * m);
+    let omega = 125.04 - 1_934.136 * t;
+    (l0 + center - 0.005_69 - 0.004_78 * sin_deg(omega)).rem_euclid(360.0)
+}
+
+// Moment (JDE) near `guess` at which the Sun's longitude reaches `target`.
+fn solar_term(guess: f64, target: f64) -> f64 {
+    let mut jde = guess;
+    for _ in 0..20 {
+        let error = (target - sun_longitude(jde) + 180.0).rem_euclid(360.0) - 180.0;
+        jde += error * TROPICAL_YEAR / 360.0;
+        if error.abs() < 1e-7 {
+            break;
+        }
+    }
+    jde
+}
+
+fn approx_solstice(year: i32) -> f64 {
+    // 21 December 2000 was 355 days after J2000.
+    J2000 + 355.0 + (year - 2000) as f64 * TROPICAL_YEAR
+}
+
+// TT − UT in seconds (Espenak and Meeus polynomials, with the long-term
+// parabola outside 1986–2050).
+fn delta_t(year: f64) -> f64 {
+    let t = year - 2000.0;
+    if (1986.0..2005.0).contains(&year) {
+        63.86 + 0.3345 * t - 0.060_374 * t.powi(2) + 0.001_727_5 * t.powi(3) + 0.000_651_814 * t.powi(4)
+            + 0.000_023_735_99 * t.powi(5)
+    } else if (2005.0..2050.0).contains(&year) {
+        62.92 + 0.322_17 * t + 0.005_589 * t * t
+    } else {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    }
+}
+
+// Civil date in China of the moment `jde`.  China used Beijing local mean
+// time (UTC+7:45:40) until 1929 and UTC+8 since.
+fn beijing_date(jde: f64) -> Option<NaiveDate> {
+    let year = 2000.0 + (jde - J2000) / 365.25;
+    let offset_minutes = if year < 1929.0 { 465.67 } else { 480.0 };
+    let jd = jde - delta_t(year) / 86_400.0 + offset_minutes / 1_440.0;
+    // Julian Day 1721425.5 is the start of 31 December 1 BCE, day 0 from CE.
+    let days_from_ce = (jd + 0.5).floor() as i64 - 1_721_425;
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(days_from_ce).ok()?)
+}
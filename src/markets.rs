@@ -0,0 +1,428 @@
+//! Built-in market holiday calendars.
+//!
+//! Each constructor generates a calendar from the holiday rules of one market
+//! for a range of years, with Saturday and Sunday as weekend days.  The
+//! calendar's [valid range](Calendar::set_valid_range) is set to the years
+//! generated, so using it outside them is detectable.  Holidays carry their
+//! names (see [`Calendar::holiday_name`]).
+//!
+//! The rules are those in force when this crate was released; governments
+//! add and move holidays at short notice.  Check the generated dates against
+//! the market's official list for production use, and patch them with
+//! [`Calendar::add_named_holiday`] or [`Calendar::remove_holidays`].
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::ops::RangeInclusive;
+
+use crate::algebra::{easter_sunday, last_weekday_of_month, nth_weekday_of_month};
+use crate::calendar::Calendar;
+
+// One-off bank holidays proclaimed in England and Wales.
+const UK_PROCLAIMED: &[(i32, u32, u32, &str)] = &[
+    (1981, 7, 29, "Royal Wedding"),
+    (1999, 12, 31, "Millennium Celebrations"),
+    (2002, 6, 3, "Golden Jubilee"),
+    (2011, 4, 29, "Royal Wedding"),
+    (2012, 6, 5, "Diamond Jubilee"),
+    (2022, 6, 3, "Platinum Jubilee"),
+    (2022, 9, 19, "State Funeral of Queen Elizabeth II"),
+    (2023, 5, 8, "Coronation of King Charles III"),
+];
+
+// Early May and spring bank holidays moved by proclamation to a new date.
+const UK_EARLY_MAY_MOVED: &[(i32, u32, u32)] = &[(1995, 5, 8), (2020, 5, 8)];
+const UK_SPRING_MOVED: &[(i32, u32, u32)] = &[(2002, 6, 4), (2012, 6, 4), (2022, 6, 2)];
+
+impl Calendar {
+    /// Brazilian national holidays as used by B3 and ANBIMA for the
+    /// [`Bd252`](crate::conventions::DayCount::Bd252) day count.
+    ///
+    /// Generates, for every year in `years`:
+    /// - New Year's Day (1 January)
+    /// - Carnival Monday and Tuesday (Easter − 48 and − 47 days)
+    /// - Good Friday (Easter − 2 days)
+    /// - Tiradentes (21 April)
+    /// - Labour Day (1 May)
+    /// - Corpus Christi (Easter + 60 days)
+    /// - Independence Day (7 September)
+    /// - Our Lady of Aparecida (12 October)
+    /// - All Souls' Day (2 November)
+    /// - Republic Day (15 November)
+    /// - Black Consciousness Day (20 November, from 2024)
+    /// - Christmas Day (25 December)
+    ///
+    /// Holidays falling on a weekend are not moved.  Exchange-only closures
+    /// (such as 24 and 31 December) are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::algebra::business_days_between;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::brazil_b3(2020..=2030);
+    ///
+    /// let carnival = NaiveDate::from_ymd_opt(2025, 3, 4).unwrap();
+    /// assert_eq!(cal.holiday_name(&carnival), Some("Carnival"));
+    ///
+    /// // ANBIMA: 249 business days in 2023.
+    /// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(business_days_between(&start, &end, &cal, None), 249);
+    /// ```
+    pub fn brazil_b3(years: RangeInclusive<i32>) -> Calendar {
+        yearly_calendar(years, |year, cal| {
+            fixed(cal, year, &[
+                (1, 1, "New Year's Day"),
+                (4, 21, "Tiradentes"),
+                (5, 1, "Labour Day"),
+                (9, 7, "Independence Day"),
+                (10, 12, "Our Lady of Aparecida"),
+                (11, 2, "All Souls' Day"),
+                (11, 15, "Republic Day"),
+                (12, 25, "Christmas Day"),
+            ]);
+            if year >= 2024 {
+                fixed(cal, year, &[(11, 20, "Black Consciousness Day")]);
+            }
+            easter_based(cal, year, &[
+                (-48, "Carnival"),
+                (-47, "Carnival"),
+                (-2, "Good Friday"),
+                (60, "Corpus Christi"),
+            ]);
+        })
+    }
+
+    /// Bank holidays in England and Wales, as published on GOV.UK.
+    ///
+    /// Generates, for every year in `years`:
+    /// - New Year's Day (1 January, or the following Monday)
+    /// - Good Friday and Easter Monday
+    /// - The early May bank holiday (first Monday of May)
+    /// - The spring bank holiday (last Monday of May)
+    /// - The summer bank holiday (last Monday of August)
+    /// - Christmas Day and Boxing Day, with substitute days on the following
+    ///   Monday or Tuesday when they fall on a weekend
+    ///
+    /// On top of the rules, a built-in table applies the holidays proclaimed
+    /// for one-off events — royal weddings, jubilees, the millennium, the
+    /// state funeral of Queen Elizabeth II and the coronation of King
+    /// Charles III — and the regular holidays moved to make room for them.
+    /// The rules are those in force since 1978.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::uk_bank_holidays(2020..=2030);
+    ///
+    /// // The 2022 spring bank holiday moved to Thursday 2 June for the Platinum Jubilee.
+    /// let jubilee = NaiveDate::from_ymd_opt(2022, 6, 3).unwrap();
+    /// assert_eq!(cal.holiday_name(&jubilee), Some("Platinum Jubilee"));
+    /// assert!(cal.is_business_day(&NaiveDate::from_ymd_opt(2022, 5, 30).unwrap()));
+    ///
+    /// // Boxing Day 2026 is a Saturday.
+    /// let substitute = NaiveDate::from_ymd_opt(2026, 12, 28).unwrap();
+    /// assert_eq!(cal.holiday_name(&substitute), Some("Boxing Day (substitute day)"));
+    /// ```
+    pub fn uk_bank_holidays(years: RangeInclusive<i32>) -> Calendar {
+        yearly_calendar(years, |year, cal| {
+            if let Some(new_year) = NaiveDate::from_ymd_opt(year, 1, 1) {
+                match new_year.weekday() {
+                    Weekday::Sat => add_days(cal, new_year, 2, "New Year's Day (substitute day)"),
+                    Weekday::Sun => add_days(cal, new_year, 1, "New Year's Day (substitute day)"),
+                    _ => cal.add_named_holiday(new_year, "New Year's Day"),
+                }
+            }
+            easter_based(cal, year, &[(-2, "Good Friday"), (1, "Easter Monday")]);
+
+            let moved = |table: &[(i32, u32, u32)]| {
+                table
+                    .iter()
+                    .find(|(moved_year, _, _)| *moved_year == year)
+                    .and_then(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day))
+            };
+            let early_may = moved(UK_EARLY_MAY_MOVED).or_else(|| nth_weekday_of_month(year, 5, Weekday::Mon, 1).ok());
+            let spring = moved(UK_SPRING_MOVED).or_else(|| last_weekday_of_month(year, 5, Weekday::Mon).ok());
+            let summer = last_weekday_of_month(year, 8, Weekday::Mon).ok();
+            for (date, name) in [
+                (early_may, "Early May bank holiday"),
+                (spring, "Spring bank holiday"),
+                (summer, "Summer bank holiday"),
+            ] {
+                if let Some(date) = date {
+                    cal.add_named_holiday(date, name);
+                }
+            }
+
+            if let Some(christmas) = NaiveDate::from_ymd_opt(year, 12, 25) {
+                let days = match christmas.weekday() {
+                    Weekday::Fri => [(0, "Christmas Day"), (3, "Boxing Day (substitute day)")],
+                    Weekday::Sat => [(2, "Christmas Day (substitute day)"), (3, "Boxing Day (substitute day)")],
+                    Weekday::Sun => [(1, "Boxing Day"), (2, "Christmas Day (substitute day)")],
+                    _ => [(0, "Christmas Day"), (1, "Boxing Day")],
+                };
+                for (offset, name) in days {
+                    add_days(cal, christmas, offset, name);
+                }
+            }
+
+            for &(_, month, day, name) in UK_PROCLAIMED.iter().filter(|(proclaimed_year, ..)| *proclaimed_year == year) {
+                fixed(cal, year, &[(month, day, name)]);
+            }
+        })
+    }
+
+    /// The TARGET2 calendar of euro settlement days, the reference calendar
+    /// for EUR fixings and payments.
+    ///
+    /// TARGET2 is closed on Saturdays and Sundays and on:
+    /// - New Year's Day (1 January)
+    /// - Good Friday and Easter Monday (from 2000)
+    /// - Labour Day (1 May, from 2000)
+    /// - Christmas Day (25 December)
+    /// - 26 December (from 2000)
+    ///
+    /// as well as 31 December in 1999 and 2001.  Unlike national calendars,
+    /// no holiday is ever moved to a weekday.  The calendar covers 1999, when
+    /// TARGET started operating, through 2099.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::target2();
+    ///
+    /// let labour_day = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    /// assert!(!cal.is_business_day(&labour_day));
+    /// // Ascension Day is a holiday in many euro countries, but not for TARGET2.
+    /// assert!(cal.is_business_day(&NaiveDate::from_ymd_opt(2024, 5, 9).unwrap()));
+    /// ```
+    pub fn target2() -> Calendar {
+        yearly_calendar(1999..=2099, |year, cal| {
+            fixed(cal, year, &[(1, 1, "New Year's Day"), (12, 25, "Christmas Day")]);
+            if year >= 2000 {
+                fixed(cal, year, &[(5, 1, "Labour Day"), (12, 26, "Christmas Holiday")]);
+                easter_based(cal, year, &[(-2, "Good Friday"), (1, "Easter Monday")]);
+            }
+            if year == 1999 || year == 2001 {
+                fixed(cal, year, &[(12, 31, "New Year's Eve")]);
+            }
+        })
+    }
+
+    /// Japanese national holidays plus the year-end bank and exchange
+    /// closures, matching the JPX trading calendar.
+    ///
+    /// Implements the Act on National Holidays as amended over the years:
+    /// - Fixed-date holidays, and the "Happy Monday" holidays on the 2nd or
+    ///   3rd Monday of their month (Coming of Age and Health and Sports Day
+    ///   from 2000, Marine Day and Respect for the Aged Day from 2003; before
+    ///   that they fell on 15 January, 10 October, 20 July and 15 September)
+    /// - Marine Day from 1996, Mountain Day from 2016, and 29 April as the
+    ///   Emperor's Birthday until 1988, Greenery Day until 2006 and Showa Day
+    ///   since
+    /// - Vernal and Autumnal Equinox Days, from the standard approximation of
+    ///   the equinox dates (the official dates are only announced a year in
+    ///   advance)
+    /// - Substitute holidays (*furikae kyūjitsu*): a holiday falling on a
+    ///   Sunday moves to the Monday, or from 2007 to the next day that is not
+    ///   already a holiday
+    /// - Citizens' holidays (*kokumin no kyūjitsu*, from 1986): a day
+    ///   sandwiched between two national holidays is itself a holiday
+    /// - The one-off imperial holidays of 1989, 1990, 1993 and 2019, and the
+    ///   holidays moved for the 2020 Tokyo Olympics in 2020 and 2021
+    ///
+    /// 2 January, 3 January and 31 December, on which banks and exchanges
+    /// close, are included as `"Bank Holiday"`.
+    ///
+    /// The equinox approximation only holds for 1980–2099, so years of
+    /// `years` outside that window are skipped and the calendar's valid range
+    /// covers only the years generated.  If no year is left the calendar has
+    /// no holidays and no valid range, as for an empty `years`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::Calendar;
+    ///
+    /// let cal = Calendar::japan(2024..=2026);
+    ///
+    /// // Culture Day 2024 fell on a Sunday.
+    /// let monday = NaiveDate::from_ymd_opt(2024, 11, 4).unwrap();
+    /// assert_eq!(cal.holiday_name(&monday), Some("Substitute Holiday"));
+    ///
+    /// // Silver Week 2026: Respect for the Aged Day and Autumnal Equinox Day
+    /// // enclose a Citizens' Holiday.
+    /// let tuesday = NaiveDate::from_ymd_opt(2026, 9, 22).unwrap();
+    /// assert_eq!(cal.holiday_name(&tuesday), Some("Citizens' Holiday"));
+    ///
+    /// // Before 2000, Coming of Age Day was 15 January.
+    /// let old = Calendar::japan(1999..=2100);
+    /// let friday = NaiveDate::from_ymd_opt(1999, 1, 15).unwrap();
+    /// assert_eq!(old.holiday_name(&friday), Some("Coming of Age Day"));
+    /// assert_eq!(old.valid_range().unwrap().1, NaiveDate::from_ymd_opt(2099, 12, 31).unwrap());
+    /// ```
+    pub fn japan(years: RangeInclusive<i32>) -> Calendar {
+        let years = (*years.start()).max(1980)..=(*years.end()).min(2099);
+        yearly_calendar(years, |year, cal| {
+            let national = japan_national_holidays(year);
+            let is_holiday = |holidays: &[(NaiveDate, &str)], date: &NaiveDate| holidays.iter().any(|(holiday, _)| holiday == date);
+
+            // Substitutes take precedence over citizens' holidays.
+            let mut holidays = national.clone();
+            for (date, _) in national.iter().filter(|(date, _)| date.weekday() == Weekday::Sun) {
+                let mut substitute = date.succ_opt();
+                if year >= 2007 {
+                    while let Some(candidate) = substitute.filter(|candidate| is_holiday(&holidays, candidate)) {
+                        substitute = candidate.succ_opt();
+                    }
+                } else {
+                    substitute = substitute.filter(|monday| !is_holiday(&holidays, monday));
+                }
+                if let Some(substitute) = substitute {
+                    holidays.push((substitute, "Substitute Holiday"));
+                }
+            }
+            for (date, _) in national.iter().filter(|_| year >= 1986) {
+                let (Some(next), Some(after)) = (date.succ_opt(), date.succ_opt().and_then(|next| next.succ_opt())) else {
+                    continue;
+                };
+                if is_holiday(&national, &after) && !is_holiday(&holidays, &next) && next.weekday() != Weekday::Sun {
+                    holidays.push((next, "Citizens' Holiday"));
+                }
+            }
+
+            for (date, name) in holidays {
+                cal.add_named_holiday(date, name);
+            }
+            for (month, day) in [(1, 2), (1, 3), (12, 31)] {
+                if let Some(date) = NaiveDate::from_ymd_opt(year, month, day).filter(|date| !cal.is_holiday(date)) {
+                    cal.add_named_holiday(date, "Bank Holiday");
+                }
+            }
+        })
+    }
+}
+
+// Japanese national holidays of `year` before substitute and citizens'
+// holidays are applied.
+fn japan_national_holidays(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let date = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+    let monday = |month, n| nth_weekday_of_month(year, month, Weekday::Mon, n).ok();
+    // Equinox days in the standard approximation, valid for 1980-2099.
+    let equinox = |base: f64| {
+        let since_1980 = (year - 1980) as f64;
+        (base + 0.242_194 * since_1980 - (since_1980 / 4.0).floor()).floor() as u32
+    };
+
+    let mut holidays = vec![
+        (date(1, 1), "New Year's Day"),
+        (if year >= 2000 { monday(1, 2) } else { date(1, 15) }, "Coming of Age Day"),
+        (date(2, 11), "National Foundation Day"),
+        (date(3, equinox(20.8431)), "Vernal Equinox Day"),
+        (date(5, 3), "Constitution Memorial Day"),
+        (date(5, 5), "Children's Day"),
+        (date(9, equinox(23.2488)), "Autumnal Equinox Day"),
+        (date(11, 3), "Culture Day"),
+        (date(11, 23), "Labour Thanksgiving Day"),
+    ];
+    match year {
+        ..=1988 => holidays.push((date(4, 29), "Emperor's Birthday")),
+        1989..=2006 => holidays.push((date(4, 29), "Greenery Day")),
+        _ => holidays.extend([(date(4, 29), "Showa Day"), (date(5, 4), "Greenery Day")]),
+    }
+    match year {
+        ..=1988 => {}
+        1989..=2018 => holidays.push((date(12, 23), "Emperor's Birthday")),
+        2019 => holidays.extend([
+            (date(5, 1), "Enthronement Day"),
+            (date(10, 22), "Enthronement Ceremony Day"),
+        ]),
+        _ => holidays.push((date(2, 23), "Emperor's Birthday")),
+    }
+    match year {
+        1989 => holidays.push((date(2, 24), "Funeral of Emperor Showa")),
+        1990 => holidays.push((date(11, 12), "Enthronement Ceremony Day")),
+        1993 => holidays.push((date(6, 9), "Wedding of Crown Prince Naruhito")),
+        _ => {}
+    }
+    let (marine, sports, mountain) = match year {
+        2020 => (date(7, 23), date(7, 24), date(8, 10)),
+        2021 => (date(7, 22), date(7, 23), date(8, 8)),
+        ..=1995 => (None, date(10, 10), None),
+        1996..=1999 => (date(7, 20), date(10, 10), None),
+        2000..=2002 => (date(7, 20), monday(10, 2), None),
+        _ => (monday(7, 3), monday(10, 2), if year >= 2016 { date(8, 11) } else { None }),
+    };
+    holidays.extend([
+        (marine, "Marine Day"),
+        (sports, if year >= 2020 { "Sports Day" } else { "Health and Sports Day" }),
+        (mountain, "Mountain Day"),
+    ]);
+    holidays.push(if year >= 2003 {
+        (monday(9, 3), "Respect for the Aged Day")
+    } else {
+        (date(9, 15), "Respect for the Aged Day")
+    });
+
+    holidays.into_iter().filter_map(|(date, name)| Some((date?, name))).collect()
+}
+
+// Adds a holiday `days` after `date`.
+fn add_days(cal: &mut Calendar, date: NaiveDate, days: u64, name: &str) {
+    if let Some(date) = date.checked_add_days(Days::new(days)) {
+        cal.add_named_holiday(date, name);
+    }
+}
+
+// A Saturday/Sunday calendar with the holidays `rule` adds for each year,
+// valid over those years.
+fn yearly_calendar<F>(years: RangeInclusive<i32>, mut rule: F) -> Calendar
+where
+    F: FnMut(i32, &mut Calendar),
+{
+    let mut cal = Calendar::with_weekends([Weekday::Sat, Weekday::Sun]);
+    let (first, last) = (*years.start(), *years.end());
+    for year in years {
+        rule(year, &mut cal);
+    }
+    if let (Some(start), Some(end)) = (NaiveDate::from_ymd_opt(first, 1, 1), NaiveDate::from_ymd_opt(last, 12, 31)) {
+        // Only fails for an empty range, which leaves the calendar unbounded.
+        let _ = cal.set_valid_range(start, end);
+    }
+    cal
+}
+
+// Adds holidays on fixed (month, day) dates.
+fn fixed(cal: &mut Calendar, year: i32, holidays: &[(u32, u32, &str)]) {
+    for &(month, day, name) in holidays {
+        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+            cal.add_named_holiday(date, name);
+        }
+    }
+}
+
+// Adds holidays a fixed number of days from Easter Sunday.
+fn easter_based(cal: &mut Calendar, year: i32, holidays: &[(i64, &str)]) {
+    let Ok(easter) = easter_sunday(year) else {
+        return;
+    };
+    for &(offset, name) in holidays {
+        let date = if offset < 0 {
+            easter.checked_sub_days(Days::new(offset.unsigned_abs()))
+        } else {
+            easter.checked_add_days(Days::new(offset as u64))
+        };
+        if let Some(date) = date {
+            cal.add_named_holiday(date, name);
+        }
+    }
+}
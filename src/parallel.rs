@@ -0,0 +1,115 @@
+//! Parallel batch operations over large date sets, using
+//! [rayon](https://docs.rs/rayon).
+//!
+//! Requires the `parallel` feature.  Each function returns exactly what its
+//! sequential counterpart would — only the work is spread across rayon's
+//! global thread pool.  For small inputs the sequential versions are usually
+//! faster.
+
+use chrono::NaiveDate;
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+
+use crate::algebra::adjust;
+use crate::calendar::Calendar;
+use crate::conventions::AdjustRule;
+use crate::error::ScheduleError;
+use crate::schedule::Schedule;
+
+/// Parallel version of [`algebra::adjust_many`](crate::algebra::adjust_many).
+///
+/// # Panics
+///
+/// Panics like [`adjust`] if the search for a business day runs off the
+/// representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::algebra::adjust_many;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::parallel::par_adjust_many;
+///
+/// let cal   = basic_calendar();
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let dates: Vec<NaiveDate> = start.iter_days().take(10_000).collect();
+///
+/// let rule = Some(AdjustRule::ModFollowing);
+/// assert_eq!(par_adjust_many(&dates, Some(&cal), rule), adjust_many(&dates, Some(&cal), rule));
+/// ```
+pub fn par_adjust_many(
+    dates: &[NaiveDate],
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Vec<NaiveDate> {
+    match (opt_calendar, adjust_rule) {
+        (None, _) | (_, None) | (_, Some(AdjustRule::Unadjusted)) => dates.to_vec(),
+        (calendar, rule) => dates.par_iter().map(|date| adjust(date, calendar, rule)).collect(),
+    }
+}
+
+/// Builds a holiday calendar by evaluating a yearly holiday rule for every
+/// year in `years` in parallel.
+///
+/// `rule` returns the holidays of one year — for instance fixed dates plus
+/// Easter-based and "nth weekday" holidays.  The result has no weekend days,
+/// like [`Calendar::with_holidays`]; add them with [`Calendar::add_weekends`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::parallel::par_expand_holidays;
+///
+/// // New Year's Day and Christmas for a century.
+/// let cal = par_expand_holidays(2000..=2099, |year| {
+///     [(1, 1), (12, 25)]
+///         .into_iter()
+///         .filter_map(|(m, d)| NaiveDate::from_ymd_opt(year, m, d))
+///         .collect()
+/// });
+/// assert_eq!(cal.get_holidays().len(), 200);
+/// ```
+pub fn par_expand_holidays<F>(years: RangeInclusive<i32>, rule: F) -> Calendar
+where
+    F: Fn(i32) -> Vec<NaiveDate> + Sync + Send,
+{
+    let holidays: Vec<NaiveDate> = years.into_par_iter().flat_map_iter(rule).collect();
+    Calendar::with_holidays(holidays)
+}
+
+/// Generates many schedules in parallel.
+///
+/// Each item is a schedule with its anchor and end date; the result holds
+/// [`Schedule::generate`]'s output for each item, in the same order.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{AdjustRule, Frequency};
+/// use findates::parallel::par_generate;
+/// use findates::schedule::Schedule;
+///
+/// let cal    = basic_calendar();
+/// let sched  = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+/// let anchor = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let instruments: Vec<_> = (1..=1000)
+///     .map(|years| (sched.clone(), anchor, NaiveDate::from_ymd_opt(2024 + years % 30, 1, 15).unwrap()))
+///     .collect();
+///
+/// let schedules = par_generate(&instruments);
+/// assert_eq!(schedules.len(), 1000);
+/// assert_eq!(schedules[0], sched.generate(&instruments[0].1, &instruments[0].2));
+/// ```
+pub fn par_generate(
+    items: &[(Schedule<'_>, NaiveDate, NaiveDate)],
+) -> Vec<Result<Vec<NaiveDate>, ScheduleError>> {
+    items
+        .par_iter()
+        .map(|(schedule, anchor, end)| schedule.generate(anchor, end))
+        .collect()
+}
@@ -0,0 +1,223 @@
+//! Named calendars and composite calendar expressions.
+//!
+//! A [`CalendarRegistry`] maps short codes such as `"NYC"` or `"LON"` to
+//! calendars, so that configuration files can name calendars as text.  Codes
+//! combine into expressions:
+//!
+//! - `"NYC|LON"` — the union of the holidays: a business day only when both
+//!   centres are open, as for a payment that must settle in both.
+//! - `"NYC&LON"` — the intersection of the holidays: a business day when
+//!   either centre is open.
+//!
+//! `&` binds tighter than `|`, so `"A|B&C"` is `A | (B & C)`.  Codes are
+//! case-insensitive and whitespace around them is ignored.
+//!
+//! [`Calendar`] implements [`FromStr`] over the registry in scope on the
+//! current thread; see [`CalendarRegistry::scope`].
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::calendar::Calendar;
+
+pub use crate::error::ParseCalendarError;
+
+thread_local! {
+    // Registries entered with `CalendarRegistry::scope`, innermost last.
+    static SCOPES: RefCell<Vec<CalendarRegistry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A set of calendars looked up by code.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::Calendar;
+/// use findates::registry::CalendarRegistry;
+///
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// let mut registry = CalendarRegistry::new();
+/// registry.register("NYC", Calendar::with_holidays([d(7, 4)])).unwrap();
+/// registry.register("LON", Calendar::with_holidays([d(8, 26)])).unwrap();
+///
+/// let both = registry.parse("NYC|LON").unwrap();
+/// assert!(!both.is_business_day(&d(7, 4)) && !both.is_business_day(&d(8, 26)));
+/// let either = registry.parse("NYC&LON").unwrap();
+/// assert!(either.is_business_day(&d(7, 4)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CalendarRegistry {
+    calendars: HashMap<String, Calendar>,
+}
+
+impl CalendarRegistry {
+    /// Creates an empty registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::registry::CalendarRegistry;
+    ///
+    /// assert!(CalendarRegistry::new().get("NYC").is_none());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `calendar` under `code`, returning the calendar previously
+    /// registered under it, if any.  Codes are case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseCalendarError::InvalidCode`] if `code` is empty after
+    /// trimming or contains `|` or `&`; the registry is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::{basic_calendar, Calendar};
+    /// use findates::registry::CalendarRegistry;
+    ///
+    /// let mut registry = CalendarRegistry::new();
+    /// assert_eq!(registry.register("TARGET", Calendar::target2()), Ok(None));
+    /// assert!(registry.register("target", basic_calendar()).unwrap().is_some());
+    /// assert!(registry.register("NYC|LON", basic_calendar()).is_err());
+    /// ```
+    pub fn register(&mut self, code: &str, calendar: Calendar) -> Result<Option<Calendar>, ParseCalendarError> {
+        let key = normalize(code);
+        if key.is_empty() || key.contains(['|', '&']) {
+            return Err(ParseCalendarError::InvalidCode(code.to_string()));
+        }
+        Ok(self.calendars.insert(key, calendar))
+    }
+
+    /// Returns the calendar registered under `code`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    /// use findates::registry::CalendarRegistry;
+    ///
+    /// let mut registry = CalendarRegistry::new();
+    /// registry.register("TARGET", Calendar::target2()).unwrap();
+    /// assert_eq!(registry.get(" Target "), Some(&Calendar::target2()));
+    /// ```
+    pub fn get(&self, code: &str) -> Option<&Calendar> {
+        self.calendars.get(&normalize(code))
+    }
+
+    /// Builds the calendar described by `expression`, e.g. `"NYC|LON"`.
+    /// See the [module documentation](self) for the syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseCalendarError::UnknownCode`] for a code that is not
+    /// registered, [`ParseCalendarError::InvalidExpression`] if a code is
+    /// missing around an operator, and [`ParseCalendarError::DisjointRanges`]
+    /// if the combined calendars have valid ranges that do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    /// use findates::registry::{CalendarRegistry, ParseCalendarError};
+    ///
+    /// let mut registry = CalendarRegistry::new();
+    /// registry.register("TARGET", Calendar::target2()).unwrap();
+    ///
+    /// assert_eq!(registry.parse("target"), Ok(Calendar::target2()));
+    /// assert_eq!(registry.parse("TARGET|NYC"), Err(ParseCalendarError::UnknownCode("NYC".to_string())));
+    /// assert_eq!(registry.parse("TARGET|"), Err(ParseCalendarError::InvalidExpression));
+    /// ```
+    pub fn parse(&self, expression: &str) -> Result<Calendar, ParseCalendarError> {
+        let mut result: Option<Calendar> = None;
+        for term in expression.split('|') {
+            let mut joint: Option<Calendar> = None;
+            for code in term.split('&') {
+                let code = code.trim();
+                if code.is_empty() {
+                    return Err(ParseCalendarError::InvalidExpression);
+                }
+                let calendar = self.get(code).ok_or_else(|| ParseCalendarError::UnknownCode(code.to_string()))?;
+                joint = Some(match joint {
+                    Some(joint) => joint.try_intersected(calendar).map_err(|_| ParseCalendarError::DisjointRanges)?,
+                    None => calendar.clone(),
+                });
+            }
+            let joint = joint.ok_or(ParseCalendarError::InvalidExpression)?;
+            result = Some(match result {
+                Some(result) => result.try_unioned(&joint).map_err(|_| ParseCalendarError::DisjointRanges)?,
+                None => joint,
+            });
+        }
+        result.ok_or(ParseCalendarError::InvalidExpression)
+    }
+
+    /// Runs `f` with this registry in scope on the current thread, so that
+    /// `str::parse::<Calendar>()` inside it resolves codes here.
+    ///
+    /// Scopes nest: the innermost registry is used.  The registry is cloned
+    /// for the duration of the call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::{basic_calendar, Calendar};
+    /// use findates::registry::CalendarRegistry;
+    ///
+    /// let mut registry = CalendarRegistry::new();
+    /// registry.register("TARGET", Calendar::target2()).unwrap();
+    /// registry.register("WE", basic_calendar()).unwrap();
+    ///
+    /// let cal: Calendar = registry.scope(|| "TARGET|WE".parse()).unwrap();
+    /// assert!(!cal.is_business_day(&NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+    /// assert!("TARGET".parse::<Calendar>().is_err()); // out of scope
+    /// ```
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        // Pops the registry even if `f` panics.
+        struct Exit;
+        impl Drop for Exit {
+            fn drop(&mut self) {
+                SCOPES.with(|scopes| scopes.borrow_mut().pop());
+            }
+        }
+
+        SCOPES.with(|scopes| scopes.borrow_mut().push(self.clone()));
+        let _exit = Exit;
+        f()
+    }
+}
+
+impl FromStr for Calendar {
+    type Err = ParseCalendarError;
+
+    /// Parses a calendar expression such as `"NYC|LON"` against the
+    /// registry in scope; see [`CalendarRegistry::scope`].
+    ///
+    /// With no registry in scope every code is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    /// use findates::registry::CalendarRegistry;
+    ///
+    /// let mut registry = CalendarRegistry::new();
+    /// registry.register("TARGET", Calendar::target2()).unwrap();
+    /// assert_eq!(registry.scope(|| "TARGET".parse()), Ok(Calendar::target2()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SCOPES.with(|scopes| match scopes.borrow().last() {
+            Some(registry) => registry.parse(s),
+            None => CalendarRegistry::new().parse(s),
+        })
+    }
+}
+
+fn normalize(code: &str) -> String {
+    code.trim().to_ascii_uppercase()
+}
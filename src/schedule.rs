@@ -4,18 +4,66 @@
 //! [`AdjustRule`].  Calling [`Schedule::iter`] returns an unbounded lazy
 //! iterator that steps from the previous **adjusted** date — suitable for
 //! interactive "next date from today" queries.  Calling [`Schedule::generate`]
-//! collects dates up to a given end date into a `Vec`, stepping from
-//! **nominal** dates to preserve schedule integrity for fixed-term instruments.
+//! collects dates up to a given end date into a `Vec`, computing the `n`-th
+//! date as **anchor + n periods** before adjusting, so neither adjustments
+//! nor short months accumulate drift in fixed-term schedules.
 //!
-//! For single-step interactive use, [`schedule_next_adjusted`] is also
-//! available as a public function.
+//! [`Schedule::iter_adjusted`] computes dates like `generate` but lazily,
+//! still guaranteeing strictly increasing adjusted dates.
+//!
+//! [`Schedule::generate_with_trace`] returns the same dates as
+//! [`GeneratedDate`]s recording the unadjusted date and adjustment behind each
+//! one, for debugging why a payment landed where it did.
+//!
+//! For single-step interactive use, [`Schedule::next_adjusted`] (also
+//! available as the free function [`schedule_next_adjusted`]) returns the
+//! adjusted date following a given date.  [`Schedule::next_after`],
+//! [`Schedule::previous_before`] and [`Schedule::contains`] answer "when is
+//! the next roll after today?" and "is this a coupon date?" for the dates
+//! `generate` would return, without collecting them.
+//!
+//! Swap and bond schedules are usually rolled **backwards** from maturity so
+//! that any irregular stub falls at the front: use
+//! [`Schedule::generate_backward`] or [`Schedule::iter_backward`].
+//!
+//! [`Schedule::coupon_periods`] turns a schedule into [`CouponPeriod`]s with
+//! accrual dates, payment dates and year fractions in one call, and
+//! [`classify_periods`] tells regular periods from short and long stubs.
+//! [`align_legs`] pairs the periods of two swap legs, nesting the periods of
+//! the more frequent leg inside those of the other.
+//! [`merge`], [`intersect`] and [`shift_by_business_days`] combine the
+//! generated dates of several schedules, e.g. payment and fixing dates.
+//! [`weekday_dates`] and [`nth_weekday_dates`] generate weekday-anchored
+//! dates such as "every Wednesday" or "the second and fourth Friday of each
+//! month" directly from a date range.
+//!
+//! Setting [`Schedule::eom`] applies the end-of-month roll convention: a
+//! month-based schedule anchored on a month end keeps landing on month ends.
+//! A [`RollConvention`] set with [`Schedule::with_roll`] pins every date to a
+//! given day of month, IMM Wednesday, CDS date, weekday or business day of
+//! the month instead, e.g. the last business day of each month for NAV
+//! dates.  A
+//! [`StubRule`] set with [`Schedule::with_stub`] chooses where the irregular
+//! coupon period goes, and a [`Rounding`] set with [`Schedule::with_rounding`]
+//! the precision of each coupon period's year fraction.
+//!
+//! [`Schedule::builder`] sets the same options by name and rejects
+//! combinations that would be silently ignored, such as a stub rule on a
+//! [`Frequency::Zero`] schedule, with a [`ScheduleBuildError`].
+//!
+//! [`ScheduleOwned`] shares its calendar through an [`Arc`]
+//! instead of borrowing it, so a schedule definition can be stored, sent
+//! across threads and — with the `serde` feature — serialized.
+
+use std::sync::Arc;
 
 use crate::FinDate;
-use chrono::{Datelike, Days, Months, NaiveDate};
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 
-use crate::algebra::{self, adjust, checked_add_years};
+use crate::algebra::{self, checked_add_years, DayCountContext, ReferencePeriod};
 use crate::calendar::Calendar;
-use crate::conventions::{AdjustRule, Frequency};
+use crate::conventions::{AdjustRule, DayCount, Frequency, RollConvention, Rounding, StubRule};
+use crate::error::{ScheduleBuildError, ScheduleError};
 
 /// A date generation rule combining a frequency, an optional calendar, and an
 /// optional adjustment rule.
@@ -56,7 +104,8 @@ use crate::conventions::{AdjustRule, Frequency};
 /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
 /// assert_eq!(dates[3], NaiveDate::from_ymd_opt(2024, 4, 30).unwrap());
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Schedule<'a> {
     /// The step frequency between consecutive dates.
     pub frequency: Frequency,
@@ -64,11 +113,20 @@ pub struct Schedule<'a> {
     pub calendar: Option<&'a Calendar>,
     /// Optional adjustment rule applied when a date falls on a non-business day.
     pub adjust_rule: Option<AdjustRule>,
+    // Private, so that further options can be added without breaking
+    // downstream code.  Set with the `with_*` methods or the builder.
+    eom: bool,
+    roll: Option<RollConvention>,
+    stub: Option<StubRule>,
+    rounding: Option<Rounding>,
 }
 
 impl<'a> Schedule<'a> {
     /// Creates a new [`Schedule`].
     ///
+    /// Use [`Schedule::builder`] to also set the roll and stub conventions
+    /// and have the combination validated.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -87,15 +145,261 @@ impl<'a> Schedule<'a> {
             frequency,
             calendar: opt_calendar,
             adjust_rule: opt_adjust_rule,
+            eom: false,
+            roll: None,
+            stub: None,
+            rounding: None,
         }
     }
 
+    /// Returns the schedule with the end-of-month roll convention switched on
+    /// or off.
+    ///
+    /// With `eom` set, a schedule whose anchor is the last day of its month —
+    /// or, when a calendar is set, the last business day — rolls to the end
+    /// of each month instead of repeating the anchor's day of month.  This
+    /// only affects month-based frequencies (monthly through annual).  When a
+    /// calendar and an adjustment rule other than
+    /// [`Unadjusted`](AdjustRule::Unadjusted) are set, rolled dates land on the
+    /// last business day of the month, as in QuantLib.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::Frequency;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2023, 11, 30).unwrap();
+    /// let sched  = Schedule::new(Frequency::Quarterly, None, None).with_eom(true);
+    ///
+    /// let dates = sched.generate(&anchor, &end).unwrap();
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2023, 5, 31).unwrap());
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2023, 8, 31).unwrap());
+    /// assert_eq!(dates[3], NaiveDate::from_ymd_opt(2023, 11, 30).unwrap());
+    /// ```
+    pub fn with_eom(mut self, eom: bool) -> Self {
+        self.eom = eom;
+        self
+    }
+
+    /// Returns whether the end-of-month roll convention is switched on.  See
+    /// [`Schedule::with_eom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::Frequency;
+    ///
+    /// let sched = Schedule::new(Frequency::Quarterly, None, None);
+    /// assert!(!sched.eom());
+    /// assert!(sched.with_eom(true).eom());
+    /// ```
+    pub fn eom(&self) -> bool {
+        self.eom
+    }
+
+    /// Returns the roll convention, if one is set.  It takes precedence over
+    /// [`eom`](Schedule::eom).  See [`Schedule::with_roll`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::{Frequency, RollConvention};
+    ///
+    /// let sched = Schedule::new(Frequency::Quarterly, None, None);
+    /// assert_eq!(sched.roll(), None);
+    /// assert_eq!(sched.with_roll(RollConvention::Imm).roll(), Some(RollConvention::Imm));
+    /// ```
+    pub fn roll(&self) -> Option<RollConvention> {
+        self.roll
+    }
+
+    /// Returns the stub rule, if one is set.  `None` is a short back stub.
+    /// See [`Schedule::with_stub`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::{Frequency, StubRule};
+    ///
+    /// let sched = Schedule::new(Frequency::Quarterly, None, None).with_stub(StubRule::LongFront);
+    /// assert_eq!(sched.stub(), Some(StubRule::LongFront));
+    /// ```
+    pub fn stub(&self) -> Option<StubRule> {
+        self.stub
+    }
+
+    /// Returns the rounding applied to coupon period year fractions, if one
+    /// is set.  See [`Schedule::with_rounding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::{Frequency, Rounding, RoundingDirection};
+    ///
+    /// let rounding = Rounding::new(6, RoundingDirection::Nearest);
+    /// let sched = Schedule::new(Frequency::Quarterly, None, None).with_rounding(rounding);
+    /// assert_eq!(sched.rounding(), Some(rounding));
+    /// ```
+    pub fn rounding(&self) -> Option<Rounding> {
+        self.rounding
+    }
+
+    /// Returns a [`ScheduleBuilder`] to set a schedule's options by name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency, StubRule};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal   = basic_calendar();
+    /// let sched = Schedule::builder()
+    ///     .frequency(Frequency::Semiannual)
+    ///     .calendar(&cal)
+    ///     .adjust_rule(AdjustRule::ModFollowing)
+    ///     .stub(StubRule::ShortFront)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     sched,
+    ///     Schedule::new(Frequency::Semiannual, Some(&cal), Some(AdjustRule::ModFollowing))
+    ///         .with_stub(StubRule::ShortFront)
+    /// );
+    /// ```
+    pub fn builder() -> ScheduleBuilder<'a> {
+        ScheduleBuilder::default()
+    }
+
+    /// Returns the schedule with every rolled date pinned to `roll`.
+    ///
+    /// The frequency still decides the month (or week) of each date; the roll
+    /// convention then picks the day within it.  Day-of-month conventions
+    /// ([`Day`](RollConvention::Day), [`EndOfMonth`](RollConvention::EndOfMonth),
+    /// [`Imm`](RollConvention::Imm), [`Cds`](RollConvention::Cds) and the
+    /// business day conventions) apply to month-based frequencies (monthly
+    /// through annual), and [`DayOfWeek`](RollConvention::DayOfWeek) to
+    /// weekly, biweekly and four-weekly frequencies; other combinations are
+    /// ignored.  The anchor itself is not rolled.
+    ///
+    /// [`LastBusinessDay`](RollConvention::LastBusinessDay),
+    /// [`FirstBusinessDay`](RollConvention::FirstBusinessDay) and
+    /// [`NthBusinessDay`](RollConvention::NthBusinessDay) count business
+    /// days in the schedule's calendar, giving reporting and NAV date
+    /// schedules such as "last business day of each quarter" that no fixed
+    /// day of month can express.  Without a calendar every day counts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::{Frequency, RollConvention};
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let sched  = Schedule::new(Frequency::Quarterly, None, None).with_roll(RollConvention::Imm);
+    ///
+    /// let dates = sched.generate(&anchor, &end).unwrap();
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 4, 17).unwrap());
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 7, 17).unwrap());
+    /// ```
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::{Frequency, RollConvention};
+    ///
+    /// // Quarter-end reporting dates: 30 June 2024 is a Sunday.
+    /// let cal    = basic_calendar();
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    /// let sched  = Schedule::new(Frequency::Quarterly, Some(&cal), None)
+    ///     .with_roll(RollConvention::LastBusinessDay);
+    ///
+    /// let dates = sched.generate(&anchor, &end).unwrap();
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 6, 28).unwrap());
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 9, 30).unwrap());
+    /// assert_eq!(dates[3], NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+    /// ```
+    pub fn with_roll(mut self, roll: RollConvention) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+
+    /// Returns the schedule with its irregular coupon period placed by
+    /// `stub`.
+    ///
+    /// The stub rule shapes the accrual periods of
+    /// [`Schedule::coupon_periods`], [`Schedule::payment_dates`] and
+    /// [`Schedule::fixing_dates`]; date generation itself is unaffected.
+    /// Without one the schedule rolls forward from the effective date and
+    /// ends with a short stub.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{DayCount, Frequency, StubRule};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let effective = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    /// let sched     = Schedule::new(Frequency::Semiannual, None, None).with_stub(StubRule::LongFront);
+    ///
+    /// let periods = sched.coupon_periods(&effective, &maturity, DayCount::Act360, 0).unwrap();
+    /// assert_eq!(periods.len(), 2);
+    /// assert_eq!(periods[0].accrual_end, NaiveDate::from_ymd_opt(2024, 12, 15).unwrap());
+    /// ```
+    pub fn with_stub(mut self, stub: StubRule) -> Self {
+        self.stub = Some(stub);
+        self
+    }
+
+    /// Returns the schedule with the year fraction of each coupon period
+    /// rounded by `rounding`.
+    ///
+    /// Applies to [`Schedule::coupon_periods`]; dates are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{DayCount, Frequency, Rounding, RoundingDirection};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+    /// let sched     = Schedule::new(Frequency::Quarterly, None, None)
+    ///     .with_rounding(Rounding::new(4, RoundingDirection::Nearest));
+    ///
+    /// let periods = sched.coupon_periods(&effective, &maturity, DayCount::Act360, 0).unwrap();
+    /// // 91 / 360 = 0.252777...
+    /// assert_eq!(periods[0].year_fraction, 0.2528);
+    /// ```
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
     /// Returns a lazy, unbounded iterator that yields the next date on each call.
     ///
     /// The first value yielded is the adjusted date *after* `anchor` (the anchor
     /// itself is not included).  For [`Frequency::Zero`] the iterator is
     /// immediately exhausted.
     ///
+    /// Each step starts from the previous yielded date, so a month-end anchor
+    /// drifts (31 January, 29 February, 29 March, ...).  Use
+    /// [`Schedule::iter_adjusted`] for dates computed from the anchor.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -110,27 +414,209 @@ impl<'a> Schedule<'a> {
     /// assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()); // leap year
     /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 3, 29).unwrap());
     /// ```
-    pub fn iter(&self, anchor: FinDate) -> ScheduleIterator<'_> {
+    pub fn iter(&self, anchor: FinDate) -> ScheduleIterator<'a> {
         ScheduleIterator {
-            schedule: self,
+            schedule: *self,
+            anchor,
+            end: None,
+        }
+    }
+
+    /// Returns a lazy, unbounded iterator of adjusted dates stepped from
+    /// **nominal** dates, matching [`Schedule::generate`].
+    ///
+    /// The `n`-th item is the anchor moved forward by `n` periods, adjusted
+    /// with the schedule's calendar and rule.  If the adjustment would not move past
+    /// the previously yielded date (or `anchor` for the first item) — as
+    /// [`Preceding`](AdjustRule::Preceding) can when it pulls a date
+    /// backwards — the date is pushed forward day by day until it does, so the
+    /// items are strictly increasing.  Unlike [`Schedule::iter`], an adjusted
+    /// or clamped date never becomes the base of the next step, so neither
+    /// adjustments nor short months accumulate.  The anchor itself is not
+    /// yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal    = basic_calendar();
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 8, 10).unwrap();
+    /// let sched  = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::Preceding));
+    ///
+    /// let dates: Vec<_> = sched.iter_adjusted(anchor).take(4).collect();
+    /// // 10 November is a Sunday and moves back to Friday 8 November, but
+    /// // December is still computed from the anchor
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 11, 8).unwrap());
+    /// assert_eq!(dates[3], NaiveDate::from_ymd_opt(2024, 12, 10).unwrap());
+    /// ```
+    pub fn iter_adjusted(&self, anchor: FinDate) -> AdjustedScheduleIterator<'a> {
+        AdjustedScheduleIterator {
+            schedule: *self,
+            eom: self.rolls_on_month_end(&anchor),
             anchor,
+            periods: 0,
+            last: anchor,
+        }
+    }
+
+    /// Returns the adjusted next date after `anchor`, or `None` if there is
+    /// no next date or it is out of range.
+    ///
+    /// The result is guaranteed to be strictly after `anchor`, even when the
+    /// adjustment rule would move it backwards.  This is the single step taken
+    /// by [`Schedule::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal    = basic_calendar();
+    /// let sched  = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Preceding));
+    /// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    ///
+    /// // Saturday would move back to Friday, so the next date is Monday
+    /// assert_eq!(sched.next_adjusted(friday), NaiveDate::from_ymd_opt(2024, 3, 18));
+    /// ```
+    pub fn next_adjusted(&self, anchor: FinDate) -> Option<FinDate> {
+        let eom = self.rolls_on_month_end(&anchor);
+        let next = self.next_nominal(&anchor, eom)?;
+        force_adjust(&anchor, &next, self.calendar, self.roll_adjust_rule(eom))
+    }
+
+    /// Returns a lazy iterator like [`Schedule::iter`] that stops after
+    /// `end_date`.
+    ///
+    /// Yields the adjusted dates strictly after `start_date` and on or before
+    /// `end_date`; `start_date` itself is not included.  The iterator is empty
+    /// if `end_date <= start_date`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::Frequency;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+    /// let sched = Schedule::new(Frequency::Quarterly, None, None);
+    ///
+    /// let dates: Vec<_> = sched.iter_between(start, end).collect();
+    /// assert_eq!(dates.len(), 2);
+    /// assert_eq!(dates[1], end);
+    /// ```
+    pub fn iter_between(&self, start_date: FinDate, end_date: FinDate) -> ScheduleIterator<'a> {
+        ScheduleIterator {
+            schedule: *self,
+            anchor: start_date,
+            end: Some(end_date),
         }
     }
 
+    /// Returns the first date of the schedule rolled from `anchor` that is
+    /// strictly after `date`, or `None` if it would be out of range.
+    ///
+    /// The schedule's dates are those of [`Schedule::generate`] from
+    /// `anchor`, without an end date: the adjusted anchor, then the anchor
+    /// moved forward by whole periods and adjusted.  Dates are stepped one at
+    /// a time up to `date`, so nothing is collected.  For [`Frequency::Zero`]
+    /// the only date is the anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal    = basic_calendar();
+    /// let anchor = NaiveDate::from_ymd_opt(2020, 3, 16).unwrap();
+    /// let sched  = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+    ///
+    /// // 16 March 2024 is a Saturday
+    /// let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// assert_eq!(sched.next_after(&anchor, &today), NaiveDate::from_ymd_opt(2024, 3, 18));
+    /// ```
+    pub fn next_after(&self, anchor: &FinDate, date: &FinDate) -> Option<FinDate> {
+        self.dates_from(anchor).find(|next| next > date)
+    }
+
+    /// Returns the last date of the schedule rolled from `anchor` that is
+    /// strictly before `date`, or `None` if `date` is on or before the
+    /// adjusted anchor.
+    ///
+    /// Dates are those of [`Schedule::next_after`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::Schedule;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+    /// let sched  = Schedule::new(Frequency::Monthly, None, None);
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+    /// assert_eq!(sched.previous_before(&anchor, &today), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert_eq!(sched.previous_before(&anchor, &anchor), None);
+    /// ```
+    pub fn previous_before(&self, anchor: &FinDate, date: &FinDate) -> Option<FinDate> {
+        self.dates_from(anchor).take_while(|previous| previous < date).last()
+    }
+
+    /// Returns `true` if `date` is a date of the schedule rolled from
+    /// `anchor`, e.g. a coupon date.
+    ///
+    /// Dates are those of [`Schedule::next_after`], so adjusted dates match
+    /// and the unadjusted dates they were moved from do not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal    = basic_calendar();
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    /// let sched  = Schedule::new(Frequency::Semiannual, Some(&cal), Some(AdjustRule::Following));
+    ///
+    /// assert!(sched.contains(&anchor, &NaiveDate::from_ymd_opt(2024, 8, 15).unwrap()));
+    /// // 15 February 2025 is a Saturday, paid on Monday
+    /// assert!(!sched.contains(&anchor, &NaiveDate::from_ymd_opt(2025, 2, 15).unwrap()));
+    /// assert!(sched.contains(&anchor, &NaiveDate::from_ymd_opt(2025, 2, 17).unwrap()));
+    /// ```
+    pub fn contains(&self, anchor: &FinDate, date: &FinDate) -> bool {
+        self.dates_from(anchor).find(|next| next >= date) == Some(*date)
+    }
+
     /// Generates a `Vec` of dates from `anchor_date` to `end_date` inclusive.
     ///
-    /// The anchor date is included as the first element.  Consecutive raw dates
-    /// are stepped by the schedule's frequency through `end_date`, then
-    /// adjusted.  Duplicate dates (which can arise when an adjustment rule moves
-    /// two consecutive raw dates to the same business day) are removed.
+    /// The anchor date is included as the first element.  The `n`-th raw date
+    /// is the anchor moved forward by `n` periods of the schedule's frequency;
+    /// raw dates up to `end_date` are then adjusted.  Duplicate dates (which
+    /// can arise when an adjustment rule moves two consecutive raw dates to the
+    /// same business day) are removed.
     ///
-    /// Stepping uses the **nominal (unadjusted)** date as the anchor for each
-    /// subsequent interval.  This preserves date integrity for fixed-term
-    /// financial instruments: an annual schedule anchored on 4 July will always
-    /// step to 4 July each year before applying the adjustment rule, so a
-    /// Saturday observation (Friday) never causes the next year to step from
-    /// Friday and land on a different nominal date.  Use [`Schedule::iter`] when
-    /// you instead want each step to begin from the previous *adjusted* date.
+    /// Computing every date from the **anchor** preserves date integrity for
+    /// fixed-term financial instruments.  An annual schedule anchored on
+    /// 4 July always lands on 4 July before the adjustment rule is applied, so
+    /// a Saturday observation (Friday) never shifts later years.  A monthly
+    /// schedule anchored on the 31st returns to the 31st after every shorter
+    /// month instead of collapsing to the 30th or 28th.  Use [`Schedule::iter`]
+    /// when you instead want each step to begin from the previous *adjusted*
+    /// date.
     ///
     /// Special case: for [`Frequency::Zero`], returns only the end date (adjusted
     /// if a calendar is set).  This represents the maturity date of a zero-coupon
@@ -138,7 +624,10 @@ impl<'a> Schedule<'a> {
     ///
     /// # Errors
     ///
-    /// Returns `Err` if `end_date <= anchor_date`.
+    /// - [`Err(ScheduleError::InvalidDateRange)`](ScheduleError::InvalidDateRange)
+    ///   if `end_date <= anchor_date`.
+    /// - [`Err(ScheduleError::OutOfRange)`](ScheduleError::OutOfRange) if
+    ///   adjusting a date runs off the representable date range.
     ///
     /// # Examples
     ///
@@ -160,53 +649,1444 @@ impl<'a> Schedule<'a> {
         &self,
         anchor_date: &FinDate,
         end_date: &FinDate,
-    ) -> Result<Vec<FinDate>, &'static str> {
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        let trace = self.generate_with_trace(anchor_date, end_date)?;
+        Ok(trace.into_iter().map(|date| date.adjusted).collect())
+    }
+
+    /// Generates the same dates as [`Schedule::generate`], each with a record
+    /// of how it was reached: the unadjusted date, the adjustment rule applied
+    /// to it and how many days the adjustment moved it.
+    ///
+    /// The unadjusted date is the anchor moved by whole periods, after any
+    /// end-of-month or [`RollConvention`] snapping.  The rule is the
+    /// schedule's [`AdjustRule`], except that dates rolled to month ends under
+    /// the end-of-month convention use [`Preceding`](AdjustRule::Preceding)
+    /// to stay in their month.  When two unadjusted dates adjust to the same
+    /// day only the first is kept, as in `generate`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Err(ScheduleError::InvalidDateRange)`](ScheduleError::InvalidDateRange)
+    ///   if `end_date <= anchor_date`.
+    /// - [`Err(ScheduleError::OutOfRange)`](ScheduleError::OutOfRange) if
+    ///   adjusting a date runs off the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal    = basic_calendar();
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// let sched  = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::Following));
+    ///
+    /// // Why is the March payment on the 18th?  The 16th is a Saturday.
+    /// let trace = sched.generate_with_trace(&anchor, &end).unwrap();
+    /// assert_eq!(trace[2].unadjusted, NaiveDate::from_ymd_opt(2024, 3, 16).unwrap());
+    /// assert_eq!(trace[2].adjusted, NaiveDate::from_ymd_opt(2024, 3, 18).unwrap());
+    /// assert_eq!(trace[2].adjust_rule, Some(AdjustRule::Following));
+    /// assert_eq!(trace[2].adjustment_days, 2);
+    /// ```
+    pub fn generate_with_trace(
+        &self,
+        anchor_date: &FinDate,
+        end_date: &FinDate,
+    ) -> Result<Vec<GeneratedDate>, ScheduleError> {
         if end_date <= anchor_date {
-            return Err("Anchor date must be before end date");
+            return Err(ScheduleError::InvalidDateRange);
         }
 
         // Special case for Frequency::Zero: return only the adjusted end date
         if self.frequency == Frequency::Zero {
-            let adjusted_end = adjust(end_date, self.calendar, self.adjust_rule);
-            return Ok(vec![adjusted_end]);
+            return Ok(vec![self.trace_date(end_date, self.adjust_rule)?]);
         }
 
-        let mut res = vec![adjust(anchor_date, self.calendar, self.adjust_rule)];
-        let mut current = *anchor_date;
-        while let Some(next) = schedule_next(&current, self.frequency) {
+        let eom = self.rolls_on_month_end(anchor_date);
+        let roll_rule = self.roll_adjust_rule(eom);
+        let mut res = vec![self.trace_date(anchor_date, self.adjust_rule)?];
+        let mut periods = 1u32;
+        while let Some(next) = nominal_step(self, anchor_date, periods, true, eom) {
             if next > *end_date {
                 break;
             }
 
-            res.push(adjust(&next, self.calendar, self.adjust_rule));
-            current = next;
+            res.push(self.trace_date(&next, roll_rule)?);
+            periods = match periods.checked_add(1) {
+                Some(p) => p,
+                None => break,
+            };
         }
-        res.dedup();
+        res.dedup_by_key(|date| date.adjusted);
         Ok(res)
     }
-}
 
-// Guarantees the adjusted result is strictly after `anchor_date`.
-//
-// Some adjustment rules (Preceding, ModFollowing, Nearest) can move a date
-// backwards past the anchor. When that happens this function keeps nudging
-// the candidate forward by one calendar day at a time until the adjusted
-// result clears the anchor. Returns None if the search walks off the end of
-// the representable NaiveDate range.
-fn force_adjust(
-    anchor_date: &FinDate,
-    next_date: &FinDate,
-    opt_calendar: Option<&Calendar>,
-    opt_adjust_rule: Option<AdjustRule>,
-) -> Option<FinDate> {
-    let mut res = algebra::adjust(next_date, opt_calendar, opt_adjust_rule);
-    let mut day_i = 1u64;
-    while res <= *anchor_date {
-        let candidate = next_date.checked_add_days(Days::new(day_i))?;
-        res = algebra::adjust(&candidate, opt_calendar, opt_adjust_rule);
-        day_i += 1;
-    }
-    Some(res)
+    /// Returns a lazy iterator that walks backwards in time from `anchor`.
+    ///
+    /// The `k`-th value is `anchor` moved back by `k` periods of the
+    /// schedule's frequency, then rolled and adjusted.  Stepping from the
+    /// anchor rather than from the previous date keeps day-of-month
+    /// information: rolling back quarterly from 31 March gives 31 December,
+    /// 30 September, 30 June, then 31 March again.  Dates are strictly
+    /// decreasing and strictly before `anchor`; an adjusted date that would
+    /// not be is skipped.  For [`Frequency::Zero`] the iterator is
+    /// immediately exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::Frequency;
+    ///
+    /// let maturity = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    /// let sched    = Schedule::new(Frequency::Quarterly, None, None);
+    ///
+    /// let dates: Vec<_> = sched.iter_backward(maturity).take(3).collect();
+    /// assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 9, 30).unwrap());
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+    /// ```
+    pub fn iter_backward(&self, anchor: FinDate) -> BackwardScheduleIterator<'a> {
+        BackwardScheduleIterator {
+            schedule: *self,
+            anchor,
+            eom: self.rolls_on_month_end(&anchor),
+            periods: 0,
+            last: anchor,
+            done: false,
+        }
+    }
+
+    /// Generates a `Vec` of dates rolled backwards from `termination_date`,
+    /// returned in ascending order.
+    ///
+    /// Dates are `termination_date` minus whole periods of the schedule's
+    /// frequency (see [`Schedule::iter_backward`]), kept while they fall
+    /// after `effective_date`.  The effective date is then added as the first
+    /// element, so any short stub sits at the front of the schedule.  All
+    /// dates are adjusted and duplicates are removed.
+    ///
+    /// The end-of-month convention ([`Schedule::eom`]) is decided by the
+    /// termination date rather than the effective date.
+    ///
+    /// Special case: for [`Frequency::Zero`], returns only the termination
+    /// date (adjusted if a calendar is set), like [`Schedule::generate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(ScheduleError::InvalidDateRange)`](ScheduleError::InvalidDateRange)
+    /// if `termination_date <= effective_date`, and
+    /// [`Err(ScheduleError::OutOfRange)`](ScheduleError::OutOfRange) if
+    /// adjusting either date runs off the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::schedule::Schedule;
+    /// use findates::conventions::Frequency;
+    ///
+    /// let effective   = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let termination = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    /// let sched       = Schedule::new(Frequency::Semiannual, None, None);
+    ///
+    /// let dates = sched.generate_backward(&effective, &termination).unwrap();
+    /// // Short front stub from 10 Feb to 15 Jun 2024
+    /// assert_eq!(dates[0], effective);
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 6, 15).unwrap());
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 12, 15).unwrap());
+    /// assert_eq!(dates[3], termination);
+    /// ```
+    pub fn generate_backward(
+        &self,
+        effective_date: &FinDate,
+        termination_date: &FinDate,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        if termination_date <= effective_date {
+            return Err(ScheduleError::InvalidDateRange);
+        }
+
+        let adjust_or_err = |date: &FinDate| {
+            algebra::checked_adjust(date, self.calendar, self.adjust_rule).ok_or(ScheduleError::OutOfRange)
+        };
+        let adjusted_end = adjust_or_err(termination_date)?;
+        if self.frequency == Frequency::Zero {
+            return Ok(vec![adjusted_end]);
+        }
+
+        let adjusted_start = adjust_or_err(effective_date)?;
+        let mut rolled: Vec<FinDate> = self
+            .iter_backward(*termination_date)
+            .take_while(|date| *date > adjusted_start)
+            .collect();
+        rolled.reverse();
+
+        let mut res = vec![adjusted_start];
+        res.extend(rolled);
+        res.push(adjusted_end);
+        res.dedup();
+        Ok(res)
+    }
+
+    /// Builds the coupon periods of an instrument running from
+    /// `effective_date` to `maturity_date`.
+    ///
+    /// Accrual boundaries are the effective date, the effective date plus
+    /// whole periods of the schedule's frequency, and the maturity date, all
+    /// adjusted with the schedule's calendar and rule.  When the maturity is
+    /// not on that grid the last period is a short back stub.  A front
+    /// [`StubRule`] rolls the grid back from the maturity date instead, and a
+    /// long one merges the stub into its neighbour (see
+    /// [`Schedule::with_stub`]).  For [`Frequency::Zero`] there is a single
+    /// period.
+    ///
+    /// Each period's payment date is `pay_lag` business days after its
+    /// adjusted accrual end, rolled forward to a business day (see
+    /// [`Schedule::payment_dates`]); without a calendar the lag counts
+    /// calendar days.  The year fraction uses
+    /// `daycount` over the accrual dates, rounded by the schedule's
+    /// [`rounding`](Schedule::rounding) if it has one.  For
+    /// [`ActActICMA`](DayCount::ActActICMA), regular periods are their own
+    /// reference period, a back stub uses the full regular period that would
+    /// have started on its accrual start, and a front stub the one that would
+    /// have ended on its accrual end.
+    ///
+    /// Use [`AdjustRule::Unadjusted`] with a calendar for instruments whose
+    /// accrual dates are unadjusted but whose payments move to business days,
+    /// such as U.S. Treasury notes.
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `maturity_date <= effective_date`.
+    /// - [`ScheduleError::OutOfRange`] if an accrual or payment date falls
+    ///   outside the representable date range.
+    /// - [`ScheduleError::DayCount`] if a year fraction cannot be computed,
+    ///   e.g. [`Bd252`](DayCount::Bd252) without a calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, DayCount, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal      = basic_calendar();
+    /// let issue    = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    /// let maturity = NaiveDate::from_ymd_opt(2025, 8, 15).unwrap();
+    /// let sched    = Schedule::new(Frequency::Semiannual, Some(&cal), Some(AdjustRule::Unadjusted));
+    ///
+    /// let periods = sched.coupon_periods(&issue, &maturity, DayCount::D30360Euro, 0).unwrap();
+    /// assert_eq!(periods.len(), 4);
+    /// assert_eq!(periods[2].accrual_end, NaiveDate::from_ymd_opt(2025, 2, 15).unwrap());
+    /// // 15 February 2025 is a Saturday, so the coupon is paid on Monday
+    /// assert_eq!(periods[2].payment_date, NaiveDate::from_ymd_opt(2025, 2, 17).unwrap());
+    /// assert_eq!(periods[2].year_fraction, 0.5);
+    /// ```
+    pub fn coupon_periods(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        daycount: DayCount,
+        pay_lag: u32,
+    ) -> Result<Vec<CouponPeriod>, ScheduleError> {
+        let AccrualDates { dates, front_reference, back_reference } =
+            self.accrual_dates(effective_date, maturity_date)?;
+        let last = dates.len() - 1;
+        let mut res = Vec::with_capacity(last);
+        for (i, pair) in dates.windows(2).enumerate() {
+            let (accrual_start, accrual_end) = (pair[0], pair[1]);
+            let is_termination_date = i + 1 == last;
+            let reference_start = match front_reference {
+                Some(start) if i == 0 => start,
+                _ => accrual_start,
+            };
+            let reference_end = match back_reference {
+                Some(end) if is_termination_date => end,
+                _ => accrual_end,
+            };
+            let context = DayCountContext {
+                reference_period: (self.frequency != Frequency::Zero).then_some(ReferencePeriod {
+                    start: reference_start,
+                    end: reference_end,
+                    frequency: self.frequency,
+                }),
+                is_termination_date,
+                rounding: self.rounding,
+            };
+            let year_fraction = algebra::day_count_fraction_with_context(
+                &accrual_start,
+                &accrual_end,
+                daycount,
+                &context,
+                self.calendar,
+                Some(AdjustRule::Unadjusted),
+            )?;
+            res.push(CouponPeriod {
+                accrual_start,
+                accrual_end,
+                payment_date: self.payment_date(&accrual_end, pay_lag)?,
+                year_fraction,
+            });
+        }
+        Ok(res)
+    }
+
+    /// Returns the payment date of each accrual period from `effective_date`
+    /// to `maturity_date`, paid `pay_lag` business days after the period end.
+    ///
+    /// Accrual periods are built exactly as in [`Schedule::coupon_periods`],
+    /// so these are the `payment_date`s of the coupon periods without
+    /// computing year fractions.  With a calendar each date is the
+    /// [`settlement_date`](algebra::settlement_date) of its adjusted accrual
+    /// end; without one the lag counts calendar days.
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `maturity_date <= effective_date`.
+    /// - [`ScheduleError::OutOfRange`] if a date falls outside the
+    ///   representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal       = basic_calendar();
+    /// let effective = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 4, 10).unwrap();
+    /// let sched     = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::ModFollowing));
+    ///
+    /// let payments = sched.payment_dates(&effective, &maturity, 2).unwrap();
+    /// assert_eq!(payments.len(), 3);
+    /// // 10 February 2024 is a Saturday: accrual ends Monday 12th, paid T+2
+    /// assert_eq!(payments[0], NaiveDate::from_ymd_opt(2024, 2, 14).unwrap());
+    /// ```
+    pub fn payment_dates(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        pay_lag: u32,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        let dates = self.accrual_dates(effective_date, maturity_date)?.dates;
+        dates[1..]
+            .iter()
+            .map(|end| self.payment_date(end, pay_lag))
+            .collect()
+    }
+
+    /// Returns the fixing (rate reset) date of each accrual period from
+    /// `effective_date` to `maturity_date`: the period's start moved back
+    /// `fixing_lag` business days in `fixing_calendar`.
+    ///
+    /// Accrual periods are built exactly as in [`Schedule::coupon_periods`],
+    /// so the `i`-th fixing date resets the rate of the `i`-th coupon period.
+    /// An accrual start that is not a business day in `fixing_calendar` is
+    /// first moved to the preceding one.  Use a lag of 2 with a London
+    /// calendar for LIBOR-style fixings, and 0 for rates fixed on the
+    /// period start itself.
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `maturity_date <= effective_date`.
+    /// - [`ScheduleError::OutOfRange`] if a date falls outside the
+    ///   representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal       = basic_calendar();
+    /// let mut london = basic_calendar();
+    /// london.add_holidays([NaiveDate::from_ymd_opt(2024, 5, 6).unwrap()]); // Early May bank holiday
+    /// let effective = NaiveDate::from_ymd_opt(2024, 2, 8).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 8, 8).unwrap();
+    /// let sched     = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+    ///
+    /// let fixings = sched.fixing_dates(&effective, &maturity, 2, &london).unwrap();
+    /// assert_eq!(fixings, [
+    ///     NaiveDate::from_ymd_opt(2024, 2, 6).unwrap(),
+    ///     // Two London days before Wednesday 8 May skips the bank holiday
+    ///     NaiveDate::from_ymd_opt(2024, 5, 3).unwrap(),
+    /// ]);
+    /// ```
+    pub fn fixing_dates(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        fixing_lag: u32,
+        fixing_calendar: &Calendar,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        let dates = self.accrual_dates(effective_date, maturity_date)?.dates;
+        dates[..dates.len() - 1]
+            .iter()
+            .map(|start| fixing_date(start, fixing_lag, fixing_calendar))
+            .collect()
+    }
+
+    // Adjusted accrual boundaries from the effective date to the maturity
+    // date, with the stub placed by the stub rule and the adjusted far end of
+    // the regular period it was cut from.
+    fn accrual_dates(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+    ) -> Result<AccrualDates, ScheduleError> {
+        if maturity_date <= effective_date {
+            return Err(ScheduleError::InvalidDateRange);
+        }
+
+        // Front stubs roll back from the maturity date, back stubs forward
+        // from the effective date.
+        let stub = self.stub.unwrap_or(StubRule::ShortBack);
+        let (seed, far_end) = if stub.is_front() {
+            (maturity_date, effective_date)
+        } else {
+            (effective_date, maturity_date)
+        };
+        let eom = self.rolls_on_month_end(seed);
+        let roll_rule = self.roll_adjust_rule(eom);
+
+        // Nominal grid strictly between the two dates, and the first step
+        // past it when that step misses the far end (a stub)
+        let mut grid = vec![];
+        let mut stub_cut = None;
+        if self.frequency != Frequency::Zero {
+            for periods in 1u32.. {
+                match nominal_step(self, seed, periods, !stub.is_front(), eom) {
+                    Some(next) if effective_date < &next && &next < maturity_date => grid.push(next),
+                    next => {
+                        stub_cut = next.filter(|next| next != far_end);
+                        break;
+                    }
+                }
+            }
+            if stub.is_long() && stub_cut.is_some() && !grid.is_empty() {
+                stub_cut = grid.pop();
+            }
+            if stub.is_front() {
+                grid.reverse();
+            }
+        }
+
+        let adjust_or_err = |date: &FinDate, rule: Option<AdjustRule>| {
+            algebra::checked_adjust(date, self.calendar, rule).ok_or(ScheduleError::OutOfRange)
+        };
+        let reference = stub_cut.map(|date| adjust_or_err(&date, roll_rule)).transpose()?;
+        let mut dates = vec![adjust_or_err(effective_date, self.adjust_rule)?];
+        for date in grid.iter().chain(std::iter::once(maturity_date)) {
+            dates.push(adjust_or_err(date, roll_rule)?);
+        }
+        dates.dedup();
+        Ok(AccrualDates {
+            dates,
+            front_reference: reference.filter(|_| stub.is_front()),
+            back_reference: reference.filter(|_| !stub.is_front()),
+        })
+    }
+
+    // Unbounded dates of `generate` from `anchor`, in non-decreasing order.
+    // Ends at the first date that cannot be adjusted within the
+    // representable date range.
+    fn dates_from<'s>(&'s self, anchor: &'s FinDate) -> impl Iterator<Item = FinDate> + 's {
+        let eom = self.rolls_on_month_end(anchor);
+        let roll_rule = self.roll_adjust_rule(eom);
+        let steps = match self.frequency {
+            Frequency::Zero => 0,
+            _ => u32::MAX,
+        };
+        std::iter::once((*anchor, self.adjust_rule))
+            .chain(
+                (1..=steps)
+                    .map_while(move |periods| nominal_step(self, anchor, periods, true, eom))
+                    .map(move |next| (next, roll_rule)),
+            )
+            .map_while(move |(date, rule)| algebra::checked_adjust(&date, self.calendar, rule))
+    }
+
+    // Payment date `pay_lag` business days after `accrual_end`.
+    fn payment_date(&self, accrual_end: &FinDate, pay_lag: u32) -> Result<FinDate, ScheduleError> {
+        match self.calendar {
+            Some(cal) => algebra::settlement_date(accrual_end, pay_lag, cal, None),
+            None => accrual_end.checked_add_days(Days::new(pay_lag as u64)),
+        }
+        .ok_or(ScheduleError::OutOfRange)
+    }
+
+    // Roll convention in effect for this schedule's frequency, if any.
+    fn active_roll(&self) -> Option<RollConvention> {
+        self.roll.filter(|roll| roll_applies(roll, self.frequency))
+    }
+
+    // True if dates rolled from `anchor` land on month ends, either through
+    // the end-of-month convention or an explicit EndOfMonth roll.
+    fn rolls_on_month_end(&self, anchor: &FinDate) -> bool {
+        if self.roll.is_some() {
+            return self.active_roll() == Some(RollConvention::EndOfMonth);
+        }
+        self.eom
+            && frequency_months(self.frequency).is_some()
+            && (algebra::is_end_of_month(anchor)
+                || self
+                    .calendar
+                    .is_some_and(|cal| algebra::is_last_business_day_of_month(anchor, cal)))
+    }
+
+    // Adjustment rule for rolled dates: month-end rolls stay on the last
+    // business day of the month.
+    fn roll_adjust_rule(&self, eom: bool) -> Option<AdjustRule> {
+        match self.adjust_rule {
+            Some(rule) if eom && self.calendar.is_some() && rule != AdjustRule::Unadjusted => {
+                Some(AdjustRule::Preceding)
+            }
+            rule => rule,
+        }
+    }
+
+    // `unadjusted` adjusted with `rule`, recording how it got there.
+    fn trace_date(&self, unadjusted: &FinDate, rule: Option<AdjustRule>) -> Result<GeneratedDate, ScheduleError> {
+        let adjusted = algebra::checked_adjust(unadjusted, self.calendar, rule).ok_or(ScheduleError::OutOfRange)?;
+        Ok(GeneratedDate {
+            unadjusted: *unadjusted,
+            adjusted,
+            adjust_rule: self.calendar.and(rule),
+            adjustment_days: (adjusted - *unadjusted).num_days(),
+        })
+    }
+
+    // Raw unadjusted next date, snapped to the month end when `eom` applies
+    // and pinned to the roll convention when one is active.
+    fn next_nominal(&self, current: &FinDate, eom: bool) -> Option<FinDate> {
+        match (frequency_months(self.frequency), self.active_roll()) {
+            (Some(months), _) if eom => {
+                let stepped = current.checked_add_months(Months::new(months))?;
+                algebra::last_day_of_month(stepped.year(), stepped.month())
+            }
+            (Some(months), Some(roll)) => {
+                self.roll_date(&roll, &current.checked_add_months(Months::new(months))?)
+            }
+            // Step from the roll weekday nearest `current`, so a date the
+            // calendar moved off its weekday does not skip or repeat a week.
+            (None, Some(roll @ RollConvention::DayOfWeek(weekday))) => {
+                roll.apply(&schedule_next(&nearest_weekday(current, weekday)?, self.frequency)?)
+            }
+            (None, Some(roll)) => self.roll_date(&roll, &schedule_next(current, self.frequency)?),
+            _ => schedule_next(current, self.frequency),
+        }
+    }
+
+    // `date` pinned to `roll`, counting business days in the schedule's
+    // calendar when it has one.
+    fn roll_date(&self, roll: &RollConvention, date: &FinDate) -> Option<FinDate> {
+        match self.calendar {
+            Some(cal) => roll.apply_in(date, cal),
+            None => roll.apply(date),
+        }
+    }
+}
+
+/// Builds a [`Schedule`] option by option, checking that the options fit
+/// together.
+///
+/// Created by [`Schedule::builder`].  Only the frequency is required.
+/// [`ScheduleBuilder::build`] rejects combinations that [`Schedule::new`] and
+/// the `with_*` methods accept but silently ignore.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use findates::conventions::{Frequency, RollConvention};
+/// use findates::schedule::Schedule;
+/// use findates::ScheduleBuildError;
+///
+/// let err = Schedule::builder()
+///     .frequency(Frequency::Monthly)
+///     .roll(RollConvention::DayOfWeek(Weekday::Fri))
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(
+///     err,
+///     ScheduleBuildError::IncompatibleRoll {
+///         roll:      RollConvention::DayOfWeek(Weekday::Fri),
+///         frequency: Frequency::Monthly,
+///     }
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScheduleBuilder<'a> {
+    frequency: Option<Frequency>,
+    calendar: Option<&'a Calendar>,
+    adjust_rule: Option<AdjustRule>,
+    eom: bool,
+    roll: Option<RollConvention>,
+    stub: Option<StubRule>,
+    rounding: Option<Rounding>,
+}
+
+impl<'a> ScheduleBuilder<'a> {
+    /// Sets the step frequency between consecutive dates.  Required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::Schedule;
+    ///
+    /// let sched = Schedule::builder().frequency(Frequency::Quarterly).build().unwrap();
+    /// assert_eq!(sched.frequency, Frequency::Quarterly);
+    /// ```
+    pub fn frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Sets the calendar used to adjust each generated date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::Schedule;
+    ///
+    /// let cal   = basic_calendar();
+    /// let sched = Schedule::builder().frequency(Frequency::Monthly).calendar(&cal).build().unwrap();
+    /// assert_eq!(sched.calendar, Some(&cal));
+    /// ```
+    pub fn calendar(mut self, calendar: &'a Calendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Sets the adjustment rule for dates that are not business days.
+    /// Requires a calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::Schedule;
+    /// use findates::ScheduleBuildError;
+    ///
+    /// let builder = Schedule::builder().frequency(Frequency::Monthly).adjust_rule(AdjustRule::Following);
+    /// assert_eq!(builder.build(), Err(ScheduleBuildError::AdjustRuleWithoutCalendar));
+    /// ```
+    pub fn adjust_rule(mut self, adjust_rule: AdjustRule) -> Self {
+        self.adjust_rule = Some(adjust_rule);
+        self
+    }
+
+    /// Switches the end-of-month roll convention on or off.  See
+    /// [`Schedule::with_eom`].  Requires a month-based frequency and no
+    /// [`roll`](ScheduleBuilder::roll).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::Schedule;
+    /// use findates::ScheduleBuildError;
+    ///
+    /// let builder = Schedule::builder().frequency(Frequency::Weekly).eom(true);
+    /// assert_eq!(builder.build(), Err(ScheduleBuildError::IncompatibleEom { frequency: Frequency::Weekly }));
+    /// ```
+    pub fn eom(mut self, eom: bool) -> Self {
+        self.eom = eom;
+        self
+    }
+
+    /// Pins every rolled date to `roll`.  See [`Schedule::with_roll`].
+    /// Weekday rolls require a weekly frequency and the others a
+    /// month-based one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, RollConvention};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let sched = Schedule::builder().frequency(Frequency::Quarterly).roll(RollConvention::Imm).build().unwrap();
+    /// assert_eq!(sched.roll(), Some(RollConvention::Imm));
+    /// ```
+    pub fn roll(mut self, roll: RollConvention) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+
+    /// Places the irregular coupon period.  See [`Schedule::with_stub`].
+    /// Requires a frequency other than [`Frequency::Zero`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, StubRule};
+    /// use findates::schedule::Schedule;
+    /// use findates::ScheduleBuildError;
+    ///
+    /// let builder = Schedule::builder().frequency(Frequency::Zero).stub(StubRule::ShortFront);
+    /// assert_eq!(builder.build(), Err(ScheduleBuildError::IncompatibleStub { frequency: Frequency::Zero }));
+    /// ```
+    pub fn stub(mut self, stub: StubRule) -> Self {
+        self.stub = Some(stub);
+        self
+    }
+
+    /// Rounds the year fraction of each coupon period.  See
+    /// [`Schedule::with_rounding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, Rounding, RoundingDirection};
+    /// use findates::schedule::Schedule;
+    ///
+    /// let rounding = Rounding::new(6, RoundingDirection::Down);
+    /// let sched = Schedule::builder().frequency(Frequency::Monthly).rounding(rounding).build().unwrap();
+    /// assert_eq!(sched.rounding(), Some(rounding));
+    /// ```
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Returns the [`Schedule`], or the first problem found with its options.
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleBuildError::MissingFrequency`] if no frequency was set.
+    /// - [`ScheduleBuildError::ZeroLengthFrequency`] if the frequency is a
+    ///   parametric one with a count of zero.
+    /// - [`ScheduleBuildError::AdjustRuleWithoutCalendar`] if an adjustment
+    ///   rule was set without a calendar.
+    /// - [`ScheduleBuildError::ConflictingRoll`] if both
+    ///   [`eom`](ScheduleBuilder::eom) and a roll convention were set.
+    /// - [`ScheduleBuildError::IncompatibleEom`] if `eom` was set on a
+    ///   frequency that is not a whole number of months.
+    /// - [`ScheduleBuildError::IncompatibleRoll`] if the roll convention does
+    ///   not apply to the frequency.
+    /// - [`ScheduleBuildError::IncompatibleStub`] if a stub rule was set on
+    ///   [`Frequency::Zero`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::conventions::{Frequency, RollConvention};
+    /// use findates::schedule::Schedule;
+    /// use findates::ScheduleBuildError;
+    ///
+    /// let builder = Schedule::builder().frequency(Frequency::Monthly).eom(true).roll(RollConvention::Day(15));
+    /// assert_eq!(builder.build(), Err(ScheduleBuildError::ConflictingRoll));
+    /// assert_eq!(Schedule::builder().build(), Err(ScheduleBuildError::MissingFrequency));
+    /// ```
+    pub fn build(self) -> Result<Schedule<'a>, ScheduleBuildError> {
+        let frequency = self.frequency.ok_or(ScheduleBuildError::MissingFrequency)?;
+        if let Frequency::EveryNMonths(0) | Frequency::EveryNWeeks(0) | Frequency::EveryNDays(0) = frequency {
+            return Err(ScheduleBuildError::ZeroLengthFrequency { frequency });
+        }
+        if self.adjust_rule.is_some() && self.calendar.is_none() {
+            return Err(ScheduleBuildError::AdjustRuleWithoutCalendar);
+        }
+        if self.eom && self.roll.is_some() {
+            return Err(ScheduleBuildError::ConflictingRoll);
+        }
+        if self.eom && frequency_months(frequency).is_none() {
+            return Err(ScheduleBuildError::IncompatibleEom { frequency });
+        }
+        if let Some(roll) = self.roll.filter(|roll| !roll_applies(roll, frequency)) {
+            return Err(ScheduleBuildError::IncompatibleRoll { roll, frequency });
+        }
+        if self.stub.is_some() && frequency == Frequency::Zero {
+            return Err(ScheduleBuildError::IncompatibleStub { frequency });
+        }
+        Ok(Schedule {
+            frequency,
+            calendar: self.calendar,
+            adjust_rule: self.adjust_rule,
+            eom: self.eom,
+            roll: self.roll,
+            stub: self.stub,
+            rounding: self.rounding,
+        })
+    }
+}
+
+/// An owned, serializable counterpart of [`Schedule`].
+///
+/// [`Schedule`] borrows its calendar, so it cannot be deserialized or stored
+/// without the calendar it points to.  `ScheduleOwned` holds the calendar in
+/// an [`Arc`], so it has no lifetime: it can live in long-lived structs, be
+/// cloned cheaply and be sent to other threads, and many schedules can share
+/// one calendar.  It has the same date generation methods as [`Schedule`],
+/// and [`ScheduleOwned::as_schedule`] borrows it back as one.
+///
+/// With the `serde` feature enabled it derives `Serialize` and `Deserialize`
+/// so a schedule definition can be persisted or sent between services.  Both
+/// types serialize to the same shape, so a serialized [`Schedule`]
+/// deserializes as a `ScheduleOwned`.  Each deserialized schedule gets its
+/// own copy of the calendar.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{AdjustRule, Frequency};
+/// use findates::schedule::{Schedule, ScheduleOwned};
+///
+/// let cal   = basic_calendar();
+/// let sched = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+/// let owned = ScheduleOwned::from(&sched);
+///
+/// let anchor = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let end    = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+/// let handle = std::thread::spawn(move || owned.generate(&anchor, &end));
+/// assert_eq!(handle.join().unwrap(), sched.generate(&anchor, &end));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleOwned {
+    /// The step frequency between consecutive dates.
+    pub frequency: Frequency,
+    /// Optional calendar used to adjust each generated date, shared with
+    /// other schedules holding the same [`Arc`].
+    pub calendar: Option<Arc<Calendar>>,
+    /// Optional adjustment rule applied when a date falls on a non-business day.
+    pub adjust_rule: Option<AdjustRule>,
+    /// End-of-month roll convention.  See [`Schedule::eom`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub eom: bool,
+    /// Optional roll convention.  See [`Schedule::roll`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub roll: Option<RollConvention>,
+    /// Optional stub rule.  See [`Schedule::stub`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stub: Option<StubRule>,
+    /// Optional year fraction rounding.  See [`Schedule::rounding`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rounding: Option<Rounding>,
+}
+
+impl ScheduleOwned {
+    /// Creates a new [`ScheduleOwned`], sharing `opt_calendar` with any other
+    /// holders of the [`Arc`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let cal       = Arc::new(basic_calendar());
+    /// let quarterly = ScheduleOwned::new(Frequency::Quarterly, Some(cal.clone()), Some(AdjustRule::ModFollowing));
+    /// let monthly   = ScheduleOwned::new(Frequency::Monthly, Some(cal.clone()), Some(AdjustRule::Following));
+    /// assert_eq!(Arc::strong_count(&cal), 3);
+    /// assert_eq!(quarterly.calendar, monthly.calendar);
+    /// ```
+    pub fn new(
+        frequency: Frequency,
+        opt_calendar: Option<Arc<Calendar>>,
+        opt_adjust_rule: Option<AdjustRule>,
+    ) -> Self {
+        Self {
+            frequency,
+            calendar: opt_calendar,
+            adjust_rule: opt_adjust_rule,
+            eom: false,
+            roll: None,
+            stub: None,
+            rounding: None,
+        }
+    }
+
+    /// Returns the schedule with the end-of-month roll convention switched on
+    /// or off.  See [`Schedule::with_eom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2023, 5, 31).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Quarterly, None, None).with_eom(true);
+    /// assert_eq!(sched.generate(&anchor, &end).unwrap(), [anchor, end]);
+    /// ```
+    pub fn with_eom(mut self, eom: bool) -> Self {
+        self.eom = eom;
+        self
+    }
+
+    /// Returns the schedule with every date pinned to `roll`.  See
+    /// [`Schedule::with_roll`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{Frequency, RollConvention};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Monthly, None, None).with_roll(RollConvention::Day(20));
+    /// assert_eq!(sched.next_adjusted(anchor), NaiveDate::from_ymd_opt(2024, 2, 20));
+    /// ```
+    pub fn with_roll(mut self, roll: RollConvention) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+
+    /// Returns the schedule with its irregular coupon period placed by
+    /// `stub`.  See [`Schedule::with_stub`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{Frequency, StubRule};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let effective = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    /// let sched     = ScheduleOwned::new(Frequency::Semiannual, None, None).with_stub(StubRule::ShortFront);
+    /// let payments  = sched.payment_dates(&effective, &maturity, 0).unwrap();
+    /// assert_eq!(payments[0], NaiveDate::from_ymd_opt(2024, 6, 15).unwrap());
+    /// ```
+    pub fn with_stub(mut self, stub: StubRule) -> Self {
+        self.stub = Some(stub);
+        self
+    }
+
+    /// Returns the schedule with coupon period year fractions rounded by
+    /// `rounding`.  See [`Schedule::with_rounding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{DayCount, Frequency, Rounding, RoundingDirection};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 4, 15).unwrap();
+    /// let sched     = ScheduleOwned::new(Frequency::Quarterly, None, None)
+    ///     .with_rounding(Rounding::new(2, RoundingDirection::Up));
+    /// let periods   = sched.coupon_periods(&effective, &maturity, DayCount::Act365Fixed, 0).unwrap();
+    /// assert_eq!(periods[0].year_fraction, 0.25);
+    /// ```
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Returns a [`Schedule`] borrowing this schedule's calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let owned = ScheduleOwned {
+    ///     frequency:   Frequency::Monthly,
+    ///     calendar:    Some(Arc::new(basic_calendar())),
+    ///     adjust_rule: None,
+    ///     eom:         false,
+    ///     roll:        None,
+    ///     stub:        None,
+    ///     rounding:    None,
+    /// };
+    /// let sched = owned.as_schedule();
+    /// assert_eq!(sched.calendar, owned.calendar.as_deref());
+    /// ```
+    pub fn as_schedule(&self) -> Schedule<'_> {
+        Schedule {
+            frequency: self.frequency,
+            calendar: self.calendar.as_deref(),
+            adjust_rule: self.adjust_rule,
+            eom: self.eom,
+            roll: self.roll,
+            stub: self.stub,
+            rounding: self.rounding,
+        }
+    }
+
+    /// Lazy iterator stepping from the previous adjusted date.  See
+    /// [`Schedule::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Monthly, None, None);
+    /// assert_eq!(sched.iter(anchor).nth(1), NaiveDate::from_ymd_opt(2024, 3, 29));
+    /// ```
+    pub fn iter(&self, anchor: FinDate) -> ScheduleIterator<'_> {
+        self.as_schedule().iter(anchor)
+    }
+
+    /// Lazy iterator of adjusted dates stepped from nominal dates.  See
+    /// [`Schedule::iter_adjusted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Monthly, None, None);
+    /// assert_eq!(sched.iter_adjusted(anchor).nth(1), NaiveDate::from_ymd_opt(2024, 3, 31));
+    /// ```
+    pub fn iter_adjusted(&self, anchor: FinDate) -> AdjustedScheduleIterator<'_> {
+        self.as_schedule().iter_adjusted(anchor)
+    }
+
+    /// Returns the adjusted next date after `anchor`.  See
+    /// [`Schedule::next_adjusted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let sched  = ScheduleOwned::new(Frequency::Daily, Some(Arc::new(basic_calendar())), Some(AdjustRule::Following));
+    /// let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    /// assert_eq!(sched.next_adjusted(friday), NaiveDate::from_ymd_opt(2024, 3, 18));
+    /// ```
+    pub fn next_adjusted(&self, anchor: FinDate) -> Option<FinDate> {
+        self.as_schedule().next_adjusted(anchor)
+    }
+
+    /// Lazy iterator like [`ScheduleOwned::iter`] that stops after
+    /// `end_date`.  See [`Schedule::iter_between`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let end   = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+    /// let sched = ScheduleOwned::new(Frequency::Quarterly, None, None);
+    /// assert_eq!(sched.iter_between(start, end).count(), 2);
+    /// ```
+    pub fn iter_between(&self, start_date: FinDate, end_date: FinDate) -> ScheduleIterator<'_> {
+        self.as_schedule().iter_between(start_date, end_date)
+    }
+
+    /// Returns the first schedule date strictly after `date`.  See
+    /// [`Schedule::next_after`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2020, 1, 15).unwrap();
+    /// let today  = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Quarterly, None, None);
+    /// assert_eq!(sched.next_after(&anchor, &today), NaiveDate::from_ymd_opt(2024, 7, 15));
+    /// ```
+    pub fn next_after(&self, anchor: &FinDate, date: &FinDate) -> Option<FinDate> {
+        self.as_schedule().next_after(anchor, date)
+    }
+
+    /// Returns the last schedule date strictly before `date`.  See
+    /// [`Schedule::previous_before`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2020, 1, 15).unwrap();
+    /// let today  = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Quarterly, None, None);
+    /// assert_eq!(sched.previous_before(&anchor, &today), NaiveDate::from_ymd_opt(2024, 4, 15));
+    /// ```
+    pub fn previous_before(&self, anchor: &FinDate, date: &FinDate) -> Option<FinDate> {
+        self.as_schedule().previous_before(anchor, date)
+    }
+
+    /// Returns `true` if `date` is a schedule date.  See
+    /// [`Schedule::contains`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2020, 1, 15).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Quarterly, None, None);
+    /// assert!(sched.contains(&anchor, &NaiveDate::from_ymd_opt(2024, 10, 15).unwrap()));
+    /// ```
+    pub fn contains(&self, anchor: &FinDate, date: &FinDate) -> bool {
+        self.as_schedule().contains(anchor, date)
+    }
+
+    /// Generates the dates from `anchor_date` to `end_date` inclusive.  See
+    /// [`Schedule::generate`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `end_date <= anchor_date`.
+    /// - [`ScheduleError::OutOfRange`] if adjusting a date runs off the
+    ///   representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Semiannual, None, None);
+    /// assert_eq!(sched.generate(&anchor, &end).unwrap().len(), 3);
+    /// ```
+    pub fn generate(
+        &self,
+        anchor_date: &FinDate,
+        end_date: &FinDate,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        self.as_schedule().generate(anchor_date, end_date)
+    }
+
+    /// Generates the dates with the adjustment behind each one.  See
+    /// [`Schedule::generate_with_trace`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `end_date <= anchor_date`.
+    /// - [`ScheduleError::OutOfRange`] if adjusting a date runs off the
+    ///   representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+    /// let end    = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Monthly, Some(Arc::new(basic_calendar())), Some(AdjustRule::Following));
+    /// assert_eq!(sched.generate_with_trace(&anchor, &end).unwrap()[2].adjustment_days, 2);
+    /// ```
+    pub fn generate_with_trace(
+        &self,
+        anchor_date: &FinDate,
+        end_date: &FinDate,
+    ) -> Result<Vec<GeneratedDate>, ScheduleError> {
+        self.as_schedule().generate_with_trace(anchor_date, end_date)
+    }
+
+    /// Lazy iterator walking backwards from `anchor`.  See
+    /// [`Schedule::iter_backward`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let sched  = ScheduleOwned::new(Frequency::Annual, None, None);
+    /// assert_eq!(sched.iter_backward(anchor).next(), NaiveDate::from_ymd_opt(2023, 1, 1));
+    /// ```
+    pub fn iter_backward(&self, anchor: FinDate) -> BackwardScheduleIterator<'_> {
+        self.as_schedule().iter_backward(anchor)
+    }
+
+    /// Generates the dates rolling backwards from `termination_date`.  See
+    /// [`Schedule::generate_backward`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ScheduleError::InvalidDateRange`] if `termination_date <= effective_date`.
+    /// - [`ScheduleError::OutOfRange`] if adjusting a date runs off the
+    ///   representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::Frequency;
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let effective   = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let termination = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    /// let sched       = ScheduleOwned::new(Frequency::Semiannual, None, None);
+    /// let dates = sched.generate_backward(&effective, &termination).unwrap();
+    /// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2024, 6, 15).unwrap());
+    /// ```
+    pub fn generate_backward(
+        &self,
+        effective_date: &FinDate,
+        termination_date: &FinDate,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        self.as_schedule().generate_backward(effective_date, termination_date)
+    }
+
+    /// Builds the coupon periods from `effective_date` to `maturity_date`.
+    /// See [`Schedule::coupon_periods`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Schedule::coupon_periods`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use findates::conventions::{DayCount, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let issue    = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
+    /// let maturity = NaiveDate::from_ymd_opt(2025, 2, 15).unwrap();
+    /// let sched    = ScheduleOwned::new(Frequency::Semiannual, None, None);
+    /// let periods  = sched.coupon_periods(&issue, &maturity, DayCount::D30360Euro, 0).unwrap();
+    /// assert_eq!(periods[1].year_fraction, 0.5);
+    /// ```
+    pub fn coupon_periods(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        daycount: DayCount,
+        pay_lag: u32,
+    ) -> Result<Vec<CouponPeriod>, ScheduleError> {
+        self.as_schedule().coupon_periods(effective_date, maturity_date, daycount, pay_lag)
+    }
+
+    /// Returns the payment date of each coupon period.  See
+    /// [`Schedule::payment_dates`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Schedule::payment_dates`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let effective = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 4, 10).unwrap();
+    /// let sched     = ScheduleOwned::new(Frequency::Monthly, Some(Arc::new(basic_calendar())), Some(AdjustRule::ModFollowing));
+    /// let payments  = sched.payment_dates(&effective, &maturity, 2).unwrap();
+    /// assert_eq!(payments[0], NaiveDate::from_ymd_opt(2024, 2, 14).unwrap());
+    /// ```
+    pub fn payment_dates(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        pay_lag: u32,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        self.as_schedule().payment_dates(effective_date, maturity_date, pay_lag)
+    }
+
+    /// Returns the fixing date of each coupon period.  See
+    /// [`Schedule::fixing_dates`].
+    ///
+    /// # Errors
+    ///
+    /// As [`Schedule::fixing_dates`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use chrono::NaiveDate;
+    /// use findates::calendar::basic_calendar;
+    /// use findates::conventions::{AdjustRule, Frequency};
+    /// use findates::schedule::ScheduleOwned;
+    ///
+    /// let cal       = Arc::new(basic_calendar());
+    /// let effective = NaiveDate::from_ymd_opt(2024, 2, 8).unwrap();
+    /// let maturity  = NaiveDate::from_ymd_opt(2024, 8, 8).unwrap();
+    /// let sched     = ScheduleOwned::new(Frequency::Quarterly, Some(cal.clone()), Some(AdjustRule::ModFollowing));
+    /// let fixings   = sched.fixing_dates(&effective, &maturity, 2, &cal).unwrap();
+    /// assert_eq!(fixings[0], NaiveDate::from_ymd_opt(2024, 2, 6).unwrap());
+    /// ```
+    pub fn fixing_dates(
+        &self,
+        effective_date: &FinDate,
+        maturity_date: &FinDate,
+        fixing_lag: u32,
+        fixing_calendar: &Calendar,
+    ) -> Result<Vec<FinDate>, ScheduleError> {
+        self.as_schedule().fixing_dates(effective_date, maturity_date, fixing_lag, fixing_calendar)
+    }
+}
+
+impl<'a> From<&Schedule<'a>> for ScheduleOwned {
+    fn from(schedule: &Schedule<'a>) -> Self {
+        Self {
+            frequency: schedule.frequency,
+            calendar: schedule.calendar.cloned().map(Arc::new),
+            adjust_rule: schedule.adjust_rule,
+            eom: schedule.eom,
+            roll: schedule.roll,
+            stub: schedule.stub,
+            rounding: schedule.rounding,
+        }
+    }
+}
+
+impl<'a> From<Schedule<'a>> for ScheduleOwned {
+    fn from(schedule: Schedule<'a>) -> Self {
+        Self::from(&schedule)
+    }
+}
+
+// Adjusted accrual boundaries of a coupon schedule.
+struct AccrualDates {
+    dates: Vec<FinDate>,
+    // Adjusted start of the regular period a front stub was cut from.
+    front_reference: Option<FinDate>,
+    // Adjusted end of the regular period a back stub was cut from.
+    back_reference: Option<FinDate>,
+}
+
+// Raw unadjusted date `periods` whole periods after (`forward`) or before
+// `seed`, snapped to the month end when `eom` applies and pinned to the roll
+// convention when one is active.
+fn nominal_step(
+    schedule: &Schedule,
+    seed: &FinDate,
+    periods: u32,
+    forward: bool,
+    eom: bool,
+) -> Option<FinDate> {
+    let shift_months = |months: u32| {
+        if forward {
+            seed.checked_add_months(Months::new(months))
+        } else {
+            seed.checked_sub_months(Months::new(months))
+        }
+    };
+    let stepped = match (frequency_months(schedule.frequency), schedule.frequency) {
+        (Some(months), _) => shift_months(months.checked_mul(periods)?)?,
+        (None, Frequency::EndOfMonth) => {
+            let stepped = shift_months(periods)?;
+            return algebra::last_day_of_month(stepped.year(), stepped.month());
+        }
+        (None, _) => {
+            let days = Days::new(frequency_days(schedule.frequency)?.checked_mul(periods as u64)?);
+            if forward {
+                seed.checked_add_days(days)?
+            } else {
+                seed.checked_sub_days(days)?
+            }
+        }
+    };
+    match schedule.active_roll() {
+        _ if eom => algebra::last_day_of_month(stepped.year(), stepped.month()),
+        Some(roll) => schedule.roll_date(&roll, &stepped),
+        None => Some(stepped),
+    }
+}
+
+// True if `roll` pins the dates of a schedule with `frequency`: weekdays for
+// weekly frequencies, days of the month for month-based ones.
+fn roll_applies(roll: &RollConvention, frequency: Frequency) -> bool {
+    match roll {
+        RollConvention::DayOfWeek(_) => matches!(
+            frequency,
+            Frequency::Weekly | Frequency::Biweekly | Frequency::EveryFourthWeek | Frequency::EveryNWeeks(_)
+        ),
+        _ => frequency_months(frequency).is_some(),
+    }
+}
+
+// Days per step for day-based frequencies.
+fn frequency_days(frequency: Frequency) -> Option<u64> {
+    frequency.to_days().map(u64::from)
+}
+
+// Months per step for frequencies the end-of-month convention applies to.
+fn frequency_months(frequency: Frequency) -> Option<u32> {
+    match frequency {
+        Frequency::EndOfMonth => None,
+        _ => frequency.to_months(),
+    }
+}
+
+// Guarantees the adjusted result is strictly after `anchor_date`.
+//
+// Some adjustment rules (Preceding, ModFollowing, Nearest) can move a date
+// backwards past the anchor. When that happens this function keeps nudging
+// the candidate forward by one calendar day at a time until the adjusted
+// result clears the anchor. Returns None if the search walks off the end of
+// the representable NaiveDate range.
+fn force_adjust(
+    anchor_date: &FinDate,
+    next_date: &FinDate,
+    opt_calendar: Option<&Calendar>,
+    opt_adjust_rule: Option<AdjustRule>,
+) -> Option<FinDate> {
+    let mut res = algebra::checked_adjust(next_date, opt_calendar, opt_adjust_rule)?;
+    let mut day_i = 1u64;
+    while res <= *anchor_date {
+        let candidate = next_date.checked_add_days(Days::new(day_i))?;
+        res = algebra::checked_adjust(&candidate, opt_calendar, opt_adjust_rule)?;
+        day_i += 1;
+    }
+    Some(res)
+}
+
+// The `weekday` within three days of `date`.
+fn nearest_weekday(date: &FinDate, weekday: Weekday) -> Option<FinDate> {
+    let ahead = (7 + weekday.num_days_from_monday() - date.weekday().num_days_from_monday()) % 7;
+    if ahead <= 3 {
+        date.checked_add_days(Days::new(ahead.into()))
+    } else {
+        date.checked_sub_days(Days::new((7 - ahead).into()))
+    }
 }
 
 // Internal building block. Returns the raw unadjusted next date for a given
@@ -232,16 +2112,34 @@ fn schedule_next(anchor_date: &FinDate, frequency: Frequency) -> Option<FinDate>
         Frequency::EveryFourthMonth => anchor_date.checked_add_months(Months::new(4)),
         Frequency::Semiannual => anchor_date.checked_add_months(Months::new(6)),
         Frequency::Annual => checked_add_years(anchor_date, 1),
+        Frequency::EveryNMonths(n) if n > 0 => anchor_date.checked_add_months(Months::new(n)),
+        Frequency::EveryNWeeks(n) if n > 0 => anchor_date.checked_add_days(Days::new(n as u64 * 7)),
+        Frequency::EveryNDays(n) if n > 0 => anchor_date.checked_add_days(Days::new(n as u64)),
+        Frequency::EveryNMonths(_) | Frequency::EveryNWeeks(_) | Frequency::EveryNDays(_) => None,
         Frequency::Zero => None,
     }
 }
 
+// `accrual_start` moved back `fixing_lag` business days in `calendar`,
+// after first moving it to a business day.
+fn fixing_date(accrual_start: &FinDate, fixing_lag: u32, calendar: &Calendar) -> Result<FinDate, ScheduleError> {
+    let preceding = |date: &FinDate| {
+        algebra::checked_adjust(date, Some(calendar), Some(AdjustRule::Preceding)).ok_or(ScheduleError::OutOfRange)
+    };
+    let mut date = preceding(accrual_start)?;
+    for _ in 0..fixing_lag {
+        date = preceding(&date.pred_opt().ok_or(ScheduleError::OutOfRange)?)?;
+    }
+    Ok(date)
+}
+
 /// Returns the adjusted next date after `anchor`, applying the schedule's
 /// calendar and adjustment rule, or `None` if there is no next date or the
 /// next date is out of range.
 ///
 /// When successful, guarantees the result is strictly after `anchor` even when
-/// an adjustment rule would otherwise move the date backwards.
+/// an adjustment rule would otherwise move the date backwards.  Equivalent to
+/// [`Schedule::next_adjusted`].
 ///
 /// Returns `None` when:
 /// - The frequency has no "next" date (e.g., [`Frequency::Zero`])
@@ -263,15 +2161,401 @@ fn schedule_next(anchor_date: &FinDate, frequency: Frequency) -> Option<FinDate>
 /// assert_eq!(next, NaiveDate::from_ymd_opt(2024, 3, 21).unwrap());
 /// ```
 pub fn schedule_next_adjusted(schedule: &Schedule, anchor: FinDate) -> Option<FinDate> {
-    let next = schedule_next(&anchor, schedule.frequency)?;
-    force_adjust(&anchor, &next, schedule.calendar, schedule.adjust_rule)
+    schedule.next_adjusted(anchor)
+}
+
+/// Pairs the accrual periods of two swap legs running from `effective_date`
+/// to `termination_date`, nesting the periods of the more frequent leg
+/// inside those of the other.
+///
+/// Each leg's accrual periods are built exactly as in
+/// [`Schedule::coupon_periods`].  The accrual dates the two legs share cut
+/// the range into [`AlignedPeriod`]s; in each, one leg has a single period
+/// and the other one or more, as for a quarterly floating leg against a
+/// semiannual fixed leg.  Legs may nest either way round, and the nesting
+/// may change along the schedule, e.g. after a stub.
+///
+/// # Errors
+///
+/// - [`ScheduleError::InvalidDateRange`] if `termination_date <= effective_date`.
+/// - [`ScheduleError::OutOfRange`] if an accrual date falls outside the
+///   representable date range.
+/// - [`ScheduleError::MisalignedLegs`] if, between two shared accrual
+///   dates, both legs have more than one period (e.g. bimonthly against
+///   quarterly), or the legs do not start and end on the same adjusted
+///   dates.  `date` is the start of the first period that cannot be paired.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::Frequency;
+/// use findates::schedule::{align_legs, Schedule};
+///
+/// let d = |y, m| NaiveDate::from_ymd_opt(y, m, 15).unwrap();
+/// let fixed    = Schedule::new(Frequency::Semiannual, None, None);
+/// let floating = Schedule::new(Frequency::Quarterly, None, None);
+///
+/// let periods = align_legs(&fixed, &floating, &d(2024, 1), &d(2025, 1)).unwrap();
+/// assert_eq!(periods.len(), 2);
+/// assert_eq!(periods[0].leg_a, [(d(2024, 1), d(2024, 7))]);
+/// assert_eq!(periods[0].leg_b, [(d(2024, 1), d(2024, 4)), (d(2024, 4), d(2024, 7))]);
+/// ```
+pub fn align_legs(
+    leg_a: &Schedule,
+    leg_b: &Schedule,
+    effective_date: &FinDate,
+    termination_date: &FinDate,
+) -> Result<Vec<AlignedPeriod>, ScheduleError> {
+    let dates_a = leg_a.accrual_dates(effective_date, termination_date)?.dates;
+    let dates_b = leg_b.accrual_dates(effective_date, termination_date)?.dates;
+    if dates_a[0] != dates_b[0] {
+        return Err(ScheduleError::MisalignedLegs { date: dates_a[0].min(dates_b[0]) });
+    }
+
+    let mut res = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i + 1 < dates_a.len() || j + 1 < dates_b.len() {
+        let (start_a, start_b) = (i, j);
+        let misaligned = ScheduleError::MisalignedLegs { date: dates_a[start_a] };
+        // Advance both legs to the next accrual date they share.
+        i += 1;
+        j += 1;
+        loop {
+            match (dates_a.get(i), dates_b.get(j)) {
+                (Some(a), Some(b)) if a == b => break,
+                (Some(a), Some(b)) if a < b => i += 1,
+                (Some(_), Some(_)) => j += 1,
+                _ => return Err(misaligned),
+            }
+        }
+        if i - start_a > 1 && j - start_b > 1 {
+            return Err(misaligned);
+        }
+        let periods = |dates: &[FinDate]| dates.windows(2).map(|pair| (pair[0], pair[1])).collect();
+        res.push(AlignedPeriod {
+            start: dates_a[start_a],
+            end: dates_a[i],
+            leg_a: periods(&dates_a[start_a..=i]),
+            leg_b: periods(&dates_b[start_b..=j]),
+        });
+    }
+    Ok(res)
+}
+
+/// Returns the dates in either of two generated schedules, sorted and
+/// without duplicates.
+///
+/// Use it to amalgamate, say, the payment dates of two legs or the payment
+/// and fixing dates of one leg into a single list of event dates.  Inputs
+/// need not be sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::Frequency;
+/// use findates::schedule::{merge, Schedule};
+///
+/// let d = |m| NaiveDate::from_ymd_opt(2024, m, 15).unwrap();
+/// let quarterly  = Schedule::new(Frequency::Quarterly, None, None).generate(&d(1), &d(12)).unwrap();
+/// let semiannual = Schedule::new(Frequency::Semiannual, None, None).generate(&d(3), &d(12)).unwrap();
+///
+/// assert_eq!(merge(&quarterly, &semiannual), [d(1), d(3), d(4), d(7), d(9), d(10)]);
+/// ```
+pub fn merge(a: &[FinDate], b: &[FinDate]) -> Vec<FinDate> {
+    let mut res: Vec<FinDate> = a.iter().chain(b).copied().collect();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
+/// Returns the dates in both of two generated schedules, sorted and without
+/// duplicates.
+///
+/// Inputs need not be sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::Frequency;
+/// use findates::schedule::{intersect, Schedule};
+///
+/// let d = |m| NaiveDate::from_ymd_opt(2024, m, 15).unwrap();
+/// let quarterly  = Schedule::new(Frequency::Quarterly, None, None).generate(&d(1), &d(12)).unwrap();
+/// let semiannual = Schedule::new(Frequency::Semiannual, None, None).generate(&d(1), &d(12)).unwrap();
+///
+/// assert_eq!(intersect(&quarterly, &semiannual), [d(1), d(7)]);
+/// ```
+pub fn intersect(a: &[FinDate], b: &[FinDate]) -> Vec<FinDate> {
+    let mut others = b.to_vec();
+    others.sort_unstable();
+    let mut res: Vec<FinDate> = a.iter().filter(|date| others.binary_search(date).is_ok()).copied().collect();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
+/// Moves every date of a generated schedule by `n` business days in
+/// `calendar`, returning the shifted dates sorted and without duplicates.
+///
+/// Each date becomes the `n`-th business day after it, or before it when
+/// `n` is negative, whether or not it is a business day itself: a Saturday
+/// shifted by `1` lands on Monday and by `-1` on Friday.  With `n = 0` the
+/// dates are unchanged.  Two dates can land on the same business day, so the
+/// result may be shorter than `dates`.
+///
+/// # Errors
+///
+/// Returns [`ScheduleError::OutOfRange`] if a shifted date falls outside
+/// the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::Frequency;
+/// use findates::schedule::{shift_by_business_days, Schedule};
+///
+/// let cal      = basic_calendar();
+/// let d        = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// let payments = Schedule::new(Frequency::Quarterly, None, None).generate(&d(3, 15), &d(9, 30)).unwrap();
+///
+/// // Fixings two business days before each date; 15 June is a Saturday and
+/// // 15 September a Sunday
+/// let fixings = shift_by_business_days(&payments, -2, &cal).unwrap();
+/// assert_eq!(fixings, [d(3, 13), d(6, 13), d(9, 12)]);
+/// ```
+pub fn shift_by_business_days(dates: &[FinDate], n: i32, calendar: &Calendar) -> Result<Vec<FinDate>, ScheduleError> {
+    let (rule, step): (AdjustRule, fn(&FinDate) -> Option<FinDate>) = if n < 0 {
+        (AdjustRule::Preceding, |date| date.pred_opt())
+    } else {
+        (AdjustRule::Following, |date| date.succ_opt())
+    };
+    let shift = |date: &FinDate| -> Result<FinDate, ScheduleError> {
+        let mut current = *date;
+        for _ in 0..n.unsigned_abs() {
+            current = step(&current)
+                .and_then(|next| algebra::checked_adjust(&next, Some(calendar), Some(rule)))
+                .ok_or(ScheduleError::OutOfRange)?;
+        }
+        Ok(current)
+    };
+    let mut res = dates.iter().map(shift).collect::<Result<Vec<_>, _>>()?;
+    res.sort_unstable();
+    res.dedup();
+    Ok(res)
+}
+
+/// Returns every `interval_weeks`-th `weekday` from `start_date` to
+/// `end_date`, each adjusted with `adjust_rule` in `calendar`.
+///
+/// For recurring fixings such as "every Wednesday, moved to the next good
+/// day".  The first date is the first `weekday` on or after `start_date`,
+/// and every later one is a whole number of weeks after it before
+/// adjustment, so a holiday that moves one date to another weekday leaves
+/// the rest on `weekday`.  Dates are chosen unadjusted within the range and
+/// may be adjusted past either end; dates that adjust onto the same day
+/// appear once.  Without a calendar or rule the dates are left unadjusted,
+/// as in [`algebra::adjust`].
+///
+/// # Errors
+///
+/// Returns [`ScheduleError::InvalidInterval`] if `interval_weeks` is zero,
+/// [`ScheduleError::InvalidDateRange`] if `end_date` is before `start_date`,
+/// and [`ScheduleError::OutOfRange`] if an adjusted date falls outside the
+/// representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::schedule::weekday_dates;
+///
+/// let mut cal = basic_calendar();
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// cal.add_holidays([d(2024, 12, 25), d(2025, 1, 1)]);
+///
+/// let fixings = weekday_dates(&d(2024, 12, 16), &d(2025, 1, 10), Weekday::Wed, 1, Some(&cal), Some(AdjustRule::Following));
+/// assert_eq!(fixings, Ok(vec![d(2024, 12, 18), d(2024, 12, 26), d(2025, 1, 2), d(2025, 1, 8)]));
+/// ```
+pub fn weekday_dates(
+    start_date: &FinDate,
+    end_date: &FinDate,
+    weekday: Weekday,
+    interval_weeks: u32,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Vec<FinDate>, ScheduleError> {
+    if interval_weeks == 0 {
+        return Err(ScheduleError::InvalidInterval);
+    }
+    if end_date < start_date {
+        return Err(ScheduleError::InvalidDateRange);
+    }
+    let first = RollConvention::DayOfWeek(weekday).apply(start_date).ok_or(ScheduleError::OutOfRange)?;
+    let step = Days::new(7 * u64::from(interval_weeks));
+    let nominal = std::iter::successors(Some(first), |date| date.checked_add_days(step))
+        .take_while(|date| date <= end_date);
+    adjust_nominal(nominal, calendar, adjust_rule)
 }
 
-/// Lazy, unbounded iterator over the dates of a [`Schedule`].
+/// Returns the `occurrences` of `weekday` in each month from `start_date` to
+/// `end_date`, e.g. the second and fourth Friday, each adjusted with
+/// `adjust_rule` in `calendar`.
 ///
-/// Created by [`Schedule::iter`] — do not construct directly.
+/// Occurrences count from 1; those a month does not have, such as a fifth
+/// Friday, are skipped.  Dates are chosen unadjusted within the range and
+/// may be adjusted past either end; dates that adjust onto the same day
+/// appear once.
+///
+/// # Errors
+///
+/// Returns [`ScheduleError::InvalidDateRange`] if `end_date` is before
+/// `start_date`, and [`ScheduleError::OutOfRange`] if an adjusted date falls
+/// outside the representable date range.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::schedule::nth_weekday_dates;
+///
+/// let mut cal = basic_calendar();
+/// let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+/// cal.add_holidays([d(3, 22)]);
+///
+/// let dates = nth_weekday_dates(&d(3, 1), &d(4, 30), Weekday::Fri, &[2, 4], Some(&cal), Some(AdjustRule::Preceding));
+/// assert_eq!(dates, Ok(vec![d(3, 8), d(3, 21), d(4, 12), d(4, 26)]));
+/// ```
+pub fn nth_weekday_dates(
+    start_date: &FinDate,
+    end_date: &FinDate,
+    weekday: Weekday,
+    occurrences: &[u8],
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Vec<FinDate>, ScheduleError> {
+    if end_date < start_date {
+        return Err(ScheduleError::InvalidDateRange);
+    }
+    let mut occurrences = occurrences.to_vec();
+    occurrences.sort_unstable();
+    occurrences.dedup();
+    let months = std::iter::successors(start_date.with_day(1), |month| month.checked_add_months(Months::new(1)))
+        .take_while(|month| month <= end_date);
+    let nominal = months
+        .flat_map(|month| {
+            occurrences.iter().filter_map(move |&n| {
+                algebra::nth_weekday_of_month(month.year(), month.month(), weekday, n).ok()
+            })
+        })
+        .filter(|date| start_date <= date && date <= end_date);
+    adjust_nominal(nominal, calendar, adjust_rule)
+}
+
+// Adjusts ascending nominal dates, dropping those that adjust onto the
+// previous one.
+fn adjust_nominal(
+    nominal: impl Iterator<Item = FinDate>,
+    calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<Vec<FinDate>, ScheduleError> {
+    let mut res = nominal
+        .map(|date| algebra::checked_adjust(&date, calendar, adjust_rule).ok_or(ScheduleError::OutOfRange))
+        .collect::<Result<Vec<_>, _>>()?;
+    res.dedup();
+    Ok(res)
+}
+
+/// Classifies each of `periods` as regular or a stub of a schedule with
+/// `frequency`, with its length as a fraction of a regular period.
+///
+/// ICMA day counts need to know which coupons are odd: a stub accrues
+/// against the notional regular period(s) it was cut from.  The first of
+/// several periods is measured back from its end, as a front stub would be;
+/// every other period is measured forward from its start.  A short stub's
+/// fraction is its days over the notional period's days; a long stub's is 1
+/// plus the days beyond one notional period over the days of the next.
+///
+/// Accrual dates are adjusted, so a period ending within a few days of a
+/// whole regular period counts as regular: up to 7 days, or a quarter of
+/// the period for shorter frequencies.  Every period of a
+/// [`Frequency::Zero`] schedule is regular.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::{DayCount, Frequency, StubRule};
+/// use findates::schedule::{classify_periods, PeriodKind, Schedule};
+///
+/// let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+/// let sched = Schedule::new(Frequency::Semiannual, None, None).with_stub(StubRule::ShortFront);
+/// let periods = sched.coupon_periods(&d(2024, 3, 15), &d(2025, 6, 15), DayCount::ActActICMA, 0).unwrap();
+///
+/// let classes = classify_periods(&periods, Frequency::Semiannual);
+/// assert_eq!(classes[0].kind, PeriodKind::ShortStub);
+/// // 92 of the 183 days from 15 December 2023 to 15 June 2024
+/// assert_eq!(classes[0].notional_fraction, 92.0 / 183.0);
+/// assert_eq!(classes[1].kind, PeriodKind::Regular);
+/// ```
+pub fn classify_periods(periods: &[CouponPeriod], frequency: Frequency) -> Vec<PeriodClass> {
+    let stepper = Schedule::new(frequency, None, None);
+    periods
+        .iter()
+        .enumerate()
+        .map(|(i, period)| {
+            let forward = i > 0 || periods.len() == 1;
+            let (seed, far_end) = if forward {
+                (period.accrual_start, period.accrual_end)
+            } else {
+                (period.accrual_end, period.accrual_start)
+            };
+            let step = |periods| nominal_step(&stepper, &seed, periods, forward, false);
+            let regular = PeriodClass { kind: PeriodKind::Regular, notional_fraction: 1.0 };
+            let Some(one) = step(1) else {
+                return regular;
+            };
+            let days = |a: FinDate, b: FinDate| (b - a).num_days().abs() as f64;
+            let regular_days = days(seed, one);
+            let tolerance = (regular_days / 4.0).floor().min(7.0);
+            let overshoot = days(seed, far_end) - regular_days;
+            if overshoot.abs() <= tolerance {
+                regular
+            } else if overshoot < 0.0 {
+                PeriodClass {
+                    kind: PeriodKind::ShortStub,
+                    notional_fraction: days(seed, far_end) / regular_days,
+                }
+            } else {
+                let Some(two) = step(2) else {
+                    return regular;
+                };
+                PeriodClass {
+                    kind: PeriodKind::LongStub,
+                    notional_fraction: 1.0 + days(one, far_end) / days(one, two),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Lazy iterator over the dates of a [`Schedule`].
+///
+/// Created by [`Schedule::iter`] (unbounded) or [`Schedule::iter_between`]
+/// (bounded by an end date) — do not construct directly.
 /// For [`Frequency::Zero`] the iterator is immediately exhausted
-/// (returns `None` on the first call to [`next`](Iterator::next)).
+/// (returns `None` on the first call to [`next`](Iterator::next)).  The
+/// iterator is fused: once the next date would fall past the end date or
+/// outside the representable date range it keeps returning `None` instead of
+/// panicking.
 ///
 /// Each step begins from the previous **adjusted** date, making this
 /// suitable for interactive "what is the next date from today?" queries.
@@ -295,20 +2579,267 @@ pub fn schedule_next_adjusted(schedule: &Schedule, anchor: FinDate) -> Option<Fi
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScheduleIterator<'a> {
-    schedule: &'a Schedule<'a>,
+    schedule: Schedule<'a>,
     anchor: FinDate,
+    end: Option<FinDate>,
 }
 
 impl<'a> Iterator for ScheduleIterator<'a> {
     type Item = FinDate;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let res = schedule_next_adjusted(self.schedule, self.anchor)?;
+        let res = self.schedule.next_adjusted(self.anchor)?;
+        if self.end.is_some_and(|end| res > end) {
+            return None;
+        }
         self.anchor = res;
         Some(res)
     }
 }
 
+impl<'a> std::iter::FusedIterator for ScheduleIterator<'a> {}
+
+/// A single accrual period of a coupon schedule.
+///
+/// Produced by [`Schedule::coupon_periods`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::{DayCount, Frequency};
+/// use findates::schedule::Schedule;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+/// let sched = Schedule::new(Frequency::Quarterly, None, None);
+///
+/// let period = sched.coupon_periods(&start, &end, DayCount::Act360, 2).unwrap()[0];
+/// assert_eq!(period.accrual_end, NaiveDate::from_ymd_opt(2024, 4, 15).unwrap());
+/// assert_eq!(period.payment_date, NaiveDate::from_ymd_opt(2024, 4, 17).unwrap());
+/// assert_eq!(period.year_fraction, 91.0 / 360.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CouponPeriod {
+    /// First day of the accrual period (adjusted).
+    pub accrual_start: FinDate,
+    /// Last day of the accrual period (adjusted).
+    pub accrual_end: FinDate,
+    /// Date the coupon is paid.
+    pub payment_date: FinDate,
+    /// Day count fraction between the accrual dates.
+    pub year_fraction: f64,
+}
+
+/// Whether a coupon period is a whole regular period or a stub; see
+/// [`classify_periods`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeriodKind {
+    /// One whole period of the schedule's frequency.
+    Regular,
+    /// Shorter than a regular period.
+    ShortStub,
+    /// Longer than a regular period.
+    LongStub,
+}
+
+/// The classification of one coupon period, returned by
+/// [`classify_periods`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodClass {
+    /// Regular period or stub.
+    pub kind: PeriodKind,
+    /// Length as a fraction of a regular period: 1 for regular periods,
+    /// below 1 for short stubs and above 1 for long stubs.
+    pub notional_fraction: f64,
+}
+
+/// A schedule date together with how it was generated.
+///
+/// Produced by [`Schedule::generate_with_trace`] to explain where each date
+/// came from.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{AdjustRule, Frequency};
+/// use findates::schedule::Schedule;
+///
+/// let cal   = basic_calendar();
+/// let sched = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+/// let trace = sched.generate_with_trace(
+///     &NaiveDate::from_ymd_opt(2024, 3, 29).unwrap(),
+///     &NaiveDate::from_ymd_opt(2024, 6, 29).unwrap(),
+/// ).unwrap();
+///
+/// // Saturday 29 June cannot roll forward into July, so it moves back a day.
+/// assert_eq!(trace[1].adjusted, NaiveDate::from_ymd_opt(2024, 6, 28).unwrap());
+/// assert_eq!(trace[1].adjustment_days, -1);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneratedDate {
+    /// Date before business day adjustment.
+    pub unadjusted: FinDate,
+    /// Date after business day adjustment, as returned by
+    /// [`Schedule::generate`].
+    pub adjusted: FinDate,
+    /// Adjustment rule applied to the unadjusted date; `None` when the
+    /// schedule has no calendar or no rule.
+    pub adjust_rule: Option<AdjustRule>,
+    /// Calendar days from the unadjusted to the adjusted date; negative when
+    /// the date moved back.
+    pub adjustment_days: i64,
+}
+
+/// A stretch of a two-leg schedule between consecutive accrual dates the
+/// legs share, with each leg's accrual periods inside it.
+///
+/// Produced by [`align_legs`].  One of `leg_a` and `leg_b` holds the single
+/// period from `start` to `end`; the other holds the consecutive periods
+/// that nest inside it.  Periods are `(accrual_start, accrual_end)` pairs
+/// of adjusted dates.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::conventions::Frequency;
+/// use findates::schedule::{align_legs, Schedule};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let end   = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+/// let fixed    = Schedule::new(Frequency::Semiannual, None, None);
+/// let floating = Schedule::new(Frequency::Monthly, None, None);
+///
+/// let period = &align_legs(&fixed, &floating, &start, &end).unwrap()[0];
+/// assert_eq!((period.start, period.end), (start, end));
+/// assert_eq!(period.leg_a.len(), 1);
+/// assert_eq!(period.leg_b.len(), 6);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlignedPeriod {
+    /// Accrual date shared by both legs where this stretch starts (adjusted).
+    pub start: FinDate,
+    /// Accrual date shared by both legs where this stretch ends (adjusted).
+    pub end: FinDate,
+    /// Accrual periods of the first leg from `start` to `end`.
+    pub leg_a: Vec<(FinDate, FinDate)>,
+    /// Accrual periods of the second leg from `start` to `end`.
+    pub leg_b: Vec<(FinDate, FinDate)>,
+}
+
+/// Lazy, unbounded iterator over the adjusted dates of a [`Schedule`],
+/// stepping from nominal dates.
+///
+/// Created by [`Schedule::iter_adjusted`] — do not construct directly.
+/// Items are strictly increasing.  For [`Frequency::Zero`] the iterator is
+/// immediately exhausted, and it is fused: once the next date falls outside
+/// the representable date range it keeps returning `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::schedule::Schedule;
+/// use findates::conventions::Frequency;
+///
+/// let anchor = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// let sched  = Schedule::new(Frequency::Quarterly, None, None);
+/// let mut it = sched.iter_adjusted(anchor);
+///
+/// assert_eq!(it.next(), NaiveDate::from_ymd_opt(2024, 4, 15));
+/// assert_eq!(it.next(), NaiveDate::from_ymd_opt(2024, 7, 15));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjustedScheduleIterator<'a> {
+    schedule: Schedule<'a>,
+    eom: bool,
+    anchor: FinDate,
+    periods: u32,
+    last: FinDate,
+}
+
+impl<'a> Iterator for AdjustedScheduleIterator<'a> {
+    type Item = FinDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let periods = self.periods.checked_add(1)?;
+        let nominal = nominal_step(&self.schedule, &self.anchor, periods, true, self.eom)?;
+        let rule = self.schedule.roll_adjust_rule(self.eom);
+        let res = force_adjust(&self.last, &nominal, self.schedule.calendar, rule)?;
+        self.periods = periods;
+        self.last = res;
+        Some(res)
+    }
+}
+
+impl<'a> std::iter::FusedIterator for AdjustedScheduleIterator<'a> {}
+
+/// Lazy iterator walking backwards in time over the dates of a [`Schedule`].
+///
+/// Created by [`Schedule::iter_backward`] — do not construct directly.
+/// Each date is the anchor moved back by a whole number of periods, so the
+/// sequence does not drift when short months clamp the day of month.  The
+/// iterator is fused: once a step falls outside the representable date range
+/// it keeps returning `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use findates::schedule::Schedule;
+/// use findates::conventions::Frequency;
+///
+/// let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let sched  = Schedule::new(Frequency::Annual, None, None);
+/// let mut it = sched.iter_backward(anchor);
+///
+/// assert_eq!(it.next(), NaiveDate::from_ymd_opt(2023, 1, 1));
+/// assert_eq!(it.next(), NaiveDate::from_ymd_opt(2022, 1, 1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackwardScheduleIterator<'a> {
+    schedule: Schedule<'a>,
+    anchor: FinDate,
+    eom: bool,
+    periods: u32,
+    last: FinDate,
+    done: bool,
+}
+
+impl<'a> Iterator for BackwardScheduleIterator<'a> {
+    type Item = FinDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rule = self.schedule.roll_adjust_rule(self.eom);
+        while !self.done {
+            let adjusted = self.periods.checked_add(1).and_then(|periods| {
+                self.periods = periods;
+                let nominal = nominal_step(&self.schedule, &self.anchor, periods, false, self.eom)?;
+                algebra::checked_adjust(&nominal, self.schedule.calendar, rule)
+            });
+            match adjusted {
+                Some(date) if date < self.last => {
+                    self.last = date;
+                    return Some(date);
+                }
+                Some(_) => continue,
+                None => self.done = true,
+            }
+        }
+        None
+    }
+}
+
+impl<'a> std::iter::FusedIterator for BackwardScheduleIterator<'a> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
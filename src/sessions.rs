@@ -0,0 +1,468 @@
+//! Trading sessions: market hours on top of a business day calendar.
+//!
+//! [`MarketHours`] gives the regular opening and closing times of a market in
+//! its local time zone.  Attached to a [`Calendar`] with
+//! [`Calendar::with_market_hours`], it forms a [`TradingSession`] that
+//! answers intraday questions: is the market open at a given instant, when
+//! does it next open, and how many minutes of trading lie between two
+//! instants.  Early closes recorded on the calendar
+//! ([`Calendar::add_early_close`]) shorten the session on those days.
+//! [`algebra::trading_time_fraction`](crate::algebra::trading_time_fraction)
+//! measures the same trading time as a year fraction, for volatility time.
+//!
+//! The market's time zone can be any chrono [`TimeZone`]: a [`FixedOffset`],
+//! or with the `tz` feature a `chrono_tz::Tz` such as
+//! `America::New_York`, which follows daylight saving changes.  Local times
+//! repeated by a daylight saving change resolve to their first occurrence; a
+//! session whose opening or closing time is skipped by one has no instant.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+use crate::calendar::Calendar;
+use crate::error::CalendarError;
+
+/// Regular trading hours of a market, in its local time zone.
+///
+/// Sessions run from `open` (inclusive) to `close` (exclusive) on the same
+/// local day.  `Tz` is the market's time zone, a fixed UTC offset by default.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveTime};
+/// use findates::sessions::MarketHours;
+///
+/// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let hours = MarketHours::new(
+///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+///     new_york,
+/// ).unwrap();
+/// assert_eq!(hours.session_minutes(), 390);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct MarketHours<Tz: TimeZone = FixedOffset> {
+    /// Local opening time.
+    pub open: NaiveTime,
+    /// Local closing time on a regular day.
+    pub close: NaiveTime,
+    /// Time zone of the market's local time.
+    pub timezone: Tz,
+}
+
+impl<Tz: TimeZone> MarketHours<Tz> {
+    /// Creates market hours from local opening and closing times.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CalendarError::InvalidHours`] if `close` is not after
+    /// `open`.  Sessions spanning midnight are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::sessions::MarketHours;
+    /// use findates::CalendarError;
+    ///
+    /// let utc  = FixedOffset::east_opt(0).unwrap();
+    /// let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    /// assert_eq!(MarketHours::new(nine, nine, utc), Err(CalendarError::InvalidHours));
+    /// ```
+    pub fn new(open: NaiveTime, close: NaiveTime, timezone: Tz) -> Result<Self, CalendarError> {
+        if close <= open {
+            return Err(CalendarError::InvalidHours);
+        }
+        Ok(Self { open, close, timezone })
+    }
+
+    /// Returns the length of a regular session in whole minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let london = FixedOffset::east_opt(0).unwrap();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+    ///     london,
+    /// ).unwrap();
+    /// assert_eq!(hours.session_minutes(), 510);
+    /// ```
+    pub fn session_minutes(&self) -> i64 {
+        (self.close - self.open).num_minutes()
+    }
+}
+
+/// A [`Calendar`] together with the [`MarketHours`] of the market it
+/// describes.
+///
+/// Created by [`Calendar::with_market_hours`] or [`TradingSession::new`].
+/// The market trades on the calendar's business days, from the opening time
+/// to the closing time or, on early close days, to the
+/// [early closing time](Calendar::close_time).
+///
+/// Methods accept instants in any time zone; they are converted to the
+/// market's local time first.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveTime, TimeZone, Utc};
+/// use findates::calendar::basic_calendar;
+/// use findates::sessions::MarketHours;
+///
+/// let cal = basic_calendar();
+/// let hours = MarketHours::new(
+///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+///     FixedOffset::west_opt(5 * 3600).unwrap(),
+/// ).unwrap();
+/// let nyse = cal.with_market_hours(hours);
+///
+/// // 15:00 UTC is 10:00 in New York.
+/// let monday = Utc.with_ymd_and_hms(2024, 3, 18, 15, 0, 0).unwrap();
+/// assert!(nyse.is_market_open(&monday));
+/// let saturday = Utc.with_ymd_and_hms(2024, 3, 16, 15, 0, 0).unwrap();
+/// assert!(!nyse.is_market_open(&saturday));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingSession<'a, Tz: TimeZone = FixedOffset> {
+    calendar: &'a Calendar,
+    hours: MarketHours<Tz>,
+}
+
+impl Calendar {
+    /// Attaches market hours to this calendar, returning a
+    /// [`TradingSession`] for intraday queries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+    ///     FixedOffset::east_opt(0).unwrap(),
+    /// ).unwrap();
+    /// let session = cal.with_market_hours(hours);
+    /// assert_eq!(session.hours(), &hours);
+    /// ```
+    pub fn with_market_hours<Tz: TimeZone>(&self, hours: MarketHours<Tz>) -> TradingSession<'_, Tz> {
+        TradingSession::new(self, hours)
+    }
+}
+
+impl<'a, Tz: TimeZone> TradingSession<'a, Tz> {
+    /// Creates a trading session from a calendar and its market hours.
+    ///
+    /// Equivalent to [`Calendar::with_market_hours`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::{MarketHours, TradingSession};
+    ///
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+    ///     FixedOffset::east_opt(9 * 3600).unwrap(),
+    /// ).unwrap();
+    /// let tokyo = TradingSession::new(&cal, hours);
+    /// assert_eq!(tokyo.calendar(), &cal);
+    /// ```
+    pub fn new(calendar: &'a Calendar, hours: MarketHours<Tz>) -> Self {
+        Self { calendar, hours }
+    }
+
+    /// Returns the underlying calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+    ///     FixedOffset::east_opt(0).unwrap(),
+    /// ).unwrap();
+    /// assert_eq!(cal.with_market_hours(hours).calendar(), &cal);
+    /// ```
+    pub fn calendar(&self) -> &'a Calendar {
+        self.calendar
+    }
+
+    /// Returns the regular market hours.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+    ///     FixedOffset::east_opt(0).unwrap(),
+    /// ).unwrap();
+    /// assert_eq!(cal.with_market_hours(hours).hours().session_minutes(), 480);
+    /// ```
+    pub fn hours(&self) -> &MarketHours<Tz> {
+        &self.hours
+    }
+
+    /// Returns the opening and closing instants of the session on the local
+    /// date `date`, or `None` if the market does not trade that day.
+    ///
+    /// The closing instant is the early closing time on early close days.
+    /// A day whose early close is at or before the opening time has no
+    /// session, and neither has a day whose opening or closing time is
+    /// skipped by a daylight saving change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveDate, NaiveTime};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let mut cal = basic_calendar();
+    /// let xmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    /// let xmas     = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// cal.add_early_close(xmas_eve, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    /// cal.add_holidays([xmas]);
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+    ///     FixedOffset::west_opt(5 * 3600).unwrap(),
+    /// ).unwrap();
+    /// let nyse = cal.with_market_hours(hours);
+    ///
+    /// let (open, close) = nyse.session(&xmas_eve).unwrap();
+    /// assert_eq!((close - open).num_minutes(), 210);
+    /// assert_eq!(nyse.session(&xmas), None);
+    /// ```
+    pub fn session(&self, date: &NaiveDate) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+        let (open, close) = self.local_session(date)?;
+        Some((self.to_market_time(open)?, self.to_market_time(close)?))
+    }
+
+    /// Returns `true` if the market is trading at `datetime`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime, TimeZone};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let london = FixedOffset::east_opt(0).unwrap();
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+    ///     london,
+    /// ).unwrap();
+    /// let lse = cal.with_market_hours(hours);
+    ///
+    /// assert!(lse.is_market_open(&london.with_ymd_and_hms(2024, 3, 18, 8, 0, 0).unwrap()));
+    /// assert!(!lse.is_market_open(&london.with_ymd_and_hms(2024, 3, 18, 16, 30, 0).unwrap()));
+    /// ```
+    pub fn is_market_open<Tz2: TimeZone>(&self, datetime: &DateTime<Tz2>) -> bool {
+        let local = self.to_local(datetime);
+        match self.local_session(&local.date()) {
+            Some((open, close)) => open <= local && local < close,
+            None => false,
+        }
+    }
+
+    /// Returns the first session opening at or after `datetime`, in the
+    /// market's time zone.
+    ///
+    /// If the market is already open at `datetime`, this is the opening of
+    /// the following session.  Returns `None` if the search for a trading
+    /// day runs past the end of the calendar's
+    /// [valid range](Calendar::valid_range) or the representable date range,
+    /// if the calendar treats every weekday as weekend, or if the opening
+    /// time is skipped by a daylight saving change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime, TimeZone};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+    ///     new_york,
+    /// ).unwrap();
+    /// let nyse = cal.with_market_hours(hours);
+    ///
+    /// let friday_evening = new_york.with_ymd_and_hms(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let monday_open    = new_york.with_ymd_and_hms(2024, 3, 18, 9, 30, 0).unwrap();
+    /// assert_eq!(nyse.next_open(&friday_evening), Some(monday_open));
+    /// ```
+    pub fn next_open<Tz2: TimeZone>(&self, datetime: &DateTime<Tz2>) -> Option<DateTime<Tz>> {
+        let local = self.to_local(datetime);
+        let (_, last) = self.search_bounds()?;
+        let mut date = local.date();
+        while date <= last {
+            if let Some((open, _)) = self.local_session(&date) {
+                if local <= open {
+                    return self.to_market_time(open);
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// Returns the number of whole minutes the market trades between `start`
+    /// and `end`.
+    ///
+    /// The result is negative when `end` is before `start`, so
+    /// `business_minutes_between(a, b) == -business_minutes_between(b, a)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveTime, TimeZone};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::sessions::MarketHours;
+    ///
+    /// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    /// let cal = basic_calendar();
+    /// let hours = MarketHours::new(
+    ///     NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+    ///     new_york,
+    /// ).unwrap();
+    /// let nyse = cal.with_market_hours(hours);
+    ///
+    /// // Friday 15:00 to Monday 10:00: 60 minutes on Friday, 30 on Monday.
+    /// let friday = new_york.with_ymd_and_hms(2024, 3, 15, 15, 0, 0).unwrap();
+    /// let monday = new_york.with_ymd_and_hms(2024, 3, 18, 10, 0, 0).unwrap();
+    /// assert_eq!(nyse.business_minutes_between(&friday, &monday), 90);
+    /// assert_eq!(nyse.business_minutes_between(&monday, &friday), -90);
+    /// ```
+    pub fn business_minutes_between<Tz1: TimeZone, Tz2: TimeZone>(
+        &self,
+        start: &DateTime<Tz1>,
+        end: &DateTime<Tz2>,
+    ) -> i64 {
+        let (start, end) = (self.to_local(start), self.to_local(end));
+        if end < start {
+            return -self.trading_time(end, start).num_minutes();
+        }
+        self.trading_time(start, end).num_minutes()
+    }
+
+    // Trading time within `[start, end)`, both in market local time.
+    pub(crate) fn trading_time(&self, start: NaiveDateTime, end: NaiveDateTime) -> Duration {
+        let mut total = Duration::zero();
+        for date in start.date().iter_days().take_while(|date| *date <= end.date()) {
+            if let Some((open, close)) = self.local_session(&date) {
+                let (from, to) = (open.max(start), close.min(end));
+                if from < to {
+                    total += to - from;
+                }
+            }
+        }
+        total
+    }
+
+    // Trading days elapsed within `[start, end)`, both in market local time
+    // and `start <= end`.  Each trading day is one unit: `overnight_weight`
+    // of it accrues evenly over the closed hours since the previous session,
+    // the rest evenly over the regular session.  `None` if the search for a
+    // session leaves the search bounds.
+    pub(crate) fn trading_days(&self, start: NaiveDateTime, end: NaiveDateTime, overnight_weight: f64) -> Option<f64> {
+        let overlap = |from: NaiveDateTime, to: NaiveDateTime| {
+            let (from, to) = (from.max(start), to.min(end));
+            if from < to { (to - from).num_seconds() as f64 } else { 0.0 }
+        };
+        let session_seconds = (self.hours.close - self.hours.open).num_seconds() as f64;
+        let (_, last) = self.search_bounds()?;
+        let mut date = start.date();
+        let mut previous_close = self.previous_close(&date)?;
+        let mut total = 0.0;
+        loop {
+            if let Some((open, close)) = self.local_session(&date) {
+                let gap_seconds = (open - previous_close).num_seconds() as f64;
+                total += overnight_weight * overlap(previous_close, open) / gap_seconds;
+                total += (1.0 - overnight_weight) * overlap(open, close) / session_seconds;
+                if open >= end {
+                    return Some(total);
+                }
+                previous_close = close;
+            }
+            date = date.succ_opt().filter(|next| *next <= last)?;
+        }
+    }
+
+    // Close of the last session before `date`, if there is one within the
+    // search bounds.
+    fn previous_close(&self, date: &NaiveDate) -> Option<NaiveDateTime> {
+        let (first, _) = self.search_bounds()?;
+        let mut date = *date;
+        loop {
+            date = date.pred_opt().filter(|previous| *previous >= first)?;
+            if let Some((_, close)) = self.local_session(&date) {
+                return Some(close);
+            }
+        }
+    }
+
+    // First and last dates a search for a session may visit: the calendar's
+    // valid range, or the whole representable range if it has none.  `None`
+    // if every weekday is a weekend day or the range is empty, as no search
+    // can succeed.
+    fn search_bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        if self.calendar.get_weekend().len() == 7 || self.calendar.has_empty_valid_range() {
+            return None;
+        }
+        Some(self.calendar.valid_range().unwrap_or((NaiveDate::MIN, NaiveDate::MAX)))
+    }
+
+    // Local opening and closing times on `date`, if the market trades.
+    pub(crate) fn local_session(&self, date: &NaiveDate) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        if !self.calendar.is_business_day(date) {
+            return None;
+        }
+        let close = self.calendar.close_time(date).unwrap_or(self.hours.close);
+        if close <= self.hours.open {
+            return None;
+        }
+        Some((date.and_time(self.hours.open), date.and_time(close)))
+    }
+
+    pub(crate) fn to_local<Tz2: TimeZone>(&self, datetime: &DateTime<Tz2>) -> NaiveDateTime {
+        datetime.with_timezone(&self.hours.timezone).naive_local()
+    }
+
+    // The instant of a local market time: the first one if a daylight saving
+    // change repeats it, `None` if one skips it.
+    fn to_market_time(&self, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+        self.hours.timezone.from_local_datetime(&local).earliest()
+    }
+}
@@ -0,0 +1,328 @@
+//! Audited holiday updates.
+//!
+//! A [`TrackedCalendar`] wraps a [`Calendar`] whose holidays change while a
+//! system is running, e.g. when a market declares a one-off closure.  Every
+//! addition and removal is recorded as a [`CalendarChange`] with the time it
+//! was made and its source, and the calendar can be rebuilt as it stood after
+//! any change, or rolled back to it.
+//!
+//! Versions count recorded changes: version 0 is the base calendar, and
+//! version `n` is the base calendar with the first `n` changes applied.  With
+//! the `serde` feature the history can be stored and later handed to
+//! [`TrackedCalendar::replay`] with the same base calendar.
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::calendar::Calendar;
+
+/// A calendar with a history of holiday changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use findates::calendar::basic_calendar;
+/// use findates::tracked::TrackedCalendar;
+///
+/// let closure = NaiveDate::from_ymd_opt(2024, 9, 19).unwrap();
+/// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+///
+/// let mut cal = TrackedCalendar::new(basic_calendar());
+/// assert!(cal.add_holiday(closure, now, "exchange notice 2024/17"));
+/// assert!(!cal.calendar().is_business_day(&closure));
+/// assert_eq!(cal.version(), 1);
+///
+/// cal.rollback(0).unwrap();
+/// assert!(cal.calendar().is_business_day(&closure));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrackedCalendar {
+    base: Calendar,
+    changes: Vec<CalendarChange>,
+    // `base` with every change in `changes` applied.
+    current: Calendar,
+}
+
+/// One recorded holiday change; see [`TrackedCalendar::history`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalendarChange {
+    /// Whether the holiday was added or removed.
+    pub kind: ChangeKind,
+    /// The holiday date.
+    pub date: NaiveDate,
+    /// When the change was made.
+    pub timestamp: DateTime<Utc>,
+    /// Where the change came from, e.g. a notice reference or a user name.
+    pub source: String,
+}
+
+/// The kind of a [`CalendarChange`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    /// The date became a holiday.
+    Added,
+    /// The date stopped being a holiday.
+    Removed,
+}
+
+impl TrackedCalendar {
+    /// Starts tracking changes to `calendar`, at version 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::Calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let cal = TrackedCalendar::new(Calendar::target2());
+    /// assert_eq!(cal.version(), 0);
+    /// assert_eq!(cal.calendar(), &Calendar::target2());
+    /// ```
+    pub fn new(calendar: Calendar) -> Self {
+        Self {
+            base: calendar.clone(),
+            changes: Vec::new(),
+            current: calendar,
+        }
+    }
+
+    /// Rebuilds a tracked calendar by applying `changes` to `base` in order,
+    /// e.g. from a history stored with the `serde` feature.
+    ///
+    /// Changes that would not alter the calendar are dropped, as
+    /// [`add_holiday`](Self::add_holiday) and
+    /// [`remove_holiday`](Self::remove_holiday) do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(basic_calendar());
+    /// cal.add_holiday(NaiveDate::from_ymd_opt(2024, 9, 19).unwrap(), now, "notice");
+    ///
+    /// let copy = TrackedCalendar::replay(basic_calendar(), cal.history().to_vec());
+    /// assert_eq!(copy, cal);
+    /// ```
+    pub fn replay(base: Calendar, changes: impl IntoIterator<Item = CalendarChange>) -> Self {
+        let mut tracked = Self::new(base);
+        for change in changes {
+            tracked.record(change);
+        }
+        tracked
+    }
+
+    /// The calendar with every recorded change applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// assert_eq!(TrackedCalendar::new(basic_calendar()).calendar(), &basic_calendar());
+    /// ```
+    pub fn calendar(&self) -> &Calendar {
+        &self.current
+    }
+
+    /// The calendar changes are recorded against, at version 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(basic_calendar());
+    /// cal.add_holiday(NaiveDate::from_ymd_opt(2024, 9, 19).unwrap(), now, "notice");
+    /// assert_eq!(cal.base(), &basic_calendar());
+    /// ```
+    pub fn base(&self) -> &Calendar {
+        &self.base
+    }
+
+    /// The current version: the number of recorded changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// assert_eq!(TrackedCalendar::new(basic_calendar()).version(), 0);
+    /// ```
+    pub fn version(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// The recorded changes, oldest first.  The change at index `i` produced
+    /// version `i + 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::{ChangeKind, TrackedCalendar};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 9, 19).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(basic_calendar());
+    /// cal.add_holiday(date, now, "notice");
+    /// cal.remove_holiday(date, now, "notice withdrawn");
+    ///
+    /// let kinds: Vec<ChangeKind> = cal.history().iter().map(|change| change.kind).collect();
+    /// assert_eq!(kinds, [ChangeKind::Added, ChangeKind::Removed]);
+    /// ```
+    pub fn history(&self) -> &[CalendarChange] {
+        &self.changes
+    }
+
+    /// Makes `date` a holiday, recording the change at `timestamp` from
+    /// `source`.
+    ///
+    /// Returns `false`, recording nothing, if `date` is already a holiday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::Calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(Calendar::target2());
+    /// assert!(!cal.add_holiday(xmas, now, "notice"));
+    /// assert_eq!(cal.version(), 0);
+    /// ```
+    pub fn add_holiday(&mut self, date: NaiveDate, timestamp: DateTime<Utc>, source: impl Into<String>) -> bool {
+        self.record(CalendarChange {
+            kind: ChangeKind::Added,
+            date,
+            timestamp,
+            source: source.into(),
+        })
+    }
+
+    /// Stops `date` being a holiday, recording the change at `timestamp`
+    /// from `source`.  Weekend days stay non-working.
+    ///
+    /// Returns `false`, recording nothing, if `date` is not a holiday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::Calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(Calendar::target2());
+    /// assert!(cal.remove_holiday(xmas, now, "notice"));
+    /// assert!(cal.calendar().is_business_day(&xmas));
+    /// ```
+    pub fn remove_holiday(&mut self, date: NaiveDate, timestamp: DateTime<Utc>, source: impl Into<String>) -> bool {
+        self.record(CalendarChange {
+            kind: ChangeKind::Removed,
+            date,
+            timestamp,
+            source: source.into(),
+        })
+    }
+
+    /// The calendar as it stood at `version`, or `None` if `version` is
+    /// after the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 9, 19).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(basic_calendar());
+    /// cal.add_holiday(date, now, "notice");
+    ///
+    /// assert_eq!(cal.at_version(0), Some(basic_calendar()));
+    /// assert!(cal.at_version(1).unwrap().is_holiday(&date));
+    /// assert_eq!(cal.at_version(2), None);
+    /// ```
+    pub fn at_version(&self, version: usize) -> Option<Calendar> {
+        let changes = self.changes.get(..version)?;
+        let mut calendar = self.base.clone();
+        for change in changes {
+            apply(&mut calendar, change);
+        }
+        Some(calendar)
+    }
+
+    /// Rolls the calendar back to `version`, discarding the later changes
+    /// and returning them, oldest first, so they can be archived.  Returns
+    /// `None`, leaving the calendar unchanged, if `version` is after the
+    /// current version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    /// use findates::calendar::basic_calendar;
+    /// use findates::tracked::TrackedCalendar;
+    ///
+    /// let d = |day| NaiveDate::from_ymd_opt(2024, 9, day).unwrap();
+    /// let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    /// let mut cal = TrackedCalendar::new(basic_calendar());
+    /// cal.add_holiday(d(19), now, "notice");
+    /// cal.add_holiday(d(20), now, "bad feed");
+    ///
+    /// let undone = cal.rollback(1).unwrap();
+    /// assert_eq!(undone[0].source, "bad feed");
+    /// assert_eq!(cal.calendar().get_holidays().len(), 1);
+    /// assert_eq!(cal.rollback(2), None);
+    /// ```
+    pub fn rollback(&mut self, version: usize) -> Option<Vec<CalendarChange>> {
+        if version > self.version() {
+            return None;
+        }
+        let undone = self.changes.split_off(version);
+        self.current = self.base.clone();
+        for change in &self.changes {
+            apply(&mut self.current, change);
+        }
+        Some(undone)
+    }
+
+    // Applies `change` and appends it to the history, unless it would leave
+    // the calendar unchanged.
+    fn record(&mut self, change: CalendarChange) -> bool {
+        let is_holiday = self.current.is_holiday(&change.date);
+        let changes = match change.kind {
+            ChangeKind::Added => !is_holiday,
+            ChangeKind::Removed => is_holiday,
+        };
+        if changes {
+            apply(&mut self.current, &change);
+            self.changes.push(change);
+        }
+        changes
+    }
+}
+
+// Adds or removes the holiday of `change` in `calendar`.
+fn apply(calendar: &mut Calendar, change: &CalendarChange) {
+    match change.kind {
+        ChangeKind::Added => calendar.add_holidays([change.date]),
+        ChangeKind::Removed => calendar.remove_holidays([change.date]),
+    }
+}
@@ -0,0 +1,148 @@
+//! Time-zone-aware counterparts of the date functions.
+//!
+//! The rest of the crate works on [`NaiveDate`]s.  The functions here take
+//! and return [`DateTime`]s in any [`TimeZone`] — typically a
+//! [`chrono_tz::Tz`] such as `Europe::London` — so that a fixing time like
+//! "11:00 London" can be attached to schedule dates and carried through
+//! business day adjustment.  Business days are judged on the local date in
+//! the datetime's own time zone, and the local time of day is preserved.
+//!
+//! Requires the `tz` feature, which pulls in
+//! [chrono-tz](https://docs.rs/chrono-tz).
+//!
+//! Local times repeated by a daylight saving change resolve to their first
+//! occurrence; local times skipped by one are an error.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone};
+
+use crate::algebra::checked_adjust;
+use crate::calendar::Calendar;
+use crate::conventions::AdjustRule;
+use crate::error::DateError;
+
+/// Returns `date` at local time `time` in `tz`.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidLocalTime`] if `time` does not exist on
+/// `date` in `tz` because a daylight saving change skips it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveTime, Utc};
+/// use chrono_tz::Europe::London;
+/// use findates::zoned::at_local_time;
+///
+/// let eleven = NaiveTime::from_hms_opt(11, 0, 0).unwrap();
+///
+/// // 11:00 London is 11:00 UTC in winter and 10:00 UTC in summer.
+/// let winter = at_local_time(&NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(), eleven, &London).unwrap();
+/// let summer = at_local_time(&NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(), eleven, &London).unwrap();
+/// assert_eq!(winter.with_timezone(&Utc).to_rfc3339(), "2024-01-15T11:00:00+00:00");
+/// assert_eq!(summer.with_timezone(&Utc).to_rfc3339(), "2024-07-15T10:00:00+00:00");
+/// ```
+pub fn at_local_time<Tz: TimeZone>(date: &NaiveDate, time: NaiveTime, tz: &Tz) -> Result<DateTime<Tz>, DateError> {
+    tz.from_local_datetime(&date.and_time(time))
+        .earliest()
+        .ok_or(DateError::InvalidLocalTime)
+}
+
+/// Attaches local time `time` in `tz` to every date in `dates`, e.g. the
+/// fixing time to a generated fixing schedule.
+///
+/// # Errors
+///
+/// Returns [`DateError::InvalidLocalTime`] if `time` does not exist on one
+/// of the dates; see [`at_local_time`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveTime, Timelike};
+/// use chrono_tz::Europe::London;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::{AdjustRule, Frequency};
+/// use findates::schedule::Schedule;
+/// use findates::zoned::with_local_time;
+///
+/// let cal   = basic_calendar();
+/// let sched = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+/// let dates = sched.generate(
+///     &NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+///     &NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+/// ).unwrap();
+///
+/// let fixings = with_local_time(&dates, NaiveTime::from_hms_opt(11, 0, 0).unwrap(), &London).unwrap();
+/// assert_eq!(fixings.len(), 5);
+/// assert!(fixings.iter().all(|fixing| fixing.hour() == 11));
+/// ```
+pub fn with_local_time<Tz: TimeZone>(
+    dates: &[NaiveDate],
+    time: NaiveTime,
+    tz: &Tz,
+) -> Result<Vec<DateTime<Tz>>, DateError> {
+    dates.iter().map(|date| at_local_time(date, time, tz)).collect()
+}
+
+/// Returns `true` if the local date of `datetime` is a business day in
+/// `calendar`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chrono_tz::Asia::Tokyo;
+/// use findates::calendar::basic_calendar;
+/// use findates::zoned::is_business_day;
+///
+/// let cal = basic_calendar();
+/// // Friday 20:00 UTC is already Saturday morning in Tokyo.
+/// let friday_evening = Utc.with_ymd_and_hms(2024, 3, 15, 20, 0, 0).unwrap();
+/// assert!(is_business_day(&friday_evening, &cal));
+/// assert!(!is_business_day(&friday_evening.with_timezone(&Tokyo), &cal));
+/// ```
+pub fn is_business_day<Tz: TimeZone>(datetime: &DateTime<Tz>, calendar: &Calendar) -> bool {
+    calendar.is_business_day(&datetime.date_naive())
+}
+
+/// Adjusts the local date of `datetime` like
+/// [`algebra::adjust`](crate::algebra::adjust), keeping its local time of
+/// day and time zone.
+///
+/// # Errors
+///
+/// Returns [`DateError::OutOfRange`] if the search for a business day runs
+/// off the representable date range, and [`DateError::InvalidLocalTime`]
+/// if the local time does not exist on the adjusted date.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveTime, TimeZone};
+/// use chrono_tz::Europe::London;
+/// use findates::calendar::basic_calendar;
+/// use findates::conventions::AdjustRule;
+/// use findates::zoned;
+///
+/// let cal = basic_calendar();
+/// let saturday = London.with_ymd_and_hms(2024, 3, 30, 11, 0, 0).unwrap();
+/// let adjusted = zoned::adjust(&saturday, Some(&cal), Some(AdjustRule::Following)).unwrap();
+///
+/// // British Summer Time starts on Sunday: Monday 11:00 is an hour earlier in UTC.
+/// assert_eq!(adjusted.date_naive(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+/// assert_eq!(adjusted.time(), NaiveTime::from_hms_opt(11, 0, 0).unwrap());
+/// assert_eq!(adjusted.naive_utc().time(), NaiveTime::from_hms_opt(10, 0, 0).unwrap());
+/// ```
+pub fn adjust<Tz: TimeZone>(
+    datetime: &DateTime<Tz>,
+    opt_calendar: Option<&Calendar>,
+    adjust_rule: Option<AdjustRule>,
+) -> Result<DateTime<Tz>, DateError> {
+    let local = datetime.naive_local();
+    let date = checked_adjust(&local.date(), opt_calendar, adjust_rule).ok_or(DateError::OutOfRange)?;
+    if date == local.date() {
+        return Ok(datetime.clone());
+    }
+    at_local_time(&date, local.time(), &datetime.timezone())
+}
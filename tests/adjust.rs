@@ -237,10 +237,55 @@ fn checked_add_years_backward_test() {
 
 #[test]
 fn checked_add_years_feb29_leap_to_nonleap_test() {
-    // Feb 29 in a leap year cannot be added to a non-leap year.
+    // Feb 29 in a leap year is clamped to Feb 28 of a non-leap year.
     let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
-    assert!(algebra::checked_add_years(&leap_day, 1).is_none()); // 2025 is not a leap year
-    assert!(algebra::checked_add_years(&leap_day, 4).is_some()); // 2028 is a leap year
+    assert_eq!(
+        algebra::checked_add_years(&leap_day, 1), // 2025 is not a leap year
+        NaiveDate::from_ymd_opt(2025, 2, 28)
+    );
+    assert_eq!(
+        algebra::checked_add_years(&leap_day, 4), // 2028 is a leap year
+        NaiveDate::from_ymd_opt(2028, 2, 29)
+    );
+}
+
+#[test]
+fn checked_add_years_with_policy_test() {
+    use algebra::MonthEndPolicy;
+    let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let add = |years, policy| algebra::checked_add_years_with(&leap_day, years, policy);
+    assert_eq!(add(1, MonthEndPolicy::ClampToEndOfMonth), NaiveDate::from_ymd_opt(2025, 2, 28));
+    assert_eq!(add(1, MonthEndPolicy::RollToNextMonth), NaiveDate::from_ymd_opt(2025, 3, 1));
+    assert_eq!(add(1, MonthEndPolicy::Reject), None);
+    assert_eq!(add(-4, MonthEndPolicy::Reject), NaiveDate::from_ymd_opt(2020, 2, 29));
+    assert_eq!(add(i32::MAX, MonthEndPolicy::ClampToEndOfMonth), None);
+}
+
+#[test]
+fn checked_sub_years_test() {
+    let d = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    assert_eq!(algebra::checked_sub_years(&d, 1), NaiveDate::from_ymd_opt(2022, 8, 15));
+    assert_eq!(algebra::checked_sub_years(&d, -1), algebra::checked_add_years(&d, 1));
+    let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(algebra::checked_sub_years(&leap_day, 1), NaiveDate::from_ymd_opt(2023, 2, 28));
+    assert_eq!(algebra::checked_sub_years(&d, i32::MIN), None);
+}
+
+#[test]
+fn checked_add_months_eom_test() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    let add = |date: NaiveDate, months| algebra::checked_add_months_eom(&date, months);
+    // Month ends stay on month ends
+    assert_eq!(add(ymd(2024, 2, 29).unwrap(), 1), ymd(2024, 3, 31));
+    assert_eq!(add(ymd(2024, 4, 30).unwrap(), 1), ymd(2024, 5, 31));
+    assert_eq!(add(ymd(2024, 3, 31).unwrap(), -1), ymd(2024, 2, 29));
+    assert_eq!(add(ymd(2024, 2, 29).unwrap(), 12), ymd(2025, 2, 28));
+    // Other days are clamped only when needed
+    assert_eq!(add(ymd(2024, 1, 31).unwrap(), 1), ymd(2024, 2, 29));
+    assert_eq!(add(ymd(2024, 1, 30).unwrap(), 1), ymd(2024, 2, 29));
+    assert_eq!(add(ymd(2024, 3, 30).unwrap(), 1), ymd(2024, 4, 30));
+    assert_eq!(add(ymd(2024, 4, 29).unwrap(), 0), ymd(2024, 4, 29));
+    assert_eq!(add(NaiveDate::MAX, 1), None);
 }
 
 #[test]
@@ -251,3 +296,219 @@ fn adjust_unadjusted_holiday_unchanged_test() {
     let result = algebra::adjust(&xmas, Some(&cal), Some(AdjustRule::Unadjusted));
     assert_eq!(result, xmas);
 }
+
+#[test]
+fn checked_adjust_out_of_range_test() {
+    // Nothing after NaiveDate::MAX: forward rules fail, backward rules still work.
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let max = NaiveDate::MAX;
+    assert_eq!(algebra::checked_adjust(&max, Some(&cal), Some(AdjustRule::Following)), None);
+    assert_eq!(
+        algebra::checked_adjust(&max, Some(&cal), Some(AdjustRule::Preceding)),
+        max.pred_opt()
+    );
+    // ModFollowing and Nearest fall back to the only business day available
+    assert_eq!(
+        algebra::checked_adjust(&max, Some(&cal), Some(AdjustRule::ModFollowing)),
+        max.pred_opt()
+    );
+    assert_eq!(
+        algebra::checked_adjust(&max, Some(&cal), Some(AdjustRule::Nearest)),
+        max.pred_opt()
+    );
+}
+
+#[test]
+fn checked_adjust_matches_adjust_test() {
+    let setup = AdjustSetup::new();
+    let cal = setup.cal;
+    for rule in [
+        AdjustRule::Following,
+        AdjustRule::ModFollowing,
+        AdjustRule::Preceding,
+        AdjustRule::ModPreceding,
+        AdjustRule::Unadjusted,
+        AdjustRule::HalfMonthModFollowing,
+        AdjustRule::Nearest,
+    ] {
+        for date in [setup.test_weekend, setup.test_holiday] {
+            assert_eq!(
+                algebra::checked_adjust(&date, Some(&cal), Some(rule)),
+                Some(algebra::adjust(&date, Some(&cal), Some(rule)))
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Valid Range Tests
+// ============================================================================
+
+#[test]
+fn try_adjust_valid_range_test() {
+    let mut cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap(); // Saturday
+    let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    cal.set_valid_range(start, end).unwrap();
+
+    // Inside the range: same answer as adjust.
+    let sat = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    assert_eq!(
+        algebra::try_adjust(&sat, Some(&cal), Some(AdjustRule::ModFollowing)),
+        Ok(algebra::adjust(&sat, Some(&cal), Some(AdjustRule::ModFollowing)))
+    );
+
+    // Input outside the range.
+    let before = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+    assert_eq!(
+        algebra::try_adjust(&before, Some(&cal), Some(AdjustRule::Following)),
+        Err(findates::CalendarError::OutOfRange { date: before })
+    );
+
+    // Adjusted date leaves the range.
+    let friday = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+    assert_eq!(
+        algebra::try_adjust(&start, Some(&cal), Some(AdjustRule::Preceding)),
+        Err(findates::CalendarError::OutOfRange { date: friday })
+    );
+
+    // Without a calendar there is no range to check.
+    assert_eq!(algebra::try_adjust(&before, None, Some(AdjustRule::Following)), Ok(before));
+}
+
+// ============================================================================
+// Batch Adjustment Tests
+// ============================================================================
+
+#[test]
+fn adjust_many_matches_adjust_test() {
+    let setup = AdjustSetup::new();
+    let dates: Vec<NaiveDate> = setup
+        .test_holiday
+        .checked_sub_days(Days::new(10))
+        .unwrap()
+        .iter_days()
+        .take(20)
+        .collect();
+    let rules = [
+        None,
+        Some(AdjustRule::Unadjusted),
+        Some(AdjustRule::Following),
+        Some(AdjustRule::ModPreceding),
+        Some(AdjustRule::Nearest),
+    ];
+    for rule in rules {
+        let expected: Vec<NaiveDate> = dates
+            .iter()
+            .map(|d| algebra::adjust(d, Some(&setup.cal), rule))
+            .collect();
+        assert_eq!(algebra::adjust_many(&dates, Some(&setup.cal), rule), expected, "{rule:?}");
+    }
+    assert_eq!(algebra::adjust_many(&dates, None, Some(AdjustRule::Following)), dates);
+    assert!(algebra::adjust_many(&[], Some(&setup.cal), Some(AdjustRule::Following)).is_empty());
+}
+
+// ============================================================================
+// Custom Adjuster Tests
+// ============================================================================
+
+// Following, unless the adjusted date would reach the next coupon date.
+struct FollowingBefore(NaiveDate);
+
+impl algebra::BusinessDayAdjuster for FollowingBefore {
+    fn checked_adjust(&self, date: &NaiveDate, cal: &calendar::Calendar) -> Option<NaiveDate> {
+        match AdjustRule::Following.checked_adjust(date, cal)? {
+            adjusted if adjusted < self.0 => Some(adjusted),
+            _ => AdjustRule::Preceding.checked_adjust(date, cal),
+        }
+    }
+}
+
+#[test]
+fn adjust_with_rule_matches_adjust_test() {
+    let setup = AdjustSetup::new();
+    let rules = [
+        AdjustRule::Unadjusted,
+        AdjustRule::Following,
+        AdjustRule::ModFollowing,
+        AdjustRule::Preceding,
+        AdjustRule::ModPreceding,
+        AdjustRule::HalfMonthModFollowing,
+        AdjustRule::Nearest,
+    ];
+    let start = setup.test_holiday.checked_sub_days(Days::new(10)).unwrap();
+    for date in start.iter_days().take(20) {
+        for rule in rules {
+            assert_eq!(
+                algebra::adjust_with(&date, Some(&setup.cal), &rule),
+                algebra::adjust(&date, Some(&setup.cal), Some(rule)),
+                "{date} {rule:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn adjust_with_custom_adjuster_test() {
+    let setup = AdjustSetup::new();
+    let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+
+    let far_coupon = FollowingBefore(NaiveDate::from_ymd_opt(2024, 6, 15).unwrap());
+    assert_eq!(algebra::adjust_with(&saturday, Some(&setup.cal), &far_coupon), monday);
+
+    let near_coupon = FollowingBefore(monday);
+    assert_eq!(algebra::adjust_with(&saturday, Some(&setup.cal), &near_coupon), friday);
+    assert_eq!(algebra::adjust_with(&saturday, None, &near_coupon), saturday);
+
+    let dynamic: &dyn algebra::BusinessDayAdjuster = &near_coupon;
+    assert_eq!(algebra::checked_adjust_with(&saturday, Some(&setup.cal), dynamic), Some(friday));
+}
+
+#[test]
+fn adjust_explain_reason_test() {
+    let mut setup = AdjustSetup::new();
+    setup.cal.add_named_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), "Christmas Day");
+    let rule = Some(AdjustRule::ModFollowing);
+
+    let weekend = algebra::adjust_explain(&setup.test_weekend, Some(&setup.cal), rule);
+    assert_eq!(weekend.original, setup.test_weekend);
+    assert_eq!(weekend.adjusted, NaiveDate::from_ymd_opt(2023, 9, 4).unwrap());
+    assert_eq!(weekend.days_moved, 2);
+    assert_eq!(weekend.reason, algebra::AdjustReason::Weekend(Weekday::Sat));
+
+    // Christmas 2023 has no name in the calendar; Christmas 2024 does.
+    let unnamed = algebra::adjust_explain(&setup.test_holiday, Some(&setup.cal), rule);
+    assert_eq!(unnamed.adjusted, NaiveDate::from_ymd_opt(2023, 12, 27).unwrap());
+    assert_eq!(unnamed.reason, algebra::AdjustReason::Holiday(None));
+    let named = algebra::adjust_explain(&NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), Some(&setup.cal), rule);
+    assert_eq!(named.reason, algebra::AdjustReason::Holiday(Some("Christmas Day".to_string())));
+
+    let monday = NaiveDate::from_ymd_opt(2023, 9, 4).unwrap();
+    let business_day = algebra::adjust_explain(&monday, Some(&setup.cal), rule);
+    assert_eq!((business_day.adjusted, business_day.days_moved), (monday, 0));
+    assert_eq!(business_day.reason, algebra::AdjustReason::BusinessDay);
+}
+
+#[test]
+fn adjust_explain_matches_adjust_test() {
+    let setup = AdjustSetup::new();
+    let rules = [
+        None, Some(AdjustRule::Following), Some(AdjustRule::ModFollowing), Some(AdjustRule::Preceding),
+        Some(AdjustRule::ModPreceding), Some(AdjustRule::Unadjusted), Some(AdjustRule::Nearest),
+    ];
+    let start = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+    for date in start.iter_days().take(20) {
+        for rule in rules {
+            let explained = algebra::adjust_explain(&date, Some(&setup.cal), rule);
+            assert_eq!(explained.adjusted, algebra::adjust(&date, Some(&setup.cal), rule));
+            assert_eq!(date + chrono::Duration::days(explained.days_moved), explained.adjusted);
+            if explained.days_moved != 0 {
+                assert!(!setup.cal.is_business_day(&date));
+            }
+        }
+        let explained = algebra::adjust_explain(&date, None, Some(AdjustRule::Following));
+        assert_eq!((explained.adjusted, explained.reason), (date, algebra::AdjustReason::NotAdjusted));
+    }
+}
@@ -1,23 +1,18 @@
-use chrono::NaiveDate;
-use findates::algebra::{add_business_days, subtract_business_days};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveTime, Utc, Weekday};
+use findates::algebra::{
+    add_business_days, date_from_timestamp, days_in_month, easter_sunday, end_of_month, first_business_day_of_iso_week,
+    first_business_day_of_month, first_business_day_of_quarter, first_business_day_of_semester, is_end_of_month,
+    is_last_business_day_of_month, iso_week_end, iso_week_start, last_business_day_of_iso_week, last_business_day_of_month,
+    last_business_day_of_quarter, last_business_day_of_semester, last_weekday_of_month, next_business_day,
+    nth_business_day_of_month, nth_weekday_of_month, previous_business_day, quarter_end, quarter_of, quarter_start,
+    semester_end, semester_start, settlement_date, subtract_business_days, timestamp_at,
+};
 use findates::calendar::{basic_calendar, Calendar};
-use findates::error::BusinessDayError;
+use findates::conventions::AdjustRule;
+use findates::error::{BusinessDayError, DateError};
 
-fn d(y: i32, m: u32, day: u32) -> NaiveDate {
-    NaiveDate::from_ymd_opt(y, m, day).unwrap()
-}
-
-fn calendar_with_holiday(date: NaiveDate) -> Calendar {
-    let mut cal = basic_calendar();
-    cal.add_holidays([date]);
-    cal
-}
-
-fn calendar_with_holidays(dates: impl IntoIterator<Item = NaiveDate>) -> Calendar {
-    let mut cal = basic_calendar();
-    cal.add_holidays(dates);
-    cal
-}
+mod setup;
+use setup::{calendar_with_holidays, d};
 
 // ── add_business_days ─────────────────────────────────────────────────────────
 
@@ -34,7 +29,7 @@ fn add_bd_invalid_start_weekend_test() {
 #[test]
 fn add_bd_invalid_start_holiday_test() {
     let monday = d(2024, 3, 18);
-    let cal = calendar_with_holiday(monday);
+    let cal = calendar_with_holidays([monday]);
     assert_eq!(
         add_business_days(&monday, 1, &cal),
         Err(BusinessDayError::InvalidStartDate)
@@ -103,6 +98,14 @@ fn add_bd_long_holiday_test() {
     );
 }
 
+#[test]
+fn add_bd_out_of_range_test() {
+    let cal = basic_calendar();
+    let last = NaiveDate::MAX.iter_days().rev().find(|day| cal.is_business_day(day)).unwrap();
+    assert_eq!(add_business_days(&last, 0, &cal), Ok(last));
+    assert_eq!(add_business_days(&last, 1, &cal), Err(BusinessDayError::OutOfRange));
+}
+
 // ── subtract_business_days ────────────────────────────────────────────────────
 
 #[test]
@@ -118,7 +121,7 @@ fn sub_bd_invalid_start_weekend_test() {
 #[test]
 fn sub_bd_invalid_start_holiday_test() {
     let monday = d(2024, 3, 18);
-    let cal = calendar_with_holiday(monday);
+    let cal = calendar_with_holidays([monday]);
     assert_eq!(
         subtract_business_days(&monday, 1, &cal),
         Err(BusinessDayError::InvalidStartDate)
@@ -186,3 +189,318 @@ fn sub_bd_long_holiday_test() {
         d(2024, 3, 15)
     );
 }
+
+#[test]
+fn sub_bd_out_of_range_test() {
+    let cal = basic_calendar();
+    let first = NaiveDate::MIN.iter_days().find(|day| cal.is_business_day(day)).unwrap();
+    assert_eq!(subtract_business_days(&first, 0, &cal), Ok(first));
+    assert_eq!(subtract_business_days(&first, 1, &cal), Err(BusinessDayError::OutOfRange));
+}
+
+// ── Settlement dates ─────────────────────────────────────────────────────────
+
+#[test]
+fn settlement_date_t_plus_n_test() {
+    let cal = basic_calendar();
+    let wednesday = d(2024, 3, 13);
+    assert_eq!(settlement_date(&wednesday, 0, &cal, None), Some(wednesday));
+    assert_eq!(settlement_date(&wednesday, 1, &cal, None), Some(d(2024, 3, 14)));
+    assert_eq!(settlement_date(&wednesday, 2, &cal, None), Some(d(2024, 3, 15)));
+    // T+3 crosses the weekend
+    assert_eq!(settlement_date(&wednesday, 3, &cal, None), Some(d(2024, 3, 18)));
+}
+
+#[test]
+fn settlement_date_skips_holidays_test() {
+    let cal = calendar_with_holidays([d(2024, 12, 25), d(2024, 12, 26)]);
+    let tuesday = d(2024, 12, 24);
+    assert_eq!(settlement_date(&tuesday, 2, &cal, None), Some(d(2024, 12, 30)));
+}
+
+#[test]
+fn settlement_date_non_business_trade_date_test() {
+    let cal = basic_calendar();
+    let sunday = d(2024, 3, 17);
+    assert_eq!(settlement_date(&sunday, 0, &cal, None), Some(d(2024, 3, 18)));
+    assert_eq!(settlement_date(&sunday, 2, &cal, None), Some(d(2024, 3, 19)));
+    assert_eq!(
+        settlement_date(&sunday, 2, &cal, Some(AdjustRule::Following)),
+        Some(d(2024, 3, 20))
+    );
+    assert_eq!(
+        settlement_date(&sunday, 2, &cal, Some(AdjustRule::Preceding)),
+        Some(d(2024, 3, 19))
+    );
+}
+
+#[test]
+fn settlement_date_out_of_range_test() {
+    let cal = basic_calendar();
+    assert_eq!(settlement_date(&NaiveDate::MAX, 5, &cal, None), None);
+}
+
+// ── Next / previous business day ─────────────────────────────────────────────
+
+#[test]
+fn next_business_day_test() {
+    let cal = calendar_with_holidays([d(2024, 3, 18)]);
+    // From a business day, a weekend day and across a holiday
+    assert_eq!(next_business_day(&d(2024, 3, 14), &cal), Some(d(2024, 3, 15)));
+    assert_eq!(next_business_day(&d(2024, 3, 16), &cal), Some(d(2024, 3, 19)));
+    assert_eq!(next_business_day(&d(2024, 3, 15), &cal), Some(d(2024, 3, 19)));
+}
+
+#[test]
+fn previous_business_day_test() {
+    let cal = calendar_with_holidays([d(2024, 3, 15)]);
+    assert_eq!(previous_business_day(&d(2024, 3, 14), &cal), Some(d(2024, 3, 13)));
+    assert_eq!(previous_business_day(&d(2024, 3, 17), &cal), Some(d(2024, 3, 14)));
+    assert_eq!(previous_business_day(&d(2024, 3, 18), &cal), Some(d(2024, 3, 14)));
+}
+
+#[test]
+fn next_previous_business_day_out_of_range_test() {
+    let cal = basic_calendar();
+    let last = NaiveDate::MAX.iter_days().rev().find(|day| cal.is_business_day(day)).unwrap();
+    let first = NaiveDate::MIN.iter_days().find(|day| cal.is_business_day(day)).unwrap();
+    assert_eq!(next_business_day(&last, &cal), None);
+    assert_eq!(previous_business_day(&first, &cal), None);
+    assert_eq!(previous_business_day(&last, &cal).and_then(|day| next_business_day(&day, &cal)), Some(last));
+}
+
+// ── Business days of the month ───────────────────────────────────────────────
+
+#[test]
+fn first_business_day_of_month_test() {
+    // 1 Jan 2024 is a Monday holiday; 1 Sep 2024 is a Sunday
+    let cal = calendar_with_holidays([d(2024, 1, 1)]);
+    assert_eq!(first_business_day_of_month(2024, 1, &cal), Some(d(2024, 1, 2)));
+    assert_eq!(first_business_day_of_month(2024, 9, &cal), Some(d(2024, 9, 2)));
+    assert_eq!(first_business_day_of_month(2024, 10, &cal), Some(d(2024, 10, 1)));
+    assert_eq!(first_business_day_of_month(2024, 13, &cal), None);
+}
+
+#[test]
+fn last_business_day_of_month_test() {
+    // 31 May 2024 is a Friday holiday; 29 Feb 2024 is a Thursday
+    let cal = calendar_with_holidays([d(2024, 5, 31)]);
+    assert_eq!(last_business_day_of_month(2024, 5, &cal), Some(d(2024, 5, 30)));
+    assert_eq!(last_business_day_of_month(2024, 2, &cal), Some(d(2024, 2, 29)));
+    assert_eq!(last_business_day_of_month(2024, 3, &cal), Some(d(2024, 3, 29)));
+    assert_eq!(last_business_day_of_month(2024, 0, &cal), None);
+}
+
+#[test]
+fn nth_business_day_of_month_test() {
+    let cal = calendar_with_holidays([d(2024, 12, 25), d(2024, 12, 26)]);
+    // December 2024 has 22 weekdays, two of them holidays
+    assert_eq!(nth_business_day_of_month(2024, 12, 1, &cal), Some(d(2024, 12, 2)));
+    assert_eq!(nth_business_day_of_month(2024, 12, 18, &cal), Some(d(2024, 12, 27)));
+    assert_eq!(nth_business_day_of_month(2024, 12, 20, &cal), Some(d(2024, 12, 31)));
+    assert_eq!(nth_business_day_of_month(2024, 12, 21, &cal), None);
+    assert_eq!(nth_business_day_of_month(2024, 12, 0, &cal), None);
+    assert_eq!(
+        nth_business_day_of_month(2024, 12, 20, &cal),
+        last_business_day_of_month(2024, 12, &cal)
+    );
+}
+
+#[test]
+fn business_day_of_month_without_business_days_test() {
+    let mut cal = Calendar::new();
+    cal.add_holidays(d(2024, 2, 1).iter_days().take(29));
+    assert_eq!(first_business_day_of_month(2024, 2, &cal), None);
+    assert_eq!(last_business_day_of_month(2024, 2, &cal), None);
+    assert_eq!(first_business_day_of_month(2024, 3, &cal), Some(d(2024, 3, 1)));
+}
+
+// ── End of month ─────────────────────────────────────────────────────────────
+
+#[test]
+fn days_in_month_test() {
+    let lengths: Vec<u32> = (1..=12).map(|m| days_in_month(2023, m).unwrap()).collect();
+    assert_eq!(lengths, [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+    assert_eq!(days_in_month(2024, 2), Some(29));
+    assert_eq!(days_in_month(1900, 2), Some(28));
+    assert_eq!(days_in_month(2000, 2), Some(29));
+    assert_eq!(days_in_month(2024, 0), None);
+    assert_eq!(days_in_month(2024, 13), None);
+    assert_eq!(days_in_month(i32::MAX, 12), None);
+    assert_eq!(days_in_month(i32::MIN, 12), None);
+}
+
+#[test]
+fn end_of_month_test() {
+    assert_eq!(end_of_month(&d(2024, 1, 1)), d(2024, 1, 31));
+    assert_eq!(end_of_month(&d(2023, 2, 14)), d(2023, 2, 28));
+    assert_eq!(end_of_month(&d(2024, 2, 29)), d(2024, 2, 29));
+    assert_eq!(end_of_month(&d(2024, 12, 5)), d(2024, 12, 31));
+    assert_eq!(end_of_month(&NaiveDate::MAX), NaiveDate::MAX);
+}
+
+#[test]
+fn is_end_of_month_test() {
+    assert!(is_end_of_month(&d(2024, 4, 30)));
+    assert!(is_end_of_month(&d(2024, 2, 29)));
+    assert!(!is_end_of_month(&d(2024, 2, 28)));
+    assert!(!is_end_of_month(&d(2024, 3, 30)));
+    assert!(is_end_of_month(&NaiveDate::MAX));
+}
+
+#[test]
+fn is_last_business_day_of_month_test() {
+    // 28 March 2024 is the last business day before the Good Friday holiday
+    let cal = calendar_with_holidays([d(2024, 3, 29)]);
+    assert!(is_last_business_day_of_month(&d(2024, 3, 28), &cal));
+    assert!(!is_last_business_day_of_month(&d(2024, 3, 29), &cal));
+    assert!(!is_last_business_day_of_month(&d(2024, 3, 31), &cal));
+    assert!(!is_last_business_day_of_month(&d(2024, 3, 27), &cal));
+    assert!(is_last_business_day_of_month(&d(2024, 4, 30), &cal));
+}
+
+// ── Quarters, semesters and weeks ────────────────────────────────────────────
+
+#[test]
+fn quarter_test() {
+    for (month, quarter, start, end) in [(1, 1, d(2024, 1, 1), d(2024, 3, 31)), (6, 2, d(2024, 4, 1), d(2024, 6, 30)),
+        (7, 3, d(2024, 7, 1), d(2024, 9, 30)), (12, 4, d(2024, 10, 1), d(2024, 12, 31))]
+    {
+        let date = d(2024, month, 15);
+        assert_eq!(quarter_of(&date), quarter);
+        assert_eq!(quarter_start(&date), start);
+        assert_eq!(quarter_end(&date), end);
+        assert_eq!(quarter_of(&start), quarter);
+        assert_eq!(quarter_of(&end), quarter);
+    }
+    assert_eq!(quarter_end(&NaiveDate::MAX), NaiveDate::MAX);
+}
+
+#[test]
+fn semester_test() {
+    assert_eq!(semester_start(&d(2024, 6, 30)), d(2024, 1, 1));
+    assert_eq!(semester_end(&d(2024, 1, 1)), d(2024, 6, 30));
+    assert_eq!(semester_start(&d(2024, 7, 1)), d(2024, 7, 1));
+    assert_eq!(semester_end(&d(2024, 7, 1)), d(2024, 12, 31));
+}
+
+#[test]
+fn iso_week_test() {
+    // ISO week 1 of 2026 runs from Monday 29 December 2025 to Sunday 4 January.
+    for date in d(2025, 12, 29).iter_days().take(7) {
+        assert_eq!(iso_week_start(&date), d(2025, 12, 29));
+        assert_eq!(iso_week_end(&date), d(2026, 1, 4));
+        assert_eq!(iso_week_start(&date).iso_week(), date.iso_week());
+    }
+    assert_eq!(iso_week_end(&NaiveDate::MIN).weekday(), Weekday::Sun);
+    // The ends of the date range cut their weeks short.
+    assert_eq!(iso_week_start(&NaiveDate::MIN), NaiveDate::MIN);
+    assert_eq!(iso_week_end(&NaiveDate::MAX), NaiveDate::MAX);
+}
+
+#[test]
+fn business_days_of_reporting_periods_test() {
+    // Good Friday and Easter Monday 2024 straddle the end of the first quarter.
+    let cal = calendar_with_holidays([d(2024, 1, 1), d(2024, 3, 29), d(2024, 4, 1), d(2024, 12, 31)]);
+    assert_eq!(first_business_day_of_quarter(&d(2024, 2, 10), &cal), Some(d(2024, 1, 2)));
+    assert_eq!(last_business_day_of_quarter(&d(2024, 2, 10), &cal), Some(d(2024, 3, 28)));
+    assert_eq!(first_business_day_of_quarter(&d(2024, 5, 10), &cal), Some(d(2024, 4, 2)));
+    assert_eq!(last_business_day_of_quarter(&d(2024, 11, 1), &cal), Some(d(2024, 12, 30)));
+    assert_eq!(first_business_day_of_semester(&d(2024, 5, 10), &cal), Some(d(2024, 1, 2)));
+    assert_eq!(last_business_day_of_semester(&d(2024, 7, 1), &cal), Some(d(2024, 12, 30)));
+    assert_eq!(first_business_day_of_iso_week(&d(2024, 3, 31), &cal), Some(d(2024, 3, 25)));
+    assert_eq!(last_business_day_of_iso_week(&d(2024, 3, 31), &cal), Some(d(2024, 3, 28)));
+    assert_eq!(first_business_day_of_iso_week(&d(2024, 4, 1), &cal), Some(d(2024, 4, 2)));
+
+    let mut closed = Calendar::new();
+    closed.add_holidays(d(2024, 1, 1).iter_days().take(91));
+    assert_eq!(last_business_day_of_quarter(&d(2024, 2, 10), &closed), None);
+    assert_eq!(first_business_day_of_iso_week(&d(2024, 2, 10), &closed), None);
+    assert_eq!(first_business_day_of_quarter(&d(2024, 4, 10), &closed), Some(d(2024, 4, 1)));
+}
+
+// ── Weekdays of the month ────────────────────────────────────────────────────
+
+#[test]
+fn nth_weekday_of_month_matches_chrono_test() {
+    let weekdays = [Weekday::Mon, Weekday::Wed, Weekday::Sat, Weekday::Sun];
+    for year in [1999, 2024, 2025] {
+        for month in 1..=12 {
+            for weekday in weekdays {
+                for n in 1..=5u8 {
+                    let expected = NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+                        .ok_or(DateError::InvalidOrdinal);
+                    assert_eq!(nth_weekday_of_month(year, month, weekday, n), expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn nth_weekday_of_month_errors_test() {
+    assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 0), Err(DateError::InvalidOrdinal));
+    assert_eq!(nth_weekday_of_month(2024, 11, Weekday::Thu, 6), Err(DateError::InvalidOrdinal));
+    assert_eq!(nth_weekday_of_month(2024, 13, Weekday::Thu, 1), Err(DateError::InvalidMonth));
+    assert_eq!(nth_weekday_of_month(2024, 0, Weekday::Thu, 1), Err(DateError::InvalidMonth));
+    assert_eq!(nth_weekday_of_month(i32::MAX, 1, Weekday::Thu, 1), Err(DateError::OutOfRange));
+    // February 2015 has exactly four of every weekday
+    assert_eq!(nth_weekday_of_month(2015, 2, Weekday::Sun, 4), Ok(d(2015, 2, 22)));
+    assert_eq!(nth_weekday_of_month(2015, 2, Weekday::Sun, 5), Err(DateError::InvalidOrdinal));
+}
+
+#[test]
+fn last_weekday_of_month_test() {
+    assert_eq!(last_weekday_of_month(2024, 5, Weekday::Mon), Ok(d(2024, 5, 27)));
+    assert_eq!(last_weekday_of_month(2024, 3, Weekday::Sun), Ok(d(2024, 3, 31)));
+    assert_eq!(last_weekday_of_month(2024, 2, Weekday::Fri), Ok(d(2024, 2, 23)));
+    assert_eq!(last_weekday_of_month(2024, 2, Weekday::Thu), Ok(d(2024, 2, 29)));
+    assert_eq!(last_weekday_of_month(2024, 13, Weekday::Mon), Err(DateError::InvalidMonth));
+    assert_eq!(last_weekday_of_month(i32::MAX, 12, Weekday::Mon), Err(DateError::OutOfRange));
+    for month in 1..=12 {
+        let last = last_weekday_of_month(2025, month, Weekday::Wed).unwrap();
+        assert_eq!(last.weekday(), Weekday::Wed);
+        assert!(last + chrono::Days::new(7) > end_of_month(&last));
+    }
+}
+
+// ── Easter ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn easter_sunday_test() {
+    let expected = [
+        d(2000, 4, 23), d(2008, 3, 23), d(2011, 4, 24), d(2019, 4, 21), d(2024, 3, 31), d(2025, 4, 20),
+        d(2038, 4, 25), d(2285, 3, 22),
+    ];
+    for date in expected {
+        assert_eq!(easter_sunday(date.year()), Ok(date));
+    }
+    for year in 1900..2200 {
+        let easter = easter_sunday(year).unwrap();
+        assert_eq!(easter.weekday(), Weekday::Sun);
+        assert!(d(year, 3, 22) <= easter && easter <= d(year, 4, 25));
+    }
+    assert_eq!(easter_sunday(i32::MAX), Err(DateError::OutOfRange));
+}
+
+// ── Timestamps ───────────────────────────────────────────────────────────────
+
+#[test]
+fn timestamp_roundtrip_test() {
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    assert_eq!(date_from_timestamp(0, &Utc), Ok(d(1970, 1, 1)));
+    assert_eq!(date_from_timestamp(-1, &Utc), Ok(d(1969, 12, 31)));
+    assert_eq!(date_from_timestamp(-1, &tokyo), Ok(d(1970, 1, 1)));
+    assert_eq!(date_from_timestamp(i64::MAX, &Utc), Err(DateError::OutOfRange));
+
+    for date in [d(1969, 7, 20), d(2000, 2, 29), d(2024, 12, 31)] {
+        for tz in [tokyo, new_york] {
+            let midnight = timestamp_at(&date, NaiveTime::MIN, &tz).unwrap();
+            assert_eq!(date_from_timestamp(midnight, &tz), Ok(date));
+            assert_eq!(date_from_timestamp(midnight - 1, &tz), Ok(date.pred_opt().unwrap()));
+        }
+    }
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(timestamp_at(&d(2024, 3, 15), noon, &new_york), Ok(1_710_522_000));
+}
@@ -2,7 +2,7 @@
 // These tests validate business day determination, schedule generation, and business day counting
 // based on calendar rules (weekends, holidays) and adjustment conventions.
 
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
 use findates::algebra;
 use findates::calendar;
 use findates::calendar::Calendar;
@@ -33,6 +33,56 @@ fn is_business_day_test() {
     assert!(!algebra::is_business_day(&christmas_day, &basic_cal));
 }
 
+#[test]
+fn is_holiday_and_is_weekend_test() {
+    // Christmas 2022 fell on a Sunday: both a holiday and a weekend day
+    let xmas = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+    let observed = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+    let saturday = NaiveDate::from_ymd_opt(2022, 12, 24).unwrap();
+    let tuesday = NaiveDate::from_ymd_opt(2022, 12, 27).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([xmas, observed]);
+
+    assert!(cal.is_holiday(&xmas) && cal.is_weekend(&xmas));
+    assert!(cal.is_holiday(&observed) && !cal.is_weekend(&observed));
+    assert!(!cal.is_holiday(&saturday) && cal.is_weekend(&saturday));
+    assert!(!cal.is_holiday(&tuesday) && !cal.is_weekend(&tuesday));
+
+    for date in [xmas, observed, saturday, tuesday] {
+        assert_eq!(cal.is_business_day(&date), algebra::is_business_day(&date, &cal));
+        assert_eq!(cal.is_business_day(&date), !cal.is_holiday(&date) && !cal.is_weekend(&date));
+    }
+}
+
+#[test]
+fn weekend_preset_constructors_test() {
+    assert_eq!(Calendar::weekend_only(), calendar::basic_calendar());
+    let gcc = Calendar::middle_east_weekend();
+    assert_eq!(gcc.get_weekend().len(), 2);
+    assert!(gcc.is_weekend(&NaiveDate::from_ymd_opt(2024, 3, 15).unwrap())); // Friday
+    assert!(gcc.is_weekend(&NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())); // Saturday
+    assert!(!gcc.is_weekend(&NaiveDate::from_ymd_opt(2024, 3, 17).unwrap())); // Sunday
+    assert!(gcc.get_holidays().is_empty());
+}
+
+#[test]
+fn with_workweek_test() {
+    let israel = Calendar::with_workweek([Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu]);
+    assert_eq!(israel, Ok(Calendar::middle_east_weekend()));
+
+    let weekdays = vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+    assert_eq!(Calendar::with_workweek(&weekdays), Ok(Calendar::weekend_only()));
+    assert_eq!(weekdays.len(), 5);
+
+    let seven_days: Vec<Weekday> = (0..7).map(|i| Weekday::try_from(i as u8).unwrap()).collect();
+    assert!(Calendar::with_workweek(seven_days).unwrap().get_weekend().is_empty());
+
+    assert_eq!(
+        Calendar::with_workweek(Vec::<Weekday>::new()),
+        Err(findates::CalendarError::NoBusinessDays)
+    );
+}
+
 #[test]
 fn convenience_constructors_work_in_business_day_logic() {
     let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
@@ -59,6 +109,7 @@ fn convenience_constructors_work_in_business_day_logic() {
 // ============================================================================
 
 #[test]
+#[allow(deprecated)]
 fn schedule_test() {
     let mut test_schedule: Vec<NaiveDate> = [].to_vec();
     let hol = NaiveDate::from_ymd_opt(2023, 9, 22).unwrap();
@@ -113,6 +164,7 @@ fn bus_days_between_test() {
 // ============================================================================
 
 #[test]
+#[allow(deprecated)]
 fn bus_day_schedule_single_day_test() {
     // start == end: the schedule should contain exactly that one business day.
     let cal = calendar::basic_calendar();
@@ -133,6 +185,7 @@ fn business_days_between_same_day_test() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn bus_day_schedule_holiday_gap_test() {
     // A week with Christmas (Wednesday) and Boxing Day (Thursday) as holidays.
     let mut cal = calendar::basic_calendar();
@@ -160,3 +213,927 @@ fn calendar_default_is_empty_test() {
     assert!(cal.get_holidays().is_empty());
     assert!(cal.get_weekend().is_empty());
 }
+
+// ============================================================================
+// CSV Load / Save Tests
+// ============================================================================
+
+#[test]
+fn calendar_from_csv_test() {
+    let csv = "date,name\n\
+               2024-12-25,Christmas Day\n\
+               \n\
+               # UK only\n\
+               2024-12-26,Boxing Day\n\
+               2025-01-01\n";
+    let cal = Calendar::from_csv(csv.as_bytes()).unwrap();
+
+    let mut expected = Calendar::with_holidays([NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()]);
+    expected.add_named_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), "Christmas Day");
+    expected.add_named_holiday(NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(), "Boxing Day");
+    assert_eq!(cal, expected);
+}
+
+#[test]
+fn calendar_from_csv_invalid_date_test() {
+    let csv = "2024-12-25\n2024-13-01\n";
+    let err = Calendar::from_csv(csv.as_bytes()).unwrap_err();
+    assert!(matches!(err, findates::CalendarIoError::InvalidDate { line: 2 }));
+}
+
+#[test]
+fn calendar_csv_roundtrip_test() {
+    let mut cal = Calendar::with_holidays([
+        NaiveDate::from_ymd_opt(2024, 5, 27).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ]);
+    let mut out = Vec::new();
+    cal.to_csv_writer(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), "date,name\n2024-01-01,\n2024-05-27,\n");
+
+    // Weekends are not part of the CSV format.
+    cal.add_weekends([Weekday::Sat, Weekday::Sun]);
+    let back = Calendar::from_csv(out.as_slice()).unwrap();
+    assert_eq!(back.get_holidays(), cal.get_holidays());
+    assert!(back.get_weekend().is_empty());
+}
+
+#[test]
+fn calendar_csv_roundtrip_quoted_names_test() {
+    let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+    let mut cal = Calendar::new();
+    cal.add_named_holiday(d(1, 1), "New Year's Day");
+    cal.add_named_holiday(d(5, 27), "Memorial Day, observed");
+    cal.add_named_holiday(d(7, 4), "Independence\nDay");
+    cal.add_named_holiday(d(11, 28), "  Thanksgiving ");
+    cal.add_named_holiday(d(12, 25), "\"Christmas Day\"");
+    let mut out = Vec::new();
+    cal.to_csv_writer(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "date,name\n\
+         2024-01-01,New Year's Day\n\
+         2024-05-27,\"Memorial Day, observed\"\n\
+         2024-07-04,\"Independence\nDay\"\n\
+         2024-11-28,\"  Thanksgiving \"\n\
+         2024-12-25,\"\"\"Christmas Day\"\"\"\n"
+    );
+    assert_eq!(Calendar::from_csv(out.as_slice()).unwrap(), cal);
+
+    // Quotes written by other tools are stripped, not stored.
+    let csv = "2024-12-25, \"Christmas Day\" \n2024-12-26,Boxing Day\n";
+    let back = Calendar::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(back.holiday_name(&d(12, 25)), Some("Christmas Day"));
+    assert_eq!(back.holiday_name(&d(12, 26)), Some("Boxing Day"));
+}
+
+#[test]
+fn calendar_from_csv_invalid_name_test() {
+    let unterminated = "2024-12-24,Christmas Eve\n2024-12-25,\"Christmas Day\n2024-12-26,Boxing Day\n";
+    let err = Calendar::from_csv(unterminated.as_bytes()).unwrap_err();
+    assert!(matches!(err, findates::CalendarIoError::InvalidName { line: 2 }));
+    let trailing = "2024-12-25,\"Christmas\" Day\n";
+    let err = Calendar::from_csv(trailing.as_bytes()).unwrap_err();
+    assert!(matches!(err, findates::CalendarIoError::InvalidName { line: 1 }));
+}
+
+#[test]
+fn holidays_iterate_in_date_order_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2025, 1, 1), d(2024, 12, 25), d(2024, 5, 27), d(2024, 12, 26)]);
+
+    let sorted: Vec<NaiveDate> = cal.iter_holidays_sorted().collect();
+    assert_eq!(sorted, [d(2024, 5, 27), d(2024, 12, 25), d(2024, 12, 26), d(2025, 1, 1)]);
+    assert_eq!(cal.holidays_between(&d(2024, 12, 25), &d(2025, 1, 1)), &sorted[1..]);
+    assert!(cal.holidays_between(&d(2025, 1, 1), &d(2024, 1, 1)).is_empty());
+
+    // Debug output does not depend on insertion order.
+    let mut reversed = Calendar::with_weekends([Weekday::Sun, Weekday::Sat]);
+    reversed.add_holidays(sorted.iter().rev());
+    assert_eq!(format!("{reversed:?}"), format!("{cal:?}"));
+    assert!(format!("{cal:?}").starts_with("Calendar { weekend: [Sat, Sun], holidays: {2024-05-27, 2024-12-25, "));
+}
+
+#[test]
+fn get_holidays_follows_changes_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = Calendar::with_holidays([d(2024, 12, 25)]);
+    assert_eq!(cal.get_holidays().len(), 1);
+
+    cal.add_holidays([d(2024, 12, 26)]);
+    assert!(cal.get_holidays().contains(&d(2024, 12, 26)));
+    cal.remove_holidays([d(2024, 12, 25)]);
+    assert!(!cal.get_holidays().contains(&d(2024, 12, 25)));
+    cal.union(&Calendar::with_holidays([d(2025, 1, 1)]));
+    assert_eq!(cal.get_holidays(), &[d(2024, 12, 26), d(2025, 1, 1)].into_iter().collect());
+    cal.intersection(&Calendar::with_holidays([d(2025, 1, 1)]));
+    assert_eq!(cal.get_holidays(), &[d(2025, 1, 1)].into_iter().collect());
+    cal.clear_holidays();
+    assert!(cal.get_holidays().is_empty());
+    assert_eq!(cal, Calendar::new());
+}
+
+#[test]
+fn calendar_display_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    assert_eq!(Calendar::new().to_string(), "Weekend: none");
+
+    let mut cal = Calendar::with_weekends([Weekday::Sat, Weekday::Fri]);
+    cal.add_holidays([d(2025, 3, 30), d(2024, 6, 16)]);
+    cal.add_named_holiday(d(2024, 4, 10), "Eid al-Fitr");
+    assert_eq!(
+        cal.to_string(),
+        "Weekend: Fri, Sat\n2024:\n  2024-04-10 Eid al-Fitr\n  2024-06-16\n2025:\n  2025-03-30"
+    );
+}
+
+#[test]
+fn calendar_summary_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    // Christmas on a Wednesday, Boxing Day on a Thursday, and a Saturday holiday.
+    cal.add_holidays([d(2024, 12, 25), d(2024, 12, 26), d(2024, 11, 2)]);
+
+    let summary = cal.summary(2024).unwrap();
+    assert_eq!(summary.year, 2024);
+    assert_eq!(summary.months[10], calendar::MonthSummary { month: 11, business_days: 21, holidays: 1 });
+    assert_eq!(summary.months[11], calendar::MonthSummary { month: 12, business_days: 20, holidays: 2 });
+    assert_eq!((summary.business_days(), summary.holidays()), (260, 3));
+    for (month, row) in summary.months.iter().zip(1..) {
+        assert_eq!(month.month, row);
+        assert_eq!(Some(month.business_days), cal.business_day_count_in_month(2024, row));
+    }
+
+    let table = summary.to_string();
+    assert_eq!(table.lines().count(), 14);
+    assert_eq!(table.lines().nth(12), Some("Dec               20         2"));
+    assert!(cal.summary(i32::MAX).is_none());
+}
+
+#[test]
+fn render_month_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_named_holiday(d(2024, 12, 25), "Christmas Day");
+    cal.add_holidays([d(2024, 12, 26)]);
+    assert_eq!(
+        calendar::render_month(&cal, 2024, 12).unwrap(),
+        "       December 2024\n \
+         Mo  Tu  We  Th  Fr  Sa  Su\n                          \
+         1-\n  2   3   4   5   6   7-  8-\n  \
+         9  10  11  12  13  14- 15-\n \
+         16  17  18  19  20  21- 22-\n \
+         23  24  25* 26* 27  28- 29-\n \
+         30  31\n\
+         * holiday  - weekend\n \
+         25 Christmas Day"
+    );
+
+    // Sunday-first weeks; ANSI codes wrap only the marked days.
+    let options = calendar::RenderOptions { week_start: Weekday::Sun, ansi: true };
+    let grid = calendar::render_month_with(&cal, 2024, 12, &options).unwrap();
+    assert_eq!(grid.lines().nth(1), Some(" Su  Mo  Tu  We  Th  Fr  Sa"));
+    assert_eq!(
+        grid.lines().nth(5),
+        Some("\x1b[2m 22-\x1b[0m 23  24 \x1b[31m 25*\x1b[0m\x1b[31m 26*\x1b[0m 27 \x1b[2m 28-\x1b[0m")
+    );
+    assert_eq!(grid.lines().count(), 9);
+
+    assert_eq!(calendar::render_month(&cal, 2024, 13), Err(findates::error::DateError::InvalidMonth));
+    assert_eq!(calendar::render_month(&cal, i32::MAX, 1), Err(findates::error::DateError::OutOfRange));
+}
+
+#[test]
+fn diff_report_test() {
+    use findates::conventions::Frequency;
+    use findates::schedule::Schedule;
+
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut old = calendar::basic_calendar();
+    old.add_holidays([d(2024, 12, 25)]);
+    let mut new = calendar::basic_calendar();
+    new.add_holidays([d(2024, 7, 1)]);
+
+    // A portfolio of three schedules traced under the old calendar.
+    let monthly = Schedule::new(Frequency::Monthly, Some(&old), Some(AdjustRule::Following));
+    let quarterly = Schedule::new(Frequency::Quarterly, Some(&old), Some(AdjustRule::Preceding));
+    let unadjusted = Schedule::new(Frequency::Quarterly, Some(&old), Some(AdjustRule::Unadjusted));
+    let mut trace = monthly.generate_with_trace(&d(2024, 6, 30), &d(2024, 9, 30)).unwrap();
+    trace.extend(quarterly.generate_with_trace(&d(2024, 6, 25), &d(2025, 3, 25)).unwrap());
+    trace.extend(unadjusted.generate_with_trace(&d(2024, 6, 25), &d(2025, 3, 25)).unwrap());
+    trace.extend(Schedule::new(Frequency::Monthly, None, None).generate_with_trace(&d(2024, 6, 30), &d(2024, 9, 30)).unwrap());
+
+    let diff = calendar::diff_report(&old, &new, &trace);
+    assert_eq!(diff.added_holidays, vec![d(2024, 7, 1)]);
+    assert_eq!(diff.removed_holidays, vec![d(2024, 12, 25)]);
+    assert_eq!(
+        diff.changes,
+        vec![
+            // Sunday 30 June rolls past the new holiday.
+            calendar::DateChange {
+                unadjusted: d(2024, 6, 30),
+                adjust_rule: Some(AdjustRule::Following),
+                old: d(2024, 7, 1),
+                new: d(2024, 7, 2),
+            },
+            // Christmas is a business day again.
+            calendar::DateChange {
+                unadjusted: d(2024, 12, 25),
+                adjust_rule: Some(AdjustRule::Preceding),
+                old: d(2024, 12, 24),
+                new: d(2024, 12, 25),
+            },
+        ]
+    );
+    assert!(!diff.is_empty());
+
+    // Swapping the calendars reverses the report.
+    let back = calendar::diff_report(&new, &old, &trace);
+    assert_eq!(back.added_holidays, diff.removed_holidays);
+    assert_eq!(back.changes.iter().map(|c| (c.new, c.old)).collect::<Vec<_>>(), diff.changes.iter().map(|c| (c.old, c.new)).collect::<Vec<_>>());
+
+    assert!(calendar::diff_report(&old, &old, &trace).is_empty());
+}
+
+// ============================================================================
+// Holiday Metadata Tests
+// ============================================================================
+
+#[test]
+fn holiday_metadata_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([boxing_day]);
+    cal.add_holiday_info(xmas, calendar::HolidayInfo::new("Christmas Day").with_category("Bank"));
+
+    assert!(!cal.is_business_day(&xmas));
+    assert_eq!(cal.holiday_name(&xmas), Some("Christmas Day"));
+    assert_eq!(cal.holiday_info(&xmas).unwrap().category.as_deref(), Some("Bank"));
+    assert_eq!(cal.holiday_name(&boxing_day), None);
+
+    // Naming an existing holiday replaces its metadata.
+    cal.add_named_holiday(xmas, "Xmas");
+    assert_eq!(cal.holiday_info(&xmas), Some(&calendar::HolidayInfo::new("Xmas")));
+    assert_eq!(cal.get_holidays().len(), 2);
+}
+
+#[test]
+fn holiday_metadata_set_operations_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let mut uk = Calendar::new();
+    uk.add_named_holiday(xmas, "Christmas Day");
+    uk.add_named_holiday(boxing_day, "Boxing Day");
+    let mut us = Calendar::new();
+    us.add_named_holiday(xmas, "Christmas");
+
+    // Union keeps self's name where both calendars name a date.
+    let mut union = us.clone();
+    union.union(&uk);
+    assert_eq!(union.holiday_name(&xmas), Some("Christmas"));
+    assert_eq!(union.holiday_name(&boxing_day), Some("Boxing Day"));
+
+    // Intersection drops metadata of removed holidays.
+    uk.intersection(&us);
+    assert_eq!(uk.holiday_name(&xmas), Some("Christmas Day"));
+    assert_eq!(uk.holiday_name(&boxing_day), None);
+}
+
+#[test]
+fn early_close_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let one_pm = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_named_holiday(d(2024, 11, 28), "Thanksgiving Day");
+    cal.add_early_close(d(2024, 11, 29), one_pm);
+    cal.add_early_close(d(2024, 12, 24), one_pm);
+
+    assert!(cal.is_business_day(&d(2024, 11, 29)));
+    assert!(cal.is_early_close(&d(2024, 11, 29)));
+    assert_eq!(cal.close_time(&d(2024, 12, 24)), Some(one_pm));
+    assert_eq!(cal.close_time(&d(2024, 12, 23)), None);
+    assert_eq!(algebra::adjust(&d(2024, 11, 28), Some(&cal), Some(AdjustRule::Following)), d(2024, 11, 29));
+
+    // Early closes on non-business days are ignored.
+    cal.add_holidays([d(2024, 12, 24)]);
+    assert!(!cal.is_early_close(&d(2024, 12, 24)));
+    cal.remove_holidays([d(2024, 12, 24)]);
+    assert!(cal.is_early_close(&d(2024, 12, 24)));
+
+    cal.remove_early_closes([d(2024, 12, 24)]);
+    assert_eq!(cal.get_early_closes().len(), 1);
+}
+
+#[test]
+fn early_close_set_operations_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    let one_pm = NaiveTime::from_hms_opt(13, 0, 0).unwrap();
+    let xmas_eve = d(2024, 12, 24);
+    let nye = d(2024, 12, 31);
+    let mut uk = calendar::basic_calendar();
+    uk.add_early_close(xmas_eve, noon);
+    uk.add_early_close(nye, noon);
+    let mut us = calendar::basic_calendar();
+    us.add_early_close(xmas_eve, one_pm);
+
+    // Union closes at the earlier time; intersection at the later time.
+    let union = &uk | &us;
+    assert_eq!(union.close_time(&xmas_eve), Some(noon));
+    assert_eq!(union.close_time(&nye), Some(noon));
+    let intersection = &uk & &us;
+    assert_eq!(intersection.close_time(&xmas_eve), Some(one_pm));
+    assert_eq!(intersection.close_time(&nye), None);
+
+    assert_eq!(uk.difference(&us).unwrap().get_early_closes().len(), 2);
+    assert_eq!(uk.difference(&uk), Ok(Calendar::new()));
+    assert_eq!(us.symmetric_difference(&us), Ok(Calendar::new()));
+}
+
+// ============================================================================
+// Removal Tests
+// ============================================================================
+
+#[test]
+fn remove_holidays_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_named_holiday(xmas, "Christmas Day");
+    cal.add_holidays([boxing_day]);
+
+    // Removing a date that is not a holiday is a no-op.
+    cal.remove_holidays([xmas, NaiveDate::from_ymd_opt(2024, 12, 27).unwrap()]);
+    assert!(cal.is_business_day(&xmas));
+    assert_eq!(cal.holiday_name(&xmas), None);
+    assert!(!cal.is_business_day(&boxing_day));
+
+    // Re-adding the date does not resurrect its old name.
+    cal.add_holidays([xmas]);
+    assert_eq!(cal.holiday_name(&xmas), None);
+}
+
+#[test]
+fn remove_weekend_and_clear_holidays_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]);
+
+    cal.remove_weekend(Weekday::Sat);
+    cal.remove_weekend(Weekday::Mon); // not a weekend day: no-op
+    assert_eq!(cal.get_weekend(), &[Weekday::Sun].into_iter().collect());
+
+    cal.clear_holidays();
+    assert_eq!(cal, Calendar::with_weekends([Weekday::Sun]));
+}
+
+#[test]
+fn removal_and_set_operations_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let mut uk = calendar::basic_calendar();
+    uk.add_holidays([xmas, boxing_day]);
+    let mut us = calendar::basic_calendar();
+    us.add_holidays([xmas]);
+
+    // Removing from one side before a union: the other side still contributes.
+    let mut union = uk.clone();
+    union.remove_holidays([xmas]);
+    union.remove_weekend(Weekday::Sat);
+    union.union(&us);
+    assert!(union.get_holidays().contains(&xmas));
+    assert!(union.get_weekend().contains(&Weekday::Sat));
+
+    // Removing after an intersection only affects the result.
+    let mut common = uk.clone();
+    common.intersection(&us);
+    common.remove_holidays([xmas]);
+    common.remove_weekend(Weekday::Sun);
+    assert!(common.get_holidays().is_empty());
+    assert_eq!(common.get_weekend(), &[Weekday::Sat].into_iter().collect());
+    assert!(uk.get_holidays().contains(&xmas));
+
+    // Removing before an intersection drops the date from the result.
+    let mut trimmed = uk.clone();
+    trimmed.remove_holidays([xmas]);
+    trimmed.intersection(&us);
+    assert!(trimmed.get_holidays().is_empty());
+}
+
+// ============================================================================
+// Difference Tests
+// ============================================================================
+
+#[test]
+fn calendar_difference_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let new_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+    let mut v1 = calendar::basic_calendar();
+    v1.add_named_holiday(xmas, "Christmas Day");
+    v1.add_named_holiday(boxing_day, "Boxing Day");
+    let mut v2 = Calendar::with_weekends([Weekday::Sun]);
+    v2.add_holidays([xmas, new_year]);
+
+    let removed = v1.difference(&v2).unwrap();
+    assert_eq!(removed.get_holidays(), &[boxing_day].into_iter().collect());
+    assert_eq!(removed.get_weekend(), &[Weekday::Sat].into_iter().collect());
+    assert_eq!(removed.holiday_name(&boxing_day), Some("Boxing Day"));
+    assert_eq!(removed.holiday_name(&xmas), None);
+
+    let added = v2.difference(&v1);
+    assert_eq!(added, Ok(Calendar::with_holidays([new_year])));
+
+    // Inputs are left untouched.
+    assert_eq!(v1.get_holidays().len(), 2);
+    assert_eq!(v1.difference(&v1), Ok(Calendar::new()));
+}
+
+#[test]
+fn calendar_symmetric_difference_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let new_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+    let mut v1 = calendar::basic_calendar();
+    v1.add_holidays([xmas, boxing_day]);
+    let mut v2 = Calendar::with_weekends([Weekday::Sun, Weekday::Fri]);
+    v2.add_named_holiday(new_year, "New Year's Day");
+    v2.add_holidays([xmas]);
+
+    let changed = v1.symmetric_difference(&v2).unwrap();
+    assert_eq!(changed.get_holidays(), &[boxing_day, new_year].into_iter().collect());
+    assert_eq!(changed.get_weekend(), &[Weekday::Sat, Weekday::Fri].into_iter().collect());
+    assert_eq!(changed.holiday_name(&new_year), Some("New Year's Day"));
+    assert_eq!(Ok(changed), v2.symmetric_difference(&v1));
+
+    // Identical calendars have no differences.
+    assert_eq!(v1.symmetric_difference(&v1.clone()), Ok(Calendar::new()));
+}
+
+// ============================================================================
+// Non-Mutating Set Operation Tests
+// ============================================================================
+
+#[test]
+fn calendar_unioned_and_intersected_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let boxing_day = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+    let mut uk = calendar::basic_calendar();
+    uk.add_holidays([xmas, boxing_day]);
+    let mut us = Calendar::with_weekends([Weekday::Sun]);
+    us.add_holidays([xmas]);
+    let (uk_before, us_before) = (uk.clone(), us.clone());
+
+    let mut expected_union = uk.clone();
+    expected_union.union(&us);
+    let mut expected_common = uk.clone();
+    expected_common.intersection(&us);
+
+    assert_eq!(uk.unioned(&us), expected_union);
+    assert_eq!(uk.intersected(&us), expected_common);
+    assert_eq!(&uk | &us, expected_union);
+    assert_eq!(&uk & &us, expected_common);
+    assert_eq!(uk, uk_before);
+    assert_eq!(us, us_before);
+}
+
+#[test]
+fn calendar_operators_chain_test() {
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    let thanksgiving = NaiveDate::from_ymd_opt(2024, 11, 28).unwrap();
+    let weekend = calendar::basic_calendar();
+    let uk = Calendar::with_holidays([xmas]);
+    let us = Calendar::with_holidays([xmas, thanksgiving]);
+
+    let settlement = &weekend | &uk | &us;
+    assert_eq!(settlement, calendar::calendar_unions(&[weekend.clone(), uk.clone(), us.clone()]));
+    assert_eq!(&(&uk | &us) & &us, us);
+    assert_eq!(&uk & &us & &us, &uk & &us);
+}
+
+// ============================================================================
+// Valid Range Tests
+// ============================================================================
+
+#[test]
+fn calendar_valid_range_test() {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+    let mut cal = calendar::basic_calendar();
+    assert_eq!(cal.valid_range(), None);
+    assert!(cal.is_in_valid_range(&NaiveDate::MAX));
+
+    assert_eq!(cal.set_valid_range(end, start), Err(findates::CalendarError::InvalidRange));
+    assert_eq!(cal.valid_range(), None);
+
+    cal.set_valid_range(start, end).unwrap();
+    assert_eq!(cal.valid_range(), Some((start, end)));
+    assert!(cal.is_in_valid_range(&start));
+    assert!(cal.is_in_valid_range(&end));
+    let before = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(); // Friday
+    assert!(!cal.is_in_valid_range(&before));
+
+    assert_eq!(cal.try_is_business_day(&start), Ok(true));
+    assert_eq!(
+        cal.try_is_business_day(&before),
+        Err(findates::CalendarError::OutOfRange { date: before })
+    );
+    // Unchecked queries keep their old behaviour.
+    assert!(cal.is_business_day(&before));
+
+    cal.clear_valid_range();
+    assert_eq!(cal.try_is_business_day(&before), Ok(true));
+}
+
+#[test]
+fn calendar_valid_range_set_operations_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut uk = calendar::basic_calendar();
+    uk.set_valid_range(d(2020, 1, 1), d(2030, 12, 31)).unwrap();
+    let mut us = calendar::basic_calendar();
+    us.set_valid_range(d(2024, 1, 1), d(2040, 12, 31)).unwrap();
+    let open = calendar::basic_calendar();
+
+    let overlap = Some((d(2024, 1, 1), d(2030, 12, 31)));
+    assert_eq!(uk.unioned(&us).valid_range(), overlap);
+    assert_eq!(uk.intersected(&us).valid_range(), overlap);
+    assert_eq!(uk.try_unioned(&us).unwrap().valid_range(), overlap);
+    assert_eq!(uk.try_intersected(&us).unwrap().valid_range(), overlap);
+    assert_eq!(uk.difference(&us).unwrap().valid_range(), overlap);
+    assert_eq!(uk.unioned(&open).valid_range(), uk.valid_range());
+    assert!(!uk.unioned(&us).has_empty_valid_range());
+
+    // Disjoint ranges are rejected by the checked forms.
+    let mut old = calendar::basic_calendar();
+    old.set_valid_range(d(2000, 1, 1), d(2009, 12, 31)).unwrap();
+    assert_eq!(old.try_unioned(&us), Err(findates::CalendarError::DisjointRanges));
+    assert_eq!(old.try_intersected(&us), Err(findates::CalendarError::DisjointRanges));
+    assert_eq!(old.difference(&us), Err(findates::CalendarError::DisjointRanges));
+    assert_eq!(old.symmetric_difference(&us), Err(findates::CalendarError::DisjointRanges));
+    // The other forms combine the sets but leave an empty range that every
+    // checked query rejects.
+    let monday = d(2024, 3, 18);
+    for cal in [&old | &us, &old & &us] {
+        assert!(cal.has_empty_valid_range());
+        assert_eq!(cal.valid_range(), None);
+        assert!(!cal.is_in_valid_range(&monday));
+        assert_eq!(cal.try_is_business_day(&monday), Err(findates::CalendarError::OutOfRange { date: monday }));
+        assert!(findates::algebra::try_adjust(&monday, Some(&cal), None).is_err());
+    }
+    // An empty range stays empty through further combinations, until it is
+    // replaced.
+    let mut merged = &old | &us;
+    merged.union(&open);
+    assert!(merged.has_empty_valid_range());
+    assert_eq!(merged.try_unioned(&open), Err(findates::CalendarError::DisjointRanges));
+    merged.clear_valid_range();
+    assert_eq!(merged.try_is_business_day(&monday), Ok(true));
+}
+
+#[test]
+fn calendar_union_with_disjoint_ranges_keeps_both_holidays_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut old = Calendar::with_holidays([d(2005, 12, 26)]);
+    old.add_weekends([Weekday::Sat]);
+    old.set_valid_range(d(2000, 1, 1), d(2009, 12, 31)).unwrap();
+    let mut new = Calendar::with_holidays([d(2024, 12, 25)]);
+    new.add_weekends([Weekday::Sun]);
+    new.set_valid_range(d(2020, 1, 1), d(2029, 12, 31)).unwrap();
+
+    let mut cal = old.clone();
+    cal.union(&new);
+    assert!(cal.get_holidays().contains(&d(2005, 12, 26)));
+    assert!(cal.get_holidays().contains(&d(2024, 12, 25)));
+    assert!(cal.get_weekend().contains(&Weekday::Sat));
+    assert!(cal.get_weekend().contains(&Weekday::Sun));
+    assert!(cal.has_empty_valid_range());
+    assert!(cal.try_is_business_day(&d(2024, 12, 24)).is_err());
+}
+
+// ============================================================================
+// Business Day Count Tests
+// ============================================================================
+
+#[test]
+#[allow(deprecated)]
+fn business_days_between_matches_schedule_length_test() {
+    let mut cal = calendar::basic_calendar();
+    for year in 2023..=2026 {
+        cal.add_holidays([
+            NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(year, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(year, 12, 26).unwrap(),
+        ]);
+    }
+    // A holiday on a weekend must not be subtracted twice.
+    cal.add_holidays([NaiveDate::from_ymd_opt(2024, 3, 16).unwrap()]);
+
+    let rules = [
+        None,
+        Some(AdjustRule::Following),
+        Some(AdjustRule::ModFollowing),
+        Some(AdjustRule::Preceding),
+        Some(AdjustRule::Nearest),
+    ];
+    let start = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+    for offset in [0u64, 1, 3, 5, 6, 7, 13, 100, 400, 1000] {
+        for shift in 0..7u64 {
+            let from = start + chrono::Days::new(shift);
+            let to = from + chrono::Days::new(offset);
+            for rule in rules {
+                let expected = algebra::bus_day_schedule(&from, &to, &cal, rule).len() as u64 - 1;
+                assert_eq!(
+                    algebra::business_days_between(&from, &to, &cal, rule),
+                    expected,
+                    "{from} -> {to} {rule:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn business_days_between_unadjusted_counts_business_days_test() {
+    let cal = calendar::basic_calendar();
+    let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    let next_saturday = NaiveDate::from_ymd_opt(2024, 3, 23).unwrap();
+    assert_eq!(
+        algebra::business_days_between(&saturday, &next_saturday, &cal, Some(AdjustRule::Unadjusted)),
+        5
+    );
+}
+
+#[test]
+fn business_days_between_with_boundaries_test() {
+    use algebra::Boundaries;
+    let mut cal = calendar::basic_calendar();
+    let xmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+    cal.add_holidays([xmas]);
+    let monday = NaiveDate::from_ymd_opt(2024, 12, 23).unwrap();
+    let friday = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+    let count = |from: &NaiveDate, to: &NaiveDate, rule, b| {
+        algebra::business_days_between_with_boundaries(from, to, &cal, rule, b)
+    };
+
+    // Mon 23, Tue 24, Thu 26, Fri 27 are business days.
+    assert_eq!(count(&monday, &friday, None, Boundaries::IncludeStart), 3);
+    assert_eq!(count(&monday, &friday, None, Boundaries::IncludeEnd), 3);
+    assert_eq!(count(&monday, &friday, None, Boundaries::IncludeBoth), 4);
+    assert_eq!(count(&monday, &friday, None, Boundaries::ExcludeBoth), 2);
+
+    // Same day: counted only when both ends are included.
+    assert_eq!(count(&monday, &monday, None, Boundaries::IncludeBoth), 1);
+    assert_eq!(count(&monday, &monday, None, Boundaries::ExcludeBoth), 0);
+    assert_eq!(count(&friday, &monday, None, Boundaries::IncludeBoth), 0);
+
+    // Unadjusted endpoints on holidays are not counted.
+    let unadj = Some(AdjustRule::Unadjusted);
+    assert_eq!(count(&xmas, &friday, unadj, Boundaries::IncludeBoth), 2);
+    assert_eq!(count(&monday, &xmas, unadj, Boundaries::IncludeEnd), 1);
+}
+
+#[test]
+fn business_days_signed_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]);
+    let dates = [
+        NaiveDate::from_ymd_opt(2024, 12, 20).unwrap(), // Friday
+        NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(), // Saturday
+        NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), // holiday
+        NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+    ];
+    for a in dates {
+        for b in dates {
+            let signed = algebra::business_days_signed(&a, &b, &cal);
+            assert_eq!(signed, -algebra::business_days_signed(&b, &a, &cal), "{a} {b}");
+            if b >= a {
+                assert_eq!(signed, algebra::business_days_between(&a, &b, &cal, None) as i64);
+            }
+        }
+    }
+    // Fri 20 Dec -> Fri 27 Dec: Fri, Mon, Tue, Thu.
+    let later = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+    assert_eq!(algebra::business_days_signed(&later, &dates[0], &cal), -4);
+    assert_eq!(algebra::business_days_signed(&dates[0], &dates[0], &cal), 0);
+}
+
+// ============================================================================
+// Business Day Iterator Tests
+// ============================================================================
+
+#[test]
+fn business_days_iter_matches_filter_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([
+        NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    ]);
+    let start = NaiveDate::from_ymd_opt(2024, 12, 14).unwrap(); // Saturday
+    let end = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(); // Sunday
+    let expected: Vec<NaiveDate> = start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| algebra::is_business_day(d, &cal))
+        .collect();
+
+    let forward: Vec<NaiveDate> = algebra::business_days_iter(&start, &end, &cal).collect();
+    assert_eq!(forward, expected);
+
+    let mut backward: Vec<NaiveDate> = algebra::business_days_iter(&start, &end, &cal).rev().collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+}
+
+#[test]
+fn business_days_iter_both_ends_meet_test() {
+    let cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+    let end = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
+    let mut days = algebra::business_days_iter(&start, &end, &cal);
+    assert_eq!(days.next(), Some(start));
+    assert_eq!(days.next_back(), Some(end));
+    assert_eq!(days.next(), NaiveDate::from_ymd_opt(2024, 3, 19));
+    assert_eq!(days.next_back(), NaiveDate::from_ymd_opt(2024, 3, 21));
+    assert_eq!(days.next(), NaiveDate::from_ymd_opt(2024, 3, 20));
+    assert_eq!(days.next_back(), None);
+    assert_eq!(days.next(), None);
+}
+
+#[test]
+fn business_days_iter_empty_ranges_test() {
+    let cal = calendar::basic_calendar();
+    let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    let sunday = NaiveDate::from_ymd_opt(2024, 3, 17).unwrap();
+    let monday = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
+    assert_eq!(algebra::business_days_iter(&saturday, &sunday, &cal).next(), None);
+    assert_eq!(algebra::business_days_iter(&monday, &saturday, &cal).next(), None);
+    assert_eq!(algebra::business_days_iter(&monday, &monday, &cal).collect::<Vec<_>>(), vec![monday]);
+    // The representable date range ends without panicking
+    let (min, max) = (NaiveDate::MIN, NaiveDate::MAX);
+    let ten_days = chrono::Days::new(10);
+    assert!(algebra::business_days_iter(&(max - ten_days), &max, &cal).count() > 0);
+    assert!(algebra::business_days_iter(&min, &(min + ten_days), &cal).rev().count() > 0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn bus_day_schedule_matches_iter_test() {
+    let cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+    let expected: Vec<NaiveDate> = algebra::business_days_iter(&start, &end, &cal).collect();
+    assert_eq!(algebra::bus_day_schedule(&start, &end, &cal, None), expected);
+}
+
+// ============================================================================
+// Business Day Schedule Options Tests
+// ============================================================================
+
+#[test]
+fn bus_day_schedule_with_defaults_test() {
+    // Saturday to Saturday: no adjustment, so exactly the five weekdays
+    let cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 3, 23).unwrap();
+    let days = algebra::bus_day_schedule_with(&start, &end, &cal, algebra::BusDayScheduleOptions::default());
+    let expected: Vec<NaiveDate> = (18..=22).map(|d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap()).collect();
+    assert_eq!(days, expected);
+
+    // Monday to Monday: the end date is excluded
+    let monday = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+    let days = algebra::bus_day_schedule_with(&expected[0], &monday, &cal, Default::default());
+    assert_eq!(days, expected);
+}
+
+#[test]
+fn bus_day_schedule_with_boundaries_test() {
+    use algebra::{Boundaries, BusDayScheduleOptions};
+    let cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(); // Monday
+    let end = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap(); // Friday
+    let list = |boundaries| {
+        let options = BusDayScheduleOptions { boundaries, ..Default::default() };
+        algebra::bus_day_schedule_with(&start, &end, &cal, options)
+    };
+    assert_eq!(list(Boundaries::IncludeStart).first(), Some(&start));
+    assert_eq!(list(Boundaries::IncludeEnd).last(), Some(&end));
+    assert_eq!(list(Boundaries::IncludeBoth).len(), 5);
+    assert_eq!(list(Boundaries::ExcludeBoth).len(), 3);
+    assert!(algebra::bus_day_schedule_with(&end, &start, &cal, Default::default()).is_empty());
+}
+
+#[test]
+fn bus_day_schedule_with_matches_count_test() {
+    use algebra::{Boundaries, BusDayScheduleOptions};
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([
+        NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ]);
+    let boundaries = [
+        Boundaries::IncludeStart,
+        Boundaries::IncludeEnd,
+        Boundaries::IncludeBoth,
+        Boundaries::ExcludeBoth,
+    ];
+    let rules = [None, Some(AdjustRule::Following), Some(AdjustRule::Preceding)];
+    let start = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+    for offset in [0u64, 1, 4, 12, 40] {
+        for shift in 0..7u64 {
+            let from = start + chrono::Days::new(shift);
+            let to = from + chrono::Days::new(offset);
+            for b in boundaries {
+                for rule in rules {
+                    let options = BusDayScheduleOptions { boundaries: b, adjust_endpoints: rule };
+                    let days = algebra::bus_day_schedule_with(&from, &to, &cal, options);
+                    let count = algebra::business_days_between_with_boundaries(
+                        &from,
+                        &to,
+                        &cal,
+                        rule.or(Some(AdjustRule::Unadjusted)),
+                        b,
+                    );
+                    assert_eq!(days.len() as u64, count, "{from} -> {to} {b:?} {rule:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn bus_day_schedule_shim_matches_options_test() {
+    use algebra::{Boundaries, BusDayScheduleOptions};
+    let cal = calendar::basic_calendar();
+    let start = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(); // Saturday
+    let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(); // Sunday
+    for rule in [AdjustRule::Following, AdjustRule::ModFollowing, AdjustRule::Preceding] {
+        let options = BusDayScheduleOptions {
+            boundaries: Boundaries::IncludeBoth,
+            adjust_endpoints: Some(rule),
+        };
+        assert_eq!(
+            algebra::bus_day_schedule(&start, &end, &cal, Some(rule)),
+            algebra::bus_day_schedule_with(&start, &end, &cal, options),
+            "{rule:?}"
+        );
+    }
+}
+
+// ============================================================================
+// Holiday Listing Tests
+// ============================================================================
+
+fn listing_calendar() -> Calendar {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays(
+        [(2023, 12, 25), (2024, 1, 1), (2024, 3, 29), (2024, 12, 25), (2024, 12, 26), (2025, 1, 1)]
+            .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+    );
+    cal
+}
+
+#[test]
+fn holidays_in_year_test() {
+    let cal = listing_calendar();
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(
+        cal.holidays_in_year(2024),
+        vec![ymd(2024, 1, 1), ymd(2024, 3, 29), ymd(2024, 12, 25), ymd(2024, 12, 26)]
+    );
+    assert_eq!(cal.holidays_in_year(2025), vec![ymd(2025, 1, 1)]);
+    assert!(cal.holidays_in_year(2030).is_empty());
+}
+
+#[test]
+fn holidays_between_test() {
+    let cal = listing_calendar();
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(
+        cal.holidays_between(&ymd(2023, 12, 25), &ymd(2024, 3, 29)),
+        vec![ymd(2023, 12, 25), ymd(2024, 1, 1), ymd(2024, 3, 29)]
+    );
+    assert_eq!(cal.holidays_between(&ymd(2024, 12, 26), &ymd(2024, 12, 26)), vec![ymd(2024, 12, 26)]);
+    assert!(cal.holidays_between(&ymd(2024, 4, 1), &ymd(2024, 11, 30)).is_empty());
+    assert!(cal.holidays_between(&ymd(2025, 1, 1), &ymd(2023, 1, 1)).is_empty());
+}
+
+#[test]
+fn business_day_count_in_month_test() {
+    let cal = listing_calendar();
+    // March 2024: 21 weekdays less Good Friday
+    assert_eq!(cal.business_day_count_in_month(2024, 3), Some(20));
+    // February 2024: 21 weekdays, leap day included
+    assert_eq!(cal.business_day_count_in_month(2024, 2), Some(21));
+    assert_eq!(cal.business_day_count_in_month(2024, 0), None);
+
+    // The months of a year add up to the year's business days
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    let total: u32 = (1..=12).map(|m| cal.business_day_count_in_month(2024, m).unwrap()).sum();
+    assert_eq!(total as u64, algebra::business_days_between(&start, &end, &cal, None));
+}
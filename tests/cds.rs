@@ -0,0 +1,120 @@
+// Integration tests for standard CDS dates.
+// Maturities follow the ISDA pre-2015 (quarterly) and 2015 (semiannual) roll rules.
+
+use chrono::NaiveDate;
+use findates::calendar;
+use findates::cds::{cds_maturity, cds_schedule, is_cds_date, next_cds_date, previous_cds_date, CdsRollRule};
+use findates::conventions::{AdjustRule, Tenor};
+
+mod setup;
+use setup::d;
+
+// ── next / previous ──────────────────────────────────────────────────────────
+
+#[test]
+fn next_cds_date_test() {
+    assert_eq!(next_cds_date(&d(2024, 1, 1)), Some(d(2024, 3, 20)));
+    assert_eq!(next_cds_date(&d(2024, 3, 19)), Some(d(2024, 3, 20)));
+    assert_eq!(next_cds_date(&d(2024, 3, 20)), Some(d(2024, 6, 20)));
+    assert_eq!(next_cds_date(&d(2024, 12, 20)), Some(d(2025, 3, 20)));
+    assert_eq!(next_cds_date(&d(2024, 12, 31)), Some(d(2025, 3, 20)));
+}
+
+#[test]
+fn previous_cds_date_test() {
+    assert_eq!(previous_cds_date(&d(2024, 3, 21)), Some(d(2024, 3, 20)));
+    assert_eq!(previous_cds_date(&d(2024, 3, 20)), Some(d(2023, 12, 20)));
+    assert_eq!(previous_cds_date(&d(2024, 1, 5)), Some(d(2023, 12, 20)));
+    assert_eq!(previous_cds_date(&d(2024, 9, 30)), Some(d(2024, 9, 20)));
+}
+
+#[test]
+fn is_cds_date_test() {
+    assert!(is_cds_date(&d(2024, 9, 20)));
+    assert!(!is_cds_date(&d(2024, 9, 21)));
+    assert!(!is_cds_date(&d(2024, 10, 20)));
+}
+
+#[test]
+fn cds_dates_out_of_range_test() {
+    assert_eq!(next_cds_date(&NaiveDate::MAX), None);
+    assert_eq!(previous_cds_date(&NaiveDate::MIN), None);
+}
+
+// ── Maturities ───────────────────────────────────────────────────────────────
+
+#[test]
+fn cds_maturity_semiannual_test() {
+    // Trades between 20 Mar and 19 Sep mature in June, the rest in December
+    let rule = CdsRollRule::Semiannual;
+    assert_eq!(cds_maturity(&d(2024, 3, 19), Tenor::Years(5), rule), Some(d(2028, 12, 20)));
+    assert_eq!(cds_maturity(&d(2024, 3, 20), Tenor::Years(5), rule), Some(d(2029, 6, 20)));
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Years(5), rule), Some(d(2029, 6, 20)));
+    assert_eq!(cds_maturity(&d(2024, 9, 19), Tenor::Years(5), rule), Some(d(2029, 6, 20)));
+    assert_eq!(cds_maturity(&d(2024, 9, 20), Tenor::Years(5), rule), Some(d(2029, 12, 20)));
+    assert_eq!(cds_maturity(&d(2025, 1, 10), Tenor::Months(6), rule), Some(d(2025, 6, 20)));
+}
+
+#[test]
+fn cds_maturity_quarterly_test() {
+    let rule = CdsRollRule::Quarterly;
+    assert_eq!(cds_maturity(&d(2024, 3, 19), Tenor::Years(5), rule), Some(d(2029, 3, 20)));
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Years(5), rule), Some(d(2029, 9, 20)));
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Years(0), rule), Some(d(2024, 9, 20)));
+}
+
+#[test]
+fn cds_maturity_zero_tenor_test() {
+    // A zero tenor in the June/December half of a roll period has no maturity
+    let rule = CdsRollRule::Semiannual;
+    assert_eq!(cds_maturity(&d(2024, 4, 1), Tenor::Months(0), rule), Some(d(2024, 6, 20)));
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Months(0), rule), None);
+}
+
+#[test]
+fn cds_maturity_rejects_short_tenors_test() {
+    let rule = CdsRollRule::Semiannual;
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Days(10), rule), None);
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Weeks(2), rule), None);
+    assert_eq!(cds_maturity(&d(2024, 7, 1), Tenor::Overnight, rule), None);
+}
+
+// ── Schedules ────────────────────────────────────────────────────────────────
+
+#[test]
+fn cds_schedule_test() {
+    let cal = calendar::basic_calendar();
+    let dates = cds_schedule(&d(2024, 7, 1), &d(2025, 6, 20), Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(
+        dates,
+        vec![
+            d(2024, 6, 20),
+            d(2024, 9, 20),
+            d(2024, 12, 20),
+            d(2025, 3, 20),
+            d(2025, 6, 20),
+        ]
+    );
+}
+
+#[test]
+fn cds_schedule_adjusts_coupons_not_maturity_test() {
+    // 20 Dec 2025 and 20 Sep 2026 fall on a Saturday and Sunday
+    let cal = calendar::basic_calendar();
+    let dates = cds_schedule(&d(2025, 11, 3), &d(2026, 9, 20), Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(
+        dates,
+        vec![
+            d(2025, 9, 22),
+            d(2025, 12, 22),
+            d(2026, 3, 20),
+            d(2026, 6, 22),
+            d(2026, 9, 20),
+        ]
+    );
+}
+
+#[test]
+fn cds_schedule_empty_range_test() {
+    assert!(cds_schedule(&d(2025, 1, 1), &d(2024, 1, 1), None, None).is_empty());
+}
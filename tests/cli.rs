@@ -0,0 +1,101 @@
+// Integration tests for the `findates` command-line tool.
+// Run with `cargo test --features cli`.
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn findates(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_findates"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap()
+}
+
+// Runs `findates` and returns its standard output, failing on a non-zero
+// exit.
+fn stdout(args: &[&str]) -> String {
+    let output = findates(args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// ── Subcommands ──────────────────────────────────────────────────────────────
+
+#[test]
+fn adjust_test() {
+    // Good Friday and Easter Monday 2024 surround the weekend on TARGET.
+    assert_eq!(stdout(&["adjust", "2024-03-30", "2024-03-29", "--calendar", "TARGET", "--rule", "MF"]), "2024-03-28\n2024-03-28\n");
+    assert_eq!(stdout(&["adjust", "2024-03-30"]), "2024-04-01\n");
+    assert_eq!(stdout(&["adjust", "2024-03-30", "-r", "Preceding"]), "2024-03-29\n");
+}
+
+#[test]
+fn dcf_test() {
+    assert_eq!(stdout(&["dcf", "2024-01-15", "2024-07-15", "--daycount", "30E/360"]), "0.5\n");
+    assert_eq!(stdout(&["dcf", "2024-01-01", "2024-01-31"]), format!("{}\n", 30.0 / 360.0));
+    // 2024-06-15 is a Saturday: adjusted to Monday before counting.
+    assert_eq!(stdout(&["dcf", "2024-06-14", "2024-06-15", "-d", "ACT/365F", "-c", "WE", "-r", "F"]), format!("{}\n", 3.0 / 365.0));
+}
+
+#[test]
+fn schedule_test() {
+    let out = stdout(&["schedule", "2024-01-31", "2025-01-31", "--frequency", "6M", "--calendar", "TARGET", "--pay-lag", "2"]);
+    assert_eq!(
+        out,
+        "accrual_start,accrual_end,payment_date,year_fraction\n\
+         2024-01-31,2024-07-31,2024-08-02,0.5055555555555555\n\
+         2024-07-31,2025-01-31,2025-02-04,0.5111111111111111\n"
+    );
+}
+
+#[test]
+fn busdays_test() {
+    assert_eq!(stdout(&["busdays", "2024-03-18", "2024-03-22"]), "4\n");
+    // TARGET 2024 closes on six weekdays: 262 - 6.
+    assert_eq!(stdout(&["busdays", "2024-01-01", "2025-01-01", "-c", "TARGET"]), "256\n");
+    assert_eq!(stdout(&["busdays", "2024-01-01", "2025-01-01", "-c", "TARGET|UK"]), "253\n");
+}
+
+#[test]
+fn holidays_test() {
+    let out = stdout(&["holidays", "TARGET", "2024"]);
+    assert!(out.starts_with("date,name\n2024-01-01,New Year's Day\n2024-03-29,Good Friday\n"));
+    assert_eq!(out.lines().count(), 7);
+    assert_eq!(stdout(&["holidays", "TARGET", "1990"]), "date,name\n");
+}
+
+// ── Calendar files ───────────────────────────────────────────────────────────
+
+#[test]
+fn csv_calendar_test() {
+    let cal = "tests/fixtures/holidays_2024.csv";
+    assert_eq!(stdout(&["adjust", "2024-07-04", "-c", cal]), "2024-07-05\n");
+    assert_eq!(stdout(&["adjust", "2024-07-06", "-c", cal]), "2024-07-08\n");
+    assert_eq!(stdout(&["holidays", cal, "2024"]), "date,name\n2024-07-04,Independence Day\n2024-12-25,Christmas Day\n");
+}
+
+#[test]
+fn json_calendar_test() {
+    // A Friday-Saturday weekend with two holidays mid-week.
+    let cal = "tests/fixtures/calendar_2024.json";
+    assert_eq!(stdout(&["busdays", "2024-04-07", "2024-04-14", "-c", cal]), "3\n");
+    assert_eq!(stdout(&["adjust", "2024-04-10", "-c", cal]), "2024-04-14\n");
+}
+
+// ── Errors ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn error_test() {
+    let unknown = findates(&["holidays", "NYC", "2024"]);
+    assert!(!unknown.status.success());
+    assert_eq!(String::from_utf8_lossy(&unknown.stderr), "findates: unknown calendar code \"NYC\"\n");
+
+    let missing = findates(&["adjust", "2024-01-01", "-c", "tests/fixtures/missing.csv"]);
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).starts_with("findates: cannot open tests/fixtures/missing.csv"));
+
+    assert!(!findates(&["dcf", "2024-01-01", "2024-02-01", "-d", "BUS/252"]).status.success());
+    assert!(!findates(&["adjust", "2024-13-01"]).status.success());
+    assert!(!findates(&["schedule", "2024-01-01", "2025-01-01"]).status.success());
+}
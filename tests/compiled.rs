@@ -0,0 +1,95 @@
+// Integration tests for CompiledCalendar.
+// Every query is cross-checked against the equivalent algebra function.
+
+use chrono::Days;
+use findates::algebra;
+use findates::calendar::{self, Calendar};
+use findates::compiled::CompiledCalendar;
+use findates::{BusinessDayError, CalendarError};
+
+mod setup;
+use setup::d;
+
+fn holiday_calendar() -> Calendar {
+    let mut cal = calendar::basic_calendar();
+    for year in 2023..=2026 {
+        cal.add_holidays([d(year, 1, 1), d(year, 7, 4), d(year, 12, 25), d(year, 12, 26)]);
+    }
+    cal
+}
+
+fn compiled() -> (Calendar, CompiledCalendar) {
+    let cal = holiday_calendar();
+    let fast = cal.compile(d(2023, 1, 1)..=d(2026, 12, 31)).unwrap();
+    (cal, fast)
+}
+
+// ── Construction ─────────────────────────────────────────────────────────────
+
+#[test]
+fn compile_range_errors_test() {
+    let cal = holiday_calendar();
+    assert_eq!(cal.compile(d(2024, 1, 2)..=d(2024, 1, 1)), Err(CalendarError::InvalidRange));
+
+    let mut bounded = cal.clone();
+    bounded.set_valid_range(d(2023, 1, 1), d(2026, 12, 31)).unwrap();
+    assert_eq!(
+        bounded.compile(d(2023, 1, 1)..=d(2027, 1, 1)),
+        Err(CalendarError::OutOfRange { date: d(2027, 1, 1) })
+    );
+    assert!(bounded.compile(d(2023, 1, 1)..=d(2026, 12, 31)).is_ok());
+}
+
+// ── Queries ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn is_business_day_matches_calendar_test() {
+    let (cal, fast) = compiled();
+    for date in d(2023, 1, 1).iter_days().take_while(|date| *date <= d(2026, 12, 31)) {
+        assert_eq!(fast.is_business_day(&date), Ok(cal.is_business_day(&date)), "{date}");
+    }
+    assert_eq!(
+        fast.is_business_day(&d(2022, 12, 31)),
+        Err(CalendarError::OutOfRange { date: d(2022, 12, 31) })
+    );
+}
+
+#[test]
+fn business_days_between_matches_algebra_test() {
+    let (cal, fast) = compiled();
+    let starts = [d(2023, 1, 1), d(2023, 12, 23), d(2024, 2, 29), d(2025, 7, 4)];
+    for start in starts {
+        for days in [0u64, 1, 2, 6, 30, 365, 700] {
+            let end = (start + Days::new(days)).min(d(2026, 12, 31));
+            assert_eq!(
+                fast.business_days_between(&start, &end),
+                Ok(algebra::business_days_between(&start, &end, &cal, None)),
+                "{start} -> {end}"
+            );
+        }
+    }
+    // Reversed dates count zero.
+    assert_eq!(fast.business_days_between(&d(2024, 6, 3), &d(2024, 6, 1)), Ok(0));
+}
+
+#[test]
+fn add_and_subtract_business_days_match_algebra_test() {
+    let (cal, fast) = compiled();
+    let start = d(2024, 12, 20); // Friday before Christmas
+    for n in [0u32, 1, 2, 3, 10, 250] {
+        assert_eq!(fast.add_business_days(&start, n), algebra::add_business_days(&start, n, &cal));
+        assert_eq!(
+            fast.subtract_business_days(&start, n),
+            algebra::subtract_business_days(&start, n, &cal)
+        );
+    }
+}
+
+#[test]
+fn add_business_days_errors_test() {
+    let (_, fast) = compiled();
+    assert_eq!(fast.add_business_days(&d(2024, 12, 25), 1), Err(BusinessDayError::InvalidStartDate));
+    assert_eq!(fast.add_business_days(&d(2022, 12, 30), 1), Err(BusinessDayError::OutOfRange));
+    assert_eq!(fast.add_business_days(&d(2026, 12, 30), 2), Err(BusinessDayError::OutOfRange));
+    assert_eq!(fast.subtract_business_days(&d(2023, 1, 3), 2), Err(BusinessDayError::OutOfRange));
+}
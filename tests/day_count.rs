@@ -3,11 +3,18 @@
 // with and without calendar adjustments.
 
 use chrono::NaiveDate;
-use findates::algebra::day_count_fraction;
+use findates::algebra::{
+    day_count, day_count_fraction_rounded, day_count_fraction_with_context, day_count_fraction_with_period,
+    day_count_fractions, dcf_matrix, icma_year_fraction, try_day_count_fraction, BusinessDays252, DayCountContext,
+    DayCounter, ReferencePeriod,
+};
 use findates::calendar;
-use findates::conventions::{AdjustRule, DayCount};
+use findates::conventions::{AdjustRule, DayCount, Frequency, Rounding, RoundingDirection};
 use findates::DayCountError;
 
+mod setup;
+use setup::d;
+
 fn round_decimals(x: f64) -> f64 {
     let multiplier = 100000.0;
     (x * multiplier).round() / multiplier
@@ -34,14 +41,14 @@ fn dcf_act360_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     let expected: f64 = 0.6305556;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::Act360, None, None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::Act360, None, None).unwrap();
     // No calendar
     assert_eq!(round_decimals(res), round_decimals(expected));
     // With Calendar
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap(); // Adjusted to 02 Oct
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 24).unwrap(); // Adjusted to 27 Dec
     let expected: f64 = 0.2388889;
-    let res: f64 = day_count_fraction(
+    let res: f64 = try_day_count_fraction(
         &start,
         &end,
         DayCount::Act360,
@@ -58,14 +65,14 @@ fn dcf_act365_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     let expected: f64 = 0.62191781;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::Act365, None, None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::Act365, None, None).unwrap();
     // No calendar
     assert_eq!(round_decimals(res), round_decimals(expected));
     // With Calendar
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap(); // Adjusted to 02 Oct
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 24).unwrap(); // Adjusted to 27 Dec
     let expected: f64 = 0.23561644;
-    let res: f64 = day_count_fraction(
+    let res: f64 = try_day_count_fraction(
         &start,
         &end,
         DayCount::Act365,
@@ -86,28 +93,28 @@ fn dcf_actactisda_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 5, 27).unwrap(); // This is a Monday within a Leap year
     let expected: f64 = 0.27868852;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 
     // Both dates within a non-leap year
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 5, 27).unwrap(); // This will get adjusted to 29May2023
     let expected: f64 = 0.28219178;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 
     // End date only within a leap year
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 5, 27).unwrap(); // This is a Monday in a Leap Year
     let expected: f64 = 1.27835167;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 
     // Start date and end dates within a leap year
     let start: NaiveDate = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap(); // This is a Saturday, will get adjusted to 2nd of March
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 5, 27).unwrap(); // This is a Monday in a Leap Year
     let expected: f64 = 4.23497268;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::ActActISDA, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 }
 
@@ -119,18 +126,18 @@ fn dcf_d30360euro_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(); // This is a Monday within a Leap year
     let expected: f64 = 1.04166667;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::D30360Euro, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::D30360Euro, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
     // End date on the 31st
     let start: NaiveDate = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(); // Although this is a 31st, it is a Sunday so will get adjusted to Following first,
                                                                           // since we are passing a calendar.
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 10, 31).unwrap();
     let expected: f64 = 0.5805556;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::D30360Euro, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::D30360Euro, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
     // Same dates but passing no calendar, i.e. no adjustment:
     let expected: f64 = 0.583333;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::D30360Euro, None, None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::D30360Euro, None, None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 }
 
@@ -141,7 +148,7 @@ fn dcf_d30365_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 24).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(); // This is a Monday within a Leap year
     let expected: f64 = 1.04383562;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::D30365, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::D30365, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
 }
 
@@ -155,25 +162,25 @@ fn dcf_bd252_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 24).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
     let expected: f64 = 1.09126984;
-    let res: f64 = day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).unwrap();
     assert_eq!(round_decimals(res), round_decimals(expected));
     // Test case with an adjustment on the end date
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 24).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 23).unwrap(); // This will get adjusted to the 27th of Dec
     let end2: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 27).unwrap(); // This is a business day so won't be adjusted
-    let res: f64 = day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).unwrap();
-    let res2: f64 = day_count_fraction(&start, &end2, DayCount::Bd252, Some(&cal), None).unwrap();
+    let res: f64 = try_day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).unwrap();
+    let res2: f64 = try_day_count_fraction(&start, &end2, DayCount::Bd252, Some(&cal), None).unwrap();
     // Business day count for both end dates above should be the same
     assert_eq!(round_decimals(res), round_decimals(res2));
     // But if we pass a Preceding adjustment they should differ
-    let res: f64 = day_count_fraction(
+    let res: f64 = try_day_count_fraction(
         &start,
         &end,
         DayCount::Bd252,
         Some(&cal),
         Some(AdjustRule::Preceding),
     ).unwrap();
-    let res2: f64 = day_count_fraction(
+    let res2: f64 = try_day_count_fraction(
         &start,
         &end2,
         DayCount::Bd252,
@@ -190,7 +197,7 @@ fn dcf_bd252_no_calendar_returns_err_test() {
     let start: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 24).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
     assert_eq!(
-        day_count_fraction(&start, &end, DayCount::Bd252, None, None),
+        try_day_count_fraction(&start, &end, DayCount::Bd252, None, None),
         Err(DayCountError::MissingCalendar),
     );
 }
@@ -200,17 +207,17 @@ fn dcf_bd252_with_calendar_returns_ok_test() {
     let cal = calendar::basic_calendar();
     let start = NaiveDate::from_ymd_opt(2024, 3, 18).unwrap();
     let end = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap();
-    assert!(day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).is_ok());
+    assert!(try_day_count_fraction(&start, &end, DayCount::Bd252, Some(&cal), None).is_ok());
 }
 
 #[test]
 fn dcf_act365fixed_leap_year_test() {
     // Over a full leap year (366 actual days) Act365Fixed still divides by 365,
-    // so the result is 366/365, not 1.0. This distinguishes it from Act365 and
-    // ActActISDA which both return 1.0 over a full leap year.
+    // so the result is 366/365, not 1.0. This distinguishes it from ActActISDA
+    // which returns 1.0 over a full leap year.
     let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
     let end   = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Act365Fixed, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Act365Fixed, None, None).unwrap();
     assert!((dcf - 366.0 / 365.0).abs() < 1e-9);
 }
 
@@ -219,10 +226,34 @@ fn dcf_act365fixed_non_leap_year_test() {
     // Over a full non-leap year (365 actual days) Act365Fixed returns exactly 1.0.
     let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
     let end   = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Act365Fixed, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Act365Fixed, None, None).unwrap();
     assert!((dcf - 1.0).abs() < 1e-9);
 }
 
+#[test]
+fn dcf_act365_matches_act365fixed_test() {
+    // Act365 is an alias: identical results inside and across leap years.
+    for (start, end) in [
+        (d(2024, 1, 1), d(2025, 1, 1)),
+        (d(2023, 2, 15), d(2023, 9, 30)),
+        (d(2023, 11, 30), d(2024, 3, 15)),
+    ] {
+        let a = try_day_count_fraction(&start, &end, DayCount::Act365, None, None).unwrap();
+        let b = try_day_count_fraction(&start, &end, DayCount::Act365Fixed, None, None).unwrap();
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn dcf_act365_25_test() {
+    // Four calendar years always contain exactly one leap day: 1461 / 365.25 = 4.0
+    let dcf = try_day_count_fraction(&d(2021, 3, 1), &d(2025, 3, 1), DayCount::Act365_25, None, None).unwrap();
+    assert!((dcf - 4.0).abs() < 1e-12);
+    // A full leap year: 366 / 365.25
+    let dcf = try_day_count_fraction(&d(2024, 1, 1), &d(2025, 1, 1), DayCount::Act365_25, None, None).unwrap();
+    assert!((dcf - 366.0 / 365.25).abs() < 1e-12);
+}
+
 #[test]
 fn dcf_thirty360us_start_on_31st_test() {
     // Rule 1: start on 31st → treated as 30th.
@@ -230,7 +261,7 @@ fn dcf_thirty360us_start_on_31st_test() {
     // res = 360*0 + 30*(4-1) + (15-30) = 90-15 = 75 → 75/360
     let start = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
     let end   = NaiveDate::from_ymd_opt(2023, 4, 15).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
     assert!((dcf - 75.0 / 360.0).abs() < 1e-9);
 }
 
@@ -241,7 +272,7 @@ fn dcf_thirty360us_end_on_31st_start_on_30th_test() {
     // res = 360*0 + 30*(3-1) + (30-30) = 60 → 60/360
     let start = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap();
     let end   = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
     assert!((dcf - 60.0 / 360.0).abs() < 1e-9);
 }
 
@@ -253,7 +284,7 @@ fn dcf_thirty360us_end_on_31st_start_before_30th_test() {
     // D30360Euro would give 75/360 (unconditionally treats end 31→30).
     let start = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
     let end   = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
     assert!((dcf - 76.0 / 360.0).abs() < 1e-9);
 }
 
@@ -264,7 +295,7 @@ fn dcf_thirty360us_start_on_eom_feb_nonleap_test() {
     // res = 360*0 + 30*(6-2) + (15-30) = 120-15 = 105 → 105/360
     let start = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
     let end   = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
     assert!((dcf - 105.0 / 360.0).abs() < 1e-9);
 }
 
@@ -275,7 +306,7 @@ fn dcf_thirty360us_both_on_eom_feb_test() {
     // res = 360*(2024-2023) + 30*(2-2) + (30-30) = 360 → 360/360 = 1.0
     let start = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
     let end   = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
-    let dcf = day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+    let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
     assert!((dcf - 1.0).abs() < 1e-9);
 }
 
@@ -288,13 +319,44 @@ fn dcf_thirty360us_differs_from_d30360euro_for_february_test() {
     //   D30360Euro:  start=28 → res = 107/360
     let start = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
     let end   = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
-    let us  = day_count_fraction(&start, &end, DayCount::Thirty360US,  None, None).unwrap();
-    let eu  = day_count_fraction(&start, &end, DayCount::D30360Euro,   None, None).unwrap();
+    let us  = try_day_count_fraction(&start, &end, DayCount::Thirty360US,  None, None).unwrap();
+    let eu  = try_day_count_fraction(&start, &end, DayCount::D30360Euro,   None, None).unwrap();
     assert!((us  - 105.0 / 360.0).abs() < 1e-9);
     assert!((eu  - 107.0 / 360.0).abs() < 1e-9);
     assert_ne!(round_decimals(us), round_decimals(eu));
 }
 
+#[test]
+fn dcf_thirty360us_month_end_table_test() {
+    // (start, end, expected 30/360 US day count)
+    // Reference values follow the SIA Standard Securities Calculation Methods
+    // rules, which are also what QuantLib's Thirty360::USA produces.
+    let cases = [
+        // Feb EOM → Mar 31: both become the 30th
+        (d(2023, 2, 28), d(2023, 3, 31), 30),
+        (d(2024, 2, 29), d(2024, 3, 31), 30),
+        // Feb 28 in a leap year is not month-end: no adjustment
+        (d(2024, 2, 28), d(2024, 3, 31), 33),
+        // End on Feb EOM while start is not: end stays at 28
+        (d(2023, 1, 31), d(2023, 2, 28), 28),
+        // Start on 30th, end on 31st
+        (d(2023, 4, 30), d(2023, 10, 31), 180),
+        // Full year from Feb EOM to Feb EOM across a leap year
+        (d(2023, 2, 28), d(2024, 2, 29), 360),
+        (d(2024, 2, 29), d(2025, 2, 28), 360),
+        // Plain mid-month period
+        (d(2023, 8, 15), d(2024, 2, 15), 180),
+    ];
+    for (start, end, days) in cases {
+        let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360US, None, None).unwrap();
+        assert!(
+            (dcf - days as f64 / 360.0).abs() < 1e-12,
+            "{start} → {end}: expected {days}/360, got {}",
+            dcf * 360.0,
+        );
+    }
+}
+
 #[test]
 fn dcf_non_bd252_conventions_return_ok_without_calendar_test() {
     let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
@@ -304,14 +366,544 @@ fn dcf_non_bd252_conventions_return_ok_without_calendar_test() {
         DayCount::Act360,
         DayCount::Act365,
         DayCount::Act365Fixed,
+        DayCount::Act365_25,
+        DayCount::NL365,
         DayCount::ActActISDA,
+        DayCount::ActActAFB,
         DayCount::D30360Euro,
         DayCount::Thirty360US,
+        DayCount::Thirty360ISDA,
+        DayCount::ThirtyE360ISDA,
         DayCount::D30365,
     ] {
         assert!(
-            day_count_fraction(&start, &end, dc, None, None).is_ok(),
+            try_day_count_fraction(&start, &end, dc, None, None).is_ok(),
             "{dc} should return Ok without a calendar",
         );
     }
 }
+
+// ── ActActICMA ───────────────────────────────────────────────────────────────
+// Expected values are the worked examples from the ISDA paper "EMU and Market
+// Conventions: Recent Developments" (Actual/Actual ICMA section).
+
+fn icma(start: NaiveDate, end: NaiveDate, ref_start: NaiveDate, ref_end: NaiveDate, frequency: Frequency) -> f64 {
+    let period = ReferencePeriod { start: ref_start, end: ref_end, frequency };
+    day_count_fraction_with_period(&start, &end, DayCount::ActActICMA, &period, None, None).unwrap()
+}
+
+#[test]
+fn dcf_actacticma_regular_period_test() {
+    let res = icma(d(2003, 11, 1), d(2004, 5, 1), d(2003, 11, 1), d(2004, 5, 1), Frequency::Semiannual);
+    assert!((res - 0.5).abs() < 1e-12);
+    let res = icma(d(1999, 7, 1), d(2000, 7, 1), d(1999, 7, 1), d(2000, 7, 1), Frequency::Annual);
+    assert!((res - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn dcf_actacticma_short_first_period_test() {
+    // 150 days in a 365-day annual reference period
+    let res = icma(d(1999, 2, 1), d(1999, 7, 1), d(1998, 7, 1), d(1999, 7, 1), Frequency::Annual);
+    assert_eq!(round_decimals(res), round_decimals(0.410958904110));
+}
+
+#[test]
+fn dcf_actacticma_long_first_period_test() {
+    // Split at 2003-01-15: 153/(2*184) + 181/(2*181)
+    let res = icma(d(2002, 8, 15), d(2003, 7, 15), d(2003, 1, 15), d(2003, 7, 15), Frequency::Semiannual);
+    assert_eq!(round_decimals(res), round_decimals(0.915760869565));
+}
+
+#[test]
+fn dcf_actacticma_short_final_period_test() {
+    // 152 days in a 182-day semiannual reference period
+    let res = icma(d(2000, 1, 30), d(2000, 6, 30), d(2000, 1, 30), d(2000, 7, 30), Frequency::Semiannual);
+    assert_eq!(round_decimals(res), round_decimals(0.417582417582));
+}
+
+#[test]
+fn dcf_actacticma_long_final_period_test() {
+    // One whole notional period after the reference period plus 31 days of the next.
+    // 2000-07-30 → 2001-01-30 = 0.5, 2001-01-30 → 2001-03-02 = 31/(2*181)
+    let res = icma(d(2000, 1, 30), d(2001, 3, 2), d(2000, 1, 30), d(2000, 7, 30), Frequency::Semiannual);
+    assert!((res - (1.0 + 31.0 / 362.0)).abs() < 1e-12);
+}
+
+#[test]
+fn icma_year_fraction_isda_examples_test() {
+    // Regular semiannual coupon
+    let res = icma_year_fraction(&d(2003, 11, 1), &d(2004, 5, 1), &d(2003, 11, 1), &d(2004, 5, 1), Frequency::Semiannual).unwrap();
+    assert!((res - 0.5).abs() < 1e-12);
+    // Short first annual coupon: 150 / 365
+    let res = icma_year_fraction(&d(1999, 2, 1), &d(1999, 7, 1), &d(1998, 7, 1), &d(1999, 7, 1), Frequency::Annual).unwrap();
+    assert_eq!(round_decimals(res), round_decimals(0.410958904110));
+    // Long first semiannual coupon: 153 / (2 * 184) + 181 / (2 * 181)
+    let res = icma_year_fraction(&d(2002, 8, 15), &d(2003, 7, 15), &d(2003, 1, 15), &d(2003, 7, 15), Frequency::Semiannual).unwrap();
+    assert_eq!(round_decimals(res), round_decimals(0.915760869565));
+    // Short final semiannual coupon: 152 / (2 * 182)
+    let res = icma_year_fraction(&d(2000, 1, 30), &d(2000, 6, 30), &d(2000, 1, 30), &d(2000, 7, 30), Frequency::Semiannual).unwrap();
+    assert_eq!(round_decimals(res), round_decimals(0.417582417582));
+    // Long final quarterly coupon on month ends: the notional period after
+    // 29 Feb 2000 ends on 31 May, so 91 / (4 * 91) + 61 / (4 * 92)
+    let res = icma_year_fraction(&d(1999, 11, 30), &d(2000, 4, 30), &d(1999, 11, 30), &d(2000, 2, 29), Frequency::Quarterly).unwrap();
+    assert!((res - (0.25 + 61.0 / 368.0)).abs() < 1e-12);
+    assert_eq!(round_decimals(res), round_decimals(0.415760869565));
+}
+
+#[test]
+fn icma_year_fraction_month_end_long_first_test() {
+    // Notional periods before 30 Jun 2024 end on 31 Mar and 31 Dec, not 30 Mar
+    // and 30 Dec: 15 / (4 * 91) for the stub, then two whole quarters.
+    let res = icma_year_fraction(&d(2024, 3, 16), &d(2024, 9, 30), &d(2024, 6, 30), &d(2024, 9, 30), Frequency::Quarterly).unwrap();
+    assert!((res - (15.0 / 364.0 + 0.5)).abs() < 1e-12);
+}
+
+#[test]
+fn icma_year_fraction_start_many_periods_back_test() {
+    // Hundreds of thousands of notional periods before the reference period;
+    // each whole week or day contributes the same share as its days.
+    let start = d(1700, 1, 1);
+    let res = icma_year_fraction(&start, &d(2024, 1, 15), &d(2024, 1, 1), &d(2024, 1, 8), Frequency::Weekly).unwrap();
+    let days = (d(2024, 1, 15) - start).num_days() as f64;
+    assert!((res - days / 364.0).abs() < 1e-9);
+
+    let start = d(1000, 1, 1);
+    let res = icma_year_fraction(&start, &d(2024, 1, 15), &d(2024, 1, 1), &d(2024, 1, 2), Frequency::Daily).unwrap();
+    let per_year = Frequency::Daily.periods_per_year().unwrap();
+    // Summing a third of a million shares leaves some rounding.
+    assert!((res - (d(2024, 1, 15) - start).num_days() as f64 / per_year).abs() < 1e-6);
+}
+
+#[test]
+fn icma_year_fraction_errors_test() {
+    assert_eq!(
+        icma_year_fraction(&d(2024, 1, 1), &d(2024, 7, 1), &d(2024, 1, 1), &d(2024, 7, 1), Frequency::Zero),
+        Err(DayCountError::InvalidFrequency),
+    );
+    assert_eq!(
+        icma_year_fraction(&d(2024, 1, 1), &d(2024, 7, 1), &d(2024, 7, 1), &d(2024, 1, 1), Frequency::Semiannual),
+        Err(DayCountError::InvalidReferencePeriod),
+    );
+}
+
+#[test]
+fn dcf_actacticma_missing_period_returns_err_test() {
+    assert_eq!(
+        try_day_count_fraction(&d(2003, 11, 1), &d(2004, 5, 1), DayCount::ActActICMA, None, None),
+        Err(DayCountError::MissingReferencePeriod),
+    );
+}
+
+#[test]
+fn dcf_actacticma_zero_frequency_returns_err_test() {
+    let period = ReferencePeriod { start: d(2003, 11, 1), end: d(2004, 5, 1), frequency: Frequency::Zero };
+    assert_eq!(
+        day_count_fraction_with_period(&d(2003, 11, 1), &d(2004, 5, 1), DayCount::ActActICMA, &period, None, None),
+        Err(DayCountError::InvalidFrequency),
+    );
+}
+
+#[test]
+fn dcf_with_period_matches_plain_for_other_conventions_test() {
+    let period = ReferencePeriod { start: d(2023, 1, 1), end: d(2023, 7, 1), frequency: Frequency::Semiannual };
+    for dc in [DayCount::Act360, DayCount::ActActISDA, DayCount::Thirty360US] {
+        let plain = try_day_count_fraction(&d(2023, 1, 1), &d(2023, 9, 30), dc, None, None).unwrap();
+        let with = day_count_fraction_with_period(&d(2023, 1, 1), &d(2023, 9, 30), dc, &period, None, None).unwrap();
+        assert_eq!(plain, with);
+    }
+}
+
+// ── ActActAFB ────────────────────────────────────────────────────────────────
+// Expected values match QuantLib's ActualActual(ActualActual::AFB).
+
+#[test]
+fn dcf_actactafb_test() {
+    let cases = [
+        (d(2003, 11, 1), d(2004, 5, 1), 0.497267759563),   // 182/366, contains 29 Feb 2004
+        (d(1999, 2, 1), d(1999, 7, 1), 0.410958904110),    // 150/365
+        (d(2002, 8, 15), d(2003, 7, 15), 0.915068493151),  // 334/365
+        (d(2000, 1, 30), d(2000, 6, 30), 0.415300546448),  // 152/366
+        (d(1999, 11, 30), d(2000, 4, 30), 0.415300546448), // 152/366
+        (d(1994, 2, 10), d(1997, 6, 30), 3.383561643836),  // 3 years + 140/365
+    ];
+    for (start, end, expected) in cases {
+        let res = try_day_count_fraction(&start, &end, DayCount::ActActAFB, None, None).unwrap();
+        assert_eq!(round_decimals(res), round_decimals(expected), "{start} → {end}");
+    }
+}
+
+#[test]
+fn dcf_actactafb_feb_29_end_date_test() {
+    // Stepping back from 29 Feb 2008 clamps to 28 Feb in non-leap years and
+    // returns to 29 Feb in 2004, leaving a one-day stub that excludes the leap day.
+    let res = try_day_count_fraction(&d(2004, 2, 28), &d(2008, 2, 29), DayCount::ActActAFB, None, None).unwrap();
+    assert!((res - (4.0 + 1.0 / 365.0)).abs() < 1e-12);
+}
+
+#[test]
+fn dcf_actactafb_whole_years_test() {
+    let res = try_day_count_fraction(&d(2020, 3, 1), &d(2024, 3, 1), DayCount::ActActAFB, None, None).unwrap();
+    assert!((res - 4.0).abs() < 1e-12);
+    // Reversed dates use the absolute difference
+    let rev = try_day_count_fraction(&d(2024, 3, 1), &d(2020, 3, 1), DayCount::ActActAFB, None, None).unwrap();
+    assert_eq!(res, rev);
+}
+
+// ── Thirty360ISDA / ThirtyE360ISDA ───────────────────────────────────────────
+
+#[test]
+fn dcf_thirty360isda_test() {
+    let cases = [
+        (d(2007, 1, 31), d(2007, 2, 28), 28),
+        // No February rule: start stays at 28, end 31 stays because start < 30
+        (d(2007, 2, 28), d(2007, 3, 31), 33),
+        (d(2007, 3, 30), d(2007, 3, 31), 0),
+        (d(2007, 3, 31), d(2007, 9, 30), 180),
+        (d(2006, 8, 31), d(2007, 2, 28), 178),
+    ];
+    for (start, end, days) in cases {
+        let dcf = try_day_count_fraction(&start, &end, DayCount::Thirty360ISDA, None, None).unwrap();
+        assert!((dcf - days as f64 / 360.0).abs() < 1e-12, "{start} → {end}");
+    }
+}
+
+#[test]
+fn dcf_thirtye360isda_test() {
+    // (start, end, days if not termination, days if termination)
+    let cases = [
+        (d(2007, 2, 28), d(2008, 2, 29), 360, 359),
+        (d(2008, 2, 29), d(2008, 8, 31), 180, 180),
+        (d(2007, 1, 31), d(2007, 2, 28), 30, 28),
+        (d(2006, 8, 31), d(2007, 2, 28), 180, 178),
+        (d(2007, 3, 15), d(2007, 9, 15), 180, 180),
+    ];
+    for (start, end, regular, termination) in cases {
+        let ctx = DayCountContext::default();
+        let dcf = day_count_fraction_with_context(&start, &end, DayCount::ThirtyE360ISDA, &ctx, None, None).unwrap();
+        assert!((dcf - regular as f64 / 360.0).abs() < 1e-12, "{start} → {end}");
+
+        let ctx = DayCountContext { is_termination_date: true, ..Default::default() };
+        let dcf = day_count_fraction_with_context(&start, &end, DayCount::ThirtyE360ISDA, &ctx, None, None).unwrap();
+        assert!((dcf - termination as f64 / 360.0).abs() < 1e-12, "{start} → {end} (termination)");
+    }
+}
+
+#[test]
+fn dcf_with_context_reference_period_matches_with_period_test() {
+    let period = ReferencePeriod { start: d(2003, 1, 15), end: d(2003, 7, 15), frequency: Frequency::Semiannual };
+    let ctx = DayCountContext { reference_period: Some(period), ..Default::default() };
+    let a = day_count_fraction_with_context(&d(2002, 8, 15), &d(2003, 7, 15), DayCount::ActActICMA, &ctx, None, None).unwrap();
+    let b = day_count_fraction_with_period(&d(2002, 8, 15), &d(2003, 7, 15), DayCount::ActActICMA, &period, None, None).unwrap();
+    assert_eq!(a, b);
+}
+
+// ── NL365 ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn dcf_nl365_test() {
+    let cases = [
+        // Full leap year: 366 actual days, 365 without 29 Feb
+        (d(2024, 1, 1), d(2025, 1, 1), 365),
+        // Spanning the leap day
+        (d(2024, 2, 15), d(2024, 3, 15), 28),
+        (d(2023, 11, 30), d(2024, 3, 15), 105),
+        // Non-leap year behaves like Act365Fixed
+        (d(2023, 2, 15), d(2023, 9, 30), 227),
+        // 29 Feb counts as 28 Feb
+        (d(2024, 2, 28), d(2024, 2, 29), 0),
+        (d(2024, 2, 29), d(2024, 3, 1), 1),
+        // Four years always count 4 * 365
+        (d(2020, 1, 15), d(2024, 1, 15), 1460),
+    ];
+    for (start, end, days) in cases {
+        let dcf = try_day_count_fraction(&start, &end, DayCount::NL365, None, None).unwrap();
+        assert!((dcf - days as f64 / 365.0).abs() < 1e-12, "{start} → {end}");
+    }
+}
+
+#[test]
+fn dcf_out_of_range_returns_err_test() {
+    // Adjusting a holiday on the last representable date cannot succeed.
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let start = d(2024, 1, 2);
+    assert_eq!(
+        try_day_count_fraction(&start, &NaiveDate::MAX, DayCount::Act360, Some(&cal), Some(AdjustRule::Following)),
+        Err(DayCountError::OutOfRange),
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_day_count_fraction_forwards_test() {
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let start = d(2024, 1, 2);
+    for end in [d(2024, 7, 2), NaiveDate::MAX] {
+        assert_eq!(
+            findates::algebra::day_count_fraction(&start, &end, DayCount::Act360, Some(&cal), None),
+            try_day_count_fraction(&start, &end, DayCount::Act360, Some(&cal), None),
+        );
+    }
+}
+
+// ── Batch fractions ──────────────────────────────────────────────────────────
+
+#[test]
+fn day_count_fractions_match_pairwise_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]);
+    let dates = [
+        NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(), // Saturday
+        NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(), // Sunday
+        NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(), // holiday
+        NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+    ];
+    let daycounts = [DayCount::Act360, DayCount::ActActISDA, DayCount::D30360Euro, DayCount::Bd252, DayCount::NL365];
+    let rules = [None, Some(AdjustRule::Unadjusted), Some(AdjustRule::ModFollowing), Some(AdjustRule::Preceding)];
+    for daycount in daycounts {
+        for rule in rules {
+            let batch = day_count_fractions(&dates, daycount, Some(&cal), rule).unwrap();
+            let pairwise: Vec<f64> = dates
+                .windows(2)
+                .map(|p| try_day_count_fraction(&p[0], &p[1], daycount, Some(&cal), rule).unwrap())
+                .collect();
+            assert_eq!(batch, pairwise, "{daycount:?} {rule:?}");
+        }
+    }
+}
+
+#[test]
+fn day_count_fractions_edge_cases_test() {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    assert_eq!(day_count_fractions(&[], DayCount::Act360, None, None), Ok(vec![]));
+    assert_eq!(day_count_fractions(&[date], DayCount::Act360, None, None), Ok(vec![]));
+    assert_eq!(
+        day_count_fractions(&[date, date], DayCount::Bd252, None, None),
+        Err(DayCountError::MissingCalendar)
+    );
+}
+
+// ── Convention matrix ────────────────────────────────────────────────────────
+
+#[test]
+fn dcf_matrix_matches_single_conventions_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 12, 25)]);
+    // Following moves Saturday 2024-03-16 to the 18th and the holiday to the 26th.
+    let (start, end) = (d(2024, 3, 16), d(2024, 12, 25));
+    let daycounts = [DayCount::Act360, DayCount::ActActISDA, DayCount::Thirty360US, DayCount::Bd252, DayCount::NL365];
+    let matrix = dcf_matrix(&start, &end, &daycounts, Some(&cal)).unwrap();
+    assert_eq!((matrix.start, matrix.end), (start, end));
+    assert_eq!(matrix.rows.iter().map(|row| row.daycount).collect::<Vec<_>>(), daycounts);
+    for row in &matrix.rows {
+        assert_eq!(row.days, day_count(&start, &end, row.daycount, Some(&cal)).unwrap());
+        assert_eq!(row.fraction, try_day_count_fraction(&start, &end, row.daycount, Some(&cal), None).unwrap());
+    }
+    assert_eq!(matrix.rows[0].days, 283);
+    assert!(matrix.spread() > 0.0);
+}
+
+#[test]
+fn dcf_matrix_table_test() {
+    let matrix = dcf_matrix(&d(2023, 11, 30), &d(2024, 5, 31), &[DayCount::Act365Fixed, DayCount::ThirtyE360ISDA], None).unwrap();
+    assert_eq!(
+        matrix.to_string(),
+        "2023-11-30 to 2024-05-31\n\
+         Convention          Days        Fraction\n\
+         Act365Fixed          183    0.5013698630\n\
+         ThirtyE360ISDA       180    0.5000000000"
+    );
+    let empty = dcf_matrix(&d(2023, 11, 30), &d(2024, 5, 31), &[], None).unwrap();
+    assert_eq!(empty.to_string(), "2023-11-30 to 2024-05-31\nConvention          Days        Fraction");
+    assert_eq!(empty.spread(), 0.0);
+    assert_eq!(empty.fraction(DayCount::Act360), None);
+}
+
+#[test]
+fn dcf_matrix_errors_test() {
+    let (start, end) = (d(2024, 1, 31), d(2024, 7, 31));
+    assert_eq!(
+        dcf_matrix(&start, &end, &[DayCount::Act360, DayCount::Bd252], None),
+        Err(DayCountError::MissingCalendar)
+    );
+    assert_eq!(
+        dcf_matrix(&start, &end, &[DayCount::ActActICMA], None),
+        Err(DayCountError::MissingReferencePeriod)
+    );
+}
+
+// ── Rounding ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn dcf_rounded_test() {
+    let (start, end) = (d(2024, 1, 15), d(2024, 4, 15));
+    // 91 / 360 = 0.252777...
+    let rounded = |decimals, direction| {
+        day_count_fraction_rounded(&start, &end, DayCount::Act360, None, None, Rounding::new(decimals, direction)).unwrap()
+    };
+    assert_eq!(rounded(4, RoundingDirection::Nearest), 0.2528);
+    assert_eq!(rounded(4, RoundingDirection::Down), 0.2527);
+    assert_eq!(rounded(2, RoundingDirection::Up), 0.26);
+    assert_eq!(rounded(0, RoundingDirection::Nearest), 0.0);
+
+    // Bus/252 truncated to eight places, as for Brazilian DI accruals.
+    let cal = calendar::basic_calendar();
+    let truncated = Rounding::new(8, RoundingDirection::Down);
+    let dcf = day_count_fraction_rounded(&d(2024, 1, 2), &d(2024, 2, 14), DayCount::Bd252, Some(&cal), None, truncated);
+    assert_eq!(dcf, Ok(0.12301587));
+    assert_eq!(
+        day_count_fraction_rounded(&start, &end, DayCount::Bd252, None, None, truncated),
+        Err(DayCountError::MissingCalendar)
+    );
+}
+
+#[test]
+fn dcf_context_rounding_test() {
+    let period = ReferencePeriod { start: d(2024, 1, 15), end: d(2024, 7, 15), frequency: Frequency::Semiannual };
+    let ctx = DayCountContext {
+        reference_period: Some(period),
+        rounding: Some(Rounding::new(6, RoundingDirection::Nearest)),
+        ..Default::default()
+    };
+    // Short first coupon: 91 / (2 * 182)
+    let dcf = day_count_fraction_with_context(&d(2024, 4, 15), &d(2024, 7, 15), DayCount::ActActICMA, &ctx, None, None);
+    assert_eq!(dcf, Ok(0.25));
+    let dcf = day_count_fraction_with_context(&d(2024, 4, 16), &d(2024, 7, 15), DayCount::ActActICMA, &ctx, None, None);
+    assert_eq!(dcf, Ok(0.247253));
+}
+
+// ── Integer day counts ───────────────────────────────────────────────────────
+
+#[test]
+fn day_count_is_numerator_of_fraction_test() {
+    let setup = DayCountSetup::new();
+    let (start, end) = (d(2023, 11, 30), d(2024, 5, 31));
+    let bases = [
+        (DayCount::Act360, 360.0),
+        (DayCount::Act365Fixed, 365.0),
+        (DayCount::Act365_25, 365.25),
+        (DayCount::NL365, 365.0),
+        (DayCount::D30360Euro, 360.0),
+        (DayCount::Thirty360ISDA, 360.0),
+        (DayCount::ThirtyE360ISDA, 360.0),
+        (DayCount::Thirty360US, 360.0),
+        (DayCount::D30365, 365.0),
+        (DayCount::Bd252, 252.0),
+    ];
+    for (daycount, basis) in bases {
+        let days = day_count(&start, &end, daycount, Some(&setup.cal)).unwrap();
+        let dcf = try_day_count_fraction(&start, &end, daycount, Some(&setup.cal), None).unwrap();
+        assert_eq!(days as f64 / basis, dcf, "{daycount:?}");
+    }
+}
+
+#[test]
+fn day_count_values_test() {
+    let setup = DayCountSetup::new();
+    // 30 Nov 2023 to 31 May 2024: 183 actual days, 182 without 29 Feb
+    let (start, end) = (d(2023, 11, 30), d(2024, 5, 31));
+    assert_eq!(day_count(&start, &end, DayCount::Act360, None), Ok(183));
+    assert_eq!(day_count(&start, &end, DayCount::NL365, None), Ok(182));
+    assert_eq!(day_count(&start, &end, DayCount::D30360Euro, None), Ok(180));
+    assert_eq!(day_count(&start, &end, DayCount::Thirty360ISDA, None), Ok(180));
+    assert_eq!(day_count(&start, &end, DayCount::D30365, None), Ok(181));
+    // 131 weekdays less Christmas and Boxing Day
+    assert_eq!(day_count(&start, &end, DayCount::Bd252, Some(&setup.cal)), Ok(129));
+    assert_eq!(day_count(&start, &end, DayCount::Bd252, None), Err(DayCountError::MissingCalendar));
+}
+
+#[test]
+fn day_count_adjusts_with_calendar_test() {
+    let setup = DayCountSetup::new();
+    // Saturday 30 Dec 2023 rolls Following (past no holiday) to Monday 1 Jan 2024
+    let (start, end) = (d(2023, 12, 1), d(2023, 12, 30));
+    assert_eq!(day_count(&start, &end, DayCount::Act360, None), Ok(29));
+    assert_eq!(day_count(&start, &end, DayCount::Act360, Some(&setup.cal)), Ok(31));
+}
+
+// ── DayCounter trait ─────────────────────────────────────────────────────────
+
+// Bus/252 that also excludes the day before each holiday, as a stand-in for a
+// bespoke local convention.
+struct BridgeDays252<'a> {
+    cal: &'a calendar::Calendar,
+}
+
+impl DayCounter for BridgeDays252<'_> {
+    fn year_fraction(&self, start: &NaiveDate, end: &NaiveDate, _: &DayCountContext) -> Result<f64, DayCountError> {
+        Ok(self.day_count(start, end)? as f64 / 252.0)
+    }
+
+    fn day_count(&self, start: &NaiveDate, end: &NaiveDate) -> Result<i64, DayCountError> {
+        let base = BusinessDays252 { calendar: self.cal }.day_count(start, end)?;
+        let bridges = self
+            .cal
+            .get_holidays()
+            .iter()
+            .filter(|h| *start < **h && **h <= *end)
+            .count() as i64;
+        Ok(base - bridges)
+    }
+}
+
+#[test]
+fn day_counter_enum_matches_day_count_fraction_test() {
+    let ctx = DayCountContext::default();
+    let pairs = [
+        (d(2023, 1, 31), d(2024, 2, 29)),
+        (d(2024, 2, 29), d(2023, 1, 31)),
+        (d(2023, 12, 31), d(2024, 12, 31)),
+        (d(2020, 2, 28), d(2028, 3, 1)),
+    ];
+    let daycounts = [
+        DayCount::Act360, DayCount::Act365, DayCount::Act365Fixed, DayCount::Act365_25,
+        DayCount::NL365, DayCount::ActActISDA, DayCount::D30360Euro, DayCount::Thirty360ISDA,
+        DayCount::ThirtyE360ISDA, DayCount::Thirty360US, DayCount::D30365, DayCount::ActActAFB,
+    ];
+    for (start, end) in pairs {
+        for daycount in daycounts {
+            assert_eq!(
+                daycount.year_fraction(&start, &end, &ctx),
+                try_day_count_fraction(&start, &end, daycount, None, None),
+                "{daycount:?} {start} {end}"
+            );
+        }
+    }
+}
+
+#[test]
+fn day_counter_day_count_is_numerator_test() {
+    let (start, end) = (d(2024, 1, 31), d(2024, 7, 31));
+    assert_eq!(DayCount::Act360.day_count(&start, &end), Ok(182));
+    assert_eq!(DayCount::Act360.day_count(&end, &start), Ok(182));
+    assert_eq!(DayCount::D30360Euro.day_count(&start, &end), Ok(180));
+    assert_eq!(DayCount::NL365.day_count(&d(2024, 2, 1), &d(2024, 3, 1)), Ok(28));
+    assert_eq!(DayCount::Bd252.day_count(&start, &end), Err(DayCountError::MissingCalendar));
+    assert_eq!(
+        DayCount::Bd252.year_fraction(&start, &end, &DayCountContext::default()),
+        Err(DayCountError::MissingCalendar)
+    );
+}
+
+#[test]
+fn business_days_252_matches_bd252_test() {
+    let setup = DayCountSetup::new();
+    let bd252 = BusinessDays252 { calendar: &setup.cal };
+    let (start, end) = (d(2023, 12, 20), d(2024, 1, 10));
+    assert_eq!(
+        bd252.year_fraction(&start, &end, &DayCountContext::default()),
+        try_day_count_fraction(&start, &end, DayCount::Bd252, Some(&setup.cal), Some(AdjustRule::Unadjusted))
+    );
+    assert_eq!(bd252.day_count(&start, &end), Ok(13));
+}
+
+#[test]
+fn user_defined_day_counter_test() {
+    let setup = DayCountSetup::new();
+    let bridge = BridgeDays252 { cal: &setup.cal };
+    let (start, end) = (d(2023, 12, 20), d(2024, 1, 10));
+    // 13 business days minus the two holidays' bridge days
+    assert_eq!(bridge.day_count(&start, &end), Ok(11));
+    let counter: &dyn DayCounter = &bridge;
+    assert_eq!(counter.year_fraction(&start, &end, &DayCountContext::default()), Ok(11.0 / 252.0));
+}
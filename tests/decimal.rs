@@ -0,0 +1,119 @@
+// Integration tests for day count fractions in decimal arithmetic.
+// Run with `cargo test --features decimal`.
+#![cfg(feature = "decimal")]
+
+use chrono::NaiveDate;
+use findates::algebra::{day_count_fraction_with_context, try_day_count_fraction, DayCountContext, ReferencePeriod};
+use findates::calendar;
+use findates::conventions::{AdjustRule, DayCount, Frequency, Rounding, RoundingDirection};
+use findates::decimal::{day_count_fraction_decimal, day_count_fraction_decimal_with_context};
+use findates::DayCountError;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+mod setup;
+use setup::d;
+
+const ALL: [DayCount; 14] = [
+    DayCount::Act360,
+    DayCount::Act365,
+    DayCount::Act365Fixed,
+    DayCount::Act365_25,
+    DayCount::NL365,
+    DayCount::Bd252,
+    DayCount::ActActISDA,
+    DayCount::ActActICMA,
+    DayCount::ActActAFB,
+    DayCount::D30360Euro,
+    DayCount::Thirty360US,
+    DayCount::Thirty360ISDA,
+    DayCount::ThirtyE360ISDA,
+    DayCount::D30365,
+];
+
+// ── Agreement with f64 ───────────────────────────────────────────────────────
+
+#[test]
+fn decimal_matches_f64_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 12, 25), d(2025, 1, 1)]);
+    let period = ReferencePeriod { start: d(2024, 2, 29), end: d(2024, 8, 31), frequency: Frequency::Semiannual };
+    let dates = [d(2023, 11, 30), d(2024, 2, 29), d(2024, 3, 16), d(2024, 8, 31), d(2024, 12, 25), d(2026, 2, 28)];
+    for start in dates {
+        for end in dates {
+            for daycount in ALL {
+                for is_termination_date in [false, true] {
+                    let ctx = DayCountContext { reference_period: Some(period), is_termination_date, ..Default::default() };
+                    let rule = Some(AdjustRule::ModFollowing);
+                    let expected = day_count_fraction_with_context(&start, &end, daycount, &ctx, Some(&cal), rule).unwrap();
+                    let actual = day_count_fraction_decimal_with_context(&start, &end, daycount, &ctx, Some(&cal), rule).unwrap();
+                    assert!(
+                        (actual.to_f64().unwrap() - expected).abs() < 1e-14,
+                        "{daycount} {start} to {end}: {actual} vs {expected}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+// ── Exactness ────────────────────────────────────────────────────────────────
+
+#[test]
+fn decimal_sums_without_drift_test() {
+    // Ten 36-day Act/360 periods of 0.1 add up to exactly one year, which
+    // 0.1 in binary does not.
+    let days: Vec<NaiveDate> = d(2024, 1, 1).iter_days().step_by(36).take(11).collect();
+    let total: Decimal = days
+        .windows(2)
+        .map(|pair| day_count_fraction_decimal(&pair[0], &pair[1], DayCount::Act360, None, None).unwrap())
+        .sum();
+    assert_eq!(total, Decimal::ONE);
+    let total: f64 = days
+        .windows(2)
+        .map(|pair| try_day_count_fraction(&pair[0], &pair[1], DayCount::Act360, None, None).unwrap())
+        .sum();
+    assert_ne!(total, 1.0);
+
+    // A regular 5-monthly coupon is 5 / 12, not 1 / 2.4.
+    let period = ReferencePeriod { start: d(2024, 1, 15), end: d(2024, 6, 15), frequency: Frequency::EveryNMonths(5) };
+    let ctx = DayCountContext { reference_period: Some(period), ..Default::default() };
+    let dcf = day_count_fraction_decimal_with_context(&period.start, &period.end, DayCount::ActActICMA, &ctx, None, None);
+    assert_eq!(dcf, Ok(Decimal::from(5) / Decimal::from(12)));
+}
+
+#[test]
+fn decimal_rounding_test() {
+    // 45 / 360 = 0.125 exactly: a true midpoint at two decimal places.
+    let rounded = |direction| {
+        let ctx = DayCountContext { rounding: Some(Rounding::new(2, direction)), ..Default::default() };
+        day_count_fraction_decimal_with_context(&d(2024, 1, 1), &d(2024, 2, 15), DayCount::Act360, &ctx, None, None).unwrap()
+    };
+    assert_eq!(rounded(RoundingDirection::Nearest), Decimal::new(13, 2));
+    assert_eq!(rounded(RoundingDirection::Up), Decimal::new(13, 2));
+    assert_eq!(rounded(RoundingDirection::Down), Decimal::new(12, 2));
+
+    // Bus/252 truncated to eight places: 31 / 252 = 0.123015873...
+    let cal = calendar::basic_calendar();
+    let ctx = DayCountContext { rounding: Some(Rounding::new(8, RoundingDirection::Down)), ..Default::default() };
+    let dcf = day_count_fraction_decimal_with_context(&d(2024, 1, 2), &d(2024, 2, 14), DayCount::Bd252, &ctx, Some(&cal), None);
+    assert_eq!(dcf, Ok(Decimal::new(12301587, 8)));
+}
+
+// ── Errors ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn decimal_errors_test() {
+    let (start, end) = (d(2024, 1, 1), d(2024, 7, 1));
+    assert_eq!(day_count_fraction_decimal(&start, &end, DayCount::Bd252, None, None), Err(DayCountError::MissingCalendar));
+    assert_eq!(
+        day_count_fraction_decimal(&start, &end, DayCount::ActActICMA, None, None),
+        Err(DayCountError::MissingReferencePeriod)
+    );
+    let period = ReferencePeriod { start, end, frequency: Frequency::Zero };
+    let ctx = DayCountContext { reference_period: Some(period), ..Default::default() };
+    assert_eq!(
+        day_count_fraction_decimal_with_context(&start, &end, DayCount::ActActICMA, &ctx, None, None),
+        Err(DayCountError::InvalidFrequency)
+    );
+}
@@ -0,0 +1,79 @@
+// Integration tests for ex-dividend and record dates.
+// UK gilts go ex-dividend 7 business days before the coupon date, and the
+// record date is the business day before that.
+
+use chrono::NaiveDate;
+use findates::algebra::{ex_dividend_date, is_ex_dividend, next_business_day, record_date};
+use findates::calendar::{basic_calendar, Calendar};
+
+mod setup;
+use setup::d;
+
+fn uk() -> Calendar {
+    Calendar::uk_bank_holidays(2024..=2025)
+}
+
+const GILT_EX_DIV_DAYS: u32 = 7;
+
+// ── UK gilts ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn gilt_ex_dividend_dates_test() {
+    let uk = uk();
+    // (coupon date, ex-dividend date, record date)
+    let cases = [
+        // Coupon on a Saturday: counting starts from the scheduled date.
+        (d(2024, 12, 7), d(2024, 11, 28), d(2024, 11, 27)),
+        // Christmas, Boxing Day and New Year's Day are skipped.
+        (d(2025, 1, 7), d(2024, 12, 24), d(2024, 12, 23)),
+        // Good Friday and Easter Monday are skipped.
+        (d(2025, 4, 22), d(2025, 4, 9), d(2025, 4, 8)),
+        (d(2025, 3, 7), d(2025, 2, 26), d(2025, 2, 25)),
+    ];
+    for (coupon, ex_div, record) in cases {
+        assert_eq!(ex_dividend_date(&coupon, GILT_EX_DIV_DAYS, &uk), Some(ex_div), "coupon {coupon}");
+        assert_eq!(record_date(&coupon, GILT_EX_DIV_DAYS, &uk), Some(record), "coupon {coupon}");
+    }
+}
+
+#[test]
+fn gilt_ex_dividend_period_test() {
+    let uk = uk();
+    let coupon = d(2025, 1, 7);
+    let ex_div = ex_dividend_date(&coupon, GILT_EX_DIV_DAYS, &uk).unwrap();
+    let mut settlement = d(2024, 12, 1);
+    while settlement <= d(2025, 1, 31) {
+        let expected = ex_div <= settlement && settlement < coupon;
+        assert_eq!(is_ex_dividend(&settlement, &coupon, GILT_EX_DIV_DAYS, &uk), Some(expected), "settlement {settlement}");
+        settlement = settlement.succ_opt().unwrap();
+    }
+    // The record date itself still settles cum-dividend.
+    let record = record_date(&coupon, GILT_EX_DIV_DAYS, &uk).unwrap();
+    assert_eq!(is_ex_dividend(&record, &coupon, GILT_EX_DIV_DAYS, &uk), Some(false));
+}
+
+// ── Other conventions ────────────────────────────────────────────────────────
+
+#[test]
+fn no_ex_dividend_period_test() {
+    let cal = basic_calendar();
+    let coupon = d(2024, 6, 15);
+    assert_eq!(ex_dividend_date(&coupon, 0, &cal), Some(coupon));
+    assert_eq!(record_date(&coupon, 0, &cal), Some(d(2024, 6, 14)));
+    assert_eq!(is_ex_dividend(&d(2024, 6, 14), &coupon, 0, &cal), Some(false));
+    assert_eq!(is_ex_dividend(&coupon, &coupon, 0, &cal), Some(false));
+    // One business day: the Friday before a Saturday coupon.
+    assert_eq!(ex_dividend_date(&coupon, 1, &cal), Some(d(2024, 6, 14)));
+    assert_eq!(is_ex_dividend(&d(2024, 6, 14), &coupon, 1, &cal), Some(true));
+}
+
+#[test]
+fn ex_dividend_out_of_range_test() {
+    let cal = basic_calendar();
+    let first = NaiveDate::MIN.iter_days().find(|day| cal.is_business_day(day)).unwrap();
+    let second = next_business_day(&first, &cal).unwrap();
+    assert_eq!(ex_dividend_date(&second, 1, &cal), Some(first));
+    assert_eq!(record_date(&second, 1, &cal), None);
+    assert_eq!(ex_dividend_date(&second, 2, &cal), None);
+    assert_eq!(is_ex_dividend(&first, &second, 2, &cal), None);
+}
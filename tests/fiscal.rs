@@ -0,0 +1,123 @@
+// Integration tests for fiscal years.
+
+use findates::algebra::adjust_many;
+use findates::calendar::basic_calendar;
+use findates::conventions::{AdjustRule, Frequency};
+use findates::fiscal::{FiscalYear, FiscalYearLabel};
+use findates::DateError;
+
+mod setup;
+use setup::d;
+
+// ── Years ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn fiscal_year_of_test() {
+    for (fy, date, year) in [
+        (FiscalYear::CALENDAR, d(2024, 12, 31), 2024),
+        (FiscalYear::US_FEDERAL, d(2024, 9, 30), 2024),
+        (FiscalYear::US_FEDERAL, d(2024, 10, 1), 2025),
+        (FiscalYear::JAPAN, d(2025, 3, 31), 2024),
+        (FiscalYear::JAPAN, d(2025, 4, 1), 2025),
+        (FiscalYear::UK_TAX, d(2025, 4, 5), 2024),
+        (FiscalYear::UK_TAX, d(2025, 4, 6), 2025),
+        (FiscalYear::AUSTRALIA, d(2024, 7, 1), 2025),
+    ] {
+        assert_eq!(fy.year_of(&date), year, "{fy:?} {date}");
+        assert!(fy.start(year).unwrap() <= date && date <= fy.end(year).unwrap(), "{fy:?} {date}");
+    }
+    // A calendar year named after its end is still the calendar year.
+    let calendar_end = FiscalYear::new(1, 1, FiscalYearLabel::EndYear).unwrap();
+    assert_eq!(calendar_end.year_of(&d(2024, 6, 1)), 2024);
+    assert_eq!(calendar_end.end(2024), Some(d(2024, 12, 31)));
+}
+
+#[test]
+fn fiscal_year_new_test() {
+    let fy = FiscalYear::new(2, 28, FiscalYearLabel::StartYear).unwrap();
+    assert_eq!((fy.start_month(), fy.start_day(), fy.label()), (2, 28, FiscalYearLabel::StartYear));
+    // The year before a leap day ends on 27 February either way.
+    assert_eq!(fy.end(2023), Some(d(2024, 2, 27)));
+    assert_eq!(FiscalYear::new(0, 1, FiscalYearLabel::StartYear), Err(DateError::InvalidMonth));
+    assert_eq!(FiscalYear::new(2, 29, FiscalYearLabel::StartYear), Err(DateError::InvalidDay));
+    assert_eq!(FiscalYear::new(2, 0, FiscalYearLabel::StartYear), Err(DateError::InvalidDay));
+    assert_eq!(FiscalYear::CALENDAR.start(i32::MAX), None);
+}
+
+// ── Quarters ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn fiscal_quarters_test() {
+    let fy = FiscalYear::US_FEDERAL;
+    let quarters: Vec<_> = (1..=4).map(|q| (fy.quarter_start(2025, q).unwrap(), fy.quarter_end(2025, q).unwrap())).collect();
+    assert_eq!(
+        quarters,
+        [
+            (d(2024, 10, 1), d(2024, 12, 31)),
+            (d(2025, 1, 1), d(2025, 3, 31)),
+            (d(2025, 4, 1), d(2025, 6, 30)),
+            (d(2025, 7, 1), d(2025, 9, 30)),
+        ]
+    );
+    for (q, (start, end)) in (1..).zip(&quarters) {
+        assert_eq!(fy.quarter_of(start), q);
+        assert_eq!(fy.quarter_of(end), q);
+    }
+    assert_eq!(fy.quarter_start(2025, 0), None);
+    assert_eq!(fy.quarter_end(2025, 5), None);
+
+    // Quarters from 6 April end on the 5th.
+    let fy = FiscalYear::UK_TAX;
+    assert_eq!(fy.quarter_of(&d(2024, 7, 5)), 1);
+    assert_eq!(fy.quarter_of(&d(2024, 7, 6)), 2);
+    assert_eq!(fy.quarter_of(&d(2025, 4, 5)), 4);
+    assert_eq!(fy.quarter_end(2024, 4), Some(d(2025, 4, 5)));
+}
+
+// ── Business days ────────────────────────────────────────────────────────────
+
+#[test]
+fn ytd_business_days_test() {
+    let mut cal = basic_calendar();
+    cal.add_holidays([d(2024, 4, 1)]);
+    let fy = FiscalYear::JAPAN;
+    // Easter Monday is the first day of the fiscal year.
+    assert_eq!(fy.ytd_business_days(&d(2024, 4, 1), &cal), 0);
+    assert_eq!(fy.ytd_business_days(&d(2024, 4, 2), &cal), 1);
+    assert_eq!(fy.ytd_business_days(&d(2024, 4, 7), &cal), 4);
+    // 52 weeks plus a weekend from Saturday 1 April 2023, and 52 weeks plus
+    // a Monday from 1 April 2024.
+    assert_eq!(fy.ytd_business_days(&d(2024, 3, 31), &basic_calendar()), 260);
+    assert_eq!(fy.ytd_business_days(&d(2025, 3, 31), &basic_calendar()), 261);
+}
+
+// ── Period ends ──────────────────────────────────────────────────────────────
+
+#[test]
+fn period_end_test() {
+    let fy = FiscalYear::AUSTRALIA;
+    let date = d(2024, 8, 15);
+    assert_eq!(fy.period_end(&date, Frequency::Monthly), Some(d(2024, 8, 31)));
+    assert_eq!(fy.period_end(&date, Frequency::Quarterly), Some(d(2024, 9, 30)));
+    assert_eq!(fy.period_end(&date, Frequency::EveryFourthMonth), Some(d(2024, 10, 31)));
+    assert_eq!(fy.period_end(&date, Frequency::Semiannual), Some(d(2024, 12, 31)));
+    assert_eq!(fy.period_end(&date, Frequency::Annual), Some(d(2025, 6, 30)));
+    assert_eq!(fy.period_end(&d(2024, 6, 30), Frequency::Annual), Some(d(2024, 6, 30)));
+    for frequency in [Frequency::EveryNMonths(5), Frequency::EveryNMonths(24), Frequency::Weekly, Frequency::Zero] {
+        assert_eq!(fy.period_end(&date, frequency), None, "{frequency}");
+    }
+}
+
+#[test]
+fn period_ends_test() {
+    let fy = FiscalYear::US_FEDERAL;
+    let ends = fy.period_ends(&d(2024, 3, 31), &d(2025, 3, 31), Frequency::Quarterly).unwrap();
+    assert_eq!(ends, [d(2024, 3, 31), d(2024, 6, 30), d(2024, 9, 30), d(2024, 12, 31), d(2025, 3, 31)]);
+    assert_eq!(fy.period_ends(&d(2024, 4, 1), &d(2024, 6, 29), Frequency::Quarterly), Some(vec![]));
+    assert_eq!(fy.period_ends(&d(2025, 1, 1), &d(2024, 1, 1), Frequency::Monthly), Some(vec![]));
+    assert_eq!(fy.period_ends(&d(2024, 1, 1), &d(2025, 1, 1), Frequency::Daily), None);
+
+    // Aligned to the last business day of each period: 30 June 2024 is a Sunday.
+    let adjusted = adjust_many(&ends, Some(&basic_calendar()), Some(AdjustRule::Preceding));
+    assert_eq!(adjusted, [d(2024, 3, 29), d(2024, 6, 28), d(2024, 9, 30), d(2024, 12, 31), d(2025, 3, 31)]);
+}
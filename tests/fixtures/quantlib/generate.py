@@ -0,0 +1,166 @@
+"""Regenerates the QuantLib golden files read by tests/quantlib_parity.rs.
+
+    pip install QuantLib
+    python tests/fixtures/quantlib/generate.py
+
+Writes day_count.csv, adjust.csv and schedule.csv next to this script.  Cases
+are named with findates strings (day count aliases, AdjustRule and Frequency
+names, calendar codes registered by the test harness), so adding a case here
+is all it takes to cover it in the tests.
+"""
+
+import csv
+import datetime
+import itertools
+import pathlib
+
+import QuantLib as ql
+
+HERE = pathlib.Path(__file__).parent
+
+DAY_COUNTS = {
+    "ACT/360": ql.Actual360(),
+    "ACT/365.FIXED": ql.Actual365Fixed(),
+    "ACT/365.25": ql.Actual36525(),
+    "NL/365": ql.Actual365Fixed(ql.Actual365Fixed.NoLeap),
+    "ACT/ACT.ISDA": ql.ActualActual(ql.ActualActual.ISDA),
+    "ACT/ACT.AFB": ql.ActualActual(ql.ActualActual.AFB),
+    "30E/360": ql.Thirty360(ql.Thirty360.European),
+    "30U/360": ql.Thirty360(ql.Thirty360.USA),
+    "30/360.ISDA": ql.Thirty360(ql.Thirty360.BondBasis),
+}
+
+CALENDARS = {
+    "WeekendsOnly": ql.WeekendsOnly(),
+    "TARGET": ql.TARGET(),
+}
+
+ADJUST_RULES = {
+    "Following": ql.Following,
+    "ModFollowing": ql.ModifiedFollowing,
+    "Preceding": ql.Preceding,
+    "ModPreceding": ql.ModifiedPreceding,
+    "Unadjusted": ql.Unadjusted,
+    "HalfMonthModFollowing": ql.HalfMonthModifiedFollowing,
+    "Nearest": ql.Nearest,
+}
+
+GENERATIONS = {
+    "Forward": ql.DateGeneration.Forward,
+    "Backward": ql.DateGeneration.Backward,
+}
+
+# Month ends, February ends in and out of leap years, and mid-month dates;
+# every ordered pair is a day count case.
+DAY_COUNT_DATES = [
+    "2023-01-31", "2023-02-28", "2023-03-31", "2023-06-15", "2023-08-31",
+    "2023-12-31", "2024-01-01", "2024-02-28", "2024-02-29", "2024-03-31",
+    "2024-06-30", "2024-07-15", "2024-12-31", "2025-02-28", "2025-05-31",
+    "2026-03-01", "2027-08-30",
+]
+
+# Spans around Easter, Christmas and weekend month ends and mid-months.
+ADJUST_SPANS = [
+    ("2024-03-25", "2024-04-07"),
+    ("2024-04-26", "2024-05-06"),
+    ("2024-06-13", "2024-06-17"),
+    ("2024-06-27", "2024-07-01"),
+    ("2024-08-29", "2024-09-02"),
+    ("2024-11-28", "2024-12-02"),
+    ("2024-12-20", "2025-01-06"),
+    ("2025-04-16", "2025-04-22"),
+    ("2025-05-29", "2025-06-02"),
+]
+
+# (effective, termination, frequency, calendar, rule, generation)
+SCHEDULES = [
+    ("2024-01-15", "2026-01-15", "6M", "TARGET", "ModFollowing", "Forward"),
+    ("2024-01-31", "2025-01-31", "1M", "TARGET", "ModFollowing", "Forward"),
+    ("2024-01-31", "2025-01-31", "1M", "WeekendsOnly", "Following", "Forward"),
+    ("2024-03-15", "2025-09-20", "3M", "WeekendsOnly", "Following", "Forward"),
+    ("2024-02-29", "2028-02-29", "12M", "WeekendsOnly", "Unadjusted", "Forward"),
+    ("2024-01-05", "2024-04-05", "1W", "TARGET", "Following", "Forward"),
+    ("2023-12-01", "2024-06-21", "4W", "TARGET", "ModFollowing", "Forward"),
+    ("2024-05-10", "2025-05-10", "2M", "TARGET", "Preceding", "Forward"),
+    ("2024-08-30", "2025-08-30", "1M", "TARGET", "ModPreceding", "Forward"),
+    ("2023-06-30", "2025-06-30", "3M", "TARGET", "HalfMonthModFollowing", "Forward"),
+    ("2024-02-14", "2024-12-14", "1M", "TARGET", "Nearest", "Forward"),
+    ("2024-04-01", "2026-10-01", "4M", "TARGET", "ModFollowing", "Forward"),
+    ("2024-02-10", "2025-06-15", "6M", "TARGET", "ModFollowing", "Backward"),
+    ("2024-01-31", "2025-04-30", "3M", "WeekendsOnly", "Following", "Backward"),
+    ("2024-11-30", "2025-05-30", "1M", "WeekendsOnly", "ModFollowing", "Backward"),
+    ("2023-10-17", "2024-12-20", "3M", "TARGET", "ModFollowing", "Backward"),
+    ("2024-03-01", "2024-06-28", "2W", "TARGET", "Following", "Backward"),
+    ("2024-07-05", "2027-03-31", "6M", "TARGET", "ModFollowing", "Backward"),
+    ("2024-05-20", "2029-05-20", "12M", "TARGET", "Preceding", "Backward"),
+    ("2024-09-03", "2025-03-28", "2M", "WeekendsOnly", "Nearest", "Backward"),
+]
+
+
+def parse(iso):
+    return datetime.date.fromisoformat(iso)
+
+
+def to_ql(date):
+    return ql.Date(date.day, date.month, date.year)
+
+
+def from_ql(date):
+    return datetime.date(date.year(), date.month(), date.dayOfMonth())
+
+
+def day_span(start, end):
+    date = parse(start)
+    while date <= parse(end):
+        yield date
+        date += datetime.timedelta(days=1)
+
+
+def day_count_rows():
+    dates = [parse(iso) for iso in DAY_COUNT_DATES]
+    for code, day_counter in DAY_COUNTS.items():
+        for start, end in itertools.combinations(dates, 2):
+            fraction = day_counter.yearFraction(to_ql(start), to_ql(end))
+            yield [code, start, end, repr(fraction)]
+
+
+def adjust_rows():
+    for name, calendar in CALENDARS.items():
+        for rule, convention in ADJUST_RULES.items():
+            for span in ADJUST_SPANS:
+                for date in day_span(*span):
+                    yield [name, rule, date, from_ql(calendar.adjust(to_ql(date), convention))]
+
+
+def schedule_rows():
+    for effective, termination, frequency, name, rule, generation in SCHEDULES:
+        schedule = ql.Schedule(
+            to_ql(parse(effective)),
+            to_ql(parse(termination)),
+            ql.Period(frequency),
+            CALENDARS[name],
+            ADJUST_RULES[rule],
+            ADJUST_RULES[rule],
+            GENERATIONS[generation],
+            False,
+        )
+        dates = " ".join(str(from_ql(date)) for date in schedule)
+        yield [effective, termination, frequency, name, rule, generation, dates]
+
+
+def write(file_name, header, rows):
+    with open(HERE / file_name, "w", newline="") as file:
+        file.write(f"# Generated by generate.py with QuantLib {ql.__version__}.\n")
+        writer = csv.writer(file, lineterminator="\n")
+        writer.writerow(header)
+        writer.writerows(rows)
+
+
+if __name__ == "__main__":
+    write("day_count.csv", ["convention", "start", "end", "fraction"], day_count_rows())
+    write("adjust.csv", ["calendar", "rule", "date", "adjusted"], adjust_rows())
+    write(
+        "schedule.csv",
+        ["effective", "termination", "frequency", "calendar", "rule", "generation", "dates"],
+        schedule_rows(),
+    )
@@ -0,0 +1,113 @@
+// Integration tests for futures and listed option expiry dates.
+
+use chrono::{Datelike, Weekday};
+use findates::algebra::{futures_expiry, option_expiries};
+use findates::calendar::basic_calendar;
+use findates::conventions::{ExpiryRule, ExpiryStyle};
+use findates::error::{DateError, FuturesExpiryError};
+
+mod setup;
+use setup::{calendar_with_holidays, d};
+
+// ── Contract codes ───────────────────────────────────────────────────────────
+
+#[test]
+fn futures_code_parsing_test() {
+    let cal = basic_calendar();
+    let expiry = |code| futures_expiry(code, ExpiryRule::FF, &cal);
+    assert_eq!(expiry("FFF25"), Ok(d(2025, 1, 31)));
+    assert_eq!(expiry("ffz2024"), Ok(d(2024, 12, 31)));
+    assert_eq!(expiry(" Q24 "), Ok(d(2024, 8, 30)));
+    // Roots may contain digits.
+    assert_eq!(expiry("6EM24"), Ok(d(2024, 6, 28)));
+
+    for code in ["FFZ4", "FFZ024", "ES24", "FFA24", "FFZ", "", "Z24X"] {
+        assert_eq!(expiry(code), Err(FuturesExpiryError::InvalidCode(code.to_string())), "{code:?}");
+    }
+}
+
+// ── Rules ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn third_friday_rolls_back_over_holiday_test() {
+    // Good Friday 2008 was the third Friday of March: ESH08 expired Thursday.
+    let cal = calendar_with_holidays([d(2008, 3, 21)]);
+    assert_eq!(futures_expiry("ESH08", ExpiryRule::ES, &cal), Ok(d(2008, 3, 20)));
+    assert_eq!(futures_expiry("ESM08", ExpiryRule::ES, &cal), Ok(d(2008, 6, 20)));
+}
+
+#[test]
+fn sofr_futures_test() {
+    let cal = calendar_with_holidays([d(2024, 6, 19), d(2025, 1, 1)]);
+    assert_eq!(futures_expiry("SR3H24", ExpiryRule::SR3, &cal), Ok(d(2024, 6, 18)));
+    // The December contract's reference quarter ends in March of the next year.
+    assert_eq!(futures_expiry("SR3Z24", ExpiryRule::SR3, &cal), Ok(d(2025, 3, 18)));
+    assert_eq!(futures_expiry("SR1Z24", ExpiryRule::SR1, &cal), Ok(d(2024, 12, 31)));
+    // 31 May 2025 is a Saturday.
+    assert_eq!(futures_expiry("SR1K25", ExpiryRule::SR1, &cal), Ok(d(2025, 5, 30)));
+}
+
+#[test]
+fn business_days_before_nth_weekday_test() {
+    let cal = calendar_with_holidays([d(2024, 3, 18)]);
+    // Two business days before Wednesday 20 March skips the Monday holiday.
+    assert_eq!(futures_expiry("IH24", ExpiryRule::EURIBOR, &cal), Ok(d(2024, 3, 15)));
+
+    let on_or_before = ExpiryRule::BusinessDaysBeforeNthWeekday { days: 0, n: 3, weekday: Weekday::Mon, months_after: 0 };
+    assert_eq!(futures_expiry("H24", on_or_before, &cal), Ok(d(2024, 3, 15)));
+    let next_month = ExpiryRule::BusinessDaysBeforeNthWeekday { days: 0, n: 1, weekday: Weekday::Mon, months_after: 13 };
+    assert_eq!(futures_expiry("Z24", next_month, &cal), Ok(d(2026, 1, 5)));
+}
+
+#[test]
+fn missing_weekday_test() {
+    let cal = basic_calendar();
+    let fifth_friday = ExpiryRule::NthWeekday { n: 5, weekday: Weekday::Fri };
+    assert_eq!(futures_expiry("H24", fifth_friday, &cal), Ok(d(2024, 3, 29)));
+    assert_eq!(futures_expiry("J24", fifth_friday, &cal), Err(FuturesExpiryError::Date(DateError::InvalidOrdinal)));
+    let zeroth = ExpiryRule::NthWeekday { n: 0, weekday: Weekday::Fri };
+    assert!(futures_expiry("J24", zeroth, &cal).is_err());
+}
+
+// ── Option expiries ──────────────────────────────────────────────────────────
+
+#[test]
+fn monthly_option_expiry_saturday_switch_test() {
+    let cal = basic_calendar();
+    let monthly = |y, m| option_expiries(y, m, ExpiryStyle::Monthly, &cal).unwrap();
+    // January 2015 was the last Saturday expiry, February 2015 the first Friday.
+    assert_eq!(monthly(2015, 1), [d(2015, 1, 17)]);
+    assert_eq!(monthly(2015, 2), [d(2015, 2, 20)]);
+    assert_eq!(monthly(2014, 3), [d(2014, 3, 22)]);
+    assert_eq!(monthly(2024, 6), [d(2024, 6, 21)]);
+    for (y, m) in [(2010, 5), (2014, 12), (2015, 1)] {
+        assert_eq!(monthly(y, m)[0].weekday(), Weekday::Sat);
+    }
+}
+
+#[test]
+fn weekly_option_expiries_test() {
+    // Juneteenth falls on the third Wednesday of June 2024.
+    let cal = calendar_with_holidays([d(2024, 6, 19)]);
+    assert_eq!(
+        option_expiries(2024, 6, ExpiryStyle::Weekly(Weekday::Wed), &cal),
+        Ok(vec![d(2024, 6, 5), d(2024, 6, 12), d(2024, 6, 18), d(2024, 6, 26)])
+    );
+    // Saturdays are not business days: each series rolls back to Friday.
+    assert_eq!(
+        option_expiries(2024, 6, ExpiryStyle::Weekly(Weekday::Sat), &cal),
+        Ok(vec![d(2024, 5, 31), d(2024, 6, 7), d(2024, 6, 14), d(2024, 6, 21), d(2024, 6, 28)])
+    );
+    // Every Friday expiry of a month includes the monthly one.
+    let fridays = option_expiries(2024, 6, ExpiryStyle::Weekly(Weekday::Fri), &cal).unwrap();
+    assert!(fridays.contains(&option_expiries(2024, 6, ExpiryStyle::Monthly, &cal).unwrap()[0]));
+}
+
+#[test]
+fn end_of_month_option_expiry_test() {
+    let cal = calendar_with_holidays([d(2024, 12, 31)]);
+    assert_eq!(option_expiries(2024, 12, ExpiryStyle::EndOfMonth, &cal), Ok(vec![d(2024, 12, 30)]));
+    assert_eq!(option_expiries(2024, 8, ExpiryStyle::EndOfMonth, &cal), Ok(vec![d(2024, 8, 30)]));
+    assert_eq!(option_expiries(2024, 13, ExpiryStyle::EndOfMonth, &cal), Err(DateError::InvalidMonth));
+    assert_eq!(option_expiries(2024, 0, ExpiryStyle::Monthly, &cal), Err(DateError::InvalidMonth));
+}
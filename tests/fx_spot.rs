@@ -0,0 +1,212 @@
+// Integration tests for FX spot date calculation.
+// Spot is T+2 (T+1 for USDCAD) counting days that are good in both non-USD
+// currencies, and must itself be a good day in both currencies and in USD.
+
+use chrono::{NaiveDate, Weekday};
+use findates::algebra::{
+    fx_forward_dates, fx_spot_date, fx_spot_date_with_lag, is_good_settlement_date, next_good_settlement_date,
+};
+use findates::calendar::{basic_calendar, Calendar};
+use findates::conventions::{ParseTenorError, Tenor};
+use findates::ForwardDateError;
+
+mod setup;
+use setup::{calendar_with_holidays, d};
+
+// ── USD pairs ────────────────────────────────────────────────────────────────
+
+#[test]
+fn fx_spot_plain_t_plus_2_test() {
+    let eur = basic_calendar();
+    let usd = basic_calendar();
+    assert_eq!(fx_spot_date(&d(2024, 3, 13), Some(&eur), None, Some(&usd)), Some(d(2024, 3, 15)));
+    // Thursday and Friday trades cross the weekend
+    assert_eq!(fx_spot_date(&d(2024, 3, 14), Some(&eur), None, Some(&usd)), Some(d(2024, 3, 18)));
+    assert_eq!(fx_spot_date(&d(2024, 3, 15), Some(&eur), None, Some(&usd)), Some(d(2024, 3, 19)));
+}
+
+#[test]
+fn fx_spot_usd_holiday_on_intermediate_day_test() {
+    let eur = basic_calendar();
+    let usd = calendar_with_holidays([d(2024, 7, 4)]);
+    assert_eq!(fx_spot_date(&d(2024, 7, 3), Some(&eur), None, Some(&usd)), Some(d(2024, 7, 5)));
+}
+
+#[test]
+fn fx_spot_usd_holiday_on_spot_date_test() {
+    let eur = basic_calendar();
+    let usd = calendar_with_holidays([d(2024, 7, 4)]);
+    assert_eq!(fx_spot_date(&d(2024, 7, 2), Some(&eur), None, Some(&usd)), Some(d(2024, 7, 5)));
+}
+
+#[test]
+fn fx_spot_non_usd_holiday_on_intermediate_day_test() {
+    // 25 and 26 December are EUR holidays: T+1 is the 27th, T+2 the 30th
+    let eur = calendar_with_holidays([d(2024, 12, 25), d(2024, 12, 26)]);
+    let usd = calendar_with_holidays([d(2024, 12, 25)]);
+    assert_eq!(fx_spot_date(&d(2024, 12, 24), Some(&eur), None, Some(&usd)), Some(d(2024, 12, 30)));
+}
+
+// ── Crosses ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn fx_spot_cross_needs_usd_good_day_test() {
+    let eur = basic_calendar();
+    let jpy = basic_calendar();
+    let usd = calendar_with_holidays([d(2024, 7, 4)]);
+    assert_eq!(fx_spot_date(&d(2024, 7, 2), Some(&eur), Some(&jpy), Some(&usd)), Some(d(2024, 7, 5)));
+    // Without a USD calendar the holiday is ignored
+    assert_eq!(fx_spot_date(&d(2024, 7, 2), Some(&eur), Some(&jpy), None), Some(d(2024, 7, 4)));
+}
+
+#[test]
+fn fx_spot_cross_holiday_in_either_currency_test() {
+    let eur = basic_calendar();
+    let jpy = calendar_with_holidays([d(2024, 5, 3), d(2024, 5, 6)]);
+    let usd = basic_calendar();
+    // Thursday 2 May: Friday and Monday are JPY holidays
+    assert_eq!(fx_spot_date(&d(2024, 5, 2), Some(&eur), Some(&jpy), Some(&usd)), Some(d(2024, 5, 8)));
+}
+
+// ── T+1 pairs ────────────────────────────────────────────────────────────────
+
+#[test]
+fn fx_spot_usdcad_t_plus_1_test() {
+    let cad = calendar_with_holidays([d(2024, 7, 1)]);
+    let usd = calendar_with_holidays([d(2024, 7, 4)]);
+    assert_eq!(fx_spot_date_with_lag(&d(2024, 3, 13), 1, None, Some(&cad), Some(&usd)), Some(d(2024, 3, 14)));
+    assert_eq!(fx_spot_date_with_lag(&d(2024, 6, 28), 1, None, Some(&cad), Some(&usd)), Some(d(2024, 7, 2)));
+    // T+1 on a USD holiday rolls forward
+    assert_eq!(fx_spot_date_with_lag(&d(2024, 7, 3), 1, None, Some(&cad), Some(&usd)), Some(d(2024, 7, 5)));
+}
+
+#[test]
+fn fx_spot_out_of_range_test() {
+    let usd = basic_calendar();
+    assert_eq!(fx_spot_date(&NaiveDate::MAX, None, None, Some(&usd)), None);
+}
+
+#[test]
+fn fx_spot_closed_calendars_test() {
+    // Between them the calendars never share an open day, so the search is
+    // abandoned at once rather than run to the end of the date range.
+    let sun_to_wed = Calendar::with_weekends([Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed]);
+    let thu_to_sat = Calendar::with_weekends([Weekday::Thu, Weekday::Fri, Weekday::Sat]);
+    let trade = d(2024, 5, 1);
+    assert_eq!(fx_spot_date(&trade, Some(&sun_to_wed), Some(&thu_to_sat), None), None);
+    assert_eq!(fx_spot_date(&trade, Some(&sun_to_wed), None, Some(&thu_to_sat)), None);
+    assert_eq!(fx_spot_date_with_lag(&trade, 0, None, Some(&sun_to_wed), Some(&thu_to_sat)), None);
+}
+
+// ── Good settlement dates ────────────────────────────────────────────────────
+
+#[test]
+fn good_settlement_date_test() {
+    let eur = calendar_with_holidays([d(2024, 5, 1)]);
+    let usd = calendar_with_holidays([d(2024, 5, 27)]);
+    let jpy = calendar_with_holidays([d(2024, 5, 3), d(2024, 5, 6)]);
+    let all = [&eur, &usd, &jpy];
+
+    assert!(is_good_settlement_date(&d(2024, 5, 2), &all));
+    assert!(!is_good_settlement_date(&d(2024, 5, 1), &all));
+    assert!(is_good_settlement_date(&d(2024, 5, 1), &[&usd, &jpy]));
+    assert!(is_good_settlement_date(&d(2024, 5, 4), &[]));
+
+    // Friday 3 May and Monday 6 May are JPY holidays.
+    assert_eq!(next_good_settlement_date(&d(2024, 5, 3), &all), Some(d(2024, 5, 7)));
+    assert_eq!(next_good_settlement_date(&d(2024, 5, 25), &all), Some(d(2024, 5, 28)));
+    assert_eq!(next_good_settlement_date(&d(2024, 5, 25), &[]), Some(d(2024, 5, 25)));
+
+    // Every result is good in all calendars, and no earlier date is.
+    let mut date = d(2024, 4, 25);
+    while date < d(2024, 6, 5) {
+        let next = next_good_settlement_date(&date, &all).unwrap();
+        assert!(is_good_settlement_date(&next, &all));
+        assert!(date.iter_days().take_while(|day| *day < next).all(|day| !is_good_settlement_date(&day, &all)));
+        date = date.succ_opt().unwrap();
+    }
+}
+
+#[test]
+fn good_settlement_date_never_found_test() {
+    let sun_to_wed = Calendar::with_weekends([Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed]);
+    let thu_to_sat = Calendar::with_weekends([Weekday::Thu, Weekday::Fri, Weekday::Sat]);
+    assert_eq!(next_good_settlement_date(&d(2024, 5, 1), &[&sun_to_wed, &thu_to_sat]), None);
+    let last_day_closed = calendar_with_holidays([NaiveDate::MAX]);
+    assert_eq!(next_good_settlement_date(&NaiveDate::MAX, &[&last_day_closed]), None);
+}
+
+// ── Forward ladders ──────────────────────────────────────────────────────────
+
+#[test]
+fn fx_forward_ladder_test() {
+    let eur = basic_calendar();
+    let usd = calendar_with_holidays([d(2024, 2, 19)]); // Presidents' Day
+    let spot = fx_spot_date(&d(2024, 1, 15), Some(&eur), None, Some(&usd)).unwrap();
+    let tenors = ["1W", "1M", "2M", "3M", "6M", "1Y"];
+    let ladder = fx_forward_dates(&spot, &tenors, &[&eur, &usd]).unwrap();
+    let labels: Vec<String> = ladder.iter().map(|rung| rung.tenor.to_string()).collect();
+    assert_eq!(labels, tenors);
+    let dates: Vec<NaiveDate> = ladder.iter().map(|rung| rung.value_date).collect();
+    // 1M lands on the USD holiday and 2M on a Sunday.
+    assert_eq!(
+        dates,
+        [d(2024, 1, 24), d(2024, 2, 20), d(2024, 3, 18), d(2024, 4, 17), d(2024, 7, 17), d(2025, 1, 17)]
+    );
+}
+
+#[test]
+fn fx_forward_end_end_test() {
+    // 30 April is a holiday in one currency only, which makes Monday 29 April
+    // the last good day of the month for the pair.
+    let eur = basic_calendar();
+    let jpy = calendar_with_holidays([d(2024, 4, 30)]);
+    let ladder = fx_forward_dates(&d(2024, 4, 29), &["1M", "2M"], &[&eur, &jpy]).unwrap();
+    assert_eq!(ladder[0].value_date, d(2024, 5, 31));
+    assert_eq!(ladder[1].value_date, d(2024, 6, 28));
+    let ladder = fx_forward_dates(&d(2024, 4, 29), &["1M"], &[&eur]).unwrap();
+    assert_eq!(ladder[0].value_date, d(2024, 5, 29));
+}
+
+#[test]
+fn fx_forward_short_tenors_test() {
+    let eur = basic_calendar();
+    let usd = calendar_with_holidays([d(2024, 7, 4)]);
+    let ladder = fx_forward_dates(&d(2024, 7, 3), &["SN", "1D", "1W"], &[&eur, &usd]).unwrap();
+    let rungs: Vec<(Tenor, NaiveDate)> = ladder.iter().map(|rung| (rung.tenor, rung.value_date)).collect();
+    assert_eq!(
+        rungs,
+        [(Tenor::SpotNext, d(2024, 7, 5)), (Tenor::Days(1), d(2024, 7, 5)), (Tenor::Weeks(1), d(2024, 7, 10))]
+    );
+    assert_eq!(fx_forward_dates(&d(2024, 7, 3), &[], &[&eur, &usd]), Ok(vec![]));
+}
+
+#[test]
+fn fx_forward_invalid_tenor_test() {
+    let eur = basic_calendar();
+    for tenor in ["ON", "TN", "3X", ""] {
+        assert_eq!(
+            fx_forward_dates(&d(2024, 7, 3), &["1M", tenor], &[&eur]),
+            Err(ForwardDateError::InvalidTenor(ParseTenorError)),
+            "{tenor}"
+        );
+    }
+}
+
+#[test]
+fn fx_forward_closed_calendars_test() {
+    let sun_to_wed = Calendar::with_weekends([Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed]);
+    let thu_to_sat = Calendar::with_weekends([Weekday::Thu, Weekday::Fri, Weekday::Sat]);
+    assert_eq!(
+        fx_forward_dates(&d(2024, 5, 1), &["1M"], &[&sun_to_wed, &thu_to_sat]),
+        Err(ForwardDateError::NoBusinessDays)
+    );
+}
+
+#[test]
+fn fx_forward_out_of_range_test() {
+    let eur = basic_calendar();
+    let last = NaiveDate::MAX.iter_days().rev().find(|day| eur.is_business_day(day)).unwrap();
+    assert_eq!(fx_forward_dates(&last, &["1W"], &[&eur]), Err(ForwardDateError::OutOfRange));
+    assert_eq!(fx_forward_dates(&last, &[], &[&eur]), Ok(vec![]));
+}
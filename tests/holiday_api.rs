@@ -0,0 +1,158 @@
+// Integration tests for loading calendars from public holiday APIs, run
+// offline against recorded responses in tests/fixtures.
+// Run with `cargo test --features http`.
+#![cfg(feature = "http")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use chrono::NaiveDate;
+use findates::calendar::Calendar;
+use findates::holiday_api::{HolidayProvider, NagerDate, OpenHolidays, PublicHoliday};
+use findates::HolidayApiError;
+
+mod setup;
+use setup::d;
+
+const NAGER_US_2024: &str = include_str!("fixtures/nager_us_2024.json");
+const OPENHOLIDAYS_DE_2024: &str = include_str!("fixtures/openholidays_de_2024.json");
+
+// Serves recorded responses by (country, year), like a provider backed by a
+// local cache.
+struct Recorded;
+
+impl HolidayProvider for Recorded {
+    fn public_holidays(&self, country_code: &str, year: i32) -> Result<Vec<PublicHoliday>, HolidayApiError> {
+        match (country_code, year) {
+            ("US", 2024) => NagerDate::parse(NAGER_US_2024),
+            ("DE", 2024) => OpenHolidays::parse(OPENHOLIDAYS_DE_2024),
+            _ => Err(HolidayApiError::Request(format!("no recording for {country_code} {year}"))),
+        }
+    }
+}
+
+// Serves `body` once from a local HTTP server and returns its base URL and a
+// handle yielding the request line.
+fn serve_once(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut header = String::new();
+        while reader.read_line(&mut header).unwrap() > 2 {
+            header.clear();
+        }
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        request_line.trim_end().to_string()
+    });
+    (base_url, handle)
+}
+
+// ── Response parsing ─────────────────────────────────────────────────────────
+
+#[test]
+fn nager_date_parse_test() {
+    let holidays = NagerDate::parse(NAGER_US_2024).unwrap();
+    // Lincoln's Birthday, Good Friday and Columbus Day are not nationwide.
+    let dates: Vec<NaiveDate> = holidays.iter().map(|h| h.date).collect();
+    assert_eq!(
+        dates,
+        [
+            d(2024, 1, 1), d(2024, 1, 15), d(2024, 2, 19), d(2024, 5, 27), d(2024, 6, 19), d(2024, 7, 4),
+            d(2024, 9, 2), d(2024, 11, 11), d(2024, 11, 28), d(2024, 12, 25),
+        ]
+    );
+    assert_eq!(holidays[2], PublicHoliday::new(d(2024, 2, 19), "Washington's Birthday"));
+}
+
+#[test]
+fn open_holidays_parse_test() {
+    let holidays = OpenHolidays::parse(OPENHOLIDAYS_DE_2024).unwrap();
+    // Epiphany is regional; Christmas spans two days.
+    assert_eq!(holidays.len(), 9);
+    assert!(holidays.iter().all(|h| h.date != d(2024, 1, 6)));
+    assert_eq!(holidays[0], PublicHoliday::new(d(2024, 1, 1), "New Year's Day"));
+    assert_eq!(holidays[7], PublicHoliday::new(d(2024, 12, 25), "Christmas Day"));
+    assert_eq!(holidays[8], PublicHoliday::new(d(2024, 12, 26), "Christmas Day"));
+
+    // Names fall back to the first language given.
+    let body = r#"[{"startDate":"2024-10-03","endDate":"2024-10-03","type":"Public",
+        "name":[{"language":"DE","text":"Tag der Deutschen Einheit"}],"nationwide":true}]"#;
+    assert_eq!(OpenHolidays::parse(body).unwrap()[0].name, "Tag der Deutschen Einheit");
+}
+
+#[test]
+fn parse_errors_test() {
+    assert!(matches!(NagerDate::parse("{}"), Err(HolidayApiError::Json(_))));
+    assert!(matches!(OpenHolidays::parse("not json"), Err(HolidayApiError::Json(_))));
+    let bad_date = r#"[{"date":"2024-13-01","name":"Nope","global":true,"types":["Public"]}]"#;
+    let err = NagerDate::parse(bad_date).unwrap_err();
+    assert!(matches!(err, HolidayApiError::InvalidDate(ref date) if date == "2024-13-01"));
+    assert_eq!(err.to_string(), "invalid holiday date \"2024-13-01\"");
+}
+
+// ── Calendars from providers ─────────────────────────────────────────────────
+
+#[test]
+fn calendar_from_provider_test() {
+    let us = Calendar::from_holiday_provider(&Recorded, "US", 2024..=2024).unwrap();
+    assert_eq!(us.get_holidays().len(), 10);
+    assert!(!us.is_business_day(&d(2024, 7, 4)));
+    assert!(us.is_business_day(&d(2024, 10, 14)));
+    assert!(!us.is_business_day(&d(2024, 7, 6))); // Saturday
+    assert_eq!(us.holiday_name(&d(2024, 11, 28)), Some("Thanksgiving Day"));
+
+    let de = Calendar::from_holiday_provider(&Recorded, "DE", 2024..=2024).unwrap();
+    assert!(!de.is_business_day(&d(2024, 12, 26)));
+    assert_eq!(de.holiday_name(&d(2024, 10, 3)), Some("German Unity Day"));
+}
+
+#[test]
+fn calendar_from_provider_error_test() {
+    let err = Calendar::from_holiday_provider(&Recorded, "US", 2024..=2025).unwrap_err();
+    assert_eq!(err.to_string(), "holiday API request failed: no recording for US 2025");
+}
+
+// ── HTTP requests ────────────────────────────────────────────────────────────
+
+#[test]
+fn nager_date_request_test() {
+    let (base_url, server) = serve_once("200 OK", NAGER_US_2024);
+    let holidays = NagerDate::with_base_url(base_url).public_holidays("US", 2024).unwrap();
+    assert_eq!(holidays.len(), 10);
+    assert_eq!(server.join().unwrap(), "GET /api/v3/PublicHolidays/2024/US HTTP/1.1");
+}
+
+#[test]
+fn open_holidays_request_test() {
+    let (base_url, server) = serve_once("200 OK", OPENHOLIDAYS_DE_2024);
+    let holidays = OpenHolidays::with_base_url(base_url).public_holidays("DE", 2024).unwrap();
+    assert_eq!(holidays.len(), 9);
+    assert_eq!(
+        server.join().unwrap(),
+        "GET /PublicHolidays?countryIsoCode=DE&languageIsoCode=EN&validFrom=2024-01-01&validTo=2024-12-31 HTTP/1.1"
+    );
+}
+
+#[test]
+fn request_error_status_test() {
+    let (base_url, server) = serve_once("404 Not Found", "");
+    let err = NagerDate::with_base_url(base_url).public_holidays("XX", 2024).unwrap_err();
+    assert!(matches!(err, HolidayApiError::Request(_)));
+    server.join().unwrap();
+}
+
+#[test]
+fn provider_urls_test() {
+    assert_eq!(NagerDate::with_base_url("http://localhost:8080/").base_url(), "http://localhost:8080");
+    assert_eq!(NagerDate::default().base_url(), "https://date.nager.at");
+    assert_eq!(OpenHolidays::default().base_url(), "https://openholidaysapi.org");
+}
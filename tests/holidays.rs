@@ -0,0 +1,138 @@
+// Integration tests for rule-based holidays and weekend observance.
+
+use chrono::Weekday;
+use findates::calendar::{basic_calendar, Calendar};
+use findates::holidays::{HolidayDate, HolidayRule, ObservanceRule};
+
+mod setup;
+use setup::d;
+
+// ── Observance rules ─────────────────────────────────────────────────────────
+
+#[test]
+fn observance_on_weekdays_test() {
+    let cal = basic_calendar();
+    let wednesday = d(2024, 12, 25);
+    for rule in [
+        ObservanceRule::Actual,
+        ObservanceRule::NearestWeekday,
+        ObservanceRule::NextWeekday,
+        ObservanceRule::NextFreeWeekday,
+        ObservanceRule::SundayToNextFreeDay,
+    ] {
+        assert_eq!(rule.observe(wednesday, &cal), Some(wednesday), "{rule:?}");
+    }
+}
+
+#[test]
+fn observance_on_weekends_test() {
+    let cal = basic_calendar();
+    let (saturday, sunday) = (d(2026, 7, 4), d(2026, 7, 5));
+    assert_eq!(ObservanceRule::NearestWeekday.observe(saturday, &cal), Some(d(2026, 7, 3)));
+    assert_eq!(ObservanceRule::NearestWeekday.observe(sunday, &cal), Some(d(2026, 7, 6)));
+    assert_eq!(ObservanceRule::NextWeekday.observe(saturday, &cal), Some(d(2026, 7, 6)));
+    assert_eq!(ObservanceRule::SundayToNextFreeDay.observe(saturday, &cal), Some(saturday));
+    assert_eq!(ObservanceRule::SundayToNextFreeDay.observe(sunday, &cal), Some(d(2026, 7, 6)));
+
+    // With a Friday/Saturday weekend, Friday moves back to Thursday.
+    let gulf = Calendar::middle_east_weekend();
+    assert_eq!(ObservanceRule::NearestWeekday.observe(d(2026, 7, 3), &gulf), Some(d(2026, 7, 2)));
+    assert_eq!(ObservanceRule::NearestWeekday.observe(saturday, &gulf), Some(sunday));
+}
+
+#[test]
+fn observance_skips_existing_holidays_test() {
+    let mut cal = basic_calendar();
+    cal.add_holidays([d(2026, 7, 6)]);
+    assert_eq!(ObservanceRule::NextWeekday.observe(d(2026, 7, 5), &cal), Some(d(2026, 7, 6)));
+    assert_eq!(ObservanceRule::NextFreeWeekday.observe(d(2026, 7, 5), &cal), Some(d(2026, 7, 7)));
+    assert_eq!(ObservanceRule::SundayToNextFreeDay.observe(d(2026, 7, 5), &cal), Some(d(2026, 7, 7)));
+}
+
+#[test]
+fn observance_without_business_days_test() {
+    let cal = Calendar::with_weekends([
+        Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun,
+    ]);
+    assert_eq!(ObservanceRule::NextWeekday.observe(d(2026, 7, 5), &cal), None);
+    assert_eq!(ObservanceRule::NearestWeekday.observe(d(2026, 7, 5), &cal), None);
+}
+
+// ── Holiday rules ────────────────────────────────────────────────────────────
+
+#[test]
+fn holiday_dates_test() {
+    assert_eq!(HolidayDate::Fixed { month: 7, day: 4 }.in_year(2024), Some(d(2024, 7, 4)));
+    assert_eq!(HolidayDate::Fixed { month: 13, day: 1 }.in_year(2024), None);
+    assert_eq!(HolidayDate::NthWeekday { month: 1, weekday: Weekday::Mon, n: 3 }.in_year(2024), Some(d(2024, 1, 15)));
+    assert_eq!(HolidayDate::NthWeekday { month: 2, weekday: Weekday::Mon, n: 5 }.in_year(2024), None);
+    assert_eq!(HolidayDate::LastWeekday { month: 5, weekday: Weekday::Mon }.in_year(2024), Some(d(2024, 5, 27)));
+    assert_eq!(HolidayDate::EasterOffset(0).in_year(2024), Some(d(2024, 3, 31)));
+    assert_eq!(HolidayDate::EasterOffset(39).in_year(2024), Some(d(2024, 5, 9)));
+}
+
+#[test]
+fn us_federal_rules_test() {
+    let observed = ObservanceRule::NearestWeekday;
+    let rules = [
+        HolidayRule::fixed("New Year's Day", 1, 1).observed(observed),
+        HolidayRule::nth_weekday("Martin Luther King Jr. Day", 1, Weekday::Mon, 3),
+        HolidayRule::nth_weekday("Washington's Birthday", 2, Weekday::Mon, 3),
+        HolidayRule::last_weekday("Memorial Day", 5, Weekday::Mon),
+        HolidayRule::fixed("Juneteenth National Independence Day", 6, 19).observed(observed),
+        HolidayRule::fixed("Independence Day", 7, 4).observed(observed),
+        HolidayRule::nth_weekday("Labor Day", 9, Weekday::Mon, 1),
+        HolidayRule::nth_weekday("Columbus Day", 10, Weekday::Mon, 2),
+        HolidayRule::fixed("Veterans Day", 11, 11).observed(observed),
+        HolidayRule::nth_weekday("Thanksgiving Day", 11, Weekday::Thu, 4),
+        HolidayRule::fixed("Christmas Day", 12, 25).observed(observed),
+    ];
+    let mut cal = basic_calendar();
+    cal.add_holiday_rules(&rules, 2021..=2022);
+
+    // OPM federal holidays for 2021; New Year's Day 2022 is observed on 31 December 2021.
+    let expected = [
+        d(2021, 1, 1), d(2021, 1, 18), d(2021, 2, 15), d(2021, 5, 31), d(2021, 6, 18), d(2021, 7, 5),
+        d(2021, 9, 6), d(2021, 10, 11), d(2021, 11, 11), d(2021, 11, 25), d(2021, 12, 24), d(2021, 12, 31),
+    ];
+    assert_eq!(cal.holidays_in_year(2021), expected);
+    assert_eq!(cal.holiday_name(&d(2021, 12, 31)), Some("New Year's Day"));
+    assert_eq!(cal.holiday_name(&d(2022, 12, 26)), Some("Christmas Day"));
+}
+
+#[test]
+fn uk_rules_match_built_in_calendar_test() {
+    let substitute = ObservanceRule::NextFreeWeekday;
+    let rules = [
+        HolidayRule::fixed("New Year's Day", 1, 1).observed(substitute),
+        HolidayRule::easter_offset("Good Friday", -2),
+        HolidayRule::easter_offset("Easter Monday", 1),
+        HolidayRule::nth_weekday("Early May bank holiday", 5, Weekday::Mon, 1),
+        HolidayRule::last_weekday("Spring bank holiday", 5, Weekday::Mon),
+        HolidayRule::last_weekday("Summer bank holiday", 8, Weekday::Mon),
+        HolidayRule::fixed("Christmas Day", 12, 25).observed(substitute),
+        HolidayRule::fixed("Boxing Day", 12, 26).observed(substitute),
+    ];
+    // 2013 to 2019 have no proclaimed holidays.
+    let mut cal = basic_calendar();
+    cal.add_holiday_rules(&rules, 2013..=2019);
+    let built_in = Calendar::uk_bank_holidays(2013..=2019);
+    for year in 2013..=2019 {
+        assert_eq!(cal.holidays_in_year(year), built_in.holidays_in_year(year), "{year}");
+    }
+}
+
+#[test]
+fn japanese_substitute_rule_test() {
+    // Golden Week 2015: Constitution Memorial Day on Sunday 3 May, with
+    // 4 and 5 May already holidays.
+    let rules = [
+        HolidayRule::fixed("Constitution Memorial Day", 5, 3).observed(ObservanceRule::SundayToNextFreeDay),
+        HolidayRule::fixed("Greenery Day", 5, 4).observed(ObservanceRule::SundayToNextFreeDay),
+        HolidayRule::fixed("Children's Day", 5, 5).observed(ObservanceRule::SundayToNextFreeDay),
+    ];
+    let mut cal = basic_calendar();
+    cal.add_holiday_rules(&rules, 2015..=2015);
+    assert_eq!(cal.holidays_in_year(2015), [d(2015, 5, 4), d(2015, 5, 5), d(2015, 5, 6)]);
+    assert_eq!(cal.holiday_name(&d(2015, 5, 6)), Some("Constitution Memorial Day"));
+}
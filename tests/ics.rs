@@ -0,0 +1,143 @@
+// Integration tests for iCalendar import and export.
+// Run with `cargo test --features ics`.
+#![cfg(feature = "ics")]
+
+use findates::calendar::Calendar;
+use findates::IcsError;
+
+mod setup;
+use setup::d;
+
+fn wrap(events: &str) -> String {
+    format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{events}END:VCALENDAR\r\n")
+}
+
+// ── Import ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn single_and_multi_day_events_test() {
+    let ics = wrap(
+        "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\nSUMMARY:New Year\r\nEND:VEVENT\r\n\
+         BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20241225\r\nDTEND;VALUE=DATE:20241227\r\nEND:VEVENT\r\n",
+    );
+    let cal = Calendar::from_ics(&ics).unwrap();
+    let mut expected = Calendar::with_holidays([d(2024, 12, 25), d(2024, 12, 26)]);
+    expected.add_named_holiday(d(2024, 1, 1), "New Year");
+    assert_eq!(cal, expected);
+}
+
+#[test]
+fn datetime_values_and_folded_lines_test() {
+    let ics = wrap("BEGIN:VEVENT\r\nDTSTART:20240704T000000Z\r\nSUMMARY:Independence\r\n  Day\r\nEND:VEVENT\r\n");
+    let cal = Calendar::from_ics(&ics).unwrap();
+    assert_eq!(cal.get_holidays().len(), 1);
+    assert_eq!(cal.holiday_name(&d(2024, 7, 4)), Some("Independence Day"));
+}
+
+#[test]
+fn yearly_rule_with_until_and_exdate_test() {
+    let ics = wrap(
+        "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20200101\r\n\
+         RRULE:FREQ=YEARLY;UNTIL=20231231\r\nEXDATE;VALUE=DATE:20210101\r\nEND:VEVENT\r\n",
+    );
+    let cal = Calendar::from_ics(&ics).unwrap();
+    assert_eq!(cal, Calendar::with_holidays([d(2020, 1, 1), d(2022, 1, 1), d(2023, 1, 1)]));
+}
+
+#[test]
+fn yearly_rule_by_day_test() {
+    // US Thanksgiving (4th Thursday of November) and Memorial Day (last Monday of May).
+    let ics = wrap(
+        "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20231123\r\nRRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=3\r\nEND:VEVENT\r\n\
+         BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20230529\r\nRRULE:FREQ=YEARLY;BYMONTH=5;BYDAY=-1MO;COUNT=2\r\nEND:VEVENT\r\n",
+    );
+    let cal = Calendar::from_ics(&ics).unwrap();
+    let expected = Calendar::with_holidays([
+        d(2023, 11, 23),
+        d(2024, 11, 28),
+        d(2025, 11, 27),
+        d(2023, 5, 29),
+        d(2024, 5, 27),
+    ]);
+    assert_eq!(cal, expected);
+}
+
+#[test]
+fn yearly_rule_without_bound_uses_horizon_test() {
+    let ics = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240229\r\nRRULE:FREQ=YEARLY\r\nEND:VEVENT\r\n");
+    let cal = Calendar::from_ics(&ics).unwrap();
+    // Feb 29 only exists in leap years: 24 of them in 2024..=2123 (2100 is not).
+    assert_eq!(cal.get_holidays().len(), 24);
+    assert!(cal.get_holidays().contains(&d(2120, 2, 29)));
+    assert_eq!(
+        findates::ics::ICS_RRULE_HORIZON_YEARS,
+        100,
+        "update the expected count if the horizon changes"
+    );
+}
+
+#[test]
+fn far_away_until_and_dtend_are_capped_test() {
+    use findates::ics::{ICS_MAX_EVENT_DAYS, ICS_RRULE_HORIZON_YEARS};
+
+    let ics = wrap(
+        "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\n\
+         RRULE:FREQ=YEARLY;UNTIL=99991231\r\nEND:VEVENT\r\n",
+    );
+    let cal = Calendar::from_ics(&ics).unwrap();
+    assert_eq!(cal.get_holidays().len(), ICS_RRULE_HORIZON_YEARS as usize);
+    assert!(cal.get_holidays().contains(&d(2123, 1, 1)));
+    assert!(!cal.get_holidays().contains(&d(2124, 1, 1)));
+
+    let ics = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\nRRULE:FREQ=YEARLY;COUNT=1000000\r\nEND:VEVENT\r\n");
+    let cal = Calendar::from_ics(&ics).unwrap();
+    assert_eq!(cal.get_holidays().len(), ICS_RRULE_HORIZON_YEARS as usize);
+
+    let ics = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\nDTEND;VALUE=DATE:99991231\r\nEND:VEVENT\r\n");
+    let cal = Calendar::from_ics(&ics).unwrap();
+    assert_eq!(cal.get_holidays().len() as u64, ICS_MAX_EVENT_DAYS);
+    assert_eq!(cal.iter_holidays_sorted().last(), Some(d(2024, 12, 31)));
+}
+
+#[test]
+fn import_errors_test() {
+    let bad_date = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:2024-01-01\r\nEND:VEVENT\r\n");
+    assert_eq!(Calendar::from_ics(&bad_date), Err(IcsError::InvalidDate { line: 4 }));
+
+    let no_start = wrap("BEGIN:VEVENT\r\nSUMMARY:Nothing\r\nEND:VEVENT\r\n");
+    assert_eq!(Calendar::from_ics(&no_start), Err(IcsError::MissingStart { line: 5 }));
+
+    let weekly = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\n");
+    assert_eq!(Calendar::from_ics(&weekly), Err(IcsError::UnsupportedRule { line: 5 }));
+
+    // A multibyte BYDAY must be rejected, not split mid-character.
+    let multibyte = wrap("BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20240101\r\nRRULE:FREQ=YEARLY;BYDAY=éx\r\nEND:VEVENT\r\n");
+    assert_eq!(Calendar::from_ics(&multibyte), Err(IcsError::UnsupportedRule { line: 5 }));
+}
+
+// ── Export ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn export_roundtrip_test() {
+    let cal = Calendar::with_holidays([d(2024, 12, 26), d(2024, 12, 25), d(2025, 1, 1)]);
+    let ics = cal.to_ics("London, UK");
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("X-WR-CALNAME:London\\, UK\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+    let first = ics.find("DTSTART;VALUE=DATE:20241225").unwrap();
+    let last = ics.find("DTSTART;VALUE=DATE:20250101").unwrap();
+    assert!(first < last);
+    assert_eq!(Calendar::from_ics(&ics).unwrap(), cal);
+}
+
+#[test]
+fn export_holiday_names_test() {
+    let mut cal = Calendar::with_holidays([d(2024, 12, 24)]);
+    cal.add_named_holiday(d(2024, 12, 25), "Christmas Day; observed");
+    let ics = cal.to_ics("UK");
+
+    assert!(ics.contains("SUMMARY:Christmas Day\\; observed\r\n"));
+    assert_eq!(ics.matches("SUMMARY").count(), 1);
+    assert_eq!(Calendar::from_ics(&ics).unwrap(), cal);
+}
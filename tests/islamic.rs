@@ -0,0 +1,99 @@
+// Integration tests for the tabular Islamic calendar.
+// Run with `cargo test --features islamic`.
+#![cfg(feature = "islamic")]
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use findates::calendar::Calendar;
+use findates::islamic::{self, HijriDate};
+
+mod setup;
+use setup::d;
+
+// ── Conversion ───────────────────────────────────────────────────────────────
+
+#[test]
+fn epoch_test() {
+    // 1 Muharram 1 AH is 16 July 622 Julian, 19 July 622 proleptic Gregorian.
+    let epoch = HijriDate::new(1, 1, 1).unwrap();
+    assert_eq!(epoch.to_gregorian(), Some(d(622, 7, 19)));
+    assert_eq!(HijriDate::from_gregorian(&d(622, 7, 19)), epoch);
+    assert_eq!(HijriDate::from_gregorian(&d(622, 7, 18)), HijriDate::new(0, 12, 29).unwrap());
+}
+
+#[test]
+fn round_trip_test() {
+    for date in d(1900, 1, 1).iter_days().take_while(|date| *date <= d(2100, 12, 31)) {
+        let hijri = HijriDate::from_gregorian(&date);
+        assert_eq!(HijriDate::new(hijri.year, hijri.month, hijri.day), Some(hijri), "{date}");
+        assert_eq!(hijri.to_gregorian(), Some(date));
+    }
+}
+
+#[test]
+fn year_length_test() {
+    // 11 leap years in every 30-year cycle; leap years have 355 days.
+    assert_eq!((1441..1471).filter(|year| islamic::is_leap_year(*year)).count(), 11);
+    for year in 1400..1500 {
+        let length: u32 = (1..=12).map(|month| islamic::days_in_month(year, month).unwrap()).sum();
+        let first = HijriDate::new(year, 1, 1).unwrap().to_gregorian().unwrap();
+        let next = HijriDate::new(year + 1, 1, 1).unwrap().to_gregorian().unwrap();
+        assert_eq!((next - first).num_days(), length as i64);
+        assert_eq!(length, if islamic::is_leap_year(year) { 355 } else { 354 });
+    }
+}
+
+#[test]
+fn invalid_dates_test() {
+    assert_eq!(HijriDate::new(1445, 0, 1), None);
+    assert_eq!(HijriDate::new(1445, 2, 30), None);
+    assert_eq!(HijriDate::new(1445, 1, 0), None);
+    assert_eq!(islamic::days_in_month(1445, 13), None);
+    assert!(HijriDate::new(i32::MAX, 1, 1).unwrap().to_gregorian().is_none());
+}
+
+// ── Eid dates ────────────────────────────────────────────────────────────────
+
+#[test]
+fn eid_close_to_observed_dates_test() {
+    // First days of Eid as announced in Saudi Arabia.
+    let fitr = [
+        d(2015, 7, 17), d(2016, 7, 6), d(2017, 6, 25), d(2018, 6, 15), d(2019, 6, 4), d(2020, 5, 24),
+        d(2021, 5, 13), d(2022, 5, 2), d(2023, 4, 21), d(2024, 4, 10), d(2025, 3, 30),
+    ];
+    let adha = [
+        d(2015, 9, 24), d(2016, 9, 12), d(2017, 9, 1), d(2018, 8, 21), d(2019, 8, 11), d(2020, 7, 31),
+        d(2021, 7, 20), d(2022, 7, 9), d(2023, 6, 28), d(2024, 6, 16), d(2025, 6, 6),
+    ];
+    for (observed, tabular) in [(fitr, islamic::eid_al_fitr as fn(i32) -> Vec<NaiveDate>), (adha, islamic::eid_al_adha)] {
+        for date in observed {
+            let approx = tabular(date.year());
+            assert_eq!(approx.len(), 1);
+            assert!((approx[0] - date).num_days().abs() <= 2, "{date} vs {}", approx[0]);
+        }
+    }
+}
+
+#[test]
+fn hijri_date_twice_in_a_year_test() {
+    assert_eq!(islamic::eid_al_fitr(2000), vec![d(2000, 1, 8), d(2000, 12, 28)]);
+    assert_eq!(islamic::eid_al_fitr(2001), vec![d(2001, 12, 17)]);
+    // The 30th of Dhu al-Hijjah only exists in leap years; 1446 is not one.
+    assert!(islamic::gregorian_dates_in_year(2025, 12, 30).is_empty());
+}
+
+#[test]
+fn eid_holidays_test() {
+    let eid = islamic::eid_holidays(2024..=2025, 3, 4);
+    assert_eq!(eid.get_holidays().len(), 2 * (3 + 4));
+    assert!(eid.get_weekend().is_empty());
+    assert_eq!(eid.valid_range(), Some((d(2024, 1, 1), d(2025, 12, 31))));
+    assert_eq!(eid.holiday_name(&d(2024, 4, 12)), Some("Eid al-Fitr"));
+    assert_eq!(eid.holiday_name(&d(2024, 4, 13)), None);
+    assert_eq!(eid.holiday_name(&d(2024, 6, 20)), Some("Eid al-Adha"));
+
+    let mut cal = Calendar::middle_east_weekend();
+    cal.union(&eid);
+    assert!(cal.get_weekend().contains(&Weekday::Fri));
+    assert!(!cal.is_business_day(&d(2024, 4, 10)));
+    assert!(cal.is_business_day(&d(2024, 4, 14)));
+}
@@ -0,0 +1,113 @@
+// Integration tests for the Chinese lunisolar calendar and lunar festivals.
+
+use chrono::Datelike;
+use findates::calendar::basic_calendar;
+use findates::lunar::{self, FestivalClosure, LunarDate, LunarFestival};
+
+mod setup;
+use setup::d;
+
+// ── Festival dates ───────────────────────────────────────────────────────────
+
+#[test]
+fn lunar_new_year_test() {
+    let expected = [
+        d(1990, 1, 27), d(1991, 2, 15), d(1992, 2, 4), d(1993, 1, 23), d(1994, 2, 10), d(1995, 1, 31),
+        d(1996, 2, 19), d(1997, 2, 7), d(1998, 1, 28), d(1999, 2, 16), d(2000, 2, 5), d(2001, 1, 24),
+        d(2002, 2, 12), d(2003, 2, 1), d(2004, 1, 22), d(2005, 2, 9), d(2006, 1, 29), d(2007, 2, 18),
+        d(2008, 2, 7), d(2009, 1, 26), d(2010, 2, 14), d(2011, 2, 3), d(2012, 1, 23), d(2013, 2, 10),
+        d(2014, 1, 31), d(2015, 2, 19), d(2016, 2, 8), d(2017, 1, 28), d(2018, 2, 16), d(2019, 2, 5),
+        d(2020, 1, 25), d(2021, 2, 12), d(2022, 2, 1), d(2023, 1, 22), d(2024, 2, 10), d(2025, 1, 29),
+        d(2026, 2, 17), d(2027, 2, 6), d(2028, 1, 26), d(2029, 2, 13), d(2030, 2, 3), d(2031, 1, 23),
+        d(2032, 2, 11), d(2033, 1, 31), d(2034, 2, 19), d(2035, 2, 8),
+    ];
+    for date in expected {
+        assert_eq!(lunar::lunar_new_year(date.year()), Some(date));
+    }
+    // Before 1929 China kept Beijing local mean time, not UTC+8.
+    assert_eq!(lunar::lunar_new_year(1916), Some(d(1916, 2, 3)));
+}
+
+#[test]
+fn hkex_festival_holidays_test() {
+    // Tuen Ng Festival and the day following the Mid-Autumn Festival, as
+    // listed in HKEX trading calendars.
+    let tuen_ng = [
+        d(2015, 6, 20), d(2016, 6, 9), d(2017, 5, 30), d(2018, 6, 18), d(2019, 6, 7), d(2020, 6, 25),
+        d(2021, 6, 14), d(2022, 6, 3), d(2023, 6, 22), d(2024, 6, 10), d(2025, 5, 31), d(2026, 6, 19),
+    ];
+    let after_mid_autumn = [
+        d(2015, 9, 28), d(2016, 9, 16), d(2017, 10, 5), d(2018, 9, 25), d(2019, 9, 14), d(2020, 10, 2),
+        d(2021, 9, 22), d(2022, 9, 11), d(2023, 9, 30), d(2024, 9, 18), d(2025, 10, 7), d(2026, 9, 26),
+    ];
+    for (year, (dragon_boat, after)) in (2015..).zip(tuen_ng.into_iter().zip(after_mid_autumn)) {
+        assert_eq!(LunarFestival::DragonBoat.date(year), Some(dragon_boat));
+        assert_eq!(LunarFestival::MidAutumn.date(year).unwrap().succ_opt(), Some(after));
+    }
+}
+
+#[test]
+fn unsupported_years_test() {
+    assert_eq!(lunar::lunar_new_year(1900), None);
+    assert_eq!(lunar::lunar_new_year(2101), None);
+    assert!(lunar::lunar_new_year(2100).is_some());
+    assert_eq!(LunarDate::from_gregorian(&d(1901, 1, 1)), None);
+}
+
+// ── Conversion ───────────────────────────────────────────────────────────────
+
+#[test]
+fn leap_month_test() {
+    assert_eq!(lunar::leap_month(2020), Some(4));
+    assert_eq!(lunar::leap_month(2023), Some(2));
+    assert_eq!(lunar::leap_month(2025), Some(6));
+    assert_eq!(lunar::leap_month(2024), None);
+    // The leap eleventh month of 2033 falls after the winter solstice.
+    assert_eq!(lunar::leap_month(2033), Some(11));
+    let leap_eleventh = LunarDate::new(2033, 11, true, 1).unwrap();
+    assert_eq!(leap_eleventh.to_gregorian(), Some(d(2033, 12, 22)));
+    assert_eq!(lunar::lunar_new_year(2034), Some(d(2034, 2, 19)));
+}
+
+#[test]
+fn round_trip_test() {
+    for date in d(2015, 1, 1).iter_days().take_while(|date| *date < d(2036, 1, 1)) {
+        let lunar = LunarDate::from_gregorian(&date).unwrap();
+        assert_eq!(LunarDate::new(lunar.year, lunar.month, lunar.leap, lunar.day), Some(lunar));
+        assert_eq!(lunar.to_gregorian(), Some(date), "{lunar:?}");
+    }
+}
+
+#[test]
+fn month_lengths_test() {
+    assert!(LunarDate::new(2024, 1, false, 29).is_some());
+    assert_eq!(LunarDate::new(2024, 1, false, 31), None);
+    assert_eq!(LunarDate::new(2024, 0, false, 1), None);
+    assert_eq!(LunarDate { year: 2024, month: 2, leap: true, day: 1 }.to_gregorian(), None);
+    // Every month has 29 or 30 days.
+    for year in 2000..2040 {
+        let months = (1..=12).filter_map(|month| LunarDate::new(year, month, false, 29));
+        assert_eq!(months.count(), 12);
+    }
+}
+
+// ── Holiday calendars ────────────────────────────────────────────────────────
+
+#[test]
+fn festival_holidays_test() {
+    let closures = [
+        FestivalClosure::new(LunarFestival::NewYear, -1, 3),
+        FestivalClosure::new(LunarFestival::MidAutumn, -1, 3),
+    ];
+    let korea = lunar::festival_holidays(2024..=2025, &closures);
+    assert_eq!(korea.get_holidays().len(), 12);
+    assert!(korea.get_weekend().is_empty());
+    assert_eq!(korea.holiday_name(&d(2024, 2, 9)), Some("Lunar New Year"));
+    assert_eq!(korea.holiday_name(&d(2025, 10, 7)), Some("Mid-Autumn Festival"));
+    assert_eq!(korea.holiday_name(&d(2025, 10, 8)), None);
+
+    let mut cal = basic_calendar();
+    cal.union(&korea);
+    assert!(!cal.is_business_day(&d(2024, 9, 16)));
+    assert!(cal.is_business_day(&d(2024, 9, 19)));
+}
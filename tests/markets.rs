@@ -0,0 +1,235 @@
+// Integration tests for the built-in market calendars.
+
+use chrono::NaiveDate;
+use findates::algebra::{business_days_between, try_day_count_fraction};
+use findates::calendar::Calendar;
+use findates::conventions::DayCount;
+
+mod setup;
+use setup::d;
+
+fn business_days_in_year(cal: &Calendar, year: i32) -> u64 {
+    business_days_between(&d(year, 1, 1), &d(year + 1, 1, 1), cal, None)
+}
+
+// ── Brazil (B3 / ANBIMA) ─────────────────────────────────────────────────────
+
+#[test]
+fn brazil_moveable_holidays_test() {
+    let cal = Calendar::brazil_b3(2024..=2025);
+    for (date, name) in [
+        (d(2024, 2, 12), "Carnival"),
+        (d(2024, 2, 13), "Carnival"),
+        (d(2024, 3, 29), "Good Friday"),
+        (d(2024, 5, 30), "Corpus Christi"),
+        (d(2025, 3, 3), "Carnival"),
+        (d(2025, 3, 4), "Carnival"),
+        (d(2025, 4, 18), "Good Friday"),
+        (d(2025, 6, 19), "Corpus Christi"),
+    ] {
+        assert_eq!(cal.holiday_name(&date), Some(name), "{date}");
+        assert!(!cal.is_business_day(&date));
+    }
+    // Ash Wednesday is a half day, not a holiday.
+    assert!(cal.is_business_day(&d(2025, 3, 5)));
+}
+
+#[test]
+fn brazil_black_consciousness_day_test() {
+    let cal = Calendar::brazil_b3(2023..=2024);
+    assert!(cal.is_business_day(&d(2023, 11, 20)));
+    assert_eq!(cal.holiday_name(&d(2024, 11, 20)), Some("Black Consciousness Day"));
+}
+
+#[test]
+fn brazil_anbima_business_day_counts_test() {
+    let cal = Calendar::brazil_b3(2023..=2023);
+    assert_eq!(business_days_in_year(&cal, 2023), 249);
+}
+
+#[test]
+fn brazil_bd252_test() {
+    let cal = Calendar::brazil_b3(2023..=2024);
+    let dcf = try_day_count_fraction(&d(2023, 1, 2), &d(2024, 1, 2), DayCount::Bd252, Some(&cal), None).unwrap();
+    assert!((dcf - 249.0 / 252.0).abs() < 1e-12);
+}
+
+#[test]
+fn brazil_valid_range_test() {
+    let cal = Calendar::brazil_b3(2020..=2030);
+    assert_eq!(cal.valid_range(), Some((d(2020, 1, 1), d(2030, 12, 31))));
+    assert!(cal.try_is_business_day(&d(2031, 1, 2)).is_err());
+    // 8 fixed dates and 4 Easter-based ones a year, plus 20 November from 2024.
+    assert_eq!(cal.get_holidays().len(), 11 * (8 + 4) + 7);
+}
+
+// ── United Kingdom (England and Wales) ───────────────────────────────────────
+
+#[test]
+fn uk_bank_holidays_match_gov_uk_test() {
+    let cal = Calendar::uk_bank_holidays(2018..=2026);
+    let expected: [(i32, &[(u32, u32)]); 9] = [
+        (2018, &[(1, 1), (3, 30), (4, 2), (5, 7), (5, 28), (8, 27), (12, 25), (12, 26)]),
+        (2019, &[(1, 1), (4, 19), (4, 22), (5, 6), (5, 27), (8, 26), (12, 25), (12, 26)]),
+        (2020, &[(1, 1), (4, 10), (4, 13), (5, 8), (5, 25), (8, 31), (12, 25), (12, 28)]),
+        (2021, &[(1, 1), (4, 2), (4, 5), (5, 3), (5, 31), (8, 30), (12, 27), (12, 28)]),
+        (2022, &[(1, 3), (4, 15), (4, 18), (5, 2), (6, 2), (6, 3), (8, 29), (9, 19), (12, 26), (12, 27)]),
+        (2023, &[(1, 2), (4, 7), (4, 10), (5, 1), (5, 8), (5, 29), (8, 28), (12, 25), (12, 26)]),
+        (2024, &[(1, 1), (3, 29), (4, 1), (5, 6), (5, 27), (8, 26), (12, 25), (12, 26)]),
+        (2025, &[(1, 1), (4, 18), (4, 21), (5, 5), (5, 26), (8, 25), (12, 25), (12, 26)]),
+        (2026, &[(1, 1), (4, 3), (4, 6), (5, 4), (5, 25), (8, 31), (12, 25), (12, 28)]),
+    ];
+    for (year, dates) in expected {
+        let expected: Vec<NaiveDate> = dates.iter().map(|&(m, day)| d(year, m, day)).collect();
+        assert_eq!(cal.holidays_in_year(year), expected, "{year}");
+    }
+}
+
+#[test]
+fn uk_substitute_day_names_test() {
+    let cal = Calendar::uk_bank_holidays(2021..=2023);
+    assert_eq!(cal.holiday_name(&d(2021, 12, 27)), Some("Christmas Day (substitute day)"));
+    assert_eq!(cal.holiday_name(&d(2021, 12, 28)), Some("Boxing Day (substitute day)"));
+    assert_eq!(cal.holiday_name(&d(2022, 1, 3)), Some("New Year's Day (substitute day)"));
+    assert_eq!(cal.holiday_name(&d(2022, 12, 26)), Some("Boxing Day"));
+    assert_eq!(cal.holiday_name(&d(2022, 12, 27)), Some("Christmas Day (substitute day)"));
+    assert_eq!(cal.holiday_name(&d(2023, 5, 8)), Some("Coronation of King Charles III"));
+}
+
+#[test]
+fn uk_proclaimed_holidays_test() {
+    let cal = Calendar::uk_bank_holidays(1995..=2012);
+    assert_eq!(cal.holiday_name(&d(1995, 5, 8)), Some("Early May bank holiday"));
+    assert!(cal.is_business_day(&d(1995, 5, 1)));
+    assert_eq!(cal.holiday_name(&d(1999, 12, 31)), Some("Millennium Celebrations"));
+    assert_eq!(cal.holiday_name(&d(2002, 6, 3)), Some("Golden Jubilee"));
+    assert_eq!(cal.holiday_name(&d(2002, 6, 4)), Some("Spring bank holiday"));
+    assert_eq!(cal.holiday_name(&d(2011, 4, 29)), Some("Royal Wedding"));
+    assert_eq!(cal.holiday_name(&d(2012, 6, 5)), Some("Diamond Jubilee"));
+    assert!(cal.is_business_day(&d(2012, 5, 28)));
+    // 261 weekdays, nine of them bank holidays.
+    assert_eq!(business_days_in_year(&cal, 2012), 261 - 9);
+}
+
+// ── TARGET2 ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn target2_closing_days_test() {
+    let cal = Calendar::target2();
+    assert_eq!(
+        cal.holidays_in_year(2024),
+        vec![d(2024, 1, 1), d(2024, 3, 29), d(2024, 4, 1), d(2024, 5, 1), d(2024, 12, 25), d(2024, 12, 26)]
+    );
+    assert_eq!(cal.holidays_in_year(1999), vec![d(1999, 1, 1), d(1999, 12, 25), d(1999, 12, 31)]);
+    assert_eq!(cal.holidays_in_year(2001).last(), Some(&d(2001, 12, 31)));
+    assert_eq!(cal.holidays_in_year(2002).last(), Some(&d(2002, 12, 26)));
+}
+
+#[test]
+fn target2_weekends_are_not_substituted_test() {
+    let cal = Calendar::target2();
+    // Christmas 2021 fell on a Saturday; Monday 27 December was open.
+    assert!(cal.is_business_day(&d(2021, 12, 27)));
+    // 2025 has 261 weekdays, six of them closing days.
+    assert_eq!(business_days_in_year(&cal, 2025), 255);
+    assert_eq!(cal.valid_range(), Some((d(1999, 1, 1), d(2099, 12, 31))));
+}
+
+// ── Japan ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn japan_matches_jpx_calendar_test() {
+    // Weekday closures of the Japan Exchange Group.
+    let cal = Calendar::japan(2019..=2025);
+    let expected: [(i32, &[(u32, u32)]); 5] = [
+        (2019, &[
+            (1, 1), (1, 2), (1, 3), (1, 14), (2, 11), (3, 21), (4, 29), (4, 30), (5, 1), (5, 2), (5, 3), (5, 6),
+            (7, 15), (8, 12), (9, 16), (9, 23), (10, 14), (10, 22), (11, 4), (12, 31),
+        ]),
+        (2020, &[
+            (1, 1), (1, 2), (1, 3), (1, 13), (2, 11), (2, 24), (3, 20), (4, 29), (5, 4), (5, 5), (5, 6), (7, 23),
+            (7, 24), (8, 10), (9, 21), (9, 22), (11, 3), (11, 23), (12, 31),
+        ]),
+        (2021, &[
+            (1, 1), (1, 11), (2, 11), (2, 23), (4, 29), (5, 3), (5, 4), (5, 5), (7, 22), (7, 23), (8, 9), (9, 20),
+            (9, 23), (11, 3), (11, 23), (12, 31),
+        ]),
+        (2024, &[
+            (1, 1), (1, 2), (1, 3), (1, 8), (2, 12), (2, 23), (3, 20), (4, 29), (5, 3), (5, 6), (7, 15), (8, 12),
+            (9, 16), (9, 23), (10, 14), (11, 4), (12, 31),
+        ]),
+        (2025, &[
+            (1, 1), (1, 2), (1, 3), (1, 13), (2, 11), (2, 24), (3, 20), (4, 29), (5, 5), (5, 6), (7, 21), (8, 11),
+            (9, 15), (9, 23), (10, 13), (11, 3), (11, 24), (12, 31),
+        ]),
+    ];
+    for (year, dates) in expected {
+        let closed: Vec<NaiveDate> = cal.holidays_in_year(year).into_iter().filter(|date| !cal.is_weekend(date)).collect();
+        let expected: Vec<NaiveDate> = dates.iter().map(|&(m, day)| d(year, m, day)).collect();
+        assert_eq!(closed, expected, "{year}");
+    }
+}
+
+#[test]
+fn japan_substitute_and_citizens_holidays_test() {
+    let cal = Calendar::japan(2009..=2026);
+    // Silver Week 2015: Respect for the Aged Day, a Citizens' Holiday and Autumnal Equinox Day.
+    assert_eq!(cal.holiday_name(&d(2015, 9, 21)), Some("Respect for the Aged Day"));
+    assert_eq!(cal.holiday_name(&d(2015, 9, 22)), Some("Citizens' Holiday"));
+    assert_eq!(cal.holiday_name(&d(2015, 9, 23)), Some("Autumnal Equinox Day"));
+    // Constitution Memorial Day 2015 fell on a Sunday; 4 and 5 May were
+    // already holidays, so the substitute is Wednesday 6 May.
+    assert_eq!(cal.holiday_name(&d(2015, 5, 6)), Some("Substitute Holiday"));
+    // 2 January 2023 is both the substitute for New Year's Day and a bank holiday.
+    assert_eq!(cal.holiday_name(&d(2023, 1, 2)), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(&d(2023, 1, 3)), Some("Bank Holiday"));
+    // Emperor's Birthday moved from 23 December to 23 February with the 2019 succession.
+    assert_eq!(cal.holiday_name(&d(2018, 12, 24)), Some("Substitute Holiday"));
+    assert!(cal.is_business_day(&d(2019, 12, 23)));
+}
+
+#[test]
+fn japan_historical_rules_test() {
+    let cal = Calendar::japan(1980..=2006);
+    // Coming of Age Day was 15 January, Health and Sports Day 10 October.
+    assert_eq!(cal.holiday_name(&d(1999, 1, 15)), Some("Coming of Age Day"));
+    assert!(cal.is_business_day(&d(1999, 1, 11)));
+    assert_eq!(cal.holiday_name(&d(1995, 10, 10)), Some("Health and Sports Day"));
+    // Marine Day started in 1996.
+    assert!(cal.is_business_day(&d(1995, 7, 20)));
+    assert_eq!(cal.holiday_name(&d(1997, 7, 21)), Some("Substitute Holiday"));
+    // 29 April was the Showa Emperor's birthday.
+    assert_eq!(cal.holiday_name(&d(1988, 4, 29)), Some("Emperor's Birthday"));
+    assert_eq!(cal.holiday_name(&d(1990, 4, 29)), Some("Greenery Day"));
+    assert!(cal.is_business_day(&d(1987, 12, 23)));
+    // Before 2007 a Sunday holiday only moved to the Monday, which took
+    // precedence over a citizens' holiday.
+    assert_eq!(cal.holiday_name(&d(1987, 5, 4)), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(&d(1988, 5, 4)), Some("Citizens' Holiday"));
+    assert_eq!(cal.holiday_name(&d(1989, 2, 24)), Some("Funeral of Emperor Showa"));
+}
+
+#[test]
+fn japan_valid_range_is_clamped_test() {
+    let cal = Calendar::japan(1970..=2110);
+    assert_eq!(cal.valid_range(), Some((d(1980, 1, 1), d(2099, 12, 31))));
+    assert_eq!(cal.holiday_name(&d(2099, 3, 20)), Some("Vernal Equinox Day"));
+    assert_eq!(cal.try_is_business_day(&d(2100, 3, 22)), Err(findates::CalendarError::OutOfRange { date: d(2100, 3, 22) }));
+    assert!(cal.holidays_in_year(2100).is_empty());
+    assert!(cal.holidays_in_year(1979).is_empty());
+}
+
+#[test]
+fn japan_equinox_days_test() {
+    let cal = Calendar::japan(2000..=2030);
+    for (vernal, autumnal) in [
+        (d(2000, 3, 20), d(2000, 9, 23)),
+        (d(2012, 3, 20), d(2012, 9, 22)),
+        (d(2019, 3, 21), d(2019, 9, 23)),
+        (d(2023, 3, 21), d(2023, 9, 23)),
+        (d(2030, 3, 20), d(2030, 9, 23)),
+    ] {
+        assert_eq!(cal.holiday_name(&vernal), Some("Vernal Equinox Day"));
+        assert_eq!(cal.holiday_name(&autumnal), Some("Autumnal Equinox Day"));
+    }
+}
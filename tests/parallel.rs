@@ -0,0 +1,72 @@
+// Integration tests for the rayon-backed batch operations.
+// Run with `cargo test --features parallel`.
+#![cfg(feature = "parallel")]
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use findates::algebra;
+use findates::calendar;
+use findates::conventions::{AdjustRule, Frequency};
+use findates::parallel::{par_adjust_many, par_expand_holidays, par_generate};
+use findates::schedule::Schedule;
+use findates::ScheduleError;
+
+mod setup;
+use setup::d;
+
+// Fixed-date holidays plus US Thanksgiving (4th Thursday of November).
+fn us_rule(year: i32) -> Vec<NaiveDate> {
+    let mut days = vec![d(year, 1, 1), d(year, 7, 4), d(year, 12, 25)];
+    days.extend(NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Thu, 4));
+    days
+}
+
+#[test]
+fn par_adjust_many_matches_sequential_test() {
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays((2020..=2030).flat_map(us_rule));
+    let dates: Vec<NaiveDate> = d(2020, 1, 1).iter_days().take(4000).collect();
+    for rule in [None, Some(AdjustRule::Unadjusted), Some(AdjustRule::ModFollowing), Some(AdjustRule::Nearest)] {
+        assert_eq!(
+            par_adjust_many(&dates, Some(&cal), rule),
+            algebra::adjust_many(&dates, Some(&cal), rule)
+        );
+    }
+}
+
+#[test]
+fn par_expand_holidays_test() {
+    let cal = par_expand_holidays(1990..=2089, us_rule);
+    assert_eq!(cal.get_holidays().len(), 400);
+    assert!(cal.get_weekend().is_empty());
+    assert!(cal.get_holidays().contains(&d(2024, 11, 28)));
+    assert!(cal
+        .get_holidays()
+        .iter()
+        .all(|h| (1990..=2089).contains(&h.year())));
+
+    let sequential = calendar::Calendar::with_holidays((1990..=2089).flat_map(us_rule));
+    assert_eq!(cal, sequential);
+}
+
+#[test]
+fn par_generate_matches_sequential_test() {
+    let cal = calendar::basic_calendar();
+    let quarterly = Schedule::new(Frequency::Quarterly, Some(&cal), Some(AdjustRule::ModFollowing));
+    let monthly = Schedule::new(Frequency::Monthly, None, None).with_eom(true);
+    let items: Vec<_> = (0..500)
+        .map(|i| {
+            let sched = if i % 2 == 0 { quarterly } else { monthly };
+            let anchor = d(2024, 1 + (i % 12) as u32, 27 + (i % 3) as u32);
+            (sched, anchor, d(2025 + i % 30, 6, 30))
+        })
+        .collect();
+
+    let results = par_generate(&items);
+    assert_eq!(results.len(), items.len());
+    for ((sched, anchor, end), result) in items.iter().zip(&results) {
+        assert_eq!(result, &sched.generate(anchor, end));
+    }
+
+    let invalid = par_generate(&[(quarterly, d(2024, 1, 1), d(2023, 1, 1))]);
+    assert_eq!(invalid, vec![Err(ScheduleError::InvalidDateRange)]);
+}
@@ -0,0 +1,133 @@
+// Property-based tests over random calendars, conventions and schedules.
+// Run with `cargo test --features proptest`.
+#![cfg(feature = "proptest")]
+
+use chrono::{Datelike, Days, NaiveDate};
+use findates::algebra;
+use findates::arbitrary::{dates, dates_between};
+use findates::calendar::Calendar;
+use findates::conventions::{AdjustRule, DayCount, Frequency, Tenor};
+use findates::schedule::ScheduleOwned;
+use proptest::prelude::*;
+
+mod setup;
+use setup::d;
+
+// ── Adjustment ───────────────────────────────────────────────────────────────
+
+proptest! {
+    #[test]
+    fn adjust_is_idempotent(date in dates(), cal in any::<Calendar>(), rule in any::<Option<AdjustRule>>()) {
+        let once = algebra::adjust(&date, Some(&cal), rule);
+        prop_assert_eq!(algebra::adjust(&once, Some(&cal), rule), once);
+    }
+
+    #[test]
+    fn adjust_lands_on_business_day(date in dates(), cal in any::<Calendar>(), rule in any::<AdjustRule>()) {
+        let adjusted = algebra::adjust(&date, Some(&cal), Some(rule));
+        if rule == AdjustRule::Unadjusted {
+            prop_assert_eq!(adjusted, date);
+        } else {
+            prop_assert!(cal.is_business_day(&adjusted));
+        }
+        if cal.is_business_day(&date) {
+            prop_assert_eq!(adjusted, date);
+        }
+    }
+
+    #[test]
+    fn following_and_preceding_bracket_date(date in dates(), cal in any::<Calendar>()) {
+        let following = algebra::adjust(&date, Some(&cal), Some(AdjustRule::Following));
+        let preceding = algebra::adjust(&date, Some(&cal), Some(AdjustRule::Preceding));
+        prop_assert!(preceding <= date && date <= following);
+        // No business day lies strictly between the date and either adjustment.
+        for between in date.iter_days().skip(1).take_while(|day| *day < following) {
+            prop_assert!(!cal.is_business_day(&between));
+        }
+        for between in preceding.iter_days().skip(1).take_while(|day| *day < date) {
+            prop_assert!(!cal.is_business_day(&between));
+        }
+    }
+
+    #[test]
+    fn adjust_explain_agrees_with_adjust(date in dates(), cal in any::<Calendar>(), rule in any::<Option<AdjustRule>>()) {
+        let explained = algebra::adjust_explain(&date, Some(&cal), rule);
+        prop_assert_eq!(explained.adjusted, algebra::adjust(&date, Some(&cal), rule));
+        prop_assert_eq!((explained.adjusted - date).num_days(), explained.days_moved);
+    }
+}
+
+// ── Day counts ───────────────────────────────────────────────────────────────
+
+proptest! {
+    #[test]
+    fn day_count_fraction_is_zero_on_same_day(date in dates(), daycount in any::<DayCount>(), cal in any::<Calendar>()) {
+        if let Ok(fraction) = algebra::try_day_count_fraction(&date, &date, daycount, Some(&cal), None) {
+            prop_assert_eq!(fraction, 0.0);
+        }
+    }
+
+    #[test]
+    fn act_day_counts_are_additive(
+        start in dates_between(d(2000, 1, 1), d(2040, 12, 31)),
+        first in 0..1000u64,
+        second in 0..1000u64,
+        daycount in prop::sample::select(vec![DayCount::Act360, DayCount::Act365Fixed, DayCount::Act365_25]),
+    ) {
+        let mid = start.checked_add_days(Days::new(first)).unwrap();
+        let end = mid.checked_add_days(Days::new(second)).unwrap();
+        let dcf = |a: &NaiveDate, b: &NaiveDate| algebra::try_day_count_fraction(a, b, daycount, None, None).unwrap();
+        prop_assert!((dcf(&start, &mid) + dcf(&mid, &end) - dcf(&start, &end)).abs() < 1e-12);
+    }
+}
+
+// ── Schedules ────────────────────────────────────────────────────────────────
+
+proptest! {
+    #[test]
+    fn generated_dates_increase(
+        owned in any::<ScheduleOwned>(),
+        anchor in dates_between(d(2000, 1, 1), d(2040, 12, 31)),
+        days in 1..2000u64,
+    ) {
+        let end = anchor.checked_add_days(Days::new(days)).unwrap();
+        let sched = owned.as_schedule();
+        let trace = sched.generate_with_trace(&anchor, &end).unwrap();
+        prop_assert!(!trace.is_empty());
+        prop_assert!(trace.windows(2).all(|pair| pair[0].adjusted < pair[1].adjusted));
+        if owned.frequency != Frequency::Zero {
+            prop_assert_eq!(trace[0].unadjusted, anchor);
+        }
+        for date in &trace {
+            prop_assert!(date.unadjusted <= end);
+            prop_assert_eq!(algebra::adjust(&date.unadjusted, owned.calendar.as_deref(), date.adjust_rule), date.adjusted);
+        }
+        let dates: Vec<NaiveDate> = trace.iter().map(|date| date.adjusted).collect();
+        prop_assert_eq!(sched.generate(&anchor, &end).unwrap(), dates);
+    }
+
+    #[test]
+    fn frequency_tenor_roundtrip(frequency in any::<Frequency>()) {
+        match Tenor::from_frequency(frequency) {
+            Some(tenor) => {
+                // The tenor maps back to a frequency with the same period.
+                let back = tenor.to_frequency().unwrap();
+                prop_assert_eq!((back.to_months(), back.to_days()), (frequency.to_months(), frequency.to_days()));
+            }
+            None => prop_assert_eq!(frequency, Frequency::Zero),
+        }
+        prop_assert_eq!(frequency.to_string().parse::<Frequency>(), Ok(frequency));
+    }
+
+    #[test]
+    fn tenor_add_sub_roundtrip(tenor in any::<Tenor>(), date in dates()) {
+        // Day and week tenors are exact; month tenors may clamp the day.
+        let there = tenor.add_to(&date).unwrap();
+        prop_assert!(there >= date);
+        match tenor {
+            Tenor::Days(_) | Tenor::Weeks(_) => prop_assert_eq!(tenor.sub_from(&there), Some(date)),
+            Tenor::Months(_) | Tenor::Years(_) => prop_assert!(there.day() <= date.day()),
+            _ => prop_assert_eq!((there - date).num_days(), 1),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// Compares day count fractions, business day adjustments and generated
+// schedules against the golden files in tests/fixtures/quantlib.  See the
+// README there for where the files come from and how to regenerate them.
+// Run with `cargo test --features quantlib-parity`.
+#![cfg(feature = "quantlib-parity")]
+
+use chrono::NaiveDate;
+use findates::algebra;
+use findates::calendar::{basic_calendar, Calendar};
+use findates::conventions::{AdjustRule, DayCount, Frequency};
+use findates::registry::CalendarRegistry;
+use findates::schedule::Schedule;
+
+const DAY_COUNT: &str = include_str!("fixtures/quantlib/day_count.csv");
+const ADJUST: &str = include_str!("fixtures/quantlib/adjust.csv");
+const SCHEDULE: &str = include_str!("fixtures/quantlib/schedule.csv");
+
+// Year fractions are written with full precision, but QuantLib sums some of
+// them in a different order.
+const TOLERANCE: f64 = 1e-12;
+
+// Data rows of a golden file: comment lines and the header are skipped.
+fn rows(csv: &str) -> impl Iterator<Item = Vec<&str>> {
+    csv.lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .skip(1)
+        .map(|line| line.split(',').collect())
+}
+
+fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap_or_else(|_| panic!("invalid date {s:?} in golden file"))
+}
+
+// The QuantLib calendars the golden files use, by their QuantLib names.
+fn registry() -> CalendarRegistry {
+    let mut registry = CalendarRegistry::new();
+    registry.register("WeekendsOnly", basic_calendar()).unwrap();
+    registry.register("TARGET", Calendar::target2()).unwrap();
+    registry
+}
+
+// Fails with every mismatch at once, so a regenerated file shows the full
+// extent of a divergence.
+fn assert_no_mismatches(checked: usize, mismatches: Vec<String>) {
+    assert!(checked > 0, "golden file has no cases");
+    assert!(
+        mismatches.is_empty(),
+        "{} of {checked} cases differ from QuantLib:\n{}",
+        mismatches.len(),
+        mismatches.join("\n")
+    );
+}
+
+// ── Day counts ───────────────────────────────────────────────────────────────
+
+#[test]
+fn day_count_parity_test() {
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for row in rows(DAY_COUNT) {
+        let [convention, start, end, expected] = row[..] else {
+            panic!("malformed day count row {row:?}");
+        };
+        let daycount: DayCount = convention.parse().unwrap();
+        let expected: f64 = expected.parse().unwrap();
+        let actual = algebra::try_day_count_fraction(&date(start), &date(end), daycount, None, None).unwrap();
+        if (actual - expected).abs() > TOLERANCE {
+            mismatches.push(format!("{convention} {start} to {end}: expected {expected}, got {actual}"));
+        }
+        checked += 1;
+    }
+    assert_no_mismatches(checked, mismatches);
+}
+
+// ── Adjustment ───────────────────────────────────────────────────────────────
+
+#[test]
+fn adjust_parity_test() {
+    let registry = registry();
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for row in rows(ADJUST) {
+        let [calendar, rule, unadjusted, expected] = row[..] else {
+            panic!("malformed adjustment row {row:?}");
+        };
+        let cal = registry.parse(calendar).unwrap();
+        let rule: AdjustRule = rule.parse().unwrap();
+        let actual = algebra::adjust(&date(unadjusted), Some(&cal), Some(rule));
+        if actual != date(expected) {
+            mismatches.push(format!("{calendar} {rule} {unadjusted}: expected {expected}, got {actual}"));
+        }
+        checked += 1;
+    }
+    assert_no_mismatches(checked, mismatches);
+}
+
+// ── Schedules ────────────────────────────────────────────────────────────────
+
+// Forward schedules are the coupon period boundaries, with any stub at the
+// back; backward schedules put the stub at the front.
+#[test]
+fn schedule_parity_test() {
+    let registry = registry();
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for row in rows(SCHEDULE) {
+        let [effective, termination, frequency, calendar, rule, generation, expected] = row[..] else {
+            panic!("malformed schedule row {row:?}");
+        };
+        let cal = registry.parse(calendar).unwrap();
+        let frequency: Frequency = frequency.parse().unwrap();
+        let sched = Schedule::new(frequency, Some(&cal), Some(rule.parse().unwrap()));
+        let (effective, termination) = (date(effective), date(termination));
+        let actual = match generation {
+            "Forward" => {
+                let periods = sched.coupon_periods(&effective, &termination, DayCount::Act360, 0).unwrap();
+                let mut dates = vec![periods[0].accrual_start];
+                dates.extend(periods.iter().map(|period| period.accrual_end));
+                dates
+            }
+            "Backward" => sched.generate_backward(&effective, &termination).unwrap(),
+            _ => panic!("unknown date generation rule {generation:?}"),
+        };
+        let expected: Vec<NaiveDate> = expected.split(' ').map(date).collect();
+        if actual != expected {
+            mismatches.push(format!(
+                "{generation} {frequency} {calendar} {rule} {effective} to {termination}:\n  expected {expected:?}\n  got      {actual:?}"
+            ));
+        }
+        checked += 1;
+    }
+    assert_no_mismatches(checked, mismatches);
+}
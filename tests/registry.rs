@@ -0,0 +1,129 @@
+// Integration tests for the calendar registry and calendar expressions.
+
+use findates::calendar::{basic_calendar, Calendar};
+use findates::registry::{CalendarRegistry, ParseCalendarError};
+
+mod setup;
+use setup::{calendar_with_holidays, d};
+
+// New York, London and Tokyo for summer 2024.
+fn registry() -> CalendarRegistry {
+    let mut registry = CalendarRegistry::new();
+    registry.register("NYC", calendar_with_holidays([d(2024, 7, 4), d(2024, 12, 25)])).unwrap();
+    registry.register("LON", calendar_with_holidays([d(2024, 8, 26), d(2024, 12, 25)])).unwrap();
+    registry.register("TYO", calendar_with_holidays([d(2024, 7, 15), d(2024, 8, 12)])).unwrap();
+    registry
+}
+
+// ── Registry ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn register_and_get_test() {
+    let mut registry = registry();
+    assert_eq!(registry.get("nyc"), registry.get(" NYC "));
+    assert!(registry.get("NYC").unwrap().is_holiday(&d(2024, 7, 4)));
+    assert!(registry.get("PAR").is_none());
+
+    let replaced = registry.register("nyc", basic_calendar()).unwrap();
+    assert!(replaced.unwrap().is_holiday(&d(2024, 7, 4)));
+    assert_eq!(registry.get("NYC"), Some(&basic_calendar()));
+}
+
+#[test]
+fn register_invalid_code_test() {
+    let mut registry = registry();
+    let before = registry.clone();
+    for code in ["", "  ", "NYC|LON", "A&B"] {
+        assert_eq!(
+            registry.register(code, basic_calendar()),
+            Err(ParseCalendarError::InvalidCode(code.to_string())),
+            "{code:?}"
+        );
+    }
+    assert_eq!(registry, before);
+}
+
+// ── Expressions ──────────────────────────────────────────────────────────────
+
+#[test]
+fn union_expression_test() {
+    let registry = registry();
+    let joint = registry.parse("NYC|LON").unwrap();
+    assert_eq!(joint, registry.get("NYC").unwrap().unioned(registry.get("LON").unwrap()));
+    assert!(!joint.is_business_day(&d(2024, 7, 4)));
+    assert!(!joint.is_business_day(&d(2024, 8, 26)));
+    assert!(joint.is_business_day(&d(2024, 7, 5)));
+}
+
+#[test]
+fn intersection_expression_test() {
+    let registry = registry();
+    let either = registry.parse("NYC & LON").unwrap();
+    assert!(either.is_business_day(&d(2024, 7, 4)));
+    assert!(either.is_business_day(&d(2024, 8, 26)));
+    assert!(!either.is_business_day(&d(2024, 12, 25)));
+    assert!(!either.is_business_day(&d(2024, 7, 6))); // Saturday
+}
+
+#[test]
+fn operator_precedence_test() {
+    let registry = registry();
+    // TYO | (NYC & LON): Tokyo holidays plus the shared Christmas.
+    let cal = registry.parse("tyo|nyc&lon").unwrap();
+    assert_eq!(cal.holidays_in_year(2024), [d(2024, 7, 15), d(2024, 8, 12), d(2024, 12, 25)]);
+    assert_eq!(registry.parse("NYC&LON|TYO").unwrap(), cal);
+    assert_eq!(registry.parse("NYC|LON|TYO").unwrap().get_holidays().len(), 5);
+}
+
+#[test]
+fn invalid_expression_test() {
+    let registry = registry();
+    assert_eq!(registry.parse("NYC|PAR"), Err(ParseCalendarError::UnknownCode("PAR".to_string())));
+    for expression in ["", " ", "NYC|", "&LON", "NYC||LON", "NYC&&LON"] {
+        assert_eq!(registry.parse(expression), Err(ParseCalendarError::InvalidExpression), "{expression:?}");
+    }
+    assert_eq!(ParseCalendarError::UnknownCode("PAR".to_string()).to_string(), "unknown calendar code \"PAR\"");
+}
+
+#[test]
+fn disjoint_ranges_expression_test() {
+    let mut registry = registry();
+    let mut old = basic_calendar();
+    old.set_valid_range(d(2000, 1, 1), d(2009, 12, 31)).unwrap();
+    let mut new = basic_calendar();
+    new.set_valid_range(d(2020, 1, 1), d(2029, 12, 31)).unwrap();
+    registry.register("OLD", old).unwrap();
+    registry.register("NEW", new).unwrap();
+    assert_eq!(registry.parse("OLD|NEW"), Err(ParseCalendarError::DisjointRanges));
+    assert_eq!(registry.parse("OLD&NEW"), Err(ParseCalendarError::DisjointRanges));
+    assert!(registry.parse("OLD|NYC").is_ok());
+}
+
+// ── FromStr ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn from_str_in_scope_test() {
+    let registry = registry();
+    let parsed: Calendar = registry.scope(|| "NYC|LON".parse()).unwrap();
+    assert_eq!(parsed, registry.parse("NYC|LON").unwrap());
+    assert_eq!("NYC".parse::<Calendar>(), Err(ParseCalendarError::UnknownCode("NYC".to_string())));
+}
+
+#[test]
+fn nested_scopes_test() {
+    let outer = registry();
+    let mut inner = CalendarRegistry::new();
+    inner.register("NYC", basic_calendar()).unwrap();
+    outer.scope(|| {
+        assert!(inner.scope(|| "NYC".parse::<Calendar>()).unwrap().get_holidays().is_empty());
+        assert!("NYC".parse::<Calendar>().unwrap().is_holiday(&d(2024, 7, 4)));
+    });
+}
+
+#[test]
+fn scope_exits_on_panic_test() {
+    let registry = registry();
+    let result = std::panic::catch_unwind(|| registry.scope(|| panic!("inside scope")));
+    assert!(result.is_err());
+    assert!("NYC".parse::<Calendar>().is_err());
+}
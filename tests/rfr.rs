@@ -0,0 +1,87 @@
+// Integration tests for compounded-in-arrears (RFR) observation periods.
+// Each business day of an interest period contributes its fixing, weighted
+// by the calendar days until the next business day.
+
+use chrono::NaiveDate;
+use findates::algebra::{observation_shift, rfr_observations, RfrConventions, RfrObservation};
+use findates::calendar::{basic_calendar, Calendar};
+
+mod setup;
+use setup::d;
+
+// US calendar around Juneteenth and Independence Day 2024.
+fn usd() -> Calendar {
+    let mut cal = basic_calendar();
+    cal.add_holidays([d(2024, 6, 19), d(2024, 7, 4)]);
+    cal
+}
+
+fn fixings(observations: &[RfrObservation]) -> Vec<(NaiveDate, u32)> {
+    observations.iter().map(|obs| (obs.fixing_date, obs.days)).collect()
+}
+
+// ── Observation shift ────────────────────────────────────────────────────────
+
+#[test]
+fn observation_shift_test() {
+    let cal = usd();
+    assert_eq!(observation_shift(&d(2024, 6, 20), &d(2024, 7, 5), 2, &cal), Some((d(2024, 6, 17), d(2024, 7, 2))));
+    assert_eq!(observation_shift(&d(2024, 6, 20), &d(2024, 7, 5), 0, &cal), Some((d(2024, 6, 20), d(2024, 7, 5))));
+    // Non-business boundaries move to the preceding business day first.
+    assert_eq!(observation_shift(&d(2024, 6, 22), &d(2024, 7, 4), 1, &cal), Some((d(2024, 6, 20), d(2024, 7, 2))));
+}
+
+// ── Daily observations ───────────────────────────────────────────────────────
+
+#[test]
+fn plain_observations_test() {
+    let cal = usd();
+    let obs = rfr_observations(&d(2024, 6, 14), &d(2024, 6, 24), &RfrConventions::default(), &cal).unwrap();
+    assert_eq!(fixings(&obs), [
+        (d(2024, 6, 14), 3),
+        (d(2024, 6, 17), 1),
+        (d(2024, 6, 18), 2),
+        (d(2024, 6, 20), 1),
+        (d(2024, 6, 21), 3),
+    ]);
+    assert_eq!(obs.iter().map(|o| o.days).sum::<u32>(), 10);
+    assert_eq!(rfr_observations(&d(2024, 6, 24), &d(2024, 6, 24), &RfrConventions::default(), &cal), Some(vec![]));
+}
+
+#[test]
+fn lookback_and_shift_test() {
+    let cal = usd();
+    let (start, end) = (d(2024, 6, 20), d(2024, 6, 25));
+
+    // Lookback: interest period weights, fixings two business days earlier.
+    let lookback = RfrConventions { lookback_days: 2, ..Default::default() };
+    let obs = rfr_observations(&start, &end, &lookback, &cal).unwrap();
+    assert_eq!(fixings(&obs), [(d(2024, 6, 17), 1), (d(2024, 6, 18), 3), (d(2024, 6, 20), 1)]);
+
+    // Observation shift: weights follow the shifted period 17 to 21 June,
+    // so the Juneteenth gap is weighted instead of the weekend.
+    let shifted = RfrConventions { lookback_days: 2, observation_shift: true, ..Default::default() };
+    let obs = rfr_observations(&start, &end, &shifted, &cal).unwrap();
+    assert_eq!(fixings(&obs), [(d(2024, 6, 17), 1), (d(2024, 6, 18), 2), (d(2024, 6, 20), 1)]);
+    assert_eq!(obs.iter().map(|o| o.days).sum::<u32>(), 4);
+}
+
+#[test]
+fn lockout_test() {
+    let cal = usd();
+    let (start, end) = (d(2024, 7, 1), d(2024, 7, 9));
+    let lockout = RfrConventions { lockout_days: 2, ..Default::default() };
+    let obs = rfr_observations(&start, &end, &lockout, &cal).unwrap();
+    // The rate cut-off date is Friday 5 July, two business days before the end.
+    assert_eq!(fixings(&obs), [
+        (d(2024, 7, 1), 1),
+        (d(2024, 7, 2), 1),
+        (d(2024, 7, 3), 2),
+        (d(2024, 7, 5), 3),
+        (d(2024, 7, 5), 1),
+    ]);
+
+    // A lockout of one day changes nothing.
+    let one = RfrConventions { lockout_days: 1, ..Default::default() };
+    assert_eq!(rfr_observations(&start, &end, &one, &cal), rfr_observations(&start, &end, &RfrConventions::default(), &cal));
+}
@@ -2,10 +2,18 @@
 // These tests validate schedule functionality including next-date calculations
 // and schedule generation with various frequency rules and adjustments.
 
-use chrono::{Datelike, NaiveDate};
+use std::sync::Arc;
+use std::thread;
+
+use chrono::{Datelike, NaiveDate, Weekday};
 use findates::calendar;
-use findates::conventions::{AdjustRule, Frequency};
-use findates::schedule::{schedule_next_adjusted, Schedule};
+use findates::conventions::{AdjustRule, DayCount, Frequency, RollConvention, Rounding, RoundingDirection, StubRule};
+use findates::schedule::{
+    align_legs, classify_periods, intersect, merge, nth_weekday_dates, schedule_next_adjusted, shift_by_business_days,
+    weekday_dates, AdjustedScheduleIterator, BackwardScheduleIterator, PeriodKind, Schedule, ScheduleIterator,
+    ScheduleOwned,
+};
+use findates::{DayCountError, ScheduleBuildError, ScheduleError};
 
 // Test setup with calendar and holidays
 struct ScheduleSetup {
@@ -32,36 +40,20 @@ fn daily_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
     // Create a new schedule
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModFollowing),
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::ModFollowing));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     // Even though this is falls on a next month, Next will force the next date for daily frequencies
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
     // With No Adjustment Rule, it will disregard the calendar and return the next date regardless if it is a good day or not.
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), None);
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 9, 30).unwrap()); // This is a Saturday.
                                                                     // Preceding Rule will also force the next day to be output for daily frequencies.
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Preceding),
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Preceding));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
     // Or even for nearest
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Nearest),
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Nearest));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
 }
@@ -72,65 +64,37 @@ fn weekly_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
     // Create a new weekly schedule
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), None);
     // Test for no adjustment, it should always return a date with the same weekday.
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.weekday(), res.weekday());
     // Even with an Adjustment rule, it is a Friday so weekday should be the same
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Preceding),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::Preceding));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.weekday(), res.weekday());
     // If the next date falls on a holiday, it will be adjusted according to adjust Rule
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 18).unwrap();
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Preceding),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::Preceding));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 12, 22).unwrap()); // This is a Friday
                                                                      // Adjusting with Following
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 18).unwrap();
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Following),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::Following));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 12, 27).unwrap());
     // With no calendar
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 18).unwrap();
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: None,
-        adjust_rule: Some(AdjustRule::Following),
-    };
+    let sch = Schedule::new(Frequency::Weekly, None, Some(AdjustRule::Following));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 12, 25).unwrap());
     assert_eq!(anchor.weekday(), res.weekday());
     // A Start date will not be adjusted, but the next date will
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 26).unwrap(); // Boxing day
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModFollowing),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::ModFollowing));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 23).unwrap(); // Saturday
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModFollowing),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::ModFollowing));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 12, 29).unwrap());
 }
@@ -141,20 +105,12 @@ fn biweekly_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     // Create a new weekly schedule
-    let sch = Schedule {
-        frequency: Frequency::Biweekly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Biweekly, Some(&cal), None);
     // Test for no adjustment, it should always return a date with the same weekday.
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.weekday(), res.weekday());
     // With adjustment
-    let sch = Schedule {
-        frequency: Frequency::Biweekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Nearest),
-    };
+    let sch = Schedule::new(Frequency::Biweekly, Some(&cal), Some(AdjustRule::Nearest));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_ne!(anchor.weekday(), res.weekday());
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 10, 13).unwrap());
@@ -166,20 +122,12 @@ fn fourweeks_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     // Create a new weekly schedule
-    let sch = Schedule {
-        frequency: Frequency::EveryFourthWeek,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::EveryFourthWeek, Some(&cal), None);
     // Test for no adjustment, it should always return a date with the same weekday.
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.weekday(), res.weekday());
     // With adjustment
-    let sch = Schedule {
-        frequency: Frequency::EveryFourthWeek,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::Nearest),
-    };
+    let sch = Schedule::new(Frequency::EveryFourthWeek, Some(&cal), Some(AdjustRule::Nearest));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_ne!(anchor.weekday(), res.weekday());
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 10, 27).unwrap());
@@ -191,11 +139,7 @@ fn monthly_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     // Create a new weekly schedule
-    let sch = Schedule {
-        frequency: Frequency::Monthly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Monthly, Some(&cal), None);
     // Test for no adjustment, it should always return a date with the same day.
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.day(), res.day());
@@ -207,11 +151,7 @@ fn monthly_next_test() {
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 11, 30).unwrap());
     // Now with an adjustment
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 18).unwrap();
-    let sch = Schedule {
-        frequency: Frequency::Monthly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModPreceding),
-    };
+    let sch = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::ModPreceding));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_ne!(anchor.day(), res.day());
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 3, 17).unwrap());
@@ -223,11 +163,7 @@ fn bimonthly_next_test() {
     let cal = setup.cal;
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     // Create a new weekly schedule
-    let sch = Schedule {
-        frequency: Frequency::Bimonthly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Bimonthly, Some(&cal), None);
     // Test for no adjustment, it should always return a date with the same day.
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.day(), res.day());
@@ -239,11 +175,7 @@ fn bimonthly_next_test() {
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
     // Now with an adjustment
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 2, 18).unwrap();
-    let sch = Schedule {
-        frequency: Frequency::Bimonthly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModPreceding),
-    };
+    let sch = Schedule::new(Frequency::Bimonthly, Some(&cal), Some(AdjustRule::ModPreceding));
     let res = schedule_next_adjusted(&sch, anchor).unwrap();
     assert_eq!(anchor.day(), res.day());
     assert_eq!(res, NaiveDate::from_ymd_opt(2023, 4, 18).unwrap());
@@ -260,11 +192,7 @@ fn daily_generator_test() {
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 10, 9).unwrap();
     // Create a new schedule
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), None);
     let dates = sch.generate(&anchor, &end).unwrap();
     // No adjustment, so expected is all dates from anchor to end.
     let dates_str = [
@@ -285,11 +213,7 @@ fn daily_generator_test() {
         .collect();
     assert_eq!(expected_dates, dates);
     // With an adjustment rule
-    let sch = Schedule {
-        frequency: Frequency::Daily,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModPreceding),
-    };
+    let sch = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::ModPreceding));
     let dates = sch.generate(&anchor, &end).unwrap();
     let dates_str = [
         "2023-09-29",
@@ -314,11 +238,7 @@ fn weekly_generator_test() {
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2023, 11, 5).unwrap();
     // Create a new schedule
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), None);
     let dates = sch.generate(&anchor, &end).unwrap();
     // No adjustment, so expected is all dates from anchor to end.
     let dates_str = [
@@ -335,11 +255,7 @@ fn weekly_generator_test() {
         .collect();
     assert_eq!(expected_dates, dates);
     // With an adjustment rule
-    let sch = Schedule {
-        frequency: Frequency::Weekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModFollowing),
-    };
+    let sch = Schedule::new(Frequency::Weekly, Some(&cal), Some(AdjustRule::ModFollowing));
     let dates = sch.generate(&anchor, &end).unwrap();
     let dates_str = [
         "2023-09-29",
@@ -363,11 +279,7 @@ fn biweekly_generator_test() {
     let anchor: NaiveDate = NaiveDate::from_ymd_opt(2023, 11, 26).unwrap();
     let end: NaiveDate = NaiveDate::from_ymd_opt(2024, 2, 5).unwrap();
     // Create a new schedule
-    let sch = Schedule {
-        frequency: Frequency::Biweekly,
-        calendar: Some(&cal),
-        adjust_rule: None,
-    };
+    let sch = Schedule::new(Frequency::Biweekly, Some(&cal), None);
     let dates = sch.generate(&anchor, &end).unwrap();
     // No adjustment, so expected is all dates from anchor to end.
     let dates_str = [
@@ -384,11 +296,7 @@ fn biweekly_generator_test() {
         .collect();
     assert_eq!(expected_dates, dates);
     // With an adjustment rule
-    let sch = Schedule {
-        frequency: Frequency::Biweekly,
-        calendar: Some(&cal),
-        adjust_rule: Some(AdjustRule::ModPreceding),
-    };
+    let sch = Schedule::new(Frequency::Biweekly, Some(&cal), Some(AdjustRule::ModPreceding));
     let dates = sch.generate(&anchor, &end).unwrap();
     let dates_str = [
         "2023-11-24",
@@ -405,6 +313,56 @@ fn biweekly_generator_test() {
     assert_eq!(expected_dates, dates);
 }
 
+// ============================================================================
+// Parametric Frequency Tests
+// ============================================================================
+
+#[test]
+fn every_n_weeks_treasury_bill_test() {
+    // 13-week bill auctions roll every 91 days, always on the same weekday.
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let sched = Schedule::new(Frequency::EveryNWeeks(13), None, None);
+    let dates = sched.generate(&d(2024, 1, 4), &d(2024, 12, 31)).unwrap();
+    assert_eq!(dates, [d(2024, 1, 4), d(2024, 4, 4), d(2024, 7, 4), d(2024, 10, 3)]);
+    assert!(dates.iter().all(|date| date.weekday() == Weekday::Thu));
+}
+
+#[test]
+fn every_n_days_tiie_test() {
+    // TIIE swaps pay every 28 days, adjusted to the following business day.
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::EveryNDays(28), Some(&setup.cal), Some(AdjustRule::Following));
+    let dates = sched.generate(&d(2023, 11, 28), &d(2024, 2, 1)).unwrap();
+    // 26 Dec is Boxing Day; the next roll steps from the nominal date again.
+    assert_eq!(dates, [d(2023, 11, 28), d(2023, 12, 27), d(2024, 1, 23)]);
+    assert_eq!(schedule_next_adjusted(&sched, d(2023, 12, 27)), Some(d(2024, 1, 24)));
+}
+
+#[test]
+fn every_n_months_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let sched = Schedule::new(Frequency::EveryNMonths(5), None, None);
+    let dates = sched.generate(&d(2024, 1, 31), &d(2025, 1, 31)).unwrap();
+    assert_eq!(dates, [d(2024, 1, 31), d(2024, 6, 30), d(2024, 11, 30)]);
+
+    // The end-of-month convention applies as for the named month frequencies.
+    let sched = Schedule::new(Frequency::EveryNMonths(5), None, None).with_eom(true);
+    let dates = sched.generate(&d(2024, 2, 29), &d(2025, 1, 31)).unwrap();
+    assert_eq!(dates, [d(2024, 2, 29), d(2024, 7, 31), d(2024, 12, 31)]);
+}
+
+#[test]
+fn zero_count_frequency_test() {
+    // A zero-length period has no next date.
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    for frequency in [Frequency::EveryNMonths(0), Frequency::EveryNWeeks(0), Frequency::EveryNDays(0)] {
+        let sched = Schedule::new(frequency, None, None);
+        assert_eq!(sched.generate(&d(2024, 1, 1), &d(2024, 6, 1)).unwrap(), [d(2024, 1, 1)]);
+        assert_eq!(schedule_next_adjusted(&sched, d(2024, 1, 1)), None);
+    }
+}
+
 // ============================================================================
 // Frequency::Zero Tests
 // ============================================================================
@@ -414,11 +372,7 @@ fn frequency_zero_generate_returns_end_date_test() {
     // Zero-coupon bonds have only one cash flow at maturity.
     let anchor = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
     let end = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
-    let sched = Schedule {
-        frequency: Frequency::Zero,
-        calendar: None,
-        adjust_rule: None,
-    };
+    let sched = Schedule::new(Frequency::Zero, None, None);
     let dates = sched.generate(&anchor, &end).unwrap();
     // Should return only the end date, not the anchor
     assert_eq!(dates.len(), 1);
@@ -432,11 +386,7 @@ fn frequency_zero_generate_with_adjustment_test() {
     let setup = ScheduleSetup::new();
     let anchor = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
     let end = NaiveDate::from_ymd_opt(2025, 3, 15).unwrap(); // Saturday
-    let sched = Schedule {
-        frequency: Frequency::Zero,
-        calendar: Some(&setup.cal),
-        adjust_rule: Some(AdjustRule::Following),
-    };
+    let sched = Schedule::new(Frequency::Zero, Some(&setup.cal), Some(AdjustRule::Following));
     let dates = sched.generate(&anchor, &end).unwrap();
     // Should return only the adjusted end date (Monday 2025-03-17)
     assert_eq!(dates.len(), 1);
@@ -451,23 +401,15 @@ fn frequency_zero_generate_with_adjustment_test() {
 fn generate_end_before_anchor_returns_err_test() {
     let anchor = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
     let before = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
-    let sched = Schedule {
-        frequency: Frequency::Monthly,
-        calendar: None,
-        adjust_rule: None,
-    };
-    assert!(sched.generate(&anchor, &before).is_err());
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(sched.generate(&anchor, &before), Err(ScheduleError::InvalidDateRange));
 }
 
 #[test]
 fn generate_end_equal_anchor_returns_err_test() {
     let anchor = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
-    let sched = Schedule {
-        frequency: Frequency::Monthly,
-        calendar: None,
-        adjust_rule: None,
-    };
-    assert!(sched.generate(&anchor, &anchor).is_err());
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(sched.generate(&anchor, &anchor), Err(ScheduleError::InvalidDateRange));
 }
 
 // ============================================================================
@@ -528,3 +470,1346 @@ fn end_of_month_weekend_adjustment_test() {
 }
 
 // ============================================================================
+
+// ============================================================================
+// End-of-Month Roll Tests
+// ============================================================================
+
+#[test]
+fn eom_quarterly_from_february_test() {
+    let anchor = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 11, 30).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None).with_eom(true);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 8, 31).unwrap(),
+            end,
+        ]
+    );
+    // Without the convention the 28th is repeated
+    let sched = Schedule::new(Frequency::Quarterly, None, None);
+    let dates = sched.generate(&anchor, &end).unwrap();
+    assert_eq!(dates[1], NaiveDate::from_ymd_opt(2023, 5, 28).unwrap());
+    assert_eq!(dates.len(), 4);
+}
+
+#[test]
+fn eom_last_business_day_anchor_test() {
+    // 2023-09-29 is a Friday and the last business day of September
+    let setup = ScheduleSetup::new();
+    let anchor = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+    let sched =
+        Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::Following)).with_eom(true);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 11, 30).unwrap(),
+            // 31 December is a Sunday: month-end rolls stay in the month
+            NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn eom_unadjusted_keeps_calendar_month_end_test() {
+    let setup = ScheduleSetup::new();
+    let anchor = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+    let sched =
+        Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::Unadjusted)).with_eom(true);
+    assert_eq!(sched.generate(&anchor, &end).unwrap(), vec![anchor, end]);
+}
+
+#[test]
+fn eom_ignored_for_mid_month_anchor_test() {
+    let anchor = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None).with_eom(true);
+    let dates = sched.generate(&anchor, &end).unwrap();
+    assert!(dates.iter().all(|d| d.day() == 15));
+}
+
+#[test]
+fn eom_ignored_for_weekly_test() {
+    let anchor = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    let sched = Schedule::new(Frequency::Weekly, None, None).with_eom(true);
+    assert_eq!(
+        schedule_next_adjusted(&sched, anchor),
+        NaiveDate::from_ymd_opt(2023, 2, 7)
+    );
+}
+
+#[test]
+fn eom_iter_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None).with_eom(true);
+    let dates: Vec<_> = sched.iter(anchor).take(3).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 7, 31).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn eom_annual_from_leap_day_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let sched = Schedule::new(Frequency::Annual, None, None).with_eom(true);
+    assert_eq!(
+        schedule_next_adjusted(&sched, anchor),
+        NaiveDate::from_ymd_opt(2025, 2, 28)
+    );
+}
+
+// ============================================================================
+// Roll Convention Tests
+// ============================================================================
+
+#[test]
+fn roll_day_of_month_test() {
+    // Day(31) clamps to shorter months and recovers afterwards
+    let anchor = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None).with_roll(RollConvention::Day(31));
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 30).unwrap(),
+            end,
+        ]
+    );
+}
+
+#[test]
+fn roll_end_of_month_test() {
+    // Unlike the eom flag, an explicit roll applies to a mid-month anchor
+    let anchor = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None).with_roll(RollConvention::EndOfMonth);
+    let dates: Vec<_> = sched.iter(anchor).take(2).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 8, 31).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn roll_imm_test() {
+    let anchor = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None).with_roll(RollConvention::Imm);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 19).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 18).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 18).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn roll_cds_test() {
+    let setup = ScheduleSetup::new();
+    let anchor = NaiveDate::from_ymd_opt(2025, 9, 22).unwrap();
+    let end = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::Following))
+        .with_roll(RollConvention::Cds);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            // 20 December 2025 is a Saturday
+            NaiveDate::from_ymd_opt(2025, 12, 22).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 20).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn roll_day_of_week_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(); // Friday
+    let sched = Schedule::new(Frequency::Weekly, None, None)
+        .with_roll(RollConvention::DayOfWeek(Weekday::Mon));
+    let dates: Vec<_> = sched.iter(anchor).take(3).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 25).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn roll_last_business_day_test() {
+    // Month-end NAV dates around Good Friday 2024
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 3, 29)]);
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::Following))
+        .with_roll(RollConvention::LastBusinessDay);
+    let expected = vec![d(2024, 1, 31), d(2024, 2, 29), d(2024, 3, 28), d(2024, 4, 30), d(2024, 5, 31), d(2024, 6, 28)];
+    assert_eq!(sched.generate(&d(2024, 1, 31), &d(2024, 6, 30)).unwrap(), expected);
+    assert_eq!(sched.iter(d(2024, 1, 31)).take(5).collect::<Vec<_>>(), expected[1..]);
+    assert_eq!(
+        sched.generate_backward(&d(2024, 1, 31), &d(2024, 6, 28)).unwrap(),
+        expected
+    );
+
+    // Without a calendar every day is a business day
+    let sched = Schedule::new(Frequency::Monthly, None, None).with_roll(RollConvention::LastBusinessDay);
+    assert_eq!(schedule_next_adjusted(&sched, d(2024, 5, 31)), Some(d(2024, 6, 30)));
+}
+
+#[test]
+fn roll_first_business_day_of_quarter_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 1, 1), d(2024, 4, 1), d(2025, 1, 1)]);
+    let sched = Schedule::new(Frequency::Quarterly, Some(&cal), None).with_roll(RollConvention::FirstBusinessDay);
+    assert_eq!(
+        sched.generate(&d(2024, 1, 2), &d(2025, 1, 31)).unwrap(),
+        vec![d(2024, 1, 2), d(2024, 4, 2), d(2024, 7, 1), d(2024, 10, 1), d(2025, 1, 2)]
+    );
+}
+
+#[test]
+fn roll_nth_business_day_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let cal = calendar::basic_calendar();
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), None).with_roll(RollConvention::NthBusinessDay(5));
+    assert_eq!(
+        sched.generate(&d(2024, 1, 5), &d(2024, 4, 30)).unwrap(),
+        vec![d(2024, 1, 5), d(2024, 2, 7), d(2024, 3, 7), d(2024, 4, 5)]
+    );
+    assert!(sched.contains(&d(2024, 1, 5), &d(2024, 6, 7)));
+    assert_eq!(sched.next_after(&d(2024, 1, 5), &d(2024, 6, 7)), Some(d(2024, 7, 5)));
+
+    // No month has 24 weekdays, so nothing follows the anchor
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), None).with_roll(RollConvention::NthBusinessDay(24));
+    assert_eq!(sched.generate(&d(2024, 1, 5), &d(2024, 4, 30)).unwrap(), vec![d(2024, 1, 5)]);
+}
+
+#[test]
+fn roll_day_of_week_after_adjustment_test() {
+    // Christmas and New Year's Day 2025 fall on Wednesdays: iter steps from
+    // the adjusted Thursday but stays on Wednesdays.
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 12, 25), d(2025, 1, 1)]);
+    let expected = vec![d(2024, 12, 18), d(2024, 12, 26), d(2025, 1, 2), d(2025, 1, 8)];
+    for rule in [AdjustRule::Following, AdjustRule::ModFollowing] {
+        let sched = Schedule::new(Frequency::Weekly, Some(&cal), Some(rule))
+            .with_roll(RollConvention::DayOfWeek(Weekday::Wed));
+        assert_eq!(sched.iter(d(2024, 12, 11)).take(4).collect::<Vec<_>>(), expected);
+        assert_eq!(sched.iter_adjusted(d(2024, 12, 11)).take(4).collect::<Vec<_>>(), expected);
+    }
+    let sched = Schedule::new(Frequency::Biweekly, Some(&cal), Some(AdjustRule::Preceding))
+        .with_roll(RollConvention::DayOfWeek(Weekday::Wed));
+    assert_eq!(
+        sched.iter(d(2024, 12, 11)).take(3).collect::<Vec<_>>(),
+        vec![d(2024, 12, 24), d(2025, 1, 8), d(2025, 1, 22)]
+    );
+}
+
+#[test]
+fn roll_ignored_for_incompatible_frequency_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let sched = Schedule::new(Frequency::Daily, None, None).with_roll(RollConvention::Day(15));
+    assert_eq!(
+        schedule_next_adjusted(&sched, anchor),
+        NaiveDate::from_ymd_opt(2024, 3, 2)
+    );
+    let sched = Schedule::new(Frequency::Monthly, None, None)
+        .with_roll(RollConvention::DayOfWeek(Weekday::Mon));
+    assert_eq!(
+        schedule_next_adjusted(&sched, anchor),
+        NaiveDate::from_ymd_opt(2024, 4, 1)
+    );
+}
+
+// ============================================================================
+// Backward Generation Tests
+// ============================================================================
+
+#[test]
+fn generate_backward_front_stub_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    let termination = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    let sched = Schedule::new(Frequency::Semiannual, None, None);
+    assert_eq!(
+        sched.generate_backward(&effective, &termination).unwrap(),
+        vec![
+            effective,
+            NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 15).unwrap(),
+            termination,
+        ]
+    );
+    // Forward generation puts the stub at the back instead
+    let forward = sched.generate(&effective, &termination).unwrap();
+    assert_eq!(forward[1], NaiveDate::from_ymd_opt(2024, 8, 10).unwrap());
+}
+
+#[test]
+fn generate_backward_no_drift_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let termination = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None);
+    assert_eq!(
+        sched.generate_backward(&effective, &termination).unwrap(),
+        vec![
+            effective,
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            termination,
+        ]
+    );
+}
+
+#[test]
+fn generate_backward_eom_from_termination_test() {
+    let effective = NaiveDate::from_ymd_opt(2023, 5, 10).unwrap();
+    let termination = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None).with_eom(true);
+    assert_eq!(
+        sched.generate_backward(&effective, &termination).unwrap(),
+        vec![
+            effective,
+            NaiveDate::from_ymd_opt(2023, 5, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 8, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 11, 30).unwrap(),
+            termination,
+        ]
+    );
+}
+
+#[test]
+fn generate_backward_adjusted_test() {
+    let setup = ScheduleSetup::new();
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let termination = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::Following));
+    assert_eq!(
+        sched.generate_backward(&effective, &termination).unwrap(),
+        vec![
+            effective,
+            NaiveDate::from_ymd_opt(2024, 1, 25).unwrap(),
+            // 25 February 2024 is a Sunday
+            NaiveDate::from_ymd_opt(2024, 2, 26).unwrap(),
+            termination,
+        ]
+    );
+}
+
+#[test]
+fn generate_backward_invalid_range_test() {
+    let date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(sched.generate_backward(&date, &date), Err(ScheduleError::InvalidDateRange));
+}
+
+#[test]
+fn generate_backward_out_of_range_test() {
+    // A holiday on the last representable date cannot be rolled Following.
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let effective = NaiveDate::MAX.pred_opt().unwrap();
+    for frequency in [Frequency::Monthly, Frequency::Zero] {
+        let sched = Schedule::new(frequency, Some(&cal), Some(AdjustRule::Following));
+        assert_eq!(sched.generate_backward(&effective, &NaiveDate::MAX), Err(ScheduleError::OutOfRange));
+    }
+}
+
+#[test]
+fn generate_backward_zero_frequency_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let termination = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let sched = Schedule::new(Frequency::Zero, None, None);
+    assert_eq!(sched.generate_backward(&effective, &termination).unwrap(), vec![termination]);
+    assert_eq!(sched.iter_backward(termination).next(), None);
+}
+
+#[test]
+fn iter_backward_skips_dates_adjusted_past_anchor_test() {
+    // Sunday and Saturday both adjust Following onto the Monday anchor
+    let setup = ScheduleSetup::new();
+    let monday = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+    let sched = Schedule::new(Frequency::Daily, Some(&setup.cal), Some(AdjustRule::Following));
+    let dates: Vec<_> = sched.iter_backward(monday).take(2).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 3, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 7).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn iter_backward_stops_at_min_date_test() {
+    let anchor = NaiveDate::MIN.checked_add_days(chrono::Days::new(3)).unwrap();
+    let sched = Schedule::new(Frequency::Daily, None, None);
+    let mut it = sched.iter_backward(anchor);
+    assert_eq!(it.by_ref().count(), 3);
+    assert_eq!(it.next(), None);
+}
+
+// ============================================================================
+// Bounded and Fused Iterator Tests
+// ============================================================================
+
+#[test]
+fn iter_stops_at_max_date_test() {
+    let anchor = NaiveDate::MAX.checked_sub_days(chrono::Days::new(5)).unwrap();
+    let sched = Schedule::new(Frequency::Daily, None, None);
+    let mut it = sched.iter(anchor);
+    assert_eq!(it.by_ref().count(), 5);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn iter_adjustment_past_max_date_returns_none_test() {
+    // Following cannot find a business day after a holiday on the last date
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let anchor = NaiveDate::MAX.pred_opt().unwrap();
+    let sched = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Following));
+    let mut it = sched.iter(anchor);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn iter_between_test() {
+    let setup = ScheduleSetup::new();
+    let start = NaiveDate::from_ymd_opt(2023, 12, 4).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let sched = Schedule::new(Frequency::Weekly, Some(&setup.cal), Some(AdjustRule::Following));
+    let dates: Vec<_> = sched.iter_between(start, end).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2023, 12, 11).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 18).unwrap(),
+            // Christmas and Boxing Day are holidays
+            NaiveDate::from_ymd_opt(2023, 12, 27).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn iter_between_includes_end_date_test() {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+    let sched = Schedule::new(Frequency::EndOfMonth, None, None);
+    assert_eq!(sched.iter_between(start, end).last(), Some(end));
+    assert_eq!(sched.iter_between(start, end).count(), 3);
+}
+
+#[test]
+fn iter_between_empty_range_test() {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(sched.iter_between(date, date).next(), None);
+    assert_eq!(
+        sched.iter_between(date, date.pred_opt().unwrap()).next(),
+        None
+    );
+}
+
+// ============================================================================
+// Adjusted Iterator Tests
+// ============================================================================
+
+#[test]
+fn iter_adjusted_preceding_does_not_accumulate_test() {
+    let cal = calendar::basic_calendar();
+    let anchor = NaiveDate::from_ymd_opt(2024, 8, 10).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::Preceding));
+    let expected = vec![
+        NaiveDate::from_ymd_opt(2024, 9, 10).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 10, 10).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 11, 8).unwrap(), // Sunday pulled back
+        NaiveDate::from_ymd_opt(2024, 12, 10).unwrap(),
+    ];
+    assert_eq!(sched.iter_adjusted(anchor).take(4).collect::<Vec<_>>(), expected);
+    // iter() steps from 8 November and lands on 6 December instead
+    let drifted: Vec<_> = sched.iter(anchor).take(4).collect();
+    assert_eq!(drifted[3], NaiveDate::from_ymd_opt(2024, 12, 6).unwrap());
+}
+
+#[test]
+fn iter_adjusted_preceding_pulled_onto_anchor_test() {
+    // Saturday and Sunday both move back onto the Friday anchor
+    let cal = calendar::basic_calendar();
+    let friday = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let sched = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Preceding));
+    let dates: Vec<_> = sched.iter_adjusted(friday).take(3).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 19).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn iter_adjusted_matches_generate_test() {
+    let setup = ScheduleSetup::new();
+    let anchor = NaiveDate::from_ymd_opt(2023, 9, 8).unwrap(); // Friday
+    let end = NaiveDate::from_ymd_opt(2024, 9, 8).unwrap();
+    for freq in [Frequency::Daily, Frequency::Weekly, Frequency::Monthly, Frequency::Quarterly] {
+        for rule in [
+            AdjustRule::Following,
+            AdjustRule::ModFollowing,
+            AdjustRule::Preceding,
+            AdjustRule::ModPreceding,
+            AdjustRule::Nearest,
+        ] {
+            let sched = Schedule::new(freq, Some(&setup.cal), Some(rule));
+            let generated = sched.generate(&anchor, &end).unwrap();
+            let adjusted_end = *generated.last().unwrap();
+            let iterated: Vec<_> = sched
+                .iter_adjusted(anchor)
+                .take_while(|d| *d <= adjusted_end)
+                .collect();
+            assert_eq!(iterated, generated[1..], "{freq} {rule}");
+        }
+    }
+}
+
+#[test]
+fn next_adjusted_matches_free_function_test() {
+    let setup = ScheduleSetup::new();
+    let anchor = NaiveDate::from_ymd_opt(2023, 12, 22).unwrap();
+    let sched = Schedule::new(Frequency::Daily, Some(&setup.cal), Some(AdjustRule::Following));
+    assert_eq!(sched.next_adjusted(anchor), schedule_next_adjusted(&sched, anchor));
+    assert_eq!(sched.next_adjusted(anchor), NaiveDate::from_ymd_opt(2023, 12, 27));
+}
+
+// ============================================================================
+// Drift Regression Tests
+// ============================================================================
+
+#[test]
+fn generate_month_end_anchor_does_not_drift_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn generate_quarterly_31st_does_not_drift_test() {
+    let anchor = NaiveDate::from_ymd_opt(2023, 8, 31).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 8, 31).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None);
+    let dates = sched.generate(&anchor, &end).unwrap();
+    assert_eq!(dates[1], NaiveDate::from_ymd_opt(2023, 11, 30).unwrap());
+    assert_eq!(dates[2], NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    assert_eq!(dates[3], NaiveDate::from_ymd_opt(2024, 5, 31).unwrap());
+    assert_eq!(dates[4], end);
+}
+
+#[test]
+fn generate_annual_leap_day_anchor_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let end = NaiveDate::from_ymd_opt(2028, 3, 1).unwrap();
+    let sched = Schedule::new(Frequency::Annual, None, None);
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2027, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2028, 2, 29).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn generate_adjusted_month_end_does_not_drift_test() {
+    // 30 November 2024 is a Saturday; December must still land on the 31st
+    let cal = calendar::basic_calendar();
+    let anchor = NaiveDate::from_ymd_opt(2024, 10, 31).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::ModFollowing));
+    assert_eq!(
+        sched.generate(&anchor, &end).unwrap(),
+        vec![
+            anchor,
+            NaiveDate::from_ymd_opt(2024, 11, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn iter_adjusted_month_end_does_not_drift_test() {
+    let anchor = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    let dates: Vec<_> = sched.iter_adjusted(anchor).take(3).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 30).unwrap(),
+        ]
+    );
+}
+
+// ============================================================================
+// Coupon Period Tests
+// ============================================================================
+
+#[test]
+fn coupon_periods_back_stub_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2024, 9, 15).unwrap();
+    let sched = Schedule::new(Frequency::Semiannual, None, None);
+    let periods = sched
+        .coupon_periods(&effective, &maturity, DayCount::ActActICMA, 0)
+        .unwrap();
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].accrual_end, NaiveDate::from_ymd_opt(2024, 7, 15).unwrap());
+    assert_eq!(periods[0].year_fraction, 0.5);
+    // 62 days of a 184-day reference period running to 15 January 2025
+    assert_eq!(periods[1].accrual_start, NaiveDate::from_ymd_opt(2024, 7, 15).unwrap());
+    assert_eq!(periods[1].accrual_end, maturity);
+    assert!((periods[1].year_fraction - 62.0 / 184.0 / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn coupon_periods_front_stub_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let (effective, maturity) = (d(2024, 1, 15), d(2024, 9, 15));
+    let periods = |stub| {
+        Schedule::new(Frequency::Semiannual, None, None)
+            .with_stub(stub)
+            .coupon_periods(&effective, &maturity, DayCount::ActActICMA, 0)
+            .unwrap()
+    };
+
+    // Rolled back from maturity: 60 days of the 182-day period from
+    // 15 September 2023, then a regular period.
+    let short = periods(StubRule::ShortFront);
+    assert_eq!(short.len(), 2);
+    assert_eq!((short[0].accrual_start, short[0].accrual_end), (effective, d(2024, 3, 15)));
+    assert!((short[0].year_fraction - 60.0 / 182.0 / 2.0).abs() < 1e-12);
+    assert_eq!(short[1].year_fraction, 0.5);
+
+    // A long stub spans both, with the same year fraction.
+    let long = periods(StubRule::LongFront);
+    assert_eq!(long.len(), 1);
+    assert_eq!((long[0].accrual_start, long[0].accrual_end), (effective, maturity));
+    assert!((long[0].year_fraction - (60.0 / 182.0 / 2.0 + 0.5)).abs() < 1e-12);
+
+    let long_back = periods(StubRule::LongBack);
+    assert_eq!(long_back.len(), 1);
+    assert!((long_back[0].year_fraction - (0.5 + 62.0 / 184.0 / 2.0)).abs() < 1e-12);
+    let default = Schedule::new(Frequency::Semiannual, None, None)
+        .coupon_periods(&effective, &maturity, DayCount::ActActICMA, 0)
+        .unwrap();
+    assert_eq!(periods(StubRule::ShortBack), default);
+}
+
+#[test]
+fn stub_rule_without_stub_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let (effective, maturity) = (d(2023, 3, 25), d(2024, 3, 25));
+    let expected = sched.payment_dates(&effective, &maturity, 0).unwrap();
+    assert_eq!(expected.len(), 4);
+    for stub in [StubRule::ShortFront, StubRule::LongFront, StubRule::ShortBack, StubRule::LongBack] {
+        assert_eq!(sched.with_stub(stub).payment_dates(&effective, &maturity, 0).unwrap(), expected);
+    }
+}
+
+#[test]
+fn coupon_periods_adjusted_with_pay_lag_test() {
+    let setup = ScheduleSetup::new();
+    let effective = NaiveDate::from_ymd_opt(2023, 9, 25).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let periods = sched
+        .coupon_periods(&effective, &maturity, DayCount::Act360, 2)
+        .unwrap();
+    let ends: Vec<_> = periods.iter().map(|p| p.accrual_end).collect();
+    let payments: Vec<_> = periods.iter().map(|p| p.payment_date).collect();
+    assert_eq!(
+        ends,
+        vec![
+            NaiveDate::from_ymd_opt(2023, 10, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 11, 27).unwrap(), // 25 November is a Saturday
+            NaiveDate::from_ymd_opt(2023, 12, 27).unwrap(), // Christmas and Boxing Day
+        ]
+    );
+    assert_eq!(
+        payments,
+        vec![
+            NaiveDate::from_ymd_opt(2023, 10, 27).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 11, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(),
+        ]
+    );
+    // Accrual periods chain without gaps
+    assert_eq!(periods[1].accrual_start, periods[0].accrual_end);
+    assert_eq!(periods[1].year_fraction, 33.0 / 360.0);
+}
+
+#[test]
+fn coupon_periods_zero_frequency_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let sched = Schedule::new(Frequency::Zero, None, None);
+    let periods = sched
+        .coupon_periods(&effective, &maturity, DayCount::Act365Fixed, 0)
+        .unwrap();
+    assert_eq!(periods.len(), 1);
+    assert_eq!(periods[0].accrual_start, effective);
+    assert_eq!(periods[0].payment_date, maturity);
+    assert_eq!(periods[0].year_fraction, 366.0 / 365.0);
+}
+
+#[test]
+fn coupon_periods_errors_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let sched = Schedule::new(Frequency::Quarterly, None, None);
+    assert_eq!(
+        sched.coupon_periods(&maturity, &effective, DayCount::Act360, 0),
+        Err(ScheduleError::InvalidDateRange)
+    );
+    assert_eq!(
+        sched.coupon_periods(&effective, &maturity, DayCount::Bd252, 0),
+        Err(ScheduleError::DayCount(DayCountError::MissingCalendar))
+    );
+}
+
+#[test]
+fn payment_dates_match_coupon_periods_test() {
+    let setup = ScheduleSetup::new();
+    let effective = NaiveDate::from_ymd_opt(2023, 9, 25).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    for lag in 0..4 {
+        let periods = sched.coupon_periods(&effective, &maturity, DayCount::Act360, lag).unwrap();
+        let payments = sched.payment_dates(&effective, &maturity, lag).unwrap();
+        let expected: Vec<_> = periods.iter().map(|p| p.payment_date).collect();
+        assert_eq!(payments, expected);
+    }
+}
+
+#[test]
+fn payment_dates_without_calendar_test() {
+    let effective = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let maturity = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    assert_eq!(
+        sched.payment_dates(&effective, &maturity, 1).unwrap(),
+        vec![
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+        ]
+    );
+    assert_eq!(
+        sched.payment_dates(&maturity, &effective, 1),
+        Err(ScheduleError::InvalidDateRange)
+    );
+}
+
+#[test]
+fn coupon_periods_rounding_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let cal = calendar::basic_calendar();
+    let (effective, maturity) = (d(2024, 1, 2), d(2024, 4, 2));
+    let truncated = Rounding::new(8, RoundingDirection::Down);
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::ModFollowing));
+    let plain = sched.coupon_periods(&effective, &maturity, DayCount::Bd252, 0).unwrap();
+    let rounded = sched.with_rounding(truncated).coupon_periods(&effective, &maturity, DayCount::Bd252, 0).unwrap();
+
+    // Dates are unchanged; only the year fractions are truncated.
+    assert_eq!(rounded.len(), plain.len());
+    for (rounded, plain) in rounded.iter().zip(&plain) {
+        assert_eq!(rounded.accrual_start, plain.accrual_start);
+        assert_eq!(rounded.payment_date, plain.payment_date);
+        assert_eq!(rounded.year_fraction, truncated.round(plain.year_fraction));
+        assert!(rounded.year_fraction <= plain.year_fraction);
+    }
+    // 23 business days from 2 January to 2 February: 23 / 252 = 0.0912698412...
+    assert_eq!(rounded[0].year_fraction, 0.09126984);
+
+    let built = Schedule::builder()
+        .frequency(Frequency::Monthly)
+        .calendar(&cal)
+        .adjust_rule(AdjustRule::ModFollowing)
+        .rounding(truncated)
+        .build()
+        .unwrap();
+    assert_eq!(built, sched.with_rounding(truncated));
+    let owned = ScheduleOwned::from(&built);
+    assert_eq!(owned.coupon_periods(&effective, &maturity, DayCount::Bd252, 0).unwrap(), rounded);
+}
+
+#[test]
+fn fixing_dates_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let mut london = calendar::basic_calendar();
+    london.add_holidays([d(2023, 12, 25), d(2023, 12, 26), d(2024, 1, 1), d(2024, 3, 29), d(2024, 4, 1)]);
+    let sched = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let effective = d(2023, 10, 2);
+    let maturity = d(2024, 7, 2);
+
+    // LIBOR-style: two London business days before each accrual start.
+    let fixings = sched.fixing_dates(&effective, &maturity, 2, &london).unwrap();
+    assert_eq!(fixings, [d(2023, 9, 28), d(2023, 12, 28), d(2024, 3, 27)]);
+
+    // One fixing per coupon period, each on or before its accrual start.
+    let periods = sched.coupon_periods(&effective, &maturity, DayCount::Act360, 0).unwrap();
+    assert_eq!(fixings.len(), periods.len());
+    for lag in 0..5 {
+        let fixings = sched.fixing_dates(&effective, &maturity, lag, &london).unwrap();
+        for (fixing, period) in fixings.iter().zip(&periods) {
+            assert!(*fixing <= period.accrual_start);
+            assert!(london.is_business_day(fixing));
+        }
+    }
+
+    // With no lag, a start on a fixing holiday fixes on the preceding day.
+    let sched = Schedule::new(Frequency::Monthly, None, None);
+    let fixings = sched.fixing_dates(&d(2023, 11, 26), &d(2024, 1, 26), 0, &london).unwrap();
+    assert_eq!(fixings, [d(2023, 11, 24), d(2023, 12, 22)]);
+    assert_eq!(sched.fixing_dates(&maturity, &effective, 2, &london), Err(ScheduleError::InvalidDateRange));
+}
+
+// ============================================================================
+// Leg Alignment Tests
+// ============================================================================
+
+#[test]
+fn align_legs_fixed_float_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let fixed = Schedule::new(Frequency::Semiannual, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let floating = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let (effective, termination) = (d(2023, 6, 26), d(2025, 6, 26));
+
+    let periods = align_legs(&fixed, &floating, &effective, &termination).unwrap();
+    assert_eq!(periods.len(), 4);
+    // 26 December 2023 is Boxing Day, so both legs roll to the 27th.
+    assert_eq!(periods[0].leg_a, [(d(2023, 6, 26), d(2023, 12, 27))]);
+    assert_eq!(periods[0].leg_b, [(d(2023, 6, 26), d(2023, 9, 26)), (d(2023, 9, 26), d(2023, 12, 27))]);
+    for (period, coupon) in periods.iter().zip(fixed.coupon_periods(&effective, &termination, DayCount::Act360, 0).unwrap()) {
+        assert_eq!((period.start, period.end), (coupon.accrual_start, coupon.accrual_end));
+        assert_eq!(period.leg_b.len(), 2);
+        assert_eq!(period.leg_b.first().unwrap().0, period.start);
+        assert_eq!(period.leg_b.last().unwrap().1, period.end);
+    }
+}
+
+#[test]
+fn align_legs_nesting_either_way_test() {
+    // The monthly leg nests inside the quarterly one until the short back
+    // stub, where both legs have a single period.
+    let d = |m| NaiveDate::from_ymd_opt(2024, m, 15).unwrap();
+    let monthly = Schedule::new(Frequency::Monthly, None, None);
+    let quarterly = Schedule::new(Frequency::Quarterly, None, None);
+
+    let periods = align_legs(&monthly, &quarterly, &d(1), &d(8)).unwrap();
+    let shapes: Vec<_> = periods.iter().map(|p| (p.start, p.end, p.leg_a.len(), p.leg_b.len())).collect();
+    assert_eq!(shapes, [(d(1), d(4), 3, 1), (d(4), d(7), 3, 1), (d(7), d(8), 1, 1)]);
+}
+
+#[test]
+fn align_legs_mismatch_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let bimonthly = Schedule::new(Frequency::Bimonthly, None, None);
+    let quarterly = Schedule::new(Frequency::Quarterly, None, None);
+    let err = align_legs(&bimonthly, &quarterly, &d(2024, 1, 15), &d(2025, 1, 15)).unwrap_err();
+    assert_eq!(err, ScheduleError::MisalignedLegs { date: d(2024, 1, 15) });
+    assert_eq!(err.to_string(), "leg periods starting 2024-01-15 do not nest");
+
+    // The legs end on different adjusted dates: 15 June 2024 is a Saturday.
+    let setup = ScheduleSetup::new();
+    let adjusted = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::Following));
+    assert_eq!(
+        align_legs(&quarterly, &adjusted, &d(2023, 12, 15), &d(2024, 6, 15)),
+        Err(ScheduleError::MisalignedLegs { date: d(2024, 3, 15) })
+    );
+    assert_eq!(
+        align_legs(&quarterly, &adjusted, &d(2024, 6, 15), &d(2024, 3, 15)),
+        Err(ScheduleError::InvalidDateRange)
+    );
+}
+
+// ============================================================================
+// Generation Trace Tests
+// ============================================================================
+
+#[test]
+fn generate_with_trace_matches_generate_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let (anchor, end) = (d(2023, 9, 26), d(2024, 9, 26));
+
+    let trace = sched.generate_with_trace(&anchor, &end).unwrap();
+    let adjusted: Vec<NaiveDate> = trace.iter().map(|date| date.adjusted).collect();
+    assert_eq!(adjusted, sched.generate(&anchor, &end).unwrap());
+    for date in &trace {
+        assert_eq!(date.adjust_rule, Some(AdjustRule::ModFollowing));
+        assert_eq!(date.adjusted - date.unadjusted, chrono::Duration::days(date.adjustment_days));
+    }
+    assert_eq!((trace[0].unadjusted, trace[0].adjusted, trace[0].adjustment_days), (anchor, anchor, 0));
+    // Sunday 26 November and Boxing Day both move forward a day.
+    assert_eq!((trace[2].unadjusted, trace[2].adjusted, trace[2].adjustment_days), (d(2023, 11, 26), d(2023, 11, 27), 1));
+    assert_eq!((trace[3].unadjusted, trace[3].adjusted, trace[3].adjustment_days), (d(2023, 12, 26), d(2023, 12, 27), 1));
+}
+
+#[test]
+fn generate_with_trace_eom_rule_test() {
+    // Rolls to month ends report the Preceding rule that keeps them in their month.
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::Following)).with_eom(true);
+    let trace = sched.generate_with_trace(&d(2024, 2, 29), &d(2024, 9, 30)).unwrap();
+    assert_eq!(trace[0].adjust_rule, Some(AdjustRule::Following));
+    assert_eq!(trace[1].unadjusted, d(2024, 5, 31));
+    assert_eq!(trace[1].adjust_rule, Some(AdjustRule::Preceding));
+    // Saturday 31 August moves back to Friday.
+    assert_eq!(trace[2].adjusted, d(2024, 8, 30));
+    assert_eq!(trace[2].adjustment_days, -1);
+}
+
+#[test]
+fn generate_with_trace_zero_and_unadjusted_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let zero = Schedule::new(Frequency::Zero, Some(&setup.cal), Some(AdjustRule::Following));
+    let trace = zero.generate_with_trace(&d(2024, 3, 15), &d(2025, 3, 15)).unwrap();
+    assert_eq!(trace.len(), 1);
+    assert_eq!((trace[0].unadjusted, trace[0].adjusted, trace[0].adjustment_days), (d(2025, 3, 15), d(2025, 3, 17), 2));
+
+    // Without a calendar no rule is applied.
+    let sched = Schedule::new(Frequency::Weekly, None, Some(AdjustRule::Following));
+    let trace = sched.generate_with_trace(&d(2024, 3, 16), &d(2024, 3, 30)).unwrap();
+    assert!(trace.iter().all(|date| date.adjust_rule.is_none() && date.adjustment_days == 0));
+    assert_eq!(zero.generate_with_trace(&d(2024, 3, 15), &d(2024, 3, 15)), Err(ScheduleError::InvalidDateRange));
+}
+
+#[test]
+fn generate_with_trace_out_of_range_test() {
+    // A holiday on the last representable date cannot be rolled Following.
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let anchor = NaiveDate::MAX.pred_opt().unwrap();
+    for frequency in [Frequency::Daily, Frequency::Zero] {
+        let sched = Schedule::new(frequency, Some(&cal), Some(AdjustRule::Following));
+        assert_eq!(sched.generate_with_trace(&anchor, &NaiveDate::MAX), Err(ScheduleError::OutOfRange));
+        assert_eq!(sched.generate(&anchor, &NaiveDate::MAX), Err(ScheduleError::OutOfRange));
+    }
+}
+
+// ============================================================================
+// Occurrence Query Tests
+// ============================================================================
+
+#[test]
+fn occurrence_queries_match_generate_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    // Generated well past the last queried date, so every next date is listed.
+    let anchor = d(2023, 1, 31);
+    let end = d(2025, 12, 31);
+    for sched in [
+        Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing)).with_eom(true),
+        Schedule::new(Frequency::Weekly, Some(&setup.cal), Some(AdjustRule::Preceding)),
+        Schedule::new(Frequency::Quarterly, None, None).with_roll(RollConvention::Imm),
+    ] {
+        let dates = sched.generate(&anchor, &end).unwrap();
+        for date in anchor.iter_days().take_while(|date| *date < d(2024, 12, 1)) {
+            let next = dates.iter().find(|next| **next > date).copied();
+            let previous = dates.iter().rev().find(|previous| **previous < date).copied();
+            assert_eq!(sched.next_after(&anchor, &date), next, "{date}");
+            assert_eq!(sched.previous_before(&anchor, &date), previous, "{date}");
+            assert_eq!(sched.contains(&anchor, &date), dates.contains(&date), "{date}");
+        }
+    }
+}
+
+#[test]
+fn occurrence_queries_edge_cases_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    // Sunday anchor adjusted to Monday.
+    let anchor = d(2023, 12, 24);
+    let sched = Schedule::new(Frequency::Annual, Some(&setup.cal), Some(AdjustRule::Following));
+    assert!(!sched.contains(&anchor, &anchor));
+    assert!(sched.contains(&anchor, &d(2023, 12, 27)));
+    assert_eq!(sched.next_after(&anchor, &d(2000, 1, 1)), Some(d(2023, 12, 27)));
+    assert_eq!(sched.previous_before(&anchor, &d(2023, 12, 27)), None);
+
+    let zero = Schedule::new(Frequency::Zero, None, None);
+    assert!(zero.contains(&anchor, &anchor));
+    assert_eq!(zero.next_after(&anchor, &anchor), None);
+    assert_eq!(zero.previous_before(&anchor, &d(2030, 1, 1)), Some(anchor));
+
+    let last = NaiveDate::MAX;
+    let daily = Schedule::new(Frequency::Daily, None, None);
+    assert_eq!(daily.next_after(&last.pred_opt().unwrap(), &last), None);
+}
+
+#[test]
+fn occurrence_queries_out_of_range_test() {
+    // A holiday on the last representable date cannot be rolled Following.
+    let cal = calendar::Calendar::with_holidays([NaiveDate::MAX]);
+    let anchor = NaiveDate::MAX.pred_opt().unwrap();
+    let sched = Schedule::new(Frequency::Daily, Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(sched.next_after(&anchor, &anchor), None);
+    assert_eq!(sched.previous_before(&anchor, &NaiveDate::MAX), Some(anchor));
+    assert!(sched.contains(&anchor, &anchor));
+    assert!(!sched.contains(&anchor, &NaiveDate::MAX));
+}
+
+// ============================================================================
+// Schedule Arithmetic Tests
+// ============================================================================
+
+#[test]
+fn merge_and_intersect_test() {
+    let d = |m, day| NaiveDate::from_ymd_opt(2024, m, day).unwrap();
+    let a = [d(6, 1), d(1, 1), d(3, 1), d(3, 1)];
+    let b = [d(3, 1), d(12, 1), d(1, 1)];
+    assert_eq!(merge(&a, &b), [d(1, 1), d(3, 1), d(6, 1), d(12, 1)]);
+    assert_eq!(intersect(&a, &b), [d(1, 1), d(3, 1)]);
+    assert_eq!(intersect(&b, &a), intersect(&a, &b));
+    assert_eq!(merge(&a, &[]), [d(1, 1), d(3, 1), d(6, 1)]);
+    assert!(intersect(&a, &[]).is_empty());
+}
+
+#[test]
+fn shift_by_business_days_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    // Thursday 21 to Sunday 24 December 2023, out of order.
+    let dates = [d(2023, 12, 22), d(2023, 12, 23), d(2023, 12, 24), d(2023, 12, 21)];
+    let mut sorted = dates.to_vec();
+    sorted.sort();
+    assert_eq!(shift_by_business_days(&dates, 0, &setup.cal).unwrap(), sorted);
+    // The weekend and Friday all land after Christmas and Boxing Day.
+    assert_eq!(shift_by_business_days(&dates, 1, &setup.cal).unwrap(), [d(2023, 12, 22), d(2023, 12, 27)]);
+    assert_eq!(shift_by_business_days(&dates, -1, &setup.cal).unwrap(), [d(2023, 12, 20), d(2023, 12, 21), d(2023, 12, 22)]);
+    assert_eq!(shift_by_business_days(&dates, 3, &setup.cal).unwrap(), [d(2023, 12, 28), d(2023, 12, 29)]);
+    assert_eq!(shift_by_business_days(&[NaiveDate::MAX], 1, &setup.cal), Err(ScheduleError::OutOfRange));
+    assert_eq!(shift_by_business_days(&[NaiveDate::MIN], -1, &setup.cal), Err(ScheduleError::OutOfRange));
+}
+
+#[test]
+fn fixing_and_payment_dates_merge_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::Quarterly, Some(&setup.cal), Some(AdjustRule::ModFollowing));
+    let (effective, maturity) = (d(2023, 3, 27), d(2024, 3, 27));
+    let payments = sched.payment_dates(&effective, &maturity, 0).unwrap();
+    let accrual_starts: Vec<_> = std::iter::once(effective).chain(payments[..payments.len() - 1].iter().copied()).collect();
+
+    // Shifting accrual starts back two business days reproduces fixing_dates
+    // when every start is a business day.
+    let fixings = shift_by_business_days(&accrual_starts, -2, &setup.cal).unwrap();
+    assert_eq!(fixings, sched.fixing_dates(&effective, &maturity, 2, &setup.cal).unwrap());
+
+    let events = merge(&payments, &fixings);
+    assert_eq!(events.len(), payments.len() + fixings.len());
+    assert!(events.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(intersect(&payments, &fixings).is_empty());
+}
+
+#[test]
+fn classify_periods_stub_rules_test() {
+    use findates::schedule::PeriodKind::{LongStub, Regular, ShortStub};
+
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let classes = |stub| {
+        let periods = Schedule::new(Frequency::Semiannual, None, None)
+            .with_stub(stub)
+            .coupon_periods(&d(2024, 2, 10), &d(2025, 6, 15), DayCount::ActActICMA, 0)
+            .unwrap();
+        classify_periods(&periods, Frequency::Semiannual)
+            .iter()
+            .map(|class| (class.kind, class.notional_fraction))
+            .collect::<Vec<_>>()
+    };
+
+    // Back stubs are measured from 10 February 2025 to 10 August 2025.
+    assert_eq!(classes(StubRule::ShortBack), [(Regular, 1.0), (Regular, 1.0), (ShortStub, 125.0 / 181.0)]);
+    assert_eq!(classes(StubRule::LongBack), [(Regular, 1.0), (LongStub, 1.0 + 125.0 / 181.0)]);
+    // Front stubs are measured from 15 December 2023 to 15 June 2024.
+    assert_eq!(classes(StubRule::ShortFront), [(ShortStub, 126.0 / 183.0), (Regular, 1.0), (Regular, 1.0)]);
+    assert_eq!(classes(StubRule::LongFront), [(LongStub, 1.0 + 126.0 / 183.0), (Regular, 1.0)]);
+}
+
+#[test]
+fn classify_periods_adjusted_dates_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+
+    // Month-end dates adjusted Modified Following are still regular.
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing)).with_eom(true);
+    let periods = sched.coupon_periods(&d(2023, 1, 31), &d(2024, 1, 31), DayCount::Act360, 0).unwrap();
+    assert_eq!(periods.len(), 12);
+    assert!(classify_periods(&periods, Frequency::Monthly).iter().all(|class| class.kind == PeriodKind::Regular));
+
+    // A two-day stub after three weeks, beyond the one-day weekly tolerance.
+    let weekly = Schedule::new(Frequency::Weekly, None, None);
+    let periods = weekly.coupon_periods(&d(2024, 1, 1), &d(2024, 1, 24), DayCount::Act360, 0).unwrap();
+    let classes = classify_periods(&periods, Frequency::Weekly);
+    assert_eq!(classes.len(), 4);
+    assert_eq!((classes[3].kind, classes[3].notional_fraction), (PeriodKind::ShortStub, 2.0 / 7.0));
+
+    let zero = Schedule::new(Frequency::Zero, None, None);
+    let periods = zero.coupon_periods(&d(2024, 1, 1), &d(2024, 1, 24), DayCount::Act360, 0).unwrap();
+    assert_eq!(classify_periods(&periods, Frequency::Zero)[0].kind, PeriodKind::Regular);
+    assert!(classify_periods(&[], Frequency::Monthly).is_empty());
+}
+
+// ============================================================================
+// Builder Tests
+// ============================================================================
+
+#[test]
+fn builder_matches_constructor_test() {
+    let setup = ScheduleSetup::new();
+    let built = Schedule::builder()
+        .frequency(Frequency::Monthly)
+        .calendar(&setup.cal)
+        .adjust_rule(AdjustRule::ModFollowing)
+        .eom(true)
+        .stub(StubRule::LongBack)
+        .build()
+        .unwrap();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing))
+        .with_eom(true)
+        .with_stub(StubRule::LongBack);
+    assert_eq!(built, sched);
+
+    let weekly = Schedule::builder()
+        .frequency(Frequency::Biweekly)
+        .roll(RollConvention::DayOfWeek(Weekday::Wed))
+        .build()
+        .unwrap();
+    assert_eq!(weekly, Schedule::new(Frequency::Biweekly, None, None).with_roll(RollConvention::DayOfWeek(Weekday::Wed)));
+    assert_eq!(Schedule::builder().frequency(Frequency::Zero).build(), Ok(Schedule::new(Frequency::Zero, None, None)));
+}
+
+#[test]
+fn builder_rejects_incompatible_options_test() {
+    let setup = ScheduleSetup::new();
+    let monthly = Schedule::builder().frequency(Frequency::Monthly);
+    assert_eq!(Schedule::builder().calendar(&setup.cal).build(), Err(ScheduleBuildError::MissingFrequency));
+    assert_eq!(
+        monthly.adjust_rule(AdjustRule::Following).build(),
+        Err(ScheduleBuildError::AdjustRuleWithoutCalendar)
+    );
+    assert_eq!(monthly.eom(true).roll(RollConvention::EndOfMonth).build(), Err(ScheduleBuildError::ConflictingRoll));
+    assert_eq!(
+        Schedule::builder().frequency(Frequency::EveryNDays(10)).eom(true).build(),
+        Err(ScheduleBuildError::IncompatibleEom { frequency: Frequency::EveryNDays(10) })
+    );
+    assert_eq!(
+        Schedule::builder().frequency(Frequency::Weekly).roll(RollConvention::Imm).build(),
+        Err(ScheduleBuildError::IncompatibleRoll { roll: RollConvention::Imm, frequency: Frequency::Weekly })
+    );
+    let zero = Schedule::builder().frequency(Frequency::Zero);
+    assert_eq!(
+        zero.stub(StubRule::ShortBack).build(),
+        Err(ScheduleBuildError::IncompatibleStub { frequency: Frequency::Zero })
+    );
+    assert_eq!(
+        zero.roll(RollConvention::Day(15)).build(),
+        Err(ScheduleBuildError::IncompatibleRoll { roll: RollConvention::Day(15), frequency: Frequency::Zero })
+    );
+    assert_eq!(
+        ScheduleBuildError::IncompatibleStub { frequency: Frequency::Zero }.to_string(),
+        "stub rule requires regular periods, not frequency Zero"
+    );
+}
+
+#[test]
+fn builder_rejects_zero_length_frequency_test() {
+    for frequency in [Frequency::EveryNMonths(0), Frequency::EveryNWeeks(0), Frequency::EveryNDays(0)] {
+        assert_eq!(
+            Schedule::builder().frequency(frequency).build(),
+            Err(ScheduleBuildError::ZeroLengthFrequency { frequency })
+        );
+    }
+    assert!(Schedule::builder().frequency(Frequency::EveryNMonths(5)).build().is_ok());
+    assert_eq!(
+        ScheduleBuildError::ZeroLengthFrequency { frequency: Frequency::EveryNWeeks(0) }.to_string(),
+        "schedule frequency 0W has a zero-length period"
+    );
+}
+
+// ============================================================================
+// Owned Schedule Tests
+// ============================================================================
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn schedule_types_are_send_and_sync_test() {
+    assert_send_sync::<ScheduleOwned>();
+    assert_send_sync::<Schedule<'static>>();
+    assert_send_sync::<ScheduleIterator<'static>>();
+    assert_send_sync::<AdjustedScheduleIterator<'static>>();
+    assert_send_sync::<BackwardScheduleIterator<'static>>();
+}
+
+#[test]
+fn schedule_owned_matches_borrowed_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let setup = ScheduleSetup::new();
+    let sched = Schedule::new(Frequency::Monthly, Some(&setup.cal), Some(AdjustRule::ModFollowing)).with_eom(true);
+    let owned = ScheduleOwned::new(Frequency::Monthly, Some(Arc::new(setup.cal.clone())), Some(AdjustRule::ModFollowing))
+        .with_eom(true);
+    assert_eq!(owned, ScheduleOwned::from(&sched));
+    assert_eq!(owned.as_schedule(), sched);
+
+    let (start, end) = (d(2023, 8, 31), d(2024, 8, 31));
+    assert_eq!(owned.generate(&start, &end), sched.generate(&start, &end));
+    assert_eq!(owned.generate_with_trace(&start, &end), sched.generate_with_trace(&start, &end));
+    assert_eq!(owned.generate_backward(&start, &end), sched.generate_backward(&start, &end));
+    assert_eq!(
+        owned.coupon_periods(&start, &end, DayCount::Act360, 2),
+        sched.coupon_periods(&start, &end, DayCount::Act360, 2)
+    );
+    assert_eq!(owned.payment_dates(&start, &end, 2), sched.payment_dates(&start, &end, 2));
+    assert_eq!(owned.fixing_dates(&start, &end, 2, &setup.cal), sched.fixing_dates(&start, &end, 2, &setup.cal));
+    assert_eq!(owned.next_adjusted(start), sched.next_adjusted(start));
+    let today = d(2024, 2, 14);
+    assert_eq!(owned.next_after(&start, &today), sched.next_after(&start, &today));
+    assert_eq!(owned.previous_before(&start, &today), sched.previous_before(&start, &today));
+    assert_eq!(owned.contains(&start, &d(2024, 2, 29)), sched.contains(&start, &d(2024, 2, 29)));
+    assert!(owned.iter(start).take(12).eq(sched.iter(start).take(12)));
+    assert!(owned.iter_adjusted(start).take(12).eq(sched.iter_adjusted(start).take(12)));
+    assert!(owned.iter_between(start, end).eq(sched.iter_between(start, end)));
+    assert!(owned.iter_backward(end).take(12).eq(sched.iter_backward(end).take(12)));
+}
+
+#[test]
+fn schedule_owned_shares_calendar_across_threads_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let cal = Arc::new(ScheduleSetup::new().cal);
+    let handles: Vec<_> = [Frequency::Monthly, Frequency::Quarterly, Frequency::Semiannual]
+        .into_iter()
+        .map(|frequency| {
+            let owned = ScheduleOwned::new(frequency, Some(Arc::clone(&cal)), Some(AdjustRule::Following));
+            thread::spawn(move || owned.generate(&d(2023, 6, 25), &d(2023, 12, 25)).unwrap())
+        })
+        .collect();
+    let dates: Vec<Vec<NaiveDate>> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+    // Christmas and Boxing Day 2023 push the last date to the 27th.
+    assert!(dates.iter().all(|dates| dates.last() == Some(&d(2023, 12, 27))));
+    assert_eq!(dates[0].len(), 7);
+    assert_eq!(Arc::strong_count(&cal), 1);
+}
+
+// ============================================================================
+// Weekday Schedule Tests
+// ============================================================================
+
+#[test]
+fn weekday_dates_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 12, 25), d(2025, 1, 1)]);
+
+    // The range ends are inclusive and need not fall on the weekday
+    let tuesdays = weekday_dates(&d(2024, 12, 3), &d(2024, 12, 31), Weekday::Tue, 1, None, None).unwrap();
+    assert_eq!(tuesdays, vec![d(2024, 12, 3), d(2024, 12, 10), d(2024, 12, 17), d(2024, 12, 24), d(2024, 12, 31)]);
+    let fortnightly = weekday_dates(&d(2024, 12, 4), &d(2025, 1, 31), Weekday::Wed, 2, Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(fortnightly.unwrap(), vec![d(2024, 12, 4), d(2024, 12, 18), d(2025, 1, 2), d(2025, 1, 15), d(2025, 1, 29)]);
+    let preceding = weekday_dates(&d(2024, 12, 21), &d(2025, 1, 2), Weekday::Wed, 1, Some(&cal), Some(AdjustRule::Preceding));
+    assert_eq!(preceding.unwrap(), vec![d(2024, 12, 24), d(2024, 12, 31)]);
+
+    assert_eq!(weekday_dates(&d(2024, 12, 5), &d(2024, 12, 10), Weekday::Wed, 1, None, None), Ok(vec![]));
+    assert_eq!(
+        weekday_dates(&d(2024, 12, 5), &d(2024, 12, 4), Weekday::Wed, 1, None, None),
+        Err(ScheduleError::InvalidDateRange)
+    );
+}
+
+#[test]
+fn weekday_dates_zero_interval_test() {
+    let date = NaiveDate::from_ymd_opt(2024, 12, 4).unwrap();
+    assert_eq!(weekday_dates(&date, &date, Weekday::Wed, 0, None, None), Err(ScheduleError::InvalidInterval));
+}
+
+#[test]
+fn nth_weekday_dates_test() {
+    let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+    let dates = nth_weekday_dates(&d(2024, 5, 10), &d(2024, 8, 31), Weekday::Fri, &[4, 2, 5, 2, 0], None, None).unwrap();
+    // May and August 2024 have five Fridays; the second Friday of May is
+    // the start date.
+    assert_eq!(
+        dates,
+        vec![
+            d(2024, 5, 10),
+            d(2024, 5, 24),
+            d(2024, 5, 31),
+            d(2024, 6, 14),
+            d(2024, 6, 28),
+            d(2024, 7, 12),
+            d(2024, 7, 26),
+            d(2024, 8, 9),
+            d(2024, 8, 23),
+            d(2024, 8, 30),
+        ]
+    );
+
+    // Good Friday 2024 is the fifth Friday of March
+    let mut cal = calendar::basic_calendar();
+    cal.add_holidays([d(2024, 3, 29)]);
+    let last = nth_weekday_dates(&d(2024, 3, 1), &d(2024, 3, 31), Weekday::Fri, &[5], Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(last, Ok(vec![d(2024, 4, 1)]));
+    assert_eq!(nth_weekday_dates(&d(2024, 3, 1), &d(2024, 3, 31), Weekday::Fri, &[], None, None), Ok(vec![]));
+    assert_eq!(
+        nth_weekday_dates(&d(2024, 3, 2), &d(2024, 3, 1), Weekday::Fri, &[1], None, None),
+        Err(ScheduleError::InvalidDateRange)
+    );
+}
@@ -0,0 +1,298 @@
+// Integration tests for the optional serde support.
+// Run with `cargo test --features serde`.
+#![cfg(feature = "serde")]
+
+use chrono::{NaiveTime, Weekday};
+use findates::calendar::Calendar;
+use findates::conventions::{
+    AdjustRule, DayCount, Frequency, RollConvention, Rounding, RoundingDirection, StubRule, Tenor,
+};
+use findates::schedule::{Schedule, ScheduleOwned};
+
+mod setup;
+use setup::d;
+
+fn target_like() -> Calendar {
+    let mut cal = findates::calendar::basic_calendar();
+    cal.add_holidays([d(2024, 1, 1)]);
+    cal.add_named_holiday(d(2024, 12, 25), "Christmas Day");
+    cal.set_valid_range(d(2024, 1, 1), d(2024, 12, 31)).unwrap();
+    cal.add_early_close(d(2024, 12, 24), NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    cal
+}
+
+// ── Calendar ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn calendar_json_roundtrip_test() {
+    let cal = target_like();
+    let json = serde_json::to_string(&cal).unwrap();
+    let back: Calendar = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, cal);
+    assert_eq!(back.holiday_name(&d(2024, 12, 25)), Some("Christmas Day"));
+    assert_eq!(back.valid_range(), Some((d(2024, 1, 1), d(2024, 12, 31))));
+    assert!(back.is_early_close(&d(2024, 12, 24)));
+}
+
+#[test]
+fn calendar_json_without_holiday_info_test() {
+    let json = r#"{"weekend":["Sat","Sun"],"holidays":["2024-12-25"]}"#;
+    let cal: Calendar = serde_json::from_str(json).unwrap();
+    assert!(cal.get_holidays().contains(&d(2024, 12, 25)));
+    assert_eq!(cal.holiday_name(&d(2024, 12, 25)), None);
+    assert_eq!(cal.valid_range(), None);
+    let json = serde_json::to_string(&cal).unwrap();
+    assert!(!json.contains("holiday_info"));
+    assert!(!json.contains("valid_range"));
+    assert!(!json.contains("early_closes"));
+}
+
+#[test]
+fn calendar_json_empty_valid_range_roundtrip_test() {
+    let mut old = Calendar::new();
+    old.set_valid_range(d(2000, 1, 1), d(2009, 12, 31)).unwrap();
+    let cal = &old | &target_like();
+    assert!(cal.has_empty_valid_range());
+    let json = serde_json::to_string(&cal).unwrap();
+    assert!(json.contains(r#""valid_range":[]"#));
+    let back: Calendar = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, cal);
+    assert!(back.has_empty_valid_range());
+}
+
+#[test]
+fn calendar_json_rejects_broken_invariants_test() {
+    // Metadata for a date that is not a holiday.
+    let json = r#"{"weekend":["Sat","Sun"],"holidays":["2024-12-25"],
+        "holiday_info":{"2024-12-26":{"name":"Boxing Day","category":null}}}"#;
+    let err = serde_json::from_str::<Calendar>(json).unwrap_err();
+    assert!(err.to_string().contains("2024-12-26 has holiday metadata but is not a holiday"));
+
+    // A valid range that ends before it starts.
+    let json = r#"{"weekend":[],"holidays":[],"valid_range":["2024-12-31","2024-01-01"]}"#;
+    let err = serde_json::from_str::<Calendar>(json).unwrap_err();
+    assert!(err.to_string().contains("calendar valid range must not end before it starts"));
+}
+
+#[test]
+fn calendar_json_is_deterministic_test() {
+    // Built in different orders, serialized identically and in date order.
+    let mut cal = Calendar::with_weekends([chrono::Weekday::Sun, chrono::Weekday::Sat]);
+    cal.add_holidays([d(2024, 12, 26), d(2024, 1, 1), d(2024, 12, 25)]);
+    let mut other = Calendar::with_weekends([chrono::Weekday::Sat, chrono::Weekday::Sun]);
+    other.add_holidays([d(2024, 12, 25), d(2024, 12, 26), d(2024, 1, 1)]);
+
+    let json = serde_json::to_string(&cal).unwrap();
+    assert_eq!(json, r#"{"weekend":["Sat","Sun"],"holidays":["2024-01-01","2024-12-25","2024-12-26"]}"#);
+    assert_eq!(serde_json::to_string(&other).unwrap(), json);
+}
+
+// ── Conventions ──────────────────────────────────────────────────────────────
+
+#[test]
+fn conventions_json_roundtrip_test() {
+    let dc: DayCount = serde_json::from_str(&serde_json::to_string(&DayCount::Act360).unwrap()).unwrap();
+    assert_eq!(dc, DayCount::Act360);
+    let rule: AdjustRule =
+        serde_json::from_str(&serde_json::to_string(&AdjustRule::ModFollowing).unwrap()).unwrap();
+    assert_eq!(rule, AdjustRule::ModFollowing);
+    let freq: Frequency =
+        serde_json::from_str(&serde_json::to_string(&Frequency::Quarterly).unwrap()).unwrap();
+    assert_eq!(freq, Frequency::Quarterly);
+}
+
+#[test]
+fn conventions_market_strings_test() {
+    assert_eq!(serde_json::to_string(&DayCount::Act360).unwrap(), r#""ACT/360""#);
+    assert_eq!(serde_json::to_string(&DayCount::Thirty360ISDA).unwrap(), r#""30/360.ISDA""#);
+    assert_eq!(serde_json::to_string(&AdjustRule::ModFollowing).unwrap(), r#""MODFOLLOWING""#);
+    assert_eq!(serde_json::to_string(&AdjustRule::Unadjusted).unwrap(), r#""NONE""#);
+    assert_eq!(serde_json::to_string(&Frequency::Semiannual).unwrap(), r#""6M""#);
+    assert_eq!(serde_json::to_string(&Frequency::EndOfMonth).unwrap(), r#""EOM""#);
+
+    // Variant names, aliases and other systems' spellings all deserialize.
+    let dc: Vec<DayCount> = serde_json::from_str(r#"["Act360", "ACT/360", "actual/360"]"#).unwrap();
+    assert_eq!(dc, [DayCount::Act360; 3]);
+    let rules: Vec<AdjustRule> = serde_json::from_str(r#"["ModFollowing", "MF", "Modified Following"]"#).unwrap();
+    assert_eq!(rules, [AdjustRule::ModFollowing; 3]);
+    let freqs: Vec<Frequency> = serde_json::from_str(r#"["Semiannual", "SemiAnnual", "6M"]"#).unwrap();
+    assert_eq!(freqs, [Frequency::Semiannual; 3]);
+
+    let err = serde_json::from_str::<DayCount>(r#""ACT/364""#).unwrap_err();
+    assert!(err.to_string().contains("unknown day count \"ACT/364\""));
+    assert!(serde_json::from_str::<Frequency>("6").is_err());
+}
+
+#[test]
+fn all_conventions_json_roundtrip_test() {
+    let day_counts = [
+        DayCount::Act360, DayCount::Act365, DayCount::Act365Fixed, DayCount::Act365_25, DayCount::NL365,
+        DayCount::Bd252, DayCount::ActActISDA, DayCount::ActActICMA, DayCount::ActActAFB,
+        DayCount::D30360Euro, DayCount::Thirty360US, DayCount::Thirty360ISDA, DayCount::ThirtyE360ISDA,
+        DayCount::D30365,
+    ];
+    let json = serde_json::to_string(&day_counts).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<DayCount>>(&json).unwrap(), day_counts);
+
+    let rules = [
+        AdjustRule::Following, AdjustRule::ModFollowing, AdjustRule::Preceding, AdjustRule::ModPreceding,
+        AdjustRule::Unadjusted, AdjustRule::HalfMonthModFollowing, AdjustRule::Nearest,
+    ];
+    let json = serde_json::to_string(&rules).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<AdjustRule>>(&json).unwrap(), rules);
+
+    let frequencies = [
+        Frequency::Zero, Frequency::Annual, Frequency::Semiannual, Frequency::EveryFourthMonth,
+        Frequency::Quarterly, Frequency::Bimonthly, Frequency::Monthly, Frequency::EndOfMonth,
+        Frequency::EveryFourthWeek, Frequency::Biweekly, Frequency::Weekly, Frequency::Daily,
+        Frequency::EveryNMonths(5), Frequency::EveryNWeeks(13), Frequency::EveryNDays(30),
+    ];
+    let json = serde_json::to_string(&frequencies).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<Frequency>>(&json).unwrap(), frequencies);
+}
+
+#[test]
+fn frequency_every_variant_roundtrip_test() {
+    let mut frequencies = vec![
+        Frequency::Zero, Frequency::Annual, Frequency::Semiannual, Frequency::EveryFourthMonth,
+        Frequency::Quarterly, Frequency::Bimonthly, Frequency::Monthly, Frequency::EndOfMonth,
+        Frequency::EveryFourthWeek, Frequency::Biweekly, Frequency::Weekly, Frequency::Daily,
+    ];
+    for n in 1..=60 {
+        frequencies.push(Frequency::EveryNMonths(n));
+        frequencies.push(Frequency::EveryNWeeks(n));
+        frequencies.push(Frequency::EveryNDays(n));
+    }
+    for freq in frequencies {
+        let json = serde_json::to_string(&freq).unwrap();
+        let back: Frequency = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, freq, "{json}");
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        assert_eq!(freq.to_string().parse::<Frequency>(), Ok(freq), "{freq}");
+    }
+
+    // Parametric variants with a named period are written as the named one.
+    assert_eq!(serde_json::to_string(&Frequency::EveryNMonths(3)).unwrap(), "\"3M\"");
+    assert_eq!(serde_json::from_str::<Frequency>("\"3M\"").unwrap(), Frequency::Quarterly);
+    assert_eq!(serde_json::to_string(&Frequency::EveryNDays(7)).unwrap(), "\"1W\"");
+
+    // A zero count has no period to write.
+    for freq in [Frequency::EveryNMonths(0), Frequency::EveryNWeeks(0), Frequency::EveryNDays(0)] {
+        assert!(serde_json::to_string(&freq).is_err());
+    }
+}
+
+#[test]
+fn tenor_and_roll_convention_serialize_as_codes_test() {
+    let tenors = [
+        Tenor::Days(1), Tenor::Weeks(2), Tenor::Months(3), Tenor::Years(10),
+        Tenor::Overnight, Tenor::TomorrowNext, Tenor::SpotNext,
+    ];
+    let json = serde_json::to_string(&tenors).unwrap();
+    assert_eq!(json, r#"["1D","2W","3M","10Y","ON","TN","SN"]"#);
+    assert_eq!(serde_json::from_str::<Vec<Tenor>>(&json).unwrap(), tenors);
+
+    let rolls = [
+        RollConvention::Day(15), RollConvention::EndOfMonth, RollConvention::Imm, RollConvention::Cds,
+        RollConvention::DayOfWeek(Weekday::Wed), RollConvention::FirstBusinessDay,
+        RollConvention::LastBusinessDay, RollConvention::NthBusinessDay(3),
+    ];
+    let json = serde_json::to_string(&rolls).unwrap();
+    assert_eq!(json, r#"["15","EOM","IMM","CDS","WED","FBD","LBD","BD3"]"#);
+    assert_eq!(serde_json::from_str::<Vec<RollConvention>>(&json).unwrap(), rolls);
+
+    // The variant form is still read.
+    let json = r#"[{"Months":3},"Overnight",{"Years":10}]"#;
+    assert_eq!(
+        serde_json::from_str::<Vec<Tenor>>(json).unwrap(),
+        [Tenor::Months(3), Tenor::Overnight, Tenor::Years(10)]
+    );
+    let json = r#"[{"Day":15},"EndOfMonth",{"DayOfWeek":"Wed"},{"NthBusinessDay":3}]"#;
+    assert_eq!(
+        serde_json::from_str::<Vec<RollConvention>>(json).unwrap(),
+        [
+            RollConvention::Day(15),
+            RollConvention::EndOfMonth,
+            RollConvention::DayOfWeek(Weekday::Wed),
+            RollConvention::NthBusinessDay(3),
+        ]
+    );
+
+    let err = serde_json::from_str::<Tenor>(r#""3X""#).unwrap_err();
+    assert!(err.to_string().contains("unknown tenor \"3X\""));
+    let err = serde_json::from_str::<RollConvention>(r#""32""#).unwrap_err();
+    assert!(err.to_string().contains("unknown roll convention \"32\""));
+    assert!(serde_json::from_str::<Tenor>(r#"{"Fortnights":1}"#).is_err());
+}
+
+// ── Schedule ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn schedule_owned_json_roundtrip_test() {
+    let cal = target_like();
+    let sched = Schedule::new(Frequency::Monthly, Some(&cal), Some(AdjustRule::ModFollowing))
+        .with_roll(RollConvention::EndOfMonth)
+        .with_stub(StubRule::LongFront)
+        .with_rounding(Rounding::new(10, RoundingDirection::Down));
+    let owned = ScheduleOwned::from(&sched);
+
+    let json = serde_json::to_string(&owned).unwrap();
+    let back: ScheduleOwned = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, owned);
+    assert_eq!(
+        back.as_schedule().generate(&d(2024, 1, 31), &d(2024, 12, 31)),
+        sched.generate(&d(2024, 1, 31), &d(2024, 12, 31))
+    );
+}
+
+#[test]
+fn borrowed_schedule_deserializes_as_owned_test() {
+    let cal = target_like();
+    let sched = Schedule::new(Frequency::Semiannual, Some(&cal), Some(AdjustRule::Following));
+    let json = serde_json::to_string(&sched).unwrap();
+    let owned: ScheduleOwned = serde_json::from_str(&json).unwrap();
+    assert_eq!(owned.as_schedule(), sched);
+}
+
+#[test]
+fn schedule_owned_defaults_missing_roll_fields_test() {
+    let json = r#"{"frequency":"Annual","calendar":null,"adjust_rule":null}"#;
+    let owned: ScheduleOwned = serde_json::from_str(json).unwrap();
+    assert_eq!(owned.as_schedule(), Schedule::new(Frequency::Annual, None, None));
+}
+
+// ── JSON files ───────────────────────────────────────────────────────────────
+
+#[cfg(feature = "json")]
+#[test]
+fn calendar_json_reader_writer_roundtrip_test() {
+    let cal = target_like();
+    let mut out = Vec::new();
+    cal.to_json_writer(&mut out).unwrap();
+    assert_eq!(Calendar::from_json_reader(out.as_slice()).unwrap(), cal);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn calendar_json_reader_rejects_invalid_input_test() {
+    let err = Calendar::from_json_reader(r#"{"weekend":["Sat"]}"#.as_bytes()).unwrap_err();
+    assert!(matches!(err, findates::CalendarIoError::Json(_)));
+}
+
+// ── TrackedCalendar ──────────────────────────────────────────────────────────
+
+#[test]
+fn tracked_calendar_history_roundtrip_test() {
+    use chrono::{TimeZone, Utc};
+    use findates::tracked::{CalendarChange, TrackedCalendar};
+
+    let now = Utc.with_ymd_and_hms(2024, 9, 10, 8, 30, 0).unwrap();
+    let mut cal = TrackedCalendar::new(target_like());
+    cal.add_holiday(d(2024, 9, 19), now, "notice 17");
+    cal.remove_holiday(d(2024, 1, 1), now, "notice 18");
+
+    let json = serde_json::to_string(cal.history()).unwrap();
+    assert!(json.contains(r#""kind":"Added","date":"2024-09-19","timestamp":"2024-09-10T08:30:00Z""#));
+    let history: Vec<CalendarChange> = serde_json::from_str(&json).unwrap();
+    assert_eq!(TrackedCalendar::replay(target_like(), history), cal);
+}
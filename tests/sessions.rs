@@ -0,0 +1,241 @@
+// Integration tests for market hours and trading sessions.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use findates::algebra;
+use findates::calendar::{basic_calendar, Calendar};
+use findates::sessions::{MarketHours, TradingSession};
+use findates::{CalendarError, DayCountError};
+
+mod setup;
+use setup::d;
+
+fn t(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+fn new_york() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+fn ny(y: i32, m: u32, day: u32, h: u32, min: u32) -> DateTime<FixedOffset> {
+    new_york().with_ymd_and_hms(y, m, day, h, min, 0).unwrap()
+}
+
+// NYSE-like calendar for late 2024: Thanksgiving and Christmas closed, half
+// days after Thanksgiving and on Christmas Eve.
+fn nyse_calendar() -> Calendar {
+    let mut cal = basic_calendar();
+    cal.add_named_holiday(d(2024, 11, 28), "Thanksgiving Day");
+    cal.add_named_holiday(d(2024, 12, 25), "Christmas Day");
+    cal.add_early_close(d(2024, 11, 29), t(13, 0));
+    cal.add_early_close(d(2024, 12, 24), t(13, 0));
+    cal
+}
+
+fn nyse(cal: &Calendar) -> TradingSession<'_> {
+    cal.with_market_hours(MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap())
+}
+
+// ── Market hours ─────────────────────────────────────────────────────────────
+
+#[test]
+fn market_hours_test() {
+    let hours = MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap();
+    assert_eq!(hours.session_minutes(), 390);
+    assert_eq!(MarketHours::new(t(16, 0), t(9, 30), new_york()), Err(CalendarError::InvalidHours));
+    assert_eq!(CalendarError::InvalidHours.to_string(), "market hours must close after they open");
+}
+
+#[test]
+fn session_bounds_test() {
+    let cal = nyse_calendar();
+    let session = nyse(&cal);
+    assert_eq!(session.session(&d(2024, 11, 27)), Some((ny(2024, 11, 27, 9, 30), ny(2024, 11, 27, 16, 0))));
+    assert_eq!(session.session(&d(2024, 11, 29)), Some((ny(2024, 11, 29, 9, 30), ny(2024, 11, 29, 13, 0))));
+    assert_eq!(session.session(&d(2024, 11, 28)), None);
+    assert_eq!(session.session(&d(2024, 11, 30)), None);
+
+    // An early close before the open leaves no session.
+    let mut cal = basic_calendar();
+    cal.add_early_close(d(2024, 11, 29), t(9, 0));
+    assert_eq!(nyse(&cal).session(&d(2024, 11, 29)), None);
+}
+
+// ── Intraday queries ─────────────────────────────────────────────────────────
+
+#[test]
+fn is_market_open_test() {
+    let cal = nyse_calendar();
+    let session = nyse(&cal);
+    assert!(!session.is_market_open(&ny(2024, 11, 27, 9, 29)));
+    assert!(session.is_market_open(&ny(2024, 11, 27, 9, 30)));
+    assert!(session.is_market_open(&ny(2024, 11, 27, 15, 59)));
+    assert!(!session.is_market_open(&ny(2024, 11, 27, 16, 0)));
+    assert!(!session.is_market_open(&ny(2024, 11, 28, 12, 0)));
+    assert!(session.is_market_open(&ny(2024, 11, 29, 12, 59)));
+    assert!(!session.is_market_open(&ny(2024, 11, 29, 13, 0)));
+
+    // Instants in other time zones are converted to New York time.
+    assert!(session.is_market_open(&Utc.with_ymd_and_hms(2024, 11, 27, 20, 59, 0).unwrap()));
+    assert!(!session.is_market_open(&Utc.with_ymd_and_hms(2024, 11, 27, 21, 0, 0).unwrap()));
+}
+
+#[test]
+fn next_open_test() {
+    let cal = nyse_calendar();
+    let session = nyse(&cal);
+    assert_eq!(session.next_open(&ny(2024, 11, 27, 8, 0)), Some(ny(2024, 11, 27, 9, 30)));
+    assert_eq!(session.next_open(&ny(2024, 11, 27, 9, 30)), Some(ny(2024, 11, 27, 9, 30)));
+    assert_eq!(session.next_open(&ny(2024, 11, 27, 10, 0)), Some(ny(2024, 11, 29, 9, 30)));
+    assert_eq!(session.next_open(&ny(2024, 12, 24, 14, 0)), Some(ny(2024, 12, 26, 9, 30)));
+    assert_eq!(session.next_open(&ny(2024, 11, 29, 17, 0)), Some(ny(2024, 12, 2, 9, 30)));
+
+    // 01:00 UTC on Saturday is still Friday evening in New York.
+    let utc = Utc.with_ymd_and_hms(2024, 11, 30, 1, 0, 0).unwrap();
+    assert_eq!(session.next_open(&utc), Some(ny(2024, 12, 2, 9, 30)));
+}
+
+#[test]
+fn next_open_out_of_range_test() {
+    let cal = basic_calendar();
+    let session = cal.with_market_hours(MarketHours::new(t(9, 0), t(17, 0), FixedOffset::east_opt(0).unwrap()).unwrap());
+    let last = NaiveDate::MAX.iter_days().rev().find(|day| cal.is_business_day(day)).unwrap();
+    let after_last_close = last.and_hms_opt(18, 0, 0).unwrap().and_utc();
+    assert_eq!(session.next_open(&after_last_close), None);
+}
+
+#[test]
+fn next_open_stays_in_valid_range_test() {
+    let mut cal = basic_calendar();
+    cal.set_valid_range(d(2024, 1, 1), d(2024, 12, 31)).unwrap();
+    let session = nyse(&cal);
+    assert_eq!(session.next_open(&ny(2024, 12, 30, 8, 0)), Some(ny(2024, 12, 30, 9, 30)));
+    assert_eq!(session.next_open(&ny(2024, 12, 31, 17, 0)), None);
+    assert_eq!(session.next_open(&ny(2025, 1, 2, 8, 0)), None);
+
+    // A calendar without business days has no next open to search for.
+    let every_day = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+    let closed = Calendar::with_weekends(every_day);
+    assert_eq!(nyse(&closed).next_open(&ny(2024, 11, 27, 8, 0)), None);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn sessions_follow_daylight_saving_test() {
+    use chrono_tz::America::New_York;
+
+    let cal = basic_calendar();
+    let session = cal.with_market_hours(MarketHours::new(t(9, 30), t(16, 0), New_York).unwrap());
+    let utc = |day, h, min| Utc.with_ymd_and_hms(2024, 3, day, h, min, 0).unwrap();
+    // New York moved to summer time on Sunday 10 March 2024.
+    let (open, close) = session.session(&d(2024, 3, 8)).unwrap();
+    assert_eq!((open.with_timezone(&Utc), close.with_timezone(&Utc)), (utc(8, 14, 30), utc(8, 21, 0)));
+    let (open, _) = session.session(&d(2024, 3, 11)).unwrap();
+    assert_eq!(open.with_timezone(&Utc), utc(11, 13, 30));
+    assert!(session.is_market_open(&utc(11, 13, 30)));
+    assert!(!session.is_market_open(&utc(8, 14, 0)));
+    assert_eq!(session.next_open(&utc(9, 12, 0)).map(|open| open.with_timezone(&Utc)), Some(utc(11, 13, 30)));
+
+    // 02:30 did not exist in New York that Sunday.
+    let all_week = Calendar::new();
+    let night = all_week.with_market_hours(MarketHours::new(t(2, 30), t(4, 0), New_York).unwrap());
+    assert_eq!(night.session(&d(2024, 3, 10)), None);
+    assert!(night.session(&d(2024, 3, 11)).is_some());
+}
+
+#[test]
+fn business_minutes_between_test() {
+    let cal = nyse_calendar();
+    let session = nyse(&cal);
+    let full_day = 390;
+
+    assert_eq!(session.business_minutes_between(&ny(2024, 11, 25, 0, 0), &ny(2024, 11, 26, 0, 0)), full_day);
+    assert_eq!(session.business_minutes_between(&ny(2024, 11, 25, 12, 0), &ny(2024, 11, 25, 12, 0)), 0);
+    assert_eq!(session.business_minutes_between(&ny(2024, 11, 25, 17, 0), &ny(2024, 11, 26, 9, 0)), 0);
+
+    // Thanksgiving week: three full days, a holiday and a half day.
+    let week = session.business_minutes_between(&ny(2024, 11, 25, 0, 0), &ny(2024, 12, 2, 0, 0));
+    assert_eq!(week, 3 * full_day + 210);
+    let reversed = session.business_minutes_between(&ny(2024, 12, 2, 0, 0), &ny(2024, 11, 25, 0, 0));
+    assert_eq!(reversed, -week);
+
+    // Mixed time zones: 14:30 UTC is the New York open.
+    let utc_open = Utc.with_ymd_and_hms(2024, 11, 25, 14, 30, 0).unwrap();
+    assert_eq!(session.business_minutes_between(&utc_open, &ny(2024, 11, 25, 10, 30)), 60);
+}
+
+// ── Trading time ─────────────────────────────────────────────────────────────
+
+#[test]
+fn trading_time_fraction_intraday_test() {
+    let cal = nyse_calendar();
+    let hours = MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap();
+    let fraction = |a, b| algebra::trading_time_fraction(&a, &b, &cal, &hours, None).unwrap();
+
+    assert_eq!(fraction(ny(2024, 11, 25, 9, 30), ny(2024, 11, 25, 16, 0)), 1.0 / 252.0);
+    assert_eq!(fraction(ny(2024, 11, 25, 16, 0), ny(2024, 11, 26, 9, 30)), 0.0);
+    // The half day after Thanksgiving counts 210 of 390 minutes.
+    let week = fraction(ny(2024, 11, 25, 0, 0), ny(2024, 12, 2, 0, 0));
+    assert!((week - (3.0 + 210.0 / 390.0) / 252.0).abs() < 1e-12);
+    assert_eq!(fraction(ny(2024, 12, 2, 0, 0), ny(2024, 11, 25, 0, 0)), -week);
+    assert_eq!(fraction(ny(2024, 11, 25, 12, 0), ny(2024, 11, 25, 12, 0)), 0.0);
+}
+
+#[test]
+fn trading_time_fraction_overnight_test() {
+    let cal = nyse_calendar();
+    let hours = MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap();
+    let fraction = |a, b, w| algebra::trading_time_fraction(&a, &b, &cal, &hours, Some(w)).unwrap();
+
+    // Close to close is one trading day whatever the weighting.
+    for weight in [0.0, 0.25, 1.0] {
+        let day = fraction(ny(2024, 11, 25, 16, 0), ny(2024, 11, 26, 16, 0), weight);
+        assert!((day - 1.0 / 252.0).abs() < 1e-12);
+    }
+    // The overnight share builds up evenly from 16:00 to 09:30.
+    let half_night = fraction(ny(2024, 11, 25, 16, 0), ny(2024, 11, 26, 0, 45), 0.5);
+    assert!((half_night - 0.25 / 252.0).abs() < 1e-12);
+    // Thanksgiving sits inside the gap before the half day.
+    let gap = fraction(ny(2024, 11, 27, 16, 0), ny(2024, 11, 29, 9, 30), 0.3);
+    assert!((gap - 0.3 / 252.0).abs() < 1e-12);
+    // Close to close over November: 19 trading days, the last a half day.
+    let month = fraction(ny(2024, 11, 1, 16, 0), ny(2024, 11, 29, 13, 0), 0.4);
+    let expected = 19.0 * 0.4 + 0.6 * (18.0 + 210.0 / 390.0);
+    assert!((month - expected / 252.0).abs() < 1e-12);
+}
+
+#[test]
+fn trading_time_fraction_invalid_weight_test() {
+    let cal = nyse_calendar();
+    let hours = MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap();
+    let (start, end) = (ny(2024, 11, 25, 0, 0), ny(2024, 11, 26, 0, 0));
+    for weight in [-0.1, 1.5, f64::NAN] {
+        assert_eq!(
+            algebra::trading_time_fraction(&start, &end, &cal, &hours, Some(weight)),
+            Err(DayCountError::InvalidOvernightWeight),
+            "{weight}"
+        );
+    }
+}
+
+#[test]
+fn trading_time_fraction_out_of_range_test() {
+    let cal = basic_calendar();
+    let hours = MarketHours::new(t(9, 0), t(17, 0), FixedOffset::east_opt(0).unwrap()).unwrap();
+    let first = NaiveDate::MIN.and_hms_opt(12, 0, 0).unwrap().and_utc();
+    let later = NaiveDate::MIN.iter_days().nth(10).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+    assert_eq!(
+        algebra::trading_time_fraction(&first, &later, &cal, &hours, None),
+        Err(DayCountError::OutOfRange)
+    );
+
+    // The search also stops at the end of the calendar's valid range.
+    let mut cal = nyse_calendar();
+    cal.set_valid_range(d(2024, 1, 1), d(2024, 12, 31)).unwrap();
+    let hours = MarketHours::new(t(9, 30), t(16, 0), new_york()).unwrap();
+    let (start, end) = (ny(2024, 12, 30, 12, 0), ny(2025, 1, 2, 12, 0));
+    assert_eq!(
+        algebra::trading_time_fraction(&start, &end, &cal, &hours, None),
+        Err(DayCountError::OutOfRange)
+    );
+}
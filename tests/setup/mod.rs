@@ -2,12 +2,27 @@
 // National Holidays: https://www.law.cornell.edu/uscode/text/5/6103
 // NY Federal Reserve published calendar: https://www.frbservices.org/about/holiday-schedules.
 
-use chrono::{Datelike, NaiveDate, Weekday};
+// Shared by several test crates; each one only uses part of it.
+#![allow(dead_code)]
+
+use chrono::{NaiveDate, Weekday};
 use findates::algebra;
 use findates::calendar::Calendar;
 use findates::conventions::{AdjustRule, DayCount, Frequency};
 use findates::schedule::Schedule;
 
+/// Shorthand for a date known to be valid.
+pub fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, day).unwrap()
+}
+
+/// The basic Saturday/Sunday calendar with `dates` added as holidays.
+pub fn calendar_with_holidays(dates: impl IntoIterator<Item = NaiveDate>) -> Calendar {
+    let mut cal = findates::calendar::basic_calendar();
+    cal.add_holidays(dates);
+    cal
+}
+
 /// Reusable test setup for adjust-rule behavior tests
 pub struct AdjustSetup {
     pub cal: Calendar,
@@ -27,7 +42,7 @@ impl AdjustSetup {
         Self {
             cal: basic_cal,
             test_holiday: christmas_day,
-            test_weekend: test_weekend,
+            test_weekend,
         }
     }
 }
@@ -99,7 +114,7 @@ pub fn calendar_setup() -> Calendar {
         .unwrap();
 
     // Juneteenth
-    let juneteenth_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 06, 19).unwrap();
+    let juneteenth_day: NaiveDate = NaiveDate::from_ymd_opt(2023, 6, 19).unwrap();
     let juneteenth_day_sch: Schedule = Schedule::new(
         Frequency::Annual,
         Some(&ny_fed_calendar),
@@ -114,56 +129,44 @@ pub fn calendar_setup() -> Calendar {
 
     // Thanksgiving
     let years = 2023..=2033;
-    let thanksgiving_days: Vec<NaiveDate>;
-    thanksgiving_days = years
+    let thanksgiving_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 11, Weekday::Thu, 4).unwrap())
+        .map(|x| algebra::nth_weekday_of_month(x, 11, Weekday::Thu, 4).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
     // Labor day
-    let labor_days: Vec<NaiveDate>;
-    labor_days = years
+    let labor_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 9, Weekday::Mon, 1).unwrap())
+        .map(|x| algebra::nth_weekday_of_month(x, 9, Weekday::Mon, 1).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
     // Columbus day
-    let columbus_days: Vec<NaiveDate>;
-    columbus_days = years
+    let columbus_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 10, Weekday::Mon, 2).unwrap())
+        .map(|x| algebra::nth_weekday_of_month(x, 10, Weekday::Mon, 2).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
     // Martin Luther King day
-    let mlkjr_days: Vec<NaiveDate>;
-    mlkjr_days = years
+    let mlkjr_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 1, Weekday::Mon, 3).unwrap())
+        .map(|x| algebra::nth_weekday_of_month(x, 1, Weekday::Mon, 3).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
     // Washington day
-    let washington_days: Vec<NaiveDate>;
-    washington_days = years
+    let washington_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| NaiveDate::from_weekday_of_month_opt(x, 2, Weekday::Mon, 3).unwrap())
+        .map(|x| algebra::nth_weekday_of_month(x, 2, Weekday::Mon, 3).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
     // Memorial day
-    fn last_monday_of_may(year: i32) -> NaiveDate {
-        let may_31st = NaiveDate::from_ymd_opt(year, 5, 31).unwrap();
-        let delta = may_31st.weekday().num_days_from_monday();
-        return may_31st - chrono::Duration::days(delta as i64);
-    }
-
-    let memorial_days: Vec<NaiveDate>;
-    memorial_days = years
+    let memorial_days: Vec<NaiveDate> = years
         .clone()
-        .map(|x| last_monday_of_may(x))
+        .map(|x| algebra::last_weekday_of_month(x, 5, Weekday::Mon).unwrap())
         .map(|x| algebra::adjust(&x, Some(&ny_fed_calendar), Some(AdjustRule::Nearest)))
         .collect();
 
@@ -187,7 +190,7 @@ pub fn calendar_setup() -> Calendar {
 
     ny_fed_calendar.add_holidays(all_holidays);
 
-    return ny_fed_calendar;
+    ny_fed_calendar
 }
 
 // Payment dates for the 10 year U.S. Treasury Note
@@ -203,25 +206,12 @@ pub fn payment_schedule_setup(calendar: &Calendar) -> (Vec<NaiveDate>, Vec<f64>,
     let coupon_dates_list: Vec<NaiveDate> = coupon_dates.unwrap().into_iter().collect();
 
     // Calculate day count fractions
-    let mut dcfs: Vec<f64> = vec![];
-    for i in 0..(coupon_dates_list.len() - 1) {
-        let dcf = algebra::day_count_fraction(
-            coupon_dates_list.get(i).unwrap(),
-            coupon_dates_list.get(i + 1).unwrap(),
-            DayCount::D30360Euro,
-            None,
-            None,
-        )
-        .unwrap();
-        dcfs.push(dcf);
-    }
+    let dcfs: Vec<f64> =
+        algebra::day_count_fractions(&coupon_dates_list, DayCount::D30360Euro, None, None).unwrap();
 
     // Adjust for actual settlement dates
-    let settlement_dates: Vec<NaiveDate> = coupon_dates_list
-        .clone()
-        .into_iter()
-        .map(|x| algebra::adjust(&x, Some(&calendar), Some(AdjustRule::Following)))
-        .collect();
+    let settlement_dates: Vec<NaiveDate> =
+        algebra::adjust_many(&coupon_dates_list, Some(calendar), Some(AdjustRule::Following));
 
-    return (coupon_dates_list, dcfs, settlement_dates);
+    (coupon_dates_list, dcfs, settlement_dates)
 }
@@ -0,0 +1,294 @@
+// Integration tests for tenor arithmetic.
+// These tests validate rolling dates by market tenors with business day
+// adjustment and the end-of-month rule.
+
+use chrono::NaiveDate;
+use findates::algebra::{
+    add_tenor, deposit_maturity, fra_dates, on_dates, remaining_periods, sn_dates, tenor_between, tn_dates, FraDates, Term,
+};
+use findates::calendar::{basic_calendar, Calendar};
+use findates::conventions::{AdjustRule, Frequency, ParseTenorError, Tenor};
+use findates::ForwardDateError;
+
+mod setup;
+use setup::{calendar_with_holidays, d};
+
+#[test]
+fn add_tenor_months_mod_following_test() {
+    let cal = basic_calendar();
+    // 2024-01-15 + 3M = 2024-04-15 (Monday)
+    assert_eq!(
+        add_tenor(&d(2024, 1, 15), Tenor::Months(3), Some(&cal), Some(AdjustRule::ModFollowing), false),
+        Some(d(2024, 4, 15))
+    );
+    // 2024-05-31 + 1M = 2024-06-30 (Sunday) → ModFollowing stays in June: Friday 28th
+    assert_eq!(
+        add_tenor(&d(2024, 5, 31), Tenor::Months(1), Some(&cal), Some(AdjustRule::ModFollowing), false),
+        Some(d(2024, 6, 28))
+    );
+    // Following crosses into July
+    assert_eq!(
+        add_tenor(&d(2024, 5, 31), Tenor::Months(1), Some(&cal), Some(AdjustRule::Following), false),
+        Some(d(2024, 7, 1))
+    );
+}
+
+#[test]
+fn add_tenor_end_of_month_rule_test() {
+    let cal = basic_calendar();
+    // 2023-02-28 is the last business day of February
+    let start = d(2023, 2, 28);
+    let rolls: Vec<_> = [3, 6, 9]
+        .into_iter()
+        .map(|m| add_tenor(&start, Tenor::Months(m), Some(&cal), Some(AdjustRule::ModFollowing), true).unwrap())
+        .collect();
+    assert_eq!(rolls, vec![d(2023, 5, 31), d(2023, 8, 31), d(2023, 11, 30)]);
+
+    // Month end on a weekend: last business day of target month
+    // 2024-08-30 (Fri) is the last business day of August; Nov 30 2024 is a Saturday.
+    assert_eq!(
+        add_tenor(&d(2024, 8, 30), Tenor::Months(3), Some(&cal), Some(AdjustRule::ModFollowing), true),
+        Some(d(2024, 11, 29))
+    );
+
+    // Not a month end: the rule does not apply
+    assert_eq!(
+        add_tenor(&d(2023, 2, 27), Tenor::Months(3), Some(&cal), Some(AdjustRule::ModFollowing), true),
+        Some(d(2023, 5, 29))
+    );
+}
+
+#[test]
+fn add_tenor_end_of_month_last_business_day_before_holiday_test() {
+    // 2023-03-31 is a holiday, so 2023-03-30 is the last business day of March.
+    let cal = calendar_with_holidays([d(2023, 3, 31)]);
+    assert_eq!(
+        add_tenor(&d(2023, 3, 30), Tenor::Months(1), Some(&cal), Some(AdjustRule::ModFollowing), true),
+        Some(d(2023, 4, 28))
+    );
+}
+
+#[test]
+fn add_tenor_years_leap_day_test() {
+    let cal = basic_calendar();
+    // 29 Feb 2024 + 1Y clamps to 28 Feb 2025 (Friday)
+    assert_eq!(
+        add_tenor(&d(2024, 2, 29), Tenor::Years(1), Some(&cal), Some(AdjustRule::Following), false),
+        Some(d(2025, 2, 28))
+    );
+}
+
+#[test]
+fn add_tenor_days_are_business_days_test() {
+    // Friday + 2D skips the weekend and a Monday holiday
+    let cal = calendar_with_holidays([d(2024, 3, 18)]);
+    assert_eq!(
+        add_tenor(&d(2024, 3, 15), Tenor::Days(2), Some(&cal), None, false),
+        Some(d(2024, 3, 20))
+    );
+    // 0D only adjusts
+    assert_eq!(
+        add_tenor(&d(2024, 3, 16), Tenor::Days(0), Some(&cal), Some(AdjustRule::Following), false),
+        Some(d(2024, 3, 19))
+    );
+    // ON is one business day
+    assert_eq!(
+        add_tenor(&d(2024, 3, 15), Tenor::Overnight, Some(&cal), None, false),
+        Some(d(2024, 3, 19))
+    );
+}
+
+#[test]
+fn add_tenor_weeks_test() {
+    let cal = calendar_with_holidays([d(2024, 3, 29)]);
+    // Friday + 2W lands on Good Friday → Following moves to Monday
+    assert_eq!(
+        add_tenor(&d(2024, 3, 15), Tenor::Weeks(2), Some(&cal), Some(AdjustRule::Following), false),
+        Some(d(2024, 4, 1))
+    );
+}
+
+#[test]
+fn add_tenor_without_calendar_test() {
+    // Calendar days, no adjustment
+    assert_eq!(add_tenor(&d(2024, 3, 15), Tenor::Days(2), None, None, false), Some(d(2024, 3, 17)));
+    // EOM uses last calendar day
+    assert_eq!(add_tenor(&d(2023, 2, 28), Tenor::Months(1), None, None, true), Some(d(2023, 3, 31)));
+    assert_eq!(add_tenor(&d(2023, 2, 28), Tenor::Months(1), None, None, false), Some(d(2023, 3, 28)));
+}
+
+#[test]
+fn add_tenor_out_of_range_test() {
+    assert_eq!(add_tenor(&NaiveDate::MAX, Tenor::Years(1), None, None, false), None);
+    let cal = basic_calendar();
+    assert_eq!(add_tenor(&NaiveDate::MAX, Tenor::Days(5), Some(&cal), None, false), None);
+}
+
+// ── Deposit maturities ───────────────────────────────────────────────────────
+
+#[test]
+fn deposit_maturity_end_end_test() {
+    let cal = basic_calendar();
+    // Spot on the last business day of the month rolls month end to month end.
+    assert_eq!(deposit_maturity(&d(2023, 2, 28), Tenor::Months(1), &cal, None, true), Some(d(2023, 3, 31)));
+    assert_eq!(deposit_maturity(&d(2024, 4, 30), Tenor::Months(1), &cal, None, true), Some(d(2024, 5, 31)));
+    assert_eq!(deposit_maturity(&d(2024, 4, 30), Tenor::Months(1), &cal, None, false), Some(d(2024, 5, 30)));
+    assert_eq!(deposit_maturity(&d(2024, 2, 29), Tenor::Years(1), &cal, None, true), Some(d(2025, 2, 28)));
+    // 30 June 2024 is a Sunday: modified following stays in June either way.
+    assert_eq!(deposit_maturity(&d(2024, 5, 31), Tenor::Months(1), &cal, None, true), Some(d(2024, 6, 28)));
+    assert_eq!(deposit_maturity(&d(2024, 5, 31), Tenor::Months(1), &cal, None, false), Some(d(2024, 6, 28)));
+    // The 31st rolls to the shorter month's end.
+    assert_eq!(deposit_maturity(&d(2024, 1, 31), Tenor::Months(1), &cal, None, false), Some(d(2024, 2, 29)));
+}
+
+#[test]
+fn deposit_maturity_target_easter_test() {
+    // Maundy Thursday 2024 is the last TARGET2 business day of March.
+    let target = Calendar::target2();
+    let spot = d(2024, 3, 28);
+    assert_eq!(deposit_maturity(&spot, Tenor::Months(3), &target, None, true), Some(d(2024, 6, 28)));
+    assert_eq!(deposit_maturity(&spot, Tenor::Months(3), &target, None, false), Some(d(2024, 6, 28)));
+    assert_eq!(deposit_maturity(&spot, Tenor::Months(1), &target, None, true), Some(d(2024, 4, 30)));
+    assert_eq!(deposit_maturity(&spot, Tenor::Months(1), &target, None, false), Some(d(2024, 4, 29)));
+    assert_eq!(
+        deposit_maturity(&spot, Tenor::Months(1), &target, Some(AdjustRule::Unadjusted), false),
+        Some(d(2024, 4, 28))
+    );
+}
+
+#[test]
+fn deposit_maturity_short_tenors_test() {
+    let cal = calendar_with_holidays([d(2024, 5, 31)]);
+    // Week tenors roll following, even into the next month.
+    assert_eq!(deposit_maturity(&d(2024, 5, 24), Tenor::Weeks(1), &cal, None, true), Some(d(2024, 6, 3)));
+    assert_eq!(add_tenor(&d(2024, 5, 24), Tenor::Weeks(1), Some(&cal), Some(AdjustRule::ModFollowing), true), Some(d(2024, 5, 30)));
+    // Day tenors count business days.
+    assert_eq!(deposit_maturity(&d(2024, 5, 30), Tenor::SpotNext, &cal, None, false), Some(d(2024, 6, 3)));
+    assert_eq!(deposit_maturity(&d(2024, 5, 29), Tenor::Days(2), &cal, None, false), Some(d(2024, 6, 3)));
+}
+
+// ── Overnight, tom-next and spot-next ────────────────────────────────────────
+
+#[test]
+fn short_dates_over_easter_test() {
+    // Good Friday and Easter Monday 2024 are TARGET2 holidays.
+    let target = Calendar::target2();
+    let wednesday = d(2024, 3, 27);
+    assert_eq!(on_dates(&wednesday, &target), Some((d(2024, 3, 27), d(2024, 3, 28))));
+    assert_eq!(tn_dates(&wednesday, &target), Some((d(2024, 3, 28), d(2024, 4, 2))));
+    assert_eq!(sn_dates(&wednesday, &target), Some((d(2024, 4, 2), d(2024, 4, 3))));
+    // Traded on the holiday weekend, everything counts from Tuesday.
+    let saturday = d(2024, 3, 30);
+    assert_eq!(on_dates(&saturday, &target), Some((d(2024, 4, 2), d(2024, 4, 3))));
+    assert_eq!(tn_dates(&saturday, &target), Some((d(2024, 4, 3), d(2024, 4, 4))));
+    assert_eq!(sn_dates(&saturday, &target), Some((d(2024, 4, 4), d(2024, 4, 5))));
+}
+
+#[test]
+fn short_dates_chain_test() {
+    // ON, TN and SN are consecutive one business day periods, and SN ends
+    // where a spot-next deposit from the spot date matures.
+    let cal = calendar_with_holidays([d(2024, 5, 31)]);
+    for today in d(2024, 5, 20).iter_days().take(21) {
+        let (on_start, on_end) = on_dates(&today, &cal).unwrap();
+        let (tn_start, tn_end) = tn_dates(&today, &cal).unwrap();
+        let (sn_start, sn_end) = sn_dates(&today, &cal).unwrap();
+        assert!(cal.is_business_day(&on_start), "{today}");
+        assert_eq!(on_end, tn_start, "{today}");
+        assert_eq!(tn_end, sn_start, "{today}");
+        assert_eq!(deposit_maturity(&sn_start, Tenor::SpotNext, &cal, None, false), Some(sn_end), "{today}");
+    }
+    assert_eq!(on_dates(&NaiveDate::MAX, &cal), None);
+    assert_eq!(sn_dates(&NaiveDate::MAX, &cal), None);
+}
+
+// ── FRA dates ────────────────────────────────────────────────────────────────
+
+#[test]
+fn fra_dates_test() {
+    let cal = basic_calendar();
+    let spot = d(2024, 1, 17);
+    let fra = |notation| fra_dates(notation, &spot, &cal).unwrap();
+    assert_eq!(fra("3x6"), FraDates { fixing: d(2024, 4, 15), settlement: d(2024, 4, 17), maturity: d(2024, 7, 17) });
+    assert_eq!(fra("1X4"), FraDates { fixing: d(2024, 2, 15), settlement: d(2024, 2, 19), maturity: d(2024, 5, 17) });
+    // Spot-starting: fixes two business days before spot.
+    assert_eq!(fra("0x3"), FraDates { fixing: d(2024, 1, 15), settlement: spot, maturity: d(2024, 4, 17) });
+    assert_eq!(fra(" 12x24 ").maturity, d(2026, 1, 19));
+}
+
+#[test]
+fn fra_dates_end_end_test() {
+    // Good Friday and Easter Monday 2024 sit between fixing and settlement.
+    let cal = calendar_with_holidays([d(2024, 3, 29), d(2024, 4, 1)]);
+    let fra = fra_dates("2x5", &d(2024, 1, 31), &cal).unwrap();
+    assert_eq!(fra.settlement, d(2024, 3, 28));
+    assert_eq!(fra.fixing, d(2024, 3, 26));
+    assert_eq!(fra.maturity, d(2024, 6, 28));
+}
+
+#[test]
+fn fra_dates_invalid_notation_test() {
+    let cal = basic_calendar();
+    for notation in ["", "3", "3x", "x6", "6x3", "3x3", "3Mx6M", "-1x2", "3x6x9"] {
+        assert_eq!(
+            fra_dates(notation, &d(2024, 1, 17), &cal),
+            Err(ForwardDateError::InvalidTenor(ParseTenorError)),
+            "{notation}"
+        );
+    }
+}
+
+#[test]
+fn fra_dates_out_of_range_test() {
+    let cal = basic_calendar();
+    let last = NaiveDate::MAX.iter_days().rev().find(|day| cal.is_business_day(day)).unwrap();
+    let first = NaiveDate::MIN.iter_days().find(|day| cal.is_business_day(day)).unwrap();
+    // The maturity overflows, and the fixing of a spot-starting FRA underflows.
+    assert_eq!(fra_dates("0x1", &last, &cal), Err(ForwardDateError::OutOfRange));
+    assert_eq!(fra_dates("0x1", &first, &cal), Err(ForwardDateError::OutOfRange));
+}
+
+// ── Terms between dates ──────────────────────────────────────────────────────
+
+#[test]
+fn tenor_between_test() {
+    let term = |start, end| tenor_between(&start, &end).unwrap();
+    assert_eq!(term(d(2024, 6, 17), d(2034, 5, 15)), Term { years: 9, months: 10, days: 28 });
+    assert_eq!(term(d(2024, 3, 15), d(2034, 2, 15)).to_string(), "9Y11M");
+    assert_eq!(term(d(2024, 3, 15), d(2024, 3, 15)).to_string(), "0D");
+    assert_eq!(term(d(2024, 3, 15), d(2024, 3, 29)).to_string(), "14D");
+    // Month ends clamp: 31 Jan plus one month is 29 Feb, plus two is 31 Mar.
+    assert_eq!(term(d(2024, 1, 31), d(2024, 2, 29)).to_string(), "1M");
+    assert_eq!(term(d(2024, 1, 31), d(2024, 3, 30)).to_string(), "1M30D");
+    assert_eq!(term(d(2024, 1, 31), d(2024, 3, 31)).to_string(), "2M");
+    assert_eq!(term(d(2024, 2, 29), d(2025, 2, 28)).to_string(), "1Y");
+    assert_eq!(term(d(2023, 12, 20), d(2024, 1, 5)).to_string(), "16D");
+    assert_eq!(tenor_between(&d(2024, 3, 16), &d(2024, 3, 15)), None);
+}
+
+#[test]
+fn term_to_tenor_test() {
+    let tenor = |start, end| tenor_between(&start, &end).unwrap().to_tenor();
+    assert_eq!(tenor(d(2024, 3, 15), d(2034, 2, 15)), Tenor::Months(119));
+    assert_eq!(tenor(d(2024, 3, 15), d(2034, 3, 2)), Tenor::Years(10));
+    assert_eq!(tenor(d(2024, 3, 15), d(2034, 3, 1)), Tenor::Months(119));
+    assert_eq!(tenor(d(2024, 3, 15), d(2024, 3, 29)), Tenor::Weeks(2));
+    assert_eq!(tenor(d(2024, 3, 15), d(2024, 3, 25)), Tenor::Days(10));
+    assert_eq!(tenor(d(2024, 3, 15), d(2024, 3, 15)), Tenor::Days(0));
+    assert_eq!(tenor(d(2024, 3, 15), d(2024, 5, 14)), Tenor::Months(2));
+}
+
+#[test]
+fn remaining_periods_test() {
+    let maturity = d(2029, 11, 30);
+    let left = |as_of, frequency| remaining_periods(&as_of, &maturity, frequency);
+    // Coupons on 30 May and 30 Nov, each rolled back from maturity.
+    assert_eq!(left(d(2024, 5, 30), Frequency::Semiannual), 11);
+    assert_eq!(left(d(2024, 5, 29), Frequency::Semiannual), 12);
+    assert_eq!(left(d(2029, 6, 1), Frequency::Semiannual), 1);
+    assert_eq!(left(d(2024, 1, 1), Frequency::Annual), 6);
+    assert_eq!(left(d(2029, 11, 2), Frequency::Weekly), 4);
+    assert_eq!(left(d(2024, 1, 1), Frequency::Zero), 1);
+    assert_eq!(left(maturity, Frequency::Monthly), 0);
+    assert_eq!(left(d(2030, 1, 1), Frequency::Monthly), 0);
+}
@@ -0,0 +1,90 @@
+// Integration tests for TrackedCalendar.
+
+use chrono::{DateTime, TimeZone, Utc};
+use findates::calendar::{self, Calendar};
+use findates::tracked::{CalendarChange, ChangeKind, TrackedCalendar};
+
+mod setup;
+use setup::d;
+
+fn at(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 9, 10, hour, 0, 0).unwrap()
+}
+
+fn base() -> Calendar {
+    let mut cal = calendar::basic_calendar();
+    cal.add_named_holiday(d(2024, 12, 25), "Christmas Day");
+    cal
+}
+
+// ── Recording ────────────────────────────────────────────────────────────────
+
+#[test]
+fn records_only_effective_changes_test() {
+    let mut cal = TrackedCalendar::new(base());
+    assert!(cal.add_holiday(d(2024, 9, 19), at(8), "notice 17"));
+    assert!(!cal.add_holiday(d(2024, 9, 19), at(9), "notice 17 again"));
+    assert!(!cal.remove_holiday(d(2024, 9, 20), at(9), "not a holiday"));
+    assert!(cal.remove_holiday(d(2024, 12, 25), at(10), "notice 18"));
+    // A weekend day can be recorded as a holiday too.
+    assert!(cal.add_holiday(d(2024, 9, 21), at(11), "notice 19"));
+
+    assert_eq!(cal.version(), 3);
+    assert_eq!(
+        cal.history()[1],
+        CalendarChange { kind: ChangeKind::Removed, date: d(2024, 12, 25), timestamp: at(10), source: "notice 18".to_string() }
+    );
+    assert_eq!(cal.calendar().iter_holidays_sorted().collect::<Vec<_>>(), [d(2024, 9, 19), d(2024, 9, 21)]);
+    assert!(cal.calendar().is_business_day(&d(2024, 12, 25)));
+    assert_eq!(cal.base(), &base());
+}
+
+// ── Versions ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn at_version_and_rollback_test() {
+    let mut cal = TrackedCalendar::new(base());
+    cal.add_holiday(d(2024, 9, 19), at(8), "notice 17");
+    cal.remove_holiday(d(2024, 12, 25), at(9), "notice 18");
+    cal.add_holiday(d(2024, 9, 20), at(10), "bad feed");
+
+    let v2 = cal.at_version(2).unwrap();
+    assert!(v2.is_holiday(&d(2024, 9, 19)) && !v2.is_holiday(&d(2024, 9, 20)));
+    assert_eq!(cal.at_version(3).as_ref(), Some(cal.calendar()));
+    assert_eq!(cal.at_version(4), None);
+
+    let undone = cal.rollback(1).unwrap();
+    assert_eq!(undone.iter().map(|change| change.source.as_str()).collect::<Vec<_>>(), ["notice 18", "bad feed"]);
+    assert_eq!(cal.version(), 1);
+    // Rolling back past the removal restores the holiday with its name.
+    assert_eq!(cal.calendar().holiday_name(&d(2024, 12, 25)), Some("Christmas Day"));
+    assert!(cal.calendar().is_holiday(&d(2024, 9, 19)));
+
+    assert_eq!(cal.rollback(0).map(|undone| undone.len()), Some(1));
+    assert_eq!(cal.calendar(), &base());
+    assert!(cal.history().is_empty());
+}
+
+#[test]
+fn rollback_to_future_version_test() {
+    let mut cal = TrackedCalendar::new(base());
+    cal.add_holiday(d(2024, 9, 19), at(8), "notice 17");
+    let before = cal.clone();
+    assert_eq!(cal.rollback(2), None);
+    assert_eq!(cal, before);
+}
+
+#[test]
+fn replay_test() {
+    let mut cal = TrackedCalendar::new(base());
+    cal.add_holiday(d(2024, 9, 19), at(8), "notice 17");
+    cal.remove_holiday(d(2024, 12, 25), at(9), "notice 18");
+    assert_eq!(TrackedCalendar::replay(base(), cal.history().to_vec()), cal);
+
+    // Replaying onto a base that already has the change drops it.
+    let mut other = base();
+    other.add_holidays([d(2024, 9, 19)]);
+    let replayed = TrackedCalendar::replay(other, cal.history().to_vec());
+    assert_eq!(replayed.version(), 1);
+    assert_eq!(replayed.calendar(), cal.calendar());
+}
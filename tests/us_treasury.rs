@@ -3,6 +3,9 @@
 // NY Federal Reserve published calendar: https://www.frbservices.org/about/holiday-schedules.
 
 use chrono::NaiveDate;
+use findates::algebra;
+use findates::conventions::{AdjustRule, DayCount, Frequency};
+use findates::schedule::Schedule;
 use std::collections::HashSet;
 
 mod setup;
@@ -142,3 +145,66 @@ fn settlement_dates_test() {
 
     assert_eq!(expected_dates, settlement_dates);
 }
+
+#[test]
+fn coupon_periods_match_setup_test() {
+    // The generate / day count / adjust workflow of the setup in one call
+    let built_calendar = setup::calendar_setup();
+    let (coupon_dates, coupon_fractions, settlement_dates) =
+        setup::payment_schedule_setup(&built_calendar);
+
+    let issue_date = NaiveDate::from_ymd_opt(2023, 8, 15).unwrap();
+    let maturity_date = NaiveDate::from_ymd_opt(2033, 8, 15).unwrap();
+    let schedule = Schedule::new(
+        Frequency::Semiannual,
+        Some(&built_calendar),
+        Some(AdjustRule::Unadjusted),
+    );
+    let periods = schedule
+        .coupon_periods(&issue_date, &maturity_date, DayCount::D30360Euro, 0)
+        .unwrap();
+
+    assert_eq!(periods.len(), 20);
+    for (i, period) in periods.iter().enumerate() {
+        assert_eq!(period.accrual_start, coupon_dates[i]);
+        assert_eq!(period.accrual_end, coupon_dates[i + 1]);
+        assert_eq!(period.year_fraction, coupon_fractions[i]);
+        assert_eq!(period.payment_date, settlement_dates[i + 1]);
+    }
+}
+
+#[test]
+fn accrued_interest_test() {
+    // The note pays a 3.875% coupon; every full semiannual 30/360 period
+    // accrues exactly half of it.
+    let built_calendar = setup::calendar_setup();
+    let (coupon_dates, _, _) = setup::payment_schedule_setup(&built_calendar);
+    let notional = 1_000_000.0;
+    let rate = 0.03875;
+
+    for period in coupon_dates.windows(2) {
+        let interest = algebra::accrued(
+            &period[0], &period[1], DayCount::D30360Euro, notional, rate, None, None,
+        )
+        .unwrap();
+        assert_eq!(interest, 19_375.0);
+    }
+
+    // Accrued interest for a trade settling mid-period: 15 Aug to 20 Oct
+    // 2023 is 65 days under 30/360.
+    let settle = NaiveDate::from_ymd_opt(2023, 10, 20).unwrap();
+    let interest = algebra::accrued(
+        &coupon_dates[0], &settle, DayCount::D30360Euro, notional, rate, None, None,
+    )
+    .unwrap();
+    assert!((interest - notional * rate * 65.0 / 360.0).abs() < 1e-9);
+
+    // Adjusting the dates to the NY Fed calendar: 15 Feb 2025 is a Saturday
+    // and rolls to Tuesday 18 Feb after Washington's Birthday.
+    let (start, end) = (coupon_dates[2], coupon_dates[3]);
+    let adjusted = algebra::accrued(
+        &start, &end, DayCount::D30360Euro, notional, rate, Some(&built_calendar), Some(AdjustRule::Following),
+    )
+    .unwrap();
+    assert!((adjusted - notional * rate * 183.0 / 360.0).abs() < 1e-9);
+}
@@ -0,0 +1,90 @@
+// Integration tests for the time-zone-aware date functions.
+// Run with `cargo test --features tz`.
+#![cfg(feature = "tz")]
+
+use chrono::{NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Europe::London;
+use findates::algebra;
+use findates::calendar::basic_calendar;
+use findates::conventions::{AdjustRule, Frequency};
+use findates::schedule::Schedule;
+use findates::zoned;
+use findates::DateError;
+
+mod setup;
+use setup::d;
+
+fn t(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+// ── Local times ──────────────────────────────────────────────────────────────
+
+#[test]
+fn at_local_time_test() {
+    let fixing = zoned::at_local_time(&d(2024, 6, 3), t(11, 0), &London).unwrap();
+    assert_eq!(fixing.with_timezone(&Utc), Utc.with_ymd_and_hms(2024, 6, 3, 10, 0, 0).unwrap());
+
+    // 01:30 does not exist on the day British Summer Time starts...
+    assert_eq!(zoned::at_local_time(&d(2024, 3, 31), t(1, 30), &London), Err(DateError::InvalidLocalTime));
+    // ...and occurs twice on the day it ends, resolving to the first.
+    let repeated = zoned::at_local_time(&d(2024, 10, 27), t(1, 30), &London).unwrap();
+    assert_eq!(repeated.with_timezone(&Utc), Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap());
+}
+
+#[test]
+fn fixing_schedule_test() {
+    let cal = basic_calendar();
+    let sched = Schedule::new(Frequency::Semiannual, Some(&cal), Some(AdjustRule::ModFollowing));
+    let dates = sched.generate(&d(2024, 1, 31), &d(2025, 1, 31)).unwrap();
+    let fixings = zoned::with_local_time(&dates, t(11, 0), &London).unwrap();
+
+    let utc: Vec<_> = fixings.iter().map(|fixing| fixing.naive_utc()).collect();
+    assert_eq!(utc, [
+        d(2024, 1, 31).and_time(t(11, 0)),
+        d(2024, 7, 31).and_time(t(10, 0)),
+        d(2025, 1, 31).and_time(t(11, 0)),
+    ]);
+    assert_eq!(zoned::with_local_time(&[d(2024, 3, 31)], t(1, 0), &London), Err(DateError::InvalidLocalTime));
+}
+
+// ── Adjustment ───────────────────────────────────────────────────────────────
+
+#[test]
+fn adjust_test() {
+    let cal = basic_calendar();
+    // Saturday 11:00 London moves to Monday 11:00 London.
+    let saturday = London.with_ymd_and_hms(2024, 3, 16, 11, 0, 0).unwrap();
+    let monday = zoned::adjust(&saturday, Some(&cal), Some(AdjustRule::Following)).unwrap();
+    assert_eq!(monday, London.with_ymd_and_hms(2024, 3, 18, 11, 0, 0).unwrap());
+    assert_eq!(zoned::adjust(&saturday, Some(&cal), Some(AdjustRule::Preceding)).unwrap().date_naive(), d(2024, 3, 15));
+    assert_eq!(zoned::adjust(&saturday, None, Some(AdjustRule::Following)), Ok(saturday));
+    assert_eq!(zoned::adjust(&monday, Some(&cal), Some(AdjustRule::Following)), Ok(monday));
+
+    // The business day is judged in the datetime's own time zone.
+    let friday_evening = New_York.with_ymd_and_hms(2024, 3, 15, 21, 0, 0).unwrap();
+    assert!(zoned::is_business_day(&friday_evening, &cal));
+    assert!(!zoned::is_business_day(&friday_evening.with_timezone(&London), &cal));
+    let adjusted = zoned::adjust(&friday_evening.with_timezone(&London), Some(&cal), Some(AdjustRule::Following));
+    assert_eq!(adjusted.unwrap(), London.with_ymd_and_hms(2024, 3, 18, 1, 0, 0).unwrap());
+}
+
+// ── Timestamps ───────────────────────────────────────────────────────────────
+
+#[test]
+fn timestamp_daylight_saving_test() {
+    // Midnight in New York is 05:00 UTC in winter and 04:00 UTC in summer.
+    let winter = algebra::timestamp_at(&d(2024, 1, 15), NaiveTime::MIN, &New_York).unwrap();
+    let summer = algebra::timestamp_at(&d(2024, 7, 15), NaiveTime::MIN, &New_York).unwrap();
+    assert_eq!(winter, Utc.with_ymd_and_hms(2024, 1, 15, 5, 0, 0).unwrap().timestamp());
+    assert_eq!(summer, Utc.with_ymd_and_hms(2024, 7, 15, 4, 0, 0).unwrap().timestamp());
+    assert_eq!(algebra::date_from_timestamp(summer, &New_York), Ok(d(2024, 7, 15)));
+    assert_eq!(algebra::date_from_timestamp(summer, &London), Ok(d(2024, 7, 15)));
+    assert_eq!(algebra::date_from_timestamp(summer - 1, &New_York), Ok(d(2024, 7, 14)));
+
+    // 01:30 London is skipped when summer time starts and repeated when it ends.
+    assert_eq!(algebra::timestamp_at(&d(2024, 3, 31), t(1, 30), &London), Err(DateError::InvalidLocalTime));
+    let repeated = algebra::timestamp_at(&d(2024, 10, 27), t(1, 30), &London).unwrap();
+    assert_eq!(repeated, Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap().timestamp());
+}